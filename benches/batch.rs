@@ -0,0 +1,47 @@
+//! Compares parsing a list of paths one at a time against parsing them into a reused
+//! [`ComponentBatch`]. Run with `cargo bench --bench batch`.
+
+mod support;
+
+use typed_path::{Component, ComponentBatch, UnixEncoding, UnixPath};
+
+fn paths() -> Vec<String> {
+    (0..1_000)
+        .map(|i| {
+            format!(
+                "/home/user{}/projects/crate{}/src/module{}/file.rs",
+                i % 7,
+                i % 31,
+                i
+            )
+        })
+        .collect()
+}
+
+fn main() {
+    let mut bencher = support::Bencher::from_args();
+    let paths = paths();
+
+    bencher.bench("batch/per_path", || {
+        let mut count = 0;
+        for path in &paths {
+            let components: Vec<_> = UnixPath::new(path)
+                .components()
+                .map(|c| (c.kind(), c.as_bytes()))
+                .collect();
+            count += components.len();
+        }
+        count
+    });
+
+    let mut batch = ComponentBatch::<UnixEncoding>::new();
+    bencher.bench("batch/reused", || {
+        batch.clear();
+        batch.extend(&paths);
+        (0..batch.len())
+            .filter_map(|i| batch.component_count(i))
+            .sum()
+    });
+
+    bencher.finish();
+}
@@ -0,0 +1,84 @@
+//! Benchmarks the hot paths of the crate for both encodings: iterating components, pushing and
+//! joining, stripping prefixes, normalizing, and detecting the type of a [`TypedPath`]. Run with
+//! `cargo bench --bench paths`, see `support` for the options. Names have the form
+//! `group/encoding/input`, so `cargo bench --bench paths -- normalize/windows` runs one group.
+
+mod support;
+
+use typed_path::{Encoding, Path, PathBuf, TypedPath, UnixEncoding, WindowsEncoding};
+
+use support::Bencher;
+
+const UNIX_PATHS: &[(&str, &str)] = &[
+    ("short", "a/b"),
+    ("absolute", "/usr/local/share/doc/typed-path/README.md"),
+    (
+        "dots",
+        "/home/user/./projects/../projects/crate/./src/../src/lib.rs",
+    ),
+    ("separators", "//srv///data//logs////app.log"),
+];
+
+const WINDOWS_PATHS: &[(&str, &str)] = &[
+    ("short", r"a\b"),
+    ("disk", r"C:\Program Files\typed-path\docs\README.md"),
+    (
+        "dots",
+        r"C:\Users\me\.\projects\..\projects\crate\.\src\..\src\lib.rs",
+    ),
+    ("unc", r"\\server\share\team\reports\2024\q1.xlsx"),
+    ("verbatim", r"\\?\C:\Windows\System32\drivers\etc\hosts"),
+];
+
+fn bench_encoding<T>(bencher: &mut Bencher, encoding: &str, paths: &[(&str, &str)])
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    for (input, path) in paths {
+        let path = Path::<T>::new(path);
+
+        bencher.bench(&format!("components/{encoding}/{input}"), || {
+            path.components().count()
+        });
+        bencher.bench(&format!("components_rev/{encoding}/{input}"), || {
+            path.components().rev().count()
+        });
+
+        bencher.bench(&format!("join/{encoding}/{input}"), || {
+            path.join("child").as_bytes().len()
+        });
+        bencher.bench(&format!("push/{encoding}/{input}"), || {
+            let mut buf = PathBuf::<T>::from(path.as_bytes());
+            buf.push("a");
+            buf.push("b");
+            buf.as_bytes().len()
+        });
+
+        // The parent always prefixes the path, so this strips successfully
+        let parent = path.parent().unwrap_or(path);
+        bencher.bench(&format!("strip_prefix/{encoding}/{input}"), || {
+            path.strip_prefix(parent)
+                .map_or(0, |rest| rest.as_bytes().len())
+        });
+
+        bencher.bench(&format!("normalize/{encoding}/{input}"), || {
+            path.normalize().as_bytes().len()
+        });
+    }
+}
+
+fn bench_typed(bencher: &mut Bencher) {
+    for (input, path) in UNIX_PATHS.iter().chain(WINDOWS_PATHS) {
+        bencher.bench(&format!("typed_derive/{input}"), || {
+            TypedPath::derive(path).is_windows() as usize
+        });
+    }
+}
+
+fn main() {
+    let mut bencher = Bencher::from_args();
+    bench_encoding::<UnixEncoding>(&mut bencher, "unix", UNIX_PATHS);
+    bench_encoding::<WindowsEncoding>(&mut bencher, "windows", WINDOWS_PATHS);
+    bench_typed(&mut bencher);
+    bencher.finish();
+}
@@ -0,0 +1,138 @@
+//! A small timing harness shared by the benches, which need to build without any dependencies.
+//!
+//! Each bench binary accepts, after `cargo bench --bench <name> --`:
+//!
+//! * a filter, running only benchmarks whose name contains it
+//! * `--save-baseline <file>`, writing the results to `file`
+//! * `--baseline <file>`, comparing the results against `file` and exiting with an error if any
+//!   benchmark is more than [`REGRESSION_THRESHOLD`] slower
+
+use std::collections::HashMap;
+use std::fs;
+use std::process;
+use std::time::{Duration, Instant};
+
+/// Slowdown, as a fraction of the baseline, past which a benchmark counts as a regression
+pub const REGRESSION_THRESHOLD: f64 = 0.10;
+
+/// Time spent running each benchmark after calibrating how many iterations it takes
+const MEASURE_TIME: Duration = Duration::from_millis(200);
+
+pub struct Bencher {
+    filter: Option<String>,
+    baseline: Option<HashMap<String, f64>>,
+    save_baseline: Option<String>,
+    results: Vec<(String, f64)>,
+    regressed: bool,
+}
+
+impl Bencher {
+    /// Reads the filter and baseline options from the command line.
+    pub fn from_args() -> Self {
+        let mut bencher = Self {
+            filter: None,
+            baseline: None,
+            save_baseline: None,
+            results: Vec::new(),
+            regressed: false,
+        };
+
+        let mut args = std::env::args().skip(1);
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--baseline" => {
+                    let file = args.next().expect("--baseline needs a file");
+                    bencher.baseline = Some(read_baseline(&file));
+                }
+                "--save-baseline" => {
+                    bencher.save_baseline =
+                        Some(args.next().expect("--save-baseline needs a file"));
+                }
+                // Passed along by `cargo bench` itself
+                "--bench" => {}
+                _ => bencher.filter = Some(arg),
+            }
+        }
+
+        bencher
+    }
+
+    /// Runs `f` repeatedly and reports the average time per call. `f` returns a value derived
+    /// from its work, which is checked so that the work cannot be optimized away.
+    pub fn bench<F: FnMut() -> usize>(&mut self, name: &str, mut f: F) {
+        if let Some(filter) = &self.filter {
+            if !name.contains(filter.as_str()) {
+                return;
+            }
+        }
+
+        // Double the iterations until a run is long enough to time reliably
+        let mut iters: u32 = 1;
+        let mut total = 0;
+        loop {
+            let start = Instant::now();
+            for _ in 0..iters {
+                total += f();
+            }
+            if start.elapsed() * 10 >= MEASURE_TIME || iters >= 1 << 30 {
+                break;
+            }
+            iters *= 2;
+        }
+
+        let iters = iters * 10;
+        let start = Instant::now();
+        for _ in 0..iters {
+            total += f();
+        }
+        let ns = start.elapsed().as_nanos() as f64 / f64::from(iters);
+        assert_ne!(total, usize::MAX, "{name}");
+
+        match self.baseline.as_ref().and_then(|b| b.get(name)) {
+            Some(&old) => {
+                let change = (ns - old) / old;
+                let regressed = change > REGRESSION_THRESHOLD;
+                self.regressed |= regressed;
+                println!(
+                    "{name:<40} {ns:>12.1} ns/iter {:>+8.1}%{}",
+                    change * 100.0,
+                    if regressed { "  REGRESSED" } else { "" }
+                );
+            }
+            None => println!("{name:<40} {ns:>12.1} ns/iter"),
+        }
+
+        self.results.push((name.to_string(), ns));
+    }
+
+    /// Saves the baseline if requested, and exits with an error if anything regressed.
+    pub fn finish(self) {
+        if let Some(file) = &self.save_baseline {
+            let contents: String = self
+                .results
+                .iter()
+                .map(|(name, ns)| format!("{name} {ns}\n"))
+                .collect();
+            fs::write(file, contents).expect("failed to write baseline");
+        }
+
+        if self.regressed {
+            eprintln!(
+                "some benchmarks regressed by more than {}%",
+                REGRESSION_THRESHOLD * 100.0
+            );
+            process::exit(1);
+        }
+    }
+}
+
+fn read_baseline(file: &str) -> HashMap<String, f64> {
+    fs::read_to_string(file)
+        .expect("failed to read baseline")
+        .lines()
+        .filter_map(|line| {
+            let (name, ns) = line.rsplit_once(' ')?;
+            Some((name.to_string(), ns.parse().ok()?))
+        })
+        .collect()
+}
@@ -0,0 +1,34 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use typed_path::{Component, Encoding, Path, PathBuf, UnixEncoding, WindowsEncoding, WindowsPath};
+
+fn check<T>(data: &[u8])
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    let path = Path::<T>::new(data);
+
+    let forward: Vec<&[u8]> = path.components().map(|c| c.as_bytes()).collect();
+    let mut backward: Vec<&[u8]> = path.components().rev().map(|c| c.as_bytes()).collect();
+    backward.reverse();
+    assert_eq!(forward, backward, "{path:?}");
+
+    // Pushing the components one by one must give back an equal path, unless a component like
+    // `C:` in `/C:` has a different meaning on its own or, like std, pushing onto a verbatim path
+    // drops `.` and resolves `..`
+    let verbatim = WindowsPath::new(data).components().has_any_verbatim_prefix();
+    if path.is_valid() && !verbatim {
+        let mut collected = PathBuf::<T>::new();
+        for component in path.components() {
+            collected.push(component.as_bytes());
+        }
+        assert_eq!(collected.components(), path.components(), "{path:?}");
+        assert_eq!(collected.as_path(), path, "{path:?}");
+    }
+}
+
+fuzz_target!(|data: &[u8]| {
+    check::<UnixEncoding>(data);
+    check::<WindowsEncoding>(data);
+});
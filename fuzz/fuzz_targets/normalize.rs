@@ -0,0 +1,26 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use typed_path::{Encoding, Path, UnixEncoding, WindowsEncoding};
+
+fn check<T>(data: &[u8])
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    let path = Path::<T>::new(data);
+    // Components like `C:` in `/C:` can take on a different meaning once rebuilt
+    if !path.is_valid() {
+        return;
+    }
+
+    let normalized = path.normalize();
+
+    assert_eq!(normalized.normalize(), normalized, "{path:?}");
+    // Normalizing may add the root a verbatim disk like `\\?\C:` needs, but never removes one
+    assert!(!path.is_absolute() || normalized.is_absolute(), "{path:?}");
+}
+
+fuzz_target!(|data: &[u8]| {
+    check::<UnixEncoding>(data);
+    check::<WindowsEncoding>(data);
+});
@@ -0,0 +1,101 @@
+#![no_main]
+
+//! Compares the encoding matching the host against `std::path`, which typed-path mirrors.
+
+use libfuzzer_sys::fuzz_target;
+
+#[cfg(unix)]
+fn check(data: &[u8]) {
+    use std::ffi::OsStr;
+    use std::os::unix::ffi::OsStrExt;
+
+    use typed_path::{Component, UnixPath};
+
+    fn components(path: &UnixPath) -> Vec<&[u8]> {
+        path.components().map(|c| c.as_bytes()).collect()
+    }
+
+    fn std_components(path: &std::path::Path) -> Vec<&[u8]> {
+        path.components().map(|c| c.as_os_str().as_bytes()).collect()
+    }
+
+    let ours = UnixPath::new(data);
+    let theirs = std::path::Path::new(OsStr::from_bytes(data));
+
+    assert_eq!(components(ours), std_components(theirs), "{ours:?}");
+    assert_eq!(
+        ours.components().rev().map(|c| c.as_bytes()).collect::<Vec<_>>(),
+        theirs.components().rev().map(|c| c.as_os_str().as_bytes()).collect::<Vec<_>>(),
+        "{ours:?}"
+    );
+    assert_eq!(ours.file_name(), theirs.file_name().map(OsStr::as_bytes), "{ours:?}");
+    assert_eq!(ours.file_stem(), theirs.file_stem().map(OsStr::as_bytes), "{ours:?}");
+    assert_eq!(ours.extension(), theirs.extension().map(OsStr::as_bytes), "{ours:?}");
+    assert_eq!(
+        ours.parent().map(UnixPath::as_bytes),
+        theirs.parent().map(|p| p.as_os_str().as_bytes()),
+        "{ours:?}"
+    );
+    assert_eq!(ours.is_absolute(), theirs.is_absolute(), "{ours:?}");
+    assert_eq!(ours.has_root(), theirs.has_root(), "{ours:?}");
+
+    // Remaining bytes may keep separators that std drops, so compare what is left by component
+    let base = ours.parent().unwrap_or(ours);
+    let std_base = std::path::Path::new(OsStr::from_bytes(base.as_bytes()));
+    assert_eq!(ours.starts_with(base), theirs.starts_with(std_base), "{ours:?}");
+    assert_eq!(
+        ours.strip_prefix(base).ok().map(components),
+        theirs.strip_prefix(std_base).ok().map(std_components),
+        "{ours:?}"
+    );
+}
+
+#[cfg(windows)]
+fn check(data: &[u8]) {
+    use typed_path::{Utf8Component, Utf8WindowsPath};
+
+    // std only takes WTF-8 on Windows, so stick to input that is valid UTF-8
+    let s = match std::str::from_utf8(data) {
+        Ok(s) => s,
+        Err(_) => return,
+    };
+
+    fn components(path: &Utf8WindowsPath) -> Vec<&str> {
+        path.components().map(|c| c.as_str()).collect()
+    }
+
+    fn std_components(path: &std::path::Path) -> Vec<&str> {
+        path.components()
+            .map(|c| c.as_os_str().to_str().unwrap())
+            .collect()
+    }
+
+    let ours = Utf8WindowsPath::new(s);
+    let theirs = std::path::Path::new(s);
+
+    assert_eq!(components(ours), std_components(theirs), "{ours:?}");
+    assert_eq!(ours.file_name(), theirs.file_name().and_then(|s| s.to_str()), "{ours:?}");
+    assert_eq!(ours.file_stem(), theirs.file_stem().and_then(|s| s.to_str()), "{ours:?}");
+    assert_eq!(ours.extension(), theirs.extension().and_then(|s| s.to_str()), "{ours:?}");
+    assert_eq!(
+        ours.parent().map(Utf8WindowsPath::as_str),
+        theirs.parent().and_then(|p| p.to_str()),
+        "{ours:?}"
+    );
+    assert_eq!(ours.is_absolute(), theirs.is_absolute(), "{ours:?}");
+    assert_eq!(ours.has_root(), theirs.has_root(), "{ours:?}");
+
+    let base = ours.parent().unwrap_or(ours);
+    let std_base = std::path::Path::new(base.as_str());
+    assert_eq!(ours.starts_with(base), theirs.starts_with(std_base), "{ours:?}");
+    assert_eq!(
+        ours.strip_prefix(base).ok().map(components),
+        theirs.strip_prefix(std_base).ok().map(std_components),
+        "{ours:?}"
+    );
+}
+
+#[cfg(not(any(unix, windows)))]
+fn check(_data: &[u8]) {}
+
+fuzz_target!(|data: &[u8]| check(data));
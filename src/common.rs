@@ -1,8 +1,72 @@
+/// Enters a [`tracing`] span at the trace level for the rest of the enclosing block when the
+/// `tracing` feature is enabled, and expands to nothing otherwise.
+///
+/// [`tracing`]: https://docs.rs/tracing
+macro_rules! trace_span {
+    ($($arg:tt)+) => {
+        #[cfg(feature = "tracing")]
+        let _span = ::tracing::trace_span!($($arg)+).entered();
+    };
+}
+
+/// Implements equality in both directions between the UTF-8 paths of `$utf8` and the byte paths
+/// of `$bytes`, borrowed or owned, which are equal when their underlying bytes are the same.
+macro_rules! impl_cmp_utf8_bytes {
+    ($utf8:ty, $bytes:ty) => {
+        impl_cmp_utf8_bytes!(@pair ; $crate::Utf8Path<$utf8>, $crate::Path<$bytes>);
+        impl_cmp_utf8_bytes!(@pair ; $crate::Utf8Path<$utf8>, $crate::PathBuf<$bytes>);
+        impl_cmp_utf8_bytes!(@pair 'a; &'a $crate::Utf8Path<$utf8>, $crate::PathBuf<$bytes>);
+        impl_cmp_utf8_bytes!(@pair ; $crate::Utf8PathBuf<$utf8>, $crate::Path<$bytes>);
+        impl_cmp_utf8_bytes!(@pair 'a; $crate::Utf8PathBuf<$utf8>, &'a $crate::Path<$bytes>);
+        impl_cmp_utf8_bytes!(@pair ; $crate::Utf8PathBuf<$utf8>, $crate::PathBuf<$bytes>);
+    };
+    (@pair $($lt:lifetime),* ; $lhs:ty, $rhs:ty) => {
+        impl<$($lt),*> PartialEq<$rhs> for $lhs {
+            #[inline]
+            fn eq(&self, other: &$rhs) -> bool {
+                self.as_str().as_bytes() == other.as_bytes()
+            }
+        }
+
+        impl<$($lt),*> PartialEq<$lhs> for $rhs {
+            #[inline]
+            fn eq(&self, other: &$lhs) -> bool {
+                self.as_bytes() == other.as_str().as_bytes()
+            }
+        }
+    };
+}
+
+mod encoding_info;
 mod errors;
+mod filter;
+pub(crate) mod fnv;
+mod mime;
 #[macro_use]
 mod non_utf8;
+mod options;
+mod path_ext;
+mod path_like;
+mod pattern;
+pub(crate) mod percent;
+mod profile;
+mod rename;
+mod sort_key;
+mod template;
+mod truncate;
 mod utf8;
+pub(crate) mod varint;
+pub(crate) mod wtf8;
 
+pub use encoding_info::*;
 pub use errors::*;
+pub use filter::*;
 pub use non_utf8::*;
+pub use options::*;
+pub use path_ext::*;
+pub use path_like::*;
+pub use pattern::*;
+pub use profile::*;
+pub use rename::*;
+pub use template::*;
 pub use utf8::*;
@@ -0,0 +1,52 @@
+/// Describes the conventions of an encoding, allowing generic code to inspect separators and
+/// case sensitivity rather than hard-coding them for each encoding.
+///
+/// This is returned by [`Encoding::info`], [`Utf8Encoding::info`], and the `encoding_info`
+/// methods of the typed paths, such as [`TypedPath::encoding_info`].
+///
+/// [`Encoding::info`]: crate::Encoding::info
+/// [`Utf8Encoding::info`]: crate::Utf8Encoding::info
+/// [`TypedPath::encoding_info`]: crate::TypedPath::encoding_info
+///
+/// # Examples
+///
+/// ```
+/// use typed_path::{Encoding, UnixEncoding, WindowsEncoding};
+///
+/// let info = WindowsEncoding::info();
+/// assert_eq!(info.name, "windows");
+/// assert_eq!(info.separator, '\\');
+/// assert!(info.is_separator(b'/'));
+/// assert!(!info.case_sensitive);
+///
+/// let info = UnixEncoding::info();
+/// assert!(!info.is_separator(b'\\'));
+/// assert!(info.case_sensitive);
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct EncodingInfo {
+    /// Name of the encoding, such as `"unix"` or `"windows"`.
+    pub name: &'static str,
+
+    /// Separator placed between components when building a path.
+    pub separator: char,
+
+    /// Additional separators recognized when parsing a path.
+    pub alt_separators: &'static [char],
+
+    /// Whether components are compared case-sensitively.
+    pub case_sensitive: bool,
+}
+
+impl EncodingInfo {
+    /// Returns true if `byte` is either the primary or an alternate separator of the encoding.
+    pub fn is_separator(&self, byte: u8) -> bool {
+        self.separator as u32 == byte as u32
+            || self.alt_separators.iter().any(|&c| c as u32 == byte as u32)
+    }
+
+    /// Returns true if `c` is either the primary or an alternate separator of the encoding.
+    pub fn is_separator_char(&self, c: char) -> bool {
+        self.separator == c || self.alt_separators.contains(&c)
+    }
+}
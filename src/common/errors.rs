@@ -1,4 +1,9 @@
+use alloc::string::FromUtf8Error;
 use core::fmt;
+use core::ops::Range;
+use core::str::Utf8Error;
+
+use crate::no_std_compat::*;
 
 /// An error returned if the prefix was not found.
 ///
@@ -8,19 +13,702 @@ use core::fmt;
 /// [`Path`]: crate::Path
 /// [`strip_prefix`]: crate::Path::strip_prefix
 #[derive(Clone, Debug, PartialEq, Eq)]
-pub struct StripPrefixError(pub(crate) ());
+pub struct StripPrefixError {
+    pub(crate) hint: Option<StripPrefixHint>,
+}
+
+impl StripPrefixError {
+    /// Returns the least relaxed comparison under which the prefix would have been found, or
+    /// [`None`] if it would not be found under any of them.
+    ///
+    /// Pass the hint to [`Path::strip_prefix_with`] to retry with that comparison.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{StripPrefixHint, WindowsPath};
+    ///
+    /// let path = WindowsPath::new(r"C:\Users\me\notes.txt");
+    ///
+    /// let err = path.strip_prefix(r"c:\users").unwrap_err();
+    /// assert_eq!(err.would_match_if(), Some(StripPrefixHint::IgnoreCase));
+    ///
+    /// let err = path.strip_prefix(r"C:\Users\you\..\me").unwrap_err();
+    /// assert_eq!(err.would_match_if(), Some(StripPrefixHint::Normalize));
+    ///
+    /// let err = path.strip_prefix(r"D:\Users").unwrap_err();
+    /// assert_eq!(err.would_match_if(), None);
+    /// ```
+    ///
+    /// [`Path::strip_prefix_with`]: crate::Path::strip_prefix_with
+    pub fn would_match_if(&self) -> Option<StripPrefixHint> {
+        self.hint
+    }
+}
 
 impl fmt::Display for StripPrefixError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "prefix not found")
+        match self.hint {
+            Some(hint) => write!(f, "prefix not found, but would match {}", hint),
+            None => write!(f, "prefix not found"),
+        }
     }
 }
 
 #[cfg(feature = "std")]
 impl std::error::Error for StripPrefixError {}
 
+/// A relaxed way of comparing components under which a failed [`strip_prefix`] would succeed,
+/// reported by [`StripPrefixError::would_match_if`].
+///
+/// [`strip_prefix`]: crate::Path::strip_prefix
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum StripPrefixHint {
+    /// When components match once ascii case is ignored, such as the drives `C:` and `c:`.
+    IgnoreCase,
+
+    /// When components match once both paths are normalized, which skips `.`, resolves `..`,
+    /// and treats every separator within a component (like those of a UNC prefix) as the same.
+    Normalize,
+
+    /// When components only match once both paths are normalized and ascii case is ignored.
+    IgnoreCaseAndNormalize,
+}
+
+impl StripPrefixHint {
+    /// Every hint, from the least to the most relaxed
+    pub(crate) const ALL: [Self; 3] = [
+        Self::IgnoreCase,
+        Self::Normalize,
+        Self::IgnoreCaseAndNormalize,
+    ];
+
+    /// Returns true if components are compared ignoring ascii case.
+    pub fn ignores_case(self) -> bool {
+        matches!(self, Self::IgnoreCase | Self::IgnoreCaseAndNormalize)
+    }
+
+    /// Returns true if both paths are normalized before their components are compared.
+    pub fn normalizes(self) -> bool {
+        matches!(self, Self::Normalize | Self::IgnoreCaseAndNormalize)
+    }
+
+    /// Returns true if `a` and `b`, the bytes of two components, are equal under this hint,
+    /// where `is_separator` identifies the separators of their encoding
+    pub(crate) fn bytes_eq(self, a: &[u8], b: &[u8], is_separator: impl Fn(u8) -> bool) -> bool {
+        a.len() == b.len()
+            && a.iter().zip(b).all(|(x, y)| {
+                x == y
+                    || (self.ignores_case() && x.eq_ignore_ascii_case(y))
+                    || (self.normalizes() && is_separator(*x) && is_separator(*y))
+            })
+    }
+}
+
+impl fmt::Display for StripPrefixHint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::IgnoreCase => write!(f, "ignoring case"),
+            Self::Normalize => write!(f, "once normalized"),
+            Self::IgnoreCaseAndNormalize => write!(f, "once normalized and ignoring case"),
+        }
+    }
+}
+
+/// An error returned if a path cannot be expressed relative to a base because the two begin
+/// with different prefixes, such as paths on different Windows drives or a UNC path and a local
+/// path.
+///
+/// This `struct` is created by the [`relative_to`] method on [`Path`].
+/// See its documentation for more.
+///
+/// [`Path`]: crate::Path
+/// [`relative_to`]: crate::Path::relative_to
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DifferentPrefixError {
+    pub(crate) prefix: Vec<u8>,
+    pub(crate) base_prefix: Vec<u8>,
+}
+
+impl DifferentPrefixError {
+    /// Returns the prefix of the path that was being made relative.
+    pub fn prefix(&self) -> &[u8] {
+        &self.prefix
+    }
+
+    /// Returns the prefix of the base that the path was being made relative to.
+    pub fn base_prefix(&self) -> &[u8] {
+        &self.base_prefix
+    }
+}
+
+impl fmt::Display for DifferentPrefixError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "path prefix {:?} differs from base prefix {:?}",
+            String::from_utf8_lossy(&self.prefix),
+            String::from_utf8_lossy(&self.base_prefix)
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for DifferentPrefixError {}
+
+/// An error returned when a path is not valid UTF-8.
+///
+/// Mirrors [`Utf8Error`] by reporting where the path stops being valid UTF-8, while also
+/// keeping the bytes of the path so that the valid portion can be shown alongside the error.
+///
+/// This `struct` is created by the [`from_bytes_path`] method on [`Utf8Path`] and the
+/// [`from_bytes_path_buf`] method on [`Utf8PathBuf`], as well as when converting a
+/// [`TypedPath`] or [`TypedPathBuf`] into its UTF-8 counterpart. See their documentation for more.
+///
+/// [`TypedPath`]: crate::TypedPath
+/// [`TypedPathBuf`]: crate::TypedPathBuf
+/// [`Utf8Path`]: crate::Utf8Path
+/// [`Utf8PathBuf`]: crate::Utf8PathBuf
+/// [`from_bytes_path`]: crate::Utf8Path::from_bytes_path
+/// [`from_bytes_path_buf`]: crate::Utf8PathBuf::from_bytes_path_buf
+///
+/// # Examples
+///
+/// ```
+/// use typed_path::{Path, Utf8Path, UnixEncoding, Utf8UnixEncoding};
+///
+/// let path = Path::<UnixEncoding>::new(b"/home/user/caf\xe9/notes.txt");
+/// let err = Utf8Path::<Utf8UnixEncoding>::from_bytes_path(path).unwrap_err();
+///
+/// assert_eq!(err.valid_up_to(), 14);
+/// assert_eq!(err.error_len(), Some(1));
+/// assert_eq!(err.valid_prefix(), "/home/user/caf");
+/// assert_eq!(err.invalid_bytes(), b"\xe9");
+///
+/// // Typed paths report the same details when converted
+/// use std::convert::TryFrom;
+/// use typed_path::{TypedPathBuf, Utf8TypedPathBuf};
+///
+/// let path = TypedPathBuf::from_windows(b"C:\\Users\\caf\xe9");
+/// let err = Utf8TypedPathBuf::try_from(path).unwrap_err();
+/// assert_eq!(err.valid_prefix(), r"C:\Users\caf");
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Utf8PathError {
+    pub(crate) bytes: Vec<u8>,
+    pub(crate) error: Utf8Error,
+}
+
+impl Utf8PathError {
+    /// Returns the index in the path up to which valid UTF-8 was verified, which is also the
+    /// byte offset of the first invalid byte.
+    ///
+    /// See [`Utf8Error::valid_up_to`] for more details.
+    pub fn valid_up_to(&self) -> usize {
+        self.error.valid_up_to()
+    }
+
+    /// Returns the length of the invalid byte sequence, or [`None`] if the path ended with an
+    /// incomplete sequence.
+    ///
+    /// See [`Utf8Error::error_len`] for more details.
+    pub fn error_len(&self) -> Option<usize> {
+        self.error.error_len()
+    }
+
+    /// Returns the portion of the path that is valid UTF-8, up to the first invalid byte.
+    pub fn valid_prefix(&self) -> &str {
+        // SAFETY: the underlying error guarantees the bytes are valid up to this point
+        unsafe { core::str::from_utf8_unchecked(&self.bytes[..self.valid_up_to()]) }
+    }
+
+    /// Returns the invalid byte sequence, which runs to the end of the path if the path ended
+    /// with an incomplete sequence.
+    pub fn invalid_bytes(&self) -> &[u8] {
+        let start = self.valid_up_to();
+        match self.error_len() {
+            Some(len) => &self.bytes[start..start + len],
+            None => &self.bytes[start..],
+        }
+    }
+
+    /// Returns the bytes of the path that failed to convert.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    /// Returns the bytes of the path that failed to convert, consuming the error.
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.bytes
+    }
+
+    /// Returns the underlying [`Utf8Error`].
+    pub fn utf8_error(&self) -> Utf8Error {
+        self.error
+    }
+}
+
+impl fmt::Display for Utf8PathError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "path is not valid utf-8 after {:?}: {}",
+            self.valid_prefix(),
+            self.error
+        )
+    }
+}
+
+impl From<FromUtf8Error> for Utf8PathError {
+    fn from(x: FromUtf8Error) -> Self {
+        Self {
+            error: x.utf8_error(),
+            bytes: x.into_bytes(),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Utf8PathError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+/// An error returned when decoding a path from its wire format fails.
+///
+/// This `enum` is created by the [`from_wire_bytes`] and [`read_wire_bytes`] methods on
+/// [`TypedPathBuf`] as well as [`PathListCodec::decode`]. See their documentation for more.
+///
+/// [`PathListCodec::decode`]: crate::PathListCodec::decode
+/// [`TypedPathBuf`]: crate::TypedPathBuf
+/// [`from_wire_bytes`]: crate::TypedPathBuf::from_wire_bytes
+/// [`read_wire_bytes`]: crate::TypedPathBuf::read_wire_bytes
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum WireFormatError {
+    /// When the input ends before the path has been fully decoded.
+    UnexpectedEnd,
+
+    /// When the input was written using a version of the format that is not supported.
+    UnsupportedVersion(u8),
+
+    /// When the tag describing the encoding of the path is not recognized.
+    UnknownEncoding(u8),
+
+    /// When the length of the path does not fit within a `usize`.
+    LengthOverflow,
+
+    /// When bytes remain after the path has been decoded.
+    TrailingBytes,
+
+    /// When a path within a list claims to share more components with the previous path than
+    /// the previous path has.
+    InvalidSharedPrefix,
+}
+
+impl fmt::Display for WireFormatError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnexpectedEnd => write!(f, "unexpected end of path wire bytes"),
+            Self::UnsupportedVersion(x) => write!(f, "unsupported path wire format version {x}"),
+            Self::UnknownEncoding(x) => write!(f, "unknown path encoding tag {x}"),
+            Self::LengthOverflow => write!(f, "path length overflows usize"),
+            Self::TrailingBytes => write!(f, "unexpected bytes after path"),
+            Self::InvalidSharedPrefix => write!(f, "path shares more components than available"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for WireFormatError {}
+
+/// An error returned when a path violates the restrictions of a [`FilesystemProfile`].
+///
+/// [`FilesystemProfile`]: crate::FilesystemProfile
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ProfileError {
+    /// When a normal component contains a byte outside of the allowed character set.
+    DisallowedByte(u8),
+
+    /// When a normal component contains an ascii control character, such as `\n`, and the
+    /// profile rejects them.
+    ControlByte(u8),
+
+    /// When a normal component is not valid UTF-8 and the profile requires UTF-8.
+    InvalidUtf8,
+
+    /// When a normal component starts with `-` and leading hyphens are not allowed.
+    LeadingHyphen,
+
+    /// When a normal component is one of the names reserved by the profile, such as `CON` on
+    /// Windows.
+    ReservedName,
+
+    /// When a normal component contains a byte that the encoding of the path never allows within
+    /// a name, such as `\0`.
+    InvalidByte(u8),
+
+    /// When a normal component is longer than the maximum allowed length in bytes.
+    ComponentTooLong { len: usize, max: usize },
+
+    /// When the path is longer than the maximum allowed length in bytes.
+    PathTooLong { len: usize, max: usize },
+}
+
+impl fmt::Display for ProfileError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::DisallowedByte(b) => {
+                write!(f, "path contains disallowed byte {:?}", char::from(*b))
+            }
+            Self::ControlByte(b) => {
+                write!(f, "path contains control byte {:?}", char::from(*b))
+            }
+            Self::InvalidUtf8 => write!(f, "path contains filename that is not valid UTF-8"),
+            Self::LeadingHyphen => write!(f, "path contains filename starting with '-'"),
+            Self::ReservedName => write!(f, "path contains reserved filename"),
+            Self::InvalidByte(b) => {
+                write!(f, "path contains invalid byte {:?}", char::from(*b))
+            }
+            Self::ComponentTooLong { len, max } => {
+                write!(
+                    f,
+                    "path contains filename of {} bytes, exceeding {}",
+                    len, max
+                )
+            }
+            Self::PathTooLong { len, max } => {
+                write!(f, "path is {} bytes, exceeding {}", len, max)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ProfileError {}
+
+/// An error reported for a single component of a path by [`ValidatedComponents`] and
+/// [`Utf8ValidatedComponents`], identifying where within the path the component sits.
+///
+/// [`ValidatedComponents`]: crate::ValidatedComponents
+/// [`Utf8ValidatedComponents`]: crate::Utf8ValidatedComponents
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ComponentError {
+    pub(crate) span: Range<usize>,
+    pub(crate) kind: ProfileError,
+}
+
+impl ComponentError {
+    /// Returns the range of bytes within the path occupied by the offending component.
+    pub fn span(&self) -> Range<usize> {
+        self.span.clone()
+    }
+
+    /// Returns the problem found with the component.
+    pub fn kind(&self) -> &ProfileError {
+        &self.kind
+    }
+}
+
+impl fmt::Display for ComponentError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at {}..{}", self.kind, self.span.start, self.span.end)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ComponentError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.kind)
+    }
+}
+
+/// An error returned when the drive of a Windows path cannot be changed.
+///
+/// This `enum` is created by methods like [`WindowsPathBuf::set_drive`].
+///
+/// [`WindowsPathBuf::set_drive`]: crate::WindowsPathBuf::set_drive
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum DriveError {
+    /// When the drive is not an ascii letter.
+    InvalidLetter(char),
+
+    /// When the path has a prefix other than a disk or verbatim disk, such as a UNC share.
+    UnsupportedPrefix,
+}
+
+impl fmt::Display for DriveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidLetter(c) => write!(f, "drive {:?} is not an ascii letter", c),
+            Self::UnsupportedPrefix => write!(f, "path has a prefix without a drive"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for DriveError {}
+
+/// An error returned when the server or share of a Windows UNC path cannot be changed.
+///
+/// This `enum` is created by methods like [`WindowsPath::with_unc_host`].
+///
+/// [`WindowsPath::with_unc_host`]: crate::WindowsPath::with_unc_host
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum UncError {
+    /// When the server name is empty or contains a separator.
+    InvalidHost,
+
+    /// When the share name is empty or contains a separator.
+    InvalidShare,
+
+    /// When the path does not have a UNC or verbatim UNC prefix.
+    UnsupportedPrefix,
+}
+
+impl fmt::Display for UncError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidHost => write!(f, "server name is empty or contains a separator"),
+            Self::InvalidShare => write!(f, "share name is empty or contains a separator"),
+            Self::UnsupportedPrefix => write!(f, "path does not have a UNC prefix"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for UncError {}
+
+/// An error returned when a Windows path would not fit within a length budget.
+///
+/// This `struct` is created by methods like [`WindowsPath::join_within_limit`].
+///
+/// [`WindowsPath::join_within_limit`]: crate::WindowsPath::join_within_limit
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct PathTooLongError {
+    pub(crate) len: usize,
+    pub(crate) max: usize,
+}
+
+impl PathTooLongError {
+    /// Returns the length of the path in UTF-16 code units, excluding the terminating NUL.
+    pub fn utf16_len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns the length budget that the path exceeded.
+    pub fn max(&self) -> usize {
+        self.max
+    }
+}
+
+impl fmt::Display for PathTooLongError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "path is {} UTF-16 units, exceeding {}",
+            self.len, self.max
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for PathTooLongError {}
+
+/// An error returned when a Windows prefix cannot be built from its parts.
+///
+/// This `enum` is created by methods like [`WindowsPrefixBuilder::build`].
+///
+/// [`WindowsPrefixBuilder::build`]: crate::WindowsPrefixBuilder::build
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum WindowsPrefixError {
+    /// When the drive is not an ascii letter.
+    InvalidDrive(char),
+
+    /// When the server of a UNC prefix is empty.
+    EmptyServer,
+
+    /// When the share of a UNC prefix is empty.
+    EmptyShare,
+
+    /// When the name of a device namespace or verbatim prefix is empty.
+    EmptyName,
+
+    /// When a part contains a separator or another byte that is not allowed within it.
+    InvalidByte(u8),
+
+    /// When the parts would be read back as a different prefix, such as `\\?\UNC`.
+    Ambiguous,
+}
+
+impl fmt::Display for WindowsPrefixError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidDrive(c) => write!(f, "drive {:?} is not an ascii letter", c),
+            Self::EmptyServer => write!(f, "server name is empty"),
+            Self::EmptyShare => write!(f, "share name is empty"),
+            Self::EmptyName => write!(f, "prefix name is empty"),
+            Self::InvalidByte(b) => write!(f, "byte {:?} is not allowed in a prefix", *b as char),
+            Self::Ambiguous => write!(f, "parts would be read as a different prefix"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for WindowsPrefixError {}
+
+/// An error returned when an `smb://` URL cannot be converted into a Windows UNC path.
+///
+/// This `enum` is created by methods like [`WindowsPathBuf::from_smb_url`].
+///
+/// [`WindowsPathBuf::from_smb_url`]: crate::WindowsPathBuf::from_smb_url
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum SmbUrlError {
+    /// When the URL does not start with `smb://`.
+    InvalidScheme,
+
+    /// When the URL does not name a server.
+    MissingHost,
+
+    /// When the URL has a port, query, or fragment, which cannot be part of a UNC path.
+    Unsupported,
+
+    /// When the server or a segment decodes to bytes that are not allowed in a Windows filename.
+    InvalidSegment,
+
+    /// When the decoded path is not valid UTF-8.
+    InvalidUtf8,
+}
+
+impl fmt::Display for SmbUrlError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidScheme => write!(f, "url does not use the smb scheme"),
+            Self::MissingHost => write!(f, "url does not name a server"),
+            Self::Unsupported => write!(f, "url has a port, query, or fragment"),
+            Self::InvalidSegment => write!(f, "url contains an invalid server or segment"),
+            Self::InvalidUtf8 => write!(f, "url does not decode to valid UTF-8"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for SmbUrlError {}
+
+/// An error returned when a z/OS dataset name is not valid.
+///
+/// This `enum` is created by methods like [`DatasetName::new`].
+///
+/// [`DatasetName::new`]: crate::DatasetName::new
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum DatasetNameError {
+    /// When the name, not counting the member, is longer than 44 characters.
+    TooLong(usize),
+
+    /// When the qualifier at the index is empty, longer than 8 characters, or contains characters
+    /// that are not allowed.
+    InvalidQualifier(usize),
+
+    /// When the member is empty, longer than 8 characters, contains characters that are not
+    /// allowed, or is not enclosed in a single pair of parentheses at the end of the name.
+    InvalidMember,
+
+    /// When a USS path does not refer to a dataset using the `//'DATASET.NAME'` form.
+    NotDatasetPath,
+}
+
+impl fmt::Display for DatasetNameError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::TooLong(len) => write!(f, "dataset name has {} characters, max is 44", len),
+            Self::InvalidQualifier(i) => write!(f, "qualifier {} of dataset name is invalid", i),
+            Self::InvalidMember => write!(f, "dataset member is invalid"),
+            Self::NotDatasetPath => write!(f, "path does not refer to a dataset"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for DatasetNameError {}
+
+/// An error returned when a [`Pattern`] cannot be parsed, reporting the byte offset within the
+/// pattern where the problem starts.
+///
+/// [`Pattern`]: crate::Pattern
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum PatternError {
+    /// When a character class opened with `[` is never closed.
+    UnclosedClass(usize),
+
+    /// When a range within a character class ends before it starts, such as `[z-a]`.
+    InvalidRange(usize),
+}
+
+impl fmt::Display for PatternError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnclosedClass(pos) => write!(f, "pattern has unclosed class at {}", pos),
+            Self::InvalidRange(pos) => write!(f, "pattern has invalid range at {}", pos),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for PatternError {}
+
+/// An error returned when a [`PathTemplate`] cannot be parsed or rendered.
+///
+/// Errors from parsing report the byte offset within the template where the problem starts, while
+/// errors from rendering report the name of the offending variable.
+///
+/// [`PathTemplate`]: crate::PathTemplate
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum TemplateError {
+    /// When a placeholder opened with `{` is never closed.
+    UnclosedPlaceholder(usize),
+
+    /// When a placeholder has no name, such as `{}`.
+    EmptyName(usize),
+
+    /// When a `}` does not close a placeholder and is not escaped as `}}`.
+    UnmatchedBrace(usize),
+
+    /// When no value is provided for a variable.
+    MissingVariable(String),
+
+    /// When the value of a variable is empty or contains a separator.
+    InvalidValue(String),
+}
+
+impl fmt::Display for TemplateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnclosedPlaceholder(pos) => {
+                write!(f, "template has unclosed placeholder at {}", pos)
+            }
+            Self::EmptyName(pos) => write!(f, "template has empty placeholder at {}", pos),
+            Self::UnmatchedBrace(pos) => write!(f, "template has unmatched brace at {}", pos),
+            Self::MissingVariable(name) => write!(f, "template variable {:?} is missing", name),
+            Self::InvalidValue(name) => write!(f, "template variable {:?} is invalid", name),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for TemplateError {}
+
 /// An error returned when a path violates checked criteria.
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum CheckedPathError {
     /// When a normal component contains invalid characters for the current encoding.
     InvalidFilename,
@@ -49,3 +737,254 @@ impl fmt::Display for CheckedPathError {
 
 #[cfg(feature = "std")]
 impl std::error::Error for CheckedPathError {}
+
+/// An error returned when a path is not of the kind required by a wrapper like [`AbsPath`] or
+/// [`FilePath`].
+///
+/// [`AbsPath`]: crate::AbsPath
+/// [`FilePath`]: crate::FilePath
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum PathKindError {
+    /// When the path is not absolute.
+    NotAbsolute,
+
+    /// When the path has a prefix or a root, and so is not strictly relative.
+    NotRelative,
+
+    /// When the path ends with a file name, and so does not denote a directory.
+    NotDirectory,
+
+    /// When the path does not end with a file name, and so does not denote a file.
+    NotFile,
+}
+
+impl fmt::Display for PathKindError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NotAbsolute => write!(f, "path is not absolute"),
+            Self::NotRelative => write!(f, "path has a prefix or root"),
+            Self::NotDirectory => write!(f, "path ends with a file name"),
+            Self::NotFile => write!(f, "path does not end with a file name"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for PathKindError {}
+
+/// An error returned when a path does not pass the strict validation of its encoding.
+///
+/// This `enum` is created by methods like [`Path::try_new_strict`].
+///
+/// [`Path::try_new_strict`]: crate::Path::try_new_strict
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum StrictPathError {
+    /// When a byte is not allowed where it appears, such as a NUL anywhere in the path or a `:`
+    /// within a Windows filename.
+    InvalidByte {
+        /// Position of the byte within the path
+        index: usize,
+
+        /// The byte that is not allowed
+        byte: u8,
+    },
+
+    /// When a prefix is missing a part that its kind requires, such as the share of `\\server`.
+    MalformedPrefix,
+}
+
+impl fmt::Display for StrictPathError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidByte { index, byte } => {
+                write!(f, "byte {:?} at {} is not allowed", *byte as char, index)
+            }
+            Self::MalformedPrefix => write!(f, "path has a malformed prefix"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for StrictPathError {}
+
+/// An error returned when a path cannot be built from a sequence of segments, identifying the
+/// segment that was rejected.
+///
+/// This `struct` is created by methods like [`PathBuf::from_segments`],
+/// [`PathBuf::extend_checked`], and [`PathBuilder::build`].
+///
+/// [`PathBuf::from_segments`]: crate::PathBuf::from_segments
+/// [`PathBuf::extend_checked`]: crate::PathBuf::extend_checked
+/// [`PathBuilder::build`]: crate::PathBuilder::build
+///
+/// # Examples
+///
+/// ```
+/// use std::error::Error;
+/// use typed_path::{PathBuf, SegmentErrorKind, UnixEncoding};
+///
+/// let err = PathBuf::<UnixEncoding>::from_segments(["usr", "", "bin"]).unwrap_err();
+/// assert_eq!(err.to_string(), "segment 1 is empty");
+///
+/// // The reason is also available as the source of the error
+/// assert_eq!(err.source().unwrap().to_string(), SegmentErrorKind::Empty.to_string());
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct SegmentError {
+    pub(crate) index: usize,
+    pub(crate) kind: SegmentErrorKind,
+}
+
+impl SegmentError {
+    /// Returns the position of the rejected segment within the sequence, starting from zero.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Returns the reason the segment was rejected.
+    pub fn kind(&self) -> SegmentErrorKind {
+        self.kind
+    }
+}
+
+impl fmt::Display for SegmentError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "segment {} {}", self.index, self.kind)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for SegmentError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.kind)
+    }
+}
+
+/// The reason a segment was rejected, reported by [`SegmentError::kind`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum SegmentErrorKind {
+    /// When the segment is empty.
+    Empty,
+
+    /// When the segment contains a separator of the encoding, and so would add more than one
+    /// component.
+    Separator,
+
+    /// When the segment is `.` or `..`.
+    Traversal,
+
+    /// When the segment would be read as a prefix, such as `C:` on Windows.
+    Prefix,
+
+    /// When the segment contains bytes the encoding does not allow in filenames.
+    InvalidFilename,
+
+    /// When the segment was rejected by a validator of a [`PathBuilder`].
+    ///
+    /// [`PathBuilder`]: crate::PathBuilder
+    Rejected,
+}
+
+impl fmt::Display for SegmentErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => write!(f, "is empty"),
+            Self::Separator => write!(f, "contains a separator"),
+            Self::Traversal => write!(f, "is a traversal token"),
+            Self::Prefix => write!(f, "would be read as a prefix"),
+            Self::InvalidFilename => write!(f, "contains invalid filename bytes"),
+            Self::Rejected => write!(f, "was rejected by a validator"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for SegmentErrorKind {}
+
+/// An error returned by [`PathExt::with_name`] when the final component cannot be replaced.
+///
+/// [`PathExt::with_name`]: crate::PathExt::with_name
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum WithNameError {
+    /// When the path does not end in a normal or `..` component, such as `/` or `.`.
+    EmptyName,
+
+    /// When the new name would not be read back as a single normal or `..` component, such as
+    /// when it is empty or contains a separator.
+    InvalidName,
+}
+
+impl fmt::Display for WithNameError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::EmptyName => write!(f, "path has an empty name"),
+            Self::InvalidName => write!(f, "name is invalid"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for WithNameError {}
+
+/// An error returned when a [`RenamePlanner`] cannot produce a plan.
+///
+/// Paths are reported as strings so that the error does not depend on the encoding of the paths
+/// being renamed.
+///
+/// [`RenamePlanner`]: crate::RenamePlanner
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum RenameError {
+    /// When a path fits the source template in more than one way, so its variables are unclear.
+    Ambiguous(String),
+
+    /// When two or more paths would be renamed to the same target.
+    Collision {
+        target: String,
+        sources: Vec<String>,
+    },
+
+    /// When a path would be renamed onto another path of the set that is not renamed itself.
+    Overwrite { from: String, to: String },
+
+    /// When renames form a cycle, such as a swap, that cannot be applied one after another
+    /// without moving a path out of the way first.
+    Cycle(Vec<String>),
+
+    /// When the target template cannot be rendered for a path.
+    Template { path: String, error: TemplateError },
+}
+
+impl fmt::Display for RenameError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Ambiguous(path) => write!(f, "path {:?} matches the template ambiguously", path),
+            Self::Collision { target, sources } => {
+                write!(
+                    f,
+                    "paths {:?} would all be renamed to {:?}",
+                    sources, target
+                )
+            }
+            Self::Overwrite { from, to } => {
+                write!(f, "renaming {:?} would overwrite {:?}", from, to)
+            }
+            Self::Cycle(paths) => write!(f, "renames of {:?} form a cycle", paths),
+            Self::Template { path, error } => write!(f, "cannot rename {:?}: {}", path, error),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for RenameError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Template { error, .. } => Some(error),
+            _ => None,
+        }
+    }
+}
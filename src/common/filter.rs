@@ -0,0 +1,216 @@
+use crate::common::sort_key;
+use crate::no_std_compat::*;
+use crate::PathLike;
+
+/// Number of bits reserved for each expected root, giving roughly 1% false positives
+const BITS_PER_ROOT: usize = 10;
+
+/// Number of bits set for each root
+const HASHES: u64 = 7;
+
+/// Compact probabilistic filter over a list of watched roots that quickly rules out paths that
+/// are definitely not within any of them, such as to drop most file events before an exact
+/// check.
+///
+/// The filter is a Bloom filter over the [sort keys] of the roots. Checking a path hashes each of
+/// its ancestors, skipping those at depths where no root was inserted, so its cost grows with the
+/// length of the path rather than the number of roots. A `false` answer is always correct, while a
+/// `true` answer may be wrong for about 1% of unrelated paths once the filter holds as many roots
+/// as it was sized for.
+///
+/// Components are compared exactly as with [sort keys], so Windows paths that differ only in
+/// case are considered different.
+///
+/// [sort keys]: crate::Path::sort_key
+///
+/// # Examples
+///
+/// ```
+/// use typed_path::{PathFilter, UnixPath, Utf8UnixPath};
+///
+/// let mut filter = PathFilter::new(2);
+/// filter.insert(UnixPath::new("/home/me/project"));
+/// filter.insert(Utf8UnixPath::new("/var/log"));
+///
+/// assert!(filter.may_contain(UnixPath::new("/home/me/project/src/main.rs")));
+/// assert!(filter.may_contain(Utf8UnixPath::new("/var/log")));
+///
+/// // Paths outside of every root are almost always ruled out, even when they share a prefix
+/// assert!(!filter.may_contain(UnixPath::new("/home/me/project-old/main.rs")));
+/// assert!(!filter.may_contain(UnixPath::new("/var")));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct PathFilter {
+    bits: Vec<u64>,
+
+    /// Bit `n` is set if a root has `n` components, with the last bit standing for all depths
+    /// past it
+    depths: u64,
+
+    len: usize,
+}
+
+impl PathFilter {
+    /// Creates an empty filter sized to hold `expected_roots` roots with roughly 1% false
+    /// positives. Inserting more roots than expected still works, at the cost of more false
+    /// positives.
+    pub fn new(expected_roots: usize) -> Self {
+        Self {
+            bits: vec![0; expected_roots.saturating_mul(BITS_PER_ROOT) / 64 + 1],
+            depths: 0,
+            len: 0,
+        }
+    }
+
+    /// Adds `root` to the watched roots, so that it and all of its descendants may be contained.
+    pub fn insert<P: PathLike + ?Sized>(&mut self, root: &P) {
+        let key = root.sort_key();
+        let mut depth = 0;
+        let mut hash = Fnv::new();
+        sort_key::for_each_ancestor(&key, |d, end| {
+            depth = d;
+            hash.write(&key[hash.len..end]);
+        });
+
+        self.depths |= depth_bit(depth);
+        let m = self.bits.len() as u64 * 64;
+        for bit in hash.bits(m) {
+            self.bits[(bit / 64) as usize] |= 1 << (bit % 64);
+        }
+        self.len += 1;
+    }
+
+    /// Returns `false` if `path` is definitely not within any of the watched roots, or `true` if
+    /// it is or, rarely, if it only appears to be.
+    pub fn may_contain<P: PathLike + ?Sized>(&self, path: &P) -> bool {
+        if self.depths == 0 {
+            return false;
+        }
+
+        let key = path.sort_key();
+        let m = self.bits.len() as u64 * 64;
+        let mut hash = Fnv::new();
+        let mut found = false;
+        sort_key::for_each_ancestor(&key, |depth, end| {
+            if found || self.depths & depth_bit(depth) == 0 {
+                return;
+            }
+
+            hash.write(&key[hash.len..end]);
+            found = hash
+                .bits(m)
+                .all(|bit| self.bits[(bit / 64) as usize] & (1 << (bit % 64)) != 0);
+        });
+        found
+    }
+
+    /// Returns the number of roots inserted into the filter, counting repeats.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if no roots have been inserted into the filter.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Removes every root from the filter, keeping its size.
+    pub fn clear(&mut self) {
+        self.bits.iter_mut().for_each(|word| *word = 0);
+        self.depths = 0;
+        self.len = 0;
+    }
+}
+
+impl<'a, P: PathLike + ?Sized + 'a> Extend<&'a P> for PathFilter {
+    fn extend<I: IntoIterator<Item = &'a P>>(&mut self, roots: I) {
+        for root in roots {
+            self.insert(root);
+        }
+    }
+}
+
+fn depth_bit(depth: usize) -> u64 {
+    1 << core::cmp::min(depth, 63)
+}
+
+/// Running 64-bit FNV-1a hash over a prefix of a sort key
+struct Fnv {
+    state: u64,
+
+    /// Number of bytes hashed so far
+    len: usize,
+}
+
+impl Fnv {
+    fn new() -> Self {
+        Self {
+            state: 0xcbf2_9ce4_8422_2325,
+            len: 0,
+        }
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.state ^= byte as u64;
+            self.state = self.state.wrapping_mul(0x0000_0100_0000_01b3);
+        }
+        self.len += bytes.len();
+    }
+
+    /// Returns the positions of the bits standing for the hash within a filter of `m` bits
+    fn bits(&self, m: u64) -> impl Iterator<Item = u64> {
+        // Spread the hash with the finalizer of splitmix64 before double hashing
+        let mut x = self.state;
+        x = (x ^ (x >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        x = (x ^ (x >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        x ^= x >> 31;
+
+        let (h1, h2) = (x & 0xffff_ffff, (x >> 32) | 1);
+        (0..HASHES).map(move |i| h1.wrapping_add(i.wrapping_mul(h2)) % m)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::format;
+
+    use super::*;
+    use crate::{UnixPath, UnixPathBuf, WindowsPath};
+
+    #[test]
+    fn may_contain_should_never_miss_descendants_of_roots() {
+        let roots: Vec<_> = (0..1000)
+            .map(|i| UnixPathBuf::from(format!("/srv/{}/data", i)))
+            .collect();
+        let mut filter = PathFilter::new(roots.len());
+        filter.extend(roots.iter().map(|root| root.as_path()));
+        assert_eq!(filter.len(), 1000);
+
+        for root in &roots {
+            assert!(filter.may_contain(root.as_path()));
+            assert!(filter.may_contain(root.join("a/b.txt").as_path()));
+        }
+
+        let false_positives = (0..1000)
+            .map(|i| UnixPathBuf::from(format!("/srv/{}/data-old/a", i)))
+            .filter(|path| filter.may_contain(path.as_path()))
+            .count();
+        assert!(false_positives < 50, "{} false positives", false_positives);
+    }
+
+    #[test]
+    fn may_contain_should_handle_empty_and_shallow_roots() {
+        let mut filter = PathFilter::new(0);
+        assert!(!filter.may_contain(UnixPath::new("")));
+
+        filter.insert(WindowsPath::new(r"C:\"));
+        assert!(filter.may_contain(WindowsPath::new(r"C:\Windows\System32")));
+        assert!(!filter.may_contain(WindowsPath::new(r"D:\Windows")));
+
+        filter.clear();
+        assert!(filter.is_empty());
+        filter.insert(UnixPath::new(""));
+        assert!(filter.may_contain(UnixPath::new("anything/at/all")));
+    }
+}
@@ -1,20 +1,46 @@
+#[macro_use]
+mod absolute;
+mod batch;
+mod builder;
 mod components;
+mod cow;
+mod directory;
+mod hashed;
 mod iter;
+mod list_codec;
 mod path;
 mod pathbuf;
+mod preparsed;
+mod segmented;
+mod segments;
+mod validated;
+mod writer;
 
 #[macro_use]
 pub(crate) mod parser;
 
 use core::hash::Hasher;
 
+pub use absolute::*;
+pub use batch::*;
+pub use builder::*;
 pub use components::*;
+pub use cow::*;
+pub use directory::*;
+pub use hashed::*;
 pub use iter::*;
+pub use list_codec::*;
 pub use parser::ParseError;
 pub use path::*;
 pub use pathbuf::*;
+pub use preparsed::*;
+pub use segmented::*;
+pub use segments::*;
+pub use validated::*;
+pub use writer::*;
 
 use crate::common::errors::CheckedPathError;
+use crate::common::EncodingInfo;
 use crate::no_std_compat::*;
 use crate::private;
 
@@ -26,6 +52,36 @@ pub trait Encoding<'a>: private::Sealed {
     /// Static label representing encoding type
     fn label() -> &'static str;
 
+    /// Name of the encoding, matching [`Encoding::label`]
+    const NAME: &'static str;
+
+    /// Separator placed between components when building a path
+    const SEPARATOR: char;
+
+    /// Additional separators recognized when parsing a path
+    const ALT_SEPARATORS: &'static [char];
+
+    /// Whether components are compared case-sensitively
+    const CASE_SENSITIVE: bool;
+
+    /// Bytes that are never allowed within a file or directory name, including separators
+    const DISALLOWED_FILENAME_BYTES: &'static [u8];
+
+    /// Returns true if `byte` is either the primary or an alternate separator of the encoding
+    fn is_separator(byte: u8) -> bool {
+        Self::info().is_separator(byte)
+    }
+
+    /// Returns the [`EncodingInfo`] describing the conventions of the encoding
+    fn info() -> EncodingInfo {
+        EncodingInfo {
+            name: Self::NAME,
+            separator: Self::SEPARATOR,
+            alt_separators: Self::ALT_SEPARATORS,
+            case_sensitive: Self::CASE_SENSITIVE,
+        }
+    }
+
     /// Produces an iterator of [`Component`]s over the given the byte slice (`path`)
     fn components(path: &'a [u8]) -> Self::Components;
 
@@ -42,4 +98,10 @@ pub trait Encoding<'a>: private::Sealed {
     /// 3. `path` cannot contain invalid filename bytes.
     /// 4. `path` cannot contain parent components such that the current path would be escaped.
     fn push_checked(current_path: &mut Vec<u8>, path: &[u8]) -> Result<(), CheckedPathError>;
+
+    /// Returns true if `prefix`, the bytes of a prefix component, has every part that its kind
+    /// requires. Encodings without malformed prefixes accept any prefix.
+    fn is_valid_prefix(_prefix: &[u8]) -> bool {
+        true
+    }
 }
@@ -0,0 +1,651 @@
+use alloc::borrow::ToOwned;
+use core::borrow::Borrow;
+use core::hash::{Hash, Hasher};
+use core::ops::Deref;
+use core::{cmp, fmt};
+
+use crate::common::PathKindError;
+use crate::{Component, ComponentKind, DifferentPrefixError, Encoding, Path, PathBuf};
+
+/// A [`Path`] that is known to be absolute (akin to [`Path::is_absolute`]).
+///
+/// Joining a [`RelPath`] onto an `AbsPath` always produces another [`AbsPathBuf`], and
+/// [`AbsPath::relative_to`] always produces a [`RelPathBuf`], so neither needs to be checked
+/// again. The full [`Path`] API is available through [`Deref`].
+///
+/// # Examples
+///
+/// ```
+/// use typed_path::{AbsPath, PathKindError, RelPath, UnixEncoding};
+///
+/// // NOTE: A path cannot be created on its own without a defined encoding
+/// let base = AbsPath::<UnixEncoding>::new("/srv/app").unwrap();
+/// let path = base.join(RelPath::new("logs/today.txt").unwrap());
+/// assert_eq!(path.as_bytes(), b"/srv/app/logs/today.txt");
+///
+/// assert_eq!(
+///     AbsPath::<UnixEncoding>::new("srv/app"),
+///     Err(PathKindError::NotAbsolute),
+/// );
+/// ```
+#[repr(transparent)]
+pub struct AbsPath<T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    inner: Path<T>,
+}
+
+/// A [`Path`] that is known to be relative, meaning that it has neither a prefix nor a root.
+///
+/// This is stricter than [`Path::is_relative`], which also holds for Windows paths like `C:foo`
+/// and `\foo`. Those would replace part of any path they are joined onto, whereas a `RelPath`
+/// only ever extends it.
+///
+/// # Examples
+///
+/// ```
+/// use typed_path::{PathKindError, RelPath, WindowsEncoding};
+///
+/// // NOTE: A path cannot be created on its own without a defined encoding
+/// let path = RelPath::<WindowsEncoding>::new(r"..\logs").unwrap();
+/// assert_eq!(path.join(RelPath::new("today.txt").unwrap()).as_bytes(), br"..\logs\today.txt");
+///
+/// assert_eq!(
+///     RelPath::<WindowsEncoding>::new(r"C:logs"),
+///     Err(PathKindError::NotRelative),
+/// );
+/// assert_eq!(
+///     RelPath::<WindowsEncoding>::new(r"\logs"),
+///     Err(PathKindError::NotRelative),
+/// );
+/// ```
+#[repr(transparent)]
+pub struct RelPath<T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    inner: Path<T>,
+}
+
+/// An owned [`AbsPath`] (akin to [`PathBuf`]).
+///
+/// # Examples
+///
+/// ```
+/// use typed_path::{AbsPathBuf, RelPath, WindowsEncoding};
+///
+/// // NOTE: A path cannot be created on its own without a defined encoding
+/// let mut path = AbsPathBuf::<WindowsEncoding>::new(r"C:\srv").unwrap();
+/// path.push(RelPath::new("app").unwrap());
+/// assert_eq!(path.as_bytes(), br"C:\srv\app");
+///
+/// assert!(path.pop());
+/// assert!(path.pop());
+/// assert!(!path.pop());
+/// assert_eq!(path.as_bytes(), br"C:\");
+/// ```
+pub struct AbsPathBuf<T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    inner: PathBuf<T>,
+}
+
+/// An owned [`RelPath`] (akin to [`PathBuf`]).
+///
+/// # Examples
+///
+/// ```
+/// use typed_path::{RelPath, RelPathBuf, UnixEncoding};
+///
+/// // NOTE: A path cannot be created on its own without a defined encoding
+/// let mut path = RelPathBuf::<UnixEncoding>::new("logs").unwrap();
+/// path.push(RelPath::new("today.txt").unwrap());
+/// assert_eq!(path.as_bytes(), b"logs/today.txt");
+/// ```
+pub struct RelPathBuf<T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    inner: PathBuf<T>,
+}
+
+/// Returns true if `path` has neither a prefix nor a root, so pushing it onto another path only
+/// ever extends that path
+fn is_strictly_relative<T>(path: &Path<T>) -> bool
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    !matches!(
+        path.components().next().map(|c| c.kind()),
+        Some(ComponentKind::Prefix | ComponentKind::RootDir)
+    )
+}
+
+impl<T> AbsPath<T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    /// Wraps `s` as an `AbsPath` if it is absolute.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PathKindError::NotAbsolute`] if [`Path::is_absolute`] does not hold.
+    ///
+    /// [`PathKindError::NotAbsolute`]: crate::PathKindError::NotAbsolute
+    pub fn new<S: AsRef<[u8]> + ?Sized>(s: &S) -> Result<&Self, PathKindError> {
+        let path = Path::new(s);
+        if path.is_absolute() {
+            Ok(Self::new_unchecked(path))
+        } else {
+            Err(PathKindError::NotAbsolute)
+        }
+    }
+
+    fn new_unchecked(path: &Path<T>) -> &Self {
+        unsafe { &*(path as *const Path<T> as *const Self) }
+    }
+
+    /// Coerces to a [`Path`] slice.
+    #[inline]
+    pub fn as_path(&self) -> &Path<T> {
+        &self.inner
+    }
+
+    /// Converts to an owned [`AbsPathBuf`].
+    pub fn to_path_buf(&self) -> AbsPathBuf<T> {
+        AbsPathBuf {
+            inner: self.inner.to_path_buf(),
+        }
+    }
+
+    /// Returns the path without its final component, if it still names an absolute path.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{AbsPath, UnixEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// let path = AbsPath::<UnixEncoding>::new("/srv/app").unwrap();
+    /// assert_eq!(path.parent().unwrap().as_bytes(), b"/srv");
+    /// assert_eq!(AbsPath::<UnixEncoding>::new("/").unwrap().parent(), None);
+    /// ```
+    pub fn parent(&self) -> Option<&Self> {
+        self.inner
+            .parent()
+            .filter(|path| path.is_absolute())
+            .map(Self::new_unchecked)
+    }
+
+    /// Creates an owned [`AbsPathBuf`] with `path` adjoined to `self`.
+    pub fn join<P: AsRef<RelPath<T>>>(&self, path: P) -> AbsPathBuf<T> {
+        AbsPathBuf {
+            inner: self.inner.join(path.as_ref().as_path()),
+        }
+    }
+
+    /// Returns the [`RelPathBuf`] that leads from `base` to `self`.
+    ///
+    /// See [`Path::relative_to`] for more details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{AbsPath, WindowsEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// let path = AbsPath::<WindowsEncoding>::new(r"C:\Users\Public").unwrap();
+    /// let base = AbsPath::new(r"C:\Users\me").unwrap();
+    /// assert_eq!(path.relative_to(base).unwrap().as_bytes(), br"..\Public");
+    ///
+    /// assert!(path.relative_to(AbsPath::new(r"D:\Users").unwrap()).is_err());
+    /// ```
+    pub fn relative_to<P: AsRef<AbsPath<T>>>(
+        &self,
+        base: P,
+    ) -> Result<RelPathBuf<T>, DifferentPrefixError> {
+        // Two absolute paths with the same anchor differ only by `..` and normal components
+        self.inner
+            .relative_to(base.as_ref().as_path())
+            .map(|inner| RelPathBuf { inner })
+    }
+}
+
+impl<T> RelPath<T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    /// Wraps `s` as a `RelPath` if it has neither a prefix nor a root.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PathKindError::NotRelative`] if the path begins with a prefix or a root.
+    ///
+    /// [`PathKindError::NotRelative`]: crate::PathKindError::NotRelative
+    pub fn new<S: AsRef<[u8]> + ?Sized>(s: &S) -> Result<&Self, PathKindError> {
+        let path = Path::new(s);
+        if is_strictly_relative(path) {
+            Ok(Self::new_unchecked(path))
+        } else {
+            Err(PathKindError::NotRelative)
+        }
+    }
+
+    fn new_unchecked(path: &Path<T>) -> &Self {
+        unsafe { &*(path as *const Path<T> as *const Self) }
+    }
+
+    /// Coerces to a [`Path`] slice.
+    #[inline]
+    pub fn as_path(&self) -> &Path<T> {
+        &self.inner
+    }
+
+    /// Converts to an owned [`RelPathBuf`].
+    pub fn to_path_buf(&self) -> RelPathBuf<T> {
+        RelPathBuf {
+            inner: self.inner.to_path_buf(),
+        }
+    }
+
+    /// Returns the path without its final component, or [`None`] if it is empty.
+    pub fn parent(&self) -> Option<&Self> {
+        self.inner.parent().map(Self::new_unchecked)
+    }
+
+    /// Creates an owned [`RelPathBuf`] with `path` adjoined to `self`.
+    pub fn join<P: AsRef<RelPath<T>>>(&self, path: P) -> RelPathBuf<T> {
+        RelPathBuf {
+            inner: self.inner.join(path.as_ref().as_path()),
+        }
+    }
+}
+
+impl<T> AbsPathBuf<T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    /// Wraps `path` as an `AbsPathBuf` if it is absolute.
+    ///
+    /// See [`AbsPath::new`] for more details.
+    pub fn new<P: Into<PathBuf<T>>>(path: P) -> Result<Self, PathKindError> {
+        let inner = path.into();
+        AbsPath::<T>::new(inner.as_bytes())?;
+        Ok(Self { inner })
+    }
+
+    /// Coerces to an [`AbsPath`] slice.
+    #[inline]
+    pub fn as_abs_path(&self) -> &AbsPath<T> {
+        AbsPath::new_unchecked(&self.inner)
+    }
+
+    /// Extends `self` with `path`, which keeps `self` absolute.
+    pub fn push<P: AsRef<RelPath<T>>>(&mut self, path: P) {
+        self.inner.push(path.as_ref().as_path());
+    }
+
+    /// Truncates `self` to [`AbsPath::parent`], returning false and leaving `self` unchanged if
+    /// there is no such parent.
+    pub fn pop(&mut self) -> bool {
+        match self.as_abs_path().parent().map(|p| p.as_bytes().len()) {
+            Some(len) => {
+                self.inner.inner.truncate(len);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Consumes the `AbsPathBuf`, yielding its [`PathBuf`].
+    #[inline]
+    pub fn into_path_buf(self) -> PathBuf<T> {
+        self.inner
+    }
+}
+
+impl<T> RelPathBuf<T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    /// Wraps `path` as a `RelPathBuf` if it has neither a prefix nor a root.
+    ///
+    /// See [`RelPath::new`] for more details.
+    pub fn new<P: Into<PathBuf<T>>>(path: P) -> Result<Self, PathKindError> {
+        let inner = path.into();
+        RelPath::<T>::new(inner.as_bytes())?;
+        Ok(Self { inner })
+    }
+
+    /// Coerces to a [`RelPath`] slice.
+    #[inline]
+    pub fn as_rel_path(&self) -> &RelPath<T> {
+        RelPath::new_unchecked(&self.inner)
+    }
+
+    /// Extends `self` with `path`, which keeps `self` relative.
+    pub fn push<P: AsRef<RelPath<T>>>(&mut self, path: P) {
+        self.inner.push(path.as_ref().as_path());
+    }
+
+    /// Truncates `self` to [`RelPath::parent`], returning false if `self` is empty.
+    pub fn pop(&mut self) -> bool {
+        self.inner.pop()
+    }
+
+    /// Consumes the `RelPathBuf`, yielding its [`PathBuf`].
+    #[inline]
+    pub fn into_path_buf(self) -> PathBuf<T> {
+        self.inner
+    }
+}
+
+/// Implements the traits shared by a wrapper around [`Path`] and its owned counterpart
+macro_rules! impl_path_wrapper {
+    ($path:ident, $buf:ident, $as_path:ident) => {
+        impl<T> fmt::Debug for $path<T>
+        where
+            T: for<'enc> Encoding<'enc>,
+        {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.debug_tuple(stringify!($path))
+                    .field(&&self.inner)
+                    .finish()
+            }
+        }
+
+        impl<T> fmt::Debug for $buf<T>
+        where
+            T: for<'enc> Encoding<'enc>,
+        {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.debug_tuple(stringify!($buf)).field(&self.inner).finish()
+            }
+        }
+
+        impl<T> Clone for $buf<T>
+        where
+            T: for<'enc> Encoding<'enc>,
+        {
+            #[inline]
+            fn clone(&self) -> Self {
+                Self {
+                    inner: self.inner.clone(),
+                }
+            }
+        }
+
+        impl<T> Deref for $path<T>
+        where
+            T: for<'enc> Encoding<'enc>,
+        {
+            type Target = Path<T>;
+
+            #[inline]
+            fn deref(&self) -> &Self::Target {
+                &self.inner
+            }
+        }
+
+        impl<T> Deref for $buf<T>
+        where
+            T: for<'enc> Encoding<'enc>,
+        {
+            type Target = $path<T>;
+
+            #[inline]
+            fn deref(&self) -> &Self::Target {
+                self.$as_path()
+            }
+        }
+
+        impl<T> AsRef<$path<T>> for $path<T>
+        where
+            T: for<'enc> Encoding<'enc>,
+        {
+            #[inline]
+            fn as_ref(&self) -> &$path<T> {
+                self
+            }
+        }
+
+        impl<T> AsRef<$path<T>> for $buf<T>
+        where
+            T: for<'enc> Encoding<'enc>,
+        {
+            #[inline]
+            fn as_ref(&self) -> &$path<T> {
+                self.$as_path()
+            }
+        }
+
+        impl<T> AsRef<Path<T>> for $path<T>
+        where
+            T: for<'enc> Encoding<'enc>,
+        {
+            #[inline]
+            fn as_ref(&self) -> &Path<T> {
+                &self.inner
+            }
+        }
+
+        impl<T> AsRef<Path<T>> for $buf<T>
+        where
+            T: for<'enc> Encoding<'enc>,
+        {
+            #[inline]
+            fn as_ref(&self) -> &Path<T> {
+                &self.inner
+            }
+        }
+
+        impl<T> AsRef<[u8]> for $path<T>
+        where
+            T: for<'enc> Encoding<'enc>,
+        {
+            #[inline]
+            fn as_ref(&self) -> &[u8] {
+                self.inner.as_bytes()
+            }
+        }
+
+        impl<T> AsRef<[u8]> for $buf<T>
+        where
+            T: for<'enc> Encoding<'enc>,
+        {
+            #[inline]
+            fn as_ref(&self) -> &[u8] {
+                self.inner.as_bytes()
+            }
+        }
+
+        impl<T> Borrow<$path<T>> for $buf<T>
+        where
+            T: for<'enc> Encoding<'enc>,
+        {
+            #[inline]
+            fn borrow(&self) -> &$path<T> {
+                self.$as_path()
+            }
+        }
+
+        impl<T> ToOwned for $path<T>
+        where
+            T: for<'enc> Encoding<'enc>,
+        {
+            type Owned = $buf<T>;
+
+            #[inline]
+            fn to_owned(&self) -> Self::Owned {
+                self.to_path_buf()
+            }
+        }
+
+        impl<T> PartialEq for $path<T>
+        where
+            T: for<'enc> Encoding<'enc>,
+        {
+            #[inline]
+            fn eq(&self, other: &Self) -> bool {
+                self.inner == other.inner
+            }
+        }
+
+        impl<T> Eq for $path<T> where T: for<'enc> Encoding<'enc> {}
+
+        impl<T> PartialEq for $buf<T>
+        where
+            T: for<'enc> Encoding<'enc>,
+        {
+            #[inline]
+            fn eq(&self, other: &Self) -> bool {
+                self.inner == other.inner
+            }
+        }
+
+        impl<T> Eq for $buf<T> where T: for<'enc> Encoding<'enc> {}
+
+        impl<T> PartialOrd for $path<T>
+        where
+            T: for<'enc> Encoding<'enc>,
+        {
+            #[inline]
+            fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl<T> Ord for $path<T>
+        where
+            T: for<'enc> Encoding<'enc>,
+        {
+            #[inline]
+            fn cmp(&self, other: &Self) -> cmp::Ordering {
+                self.inner.cmp(&other.inner)
+            }
+        }
+
+        impl<T> PartialOrd for $buf<T>
+        where
+            T: for<'enc> Encoding<'enc>,
+        {
+            #[inline]
+            fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl<T> Ord for $buf<T>
+        where
+            T: for<'enc> Encoding<'enc>,
+        {
+            #[inline]
+            fn cmp(&self, other: &Self) -> cmp::Ordering {
+                self.inner.cmp(&other.inner)
+            }
+        }
+
+        impl<T> Hash for $path<T>
+        where
+            T: for<'enc> Encoding<'enc>,
+        {
+            #[inline]
+            fn hash<H: Hasher>(&self, h: &mut H) {
+                self.inner.hash(h)
+            }
+        }
+
+        impl<T> Hash for $buf<T>
+        where
+            T: for<'enc> Encoding<'enc>,
+        {
+            #[inline]
+            fn hash<H: Hasher>(&self, h: &mut H) {
+                self.inner.hash(h)
+            }
+        }
+
+        impl<'a, T> TryFrom<&'a Path<T>> for &'a $path<T>
+        where
+            T: for<'enc> Encoding<'enc>,
+        {
+            type Error = PathKindError;
+
+            #[inline]
+            fn try_from(path: &'a Path<T>) -> Result<Self, Self::Error> {
+                $path::new(path.as_bytes())
+            }
+        }
+
+        impl<T> TryFrom<PathBuf<T>> for $buf<T>
+        where
+            T: for<'enc> Encoding<'enc>,
+        {
+            type Error = PathKindError;
+
+            #[inline]
+            fn try_from(path: PathBuf<T>) -> Result<Self, Self::Error> {
+                $path::<T>::new(path.as_bytes())?;
+                Ok(Self { inner: path })
+            }
+        }
+
+        impl<T> From<$buf<T>> for PathBuf<T>
+        where
+            T: for<'enc> Encoding<'enc>,
+        {
+            #[inline]
+            fn from(path: $buf<T>) -> Self {
+                path.inner
+            }
+        }
+    };
+}
+
+impl_path_wrapper!(AbsPath, AbsPathBuf, as_abs_path);
+impl_path_wrapper!(RelPath, RelPathBuf, as_rel_path);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Plan9Encoding, UnixEncoding, WindowsEncoding};
+
+    #[test]
+    fn rel_path_should_reject_anything_that_would_replace_part_of_a_path() {
+        for path in ["", ".", "..", "a", "a/b", "../a"] {
+            assert!(RelPath::<UnixEncoding>::new(path).is_ok(), "{path}");
+            assert!(RelPath::<WindowsEncoding>::new(path).is_ok(), "{path}");
+        }
+
+        for path in ["/", "/a"] {
+            assert!(RelPath::<UnixEncoding>::new(path).is_err(), "{path}");
+        }
+
+        for path in [r"\a", r"C:", r"C:a", r"\\server\share", r"\\?\a"] {
+            assert!(RelPath::<WindowsEncoding>::new(path).is_err(), "{path}");
+        }
+
+        assert!(RelPath::<Plan9Encoding>::new("#c/cons").is_err());
+    }
+
+    #[test]
+    fn abs_path_should_stay_absolute_through_parents_and_joins() {
+        for path in [r"C:\a\b", r"\\server\share\a", r"\\?\C:\a", r"\\.\COM1\a"] {
+            let path = AbsPath::<WindowsEncoding>::new(path).unwrap();
+            let mut parent = Some(path);
+            while let Some(path) = parent {
+                assert!(path.is_absolute(), "{path:?}");
+                assert!(path.join(RelPath::new(r"..\x").unwrap()).is_absolute());
+                parent = path.parent();
+            }
+        }
+
+        let mut path = AbsPathBuf::<UnixEncoding>::new("/a/b").unwrap();
+        assert!(path.pop());
+        assert!(path.pop());
+        assert!(!path.pop());
+        assert_eq!(path.as_bytes(), b"/");
+    }
+}
@@ -0,0 +1,299 @@
+use core::iter::FusedIterator;
+use core::marker::PhantomData;
+use core::ops::Range;
+use core::{fmt, slice};
+
+use crate::no_std_compat::*;
+use crate::{Component, ComponentKind, Components, Encoding, Path};
+
+/// The components of many paths, parsed into one shared table.
+///
+/// Parsing a long list of paths one [`Path::components`] call at a time is cheap per call but
+/// leaves nothing behind, so anything that needs the components more than once, or needs to look
+/// them up by index, ends up collecting them into a fresh allocation per path. A
+/// `ComponentBatch` instead records every path's components in a single buffer, and
+/// [`ComponentBatch::clear`] keeps that buffer's capacity so the next batch can be parsed without
+/// allocating again.
+///
+/// # Examples
+///
+/// ```
+/// use typed_path::{ComponentBatch, ComponentKind, Path, UnixEncoding};
+///
+/// // NOTE: A path cannot be created on its own without a defined encoding
+/// let mut batch = ComponentBatch::<UnixEncoding>::parse_many(["/usr/bin", "src/lib.rs"]);
+/// assert_eq!(batch.len(), 2);
+/// assert_eq!(batch.path(1), Some(Path::new("src/lib.rs")));
+///
+/// let components: Vec<_> = batch.components(0).unwrap().collect();
+/// assert_eq!(
+///     components,
+///     [
+///         (ComponentKind::RootDir, b"/".as_slice()),
+///         (ComponentKind::Normal, b"usr".as_slice()),
+///         (ComponentKind::Normal, b"bin".as_slice()),
+///     ]
+/// );
+///
+/// // Reuse the buffers for the next batch
+/// batch.clear();
+/// batch.extend(["a/b/c"]);
+/// assert_eq!(batch.component_count(0), Some(3));
+/// ```
+pub struct ComponentBatch<'a, T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    paths: Vec<&'a [u8]>,
+
+    /// Kind and byte range within its path of every component, grouped by path
+    spans: Vec<(ComponentKind, Range<usize>)>,
+
+    /// Index into `spans` one past the last component of each path
+    ends: Vec<usize>,
+
+    _encoding: PhantomData<T>,
+}
+
+impl<'a, T> ComponentBatch<'a, T>
+where
+    T: for<'enc> Encoding<'enc> + 'a,
+{
+    /// Creates an empty batch.
+    #[inline]
+    pub fn new() -> Self {
+        Self::with_capacity(0, 0)
+    }
+
+    /// Creates an empty batch with room for `paths` paths holding `components` components in
+    /// total before allocating.
+    pub fn with_capacity(paths: usize, components: usize) -> Self {
+        Self {
+            paths: Vec::with_capacity(paths),
+            spans: Vec::with_capacity(components),
+            ends: Vec::with_capacity(paths),
+            _encoding: PhantomData,
+        }
+    }
+
+    /// Parses every path in `paths` into a new batch.
+    pub fn parse_many<I, P>(paths: I) -> Self
+    where
+        I: IntoIterator<Item = &'a P>,
+        P: AsRef<[u8]> + ?Sized + 'a,
+    {
+        let mut batch = Self::new();
+        batch.extend(paths);
+        batch
+    }
+
+    /// Parses `path` and appends it to the batch.
+    pub fn push<P: AsRef<[u8]> + ?Sized>(&mut self, path: &'a P) {
+        let bytes = path.as_ref();
+        let mut components = T::components(bytes);
+        loop {
+            // Each component starts where the bytes left by the previous one do
+            let start = bytes.len() - components.as_bytes().len();
+            match components.next() {
+                Some(component) => {
+                    self.spans
+                        .push((component.kind(), start..start + component.len()));
+                }
+                None => break,
+            }
+        }
+
+        self.paths.push(bytes);
+        self.ends.push(self.spans.len());
+    }
+
+    /// Parses every path in `paths` and appends them to the batch.
+    pub fn extend<I, P>(&mut self, paths: I)
+    where
+        I: IntoIterator<Item = &'a P>,
+        P: AsRef<[u8]> + ?Sized + 'a,
+    {
+        let paths = paths.into_iter();
+        self.paths.reserve(paths.size_hint().0);
+        self.ends.reserve(paths.size_hint().0);
+        for path in paths {
+            self.push(path);
+        }
+    }
+
+    /// Removes every path from the batch, keeping the allocated buffers for reuse.
+    pub fn clear(&mut self) {
+        self.paths.clear();
+        self.spans.clear();
+        self.ends.clear();
+    }
+
+    /// Returns the number of paths in the batch.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.paths.len()
+    }
+
+    /// Returns true if the batch holds no paths.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.paths.is_empty()
+    }
+
+    /// Returns the path at `index`, or [`None`] if `index` is out of bounds.
+    #[inline]
+    pub fn path(&self, index: usize) -> Option<&'a Path<T>> {
+        self.paths.get(index).map(|path| Path::new(*path))
+    }
+
+    /// Returns the number of components of the path at `index`, matching
+    /// `components().count()`, or [`None`] if `index` is out of bounds.
+    #[inline]
+    pub fn component_count(&self, index: usize) -> Option<usize> {
+        self.span_range(index).map(|range| range.len())
+    }
+
+    /// Returns an iterator over the kind and bytes of each component of the path at `index`, or
+    /// [`None`] if `index` is out of bounds.
+    pub fn components(&self, index: usize) -> Option<BatchComponents<'_, 'a>> {
+        let range = self.span_range(index)?;
+        Some(BatchComponents {
+            path: self.paths[index],
+            spans: self.spans[range].iter(),
+        })
+    }
+
+    fn span_range(&self, index: usize) -> Option<Range<usize>> {
+        let end = *self.ends.get(index)?;
+        let start = match index {
+            0 => 0,
+            _ => self.ends[index - 1],
+        };
+        Some(start..end)
+    }
+}
+
+impl<'a, T> Default for ComponentBatch<'a, T>
+where
+    T: for<'enc> Encoding<'enc> + 'a,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a, T> Clone for ComponentBatch<'a, T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            paths: self.paths.clone(),
+            spans: self.spans.clone(),
+            ends: self.ends.clone(),
+            _encoding: PhantomData,
+        }
+    }
+}
+
+impl<'a, T> fmt::Debug for ComponentBatch<'a, T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list()
+            .entries((0..self.len()).filter_map(|i| self.path(i)))
+            .finish()
+    }
+}
+
+/// An iterator over the kind and bytes of each component of one path in a [`ComponentBatch`].
+///
+/// This `struct` is created by the [`ComponentBatch::components`] method. See its documentation
+/// for more.
+#[derive(Clone, Debug)]
+pub struct BatchComponents<'b, 'a> {
+    path: &'a [u8],
+    spans: slice::Iter<'b, (ComponentKind, Range<usize>)>,
+}
+
+impl<'b, 'a> Iterator for BatchComponents<'b, 'a> {
+    type Item = (ComponentKind, &'a [u8]);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let (kind, span) = self.spans.next()?;
+        Some((*kind, &self.path[span.clone()]))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.spans.size_hint()
+    }
+}
+
+impl<'b, 'a> DoubleEndedIterator for BatchComponents<'b, 'a> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let (kind, span) = self.spans.next_back()?;
+        Some((*kind, &self.path[span.clone()]))
+    }
+}
+
+impl<'b, 'a> ExactSizeIterator for BatchComponents<'b, 'a> {}
+
+impl<'b, 'a> FusedIterator for BatchComponents<'b, 'a> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{UnixEncoding, WindowsEncoding};
+
+    fn check<T>(paths: &[&str])
+    where
+        T: for<'enc> Encoding<'enc>,
+    {
+        let batch = ComponentBatch::<T>::parse_many(paths.iter().copied());
+        assert_eq!(batch.len(), paths.len());
+
+        for (i, path) in paths.iter().enumerate() {
+            let expected: Vec<_> = Path::<T>::new(path)
+                .components()
+                .map(|c| (c.kind(), c.as_bytes()))
+                .collect();
+            let actual: Vec<_> = batch.components(i).unwrap().collect();
+            assert_eq!(actual.len(), expected.len(), "{path}");
+
+            // Roots keep the separator they were written with, which components normalize
+            for ((kind, bytes), (expected_kind, expected_bytes)) in actual.iter().zip(&expected) {
+                assert_eq!(kind, expected_kind, "{path}");
+                if *kind != ComponentKind::RootDir {
+                    assert_eq!(bytes, expected_bytes, "{path}");
+                }
+            }
+        }
+
+        assert!(batch.components(paths.len()).is_none());
+    }
+
+    #[test]
+    fn should_match_components_of_each_path() {
+        check::<UnixEncoding>(&["", "/", "a/./b/", "../a//b", "/.."]);
+        check::<WindowsEncoding>(&[r"C:", r"C:\a/b", r"\\server\share\a", r"\\?\C:\a\.\b"]);
+    }
+
+    #[test]
+    fn clear_should_keep_capacity() {
+        let mut batch = ComponentBatch::<UnixEncoding>::parse_many(["/a/b/c", "d/e"]);
+        let capacity = batch.spans.capacity();
+
+        batch.clear();
+        assert!(batch.is_empty());
+        assert_eq!(batch.spans.capacity(), capacity);
+
+        batch.extend(["x/y"]);
+        assert_eq!(batch.component_count(0), Some(2));
+        assert_eq!(batch.path(0), Some(Path::new("x/y")));
+    }
+}
@@ -0,0 +1,299 @@
+use core::fmt;
+
+use crate::no_std_compat::*;
+use crate::{Encoding, Path, PathBuf, SegmentError, SegmentErrorKind};
+
+/// A builder assembling a path out of individually validated segments.
+///
+/// Each segment is held to the same rules as [`PathBuf::extend_checked`], being exactly one
+/// normal component, and then to every validator added through [`PathBuilder::validate`].
+/// Nothing is checked until [`PathBuilder::build`], which reports the first segment to fail, so
+/// segments taken straight from user input can be chained without handling each one in turn.
+///
+/// # Examples
+///
+/// ```
+/// use typed_path::{PathBuilder, PathBuf, SegmentErrorKind, UnixEncoding};
+///
+/// // NOTE: A path cannot be created on its own without a defined encoding
+/// let builder = PathBuilder::<UnixEncoding>::new()
+///     .root()
+///     .push_segment("srv")
+///     .push_segment("uploads")
+///     .push_segment("avatar")
+///     .extension("png")
+///     .validate(|segment| segment.len() <= 16);
+/// assert_eq!(builder.build(), Ok(PathBuf::from("/srv/uploads/avatar.png")));
+///
+/// let err = builder.push_segment("..").build().unwrap_err();
+/// assert_eq!((err.index(), err.kind()), (3, SegmentErrorKind::Traversal));
+/// ```
+pub struct PathBuilder<'a, T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    base: PathBuf<T>,
+    segments: Vec<Vec<u8>>,
+    validators: Vec<Validator<'a>>,
+}
+
+impl<'a, T> PathBuilder<'a, T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    /// Creates a builder for a relative path with no segments.
+    #[inline]
+    pub fn new() -> Self {
+        Self::from_base(PathBuf::new())
+    }
+
+    /// Creates a builder whose segments are pushed onto `base`, which is trusted as it is and
+    /// never validated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{PathBuilder, PathBuf, WindowsEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// let path = PathBuilder::<WindowsEncoding>::from_base(r"C:\Users")
+    ///     .push_segment("me")
+    ///     .build();
+    /// assert_eq!(path, Ok(PathBuf::from(r"C:\Users\me")));
+    /// ```
+    pub fn from_base<P: Into<PathBuf<T>>>(base: P) -> Self {
+        Self {
+            base: base.into(),
+            segments: Vec::new(),
+            validators: Vec::new(),
+        }
+    }
+
+    /// Starts the path at a root directory, keeping any prefix of the base.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{PathBuilder, PathBuf, WindowsEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// let path = PathBuilder::<WindowsEncoding>::from_base("D:")
+    ///     .root()
+    ///     .push_segment("data")
+    ///     .build();
+    /// assert_eq!(path, Ok(PathBuf::from(r"D:\data")));
+    /// ```
+    pub fn root(mut self) -> Self {
+        if !self.base.has_root() {
+            let mut separator = [0; 4];
+            self.base
+                .push(T::SEPARATOR.encode_utf8(&mut separator).as_bytes());
+        }
+        self
+    }
+
+    /// Appends `segment` to the path, to be validated once the path is built.
+    pub fn push_segment<S: AsRef<[u8]>>(mut self, segment: S) -> Self {
+        self.segments.push(segment.as_ref().to_vec());
+        self
+    }
+
+    /// Replaces the extension of the last segment with `extension`, or removes it if
+    /// `extension` is empty, following [`Path::with_extension`].
+    ///
+    /// With no segments to extend, the path fails to build with [`SegmentErrorKind::Empty`].
+    pub fn extension<S: AsRef<[u8]>>(mut self, extension: S) -> Self {
+        match self.segments.last_mut() {
+            Some(segment) => {
+                *segment = Path::<T>::new(segment.as_slice())
+                    .with_extension(extension)
+                    .into_vec();
+            }
+            None => self.segments.push(Vec::new()),
+        }
+        self
+    }
+
+    /// Adds a check that every segment must pass, where returning `false` rejects the segment
+    /// with [`SegmentErrorKind::Rejected`].
+    ///
+    /// Validators apply to every segment, including those pushed before the validator was added,
+    /// and only see segments that already passed the checks of [`PathBuf::extend_checked`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{PathBuilder, SegmentErrorKind, UnixEncoding};
+    ///
+    /// let allowed = ["images", "logo.png"];
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// let err = PathBuilder::<UnixEncoding>::new()
+    ///     .push_segment("images")
+    ///     .push_segment("secret.png")
+    ///     .validate(|segment| allowed.iter().any(|name| name.as_bytes() == segment))
+    ///     .build()
+    ///     .unwrap_err();
+    /// assert_eq!((err.index(), err.kind()), (1, SegmentErrorKind::Rejected));
+    /// ```
+    pub fn validate<F>(mut self, validator: F) -> Self
+    where
+        F: Fn(&[u8]) -> bool + 'a,
+    {
+        self.validators.push(Box::new(validator));
+        self
+    }
+
+    /// Validates every segment and builds the path.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`SegmentError`] for the first segment that is not exactly one normal component,
+    /// as described by [`PathBuf::extend_checked`], or that a validator rejects.
+    pub fn build(&self) -> Result<PathBuf<T>, SegmentError> {
+        let mut path = self.base.clone();
+        for (index, segment) in self.segments.iter().enumerate() {
+            let result = PathBuf::<T>::check_segment(segment).and_then(|_| {
+                if self.validators.iter().all(|validator| validator(segment)) {
+                    Ok(())
+                } else {
+                    Err(SegmentErrorKind::Rejected)
+                }
+            });
+
+            if let Err(kind) = result {
+                return Err(SegmentError { index, kind });
+            }
+            path.push(Path::new(segment));
+        }
+
+        Ok(path)
+    }
+}
+
+impl<'a, T> Default for PathBuilder<'a, T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a, T> fmt::Debug for PathBuilder<'a, T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PathBuilder")
+            .field("base", &self.base)
+            .field(
+                "segments",
+                &self
+                    .segments
+                    .iter()
+                    .map(|segment| Path::<T>::new(segment))
+                    .collect::<Vec<_>>(),
+            )
+            .field("validators", &self.validators.len())
+            .finish()
+    }
+}
+
+/// Check added through `validate`, returning false to reject a segment
+type Validator<'a> = Box<dyn Fn(&[u8]) -> bool + 'a>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{UnixEncoding, WindowsEncoding};
+
+    #[test]
+    fn build_should_report_the_first_rejected_segment() {
+        let builder = PathBuilder::<UnixEncoding>::new()
+            .push_segment("a")
+            .push_segment("b/c")
+            .push_segment("");
+        assert_eq!(
+            builder.build(),
+            Err(SegmentError {
+                index: 1,
+                kind: SegmentErrorKind::Separator
+            })
+        );
+
+        let builder = PathBuilder::<WindowsEncoding>::new()
+            .push_segment("a")
+            .push_segment("C:");
+        assert_eq!(
+            builder.build(),
+            Err(SegmentError {
+                index: 1,
+                kind: SegmentErrorKind::Prefix
+            })
+        );
+    }
+
+    #[test]
+    fn validators_should_only_see_valid_segments() {
+        let builder = PathBuilder::<UnixEncoding>::new()
+            .push_segment("a")
+            .push_segment(".")
+            .validate(|segment| {
+                assert_ne!(segment, b".");
+                true
+            });
+        assert_eq!(
+            builder.build(),
+            Err(SegmentError {
+                index: 1,
+                kind: SegmentErrorKind::Traversal
+            })
+        );
+    }
+
+    #[test]
+    fn extension_should_replace_that_of_the_last_segment() {
+        let build = |builder: PathBuilder<'_, UnixEncoding>| builder.build();
+        assert_eq!(
+            build(PathBuilder::new().push_segment("a.tar").extension("gz")),
+            Ok(PathBuf::from("a.gz"))
+        );
+        assert_eq!(
+            build(PathBuilder::new().push_segment("a.txt").extension("")),
+            Ok(PathBuf::from("a"))
+        );
+        assert_eq!(
+            build(PathBuilder::new().push_segment("a").extension("b/c")),
+            Err(SegmentError {
+                index: 0,
+                kind: SegmentErrorKind::Separator
+            })
+        );
+        assert_eq!(
+            build(PathBuilder::new().extension("rs")),
+            Err(SegmentError {
+                index: 0,
+                kind: SegmentErrorKind::Empty
+            })
+        );
+    }
+
+    #[test]
+    fn root_should_keep_the_prefix_of_the_base() {
+        let build = |base: &str| {
+            PathBuilder::<WindowsEncoding>::from_base(base)
+                .root()
+                .push_segment("a")
+                .build()
+        };
+        assert_eq!(build(""), Ok(PathBuf::from(r"\a")));
+        assert_eq!(build("C:"), Ok(PathBuf::from(r"C:\a")));
+        assert_eq!(build(r"C:\b"), Ok(PathBuf::from(r"C:\b\a")));
+        assert_eq!(
+            build(r"\\server\share"),
+            Ok(PathBuf::from(r"\\server\share\a"))
+        );
+    }
+}
@@ -1,7 +1,9 @@
+mod adapters;
 mod component;
 
 use core::{cmp, fmt, iter};
 
+pub use adapters::*;
 pub use component::*;
 
 use crate::private;
@@ -51,4 +53,133 @@ pub trait Components<'a>:
     ///     * has a prefix followed by a separator, e.g., `c:\windows` but not `c:windows`
     ///     * has any non-disk prefix, e.g., `\\server\share`
     fn has_root(&self) -> bool;
+
+    /// Creates an iterator over at most the next `n` components that can still report the rest
+    /// of the path through [`TakeDepth::as_path`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Components, UnixComponent, UnixPath};
+    ///
+    /// let components = UnixPath::new("/usr/local/bin").components().take_depth(2);
+    /// assert_eq!(
+    ///     components.collect::<Vec<_>>(),
+    ///     [UnixComponent::RootDir, UnixComponent::Normal(b"usr")],
+    /// );
+    /// ```
+    fn take_depth(self, n: usize) -> TakeDepth<Self> {
+        TakeDepth::new(self, n)
+    }
+
+    /// Creates an iterator that skips any prefix and root at the start of the path.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Components, WindowsComponent, WindowsPath};
+    ///
+    /// let components = WindowsPath::new(r"\\server\share\dir").components().skip_root();
+    /// assert_eq!(components.collect::<Vec<_>>(), [WindowsComponent::Normal(b"dir")]);
+    /// ```
+    fn skip_root(self) -> SkipRoot<Self> {
+        SkipRoot::new(self)
+    }
+
+    /// Creates an iterator over the bytes of only the normal components, skipping any prefix,
+    /// root, current, or parent components.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Components, UnixPath};
+    ///
+    /// let components = UnixPath::new("/a/./b/../c").components().normal_only();
+    /// assert_eq!(components.collect::<Vec<_>>(), [b"a", b"b", b"c"]);
+    /// ```
+    fn normal_only(self) -> NormalOnly<'a, Self> {
+        NormalOnly::new(self)
+    }
+
+    /// Creates an iterator over every run of `n` consecutive components, like [`slice::windows`].
+    ///
+    /// Each window is itself an iterator over its components, and no windows are yielded if the
+    /// path has fewer than `n` components.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Component, Components, UnixComponent, UnixPath};
+    ///
+    /// // Find a component that is left and immediately re-entered, like `foo/../foo`
+    /// let path = UnixPath::new("/srv/www/../www/index.html");
+    /// let round_trip = path.components().component_windows(3).find_map(|mut window| {
+    ///     match (window.next(), window.next(), window.next()) {
+    ///         (Some(a), Some(b), Some(c)) if b.is_parent() && a.is_normal() && a == c => Some(a),
+    ///         _ => None,
+    ///     }
+    /// });
+    /// assert_eq!(round_trip, Some(UnixComponent::Normal(b"www")));
+    /// ```
+    fn component_windows(self, n: usize) -> ComponentWindows<Self> {
+        ComponentWindows::new(self, n)
+    }
+
+    /// Creates an iterator over each component alongside the component that follows it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Component, Components, UnixPath};
+    ///
+    /// // Find the packages installed within a node_modules directory
+    /// let path = UnixPath::new("app/node_modules/left-pad/index.js");
+    /// let packages: Vec<_> = path
+    ///     .components()
+    ///     .component_pairs()
+    ///     .filter(|(dir, _)| dir.as_bytes() == b"node_modules")
+    ///     .map(|(_, pkg)| pkg.as_bytes())
+    ///     .collect();
+    /// assert_eq!(packages, [b"left-pad"]);
+    /// ```
+    fn component_pairs(self) -> ComponentPairs<Self> {
+        ComponentPairs::new(self)
+    }
+}
+
+/// Extracts the portion of the path remaining for iteration without the trailing separators and
+/// current directory components that will never be yielded, which is the slice returned by
+/// `std::path::Components::as_path`.
+pub(crate) fn remaining_path<'a, C>(components: &C) -> &'a [u8]
+where
+    C: Components<'a>,
+{
+    let remaining = components.as_bytes();
+    let mut rest = components.clone();
+    let last = match rest.next_back() {
+        Some(component) => component,
+        None => return &remaining[..0],
+    };
+
+    // The last component starts somewhere after the bytes left once it has been consumed
+    let start = rest.as_bytes().len();
+    let bytes = last.as_bytes();
+    let end = if last.is_normal() {
+        bytes.as_ptr() as usize - remaining.as_ptr() as usize + bytes.len()
+    } else if last.is_current() || last.is_parent() {
+        remaining[start..]
+            .windows(bytes.len())
+            .position(|window| window == bytes)
+            .map(|i| start + i + bytes.len())
+            .unwrap_or(remaining.len())
+    } else {
+        // Prefix and root can only be preceded by each other, never by separators
+        start + last.len()
+    };
+
+    &remaining[..end]
 }
@@ -0,0 +1,283 @@
+use core::iter::FusedIterator;
+use core::marker::PhantomData;
+
+use super::remaining_path;
+use crate::{Component, Components, Encoding, Path};
+
+/// An iterator over at most `n` [`Component`]s, created by [`Components::take_depth`].
+///
+/// Unlike [`Iterator::take`], the rest of the path can still be retrieved through
+/// [`TakeDepth::as_path`].
+#[derive(Clone, Debug)]
+pub struct TakeDepth<I> {
+    iter: I,
+    remaining: usize,
+}
+
+impl<I> TakeDepth<I> {
+    pub(crate) fn new(iter: I, n: usize) -> Self {
+        Self { iter, remaining: n }
+    }
+}
+
+impl<'a, I> TakeDepth<I>
+where
+    I: Components<'a>,
+{
+    /// Extracts a slice corresponding to the portion of the path not yet consumed, including any
+    /// components beyond the depth limit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Components, Path, UnixEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// let mut components = Path::<UnixEncoding>::new("/srv/www/site/index.html")
+    ///     .components()
+    ///     .take_depth(2);
+    /// assert_eq!(components.by_ref().count(), 2);
+    ///
+    /// assert_eq!(Path::<UnixEncoding>::new("www/site/index.html"), components.as_path());
+    /// ```
+    pub fn as_path<T>(&self) -> &'a Path<T>
+    where
+        T: for<'enc> Encoding<'enc>,
+    {
+        Path::new(remaining_path(&self.iter))
+    }
+}
+
+impl<I> Iterator for TakeDepth<I>
+where
+    I: Iterator,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        self.remaining -= 1;
+        self.iter.next()
+    }
+}
+
+impl<I> FusedIterator for TakeDepth<I> where I: FusedIterator {}
+
+/// An iterator over the [`Component`]s of a path after any prefix and root, created by
+/// [`Components::skip_root`].
+#[derive(Clone, Debug)]
+pub struct SkipRoot<I> {
+    iter: I,
+}
+
+impl<'a, I> SkipRoot<I>
+where
+    I: Components<'a>,
+{
+    pub(crate) fn new(mut iter: I) -> Self {
+        let mut rest = iter.clone();
+        while let Some(component) = rest.next() {
+            // Anything other than a normal, current, or parent component is a prefix or root
+            if component.is_normal() || component.is_current() || component.is_parent() {
+                break;
+            }
+            iter = rest.clone();
+        }
+
+        Self { iter }
+    }
+
+    /// Extracts a slice corresponding to the portion of the path remaining for iteration, which
+    /// never includes a prefix or root.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Components, Path, WindowsEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// let components = Path::<WindowsEncoding>::new(r"C:\Users\me").components().skip_root();
+    /// assert_eq!(Path::<WindowsEncoding>::new(r"Users\me"), components.as_path());
+    /// ```
+    pub fn as_path<T>(&self) -> &'a Path<T>
+    where
+        T: for<'enc> Encoding<'enc>,
+    {
+        Path::new(remaining_path(&self.iter))
+    }
+}
+
+impl<I> Iterator for SkipRoot<I>
+where
+    I: Iterator,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next()
+    }
+}
+
+impl<I> DoubleEndedIterator for SkipRoot<I>
+where
+    I: DoubleEndedIterator,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back()
+    }
+}
+
+impl<I> FusedIterator for SkipRoot<I> where I: FusedIterator {}
+
+/// An iterator over the bytes of only the normal [`Component`]s of a path, created by
+/// [`Components::normal_only`].
+#[derive(Clone, Debug)]
+pub struct NormalOnly<'a, I> {
+    iter: I,
+    _marker: PhantomData<&'a [u8]>,
+}
+
+impl<I> NormalOnly<'_, I> {
+    pub(crate) fn new(iter: I) -> Self {
+        Self {
+            iter,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<'a, I> NormalOnly<'a, I>
+where
+    I: Components<'a>,
+{
+    /// Extracts a slice corresponding to the portion of the path remaining for iteration.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Components, Path, UnixEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// let mut components = Path::<UnixEncoding>::new("/a/../b/./c").components().normal_only();
+    /// assert_eq!(components.next(), Some(b"a".as_slice()));
+    /// assert_eq!(components.next(), Some(b"b".as_slice()));
+    ///
+    /// assert_eq!(Path::<UnixEncoding>::new("c"), components.as_path());
+    /// ```
+    pub fn as_path<T>(&self) -> &'a Path<T>
+    where
+        T: for<'enc> Encoding<'enc>,
+    {
+        Path::new(remaining_path(&self.iter))
+    }
+}
+
+impl<'a, I> Iterator for NormalOnly<'a, I>
+where
+    I: Components<'a>,
+{
+    type Item = &'a [u8];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter
+            .by_ref()
+            .find(|c| c.is_normal())
+            .map(|c| c.as_bytes())
+    }
+}
+
+impl<'a, I> DoubleEndedIterator for NormalOnly<'a, I>
+where
+    I: Components<'a>,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter
+            .by_ref()
+            .rfind(|c| c.is_normal())
+            .map(|c| c.as_bytes())
+    }
+}
+
+impl<'a, I> FusedIterator for NormalOnly<'a, I> where I: Components<'a> {}
+
+/// An iterator over overlapping windows of `n` [`Component`]s, created by
+/// [`Components::component_windows`].
+///
+/// Each window is a [`TakeDepth`] over the components it covers, so no components are
+/// collected.
+#[derive(Clone, Debug)]
+pub struct ComponentWindows<I> {
+    iter: I,
+    n: usize,
+}
+
+impl<I> ComponentWindows<I> {
+    pub(crate) fn new(iter: I, n: usize) -> Self {
+        assert!(n != 0, "window size must be non-zero");
+        Self { iter, n }
+    }
+}
+
+impl<I> Iterator for ComponentWindows<I>
+where
+    I: Iterator + Clone,
+{
+    type Item = TakeDepth<I>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.iter.clone().take(self.n).count() < self.n {
+            return None;
+        }
+
+        let window = TakeDepth::new(self.iter.clone(), self.n);
+        self.iter.next();
+        Some(window)
+    }
+}
+
+impl<I> FusedIterator for ComponentWindows<I> where I: Iterator + Clone {}
+
+/// An iterator over each [`Component`] alongside the one following it, created by
+/// [`Components::component_pairs`].
+#[derive(Clone, Debug)]
+pub struct ComponentPairs<I>
+where
+    I: Iterator,
+{
+    iter: I,
+    prev: Option<I::Item>,
+}
+
+impl<I> ComponentPairs<I>
+where
+    I: Iterator,
+{
+    pub(crate) fn new(mut iter: I) -> Self {
+        let prev = iter.next();
+        Self { iter, prev }
+    }
+}
+
+impl<I> Iterator for ComponentPairs<I>
+where
+    I: Iterator,
+    I::Item: Clone,
+{
+    type Item = (I::Item, I::Item);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let next = self.iter.next()?;
+        let prev = self.prev.replace(next.clone())?;
+        Some((prev, next))
+    }
+}
+
+impl<I> FusedIterator for ComponentPairs<I>
+where
+    I: FusedIterator,
+    I::Item: Clone,
+{
+}
@@ -102,4 +102,76 @@ pub trait Component<'a>:
 
     /// Returns a current directory [`Component`].
     fn current() -> Self;
+
+    /// Returns the [`ComponentKind`] of this component, for code that is generic over encodings.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Component, ComponentKind, Components, WindowsPath};
+    ///
+    /// let kinds: Vec<_> = WindowsPath::new(r"C:\..\dir").components().map(|c| c.kind()).collect();
+    /// assert_eq!(
+    ///     kinds,
+    ///     [
+    ///         ComponentKind::Prefix,
+    ///         ComponentKind::RootDir,
+    ///         ComponentKind::ParentDir,
+    ///         ComponentKind::Normal,
+    ///     ],
+    /// );
+    /// ```
+    fn kind(&self) -> ComponentKind {
+        if self.is_normal() {
+            ComponentKind::Normal
+        } else if self.is_parent() {
+            ComponentKind::ParentDir
+        } else if self.is_current() {
+            ComponentKind::CurDir
+        } else if *self == Self::root() {
+            ComponentKind::RootDir
+        } else {
+            ComponentKind::Prefix
+        }
+    }
+
+    /// Returns the bytes of this component if it is a normal component.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Component, UnixComponent};
+    ///
+    /// assert_eq!(UnixComponent::Normal(b"file.txt").as_normal_bytes(), Some(b"file.txt".as_slice()));
+    /// assert_eq!(UnixComponent::ParentDir.as_normal_bytes(), None);
+    /// ```
+    fn as_normal_bytes(&self) -> Option<&'a [u8]> {
+        if self.is_normal() {
+            Some(self.as_bytes())
+        } else {
+            None
+        }
+    }
+}
+
+/// Classification of a component of a path, shared by every encoding.
+///
+/// This `enum` is returned by [`Component::kind`] and
+/// [`Utf8Component::kind`](crate::Utf8Component::kind).
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ComponentKind {
+    /// A prefix such as `C:` or `\\server\share` on Windows, which only appears first
+    Prefix,
+
+    /// The root directory, which only appears first or after a prefix
+    RootDir,
+
+    /// A reference to the current directory, `.`
+    CurDir,
+
+    /// A reference to the parent directory, `..`
+    ParentDir,
+
+    /// A normal component, such as `a` and `b` in `a/b`
+    Normal,
 }
@@ -0,0 +1,359 @@
+use alloc::borrow::Cow;
+use core::borrow::Borrow;
+use core::hash::{Hash, Hasher};
+use core::marker::PhantomData;
+use core::ops::Deref;
+use core::{cmp, fmt};
+
+use crate::{Component, Encoding, Path, PathBuf};
+
+/// A clone-on-write [`Path`], holding either a borrowed path or an owned path.
+///
+/// Functions that only sometimes rewrite a path, like [`Path::normalize_cow`], return this to
+/// avoid allocating when the input is already in the desired form. The full [`Path`] API is
+/// available through [`Deref`], and [`CowPath::into_owned`] produces a [`PathBuf`] when one is
+/// needed.
+///
+/// # Examples
+///
+/// ```
+/// use typed_path::{CowPath, Path, UnixEncoding};
+///
+/// // NOTE: A path cannot be created on its own without a defined encoding
+/// let path = Path::<UnixEncoding>::new("/etc/hosts");
+/// let cow = path.normalize_cow();
+/// assert!(cow.is_borrowed());
+/// assert_eq!(cow.file_name(), Some(b"hosts".as_slice()));
+///
+/// let cow: CowPath<UnixEncoding> = Path::new("/etc/../hosts").normalize_cow();
+/// assert!(cow.is_owned());
+/// assert_eq!(cow.into_owned(), Path::new("/hosts"));
+/// ```
+pub struct CowPath<'a, T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    _encoding: PhantomData<T>,
+    inner: Cow<'a, [u8]>,
+}
+
+impl<'a, T> CowPath<'a, T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    /// Wraps a borrowed [`Path`].
+    #[inline]
+    pub fn borrowed(path: &'a Path<T>) -> Self {
+        Self {
+            _encoding: PhantomData,
+            inner: Cow::Borrowed(path.as_bytes()),
+        }
+    }
+
+    /// Wraps an owned [`PathBuf`].
+    #[inline]
+    pub fn owned(path: PathBuf<T>) -> Self {
+        Self {
+            _encoding: PhantomData,
+            inner: Cow::Owned(path.into_vec()),
+        }
+    }
+
+    /// Returns true if the path is borrowed.
+    #[inline]
+    pub fn is_borrowed(&self) -> bool {
+        matches!(self.inner, Cow::Borrowed(_))
+    }
+
+    /// Returns true if the path is owned.
+    #[inline]
+    pub fn is_owned(&self) -> bool {
+        !self.is_borrowed()
+    }
+
+    /// Coerces to a [`Path`] slice.
+    #[inline]
+    pub fn as_path(&self) -> &Path<T> {
+        Path::new(&*self.inner)
+    }
+
+    /// Extracts the owned [`PathBuf`], cloning the path if it is borrowed.
+    #[inline]
+    pub fn into_owned(self) -> PathBuf<T> {
+        PathBuf::from(self.inner.into_owned())
+    }
+
+    /// Consumes the path, yielding the underlying bytes.
+    #[inline]
+    pub fn into_inner(self) -> Cow<'a, [u8]> {
+        self.inner
+    }
+}
+
+/// Returns true if [`Path::normalize`] would produce the bytes of `path` unchanged, erring on
+/// the side of false for anything other than components joined by the primary separator
+pub(crate) fn is_normalized<T>(path: &Path<T>) -> bool
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    let bytes = path.as_bytes();
+    let mut pos = 0;
+    let mut after_normal = false;
+
+    for component in path.components() {
+        if component.is_current() || component.is_parent() {
+            return false;
+        }
+
+        // Normalizing pushes each component in turn, which only adds a separator after a normal
+        // component
+        if after_normal {
+            if bytes.get(pos) != Some(&(T::SEPARATOR as u8)) {
+                return false;
+            }
+            pos += 1;
+        }
+
+        if !bytes[pos..].starts_with(component.as_bytes()) {
+            return false;
+        }
+        pos += component.len();
+        after_normal = component.is_normal();
+    }
+
+    pos == bytes.len()
+}
+
+impl<T> Clone for CowPath<'_, T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            _encoding: PhantomData,
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<T> fmt::Debug for CowPath<'_, T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CowPath")
+            .field("_encoding", &T::label())
+            .field("inner", &self.inner)
+            .finish()
+    }
+}
+
+impl<T> Deref for CowPath<'_, T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    type Target = Path<T>;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        self.as_path()
+    }
+}
+
+impl<T> AsRef<Path<T>> for CowPath<'_, T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    #[inline]
+    fn as_ref(&self) -> &Path<T> {
+        self.as_path()
+    }
+}
+
+impl<T> AsRef<[u8]> for CowPath<'_, T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        self.inner.as_ref()
+    }
+}
+
+impl<T> Borrow<Path<T>> for CowPath<'_, T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    #[inline]
+    fn borrow(&self) -> &Path<T> {
+        self.as_path()
+    }
+}
+
+impl<T> PartialEq for CowPath<'_, T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.as_path() == other.as_path()
+    }
+}
+
+impl<T> Eq for CowPath<'_, T> where T: for<'enc> Encoding<'enc> {}
+
+impl<T> PartialOrd for CowPath<'_, T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T> Ord for CowPath<'_, T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    #[inline]
+    fn cmp(&self, other: &Self) -> cmp::Ordering {
+        self.as_path().cmp(other.as_path())
+    }
+}
+
+impl<T> Hash for CowPath<'_, T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    #[inline]
+    fn hash<H: Hasher>(&self, h: &mut H) {
+        self.as_path().hash(h)
+    }
+}
+
+impl<'a, T> From<&'a Path<T>> for CowPath<'a, T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    #[inline]
+    fn from(path: &'a Path<T>) -> Self {
+        Self::borrowed(path)
+    }
+}
+
+impl<'a, T> From<&'a PathBuf<T>> for CowPath<'a, T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    #[inline]
+    fn from(path: &'a PathBuf<T>) -> Self {
+        Self::borrowed(path.as_path())
+    }
+}
+
+impl<T> From<PathBuf<T>> for CowPath<'_, T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    #[inline]
+    fn from(path: PathBuf<T>) -> Self {
+        Self::owned(path)
+    }
+}
+
+impl<'a, T> From<Cow<'a, Path<T>>> for CowPath<'a, T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    #[inline]
+    fn from(path: Cow<'a, Path<T>>) -> Self {
+        match path {
+            Cow::Borrowed(path) => Self::borrowed(path),
+            Cow::Owned(path) => Self::owned(path),
+        }
+    }
+}
+
+impl<'a, T> From<CowPath<'a, T>> for Cow<'a, Path<T>>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    #[inline]
+    fn from(path: CowPath<'a, T>) -> Self {
+        match path.inner {
+            Cow::Borrowed(bytes) => Cow::Borrowed(Path::new(bytes)),
+            Cow::Owned(bytes) => Cow::Owned(PathBuf::from(bytes)),
+        }
+    }
+}
+
+impl<T> From<CowPath<'_, T>> for PathBuf<T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    #[inline]
+    fn from(path: CowPath<'_, T>) -> Self {
+        path.into_owned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Plan9Encoding, PowerShellEncoding, UnixEncoding, WindowsEncoding};
+
+    fn check<T>(paths: &[&str])
+    where
+        T: for<'enc> Encoding<'enc>,
+    {
+        for path in paths {
+            let path = Path::<T>::new(path);
+            if is_normalized(path) {
+                assert_eq!(path.normalize().as_bytes(), path.as_bytes(), "{path:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn is_normalized_should_only_pass_paths_that_normalize_to_themselves() {
+        let paths = [
+            "",
+            ".",
+            "..",
+            "a",
+            "a/",
+            "a//b",
+            "/",
+            "//",
+            "/a",
+            "/a/b",
+            "a/./b",
+            "a/../b",
+            r"C:",
+            r"C:a",
+            r"C:\",
+            r"C:\a\b",
+            r"C:/a",
+            r"\\server\share",
+            r"\\server\share\a",
+            r"\\?\C:\a\.",
+            "#c",
+            "#c/cons",
+            "HKLM:",
+            r"HKLM:\Software",
+            r"FileSystem::C:\a",
+        ];
+
+        check::<UnixEncoding>(&paths);
+        check::<WindowsEncoding>(&paths);
+        check::<Plan9Encoding>(&paths);
+        check::<PowerShellEncoding>(&paths);
+
+        assert!(is_normalized(Path::<UnixEncoding>::new("/a/b")));
+        assert!(is_normalized(Path::<WindowsEncoding>::new(r"C:\a\b")));
+        assert!(!is_normalized(Path::<UnixEncoding>::new("a//b")));
+        assert!(!is_normalized(Path::<WindowsEncoding>::new(r"C:/a")));
+    }
+}
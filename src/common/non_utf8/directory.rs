@@ -0,0 +1,377 @@
+use alloc::borrow::ToOwned;
+use core::borrow::Borrow;
+use core::hash::{Hash, Hasher};
+use core::ops::Deref;
+use core::{cmp, fmt};
+
+use crate::common::PathKindError;
+use crate::{Component, Encoding, Path, PathBuf};
+
+/// A [`Path`] that denotes a directory, judged purely by how it is written.
+///
+/// Any path that is not a [`FilePath`] is a directory path, meaning that it is empty, ends with a
+/// separator, or ends with a component other than a normal one, such as `..` or a root. There is
+/// no way to join onto a [`FilePath`], so wrapping the base of a join in a `DirectoryPath`
+/// ensures nothing is ever placed beneath a file.
+///
+/// # Examples
+///
+/// ```
+/// use typed_path::{DirectoryPath, FilePath, PathKindError, UnixEncoding};
+///
+/// // NOTE: A path cannot be created on its own without a defined encoding
+/// let dir = DirectoryPath::<UnixEncoding>::new("/srv/app/").unwrap();
+/// let file = dir.join_file(FilePath::new("logs/today.txt").unwrap());
+/// assert_eq!(file.as_bytes(), b"/srv/app/logs/today.txt");
+///
+/// assert_eq!(
+///     DirectoryPath::<UnixEncoding>::new("/srv/app"),
+///     Err(PathKindError::NotDirectory),
+/// );
+/// assert!(DirectoryPath::<UnixEncoding>::new("/srv/app/..").is_ok());
+/// ```
+#[repr(transparent)]
+pub struct DirectoryPath<T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    inner: Path<T>,
+}
+
+/// A [`Path`] that denotes a file, judged purely by how it is written.
+///
+/// A file path ends with a normal component, written without any separator or `.` after it, so
+/// it always has a [`FilePath::file_name`] and a [`FilePath::parent`] directory.
+///
+/// # Examples
+///
+/// ```
+/// use typed_path::{FilePath, PathKindError, WindowsEncoding};
+///
+/// // NOTE: A path cannot be created on its own without a defined encoding
+/// let file = FilePath::<WindowsEncoding>::new(r"C:\logs\today.txt").unwrap();
+/// assert_eq!(file.file_name(), b"today.txt");
+/// assert_eq!(file.parent().as_bytes(), br"C:\logs\");
+///
+/// assert_eq!(
+///     FilePath::<WindowsEncoding>::new(r"C:\logs\"),
+///     Err(PathKindError::NotFile),
+/// );
+/// ```
+#[repr(transparent)]
+pub struct FilePath<T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    inner: Path<T>,
+}
+
+/// An owned [`DirectoryPath`] (akin to [`PathBuf`]).
+///
+/// # Examples
+///
+/// ```
+/// use typed_path::{DirectoryPathBuf, FilePath, UnixEncoding};
+///
+/// // NOTE: A pathbuf cannot be created on its own without a defined encoding
+/// let mut dir = DirectoryPathBuf::<UnixEncoding>::new("/srv");
+/// assert_eq!(dir.as_bytes(), b"/srv/");
+///
+/// dir.push_dir("app");
+/// assert_eq!(dir.as_bytes(), b"/srv/app/");
+///
+/// let file = dir.into_file(FilePath::new("config.toml").unwrap());
+/// assert_eq!(file.as_bytes(), b"/srv/app/config.toml");
+/// ```
+pub struct DirectoryPathBuf<T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    inner: PathBuf<T>,
+}
+
+/// An owned [`FilePath`] (akin to [`PathBuf`]).
+///
+/// # Examples
+///
+/// ```
+/// use typed_path::{FilePathBuf, UnixEncoding};
+///
+/// // NOTE: A pathbuf cannot be created on its own without a defined encoding
+/// let file = FilePathBuf::<UnixEncoding>::new("logs/today.txt").unwrap();
+/// assert_eq!(file.parent().as_bytes(), b"logs/");
+/// assert!(FilePathBuf::<UnixEncoding>::new("logs/").is_err());
+/// ```
+pub struct FilePathBuf<T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    inner: PathBuf<T>,
+}
+
+/// Returns the length of the file name ending `path`, if the path ends with a normal component
+/// written without anything after it
+fn file_name_len<T>(path: &Path<T>) -> Option<usize>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    let bytes = path.as_bytes();
+    match path.components().next_back() {
+        Some(component)
+            if component.is_normal()
+                && bytes.ends_with(component.as_bytes())
+                && matches!(bytes.last(), Some(b) if !T::is_separator(*b)) =>
+        {
+            Some(component.len())
+        }
+        _ => None,
+    }
+}
+
+impl<T> DirectoryPath<T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    /// Wraps `s` as a `DirectoryPath` if it is not written as a [`FilePath`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PathKindError::NotDirectory`] if the path ends with a file name.
+    ///
+    /// [`PathKindError::NotDirectory`]: crate::PathKindError::NotDirectory
+    pub fn new<S: AsRef<[u8]> + ?Sized>(s: &S) -> Result<&Self, PathKindError> {
+        let path = Path::new(s);
+        match file_name_len(path) {
+            Some(_) => Err(PathKindError::NotDirectory),
+            None => Ok(Self::new_unchecked(path)),
+        }
+    }
+
+    fn new_unchecked(path: &Path<T>) -> &Self {
+        unsafe { &*(path as *const Path<T> as *const Self) }
+    }
+
+    /// Coerces to a [`Path`] slice.
+    #[inline]
+    pub fn as_path(&self) -> &Path<T> {
+        &self.inner
+    }
+
+    /// Converts to an owned [`DirectoryPathBuf`].
+    pub fn to_path_buf(&self) -> DirectoryPathBuf<T> {
+        DirectoryPathBuf {
+            inner: self.inner.to_path_buf(),
+        }
+    }
+
+    /// Creates an owned [`DirectoryPathBuf`] with `path` adjoined to `self`, marking the result
+    /// as a directory like [`DirectoryPathBuf::new`].
+    pub fn join_dir<P: AsRef<Path<T>>>(&self, path: P) -> DirectoryPathBuf<T> {
+        DirectoryPathBuf::new(self.inner.join(path))
+    }
+
+    /// Creates an owned [`FilePathBuf`] with `path` adjoined to `self`.
+    pub fn join_file<P: AsRef<FilePath<T>>>(&self, path: P) -> FilePathBuf<T> {
+        FilePathBuf {
+            inner: self.inner.join(path.as_ref().as_path()),
+        }
+    }
+}
+
+impl<T> FilePath<T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    /// Wraps `s` as a `FilePath` if it ends with a normal component written without any
+    /// separator or `.` after it.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PathKindError::NotFile`] if the path is a [`DirectoryPath`].
+    ///
+    /// [`PathKindError::NotFile`]: crate::PathKindError::NotFile
+    pub fn new<S: AsRef<[u8]> + ?Sized>(s: &S) -> Result<&Self, PathKindError> {
+        let path = Path::new(s);
+        match file_name_len(path) {
+            Some(_) => Ok(Self::new_unchecked(path)),
+            None => Err(PathKindError::NotFile),
+        }
+    }
+
+    fn new_unchecked(path: &Path<T>) -> &Self {
+        unsafe { &*(path as *const Path<T> as *const Self) }
+    }
+
+    /// Coerces to a [`Path`] slice.
+    #[inline]
+    pub fn as_path(&self) -> &Path<T> {
+        &self.inner
+    }
+
+    /// Converts to an owned [`FilePathBuf`].
+    pub fn to_path_buf(&self) -> FilePathBuf<T> {
+        FilePathBuf {
+            inner: self.inner.to_path_buf(),
+        }
+    }
+
+    /// Returns the final component of the path.
+    pub fn file_name(&self) -> &[u8] {
+        let bytes = self.inner.as_bytes();
+        &bytes[bytes.len() - self.name_len()..]
+    }
+
+    /// Returns everything before [`FilePath::file_name`], including the separator before it, as
+    /// the directory holding the file. The directory is empty if the path is only a file name.
+    pub fn parent(&self) -> &DirectoryPath<T> {
+        let bytes = self.inner.as_bytes();
+
+        // What precedes a normal component is nothing, a separator, or a prefix like `C:`
+        DirectoryPath::new_unchecked(Path::new(&bytes[..bytes.len() - self.name_len()]))
+    }
+
+    fn name_len(&self) -> usize {
+        file_name_len(&self.inner).unwrap_or(0)
+    }
+}
+
+impl<T> DirectoryPathBuf<T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    /// Wraps `path` as a `DirectoryPathBuf`, adding a separator to the end if it would otherwise
+    /// be written as a [`FilePath`].
+    ///
+    /// Use [`TryFrom`] instead to reject such paths.
+    pub fn new<P: Into<PathBuf<T>>>(path: P) -> Self {
+        let mut inner = path.into();
+        if file_name_len(&inner).is_some() {
+            inner.inner.push(T::SEPARATOR as u8);
+        }
+
+        Self { inner }
+    }
+
+    /// Coerces to a [`DirectoryPath`] slice.
+    #[inline]
+    pub fn as_directory_path(&self) -> &DirectoryPath<T> {
+        DirectoryPath::new_unchecked(&self.inner)
+    }
+
+    /// Extends `self` with `path`, marking the result as a directory like
+    /// [`DirectoryPathBuf::new`].
+    pub fn push_dir<P: AsRef<Path<T>>>(&mut self, path: P) {
+        self.inner.push(path);
+        if file_name_len(&self.inner).is_some() {
+            self.inner.inner.push(T::SEPARATOR as u8);
+        }
+    }
+
+    /// Consumes `self`, extending it with the file `path`.
+    pub fn into_file<P: AsRef<FilePath<T>>>(mut self, path: P) -> FilePathBuf<T> {
+        self.inner.push(path.as_ref().as_path());
+        FilePathBuf { inner: self.inner }
+    }
+
+    /// Consumes the `DirectoryPathBuf`, yielding its [`PathBuf`].
+    #[inline]
+    pub fn into_path_buf(self) -> PathBuf<T> {
+        self.inner
+    }
+}
+
+impl<T> FilePathBuf<T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    /// Wraps `path` as a `FilePathBuf` if it ends with a file name.
+    ///
+    /// See [`FilePath::new`] for more details.
+    pub fn new<P: Into<PathBuf<T>>>(path: P) -> Result<Self, PathKindError> {
+        let inner = path.into();
+        FilePath::<T>::new(inner.as_bytes())?;
+        Ok(Self { inner })
+    }
+
+    /// Coerces to a [`FilePath`] slice.
+    #[inline]
+    pub fn as_file_path(&self) -> &FilePath<T> {
+        FilePath::new_unchecked(&self.inner)
+    }
+
+    /// Consumes `self`, yielding the [`DirectoryPathBuf`] that holds the file.
+    pub fn into_parent(mut self) -> DirectoryPathBuf<T> {
+        let len = self.parent().as_bytes().len();
+        self.inner.inner.truncate(len);
+        DirectoryPathBuf { inner: self.inner }
+    }
+
+    /// Consumes the `FilePathBuf`, yielding its [`PathBuf`].
+    #[inline]
+    pub fn into_path_buf(self) -> PathBuf<T> {
+        self.inner
+    }
+}
+
+impl_path_wrapper!(DirectoryPath, DirectoryPathBuf, as_directory_path);
+impl_path_wrapper!(FilePath, FilePathBuf, as_file_path);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Plan9Encoding, UnixEncoding, WindowsEncoding};
+
+    fn check<T>(files: &[&str], dirs: &[&str])
+    where
+        T: for<'enc> Encoding<'enc>,
+    {
+        for path in files {
+            let file = FilePath::<T>::new(path).unwrap();
+            assert!(DirectoryPath::<T>::new(path).is_err(), "{path}");
+            assert_eq!(
+                [file.parent().as_bytes(), file.file_name()].concat(),
+                path.as_bytes(),
+                "{path}"
+            );
+
+            let name = FilePath::new(file.file_name()).unwrap();
+            assert_eq!(file.parent().join_file(name).as_bytes(), path.as_bytes());
+
+            let dir = DirectoryPathBuf::<T>::new(*path);
+            assert_eq!(
+                dir.as_bytes(),
+                [path.as_bytes(), &[T::SEPARATOR as u8]].concat()
+            );
+        }
+
+        for path in dirs {
+            assert!(DirectoryPath::<T>::new(path).is_ok(), "{path}");
+            assert!(FilePath::<T>::new(path).is_err(), "{path}");
+            assert_eq!(
+                DirectoryPathBuf::<T>::new(*path).as_bytes(),
+                path.as_bytes()
+            );
+        }
+    }
+
+    #[test]
+    fn should_split_paths_into_files_and_directories() {
+        check::<UnixEncoding>(
+            &["a", "/a", "a/b", "./a", "a//b", "../a"],
+            &["", "/", ".", "..", "a/", "a/.", "a/..", "/a//"],
+        );
+        check::<WindowsEncoding>(
+            &["a", r"C:a", r"C:\a", r"\\server\share\a", r"\\?\C:\a\b"],
+            &[
+                "",
+                r"C:",
+                r"C:\",
+                r"a\",
+                r"a/",
+                r"\\server\share",
+                r"\\?\C:\a\",
+            ],
+        );
+        check::<Plan9Encoding>(&["#c/cons", "a/b"], &["#c", "#c/", "a/"]);
+    }
+}
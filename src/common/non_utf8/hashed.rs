@@ -0,0 +1,225 @@
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::ops::Deref;
+
+use crate::common::fnv::FnvHasher;
+use crate::{Encoding, Path, PathBuf};
+
+/// An owned [`PathBuf`] that computes its hash once on construction and reuses it afterwards.
+///
+/// Hashing a path parses it into components each time, which adds up when the same path is
+/// inserted into or looked up in many maps. This wrapper feeds the cached hash to hashers
+/// instead, and compares by cached hash before comparing the underlying bytes.
+///
+/// Because equality is by bytes, paths that differ only in ways [`Path`] equality ignores, such
+/// as repeated separators, are distinct as a `HashedPathBuf`. Normalize paths beforehand if they
+/// need to collapse into a single entry.
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::HashSet;
+/// use typed_path::{HashedPathBuf, UnixEncoding};
+///
+/// // NOTE: A path cannot be created on its own without a defined encoding
+/// let path = HashedPathBuf::<UnixEncoding>::from("/usr/lib/libc.so");
+///
+/// let mut set = HashSet::new();
+/// set.insert(path.clone());
+/// assert!(set.contains(&path));
+///
+/// // Derefs to the underlying path
+/// assert_eq!(path.file_name(), Some(b"libc.so".as_slice()));
+/// ```
+pub struct HashedPathBuf<T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    hash: u64,
+    path: PathBuf<T>,
+}
+
+impl<T> HashedPathBuf<T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    /// Wraps `path`, computing its hash.
+    pub fn new(path: PathBuf<T>) -> Self {
+        let mut hasher = FnvHasher::default();
+        path.hash(&mut hasher);
+
+        Self {
+            hash: hasher.finish(),
+            path,
+        }
+    }
+
+    /// Returns the hash computed when the path was wrapped.
+    ///
+    /// The value is stable for a given path and encoding within a build of this crate, but is
+    /// not meant to be persisted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{HashedPathBuf, UnixEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// let a = HashedPathBuf::<UnixEncoding>::from("/etc/hosts");
+    /// let b = HashedPathBuf::<UnixEncoding>::from("/etc//hosts");
+    ///
+    /// // The hash follows the components of the path
+    /// assert_eq!(a.cached_hash(), b.cached_hash());
+    /// ```
+    #[inline]
+    pub fn cached_hash(&self) -> u64 {
+        self.hash
+    }
+
+    /// Coerces to a [`Path`] slice.
+    #[inline]
+    pub fn as_path(&self) -> &Path<T> {
+        &self.path
+    }
+
+    /// Consumes the wrapper, yielding the inner [`PathBuf`].
+    #[inline]
+    pub fn into_path_buf(self) -> PathBuf<T> {
+        self.path
+    }
+}
+
+impl<T> Clone for HashedPathBuf<T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            hash: self.hash,
+            path: self.path.clone(),
+        }
+    }
+}
+
+impl<T> fmt::Debug for HashedPathBuf<T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("HashedPathBuf")
+            .field("hash", &self.hash)
+            .field("path", &self.path)
+            .finish()
+    }
+}
+
+impl<T> PartialEq for HashedPathBuf<T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.hash == other.hash && self.path.as_bytes() == other.path.as_bytes()
+    }
+}
+
+impl<T> Eq for HashedPathBuf<T> where T: for<'enc> Encoding<'enc> {}
+
+impl<T> Hash for HashedPathBuf<T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    #[inline]
+    fn hash<H: Hasher>(&self, h: &mut H) {
+        h.write_u64(self.hash)
+    }
+}
+
+impl<T> Deref for HashedPathBuf<T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    type Target = Path<T>;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.path
+    }
+}
+
+impl<T> AsRef<Path<T>> for HashedPathBuf<T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    #[inline]
+    fn as_ref(&self) -> &Path<T> {
+        &self.path
+    }
+}
+
+impl<T> From<PathBuf<T>> for HashedPathBuf<T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    #[inline]
+    fn from(path: PathBuf<T>) -> Self {
+        Self::new(path)
+    }
+}
+
+impl<T, V> From<&V> for HashedPathBuf<T>
+where
+    T: for<'enc> Encoding<'enc>,
+    V: ?Sized + AsRef<[u8]>,
+{
+    #[inline]
+    fn from(s: &V) -> Self {
+        Self::new(PathBuf::from(s))
+    }
+}
+
+impl<T> From<HashedPathBuf<T>> for PathBuf<T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    #[inline]
+    fn from(path: HashedPathBuf<T>) -> Self {
+        path.into_path_buf()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{UnixEncoding, WindowsEncoding};
+
+    #[test]
+    fn should_compare_by_bytes_and_hash_by_components() {
+        let a = HashedPathBuf::<UnixEncoding>::from("a/b");
+        let b = HashedPathBuf::<UnixEncoding>::from("a//b/");
+        let c = HashedPathBuf::<UnixEncoding>::from("a/c");
+
+        assert_eq!(a, a.clone());
+        assert_eq!(a.cached_hash(), b.cached_hash());
+        assert_ne!(a, b);
+        assert_ne!(a, c);
+    }
+
+    #[test]
+    fn hash_should_write_only_the_cached_hash() {
+        let path = HashedPathBuf::<WindowsEncoding>::from(r"C:\Windows\System32");
+
+        let mut expected = FnvHasher::default();
+        expected.write_u64(path.cached_hash());
+
+        let mut actual = FnvHasher::default();
+        path.hash(&mut actual);
+
+        assert_eq!(actual.finish(), expected.finish());
+        assert_eq!(
+            PathBuf::from(path),
+            PathBuf::<WindowsEncoding>::from(r"C:\Windows\System32")
+        );
+    }
+}
@@ -2,7 +2,8 @@ use core::fmt;
 use core::iter::FusedIterator;
 use core::marker::PhantomData;
 
-use crate::{Component, Components, Encoding, Path};
+use crate::common::remaining_path;
+use crate::{Component, Encoding, Path};
 
 /// An iterator over the [`Component`]s of a [`Path`], as [`[u8]`] slices.
 ///
@@ -44,8 +45,8 @@ where
     ///
     /// assert_eq!(Path::<UnixEncoding>::new("foo/bar.txt"), iter.as_path());
     /// ```
-    pub fn as_path(&self) -> &Path<T> {
-        Path::new(self.inner.as_bytes())
+    pub fn as_path(&self) -> &'a Path<T> {
+        Path::new(remaining_path(&self.inner))
     }
 }
 
@@ -148,6 +149,9 @@ where
     T: for<'enc> Encoding<'enc>,
 {
     pub(crate) next: Option<&'a Path<T>>,
+
+    /// Ancestor after which iteration stops, if any
+    pub(crate) until: Option<&'a Path<T>>,
 }
 
 impl<'a, T> Iterator for Ancestors<'a, T>
@@ -159,7 +163,10 @@ where
     #[inline]
     fn next(&mut self) -> Option<Self::Item> {
         let next = self.next;
-        self.next = next.and_then(Path::parent);
+        self.next = match (next, self.until) {
+            (Some(path), Some(until)) if path == until => None,
+            _ => next.and_then(Path::parent),
+        };
         next
     }
 }
@@ -0,0 +1,191 @@
+use core::marker::PhantomData;
+
+use crate::common::{varint, WireFormatError};
+use crate::no_std_compat::*;
+use crate::{Component, Components, Encoding, Path, PathBuf};
+
+/// Current version of the path list format, written as the first byte
+const VERSION: u8 = 1;
+
+/// Serializes lists of paths using shared-prefix compression.
+///
+/// Each path is stored as the number of leading components it shares with the path before it,
+/// followed by the remaining bytes of the path. Sorting the list beforehand groups paths that
+/// share parents, which typically makes file indexes several times smaller than storing each
+/// path in full. Paths are decoded back into exactly the bytes that were encoded.
+///
+/// The format consists of a version byte and the number of paths, followed by the shared
+/// component count, suffix length, and suffix bytes of each path. All counts and lengths are
+/// unsigned LEB128 integers.
+///
+/// # Examples
+///
+/// ```
+/// use typed_path::{PathListCodec, UnixEncoding, UnixPathBuf};
+///
+/// let paths = vec![
+///     UnixPathBuf::from("/usr/share/doc/git/README"),
+///     UnixPathBuf::from("/usr/share/doc/git/RelNotes"),
+///     UnixPathBuf::from("/usr/share/doc/gzip/NEWS"),
+/// ];
+///
+/// let bytes = PathListCodec::<UnixEncoding>::encode(&paths);
+/// assert!(bytes.len() < paths.iter().map(|p| p.as_bytes().len()).sum());
+///
+/// let decoded = PathListCodec::<UnixEncoding>::decode(&bytes).unwrap();
+/// assert_eq!(decoded, paths);
+/// ```
+pub struct PathListCodec<T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    _encoding: PhantomData<T>,
+}
+
+impl<T> PathListCodec<T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    /// Encodes `paths` in order, compressing the components each path shares with the path
+    /// before it.
+    ///
+    /// Any order is supported, but sorted paths compress best.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{PathListCodec, WindowsEncoding, WindowsPath};
+    ///
+    /// let bytes = PathListCodec::<WindowsEncoding>::encode([
+    ///     WindowsPath::new(r"C:\src\lib.rs"),
+    ///     WindowsPath::new(r"C:\src\main.rs"),
+    /// ]);
+    ///
+    /// // The second path only stores `main.rs` after sharing `C:`, `\`, and `src`
+    /// assert!(bytes.ends_with(b"\x03\x08\\main.rs"));
+    /// ```
+    pub fn encode<I, P>(paths: I) -> Vec<u8>
+    where
+        I: IntoIterator<Item = P>,
+        P: AsRef<Path<T>>,
+    {
+        let mut body = Vec::new();
+        let mut count = 0;
+        let mut prev: Vec<u8> = Vec::new();
+        let mut prev_ends: Vec<usize> = Vec::new();
+
+        for path in paths {
+            let path = path.as_ref();
+            let bytes = path.as_bytes();
+            let ends = component_ends(path);
+
+            // Share components only while the bytes leading up to them are identical, so the
+            // original bytes (including any redundant separators) are restored exactly
+            let shared = prev_ends
+                .iter()
+                .zip(ends.iter())
+                .take_while(|(&a, &b)| prev[..a] == bytes[..b])
+                .count();
+            let start = if shared == 0 { 0 } else { ends[shared - 1] };
+
+            varint::write(shared, &mut body);
+            varint::write(bytes.len() - start, &mut body);
+            body.extend_from_slice(&bytes[start..]);
+
+            prev.clear();
+            prev.extend_from_slice(bytes);
+            prev_ends = ends;
+            count += 1;
+        }
+
+        let mut out = Vec::with_capacity(body.len() + 4);
+        out.push(VERSION);
+        varint::write(count, &mut out);
+        out.extend(body);
+        out
+    }
+
+    /// Decodes paths produced by [`PathListCodec::encode`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`WireFormatError`] if the bytes are not a list of paths in the expected format.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{PathListCodec, UnixEncoding, WireFormatError};
+    ///
+    /// let bytes = PathListCodec::<UnixEncoding>::encode(["/etc/hosts", "/etc/passwd"]);
+    /// assert_eq!(PathListCodec::<UnixEncoding>::decode(&bytes).unwrap().len(), 2);
+    ///
+    /// assert_eq!(
+    ///     PathListCodec::<UnixEncoding>::decode(&bytes[..bytes.len() - 1]),
+    ///     Err(WireFormatError::UnexpectedEnd),
+    /// );
+    /// ```
+    pub fn decode(bytes: &[u8]) -> Result<Vec<PathBuf<T>>, WireFormatError> {
+        let (&version, input) = bytes.split_first().ok_or(WireFormatError::UnexpectedEnd)?;
+        if version != VERSION {
+            return Err(WireFormatError::UnsupportedVersion(version));
+        }
+
+        let (count, mut input) = varint::read(input)?;
+
+        // Every path takes at least two bytes, which bounds the allocation for malformed input
+        let mut paths: Vec<PathBuf<T>> = Vec::with_capacity(count.min(input.len() / 2));
+        let mut prev_ends: Vec<usize> = Vec::new();
+
+        for _ in 0..count {
+            let (shared, rest) = varint::read(input)?;
+            let (len, rest) = varint::read(rest)?;
+            let (suffix, rest) = varint::read_bytes(rest, len)?;
+            input = rest;
+
+            if shared > prev_ends.len() {
+                return Err(WireFormatError::InvalidSharedPrefix);
+            }
+
+            let start = if shared == 0 {
+                0
+            } else {
+                prev_ends[shared - 1]
+            };
+            let mut buf = match paths.last() {
+                Some(prev) => prev.as_bytes()[..start].to_vec(),
+                None => Vec::new(),
+            };
+            buf.extend_from_slice(suffix);
+
+            let path = PathBuf::from(buf);
+            prev_ends = component_ends(&path);
+            paths.push(path);
+        }
+
+        if !input.is_empty() {
+            return Err(WireFormatError::TrailingBytes);
+        }
+
+        Ok(paths)
+    }
+}
+
+/// Returns the offset just past each component of `path`
+fn component_ends<T>(path: &Path<T>) -> Vec<usize>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    let len = path.as_bytes().len();
+    let mut components = path.components();
+    let mut ends = Vec::new();
+
+    loop {
+        let start = len - components.as_bytes().len();
+        match components.next() {
+            Some(component) => ends.push(start + component.len()),
+            None => break,
+        }
+    }
+
+    ends
+}
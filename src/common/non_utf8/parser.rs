@@ -18,6 +18,35 @@ macro_rules! any_of {
     };
 }
 
+/// Checks, in debug builds, the invariants every component parser upholds so that fuzzing can
+/// catch a parser that breaks them: parsing a component consumes at least one byte, the remaining
+/// input is what is left of `before` after removing bytes from the `front` or the back, and the
+/// component fits within the consumed bytes
+#[inline]
+pub fn debug_assert_consumed(
+    before: ParseInput,
+    after: ParseInput,
+    component_len: usize,
+    front: bool,
+) {
+    debug_assert!(
+        after.len() < before.len(),
+        "parsing a component consumed nothing"
+    );
+    debug_assert!(
+        if front {
+            before.ends_with(after)
+        } else {
+            before.starts_with(after)
+        },
+        "parsing a component left input that was not part of the original"
+    );
+    debug_assert!(
+        component_len <= before.len() - after.len(),
+        "parsed component is longer than the bytes consumed"
+    );
+}
+
 /// Succeeds if input is empty, otherwise fails
 pub fn empty(input: ParseInput) -> ParseResult<()> {
     if input.is_empty() {
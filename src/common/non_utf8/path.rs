@@ -1,17 +1,31 @@
 mod display;
+mod escaped_display;
+mod home_display;
 
 use alloc::borrow::{Cow, ToOwned};
 use alloc::rc::Rc;
 use alloc::sync::Arc;
 use core::hash::{Hash, Hasher};
 use core::marker::PhantomData;
+use core::ops::{Bound, Range};
 use core::{cmp, fmt};
 
 pub use display::Display;
-
+pub use escaped_display::EscapedDisplay;
+pub use home_display::HomeDisplay;
+
+use super::cow::is_normalized;
+use crate::common::mime;
+use crate::common::options::{comparable_component_bytes, RawComponent};
+use crate::common::sort_key;
+use crate::common::truncate::{abbreviate, display_width, truncate_name};
 use crate::no_std_compat::*;
 use crate::{
-    Ancestors, CheckedPathError, Component, Components, Encoding, Iter, PathBuf, StripPrefixError,
+    Ancestors, CheckedPathError, Component, ComponentKind, Components, CowPath,
+    DifferentPrefixError, Encoding, FilesystemProfile, IllegalCharReason, Iter, LossyPolicy,
+    NormalizationOptions, PathBuf, PathEquivalence, ProfileError, PushMode, SegmentsWithSeparators,
+    StrictPathError, StripPrefixError, StripPrefixHint, SuggestOptions, UniquifyOptions,
+    Utf8Encoding, Utf8PathBuf, Utf8PathError, ValidatedComponents, WindowsEncoding,
 };
 
 /// A slice of a path (akin to [`str`]).
@@ -71,6 +85,40 @@ use crate::{
 /// let path = NativePath::new("/foo/bar.txt");
 /// ```
 ///
+/// # Empty paths
+///
+/// The empty path, available as [`Path::EMPTY`], is a relative path with no components that
+/// behaves the same with every encoding:
+///
+/// * it has no [`parent`], [`file_name`], [`file_stem`], or [`extension`], and its only ancestor
+///   is itself
+/// * joining a path onto it yields that path, and joining it onto a path leaves that path as it
+///   is; unlike `std::path`, no trailing separator is added
+/// * it [`starts_with`] and [`ends_with`] itself, and every path starts with it
+/// * [`normalize`] leaves it unchanged, while [`clean`] turns it into `.`
+///
+/// ```
+/// use typed_path::{Path, UnixEncoding};
+///
+/// // NOTE: A path cannot be created on its own without a defined encoding
+/// let empty = Path::<UnixEncoding>::EMPTY;
+/// assert!(empty.is_empty());
+/// assert_eq!(empty.parent(), None);
+/// assert_eq!(empty.file_name(), None);
+/// assert_eq!(empty.components().count(), 0);
+/// assert_eq!(empty.join("foo"), Path::new("foo"));
+/// assert_eq!(Path::<UnixEncoding>::new("foo").join(empty), Path::new("foo"));
+/// ```
+///
+/// [`parent`]: Path::parent
+/// [`file_name`]: Path::file_name
+/// [`file_stem`]: Path::file_stem
+/// [`extension`]: Path::extension
+/// [`starts_with`]: Path::starts_with
+/// [`ends_with`]: Path::ends_with
+/// [`normalize`]: Path::normalize
+/// [`clean`]: Path::clean
+///
 /// [`NativePath`]: crate::NativePath
 #[repr(transparent)]
 pub struct Path<T>
@@ -84,6 +132,25 @@ where
     pub(crate) inner: [u8],
 }
 
+impl<T> Path<T>
+where
+    T: for<'enc> Encoding<'enc> + 'static,
+{
+    /// The empty path, which has no components.
+    ///
+    /// See [the type-level documentation](Path#empty-paths) for how it behaves.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Path, UnixEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// assert_eq!(Path::<UnixEncoding>::EMPTY, Path::new(""));
+    /// ```
+    pub const EMPTY: &'static Self = unsafe { &*(&[] as *const [u8] as *const Self) };
+}
+
 impl<T> Path<T>
 where
     T: for<'enc> Encoding<'enc>,
@@ -128,6 +195,34 @@ where
         unsafe { &*(s.as_ref() as *const [u8] as *const Self) }
     }
 
+    /// Like [`Path::new`] for a byte slice, but usable in `const` contexts.
+    #[inline]
+    pub(crate) const fn from_bytes_const(bytes: &[u8]) -> &Self {
+        unsafe { &*(bytes as *const [u8] as *const Self) }
+    }
+
+    /// Like [`Path::new`], but only succeeds if the path passes [`Path::validate_strict`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Path, StrictPathError, WindowsEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// let path = Path::<WindowsEncoding>::try_new_strict(r"C:\logs\today.txt").unwrap();
+    /// assert_eq!(path, Path::new(r"C:\logs\today.txt"));
+    ///
+    /// assert_eq!(
+    ///     Path::<WindowsEncoding>::try_new_strict(r"C:\logs\to:day.txt"),
+    ///     Err(StrictPathError::InvalidByte { index: 10, byte: b':' }),
+    /// );
+    /// ```
+    pub fn try_new_strict<S: AsRef<[u8]> + ?Sized>(s: &S) -> Result<&Self, StrictPathError> {
+        let path = Self::new(s);
+        path.validate_strict()?;
+        Ok(path)
+    }
+
     /// Yields the underlying [`[u8]`] slice.
     ///
     /// # Examples
@@ -191,6 +286,49 @@ where
         String::from_utf8_lossy(&self.inner)
     }
 
+    /// Converts a `Path` to an owned [`Utf8PathBuf`], handling any non-Unicode sequences
+    /// according to `policy`.
+    ///
+    /// With the default [`LossyPolicy`], this matches [`Path::to_string_lossy`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Utf8PathError`] if the path is not valid UTF-8 and `policy` is
+    /// [`LossyPolicy::Error`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{LossyPolicy, Path, UnixEncoding, Utf8UnixEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// let path = Path::<UnixEncoding>::new(b"/tmp/fo\xf0\x90\x80.txt");
+    ///
+    /// let utf8 = path.to_utf8_lossy_with::<Utf8UnixEncoding>(LossyPolicy::default()).unwrap();
+    /// assert_eq!(utf8.as_str(), "/tmp/fo\u{FFFD}.txt");
+    ///
+    /// let utf8 = path.to_utf8_lossy_with::<Utf8UnixEncoding>(LossyPolicy::PercentEncode).unwrap();
+    /// assert_eq!(utf8.as_str(), "/tmp/fo%F0%90%80.txt");
+    ///
+    /// let err = path.to_utf8_lossy_with::<Utf8UnixEncoding>(LossyPolicy::Error).unwrap_err();
+    /// assert_eq!(err.valid_up_to(), 7);
+    /// ```
+    pub fn to_utf8_lossy_with<U>(
+        &self,
+        policy: LossyPolicy,
+    ) -> Result<Utf8PathBuf<U>, Utf8PathError>
+    where
+        U: for<'enc> Utf8Encoding<'enc>,
+    {
+        match policy.decode(&self.inner) {
+            Ok(s) => Ok(Utf8PathBuf::from(s.into_owned())),
+            Err(error) => Err(Utf8PathError {
+                bytes: self.inner.to_vec(),
+                error,
+            }),
+        }
+    }
+
     /// Converts a `Path` to an owned [`PathBuf`].
     ///
     /// # Examples
@@ -209,6 +347,24 @@ where
         }
     }
 
+    /// Returns `true` if the path is empty, meaning it has no bytes at all.
+    ///
+    /// A path like `.` or `./` is not empty even though it has no components.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Path, UnixEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// assert!(Path::<UnixEncoding>::new("").is_empty());
+    /// assert!(!Path::<UnixEncoding>::new(".").is_empty());
+    /// ```
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+
     /// Returns `true` if the `Path` is absolute, i.e., if it is independent of
     /// the current directory.
     ///
@@ -273,6 +429,221 @@ where
         self.components().all(|c| c.is_valid())
     }
 
+    /// Checks the path against the rules of its encoding, returning the first violation found.
+    ///
+    /// * every normal component must be free of the bytes the encoding disallows in filenames
+    /// * every prefix must have the parts its kind requires, such as both the server and the
+    ///   share of a Windows UNC prefix
+    /// * no NUL byte may appear anywhere, including within a prefix
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Path, StrictPathError, UnixEncoding, WindowsEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// assert_eq!(Path::<UnixEncoding>::new("/tmp/a:b").validate_strict(), Ok(()));
+    /// assert_eq!(
+    ///     Path::<UnixEncoding>::new("/tmp/a\0b").validate_strict(),
+    ///     Err(StrictPathError::InvalidByte { index: 6, byte: b'\0' }),
+    /// );
+    ///
+    /// assert_eq!(
+    ///     Path::<WindowsEncoding>::new(r"\\server\share\a").validate_strict(),
+    ///     Ok(()),
+    /// );
+    /// assert_eq!(
+    ///     Path::<WindowsEncoding>::new(r"\\server").validate_strict(),
+    ///     Err(StrictPathError::MalformedPrefix),
+    /// );
+    /// ```
+    pub fn validate_strict(&self) -> Result<(), StrictPathError> {
+        let path = self.as_bytes();
+
+        for component in self.components() {
+            match component.kind() {
+                ComponentKind::Prefix if !T::is_valid_prefix(component.as_bytes()) => {
+                    return Err(StrictPathError::MalformedPrefix);
+                }
+                ComponentKind::Normal => {
+                    // Normal components always borrow from the path being iterated
+                    let bytes = component.as_bytes();
+                    let start = bytes.as_ptr() as usize - path.as_ptr() as usize;
+
+                    if let Some(i) = bytes
+                        .iter()
+                        .position(|b| T::DISALLOWED_FILENAME_BYTES.contains(b))
+                    {
+                        return Err(StrictPathError::InvalidByte {
+                            index: start + i,
+                            byte: bytes[i],
+                        });
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        match path.iter().position(|b| *b == b'\0') {
+            Some(index) => Err(StrictPathError::InvalidByte { index, byte: b'\0' }),
+            None => Ok(()),
+        }
+    }
+
+    /// Returns `true` if the path satisfies the restrictions of `profile`.
+    ///
+    /// See [`Path::validate_for`] for more details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{FilesystemProfile, Path, UnixEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// let profile = FilesystemProfile::posix_portable();
+    /// assert!(Path::<UnixEncoding>::new("/tmp/foo.txt").is_valid_for(&profile));
+    /// assert!(!Path::<UnixEncoding>::new("/tmp/foo bar.txt").is_valid_for(&profile));
+    /// ```
+    pub fn is_valid_for(&self, profile: &FilesystemProfile) -> bool {
+        self.validate_for(profile).is_ok()
+    }
+
+    /// Checks the path against the restrictions of `profile`, returning the first violation
+    /// found.
+    ///
+    /// Each normal component is checked by [`FilesystemProfile::check_component`] before the
+    /// length of the entire path is checked. Prefix, root, current, and parent directory
+    /// components are not checked against the character set of the profile.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{FilesystemProfile, Path, ProfileError, WindowsEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// let profile = FilesystemProfile::posix_portable();
+    /// assert_eq!(Path::<WindowsEncoding>::new(r"C:\..\foo.txt").validate_for(&profile), Ok(()));
+    /// assert_eq!(
+    ///     Path::<WindowsEncoding>::new(r"C:\foo+bar").validate_for(&profile),
+    ///     Err(ProfileError::DisallowedByte(b'+')),
+    /// );
+    /// ```
+    pub fn validate_for(&self, profile: &FilesystemProfile) -> Result<(), ProfileError> {
+        for component in self.components() {
+            if component.is_normal() {
+                profile.check_component(component.as_bytes())?;
+            }
+        }
+
+        profile.check_path_len(self.as_bytes().len())
+    }
+
+    /// Produces an iterator over the [`Component`]s of the path that reports every normal
+    /// component violating its encoding or the restrictions of `profile`, rather than stopping at
+    /// the first one like [`Path::validate_for`].
+    ///
+    /// Each component that fails is replaced by a [`ComponentError`] describing its first problem
+    /// alongside the range of bytes it occupies within the path. The length of the entire path is
+    /// not checked.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{FilesystemProfile, Path, ProfileError, WindowsEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// let path = Path::<WindowsEncoding>::new(r"C:\docs\nul.txt\a?b\ok.txt");
+    /// let errors: Vec<_> = path
+    ///     .validated_components(&FilesystemProfile::windows())
+    ///     .filter_map(Result::err)
+    ///     .map(|e| (e.span(), e.kind().clone()))
+    ///     .collect();
+    ///
+    /// assert_eq!(
+    ///     errors,
+    ///     [
+    ///         (8..15, ProfileError::ReservedName),
+    ///         (16..19, ProfileError::InvalidByte(b'?')),
+    ///     ],
+    /// );
+    /// ```
+    ///
+    /// [`ComponentError`]: crate::ComponentError
+    pub fn validated_components(&self, profile: &FilesystemProfile) -> ValidatedComponents<'_, T> {
+        ValidatedComponents::new(self.as_bytes(), profile)
+    }
+
+    /// Finds every byte within the normal components of the path that is not allowed by its
+    /// encoding or by `profile`, returning the position of each byte within the path alongside
+    /// why it was reported.
+    ///
+    /// A control character that is not allowed, or that the profile warns about or rejects, is
+    /// reported as [`IllegalCharReason::Control`]. Bytes that are not valid UTF-8 are only
+    /// reported when the profile requires UTF-8.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{FilesystemProfile, IllegalCharReason, Path, WindowsEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// let path = Path::<WindowsEncoding>::new(b"C:\\a*b\\c\0\\\xff.txt");
+    /// let illegal: Vec<_> = path.find_illegal_chars(&FilesystemProfile::windows()).collect();
+    ///
+    /// assert_eq!(
+    ///     illegal,
+    ///     [
+    ///         (4, b'*', IllegalCharReason::Reserved),
+    ///         (8, b'\0', IllegalCharReason::Control),
+    ///         (10, 0xff, IllegalCharReason::NonUtf8),
+    ///     ],
+    /// );
+    /// ```
+    ///
+    /// [`IllegalCharReason::Control`]: crate::IllegalCharReason::Control
+    pub fn find_illegal_chars(
+        &self,
+        profile: &FilesystemProfile,
+    ) -> impl Iterator<Item = (usize, u8, IllegalCharReason)> {
+        let mut illegal = Vec::new();
+        for component in self.components() {
+            if component.is_normal() {
+                let bytes = component.as_bytes();
+                let offset = bytes.as_ptr() as usize - self.as_bytes().as_ptr() as usize;
+                profile.find_illegal_bytes(
+                    bytes,
+                    T::DISALLOWED_FILENAME_BYTES,
+                    offset,
+                    &mut illegal,
+                );
+            }
+        }
+
+        illegal.into_iter()
+    }
+
+    /// Returns `true` if any byte of the path is an ascii control character, such as `\n`, `\t`,
+    /// or `\0`.
+    ///
+    /// This scans the path once without parsing it, making it a cheap check before rejecting a
+    /// path or validating it further with a [`ControlCharPolicy`].
+    ///
+    /// [`ControlCharPolicy`]: crate::ControlCharPolicy
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Path, UnixEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// assert!(Path::<UnixEncoding>::new("/tmp/evil\nname").contains_control_bytes());
+    /// assert!(!Path::<UnixEncoding>::new("/tmp/plain name").contains_control_bytes());
+    /// ```
+    #[inline]
+    pub fn contains_control_bytes(&self) -> bool {
+        self.as_bytes().iter().any(u8::is_ascii_control)
+    }
+
     /// Returns `true` if the `Path` has a root.
     ///
     /// * On Unix ([`UnixPath`]), a path has a root if it begins with `/`.
@@ -298,6 +669,134 @@ where
         self.components().has_root()
     }
 
+    /// Returns the prefix and root at the start of the path, if it has either, such as `/`, `C:\`,
+    /// or `\\server\share\`.
+    ///
+    /// Any separators that follow the root are included. A prefix without a root, such as `C:` in
+    /// `C:foo`, is returned on its own.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Path, UnixEncoding, WindowsEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// assert_eq!(Path::<UnixEncoding>::new("/etc/passwd").root(), Some(Path::new("/")));
+    /// assert_eq!(Path::<UnixEncoding>::new("etc/passwd").root(), None);
+    ///
+    /// let path = Path::<WindowsEncoding>::new(r"\\server\share\dir");
+    /// assert_eq!(path.root(), Some(Path::new(r"\\server\share\")));
+    /// assert_eq!(Path::<WindowsEncoding>::new(r"C:dir").root(), Some(Path::new("C:")));
+    /// ```
+    pub fn root(&self) -> Option<&Self> {
+        self.split_root().0
+    }
+
+    /// Splits the path into its prefix and root, as returned by [`Path::root`], and the relative
+    /// remainder of the path.
+    ///
+    /// Joining the two pieces back together always produces the original path.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Path, UnixEncoding, WindowsEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// let (root, rest) = Path::<UnixEncoding>::new("//usr/lib/").split_root();
+    /// assert_eq!(root, Some(Path::new("//")));
+    /// assert_eq!(rest, Path::new("usr/lib/"));
+    ///
+    /// let (root, rest) = Path::<WindowsEncoding>::new(r"C:\Users\me").split_root();
+    /// assert_eq!(root, Some(Path::new(r"C:\")));
+    /// assert_eq!(rest, Path::new(r"Users\me"));
+    ///
+    /// let (root, rest) = Path::<UnixEncoding>::new("./a").split_root();
+    /// assert_eq!(root, None);
+    /// assert_eq!(rest, Path::new("./a"));
+    /// ```
+    pub fn split_root(&self) -> (Option<&Self>, &Self) {
+        let bytes = self.as_bytes();
+        let mut prefix_len = 0;
+        let mut has_root = false;
+        for component in self.components() {
+            if component.is_normal() || component.is_current() || component.is_parent() {
+                break;
+            }
+
+            // Anything else is either a root directory, which is a lone separator, or a prefix,
+            // which may also count as a root
+            match component.as_bytes() {
+                [b] if T::is_separator(*b) => has_root = true,
+                prefix => {
+                    prefix_len = prefix.len();
+                    has_root |= component.is_root();
+                }
+            }
+        }
+
+        let mut end = prefix_len;
+        if has_root {
+            end += bytes[prefix_len..]
+                .iter()
+                .take_while(|b| T::is_separator(**b))
+                .count();
+        }
+
+        if end == 0 {
+            (None, self)
+        } else {
+            (Some(Self::new(&bytes[..end])), Self::new(&bytes[end..]))
+        }
+    }
+
+    /// Returns `true` if the path is made up of nothing more than a root, such as `/`, `C:\`, or
+    /// `\\server\share`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Path, UnixEncoding, WindowsEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// assert!(Path::<UnixEncoding>::new("/").is_root());
+    /// assert!(!Path::<UnixEncoding>::new("/etc").is_root());
+    ///
+    /// assert!(Path::<WindowsEncoding>::new(r"C:\").is_root());
+    /// assert!(Path::<WindowsEncoding>::new(r"\\server\share").is_root());
+    /// assert!(!Path::<WindowsEncoding>::new("C:").is_root());
+    /// ```
+    pub fn is_root(&self) -> bool {
+        self.has_root()
+            && self
+                .components()
+                .all(|c| !(c.is_normal() || c.is_current() || c.is_parent()))
+    }
+
+    /// Returns `true` if the path is made up of nothing more than a prefix without a root, such as
+    /// `C:`, which refers to the current directory of a drive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Path, UnixEncoding, WindowsEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// assert!(Path::<WindowsEncoding>::new("C:").is_prefix_only());
+    /// assert!(!Path::<WindowsEncoding>::new(r"C:\").is_prefix_only());
+    /// assert!(!Path::<WindowsEncoding>::new("C:foo").is_prefix_only());
+    /// assert!(!Path::<UnixEncoding>::new("").is_prefix_only());
+    /// ```
+    pub fn is_prefix_only(&self) -> bool {
+        let mut components = self.components();
+        !self.has_root()
+            && components
+                .next()
+                .map(|c| !(c.is_normal() || c.is_current() || c.is_parent()))
+                .unwrap_or(false)
+            && components.next().is_none()
+    }
+
     /// Returns the `Path` without its final component, if there is one.
     ///
     /// Returns [`None`] if the path terminates in a root or prefix.
@@ -360,7 +859,69 @@ where
     /// [`parent`]: Path::parent
     #[inline]
     pub fn ancestors(&self) -> Ancestors<T> {
-        Ancestors { next: Some(self) }
+        Ancestors {
+            next: Some(self),
+            until: None,
+        }
+    }
+
+    /// Produces an iterator over `Path` and its ancestors that stops after yielding `base`.
+    ///
+    /// This behaves the same as [`Path::ancestors`], except that iteration ends once an ancestor
+    /// equal to `base` has been yielded. If `base` is not an ancestor of `self`, all ancestors
+    /// are yielded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Path, UnixEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// let path = Path::<UnixEncoding>::new("/home/user/project/src/bin");
+    ///
+    /// let mut ancestors = path.ancestors_until("/home/user/project");
+    /// assert_eq!(ancestors.next(), Some(Path::new("/home/user/project/src/bin")));
+    /// assert_eq!(ancestors.next(), Some(Path::new("/home/user/project/src")));
+    /// assert_eq!(ancestors.next(), Some(Path::new("/home/user/project")));
+    /// assert_eq!(ancestors.next(), None);
+    ///
+    /// // Walking up to find a project root marker
+    /// let root = path
+    ///     .ancestors_until("/home/user")
+    ///     .find(|p| p.file_name() == Some(b"project"));
+    /// assert_eq!(root, Some(Path::new("/home/user/project")));
+    ///
+    /// assert_eq!(path.ancestors_until("/srv").count(), path.ancestors().count());
+    /// ```
+    pub fn ancestors_until<P: AsRef<Path<T>>>(&self, base: P) -> Ancestors<'_, T> {
+        let base = base.as_ref();
+        Ancestors {
+            next: Some(self),
+            until: self.ancestors().find(|p| *p == base),
+        }
+    }
+
+    /// Returns the `n`th ancestor of the path, or [`None`] if the path has fewer than `n`
+    /// parents.
+    ///
+    /// Calling `nth_parent(0)` returns the path itself, and `nth_parent(1)` is the same as
+    /// [`Path::parent`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Path, UnixEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// let path = Path::<UnixEncoding>::new("/foo/bar/baz");
+    ///
+    /// assert_eq!(path.nth_parent(0), Some(path));
+    /// assert_eq!(path.nth_parent(2), Some(Path::new("/foo")));
+    /// assert_eq!(path.nth_parent(3), Some(Path::new("/")));
+    /// assert_eq!(path.nth_parent(4), None);
+    /// ```
+    pub fn nth_parent(&self, n: usize) -> Option<&Self> {
+        self.ancestors().nth(n)
     }
 
     /// Returns the final component of the `Path`, if there is one.
@@ -435,15 +996,223 @@ where
     fn _strip_prefix(&self, base: &Path<T>) -> Result<&Path<T>, StripPrefixError> {
         match helpers::iter_after(self.components(), base.components()) {
             Some(c) => Ok(Path::new(c.as_bytes())),
-            None => Err(StripPrefixError(())),
+            None => Err(StripPrefixError {
+                hint: StripPrefixHint::ALL
+                    .into_iter()
+                    .find(|hint| self.relaxed_strip_prefix(base, *hint).is_some()),
+            }),
         }
     }
 
-    /// Determines whether `base` is a prefix of `self`.
+    /// Like [`Path::strip_prefix`], but compares components the relaxed way described by `hint`,
+    /// as suggested by [`StripPrefixError::would_match_if`].
     ///
-    /// Only considers whole path components to match.
+    /// The remainder is owned, as it is taken from the normalized form of `self` when `hint`
+    /// [normalizes](StripPrefixHint::normalizes).
     ///
-    /// # Examples
+    /// # Errors
+    ///
+    /// If `base` is not a prefix of `self` even under `hint`, returns [`Err`] holding any more
+    /// relaxed hint under which it would be.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{StripPrefixHint, WindowsPath, WindowsPathBuf};
+    ///
+    /// let path = WindowsPath::new(r"\\server\share\reports\q1.csv");
+    /// let base = WindowsPath::new(r"//SERVER/share/");
+    ///
+    /// let err = path.strip_prefix(base).unwrap_err();
+    /// assert_eq!(err.would_match_if(), Some(StripPrefixHint::IgnoreCaseAndNormalize));
+    ///
+    /// let err = path.strip_prefix_with(base, StripPrefixHint::IgnoreCase).unwrap_err();
+    /// assert_eq!(err.would_match_if(), Some(StripPrefixHint::IgnoreCaseAndNormalize));
+    ///
+    /// assert_eq!(
+    ///     path.strip_prefix_with(base, StripPrefixHint::IgnoreCaseAndNormalize),
+    ///     Ok(WindowsPathBuf::from(r"reports\q1.csv")),
+    /// );
+    /// ```
+    ///
+    /// [`StripPrefixError::would_match_if`]: crate::StripPrefixError::would_match_if
+    pub fn strip_prefix_with<P>(
+        &self,
+        base: P,
+        hint: StripPrefixHint,
+    ) -> Result<PathBuf<T>, StripPrefixError>
+    where
+        P: AsRef<Path<T>>,
+    {
+        let base = base.as_ref();
+        self.relaxed_strip_prefix(base, hint)
+            .ok_or_else(|| StripPrefixError {
+                hint: StripPrefixHint::ALL
+                    .into_iter()
+                    .skip_while(|h| *h != hint)
+                    .find(|h| self.relaxed_strip_prefix(base, *h).is_some()),
+            })
+    }
+
+    fn relaxed_strip_prefix(&self, base: &Path<T>, hint: StripPrefixHint) -> Option<PathBuf<T>> {
+        let has_parent = |path: &Path<T>| path.components().any(|c| c.is_parent());
+
+        // Normalizing only matters for `..`, as `.` and separators are handled while comparing
+        let normalized;
+        let (path, base) = if hint.normalizes() && (has_parent(self) || has_parent(base)) {
+            let options = NormalizationOptions::new().preserve_leading_parent(true);
+            normalized = (self.normalize_with(&options), base.normalize_with(&options));
+            (normalized.0.as_path(), normalized.1.as_path())
+        } else {
+            (self, base)
+        };
+
+        let skip = hint.normalizes();
+        let mut components = path.components();
+        for b in base.components().filter(|c| !(skip && c.is_current())) {
+            let a = loop {
+                match components.next() {
+                    Some(c) if skip && c.is_current() => continue,
+                    c => break c?,
+                }
+            };
+
+            if !hint.bytes_eq(a.as_bytes(), b.as_bytes(), T::is_separator) {
+                return None;
+            }
+        }
+
+        Some(Path::new(components.as_bytes()).to_path_buf())
+    }
+
+    /// Returns a relative path that, when joined onto `base`, refers to the same location as
+    /// `self`.
+    ///
+    /// Both paths are [`cleaned`] first, after which `..` components are added for each component
+    /// of `base` that is not shared with `self`. Components are compared using the case rules of
+    /// the encoding, as with [`Path::is_ancestor_of`]. If both paths are the same, the result is
+    /// empty.
+    ///
+    /// [`cleaned`]: Path::clean
+    ///
+    /// # Errors
+    ///
+    /// If `self` and `base` begin with different anchors, meaning their prefix, root, or leading
+    /// `..` components differ, returns a [`DifferentPrefixError`] carrying both anchors. This is
+    /// the case for paths on different Windows drives, for an absolute and a relative path, and
+    /// for a relative path that escapes further above its starting point than the other.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Path, UnixEncoding, WindowsEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// let path = Path::<UnixEncoding>::new("/usr/share/doc");
+    ///
+    /// assert_eq!(path.relative_to("/usr/lib").unwrap().as_bytes(), b"../share/doc");
+    /// assert_eq!(path.relative_to("/usr").unwrap().as_bytes(), b"share/doc");
+    /// assert_eq!(path.relative_to("/usr/share/doc/rust").unwrap().as_bytes(), b"..");
+    /// assert!(path.relative_to("usr").is_err());
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// let path = Path::<WindowsEncoding>::new(r"C:\Users\Public");
+    ///
+    /// assert_eq!(path.relative_to(r"c:\users\me").unwrap().as_bytes(), br"..\Public");
+    ///
+    /// let err = path.relative_to(r"D:\Users").unwrap_err();
+    /// assert_eq!(err.prefix(), br"C:\");
+    /// assert_eq!(err.base_prefix(), br"D:\");
+    /// ```
+    pub fn relative_to<P>(&self, base: P) -> Result<PathBuf<T>, DifferentPrefixError>
+    where
+        P: AsRef<Path<T>>,
+    {
+        self._relative_to(base.as_ref())
+    }
+
+    fn _relative_to(&self, base: &Path<T>) -> Result<PathBuf<T>, DifferentPrefixError> {
+        let windows = self.has_windows_encoding();
+        let case_sensitive = !windows;
+        let eq = |a: &[u8], b: &[u8]| a == b || !case_sensitive && a.eq_ignore_ascii_case(b);
+
+        let path = self.clean();
+        let base = base.clean();
+        let (path_anchor, path_normals) = path.split_relative_anchor();
+        let (base_anchor, base_normals) = base.split_relative_anchor();
+
+        let same_anchor = {
+            let mut a = Path::<T>::new(path_anchor).components();
+            let mut b = Path::<T>::new(base_anchor).components();
+            loop {
+                match (a.next(), b.next()) {
+                    (Some(x), Some(y)) => {
+                        // Separators within a Windows prefix are as insignificant as anywhere else
+                        let x = comparable_component_bytes(x.kind(), x.as_bytes(), windows);
+                        let y = comparable_component_bytes(y.kind(), y.as_bytes(), windows);
+                        if !(x == y || windows && x.eq_ignore_ascii_case(&y)) {
+                            break false;
+                        }
+                    }
+                    (None, None) => break true,
+                    _ => break false,
+                }
+            }
+        };
+
+        if !same_anchor {
+            return Err(DifferentPrefixError {
+                prefix: path_anchor.to_vec(),
+                base_prefix: base_anchor.to_vec(),
+            });
+        }
+
+        let common = path_normals
+            .iter()
+            .zip(base_normals.iter())
+            .take_while(|(a, b)| eq(a, b))
+            .count();
+
+        let parent =
+            <<<T as Encoding>::Components as Components>::Component as Component>::parent();
+        let mut relative = PathBuf::new();
+        for _ in common..base_normals.len() {
+            relative.push(parent.as_bytes());
+        }
+        for normal in &path_normals[common..] {
+            relative.push(normal);
+        }
+
+        Ok(relative)
+    }
+
+    /// Splits a cleaned path into its anchor, consisting of any prefix, root, and leading `..`
+    /// components, and the normal components that follow it
+    fn split_relative_anchor(&self) -> (&[u8], Vec<&[u8]>) {
+        let mut components = self.components();
+        let mut anchor_len = 0;
+        let mut normals = Vec::new();
+
+        loop {
+            let rest = components.as_bytes();
+            match components.next() {
+                Some(component) if component.is_normal() => normals.push(component.as_bytes()),
+                Some(component) if normals.is_empty() && !component.is_current() => {
+                    anchor_len = self.inner.len() - rest.len() + component.len();
+                }
+                Some(_) => {}
+                None => break,
+            }
+        }
+
+        (&self.inner[..anchor_len], normals)
+    }
+
+    /// Determines whether `base` is a prefix of `self`.
+    ///
+    /// Only considers whole path components to match.
+    ///
+    /// # Examples
     ///
     /// ```
     /// use typed_path::{Path, UnixEncoding};
@@ -503,16 +1272,41 @@ where
         helpers::iter_after(self.components().rev(), child.components().rev()).is_some()
     }
 
-    /// Extracts the stem (non-extension) portion of [`self.file_name`].
+    /// Determines whether `self` is a strict ancestor of `other`, meaning that `other` begins
+    /// with all of the components of `self` followed by at least one more component.
     ///
-    /// [`self.file_name`]: Path::file_name
+    /// Components are compared whole, using the case rules of the encoding: Unix paths are
+    /// compared case-sensitively, whereas Windows paths (including their prefixes) are compared
+    /// ascii case-insensitively. No normalization is performed, so `.` and `..` components are
+    /// compared as-is.
     ///
-    /// The stem is:
+    /// # Examples
     ///
-    /// * [`None`], if there is no file name;
-    /// * The entire file name if there is no embedded `.`;
-    /// * The entire file name if the file name begins with `.` and has no other `.`s within;
-    /// * Otherwise, the portion of the file name before the final `.`
+    /// ```
+    /// use typed_path::{Path, UnixEncoding, WindowsEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// let path = Path::<UnixEncoding>::new("/etc");
+    ///
+    /// assert!(path.is_ancestor_of("/etc/passwd"));
+    /// assert!(!path.is_ancestor_of("/etc"));
+    /// assert!(!path.is_ancestor_of("/etcetera"));
+    /// assert!(!path.is_ancestor_of("/ETC/passwd"));
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// let path = Path::<WindowsEncoding>::new(r"C:\Windows");
+    ///
+    /// assert!(path.is_ancestor_of(r"c:\windows\System32"));
+    /// assert!(path.is_ancestor_of(r"C:/WINDOWS/System32"));
+    /// assert!(!path.is_ancestor_of(r"D:\Windows\System32"));
+    /// ```
+    pub fn is_ancestor_of<P: AsRef<Path<T>>>(&self, other: P) -> bool {
+        self._is_ancestor_of(other.as_ref(), true)
+    }
+
+    /// Determines whether `self` is either the same path as `other` or one of its ancestors.
+    ///
+    /// See [`Path::is_ancestor_of`] for how components are compared.
     ///
     /// # Examples
     ///
@@ -520,26 +1314,41 @@ where
     /// use typed_path::{Path, UnixEncoding};
     ///
     /// // NOTE: A path cannot be created on its own without a defined encoding
-    /// assert_eq!(b"foo", Path::<UnixEncoding>::new("foo.rs").file_stem().unwrap());
-    /// assert_eq!(b"foo.tar", Path::<UnixEncoding>::new("foo.tar.gz").file_stem().unwrap());
-    /// ```
+    /// let path = Path::<UnixEncoding>::new("/etc");
     ///
-    pub fn file_stem(&self) -> Option<&[u8]> {
-        self.file_name()
-            .map(helpers::rsplit_file_at_dot)
-            .and_then(|(before, after)| before.or(after))
+    /// assert!(path.is_inclusive_ancestor_of("/etc/passwd"));
+    /// assert!(path.is_inclusive_ancestor_of("/etc/"));
+    /// assert!(!path.is_inclusive_ancestor_of("/"));
+    /// ```
+    pub fn is_inclusive_ancestor_of<P: AsRef<Path<T>>>(&self, other: P) -> bool {
+        self._is_ancestor_of(other.as_ref(), false)
     }
 
-    /// Extracts the extension of [`self.file_name`], if possible.
+    /// Determines whether `self` is a strict descendant of `other`, meaning that `self` begins
+    /// with all of the components of `other` followed by at least one more component.
     ///
-    /// The extension is:
+    /// See [`Path::is_ancestor_of`] for how components are compared.
     ///
-    /// * [`None`], if there is no file name;
-    /// * [`None`], if there is no embedded `.`;
-    /// * [`None`], if the file name begins with `.` and has no other `.`s within;
-    /// * Otherwise, the portion of the file name after the final `.`
+    /// # Examples
     ///
-    /// [`self.file_name`]: Path::file_name
+    /// ```
+    /// use typed_path::{Path, UnixEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// let path = Path::<UnixEncoding>::new("/etc/passwd");
+    ///
+    /// assert!(path.is_descendant_of("/etc"));
+    /// assert!(path.is_descendant_of("/"));
+    /// assert!(!path.is_descendant_of("/etc/passwd"));
+    /// assert!(!path.is_descendant_of("/et"));
+    /// ```
+    pub fn is_descendant_of<P: AsRef<Path<T>>>(&self, other: P) -> bool {
+        other.as_ref()._is_ancestor_of(self, true)
+    }
+
+    /// Determines whether `self` is either the same path as `other` or one of its descendants.
+    ///
+    /// See [`Path::is_ancestor_of`] for how components are compared.
     ///
     /// # Examples
     ///
@@ -547,114 +1356,782 @@ where
     /// use typed_path::{Path, UnixEncoding};
     ///
     /// // NOTE: A path cannot be created on its own without a defined encoding
-    /// assert_eq!(b"rs", Path::<UnixEncoding>::new("foo.rs").extension().unwrap());
-    /// assert_eq!(b"gz", Path::<UnixEncoding>::new("foo.tar.gz").extension().unwrap());
+    /// let path = Path::<UnixEncoding>::new("/etc/passwd");
+    ///
+    /// assert!(path.is_inclusive_descendant_of("/etc"));
+    /// assert!(path.is_inclusive_descendant_of("/etc/passwd"));
+    /// assert!(!path.is_inclusive_descendant_of("/etc/passwd/shadow"));
     /// ```
-    pub fn extension(&self) -> Option<&[u8]> {
-        self.file_name()
-            .map(helpers::rsplit_file_at_dot)
-            .and_then(|(before, after)| before.and(after))
+    pub fn is_inclusive_descendant_of<P: AsRef<Path<T>>>(&self, other: P) -> bool {
+        other.as_ref()._is_ancestor_of(self, false)
     }
 
-    /// Returns an owned [`PathBuf`] by resolving `..` and `.` segments.
+    fn _is_ancestor_of(&self, other: &Path<T>, strict: bool) -> bool {
+        let windows = self.has_windows_encoding();
+        let mut ours = self.components();
+        let mut theirs = other.components();
+
+        loop {
+            match (ours.next(), theirs.next()) {
+                (Some(a), Some(b)) => {
+                    // Separators within a Windows prefix are as insignificant as anywhere else
+                    let a = comparable_component_bytes(a.kind(), a.as_bytes(), windows);
+                    let b = comparable_component_bytes(b.kind(), b.as_bytes(), windows);
+                    if !(a == b || windows && a.eq_ignore_ascii_case(&b)) {
+                        return false;
+                    }
+                }
+                (Some(_), None) => return false,
+                (None, rest) => return !strict || rest.is_some(),
+            }
+        }
+    }
+
+    /// Determines whether `needle` appears anywhere within `self` as a sequence of whole
+    /// components.
     ///
-    /// When multiple, sequential path segment separation characters are found (e.g. `/` for Unix
-    /// and either `\` or `/` on Windows), they are replaced by a single instance of the
-    /// platform-specific path segment separator (`/` on Unix and `\` on Windows).
+    /// See [`Path::find_subpath`] for more details.
     ///
     /// # Examples
     ///
     /// ```
-    /// use typed_path::{Path, PathBuf, UnixEncoding};
+    /// use typed_path::{Path, UnixEncoding};
     ///
     /// // NOTE: A path cannot be created on its own without a defined encoding
-    /// assert_eq!(
-    ///     Path::<UnixEncoding>::new("foo/bar//baz/./asdf/quux/..").normalize(),
-    ///     PathBuf::from("foo/bar/baz/asdf"),
-    /// );
+    /// let path = Path::<UnixEncoding>::new("/repo/node_modules/.bin/tsc");
+    ///
+    /// assert!(path.contains_path("node_modules/.bin"));
+    /// assert!(path.contains_path("/repo"));
+    /// assert!(!path.contains_path("node_modules/tsc"));
+    /// assert!(!path.contains_path("modules"));
     /// ```
+    pub fn contains_path<P: AsRef<Path<T>>>(&self, needle: P) -> bool {
+        self._find_subpath(needle.as_ref()).is_some()
+    }
+
+    /// Locates the first occurrence of `needle` within `self` as a sequence of whole components,
+    /// returning the index of the first matching component alongside the span of bytes within
+    /// `self` covered by the matching components.
     ///
-    /// When starting with a root directory, any `..` segment whose parent is the root directory
-    /// will be filtered out:
+    /// Components are compared the same way as with [`Path::starts_with`]. An empty `needle`
+    /// matches at the start of `self`.
+    ///
+    /// # Examples
     ///
     /// ```
-    /// use typed_path::{Path, PathBuf, UnixEncoding};
+    /// use typed_path::{Path, UnixEncoding, WindowsEncoding};
     ///
     /// // NOTE: A path cannot be created on its own without a defined encoding
-    /// assert_eq!(
-    ///     Path::<UnixEncoding>::new("/../foo").normalize(),
-    ///     PathBuf::from("/foo"),
-    /// );
+    /// let path = Path::<UnixEncoding>::new("/repo//node_modules/.bin/tsc");
+    ///
+    /// // Components are `/`, `repo`, `node_modules`, `.bin`, and `tsc`
+    /// let (index, span) = path.find_subpath("node_modules/.bin").unwrap();
+    /// assert_eq!(index, 2);
+    /// assert_eq!(&path.as_bytes()[span], b"node_modules/.bin");
+    ///
+    /// assert_eq!(path.find_subpath("tsc/"), Some((4, 25..28)));
+    /// assert_eq!(path.find_subpath("bin"), None);
+    ///
+    /// // Windows paths can match across either separator
+    /// let path = Path::<WindowsEncoding>::new(r"C:\src/gen\out");
+    /// assert_eq!(path.find_subpath(r"src\gen"), Some((2, 3..10)));
     /// ```
+    pub fn find_subpath<P: AsRef<Path<T>>>(&self, needle: P) -> Option<(usize, Range<usize>)> {
+        self._find_subpath(needle.as_ref())
+    }
+
+    fn _find_subpath(&self, needle: &Path<T>) -> Option<(usize, Range<usize>)> {
+        let mut components = self.components();
+        let mut index = 0;
+
+        loop {
+            if let Some((span, _)) = self.match_subpath(&components, needle) {
+                return Some((index, span));
+            }
+
+            components.next()?;
+            index += 1;
+        }
+    }
+
+    /// Attempts to match `needle` against the components of `self` starting at `components`,
+    /// returning the span of the matching components and the components remaining after them
+    fn match_subpath<'a>(
+        &'a self,
+        components: &<T as Encoding<'a>>::Components,
+        needle: &'a Path<T>,
+    ) -> Option<(Range<usize>, <T as Encoding<'a>>::Components)> {
+        let offset = |rest: &[u8]| self.inner.len() - rest.len();
+        let start = offset(components.as_bytes());
+        let mut end = start;
+        let mut candidate = components.clone();
+        for a in needle.components() {
+            let rest = candidate.as_bytes();
+            match candidate.next() {
+                Some(b) if a == b => end = offset(rest) + b.len(),
+                _ => return None,
+            }
+        }
+
+        Some((start..end, candidate))
+    }
+
+    /// Creates an owned [`PathBuf`] where every non-overlapping occurrence of the components of
+    /// `from` within `self` is replaced with `to`.
     ///
-    /// If any `..` is left unresolved as the path is relative and no parent is found, it is
-    /// discarded:
+    /// Occurrences are located the same way as with [`Path::find_subpath`], so a `from` that
+    /// begins with a root or prefix only matches at the start of `self`. Bytes of `self` outside
+    /// of the replaced components, including separators, are kept as-is. Replacing with an empty
+    /// path removes the matching components entirely.
+    ///
+    /// If `from` has no components, the path is returned unchanged.
+    ///
+    /// # Examples
     ///
     /// ```
-    /// use typed_path::{Path, PathBuf, UnixEncoding, WindowsEncoding};
+    /// use typed_path::{Path, UnixEncoding, WindowsEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// let path = Path::<UnixEncoding>::new("/repo/src/lib/src/main.rs");
     ///
     /// assert_eq!(
-    ///     Path::<UnixEncoding>::new("../foo/..").normalize(),
-    ///     PathBuf::from(""),
+    ///     path.replace_components("src", "generated").as_bytes(),
+    ///     b"/repo/generated/lib/generated/main.rs",
     /// );
+    /// assert_eq!(path.replace_components("lib/src", "").as_bytes(), b"/repo/src/main.rs");
     ///
-    /// // Windows prefixes also count this way, but the prefix remains
+    /// // Anchored components only match at the start of the path
     /// assert_eq!(
-    ///     Path::<WindowsEncoding>::new(r"C:..\foo\..").normalize(),
-    ///     PathBuf::from(r"C:"),
+    ///     path.replace_components("/repo", "/mnt/repo").as_bytes(),
+    ///     b"/mnt/repo/src/lib/src/main.rs",
     /// );
+    /// assert_eq!(path.replace_components("/src", "gen").as_bytes(), path.as_bytes());
+    ///
+    /// // Windows components are matched across either separator
+    /// let path = Path::<WindowsEncoding>::new(r"C:\repo/src\main.rs");
+    /// assert_eq!(path.replace_components(r"repo\src", "out").as_bytes(), br"C:\out\main.rs");
     /// ```
-    pub fn normalize(&self) -> PathBuf<T> {
-        let mut components = Vec::new();
-        for component in self.components() {
-            if !component.is_current() && !component.is_parent() {
-                components.push(component);
-            } else if component.is_parent() {
-                if let Some(last) = components.last() {
-                    if last.is_normal() {
-                        components.pop();
-                    }
+    pub fn replace_components<P, Q>(&self, from: P, to: Q) -> PathBuf<T>
+    where
+        P: AsRef<Path<T>>,
+        Q: AsRef<Path<T>>,
+    {
+        self._replace_components(from.as_ref(), to.as_ref())
+    }
+
+    fn _replace_components(&self, from: &Path<T>, to: &Path<T>) -> PathBuf<T> {
+        if from.components().next().is_none() {
+            return self.to_path_buf();
+        }
+
+        let mut inner = Vec::with_capacity(self.inner.len());
+        let mut cursor = 0;
+        let mut components = self.components();
+
+        loop {
+            if let Some((span, rest)) = self.match_subpath(&components, from) {
+                inner.extend_from_slice(&self.inner[cursor..span.start]);
+
+                // NOTE: When removing components, also drop the separators that follow them so
+                //       that the neighboring components are not left with an empty gap
+                if to.inner.is_empty() && !rest.as_bytes().is_empty() {
+                    cursor = self.inner.len() - rest.as_bytes().len();
+                } else {
+                    inner.extend_from_slice(&to.inner);
+                    cursor = span.end;
                 }
+
+                components = rest;
+            } else if components.next().is_none() {
+                break;
             }
         }
 
-        let mut path = PathBuf::<T>::new();
+        inner.extend_from_slice(&self.inner[cursor..]);
+        PathBuf {
+            _encoding: PhantomData,
+            inner,
+        }
+    }
+
+    /// Creates an owned [`PathBuf`] where each component of `self` is replaced with the bytes
+    /// returned by `f`.
+    ///
+    /// Separators between components are kept as-is, so mapping each component to itself
+    /// reproduces the original path other than any root being written with the encoding's
+    /// separator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Component, Path, UnixEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// let path = Path::<UnixEncoding>::new("/repo/src/main.rs");
+    ///
+    /// let mapped = path.map_components(|c| {
+    ///     if c.as_bytes() == b"src" {
+    ///         b"generated".as_slice()
+    ///     } else {
+    ///         c.as_bytes()
+    ///     }
+    /// });
+    /// assert_eq!(mapped.as_bytes(), b"/repo/generated/main.rs");
+    ///
+    /// let upper = path.map_components(|c| c.as_bytes().to_ascii_uppercase());
+    /// assert_eq!(upper.as_bytes(), b"/REPO/SRC/MAIN.RS");
+    /// ```
+    pub fn map_components<'a, F, C>(&'a self, mut f: F) -> PathBuf<T>
+    where
+        F: FnMut(<<T as Encoding<'a>>::Components as Components<'a>>::Component) -> C,
+        C: AsRef<[u8]>,
+    {
+        let mut inner = Vec::with_capacity(self.inner.len());
+        let mut cursor = 0;
+        let mut components = self.components();
 
-        for component in components {
-            path.push(component.as_bytes());
+        loop {
+            let start = self.inner.len() - components.as_bytes().len();
+            let component = match components.next() {
+                Some(component) => component,
+                None => break,
+            };
+
+            inner.extend_from_slice(&self.inner[cursor..start]);
+            cursor = start + component.len();
+            inner.extend_from_slice(f(component).as_ref());
         }
 
-        path
+        inner.extend_from_slice(&self.inner[cursor..]);
+        PathBuf {
+            _encoding: PhantomData,
+            inner,
+        }
     }
 
-    /// Converts a path to an absolute form by [`normalizing`] the path, returning a
-    /// [`PathBuf`].
+    /// Determines whether `other` refers to the same place as `self` after lexical
+    /// normalization, using the case rules of the encoding.
     ///
-    /// In the case that the path is relative, the current working directory is prepended prior to
-    /// normalizing.
+    /// Separators and `.` components are never significant, and `..` components are resolved
+    /// against the normal components preceding them (keeping any leading `..` of a relative
+    /// path, as with [`Path::clean`]). Unix paths are compared case-sensitively, whereas Windows
+    /// paths are compared ascii case-insensitively.
     ///
-    /// [`normalizing`]: Path::normalize
+    /// Nothing is allocated unless either path contains a `..` component.
+    ///
+    /// This is [`Path::equivalent_with`] using the default [`PathEquivalence`].
     ///
     /// # Examples
     ///
     /// ```
-    /// use typed_path::{utils, Path, UnixEncoding};
+    /// use typed_path::{Path, UnixEncoding, WindowsEncoding};
     ///
-    /// // With an absolute path, it is just normalized
-    /// let path = Path::<UnixEncoding>::new("/a/b/../c/./d");
-    /// assert_eq!(path.absolutize().unwrap(), Path::new("/a/c/d"));
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// let path = Path::<UnixEncoding>::new("/etc/./ssh//config");
+    /// assert!(path.normalized_eq("/etc/ssh/config"));
+    /// assert!(path.normalized_eq("/etc/ssl/../ssh/config"));
+    /// assert!(!path.normalized_eq("/etc/SSH/config"));
     ///
-    /// // With a relative path, it is first joined with the current working directory
-    /// // and then normalized
-    /// let cwd = utils::current_dir().unwrap().with_encoding::<UnixEncoding>();
-    /// let path = cwd.join(Path::new("a/b/../c/./d"));
-    /// assert_eq!(path.absolutize().unwrap(), cwd.join(Path::new("a/c/d")));
+    /// // Leading parent directories are kept, so these are not the same
+    /// assert!(!Path::<UnixEncoding>::new("../foo").normalized_eq("foo"));
+    ///
+    /// // Windows paths ignore ascii case
+    /// let path = Path::<WindowsEncoding>::new(r"C:\Program Files\App");
+    /// assert!(path.normalized_eq(r"c:/PROGRAM FILES/app/"));
     /// ```
-    #[cfg(all(feature = "std", not(target_family = "wasm")))]
-    pub fn absolutize(&self) -> std::io::Result<PathBuf<T>> {
-        if self.is_absolute() {
-            Ok(self.normalize())
-        } else {
+    pub fn normalized_eq<P: AsRef<Path<T>>>(&self, other: P) -> bool {
+        self._equivalent_with(other.as_ref(), &PathEquivalence::new())
+    }
+
+    /// Determines whether `other` refers to the same place as `self` using the given
+    /// [`PathEquivalence`] rules.
+    ///
+    /// See [`Path::normalized_eq`] for the rules used by default.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Path, PathEquivalence, UnixEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// let path = Path::<UnixEncoding>::new("/a/b/../c");
+    ///
+    /// assert!(path.equivalent_with("/a/c", &PathEquivalence::new()));
+    /// assert!(!path.equivalent_with("/a/c", &PathEquivalence::new().resolve_parent(false)));
+    /// assert!(path.equivalent_with("/A/C", &PathEquivalence::new().case_sensitive(false)));
+    /// ```
+    pub fn equivalent_with<P: AsRef<Path<T>>>(&self, other: P, rules: &PathEquivalence) -> bool {
+        self._equivalent_with(other.as_ref(), rules)
+    }
+
+    fn _equivalent_with(&self, other: &Path<T>, rules: &PathEquivalence) -> bool {
+        let windows = self.has_windows_encoding();
+        let has_parent = |path: &Path<T>| path.components().any(|c| c.is_parent());
+
+        if rules.needs_resolve(has_parent(self), has_parent(other)) {
+            let options = NormalizationOptions::new().preserve_leading_parent(true);
+            let (a, b) = (
+                self.normalize_with(&options),
+                other.normalize_with(&options),
+            );
+            rules.iter_eq(
+                windows,
+                a.components().map(|c| (c.kind(), c.as_bytes())),
+                b.components().map(|c| (c.kind(), c.as_bytes())),
+            )
+        } else {
+            rules.iter_eq(
+                windows,
+                self.components()
+                    .filter(|c| !c.is_current())
+                    .map(|c| (c.kind(), c.as_bytes())),
+                other
+                    .components()
+                    .filter(|c| !c.is_current())
+                    .map(|c| (c.kind(), c.as_bytes())),
+            )
+        }
+    }
+
+    /// Creates an owned [`PathBuf`] like `self` but with every ascii letter of its component text
+    /// made lowercase.
+    ///
+    /// The structure of a prefix is kept intact, such as the `UNC` of a Windows `\\?\UNC\` prefix
+    /// which would otherwise stop being recognized. Windows prefixes still have their drive
+    /// letter, server, share, and names transformed, while the prefixes of other encodings (like
+    /// Plan 9 device names, whose case is significant) are left as they are.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Path, PathBuf, WindowsEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// let path = Path::<WindowsEncoding>::new(r"\\?\UNC\FileServer\Share\Reports\Q1.CSV");
+    /// assert_eq!(
+    ///     path.to_ascii_lowercase(),
+    ///     PathBuf::from(r"\\?\UNC\fileserver\share\reports\q1.csv"),
+    /// );
+    /// ```
+    pub fn to_ascii_lowercase(&self) -> PathBuf<T> {
+        self.to_ascii_case(false)
+    }
+
+    /// Creates an owned [`PathBuf`] like `self` but with every ascii letter of its component text
+    /// made uppercase.
+    ///
+    /// See [`Path::to_ascii_lowercase`] for how prefixes are handled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Path, PathBuf, WindowsEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// let path = Path::<WindowsEncoding>::new(r"\\?\c:\Users\me");
+    /// assert_eq!(path.to_ascii_uppercase(), PathBuf::from(r"\\?\C:\USERS\ME"));
+    /// ```
+    pub fn to_ascii_uppercase(&self) -> PathBuf<T> {
+        self.to_ascii_case(true)
+    }
+
+    fn to_ascii_case(&self, upper: bool) -> PathBuf<T> {
+        let mut buf = self.to_path_buf();
+        if upper {
+            buf.make_ascii_uppercase();
+        } else {
+            buf.make_ascii_lowercase();
+        }
+        buf
+    }
+
+    /// Returns the range of bytes that case transforms leave alone, which is the `UNC` of a
+    /// Windows verbatim UNC prefix or the whole prefix of any other encoding
+    pub(crate) fn case_preserved_range(&self) -> Range<usize> {
+        match self.components().next() {
+            Some(c) if c.kind() == ComponentKind::Prefix => {
+                if self.has_windows_encoding() {
+                    WindowsEncoding::case_preserved_range(self.as_bytes())
+                } else {
+                    0..c.len()
+                }
+            }
+            _ => 0..0,
+        }
+    }
+
+    /// Extracts the stem (non-extension) portion of [`self.file_name`].
+    ///
+    /// [`self.file_name`]: Path::file_name
+    ///
+    /// The stem is:
+    ///
+    /// * [`None`], if there is no file name;
+    /// * The entire file name if there is no embedded `.`;
+    /// * The entire file name if the file name begins with `.` and has no other `.`s within;
+    /// * Otherwise, the portion of the file name before the final `.`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Path, UnixEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// assert_eq!(b"foo", Path::<UnixEncoding>::new("foo.rs").file_stem().unwrap());
+    /// assert_eq!(b"foo.tar", Path::<UnixEncoding>::new("foo.tar.gz").file_stem().unwrap());
+    /// ```
+    ///
+    pub fn file_stem(&self) -> Option<&[u8]> {
+        self.file_name()
+            .map(helpers::rsplit_file_at_dot)
+            .and_then(|(before, after)| before.or(after))
+    }
+
+    /// Extracts the extension of [`self.file_name`], if possible.
+    ///
+    /// The extension is:
+    ///
+    /// * [`None`], if there is no file name;
+    /// * [`None`], if there is no embedded `.`;
+    /// * [`None`], if the file name begins with `.` and has no other `.`s within;
+    /// * Otherwise, the portion of the file name after the final `.`
+    ///
+    /// [`self.file_name`]: Path::file_name
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Path, UnixEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// assert_eq!(b"rs", Path::<UnixEncoding>::new("foo.rs").extension().unwrap());
+    /// assert_eq!(b"gz", Path::<UnixEncoding>::new("foo.tar.gz").extension().unwrap());
+    /// ```
+    pub fn extension(&self) -> Option<&[u8]> {
+        self.file_name()
+            .map(helpers::rsplit_file_at_dot)
+            .and_then(|(before, after)| before.and(after))
+    }
+
+    /// Returns an owned [`PathBuf`] by resolving `..` and `.` segments.
+    ///
+    /// When multiple, sequential path segment separation characters are found (e.g. `/` for Unix
+    /// and either `\` or `/` on Windows), they are replaced by a single instance of the
+    /// platform-specific path segment separator (`/` on Unix and `\` on Windows).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Path, PathBuf, UnixEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// assert_eq!(
+    ///     Path::<UnixEncoding>::new("foo/bar//baz/./asdf/quux/..").normalize(),
+    ///     PathBuf::from("foo/bar/baz/asdf"),
+    /// );
+    /// ```
+    ///
+    /// When starting with a root directory, any `..` segment whose parent is the root directory
+    /// will be filtered out:
+    ///
+    /// ```
+    /// use typed_path::{Path, PathBuf, UnixEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// assert_eq!(
+    ///     Path::<UnixEncoding>::new("/../foo").normalize(),
+    ///     PathBuf::from("/foo"),
+    /// );
+    /// ```
+    ///
+    /// If any `..` is left unresolved as the path is relative and no parent is found, it is
+    /// discarded:
+    ///
+    /// ```
+    /// use typed_path::{Path, PathBuf, UnixEncoding, WindowsEncoding};
+    ///
+    /// assert_eq!(
+    ///     Path::<UnixEncoding>::new("../foo/..").normalize(),
+    ///     PathBuf::from(""),
+    /// );
+    ///
+    /// // Windows prefixes also count this way, but the prefix remains
+    /// assert_eq!(
+    ///     Path::<WindowsEncoding>::new(r"C:..\foo\..").normalize(),
+    ///     PathBuf::from(r"C:"),
+    /// );
+    /// ```
+    pub fn normalize(&self) -> PathBuf<T> {
+        trace_span!("normalize", encoding = T::label(), len = self.inner.len());
+
+        let mut components = Vec::new();
+        for component in self.components() {
+            if !component.is_current() && !component.is_parent() {
+                components.push(component);
+            } else if component.is_parent() {
+                if let Some(last) = components.last() {
+                    if last.is_normal() {
+                        components.pop();
+                    }
+                }
+            }
+        }
+
+        let mut path = PathBuf::<T>::new();
+        let current =
+            <<<T as Encoding>::Components as Components>::Component as Component>::current();
+        let separator =
+            <<<T as Encoding>::Components as Components>::Component as Component>::root();
+        let mut components = components.into_iter().peekable();
+
+        while let Some(component) = components.next() {
+            match components.peek() {
+                // Push a prefix together with the root following it, as pushing a rooted path can
+                // replace the prefix, such as for Plan 9 device roots
+                Some(root)
+                    if component.kind() == ComponentKind::Prefix
+                        && root.kind() == ComponentKind::RootDir =>
+                {
+                    let mut anchor = component.as_bytes().to_vec();
+                    anchor.extend_from_slice(root.as_bytes());
+                    path.push(anchor.as_slice());
+                    components.next();
+                }
+
+                // Keep a normal component leading the path from being read as something else,
+                // such as `#c` after resolving `./#c` for Plan 9 or `C:` after resolving `.\C:`
+                // for Windows
+                _ if path.as_bytes().is_empty()
+                    && component.is_normal()
+                    && matches!(
+                        Path::<T>::new(component.as_bytes()).components().next(),
+                        Some(c) if !c.is_normal()
+                    ) =>
+                {
+                    let mut relative = current.as_bytes().to_vec();
+                    relative.extend_from_slice(separator.as_bytes());
+                    relative.extend_from_slice(component.as_bytes());
+                    path.push(relative.as_slice());
+                }
+
+                _ => path.push(component.as_bytes()),
+            }
+        }
+
+        path
+    }
+
+    /// Normalizes the path like [`Path::normalize`], borrowing `self` instead of allocating when
+    /// it is already normalized.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Path, WindowsEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// let path = Path::<WindowsEncoding>::new(r"C:\Users\me");
+    /// assert!(path.normalize_cow().is_borrowed());
+    ///
+    /// let path = Path::<WindowsEncoding>::new(r"C:\Users\.\me");
+    /// assert!(path.normalize_cow().is_owned());
+    /// assert_eq!(path.normalize_cow().as_path(), Path::new(r"C:\Users\me"));
+    /// ```
+    pub fn normalize_cow(&self) -> CowPath<'_, T> {
+        if is_normalized(self) {
+            return CowPath::borrowed(self);
+        }
+
+        let normalized = self.normalize();
+        if normalized.as_bytes() == self.as_bytes() {
+            CowPath::borrowed(self)
+        } else {
+            CowPath::owned(normalized)
+        }
+    }
+
+    /// Returns an owned [`PathBuf`] by lexically cleaning the path in the same way as Go's
+    /// `path/filepath.Clean`.
+    ///
+    /// Unlike [`Path::normalize`], this keeps any leading `..` of a relative path, as they cannot
+    /// be resolved without knowing the current directory:
+    ///
+    /// 1. Sequential separators are replaced by a single separator.
+    /// 2. Each `.` is removed.
+    /// 3. Each `..` is removed alongside the normal component preceding it.
+    /// 4. Each `..` that begins a rooted path is removed.
+    /// 5. Each `..` that begins a relative path is kept.
+    /// 6. Trailing separators are removed.
+    ///
+    /// If the result would be empty (or only a prefix like `C:`), `.` is added in its place.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Path, PathBuf, UnixEncoding, WindowsEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// assert_eq!(Path::<UnixEncoding>::new("a/../../b/./c/").clean(), PathBuf::from("../b/c"));
+    /// assert_eq!(Path::<UnixEncoding>::new("/../a").clean(), PathBuf::from("/a"));
+    /// assert_eq!(Path::<UnixEncoding>::new("a/..").clean(), PathBuf::from("."));
+    /// assert_eq!(Path::<UnixEncoding>::new("").clean(), PathBuf::from("."));
+    ///
+    /// assert_eq!(Path::<WindowsEncoding>::new(r"C:a\..").clean(), PathBuf::from("C:."));
+    /// assert_eq!(Path::<WindowsEncoding>::new(r"C:..\a").clean(), PathBuf::from(r"C:..\a"));
+    /// ```
+    pub fn clean(&self) -> PathBuf<T> {
+        let mut path =
+            self.normalize_with(&NormalizationOptions::new().preserve_leading_parent(true));
+
+        if !path.has_root() && path.split_anchor().1.as_bytes().is_empty() {
+            // NOTE: Extend directly, as pushing `.` onto a prefix is a no-op
+            path.inner.extend_from_slice(
+                <<<T as Encoding>::Components as Components>::Component as Component>::current()
+                    .as_bytes(),
+            );
+        }
+
+        path
+    }
+
+    /// Returns an owned [`PathBuf`] by normalizing the path using the given
+    /// [`NormalizationOptions`].
+    ///
+    /// With the default options, this is the same as [`Path::normalize`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{NormalizationOptions, Path, PathBuf, UnixEncoding, WindowsEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// let path = Path::<UnixEncoding>::new("../foo//./bar/..");
+    ///
+    /// assert_eq!(
+    ///     path.normalize_with(&NormalizationOptions::new()),
+    ///     PathBuf::from("foo"),
+    /// );
+    ///
+    /// assert_eq!(
+    ///     path.normalize_with(&NormalizationOptions::new().preserve_leading_parent(true)),
+    ///     PathBuf::from("../foo"),
+    /// );
+    ///
+    /// assert_eq!(
+    ///     path.normalize_with(&NormalizationOptions::new().resolve_parent(false)),
+    ///     PathBuf::from("../foo/bar/.."),
+    /// );
+    ///
+    /// assert_eq!(
+    ///     path.normalize_with(&NormalizationOptions::new().collapse_separators(false)),
+    ///     PathBuf::from("foo"),
+    /// );
+    ///
+    /// assert_eq!(
+    ///     path.normalize_with(
+    ///         &NormalizationOptions::new()
+    ///             .resolve_parent(false)
+    ///             .collapse_separators(false)
+    ///     ),
+    ///     PathBuf::from("../foo//bar/.."),
+    /// );
+    ///
+    /// // Separators are only kept as written when not collapsing them
+    /// let path = Path::<WindowsEncoding>::new(r"C:\foo//bar\.\baz\");
+    ///
+    /// assert_eq!(
+    ///     path.normalize_with(&NormalizationOptions::new().lowercase_drive_letter(true)),
+    ///     PathBuf::from(r"c:\foo\bar\baz"),
+    /// );
+    ///
+    /// assert_eq!(
+    ///     path.normalize_with(
+    ///         &NormalizationOptions::new()
+    ///             .collapse_separators(false)
+    ///             .trim_trailing_separator(false)
+    ///     ),
+    ///     PathBuf::from(r"C:\foo//bar\baz\"),
+    /// );
+    ///
+    /// assert_eq!(
+    ///     path.normalize_with(&NormalizationOptions::new().remove_current(false)),
+    ///     PathBuf::from(r"C:\foo\bar\.\baz"),
+    /// );
+    /// ```
+    pub fn normalize_with(&self, options: &NormalizationOptions) -> PathBuf<T> {
+        trace_span!(
+            "normalize_with",
+            encoding = T::label(),
+            len = self.inner.len()
+        );
+
+        let separator =
+            <<<T as Encoding>::Components as Components>::Component as Component>::root();
+        let components = self.raw_components();
+        let mut path = PathBuf::<T>::new();
+
+        for piece in options.apply(&components, self.has_root(), separator.as_bytes()) {
+            path.inner.extend_from_slice(&piece);
+        }
+
+        path
+    }
+
+    /// Returns each component of the path alongside the raw bytes it occupies.
+    pub(crate) fn raw_components(&self) -> Vec<RawComponent<'_>> {
+        let mut components = self.components();
+        let mut raw = Vec::new();
+
+        loop {
+            let rest = components.as_bytes();
+            let component = match components.next() {
+                Some(component) => component,
+                None => break,
+            };
+
+            // Consuming a component also consumes the separators that follow it
+            let consumed = &rest[..rest.len() - components.as_bytes().len()];
+            let (bytes, gap) = consumed.split_at(component.len());
+
+            raw.push(RawComponent {
+                kind: component.kind(),
+                canonical: component.as_bytes(),
+                raw: bytes,
+                gap,
+            });
+        }
+
+        raw
+    }
+
+    /// Converts a path to an absolute form by [`normalizing`] the path, returning a
+    /// [`PathBuf`].
+    ///
+    /// In the case that the path is relative, the current working directory is prepended prior to
+    /// normalizing.
+    ///
+    /// [`normalizing`]: Path::normalize
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{utils, Path, UnixEncoding};
+    ///
+    /// // With an absolute path, it is just normalized
+    /// let path = Path::<UnixEncoding>::new("/a/b/../c/./d");
+    /// assert_eq!(path.absolutize().unwrap(), Path::new("/a/c/d"));
+    ///
+    /// // With a relative path, it is first joined with the current working directory
+    /// // and then normalized
+    /// let cwd = utils::current_dir().unwrap().with_encoding::<UnixEncoding>();
+    /// let path = cwd.join(Path::new("a/b/../c/./d"));
+    /// assert_eq!(path.absolutize().unwrap(), cwd.join(Path::new("a/c/d")));
+    /// ```
+    #[cfg(all(feature = "std", not(target_family = "wasm")))]
+    pub fn absolutize(&self) -> std::io::Result<PathBuf<T>> {
+        if self.is_absolute() {
+            Ok(self.normalize())
+        } else {
             // Get the cwd as a native path and convert to this path's encoding
             let cwd = crate::utils::current_dir()?.with_encoding();
 
@@ -662,114 +2139,570 @@ where
         }
     }
 
-    /// Creates an owned [`PathBuf`] with `path` adjoined to `self`.
+    /// Creates an owned [`PathBuf`] with `path` adjoined to `self`.
+    ///
+    /// See [`PathBuf::push`] for more details on what it means to adjoin a path.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Path, PathBuf, UnixEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// assert_eq!(
+    ///     Path::<UnixEncoding>::new("/etc").join("passwd"),
+    ///     PathBuf::from("/etc/passwd"),
+    /// );
+    /// ```
+    pub fn join<P: AsRef<Path<T>>>(&self, path: P) -> PathBuf<T> {
+        self._join(path.as_ref())
+    }
+
+    fn _join(&self, path: &Path<T>) -> PathBuf<T> {
+        let mut buf = self.to_path_buf();
+        buf.push(path);
+        buf
+    }
+
+    /// Creates an owned [`PathBuf`] with `path` adjoined to `self`, checking the `path` to ensure
+    /// it is safe to join. _When dealing with user-provided paths, this is the preferred method._
+    ///
+    /// See [`PathBuf::push_checked`] for more details on what it means to adjoin a path safely.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{CheckedPathError, Path, PathBuf, UnixEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// let path = Path::<UnixEncoding>::new("/etc");
+    ///
+    /// // A valid path can be joined onto the existing one
+    /// assert_eq!(path.join_checked("passwd"), Ok(PathBuf::from("/etc/passwd")));
+    ///
+    /// // An invalid path will result in an error
+    /// assert_eq!(path.join_checked("/sneaky/replacement"), Err(CheckedPathError::UnexpectedRoot));
+    /// ```
+    pub fn join_checked<P: AsRef<Path<T>>>(&self, path: P) -> Result<PathBuf<T>, CheckedPathError> {
+        self._join_checked(path.as_ref())
+    }
+
+    fn _join_checked(&self, path: &Path<T>) -> Result<PathBuf<T>, CheckedPathError> {
+        let mut buf = self.to_path_buf();
+        buf.push_checked(path)?;
+        Ok(buf)
+    }
+
+    /// Creates an owned [`PathBuf`] with `path` adjoined to `self` using the given [`PushMode`]
+    /// to decide what happens when `path` has its own root or prefix.
+    ///
+    /// See [`PathBuf::push_with_mode`] for more details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{CheckedPathError, Path, PathBuf, PushMode, UnixEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// let path = Path::<UnixEncoding>::new("/srv");
+    ///
+    /// assert_eq!(path.join_with_mode("/etc", PushMode::Replace), Ok(PathBuf::from("/etc")));
+    /// assert_eq!(path.join_with_mode("/etc", PushMode::Append), Ok(PathBuf::from("/srv/etc")));
+    /// assert_eq!(
+    ///     path.join_with_mode("/etc", PushMode::Reject),
+    ///     Err(CheckedPathError::UnexpectedRoot),
+    /// );
+    /// ```
+    pub fn join_with_mode<P: AsRef<Path<T>>>(
+        &self,
+        path: P,
+        mode: PushMode,
+    ) -> Result<PathBuf<T>, CheckedPathError> {
+        let mut buf = self.to_path_buf();
+        buf.push_with_mode(path, mode)?;
+        Ok(buf)
+    }
+
+    /// Creates an owned [`PathBuf`] with `path` adjoined to `self`, never replacing `self` even
+    /// if `path` is absolute. This is [`join_with_mode`] using [`PushMode::Append`].
+    ///
+    /// [`join_with_mode`]: Path::join_with_mode
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Path, PathBuf, UnixEncoding, WindowsEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// let path = Path::<UnixEncoding>::new("/srv/www");
+    /// assert_eq!(path.join_keeping_base("/index.html"), PathBuf::from("/srv/www/index.html"));
+    ///
+    /// // Windows prefixes are dropped alongside the root
+    /// let path = Path::<WindowsEncoding>::new(r"C:\srv");
+    /// assert_eq!(path.join_keeping_base(r"D:\www"), PathBuf::from(r"C:\srv\www"));
+    /// ```
+    pub fn join_keeping_base<P: AsRef<Path<T>>>(&self, path: P) -> PathBuf<T> {
+        let mut buf = self.to_path_buf();
+        buf.push_keeping_base(path);
+        buf
+    }
+
+    /// Creates an owned [`PathBuf`] with `path` adjoined to `self`, failing if `path` has a root
+    /// or prefix. This is [`join_with_mode`] using [`PushMode::Reject`].
+    ///
+    /// Unlike [`join_checked`], no other validation is performed on `path`.
+    ///
+    /// [`join_checked`]: Path::join_checked
+    /// [`join_with_mode`]: Path::join_with_mode
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{CheckedPathError, Path, PathBuf, UnixEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// let path = Path::<UnixEncoding>::new("/srv");
+    /// assert_eq!(path.join_relative_only("../etc"), Ok(PathBuf::from("/srv/../etc")));
+    /// assert_eq!(path.join_relative_only("/etc"), Err(CheckedPathError::UnexpectedRoot));
+    /// ```
+    pub fn join_relative_only<P: AsRef<Path<T>>>(
+        &self,
+        path: P,
+    ) -> Result<PathBuf<T>, CheckedPathError> {
+        self.join_with_mode(path, PushMode::Reject)
+    }
+
+    /// Splits any leading prefix and root components off of the path, returning the relative
+    /// remainder alongside the error that [`PushMode::Reject`] would report for them.
+    pub(crate) fn split_anchor(&self) -> (Option<CheckedPathError>, &Path<T>) {
+        let mut components = self.components();
+        let mut error = None;
+
+        loop {
+            let rest = components.as_bytes();
+            match components.next() {
+                Some(c) if !c.is_normal() && !c.is_parent() && !c.is_current() => {
+                    if error.is_none() {
+                        error = Some(if c == Component::root() {
+                            CheckedPathError::UnexpectedRoot
+                        } else {
+                            CheckedPathError::UnexpectedPrefix
+                        });
+                    }
+                }
+                _ => return (error, Path::new(rest)),
+            }
+        }
+    }
+
+    /// Creates an owned [`PathBuf`] like `self` but with the given file name.
+    ///
+    /// See [`PathBuf::set_file_name`] for more details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Path, PathBuf, UnixEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// let path = Path::<UnixEncoding>::new("/tmp/foo.txt");
+    /// assert_eq!(path.with_file_name("bar.txt"), PathBuf::from("/tmp/bar.txt"));
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// let path = Path::<UnixEncoding>::new("/tmp");
+    /// assert_eq!(path.with_file_name("var"), PathBuf::from("/var"));
+    /// ```
+    pub fn with_file_name<S: AsRef<[u8]>>(&self, file_name: S) -> PathBuf<T> {
+        self._with_file_name(file_name.as_ref())
+    }
+
+    fn _with_file_name(&self, file_name: &[u8]) -> PathBuf<T> {
+        let mut buf = self.to_path_buf();
+        buf.set_file_name(file_name);
+        buf
+    }
+
+    /// Creates an owned [`PathBuf`] like `self` but with the given extension.
+    ///
+    /// See [`PathBuf::set_extension`] for more details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Path, PathBuf, UnixEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// let path = Path::<UnixEncoding>::new("foo.rs");
+    /// assert_eq!(path.with_extension("txt"), PathBuf::from("foo.txt"));
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// let path = Path::<UnixEncoding>::new("foo.tar.gz");
+    /// assert_eq!(path.with_extension(""), PathBuf::from("foo.tar"));
+    /// assert_eq!(path.with_extension("xz"), PathBuf::from("foo.tar.xz"));
+    /// assert_eq!(path.with_extension("").with_extension("txt"), PathBuf::from("foo.txt"));
+    /// ```
+    pub fn with_extension<S: AsRef<[u8]>>(&self, extension: S) -> PathBuf<T> {
+        self._with_extension(extension.as_ref())
+    }
+
+    fn _with_extension(&self, extension: &[u8]) -> PathBuf<T> {
+        let mut buf = self.to_path_buf();
+        buf.set_extension(extension);
+        buf
+    }
+
+    /// Returns true if the [`file_name`] of the path marks it as hidden by convention alone,
+    /// without consulting any filesystem attributes.
+    ///
+    /// Regardless of the encoding of the path, a file name is considered hidden when it:
+    ///
+    /// * starts with `.`, such as `.git` on Unix
+    /// * starts with `~$`, such as the lock files created by Microsoft Office
+    /// * is `desktop.ini` or `Thumbs.db`, ignoring ascii case, which Windows Explorer creates
+    ///
+    /// [`file_name`]: Path::file_name
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Path, UnixEncoding, WindowsEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// assert!(Path::<UnixEncoding>::new("/repo/.gitignore").is_hidden_by_name());
+    /// assert!(Path::<WindowsEncoding>::new(r"C:\Docs\~$report.docx").is_hidden_by_name());
+    /// assert!(Path::<WindowsEncoding>::new(r"C:\Pictures\thumbs.db").is_hidden_by_name());
+    /// assert!(!Path::<WindowsEncoding>::new(r"C:\Docs\report.docx").is_hidden_by_name());
+    /// ```
+    pub fn is_hidden_by_name(&self) -> bool {
+        match self.file_name() {
+            Some(name) => {
+                name.starts_with(b".")
+                    || name.starts_with(b"~$")
+                    || name.eq_ignore_ascii_case(b"desktop.ini")
+                    || name.eq_ignore_ascii_case(b"thumbs.db")
+            }
+            None => false,
+        }
+    }
+
+    /// Guesses the media type of the path's content from its [`extension`], ignoring ascii case.
+    ///
+    /// This uses a built-in table of common extensions, returning [`None`] if the path has no
+    /// extension or the extension is not known.
+    ///
+    /// [`extension`]: Path::extension
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Path, UnixEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// assert_eq!(Path::<UnixEncoding>::new("/www/index.html").mime_type(), Some("text/html"));
+    /// assert_eq!(Path::<UnixEncoding>::new("/www/logo.SVG").mime_type(), Some("image/svg+xml"));
+    /// assert_eq!(Path::<UnixEncoding>::new("/www/Makefile").mime_type(), None);
+    /// ```
+    pub fn mime_type(&self) -> Option<&'static str> {
+        mime::from_extension(self.extension()?)
+    }
+
+    /// Produces a path like `self` whose file name is not taken according to `exists`, trying
+    /// `file.txt`, `file (1).txt`, `file (2).txt`, and so on.
+    ///
+    /// This is purely lexical: `exists` is called with each candidate and decides whether it is
+    /// taken, such as by checking the filesystem or a set of known paths. Candidate file names
+    /// are kept within 255 bytes by shortening the stem. See [`Path::uniquify_with`] to use a
+    /// different pattern.
+    ///
+    /// Returns [`None`] if the path has no file name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Path, UnixEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// let taken = ["/tmp/file.txt", "/tmp/file (1).txt"];
+    /// let exists = |p: &Path<UnixEncoding>| taken.iter().any(|t| p.as_bytes() == t.as_bytes());
+    ///
+    /// let path = Path::<UnixEncoding>::new("/tmp/file.txt").uniquify(exists).unwrap();
+    /// assert_eq!(path.as_bytes(), b"/tmp/file (2).txt");
+    ///
+    /// let path = Path::<UnixEncoding>::new("/tmp/other.txt").uniquify(exists).unwrap();
+    /// assert_eq!(path.as_bytes(), b"/tmp/other.txt");
+    /// ```
+    pub fn uniquify<F>(&self, exists: F) -> Option<PathBuf<T>>
+    where
+        F: FnMut(&Path<T>) -> bool,
+    {
+        self.uniquify_with(&UniquifyOptions::new(), exists)
+    }
+
+    /// Produces a path like `self` whose file name is not taken according to `exists`, using
+    /// `options` to generate alternative file names.
+    ///
+    /// See [`Path::uniquify`] for more details.
+    ///
+    /// Returns [`None`] if the path has no file name or the counter would overflow.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Path, UniquifyOptions, UnixEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// let options = UniquifyOptions::new().prefix("_v").suffix("").max_component_len(Some(11));
+    /// let path = Path::<UnixEncoding>::new("/srv/data_export.csv")
+    ///     .uniquify_with(&options, |p| p.as_bytes().ends_with(b"export.csv"))
+    ///     .unwrap();
+    /// assert_eq!(path.as_bytes(), b"/srv/data_v1.csv");
+    /// ```
+    pub fn uniquify_with<F>(&self, options: &UniquifyOptions, mut exists: F) -> Option<PathBuf<T>>
+    where
+        F: FnMut(&Path<T>) -> bool,
+    {
+        let stem = self.file_stem()?;
+        if !exists(self) {
+            return Some(self.to_path_buf());
+        }
+
+        let extension = self.extension();
+        let mut n = options.first();
+        loop {
+            let candidate = self.with_file_name(options.file_name(stem, extension, n));
+            if !exists(&candidate) {
+                return Some(candidate);
+            }
+            n = n.checked_add(1)?;
+        }
+    }
+
+    /// Returns the cost of editing `self` into `other` according to the cost model in `options`,
+    /// comparing the two paths component by component.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Path, SuggestOptions, UnixEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// let path = Path::<UnixEncoding>::new("src/mian.rs");
+    /// assert_eq!(path.edit_distance("src/main.rs", &SuggestOptions::new()), 2);
+    ///
+    /// // Removing the `src` component costs its three characters and the component itself
+    /// assert_eq!(path.edit_distance("mian.rs", &SuggestOptions::new()), 5);
+    /// assert_eq!(path.edit_distance("mian.rs", &SuggestOptions::new().component(0)), 3);
+    /// ```
+    pub fn edit_distance<P: AsRef<Path<T>>>(&self, other: P, options: &SuggestOptions) -> usize {
+        let a: Vec<_> = self.components().map(|c| c.as_bytes()).collect();
+        let b: Vec<_> = other.as_ref().components().map(|c| c.as_bytes()).collect();
+        options.distance(&a, &b, u8::eq_ignore_ascii_case)
+    }
+
+    /// Returns the candidate closest to `self` by [`Path::edit_distance`] using the default
+    /// [`SuggestOptions`], such as to power a "did you mean" message, or `None` if no candidate
+    /// is within a third of the length of `self`.
+    ///
+    /// When several candidates are equally close, the first of them is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Path, UnixEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// let candidates = ["src/lib.rs", "src/main.rs", "tests/main.rs"];
+    ///
+    /// let path = Path::<UnixEncoding>::new("src/mian.rs");
+    /// assert_eq!(path.suggest_nearest(candidates), Some("src/main.rs"));
+    ///
+    /// let path = Path::<UnixEncoding>::new("docs/guide.md");
+    /// assert_eq!(path.suggest_nearest(candidates), None);
+    /// ```
+    pub fn suggest_nearest<I, P>(&self, candidates: I) -> Option<P>
+    where
+        I: IntoIterator<Item = P>,
+        P: AsRef<Path<T>>,
+    {
+        self.suggest_nearest_with(candidates, &SuggestOptions::new())
+    }
+
+    /// Returns the candidate closest to `self` by [`Path::edit_distance`] using the cost model in
+    /// `options`, or `None` if no candidate is within its maximum distance.
     ///
-    /// See [`PathBuf::push`] for more details on what it means to adjoin a path.
+    /// When several candidates are equally close, the first of them is returned.
     ///
     /// # Examples
     ///
     /// ```
-    /// use typed_path::{Path, PathBuf, UnixEncoding};
+    /// use typed_path::{Path, SuggestOptions, WindowsEncoding};
     ///
     /// // NOTE: A path cannot be created on its own without a defined encoding
-    /// assert_eq!(
-    ///     Path::<UnixEncoding>::new("/etc").join("passwd"),
-    ///     PathBuf::from("/etc/passwd"),
-    /// );
+    /// let candidates = [r"C:\Users\Public", r"C:\Users\public2"];
+    /// let path = Path::<WindowsEncoding>::new(r"C:\users\PUBLIC");
+    ///
+    /// let options = SuggestOptions::new().case_change(0).max_distance(Some(0));
+    /// assert_eq!(path.suggest_nearest_with(candidates, &options), Some(r"C:\Users\Public"));
     /// ```
-    pub fn join<P: AsRef<Path<T>>>(&self, path: P) -> PathBuf<T> {
-        self._join(path.as_ref())
-    }
-
-    fn _join(&self, path: &Path<T>) -> PathBuf<T> {
-        let mut buf = self.to_path_buf();
-        buf.push(path);
-        buf
+    pub fn suggest_nearest_with<I, P>(&self, candidates: I, options: &SuggestOptions) -> Option<P>
+    where
+        I: IntoIterator<Item = P>,
+        P: AsRef<Path<T>>,
+    {
+        let mut limit = options.limit(self.as_bytes().len());
+        let mut best = None;
+        for candidate in candidates {
+            let distance = self.edit_distance(&candidate, options);
+            if distance <= limit {
+                // Only a strictly closer candidate can replace the first one found
+                limit = match distance.checked_sub(1) {
+                    Some(limit) => limit,
+                    None => return Some(candidate),
+                };
+                best = Some(candidate);
+            }
+        }
+        best
     }
 
-    /// Creates an owned [`PathBuf`] with `path` adjoined to `self`, checking the `path` to ensure
-    /// it is safe to join. _When dealing with user-provided paths, this is the preferred method._
+    /// Renders `self` for display within `max_width` columns by replacing leading directories with
+    /// `…`, such as `/…/deep/dir/file.rs` or `C:\…\file.rs`, with any non-Unicode data replaced
+    /// with [`U+FFFD REPLACEMENT CHARACTER`][U+FFFD].
     ///
-    /// See [`PathBuf::push_checked`] for more details on what it means to adjoin a path safely.
+    /// The prefix, root, and file name are always kept, so the result can still be wider than
+    /// `max_width` when they alone do not fit. Width is measured in chars, or in terminal columns
+    /// when the `unicode-width` feature is enabled.
+    ///
+    /// [U+FFFD]: core::char::REPLACEMENT_CHARACTER
     ///
     /// # Examples
     ///
     /// ```
-    /// use typed_path::{CheckedPathError, Path, PathBuf, UnixEncoding};
+    /// use typed_path::{Path, UnixEncoding, WindowsEncoding};
     ///
     /// // NOTE: A path cannot be created on its own without a defined encoding
-    /// let path = Path::<UnixEncoding>::new("/etc");
-    ///
-    /// // A valid path can be joined onto the existing one
-    /// assert_eq!(path.join_checked("passwd"), Ok(PathBuf::from("/etc/passwd")));
-    ///
-    /// // An invalid path will result in an error
-    /// assert_eq!(path.join_checked("/sneaky/replacement"), Err(CheckedPathError::UnexpectedRoot));
-    /// ```
-    pub fn join_checked<P: AsRef<Path<T>>>(&self, path: P) -> Result<PathBuf<T>, CheckedPathError> {
-        self._join_checked(path.as_ref())
-    }
+    /// let path = Path::<UnixEncoding>::new("/home/user/src/deep/dir/file.rs");
+    /// assert_eq!(path.abbreviate(40), "/home/user/src/deep/dir/file.rs");
+    /// assert_eq!(path.abbreviate(20), "/…/deep/dir/file.rs");
+    /// assert_eq!(path.abbreviate(5), "/…/file.rs");
+    ///
+    /// let path = Path::<WindowsEncoding>::new(r"C:\Users\user\Documents\file.rs");
+    /// assert_eq!(path.abbreviate(12), r"C:\…\file.rs");
+    /// ```
+    pub fn abbreviate(&self, max_width: usize) -> String {
+        let path = self.to_string_lossy();
+        if display_width(&path) <= max_width {
+            return path.into_owned();
+        }
 
-    fn _join_checked(&self, path: &Path<T>) -> Result<PathBuf<T>, CheckedPathError> {
-        let mut buf = self.to_path_buf();
-        buf.push_checked(path)?;
-        Ok(buf)
+        let mut head = String::new();
+        let mut rest = Vec::new();
+        for component in self.components() {
+            let s = String::from_utf8_lossy(component.as_bytes());
+            match component.kind() {
+                ComponentKind::Prefix | ComponentKind::RootDir if rest.is_empty() => {
+                    head.push_str(&s)
+                }
+                _ => rest.push(s),
+            }
+        }
+
+        match rest.split_last() {
+            Some((name, dirs)) if !dirs.is_empty() => {
+                let dirs: Vec<&str> = dirs.iter().map(|dir| dir.as_ref()).collect();
+                abbreviate(&head, &dirs, name, T::info().separator, max_width)
+            }
+            _ => path.into_owned(),
+        }
     }
 
-    /// Creates an owned [`PathBuf`] like `self` but with the given file name.
+    /// Creates an owned [`PathBuf`] like `self` but with every normal component shortened to at
+    /// most `n_bytes` bytes.
     ///
-    /// See [`PathBuf::set_file_name`] for more details.
+    /// The extension of a component is preserved whenever it fits alongside part of the stem,
+    /// and components are never cut in the middle of a UTF-8 sequence, meaning that a component
+    /// may end up shorter than `n_bytes`. Separators and all other components are left as-is.
     ///
     /// # Examples
     ///
     /// ```
-    /// use typed_path::{Path, PathBuf, UnixEncoding};
+    /// use typed_path::{Path, UnixEncoding};
     ///
     /// // NOTE: A path cannot be created on its own without a defined encoding
-    /// let path = Path::<UnixEncoding>::new("/tmp/foo.txt");
-    /// assert_eq!(path.with_file_name("bar.txt"), PathBuf::from("/tmp/bar.txt"));
+    /// let path = Path::<UnixEncoding>::new("/very_long_directory/some_file_name.txt");
+    /// assert_eq!(path.truncate_component_to(10).as_bytes(), b"/very_long_/some_f.txt");
     ///
-    /// // NOTE: A path cannot be created on its own without a defined encoding
-    /// let path = Path::<UnixEncoding>::new("/tmp");
-    /// assert_eq!(path.with_file_name("var"), PathBuf::from("/var"));
+    /// // Multi-byte characters are kept whole
+    /// let path = Path::<UnixEncoding>::new("/caf\u{e9}.txt");
+    /// assert_eq!(path.truncate_component_to(8).as_bytes(), b"/caf.txt");
     /// ```
-    pub fn with_file_name<S: AsRef<[u8]>>(&self, file_name: S) -> PathBuf<T> {
-        self._with_file_name(file_name.as_ref())
-    }
+    pub fn truncate_component_to(&self, n_bytes: usize) -> PathBuf<T> {
+        let mut path = PathBuf::<T>::new();
 
-    fn _with_file_name(&self, file_name: &[u8]) -> PathBuf<T> {
-        let mut buf = self.to_path_buf();
-        buf.set_file_name(file_name);
-        buf
+        for component in self.raw_components() {
+            if component.kind == ComponentKind::Normal {
+                let (stem, ext) = truncate_name(component.raw, n_bytes);
+                path.inner.extend_from_slice(stem);
+                path.inner.extend_from_slice(ext);
+            } else {
+                path.inner.extend_from_slice(component.raw);
+            }
+            path.inner.extend_from_slice(component.gap);
+        }
+
+        path
     }
 
-    /// Creates an owned [`PathBuf`] like `self` but with the given extension.
+    /// Creates an owned [`PathBuf`] like `self` but with its [`file_name`] shortened such that
+    /// the entire path is at most `total_bytes` bytes, preserving the extension when possible.
     ///
-    /// See [`PathBuf::set_extension`] for more details.
+    /// Returns [`None`] if the path has no file name or cannot be made to fit without removing
+    /// the file name entirely. See [`Path::truncate_component_to`] for how the file name is
+    /// shortened.
+    ///
+    /// [`file_name`]: Path::file_name
     ///
     /// # Examples
     ///
     /// ```
-    /// use typed_path::{Path, PathBuf, UnixEncoding};
+    /// use typed_path::{Path, UnixEncoding};
     ///
     /// // NOTE: A path cannot be created on its own without a defined encoding
-    /// let path = Path::<UnixEncoding>::new("foo.rs");
-    /// assert_eq!(path.with_extension("txt"), PathBuf::from("foo.txt"));
+    /// let path = Path::<UnixEncoding>::new("/cache/0123456789abcdef.json");
+    /// assert_eq!(path.fit_within(30).unwrap().as_bytes(), path.as_bytes());
+    /// assert_eq!(path.fit_within(20).unwrap().as_bytes(), b"/cache/01234567.json");
     ///
-    /// // NOTE: A path cannot be created on its own without a defined encoding
-    /// let path = Path::<UnixEncoding>::new("foo.tar.gz");
-    /// assert_eq!(path.with_extension(""), PathBuf::from("foo.tar"));
-    /// assert_eq!(path.with_extension("xz"), PathBuf::from("foo.tar.xz"));
-    /// assert_eq!(path.with_extension("").with_extension("txt"), PathBuf::from("foo.txt"));
+    /// // The parent alone is too long
+    /// assert_eq!(path.fit_within(7), None);
     /// ```
-    pub fn with_extension<S: AsRef<[u8]>>(&self, extension: S) -> PathBuf<T> {
-        self._with_extension(extension.as_ref())
-    }
+    pub fn fit_within(&self, total_bytes: usize) -> Option<PathBuf<T>> {
+        let len = self.as_bytes().len();
+        if len <= total_bytes {
+            return Some(self.to_path_buf());
+        }
 
-    fn _with_extension(&self, extension: &[u8]) -> PathBuf<T> {
-        let mut buf = self.to_path_buf();
-        buf.set_extension(extension);
-        buf
+        let components = self.raw_components();
+        let (last, rest) = components.split_last()?;
+        if last.kind != ComponentKind::Normal {
+            return None;
+        }
+
+        let excess = len - total_bytes;
+        let (stem, ext) = truncate_name(last.raw, last.raw.len().checked_sub(excess)?);
+        if stem.is_empty() {
+            return None;
+        }
+
+        let mut path = PathBuf::<T>::new();
+        for component in rest {
+            path.inner.extend_from_slice(component.raw);
+            path.inner.extend_from_slice(component.gap);
+        }
+        path.inner.extend_from_slice(stem);
+        path.inner.extend_from_slice(ext);
+        path.inner.extend_from_slice(last.gap);
+
+        Some(path)
     }
 
     /// Produces an iterator over the [`Component`]s of the path.
@@ -834,6 +2767,53 @@ where
         Iter::new(self.components())
     }
 
+    /// Produces an iterator over the path as it was written, alternating between components and
+    /// runs of separators.
+    ///
+    /// Unlike [`Path::components`], nothing is normalized away: repeated separators stay in a
+    /// single [`PathSegment::Separator`], a root directory is yielded as part of the separators
+    /// that follow it, and any `.` that parsing would skip is yielded as a component. Joining the
+    /// bytes of every segment reproduces the path byte for byte.
+    ///
+    /// [`PathSegment::Separator`]: crate::PathSegment::Separator
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Path, PathSegment, UnixEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// let path = Path::<UnixEncoding>::new("//srv/./www//");
+    /// let segments: Vec<_> = path.segments_with_separators().collect();
+    /// assert_eq!(
+    ///     segments,
+    ///     [
+    ///         PathSegment::Separator(b"//"),
+    ///         PathSegment::Component(b"srv"),
+    ///         PathSegment::Separator(b"/"),
+    ///         PathSegment::Component(b"."),
+    ///         PathSegment::Separator(b"/"),
+    ///         PathSegment::Component(b"www"),
+    ///         PathSegment::Separator(b"//"),
+    ///     ],
+    /// );
+    ///
+    /// // Rename a component while keeping the rest of the path untouched
+    /// let renamed: Vec<u8> = segments
+    ///     .iter()
+    ///     .flat_map(|s| match s {
+    ///         PathSegment::Component(b"www") => b"web".as_slice(),
+    ///         s => s.as_bytes(),
+    ///     })
+    ///     .copied()
+    ///     .collect();
+    /// assert_eq!(renamed, b"//srv/./web//");
+    /// ```
+    #[inline]
+    pub fn segments_with_separators(&self) -> SegmentsWithSeparators<'_, T> {
+        SegmentsWithSeparators::new(self.as_bytes())
+    }
+
     /// Returns an object that implements [`Display`] for safely printing paths
     /// that may contain non-Unicode data. This may perform lossy conversion,
     /// depending on the platform.  If you would like an implementation which
@@ -857,6 +2837,141 @@ where
         Display { path: self }
     }
 
+    /// Returns an object that implements [`Display`] for printing paths with any non-Unicode
+    /// data escaped as `\xNN` rather than lossily replaced, optionally surrounded by quotes.
+    ///
+    /// This is suitable for logs and error messages, where [`to_string_lossy`] would discard
+    /// the bytes needed to identify the offending file.
+    ///
+    /// [`Display`]: fmt::Display
+    /// [`to_string_lossy`]: Path::to_string_lossy
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Path, UnixEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// let path = Path::<UnixEncoding>::new(b"/srv/upload-\xff\xfe.bin");
+    ///
+    /// assert_eq!(path.to_string_lossy(), "/srv/upload-\u{FFFD}\u{FFFD}.bin");
+    /// assert_eq!(path.escaped_display().to_string(), r"/srv/upload-\xFF\xFE.bin");
+    /// assert_eq!(
+    ///     format!("cannot open {}", path.escaped_display().quoted()),
+    ///     r#"cannot open "/srv/upload-\xFF\xFE.bin""#,
+    /// );
+    /// ```
+    #[inline]
+    pub fn escaped_display(&self) -> EscapedDisplay<'_> {
+        EscapedDisplay::new(&self.inner)
+    }
+
+    /// Returns an object that implements [`Display`] for printing `self` relative to the `home`
+    /// directory, such as `~/project/x` for `/home/me/project/x`, or `%USERPROFILE%\Desktop` for
+    /// Windows paths. Paths outside of `home` are printed in full.
+    ///
+    /// Components are compared using the case rules of the encoding, as with
+    /// [`Path::is_ancestor_of`], and any non-Unicode data is lossily converted as with
+    /// [`Path::display`]. The placeholder standing for `home` can be changed with
+    /// [`HomeDisplay::placeholder`].
+    ///
+    /// [`Display`]: fmt::Display
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Path, UnixEncoding, WindowsEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// let home = Path::<UnixEncoding>::new("/home/me");
+    /// let path = Path::<UnixEncoding>::new("/home/me/project/x");
+    /// assert_eq!(path.display_relative_to_home(home).to_string(), "~/project/x");
+    /// assert_eq!(home.display_relative_to_home(home).to_string(), "~");
+    ///
+    /// let path = Path::<UnixEncoding>::new("/home/meg/notes");
+    /// assert_eq!(path.display_relative_to_home(home).to_string(), "/home/meg/notes");
+    ///
+    /// let home = Path::<WindowsEncoding>::new(r"C:\Users\Me");
+    /// let path = Path::<WindowsEncoding>::new(r"c:\users\me\Desktop\todo.txt");
+    /// assert_eq!(
+    ///     path.display_relative_to_home(home).to_string(),
+    ///     r"%USERPROFILE%\Desktop\todo.txt",
+    /// );
+    /// ```
+    #[inline]
+    pub fn display_relative_to_home<'a>(&'a self, home: &'a Path<T>) -> HomeDisplay<'a, T> {
+        HomeDisplay {
+            path: self,
+            home,
+            placeholder: if self.has_windows_encoding() {
+                "%USERPROFILE%"
+            } else {
+                "~"
+            },
+        }
+    }
+
+    /// Produces an owned key whose plain byte order matches the order of paths as trees, so that
+    /// paths can be stored in key-value stores like LMDB or RocksDB without a custom comparator.
+    ///
+    /// Keys compare component by component, so a path sorts right before its descendants, which
+    /// all sort before the next sibling of the path, even when that sibling shares a prefix (e.g.
+    /// `foo` < `foo/bar` < `foo-bar`). For Unix paths, comparing keys gives the same result as
+    /// comparing the paths themselves, while Windows prefixes are compared by their bytes.
+    ///
+    /// Paths that are equal, such as `a/b` and `a//b/`, produce the same key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Path, UnixEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// let mut paths = vec!["/foo-bar", "/foo/bar", "/foo", "/foo/", "/"];
+    /// paths.sort_by_key(|p| Path::<UnixEncoding>::new(p).sort_key());
+    /// assert_eq!(paths, ["/", "/foo", "/foo/", "/foo/bar", "/foo-bar"]);
+    ///
+    /// // Byte order of the paths themselves puts `-` before `/`
+    /// assert!("/foo-bar" < "/foo/bar");
+    /// ```
+    pub fn sort_key(&self) -> Vec<u8> {
+        let mut key = Vec::with_capacity(self.inner.len() + 8);
+        for component in self.components() {
+            sort_key::push_component(&mut key, component.kind(), component.as_bytes());
+        }
+        key
+    }
+
+    /// Returns the bounds of the [sort keys] covering exactly `self` and its descendants, for
+    /// prefix scans over a key-value store ordered by sort key.
+    ///
+    /// Unlike scanning for keys that merely start with the bytes of `self`, the bounds never
+    /// include siblings that share a prefix, so `foo-bar` is not part of the subtree of `foo`.
+    /// The end bound is [`Unbounded`] only for an empty path, which has no components to bound.
+    ///
+    /// [sort keys]: Path::sort_key
+    /// [`Unbounded`]: Bound::Unbounded
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::BTreeMap;
+    /// use typed_path::{Path, UnixEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// let mut store = BTreeMap::new();
+    /// for path in ["/foo", "/foo/a", "/foo/b/c", "/foo-bar", "/fo"] {
+    ///     store.insert(Path::<UnixEncoding>::new(path).sort_key(), path);
+    /// }
+    ///
+    /// let bounds = Path::<UnixEncoding>::new("/foo").range_bounds_for_subtree();
+    /// let subtree: Vec<_> = store.range(bounds).map(|(_, path)| *path).collect();
+    /// assert_eq!(subtree, ["/foo", "/foo/a", "/foo/b/c"]);
+    /// ```
+    pub fn range_bounds_for_subtree(&self) -> (Bound<Vec<u8>>, Bound<Vec<u8>>) {
+        sort_key::subtree_bounds(self.sort_key())
+    }
+
     /// Creates an owned [`PathBuf`] like `self` but with a different encoding.
     ///
     /// # Note
@@ -893,6 +3008,13 @@ where
     where
         U: for<'enc> Encoding<'enc>,
     {
+        trace_span!(
+            "with_encoding",
+            from = T::label(),
+            to = U::label(),
+            len = self.inner.len(),
+        );
+
         // If we're the same, just return the path buf, which
         // we do with a fancy trick to convert it
         if T::label() == U::label() {
@@ -973,6 +3095,13 @@ where
     where
         U: for<'enc> Encoding<'enc>,
     {
+        trace_span!(
+            "with_encoding_checked",
+            from = T::label(),
+            to = U::label(),
+            len = self.inner.len(),
+        );
+
         let mut path = PathBuf::new();
 
         // For root, current, and parent we specially handle to convert to the appropriate type,
@@ -1003,6 +3132,118 @@ where
         Ok(path)
     }
 
+    /// Like [`with_encoding_checked`], creates an owned [`PathBuf`] like `self` but with a
+    /// different encoding. Additionally, any byte of a normal component that cannot appear
+    /// within a filename of the new encoding is handled according to `policy`.
+    ///
+    /// A component is rewritten whenever it would not be kept as a single valid filename in the
+    /// new encoding, such as a Unix filename containing `\` or `:` being converted to Windows.
+    ///
+    /// [`with_encoding_checked`]: Path::with_encoding_checked
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CheckedPathError::InvalidFilename`] if a component cannot be represented and
+    /// `policy` is [`LossyPolicy::Error`], or if the replacement itself is not allowed within a
+    /// filename of the new encoding.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{CheckedPathError, LossyPolicy, Path, UnixEncoding, WindowsEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// let unix_path = Path::<UnixEncoding>::new(r"/notes/a\b:c?.txt");
+    ///
+    /// let windows_path = unix_path
+    ///     .with_encoding_lossy_with::<WindowsEncoding>(LossyPolicy::Replace('_'))
+    ///     .unwrap();
+    /// assert_eq!(windows_path.as_bytes(), br"\notes\a_b_c_.txt");
+    ///
+    /// let windows_path = unix_path
+    ///     .with_encoding_lossy_with::<WindowsEncoding>(LossyPolicy::PercentEncode)
+    ///     .unwrap();
+    /// assert_eq!(windows_path.as_bytes(), br"\notes\a%5Cb%3Ac%3F.txt");
+    ///
+    /// assert_eq!(
+    ///     unix_path.with_encoding_lossy_with::<WindowsEncoding>(LossyPolicy::Error),
+    ///     Err(CheckedPathError::InvalidFilename),
+    /// );
+    ///
+    /// // Replacing with a character that is itself not allowed also fails
+    /// assert_eq!(
+    ///     unix_path.with_encoding_lossy_with::<WindowsEncoding>(LossyPolicy::Replace('*')),
+    ///     Err(CheckedPathError::InvalidFilename),
+    /// );
+    /// ```
+    pub fn with_encoding_lossy_with<U>(
+        &self,
+        policy: LossyPolicy,
+    ) -> Result<PathBuf<U>, CheckedPathError>
+    where
+        U: for<'enc> Encoding<'enc>,
+    {
+        trace_span!(
+            "with_encoding_lossy_with",
+            from = T::label(),
+            to = U::label(),
+            len = self.inner.len(),
+        );
+
+        let mut path = PathBuf::new();
+
+        // For root, current, and parent we specially handle to convert to the appropriate type,
+        // otherwise we make sure each normal component survives as a single valid filename
+        for component in self.components() {
+            if component.is_root() {
+                path.push(
+                    <<<U as Encoding>::Components as Components>::Component as Component>::root()
+                        .as_bytes(),
+                );
+            } else if component.is_current() {
+                path.push(
+                    <<<U as Encoding>::Components as Components>::Component as Component>::current(
+                    )
+                    .as_bytes(),
+                );
+            } else if component.is_parent() {
+                path.push(
+                    <<<U as Encoding>::Components as Components>::Component as Component>::parent()
+                        .as_bytes(),
+                );
+            } else if Path::<U>::new(component.as_bytes()).is_single_filename() {
+                path.push_checked(component.as_bytes())?;
+            } else {
+                let bytes = policy
+                    .sanitize(component.as_bytes(), Path::<U>::is_filename_byte)
+                    .ok_or(CheckedPathError::InvalidFilename)?;
+
+                if !Path::<U>::new(&bytes).is_single_filename() {
+                    return Err(CheckedPathError::InvalidFilename);
+                }
+
+                path.push_checked(bytes)?;
+            }
+        }
+
+        Ok(path)
+    }
+
+    /// Returns whether the path consists of exactly one valid normal component
+    fn is_single_filename(&self) -> bool {
+        let mut components = self.components();
+        match (components.next(), components.next()) {
+            (Some(component), None) => component.is_normal() && component.is_valid(),
+            _ => false,
+        }
+    }
+
+    /// Returns whether `byte` is allowed within a normal component of the encoding
+    fn is_filename_byte(byte: u8) -> bool {
+        // NOTE: Only ascii bytes are ever disallowed or given special meaning by an encoding
+        !byte.is_ascii() || Path::<T>::new(&[b'a', byte, b'a']).is_single_filename()
+    }
+
     /// Converts a [`Box<Path>`](Box) into a
     /// [`PathBuf`] without copying or allocating.
     pub fn into_path_buf(self: Box<Path<T>>) -> PathBuf<T> {
@@ -1489,3 +3730,137 @@ mod helpers {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        Plan9Encoding, PortableEncoding, PowerShellEncoding, SchemedEncoding, UnixEncoding,
+        WindowsEncoding,
+    };
+
+    fn check_empty<T>()
+    where
+        T: for<'enc> Encoding<'enc> + 'static,
+    {
+        let empty = Path::<T>::EMPTY;
+        let label = T::label();
+
+        assert!(empty.is_empty(), "{label}");
+        assert!(empty.is_relative(), "{label}");
+        assert!(!empty.has_root(), "{label}");
+        assert_eq!(empty.components().count(), 0, "{label}");
+        assert_eq!(empty.parent(), None, "{label}");
+        assert_eq!(empty.file_name(), None, "{label}");
+        assert_eq!(empty.file_stem(), None, "{label}");
+        assert_eq!(empty.extension(), None, "{label}");
+        assert_eq!(empty.ancestors().collect::<Vec<_>>(), [empty], "{label}");
+        assert!(
+            empty.starts_with(empty) && empty.ends_with(empty),
+            "{label}"
+        );
+        assert!(Path::<T>::new("a").starts_with(empty), "{label}");
+        assert_eq!(Path::<T>::new("a").strip_prefix(empty), Ok(Path::new("a")));
+
+        assert_eq!(empty.join("a"), Path::new("a"), "{label}");
+        assert_eq!(Path::<T>::new("a").join(empty), Path::new("a"), "{label}");
+        assert_eq!(empty.join(empty), empty, "{label}");
+        assert_eq!(empty.normalize(), empty, "{label}");
+        assert_eq!(empty.clean(), Path::new("."), "{label}");
+        assert_eq!(empty.with_extension("x"), empty, "{label}");
+        assert_eq!(empty.with_file_name("x"), Path::new("x"), "{label}");
+
+        let mut buf = PathBuf::<T>::new();
+        assert!(!buf.pop(), "{label}");
+        assert!(!buf.set_extension("x"), "{label}");
+        assert_eq!(buf, empty, "{label}");
+    }
+
+    #[test]
+    fn empty_path_should_behave_the_same_with_every_encoding() {
+        check_empty::<UnixEncoding>();
+        check_empty::<WindowsEncoding>();
+        check_empty::<Plan9Encoding>();
+        check_empty::<PortableEncoding>();
+        check_empty::<PowerShellEncoding>();
+        check_empty::<SchemedEncoding>();
+    }
+
+    #[test]
+    fn strip_prefix_should_hint_at_the_least_relaxed_comparison_that_matches() {
+        let unix = [
+            ("/a/b/c", "/a/x/../b", Some(StripPrefixHint::Normalize)),
+            ("/A/b", "/a", Some(StripPrefixHint::IgnoreCase)),
+            ("./a/b", "a", Some(StripPrefixHint::Normalize)),
+            (
+                "/A/x/../b/c",
+                "/a/b",
+                Some(StripPrefixHint::IgnoreCaseAndNormalize),
+            ),
+            ("/a/b", "/b", None),
+            ("a/b", "/a", None),
+        ];
+        for (path, base, hint) in unix {
+            let err = Path::<UnixEncoding>::new(path)
+                .strip_prefix(base)
+                .unwrap_err();
+            assert_eq!(err.would_match_if(), hint, "{path} {base}");
+        }
+
+        let windows = [
+            (r"C:\Users", r"c:\", Some(StripPrefixHint::IgnoreCase)),
+            (
+                r"\\srv\share\a",
+                r"//srv/share",
+                Some(StripPrefixHint::Normalize),
+            ),
+            (r"\\?\C:\a\b", r"C:\a", None),
+            (r"C:\a", r"D:\a", None),
+        ];
+        for (path, base, hint) in windows {
+            let err = Path::<WindowsEncoding>::new(path)
+                .strip_prefix(base)
+                .unwrap_err();
+            assert_eq!(err.would_match_if(), hint, "{path} {base}");
+        }
+
+        let err = Path::<UnixEncoding>::new("/A")
+            .strip_prefix("/a")
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "prefix not found, but would match ignoring case"
+        );
+    }
+
+    #[cfg(all(feature = "std", unix))]
+    #[test]
+    fn empty_path_should_match_std_except_when_joined_onto() {
+        use std::os::unix::ffi::OsStrExt;
+        use std::path::Path as StdPath;
+
+        let std_empty = StdPath::new("");
+        let empty = Path::<UnixEncoding>::EMPTY;
+
+        assert_eq!(empty.parent().is_none(), std_empty.parent().is_none());
+        assert_eq!(empty.file_name().is_none(), std_empty.file_name().is_none());
+        assert_eq!(empty.components().count(), std_empty.components().count());
+        assert_eq!(empty.ancestors().count(), std_empty.ancestors().count());
+        assert_eq!(
+            empty.join("a").as_bytes(),
+            std_empty.join("a").as_os_str().as_bytes()
+        );
+        assert_eq!(
+            empty.with_file_name("x").as_bytes(),
+            std_empty.with_file_name("x").as_os_str().as_bytes()
+        );
+
+        // std adds a trailing separator when joining an empty path, which would turn `a` into a
+        // directory-looking `a/`
+        assert_eq!(Path::<UnixEncoding>::new("a").join(empty).as_bytes(), b"a");
+        assert_eq!(
+            StdPath::new("a").join(std_empty).as_os_str().as_bytes(),
+            b"a/"
+        );
+    }
+}
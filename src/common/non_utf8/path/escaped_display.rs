@@ -0,0 +1,135 @@
+use core::fmt::{self, Write};
+
+/// Helper struct for printing paths with any non-Unicode data escaped.
+///
+/// Unlike [`Display`], which replaces invalid UTF-8 with [`U+FFFD REPLACEMENT CHARACTER`],
+/// this `struct` writes every byte that is not part of valid UTF-8 as `\xNN`, so the exact bytes
+/// of the path can be recovered from logs and error messages. Control characters are escaped
+/// the same way (or as `\t`, `\n`, and `\r`). Backslashes are written as is so that Windows paths
+/// remain readable.
+///
+/// It is created by the [`escaped_display`](crate::Path::escaped_display) method on
+/// [`Path`](crate::Path).
+///
+/// # Examples
+///
+/// ```
+/// use typed_path::{Path, UnixEncoding};
+///
+/// // NOTE: A path cannot be created on its own without a defined encoding
+/// let path = Path::<UnixEncoding>::new(b"/tmp/caf\xe9\n.rs");
+///
+/// assert_eq!(path.escaped_display().to_string(), r"/tmp/caf\xE9\n.rs");
+/// assert_eq!(path.escaped_display().quoted().to_string(), r#""/tmp/caf\xE9\n.rs""#);
+/// ```
+///
+/// [`Display`]: crate::Display
+/// [`U+FFFD REPLACEMENT CHARACTER`]: char::REPLACEMENT_CHARACTER
+#[derive(Copy, Clone, Debug)]
+pub struct EscapedDisplay<'a> {
+    pub(crate) bytes: &'a [u8],
+    pub(crate) quoted: bool,
+    pub(crate) debug: bool,
+}
+
+impl<'a> EscapedDisplay<'a> {
+    pub(crate) fn new(bytes: &'a [u8]) -> Self {
+        Self {
+            bytes,
+            quoted: false,
+            debug: false,
+        }
+    }
+
+    /// Writes the path within quotes, escaping valid UTF-8 the same way [`str`] does for
+    /// [`fmt::Debug`] while still writing invalid bytes as `\xNN`
+    pub(crate) fn debug(self) -> Self {
+        Self {
+            quoted: true,
+            debug: true,
+            ..self
+        }
+    }
+
+    /// Surrounds the path with double quotes, escaping any double quotes within the path as `\"`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Path, UnixEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// let path = Path::<UnixEncoding>::new(r#"/tmp/"draft".txt"#);
+    /// assert_eq!(
+    ///     path.escaped_display().quoted().to_string(),
+    ///     r#""/tmp/\"draft\".txt""#,
+    /// );
+    /// ```
+    pub fn quoted(self) -> Self {
+        Self {
+            quoted: true,
+            ..self
+        }
+    }
+
+    fn write_str_escaped(&self, f: &mut fmt::Formatter<'_>, s: &str) -> fmt::Result {
+        for c in s.chars() {
+            if self.debug {
+                // Only the quotes surrounding the path need to be escaped
+                match c {
+                    '\'' => f.write_char(c)?,
+                    c => write!(f, "{}", c.escape_debug())?,
+                }
+                continue;
+            }
+
+            match c {
+                '\t' => f.write_str("\\t")?,
+                '\n' => f.write_str("\\n")?,
+                '\r' => f.write_str("\\r")?,
+                '"' if self.quoted => f.write_str("\\\"")?,
+                c if c.is_ascii_control() => write!(f, "\\x{:02X}", c as u8)?,
+                c => f.write_char(c)?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl fmt::Display for EscapedDisplay<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.quoted {
+            f.write_char('"')?;
+        }
+
+        let mut rest = self.bytes;
+        loop {
+            match core::str::from_utf8(rest) {
+                Ok(s) => {
+                    self.write_str_escaped(f, s)?;
+                    break;
+                }
+                Err(x) => {
+                    let (valid, invalid) = rest.split_at(x.valid_up_to());
+
+                    // SAFETY: the bytes are valid UTF-8 up to this point
+                    self.write_str_escaped(f, unsafe { core::str::from_utf8_unchecked(valid) })?;
+
+                    let len = x.error_len().unwrap_or(invalid.len());
+                    for byte in &invalid[..len] {
+                        write!(f, "\\x{:02X}", byte)?;
+                    }
+
+                    rest = &invalid[len..];
+                }
+            }
+        }
+
+        if self.quoted {
+            f.write_char('"')?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,99 @@
+use core::fmt;
+
+use crate::no_std_compat::*;
+use crate::{Components, Encoding, Path};
+
+/// Helper struct for printing paths within the home directory relative to it, such as
+/// `~/project/x`.
+///
+/// It is created by the [`display_relative_to_home`](Path::display_relative_to_home) method on
+/// [`Path`]. Paths outside of the home directory are printed in full, and any non-Unicode data is
+/// lossily converted as with [`Path::display`].
+///
+/// # Examples
+///
+/// ```
+/// use typed_path::{Path, UnixEncoding};
+///
+/// // NOTE: A path cannot be created on its own without a defined encoding
+/// let home = Path::<UnixEncoding>::new("/home/me");
+/// let path = Path::<UnixEncoding>::new("/home/me/project/x");
+///
+/// println!("{}", path.display_relative_to_home(home));
+/// ```
+pub struct HomeDisplay<'a, T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    pub(crate) path: &'a Path<T>,
+    pub(crate) home: &'a Path<T>,
+    pub(crate) placeholder: &'a str,
+}
+
+impl<'a, T> HomeDisplay<'a, T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    /// Sets the text that stands for the home directory, which is `%USERPROFILE%` for Windows
+    /// paths and `~` otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::WindowsPath;
+    ///
+    /// let home = WindowsPath::new(r"C:\Users\me");
+    /// let path = WindowsPath::new(r"C:\Users\me\Desktop");
+    ///
+    /// let display = path.display_relative_to_home(home).placeholder("~");
+    /// assert_eq!(display.to_string(), r"~\Desktop");
+    /// ```
+    pub fn placeholder(mut self, placeholder: &'a str) -> Self {
+        self.placeholder = placeholder;
+        self
+    }
+}
+
+impl<T> fmt::Debug for HomeDisplay<'_, T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("HomeDisplay")
+            .field("path", &self.path)
+            .field("home", &self.home)
+            .field("placeholder", &self.placeholder)
+            .finish()
+    }
+}
+
+impl<T> fmt::Display for HomeDisplay<'_, T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    /// Performs lossy conversion to UTF-8 str
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.home.components().next().is_none()
+            || !self.path.is_inclusive_descendant_of(self.home)
+        {
+            return write!(f, "{}", String::from_utf8_lossy(self.path.as_bytes()));
+        }
+
+        let mut components = self.path.components();
+        for _ in self.home.components() {
+            components.next();
+        }
+
+        f.write_str(self.placeholder)?;
+        let rest = components.as_bytes();
+        if !rest.is_empty() {
+            write!(
+                f,
+                "{}{}",
+                T::info().separator,
+                String::from_utf8_lossy(rest)
+            )?;
+        }
+        Ok(())
+    }
+}
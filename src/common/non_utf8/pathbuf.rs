@@ -9,7 +9,10 @@ use core::str::FromStr;
 use core::{cmp, fmt};
 
 use crate::no_std_compat::*;
-use crate::{CheckedPathError, Encoding, Iter, Path};
+use crate::{
+    CheckedPathError, Component, Components, Encoding, Iter, Path, PushMode, SegmentError,
+    SegmentErrorKind, StrictPathError,
+};
 
 /// An owned, mutable path that mirrors [`std::path::PathBuf`], but operatings using an
 /// [`Encoding`] to determine how to parse the underlying bytes.
@@ -117,6 +120,31 @@ where
         }
     }
 
+    /// Creates a `PathBuf` out of `path`, only succeeding if it passes [`Path::validate_strict`].
+    ///
+    /// Like [`Path::try_new_strict`], but takes ownership of `path` so that an owned
+    /// [`Vec<u8>`] or [`String`] is kept without copying it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{PathBuf, StrictPathError, WindowsEncoding};
+    ///
+    /// // NOTE: A pathbuf cannot be created on its own without a defined encoding
+    /// let path = PathBuf::<WindowsEncoding>::try_from_strict(r"C:\logs\today.txt").unwrap();
+    /// assert_eq!(path, PathBuf::from(r"C:\logs\today.txt"));
+    ///
+    /// assert_eq!(
+    ///     PathBuf::<WindowsEncoding>::try_from_strict(String::from(r"\\server")),
+    ///     Err(StrictPathError::MalformedPrefix),
+    /// );
+    /// ```
+    pub fn try_from_strict<V: Into<Vec<u8>>>(path: V) -> Result<Self, StrictPathError> {
+        let path = PathBuf::from(path.into());
+        path.validate_strict()?;
+        Ok(path)
+    }
+
     /// Coerces to a [`Path`] slice.
     ///
     /// # Examples
@@ -146,6 +174,9 @@ where
     ///   and `path` is not empty, the new path is normalized: all references
     ///   to `.` and `..` are removed.
     ///
+    /// If `path` is empty, `self` is left unchanged. Unlike `std::path::PathBuf::push`, no
+    /// trailing separator is added.
+    ///
     /// [`WindowsPathBuf`]: crate::WindowsPathBuf
     ///
     /// # Examples
@@ -232,6 +263,193 @@ where
         T::push_checked(&mut self.inner, path.as_ref().as_bytes())
     }
 
+    /// Like [`PathBuf::push`], extends `self` with `path`, but uses the given [`PushMode`] to
+    /// decide what happens when `path` has its own root or prefix.
+    ///
+    /// * [`PushMode::Replace`] behaves exactly like [`PathBuf::push`] and never fails.
+    /// * [`PushMode::Append`] drops any prefix and root from `path` and appends the rest as if
+    ///   it were relative, so `self` is never replaced. This never fails.
+    /// * [`PushMode::Reject`] fails if `path` has a prefix or root, leaving `self` untouched.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{CheckedPathError, PathBuf, PushMode, UnixEncoding, WindowsEncoding};
+    ///
+    /// // NOTE: A pathbuf cannot be created on its own without a defined encoding
+    /// let mut path = PathBuf::<UnixEncoding>::from("/srv/www");
+    /// path.push_with_mode("/etc", PushMode::Append).unwrap();
+    /// assert_eq!(path, PathBuf::from("/srv/www/etc"));
+    ///
+    /// assert_eq!(
+    ///     path.push_with_mode("/etc", PushMode::Reject),
+    ///     Err(CheckedPathError::UnexpectedRoot),
+    /// );
+    ///
+    /// path.push_with_mode("/etc", PushMode::Replace).unwrap();
+    /// assert_eq!(path, PathBuf::from("/etc"));
+    ///
+    /// // Windows prefixes are reported separately from roots
+    /// let mut path = PathBuf::<WindowsEncoding>::from(r"C:\srv");
+    /// assert_eq!(
+    ///     path.push_with_mode(r"D:\www", PushMode::Reject),
+    ///     Err(CheckedPathError::UnexpectedPrefix),
+    /// );
+    /// assert_eq!(
+    ///     path.push_with_mode(r"\www", PushMode::Reject),
+    ///     Err(CheckedPathError::UnexpectedRoot),
+    /// );
+    /// ```
+    pub fn push_with_mode<P: AsRef<Path<T>>>(
+        &mut self,
+        path: P,
+        mode: PushMode,
+    ) -> Result<(), CheckedPathError> {
+        let path = path.as_ref();
+        match mode {
+            PushMode::Replace => self.push(path),
+            PushMode::Append => {
+                let (_, rest) = path.split_anchor();
+                self.push(rest);
+            }
+            PushMode::Reject => match path.split_anchor() {
+                (Some(error), _) => return Err(error),
+                (None, _) => self.push(path),
+            },
+        }
+
+        Ok(())
+    }
+
+    /// Extends `self` with `path`, never replacing `self` even if `path` is absolute. This is
+    /// [`push_with_mode`] using [`PushMode::Append`].
+    ///
+    /// [`push_with_mode`]: PathBuf::push_with_mode
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{PathBuf, UnixEncoding};
+    ///
+    /// // NOTE: A pathbuf cannot be created on its own without a defined encoding
+    /// let mut path = PathBuf::<UnixEncoding>::from("/srv/www");
+    /// path.push_keeping_base("/index.html");
+    /// assert_eq!(path, PathBuf::from("/srv/www/index.html"));
+    /// ```
+    pub fn push_keeping_base<P: AsRef<Path<T>>>(&mut self, path: P) {
+        let (_, rest) = path.as_ref().split_anchor();
+        self.push(rest);
+    }
+
+    /// Extends `self` with `path`, failing if `path` has a root or prefix. This is
+    /// [`push_with_mode`] using [`PushMode::Reject`].
+    ///
+    /// [`push_with_mode`]: PathBuf::push_with_mode
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{CheckedPathError, PathBuf, UnixEncoding};
+    ///
+    /// // NOTE: A pathbuf cannot be created on its own without a defined encoding
+    /// let mut path = PathBuf::<UnixEncoding>::from("/srv");
+    /// assert_eq!(path.push_relative_only("/etc"), Err(CheckedPathError::UnexpectedRoot));
+    /// assert_eq!(path.push_relative_only("www"), Ok(()));
+    /// assert_eq!(path, PathBuf::from("/srv/www"));
+    /// ```
+    pub fn push_relative_only<P: AsRef<Path<T>>>(
+        &mut self,
+        path: P,
+    ) -> Result<(), CheckedPathError> {
+        self.push_with_mode(path, PushMode::Reject)
+    }
+
+    /// Builds a relative path out of `segments`, each of which must be exactly one normal
+    /// component. See [`PathBuf::extend_checked`] for the segments that are rejected.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{PathBuf, SegmentErrorKind, UnixEncoding};
+    ///
+    /// // NOTE: A pathbuf cannot be created on its own without a defined encoding
+    /// let path = PathBuf::<UnixEncoding>::from_segments(["static", "css", "site.css"]).unwrap();
+    /// assert_eq!(path, PathBuf::from("static/css/site.css"));
+    ///
+    /// let err = PathBuf::<UnixEncoding>::from_segments(["static", "..", "secret"]).unwrap_err();
+    /// assert_eq!(err.index(), 1);
+    /// assert_eq!(err.kind(), SegmentErrorKind::Traversal);
+    /// ```
+    pub fn from_segments<I, S>(segments: I) -> Result<Self, SegmentError>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<[u8]>,
+    {
+        let mut path = Self::new();
+        path.extend_checked(segments)?;
+        Ok(path)
+    }
+
+    /// Pushes each of `segments` onto `self`, where every segment must be exactly one normal
+    /// component, so the result can never escape `self` or replace it.
+    ///
+    /// A segment is rejected if it is empty, contains a separator, is `.` or `..`, would be read
+    /// as a prefix, or contains bytes the encoding does not allow in filenames. The error holds
+    /// the position of the first rejected segment, and `self` is left as it was before the call.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{PathBuf, SegmentErrorKind, WindowsEncoding};
+    ///
+    /// // NOTE: A pathbuf cannot be created on its own without a defined encoding
+    /// let mut path = PathBuf::<WindowsEncoding>::from(r"C:\www");
+    /// path.extend_checked(["img", "logo.png"]).unwrap();
+    /// assert_eq!(path, PathBuf::from(r"C:\www\img\logo.png"));
+    ///
+    /// let err = path.extend_checked(["a", r"b\c"]).unwrap_err();
+    /// assert_eq!((err.index(), err.kind()), (1, SegmentErrorKind::Separator));
+    ///
+    /// let err = path.extend_checked(["D:"]).unwrap_err();
+    /// assert_eq!((err.index(), err.kind()), (0, SegmentErrorKind::Prefix));
+    ///
+    /// // Nothing is pushed when a segment is rejected
+    /// assert_eq!(path, PathBuf::from(r"C:\www\img\logo.png"));
+    /// ```
+    pub fn extend_checked<I, S>(&mut self, segments: I) -> Result<(), SegmentError>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<[u8]>,
+    {
+        let len = self.inner.len();
+        for (index, segment) in segments.into_iter().enumerate() {
+            let segment = segment.as_ref();
+            if let Err(kind) = Self::check_segment(segment) {
+                self.inner.truncate(len);
+                return Err(SegmentError { index, kind });
+            }
+            self.push(Path::new(segment));
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn check_segment(segment: &[u8]) -> Result<(), SegmentErrorKind> {
+        if segment.is_empty() {
+            return Err(SegmentErrorKind::Empty);
+        } else if segment.iter().any(|b| T::is_separator(*b)) {
+            return Err(SegmentErrorKind::Separator);
+        }
+
+        let mut components = Path::<T>::new(segment).components();
+        match (components.next(), components.next()) {
+            (Some(c), None) if c.is_normal() && c.is_valid() => Ok(()),
+            (Some(c), None) if c.is_normal() => Err(SegmentErrorKind::InvalidFilename),
+            (Some(c), None) if c.is_current() || c.is_parent() => Err(SegmentErrorKind::Traversal),
+            _ => Err(SegmentErrorKind::Prefix),
+        }
+    }
+
     /// Truncates `self` to [`self.parent`].
     ///
     /// Returns `false` and does nothing if [`self.parent`] is [`None`].
@@ -262,6 +480,249 @@ where
         }
     }
 
+    /// Inserts `component` into `self` so that it becomes the component at position `index`,
+    /// shifting all components after it to the right.
+    ///
+    /// An `index` equal to the number of components appends `component` to the end of the path.
+    /// Unlike [`PathBuf::push`], `component` never replaces the existing path: any prefix or root
+    /// of `component` is left out, so only its relative part is inserted. Inserting an empty path
+    /// does nothing.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is greater than the number of components in the path, or if `index`
+    /// falls on the prefix or root of the path.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{PathBuf, UnixEncoding, WindowsEncoding};
+    ///
+    /// // NOTE: A pathbuf cannot be created on its own without a defined encoding
+    /// let mut p = PathBuf::<UnixEncoding>::from("/usr/lib");
+    ///
+    /// // Components are `/`, `usr`, and `lib`
+    /// p.insert_component(3, "bin");
+    /// assert_eq!(p.as_bytes(), b"/usr/lib/bin");
+    ///
+    /// p.insert_component(1, "opt/local");
+    /// assert_eq!(p.as_bytes(), b"/opt/local/usr/lib/bin");
+    ///
+    /// // Components following a drive-relative prefix stay drive-relative
+    /// let mut p = PathBuf::<WindowsEncoding>::from("C:");
+    /// p.insert_component(1, "foo");
+    /// assert_eq!(p.as_bytes(), b"C:foo");
+    /// ```
+    pub fn insert_component<P: AsRef<Path<T>>>(&mut self, index: usize, component: P) {
+        self._insert_component(index, component.as_ref())
+    }
+
+    fn _insert_component(&mut self, index: usize, component: &Path<T>) {
+        let spans = self.component_spans();
+        assert!(
+            index <= spans.len(),
+            "index {index} out of range for path with {} components",
+            spans.len()
+        );
+
+        let anchor_len = spans.iter().take_while(|span| !span.separated).count();
+        assert!(
+            index >= anchor_len,
+            "index {index} falls on the prefix or root of the path"
+        );
+
+        let component = component.split_anchor().1;
+        if component.inner.is_empty() {
+            return;
+        }
+
+        let separator =
+            <<<T as Encoding>::Components as Components>::Component as Component>::root();
+        let separator = separator.as_bytes();
+
+        match spans.get(index) {
+            Some(span) => {
+                let mut bytes = component.inner.to_vec();
+                if !bytes.ends_with(separator) {
+                    bytes.extend_from_slice(separator);
+                }
+                self.inner.splice(span.start..span.start, bytes);
+            }
+            None => {
+                if let Some(span) = index.checked_sub(1).map(|i| &spans[i]) {
+                    self.inner.truncate(span.end);
+                    if span.separated {
+                        self.inner.extend_from_slice(separator);
+                    }
+                }
+                self.inner.extend_from_slice(&component.inner);
+            }
+        }
+    }
+
+    /// Removes the component at position `index` within `self`, shifting all components after it
+    /// to the left.
+    ///
+    /// Returns `false` and does nothing if `index` is out of range. Otherwise, returns `true`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{PathBuf, UnixEncoding};
+    ///
+    /// // NOTE: A pathbuf cannot be created on its own without a defined encoding
+    /// let mut p = PathBuf::<UnixEncoding>::from("/usr/local/lib");
+    ///
+    /// assert!(p.remove_component(2));
+    /// assert_eq!(p.as_bytes(), b"/usr/lib");
+    ///
+    /// assert!(p.remove_component(2));
+    /// assert_eq!(p.as_bytes(), b"/usr");
+    ///
+    /// // Removing the root makes the path relative
+    /// assert!(p.remove_component(0));
+    /// assert_eq!(p.as_bytes(), b"usr");
+    ///
+    /// assert!(!p.remove_component(1));
+    /// ```
+    pub fn remove_component(&mut self, index: usize) -> bool {
+        let spans = self.component_spans();
+        if index >= spans.len() {
+            return false;
+        }
+
+        // Remove the separators that follow the component, or those that precede it when it is
+        // the last component of the path
+        match spans.get(index + 1) {
+            Some(next) => {
+                self.inner.drain(spans[index].start..next.start);
+            }
+            None => match index.checked_sub(1) {
+                Some(i) => self.inner.truncate(spans[i].end),
+                None => self.inner.clear(),
+            },
+        }
+
+        true
+    }
+
+    /// Retains only the components of `self` for which `f` returns `true`, passing each
+    /// component of the path in order.
+    ///
+    /// The separators between retained components are kept as-is.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Component, PathBuf, UnixEncoding};
+    ///
+    /// // NOTE: A pathbuf cannot be created on its own without a defined encoding
+    /// let mut p = PathBuf::<UnixEncoding>::from("./build/out/../debug/main.o");
+    ///
+    /// p.retain_components(|c| !c.is_current() && !c.is_parent());
+    /// assert_eq!(p.as_bytes(), b"build/out/debug/main.o");
+    ///
+    /// p.retain_components(|c| c.as_bytes() != b"out");
+    /// assert_eq!(p.as_bytes(), b"build/debug/main.o");
+    ///
+    /// let mut p = PathBuf::<UnixEncoding>::from("/usr/local/lib");
+    /// p.retain_components(|c| c.as_bytes() != b"usr");
+    /// assert_eq!(p.as_bytes(), b"/local/lib");
+    /// ```
+    pub fn retain_components<F>(&mut self, mut f: F)
+    where
+        F: for<'a> FnMut(<<T as Encoding<'a>>::Components as Components<'a>>::Component) -> bool,
+    {
+        let mut inner = Vec::with_capacity(self.inner.len());
+        let mut last_end = 0;
+        let mut keep = false;
+        let mut separated = false;
+
+        {
+            let mut components = self.components();
+            loop {
+                let start = self.inner.len() - components.as_bytes().len();
+                let component = match components.next() {
+                    Some(component) => component,
+                    None => break,
+                };
+
+                let end = start + component.len();
+                let is_separated =
+                    component.is_normal() || component.is_parent() || component.is_current();
+                keep = f(component);
+                if keep {
+                    if separated {
+                        inner.extend_from_slice(&self.inner[last_end..start]);
+                    }
+                    inner.extend_from_slice(&self.inner[start..end]);
+                    separated = is_separated;
+                }
+
+                last_end = end;
+            }
+        }
+
+        // Any trailing separators are kept only alongside the last component
+        if keep {
+            inner.extend_from_slice(&self.inner[last_end..]);
+        }
+
+        self.inner = inner;
+    }
+
+    /// Shortens `self` to its first `len` components, removing any separators that follow them.
+    ///
+    /// If `len` is greater than or equal to the number of components in the path, this has no
+    /// effect.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{PathBuf, UnixEncoding};
+    ///
+    /// // NOTE: A pathbuf cannot be created on its own without a defined encoding
+    /// let mut p = PathBuf::<UnixEncoding>::from("/usr/local/lib/");
+    ///
+    /// p.truncate_components(5);
+    /// assert_eq!(p.as_bytes(), b"/usr/local/lib/");
+    ///
+    /// p.truncate_components(2);
+    /// assert_eq!(p.as_bytes(), b"/usr");
+    ///
+    /// p.truncate_components(0);
+    /// assert!(p.as_bytes().is_empty());
+    /// ```
+    pub fn truncate_components(&mut self, len: usize) {
+        let spans = self.component_spans();
+        if len < spans.len() {
+            match len.checked_sub(1) {
+                Some(i) => self.inner.truncate(spans[i].end),
+                None => self.inner.clear(),
+            }
+        }
+    }
+
+    /// Returns the span of each component within `self`, parsing the path only once
+    fn component_spans(&self) -> Vec<ComponentSpan> {
+        let mut components = self.components();
+        let mut spans = Vec::new();
+
+        loop {
+            let start = self.inner.len() - components.as_bytes().len();
+            match components.next() {
+                Some(component) => spans.push(ComponentSpan {
+                    start,
+                    end: start + component.len(),
+                    separated: component.is_normal()
+                        || component.is_parent()
+                        || component.is_current(),
+                }),
+                None => return spans,
+            }
+        }
+    }
+
     /// Updates [`self.file_name`] to `file_name`.
     ///
     /// If [`self.file_name`] was [`None`], this is equivalent to pushing
@@ -358,6 +819,50 @@ where
         true
     }
 
+    /// Makes every ascii letter of the component text of `self` lowercase in place, without
+    /// reallocating.
+    ///
+    /// See [`Path::to_ascii_lowercase`] for how prefixes are handled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{PathBuf, WindowsEncoding};
+    ///
+    /// // NOTE: A pathbuf cannot be created on its own without a defined encoding
+    /// let mut path = PathBuf::<WindowsEncoding>::from(r"\\?\UNC\Server\Share\Logs\App.LOG");
+    /// path.make_ascii_lowercase();
+    /// assert_eq!(path, PathBuf::from(r"\\?\UNC\server\share\logs\app.log"));
+    /// ```
+    pub fn make_ascii_lowercase(&mut self) {
+        let keep = self.case_preserved_range();
+        self.inner[..keep.start].make_ascii_lowercase();
+        self.inner[keep.end..].make_ascii_lowercase();
+    }
+
+    /// Makes every ascii letter of the component text of `self` uppercase in place, without
+    /// reallocating.
+    ///
+    /// See [`Path::to_ascii_lowercase`] for how prefixes are handled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{PathBuf, WindowsEncoding};
+    ///
+    /// // NOTE: A pathbuf cannot be created on its own without a defined encoding
+    /// let mut path = PathBuf::<WindowsEncoding>::from(r"\\?\unc\server\share");
+    /// path.make_ascii_uppercase();
+    ///
+    /// // `unc` is a verbatim name rather than the marker of a UNC prefix, so it is kept as is
+    /// assert_eq!(path, PathBuf::from(r"\\?\unc\SERVER\SHARE"));
+    /// ```
+    pub fn make_ascii_uppercase(&mut self) {
+        let keep = self.case_preserved_range();
+        self.inner[..keep.start].make_ascii_uppercase();
+        self.inner[keep.end..].make_ascii_uppercase();
+    }
+
     /// Consumes the `PathBuf`, yielding its internal [`Vec<u8>`] storage.
     ///
     /// # Examples
@@ -373,6 +878,23 @@ where
         self.inner
     }
 
+    /// Consumes the `PathBuf`, yielding its internal [`Vec<u8>`] storage.
+    ///
+    /// This is the same as [`PathBuf::into_vec`], named after [`String::into_bytes`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{PathBuf, UnixEncoding};
+    ///
+    /// let p = PathBuf::<UnixEncoding>::from("/the/head");
+    /// assert_eq!(p.into_bytes(), b"/the/head");
+    /// ```
+    #[inline]
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.inner
+    }
+
     /// Converts this [`PathBuf`] into a [boxed](Box) [`Path`].
     #[inline]
     pub fn into_boxed_path(self) -> Box<Path<T>> {
@@ -686,3 +1208,12 @@ where
         self.components().cmp(other.components())
     }
 }
+
+/// Location of a single component within the bytes of a [`PathBuf`]
+struct ComponentSpan {
+    start: usize,
+    end: usize,
+
+    /// Whether a separator is needed between this component and the next
+    separated: bool,
+}
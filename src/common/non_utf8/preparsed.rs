@@ -0,0 +1,299 @@
+use core::fmt;
+use core::ops::Range;
+
+use crate::no_std_compat::*;
+use crate::{Component, ComponentKind, Components, Encoding, Path, PathBuf};
+
+/// An owned path alongside a table locating each of its components, so that it only needs to be
+/// parsed once.
+///
+/// Methods like [`Path::file_name`] and [`Path::parent`] parse the path every time they are
+/// called. A `PreparsedPath` parses on construction instead, making [`PreparsedPath::file_name`],
+/// [`PreparsedPath::parent`], and [`PreparsedPath::component`] constant time and letting
+/// [`PreparsedPath::starts_with`] compare components without parsing either path. The tradeoff
+/// is a small allocation for the table, so it pays off for paths that are queried repeatedly.
+///
+/// # Examples
+///
+/// ```
+/// use typed_path::{ComponentKind, Path, PreparsedPath, UnixEncoding};
+///
+/// // NOTE: A path cannot be created on its own without a defined encoding
+/// let path = PreparsedPath::<UnixEncoding>::new("/src/lib/mod.rs");
+/// assert_eq!(path.len(), 4);
+/// assert_eq!(path.file_name(), Some(b"mod.rs".as_slice()));
+/// assert_eq!(path.parent(), Some(Path::new("/src/lib")));
+/// assert_eq!(path.component(1), Some(b"src".as_slice()));
+/// assert_eq!(path.component_kind(0), Some(ComponentKind::RootDir));
+///
+/// let base = PreparsedPath::<UnixEncoding>::new("/src");
+/// assert!(path.starts_with(&base));
+/// ```
+pub struct PreparsedPath<T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    path: PathBuf<T>,
+    spans: Vec<(ComponentKind, Range<usize>)>,
+
+    /// Whether the final component is a root, which includes prefixes other than disks
+    ends_in_root: bool,
+}
+
+impl<T> PreparsedPath<T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    /// Parses `path` once, recording where each of its components sits.
+    pub fn new<P: Into<PathBuf<T>>>(path: P) -> Self {
+        let path = path.into();
+        let bytes = path.as_bytes();
+
+        let mut spans = Vec::new();
+        let mut ends_in_root = false;
+        {
+            let mut components = path.components();
+            loop {
+                // Each component starts where the bytes left by the previous one do
+                let start = bytes.len() - components.as_bytes().len();
+                match components.next() {
+                    Some(component) => {
+                        spans.push((component.kind(), start..start + component.len()));
+                        ends_in_root = component.is_root();
+                    }
+                    None => break,
+                }
+            }
+        }
+
+        Self {
+            path,
+            spans,
+            ends_in_root,
+        }
+    }
+
+    /// Returns the path that was parsed.
+    #[inline]
+    pub fn as_path(&self) -> &Path<T> {
+        self.path.as_path()
+    }
+
+    /// Consumes the `PreparsedPath`, returning the path that was parsed.
+    #[inline]
+    pub fn into_path_buf(self) -> PathBuf<T> {
+        self.path
+    }
+
+    /// Returns the number of components, matching `self.as_path().components().count()`.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.spans.len()
+    }
+
+    /// Returns true if the path has no components.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.spans.is_empty()
+    }
+
+    /// Returns the bytes of the component at `index` as they appear in the path, or [`None`] if
+    /// `index` is out of bounds.
+    pub fn component(&self, index: usize) -> Option<&[u8]> {
+        let (_, span) = self.spans.get(index)?;
+        Some(&self.path.as_bytes()[span.clone()])
+    }
+
+    /// Returns the kind of the component at `index`, or [`None`] if `index` is out of bounds.
+    #[inline]
+    pub fn component_kind(&self, index: usize) -> Option<ComponentKind> {
+        self.spans.get(index).map(|(kind, _)| *kind)
+    }
+
+    /// Returns the final component if it is a normal component, matching [`Path::file_name`].
+    pub fn file_name(&self) -> Option<&[u8]> {
+        match self.spans.last() {
+            Some((ComponentKind::Normal, span)) => Some(&self.path.as_bytes()[span.clone()]),
+            _ => None,
+        }
+    }
+
+    /// Returns the path without its final component, matching [`Path::parent`].
+    pub fn parent(&self) -> Option<&Path<T>> {
+        let (_, rest) = self.spans.split_last()?;
+        if self.ends_in_root {
+            return None;
+        }
+
+        let end = rest.last().map_or(0, |(_, span)| span.end);
+        Some(Path::new(&self.path.as_bytes()[..end]))
+    }
+
+    /// Returns true if the components of `base` are the first components of `self`, matching
+    /// [`Path::starts_with`] without parsing either path again.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{PreparsedPath, WindowsEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// let path = PreparsedPath::<WindowsEncoding>::new(r"C:\Users\me");
+    /// assert!(path.starts_with(&PreparsedPath::new("C:/Users/")));
+    /// assert!(!path.starts_with(&PreparsedPath::new(r"C:\Users\you")));
+    /// ```
+    pub fn starts_with(&self, base: &Self) -> bool {
+        base.spans.len() <= self.spans.len()
+            && (0..base.spans.len()).all(|i| {
+                // A root is the same however its separator is written
+                self.spans[i].0 == base.spans[i].0
+                    && (self.spans[i].0 == ComponentKind::RootDir
+                        || self.component(i) == base.component(i))
+            })
+    }
+}
+
+impl<T> Clone for PreparsedPath<T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            path: self.path.clone(),
+            spans: self.spans.clone(),
+            ends_in_root: self.ends_in_root,
+        }
+    }
+}
+
+impl<T> fmt::Debug for PreparsedPath<T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PreparsedPath")
+            .field("path", &self.path)
+            .field("spans", &self.spans)
+            .finish()
+    }
+}
+
+impl<T> PartialEq for PreparsedPath<T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    /// Compares the paths that were parsed, like [`Path`] does.
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.path == other.path
+    }
+}
+
+impl<T> Eq for PreparsedPath<T> where T: for<'enc> Encoding<'enc> {}
+
+impl<T> AsRef<Path<T>> for PreparsedPath<T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    #[inline]
+    fn as_ref(&self) -> &Path<T> {
+        self.as_path()
+    }
+}
+
+impl<T> From<PathBuf<T>> for PreparsedPath<T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    #[inline]
+    fn from(path: PathBuf<T>) -> Self {
+        Self::new(path)
+    }
+}
+
+impl<T> From<&Path<T>> for PreparsedPath<T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    #[inline]
+    fn from(path: &Path<T>) -> Self {
+        Self::new(path)
+    }
+}
+
+impl<T> From<PreparsedPath<T>> for PathBuf<T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    #[inline]
+    fn from(path: PreparsedPath<T>) -> Self {
+        path.into_path_buf()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{UnixEncoding, WindowsEncoding};
+
+    fn check<T>(path: &str)
+    where
+        T: for<'enc> Encoding<'enc>,
+    {
+        let expected = Path::<T>::new(path);
+        let preparsed = PreparsedPath::<T>::new(path);
+
+        assert_eq!(preparsed.len(), expected.components().count(), "{path}");
+        assert_eq!(preparsed.file_name(), expected.file_name(), "{path}");
+        assert_eq!(preparsed.parent(), expected.parent(), "{path}");
+
+        for (i, component) in expected.components().enumerate() {
+            assert_eq!(
+                preparsed.component_kind(i),
+                Some(component.kind()),
+                "{path}"
+            );
+            if component.kind() != ComponentKind::RootDir {
+                assert_eq!(preparsed.component(i), Some(component.as_bytes()), "{path}");
+            }
+        }
+
+        for base in expected.ancestors() {
+            assert!(preparsed.starts_with(&PreparsedPath::new(base)), "{path}");
+        }
+    }
+
+    #[test]
+    fn should_match_path_methods() {
+        for path in [
+            "", "/", "//", "a", "a/", "./a", "../a/b", "a/./b/.", "/a//b/", "/../a",
+        ] {
+            check::<UnixEncoding>(path);
+        }
+
+        for path in [
+            r"C:",
+            r"C:\",
+            r"C:a\b",
+            r"C:/a//b\",
+            r"\a",
+            r"\\server\share",
+            r"\\server\share\a",
+            r"\\?\C:\a\.\b",
+            r"\\?\UNC\server\share\a",
+            r"\\.\COM1\a",
+        ] {
+            check::<WindowsEncoding>(path);
+        }
+    }
+
+    #[test]
+    fn starts_with_should_compare_whole_components() {
+        let path = PreparsedPath::<UnixEncoding>::new("/usr/local/bin");
+        assert!(path.starts_with(&PreparsedPath::new("/usr/local/")));
+        assert!(!path.starts_with(&PreparsedPath::new("/usr/loc")));
+        assert!(!path.starts_with(&PreparsedPath::new("usr")));
+        assert!(!path.starts_with(&PreparsedPath::new("/usr/local/bin/sh")));
+    }
+}
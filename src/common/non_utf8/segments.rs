@@ -0,0 +1,205 @@
+use core::iter::FusedIterator;
+
+use crate::{Component, Components, Encoding};
+
+/// A piece of a path exactly as it was written, yielded by
+/// [`Path::segments_with_separators`](crate::Path::segments_with_separators).
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum PathSegment<'a> {
+    /// Bytes of a component, including any prefix like `C:` and any `.` that parsing would skip
+    Component(&'a [u8]),
+
+    /// A run of one or more separators, including any root directory
+    Separator(&'a [u8]),
+}
+
+impl<'a> PathSegment<'a> {
+    /// Returns the bytes of the segment as they appear in the path.
+    #[inline]
+    pub fn as_bytes(&self) -> &'a [u8] {
+        match self {
+            Self::Component(bytes) | Self::Separator(bytes) => bytes,
+        }
+    }
+
+    /// Returns true if the segment is a component.
+    #[inline]
+    pub fn is_component(&self) -> bool {
+        matches!(self, Self::Component(_))
+    }
+
+    /// Returns true if the segment is a run of separators.
+    #[inline]
+    pub fn is_separator(&self) -> bool {
+        matches!(self, Self::Separator(_))
+    }
+}
+
+/// An iterator over the [`PathSegment`]s of a [`Path`], which together cover every byte of the
+/// path.
+///
+/// This `struct` is created by the [`segments_with_separators`] method on [`Path`].
+/// See its documentation for more.
+///
+/// [`Path`]: crate::Path
+/// [`segments_with_separators`]: crate::Path::segments_with_separators
+#[derive(Clone)]
+pub struct SegmentsWithSeparators<'a, T>
+where
+    T: Encoding<'a>,
+{
+    components: <T as Encoding<'a>>::Components,
+
+    /// Bytes following the last component yielded, which hold only separators and skipped `.`
+    gap: &'a [u8],
+}
+
+impl<'a, T> SegmentsWithSeparators<'a, T>
+where
+    T: for<'enc> Encoding<'enc> + 'a,
+{
+    pub(crate) fn new(path: &'a [u8]) -> Self {
+        Self {
+            components: T::components(path),
+            gap: &[],
+        }
+    }
+
+    fn separator_len(bytes: &[u8]) -> usize {
+        bytes
+            .iter()
+            .position(|b| !T::is_separator(*b))
+            .unwrap_or(bytes.len())
+    }
+}
+
+impl<'a, T> Iterator for SegmentsWithSeparators<'a, T>
+where
+    T: for<'enc> Encoding<'enc> + 'a,
+{
+    type Item = PathSegment<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if !self.gap.is_empty() {
+            let len = Self::separator_len(self.gap);
+            let (segment, rest) = if len > 0 {
+                let (bytes, rest) = self.gap.split_at(len);
+                (PathSegment::Separator(bytes), rest)
+            } else {
+                let len = self
+                    .gap
+                    .iter()
+                    .position(|b| T::is_separator(*b))
+                    .unwrap_or(self.gap.len());
+                let (bytes, rest) = self.gap.split_at(len);
+                (PathSegment::Component(bytes), rest)
+            };
+
+            self.gap = rest;
+            return Some(segment);
+        }
+
+        let rest = self.components.as_bytes();
+        let component = self.components.next()?;
+
+        // Consuming a component also consumes the separators and skipped `.` that follow it
+        let consumed = &rest[..rest.len() - self.components.as_bytes().len()];
+        let (bytes, gap) = consumed.split_at(component.len());
+
+        if !bytes.is_empty() && Self::separator_len(bytes) == bytes.len() {
+            // A root directory is a separator itself, so it joins the separators after it
+            let (bytes, gap) = consumed.split_at(bytes.len() + Self::separator_len(gap));
+            self.gap = gap;
+            Some(PathSegment::Separator(bytes))
+        } else {
+            self.gap = gap;
+            Some(PathSegment::Component(bytes))
+        }
+    }
+}
+
+impl<'a, T> FusedIterator for SegmentsWithSeparators<'a, T> where T: for<'enc> Encoding<'enc> + 'a {}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use super::*;
+    use crate::{UnixEncoding, WindowsEncoding};
+
+    fn segments<'a, T>(path: &'a str) -> Vec<PathSegment<'a>>
+    where
+        T: for<'enc> Encoding<'enc> + 'a,
+    {
+        SegmentsWithSeparators::<T>::new(path.as_bytes()).collect()
+    }
+
+    #[test]
+    fn should_cover_every_byte_of_the_path() {
+        let paths = [
+            "",
+            "/",
+            "//",
+            "a",
+            "a/",
+            "./a",
+            "a/./b",
+            "a//.//b/",
+            "/../a",
+            r"C:",
+            r"C:a\b",
+            r"C:\\a/b\",
+            r"\\server\share\a",
+            r"\\?\C:\a\.\b",
+            r"\\.\COM1",
+        ];
+
+        for path in paths {
+            for segments in [
+                segments::<UnixEncoding>(path),
+                segments::<WindowsEncoding>(path),
+            ] {
+                let bytes: Vec<u8> = segments
+                    .iter()
+                    .flat_map(|s| s.as_bytes())
+                    .copied()
+                    .collect();
+                assert_eq!(bytes, path.as_bytes(), "{path}");
+
+                // Separators never follow separators, and no segment is empty
+                for pair in segments.windows(2) {
+                    assert!(
+                        !(pair[0].is_separator() && pair[1].is_separator()),
+                        "{path}"
+                    );
+                }
+                assert!(segments.iter().all(|s| !s.as_bytes().is_empty()), "{path}");
+            }
+        }
+    }
+
+    #[test]
+    fn should_yield_skipped_current_dirs_as_components() {
+        assert_eq!(
+            segments::<UnixEncoding>("//a/./b//"),
+            [
+                PathSegment::Separator(b"//"),
+                PathSegment::Component(b"a"),
+                PathSegment::Separator(b"/"),
+                PathSegment::Component(b"."),
+                PathSegment::Separator(b"/"),
+                PathSegment::Component(b"b"),
+                PathSegment::Separator(b"//"),
+            ]
+        );
+
+        assert_eq!(
+            segments::<WindowsEncoding>(r"C:\/x"),
+            [
+                PathSegment::Component(b"C:"),
+                PathSegment::Separator(br"\/"),
+                PathSegment::Component(b"x"),
+            ]
+        );
+    }
+}
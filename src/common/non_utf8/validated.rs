@@ -0,0 +1,123 @@
+use core::iter::FusedIterator;
+
+use crate::common::{ComponentError, FilesystemProfile, ProfileError};
+use crate::{Component, Encoding};
+
+/// An iterator over the [`Component`]s of a [`Path`] that checks each normal component against
+/// its encoding and a [`FilesystemProfile`].
+///
+/// This `struct` is created by the [`validated_components`] method on [`Path`].
+/// See its documentation for more.
+///
+/// [`Path`]: crate::Path
+/// [`validated_components`]: crate::Path::validated_components
+#[derive(Clone)]
+pub struct ValidatedComponents<'a, T>
+where
+    T: Encoding<'a>,
+{
+    path: &'a [u8],
+    inner: <T as Encoding<'a>>::Components,
+    profile: FilesystemProfile,
+}
+
+impl<'a, T> ValidatedComponents<'a, T>
+where
+    T: for<'enc> Encoding<'enc> + 'a,
+{
+    pub(crate) fn new(path: &'a [u8], profile: &FilesystemProfile) -> Self {
+        Self {
+            path,
+            inner: T::components(path),
+            profile: *profile,
+        }
+    }
+
+    fn validate(
+        &self,
+        component: <<T as Encoding<'a>>::Components as Iterator>::Item,
+    ) -> Result<<<T as Encoding<'a>>::Components as Iterator>::Item, ComponentError> {
+        if !component.is_normal() {
+            return Ok(component);
+        }
+
+        // Normal components always borrow from the path being iterated
+        let bytes = component.as_bytes();
+        let start = bytes.as_ptr() as usize - self.path.as_ptr() as usize;
+        let span = start..start + bytes.len();
+
+        let result = match bytes
+            .iter()
+            .find(|b| T::DISALLOWED_FILENAME_BYTES.contains(b))
+        {
+            Some(b) => Err(ProfileError::InvalidByte(*b)),
+            None => self.profile.check_component(bytes),
+        };
+
+        result
+            .map(|_| component)
+            .map_err(|kind| ComponentError { span, kind })
+    }
+}
+
+impl<'a, T> Iterator for ValidatedComponents<'a, T>
+where
+    T: for<'enc> Encoding<'enc> + 'a,
+{
+    type Item = Result<<<T as Encoding<'a>>::Components as Iterator>::Item, ComponentError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let component = self.inner.next()?;
+        Some(self.validate(component))
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for ValidatedComponents<'a, T>
+where
+    T: for<'enc> Encoding<'enc> + 'a,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let component = self.inner.next_back()?;
+        Some(self.validate(component))
+    }
+}
+
+impl<'a, T> FusedIterator for ValidatedComponents<'a, T> where T: for<'enc> Encoding<'enc> + 'a {}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use super::*;
+    use crate::{UnixComponent, UnixPath};
+
+    #[test]
+    fn should_report_every_invalid_component_with_its_span() {
+        let path = UnixPath::new(b"/a\0b/../c d/./-e");
+        let profile = FilesystemProfile::posix_portable();
+
+        let results: Vec<_> = path.validated_components(&profile).collect();
+        assert_eq!(
+            results,
+            [
+                Ok(UnixComponent::RootDir),
+                Err(ComponentError {
+                    span: 1..4,
+                    kind: ProfileError::InvalidByte(b'\0'),
+                }),
+                Ok(UnixComponent::ParentDir),
+                Err(ComponentError {
+                    span: 8..11,
+                    kind: ProfileError::DisallowedByte(b' '),
+                }),
+                Err(ComponentError {
+                    span: 14..16,
+                    kind: ProfileError::LeadingHyphen,
+                }),
+            ]
+        );
+
+        let last = path.validated_components(&profile).next_back();
+        assert_eq!(last.unwrap().unwrap_err().span(), 14..16);
+    }
+}
@@ -0,0 +1,273 @@
+use core::fmt;
+use core::marker::PhantomData;
+
+use crate::no_std_compat::*;
+use crate::{Component, ComponentKind, Encoding, Path, PathBuf, StrictPathError};
+
+/// A buffer assembling a path out of bytes as they arrive, such as while decoding a network
+/// protocol, that holds them to the rules of [`Path::validate_strict`] along the way.
+///
+/// Each write is checked as soon as it is made, so a NUL or a byte the encoding disallows within
+/// a filename is reported by the write that carried it, at its position within the whole path.
+/// A prefix is only checked once something other than a root follows it, or when the path is
+/// finished, as in the meantime more bytes may complete it: `\\server` is missing the share
+/// that `\\server\share` provides. A write that fails is discarded, leaving what was written
+/// before it in place.
+///
+/// # Examples
+///
+/// ```
+/// use typed_path::{PathBuf, PathWriter, StrictPathError, WindowsEncoding};
+///
+/// // NOTE: A path cannot be created on its own without a defined encoding
+/// let mut writer = PathWriter::<WindowsEncoding>::new();
+/// for chunk in [r"\\file", r"server\pub", r"lic\reports\", "q3.pdf"] {
+///     writer.write(chunk).unwrap();
+/// }
+/// assert_eq!(writer.finish(), Ok(PathBuf::from(r"\\fileserver\public\reports\q3.pdf")));
+///
+/// let mut writer = PathWriter::<WindowsEncoding>::new();
+/// writer.write(r"C:\reports\").unwrap();
+/// assert_eq!(
+///     writer.write("q3?.pdf"),
+///     Err(StrictPathError::InvalidByte { index: 13, byte: b'?' }),
+/// );
+/// assert_eq!(writer.as_path(), PathBuf::from(r"C:\reports\"));
+/// ```
+pub struct PathWriter<T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    buf: Vec<u8>,
+    _encoding: PhantomData<T>,
+}
+
+impl<T> PathWriter<T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    /// Creates a writer holding an empty path.
+    #[inline]
+    pub fn new() -> Self {
+        Self::with_capacity(0)
+    }
+
+    /// Creates a writer with room for `capacity` bytes before allocating.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            buf: Vec::with_capacity(capacity),
+            _encoding: PhantomData,
+        }
+    }
+
+    /// Appends `bytes` to the path, checking them along with any component they extend.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`StrictPathError::InvalidByte`] for a NUL or for a byte the encoding disallows
+    /// within a filename, and [`StrictPathError::MalformedPrefix`] once a prefix that is missing
+    /// a required part is followed by a component. Nothing is appended when a write fails.
+    pub fn write<B: AsRef<[u8]>>(&mut self, bytes: B) -> Result<(), StrictPathError> {
+        let start = self.buf.len();
+        self.buf.extend_from_slice(bytes.as_ref());
+
+        let result = self.check(start, false);
+        if result.is_err() {
+            self.buf.truncate(start);
+        }
+        result
+    }
+
+    /// Returns the path written so far, which may still end in an incomplete prefix.
+    #[inline]
+    pub fn as_path(&self) -> &Path<T> {
+        Path::new(&self.buf)
+    }
+
+    /// Checks any prefix left unchecked and returns the path.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`StrictPathError::MalformedPrefix`] if the path ends in a prefix that is missing
+    /// a required part, such as the share of `\\server`.
+    pub fn finish(self) -> Result<PathBuf<T>, StrictPathError> {
+        self.check(self.buf.len(), true)?;
+        Ok(PathBuf::from(self.buf))
+    }
+
+    /// Checks the bytes from `start` onward and the normal components they extend, along with
+    /// any prefix that is followed by a component or, if `finished`, ends the path
+    fn check(&self, start: usize, finished: bool) -> Result<(), StrictPathError> {
+        let path = self.buf.as_slice();
+        if let Some(i) = path[start..].iter().position(|b| *b == b'\0') {
+            return Err(StrictPathError::InvalidByte {
+                index: start + i,
+                byte: b'\0',
+            });
+        }
+
+        let mut prefix = None;
+        for component in T::components(path) {
+            match component.kind() {
+                ComponentKind::Prefix => prefix = Some(component.as_bytes()),
+                ComponentKind::RootDir => {}
+                kind => {
+                    if let Some(prefix) = prefix.take() {
+                        if !T::is_valid_prefix(prefix) {
+                            return Err(StrictPathError::MalformedPrefix);
+                        }
+                    }
+
+                    // Normal components always borrow from the path being iterated
+                    let bytes = component.as_bytes();
+                    let offset = bytes.as_ptr() as usize - path.as_ptr() as usize;
+                    if kind == ComponentKind::Normal && offset + bytes.len() > start {
+                        if let Some(i) = bytes
+                            .iter()
+                            .position(|b| T::DISALLOWED_FILENAME_BYTES.contains(b))
+                        {
+                            return Err(StrictPathError::InvalidByte {
+                                index: offset + i,
+                                byte: bytes[i],
+                            });
+                        }
+                    }
+                }
+            }
+        }
+
+        match prefix {
+            Some(prefix) if finished && !T::is_valid_prefix(prefix) => {
+                Err(StrictPathError::MalformedPrefix)
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+impl<T> Default for PathWriter<T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> fmt::Debug for PathWriter<T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PathWriter")
+            .field("path", &self.as_path())
+            .finish()
+    }
+}
+
+impl<T> fmt::Write for PathWriter<T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    /// Appends `s` to the path, failing without detail if it is rejected.
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.write(s).map_err(|_| fmt::Error)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T> std::io::Write for PathWriter<T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    /// Appends all of `buf` to the path, failing with [`std::io::ErrorKind::InvalidData`] if it
+    /// is rejected.
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        match PathWriter::write(self, buf) {
+            Ok(()) => Ok(buf.len()),
+            Err(err) => Err(std::io::Error::new(std::io::ErrorKind::InvalidData, err)),
+        }
+    }
+
+    #[inline]
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{UnixEncoding, WindowsEncoding};
+
+    fn write_all<T>(chunks: &[&str]) -> Result<PathBuf<T>, StrictPathError>
+    where
+        T: for<'enc> Encoding<'enc>,
+    {
+        let mut writer = PathWriter::<T>::new();
+        for chunk in chunks {
+            writer.write(chunk)?;
+        }
+        writer.finish()
+    }
+
+    #[test]
+    fn write_should_wait_for_prefixes_to_complete() {
+        let cases: &[&[&str]] = &[
+            &[r"\", r"\server", r"\share"],
+            &[r"\\?\", "UNC", r"\", r"server\", "share", r"\a"],
+            &["C", ":", r"\a"],
+            &[r"\\.\", "COM1"],
+        ];
+        for chunks in cases {
+            assert_eq!(
+                write_all::<WindowsEncoding>(chunks),
+                Ok(PathBuf::from(chunks.concat())),
+                "{:?}",
+                chunks
+            );
+        }
+
+        assert_eq!(
+            write_all::<WindowsEncoding>(&[r"\\server"]),
+            Err(StrictPathError::MalformedPrefix)
+        );
+        assert_eq!(
+            write_all::<WindowsEncoding>(&[r"\\?\UNC\server\"]),
+            Err(StrictPathError::MalformedPrefix)
+        );
+    }
+
+    #[test]
+    fn write_should_reject_bytes_as_they_arrive() {
+        let mut writer = PathWriter::<WindowsEncoding>::new();
+        writer.write(r"\\?\UNC\server\").unwrap();
+        assert_eq!(writer.write(r"\a"), Err(StrictPathError::MalformedPrefix));
+        assert_eq!(writer.as_path(), Path::new(r"\\?\UNC\server\"));
+
+        let mut writer = PathWriter::<UnixEncoding>::new();
+        writer.write("/tmp/a").unwrap();
+        assert_eq!(
+            writer.write("b\0c"),
+            Err(StrictPathError::InvalidByte {
+                index: 7,
+                byte: b'\0'
+            })
+        );
+        writer.write("bc").unwrap();
+        assert_eq!(writer.finish(), Ok(PathBuf::from("/tmp/abc")));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn io_write_should_report_rejected_bytes() {
+        use std::io::Write;
+
+        let mut writer = PathWriter::<WindowsEncoding>::new();
+        write!(writer, r"C:\logs\app.log").unwrap();
+        let err = writer.write_all(b"|tee").unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+        assert_eq!(writer.as_path(), Path::new(r"C:\logs\app.log"));
+    }
+}
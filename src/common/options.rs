@@ -0,0 +1,1049 @@
+use alloc::borrow::Cow;
+use core::cmp;
+use core::str::Utf8Error;
+
+use crate::common::percent::percent_encode;
+use crate::common::truncate::floor_char_boundary;
+use crate::no_std_compat::*;
+use crate::{ComponentKind, WindowsPrefix, WindowsPrefixComponent};
+
+/// Policy describing how a path is adjoined onto an existing path when it carries its own root
+/// or prefix.
+///
+/// This is consumed by methods like [`PathBuf::push_with_mode`] and [`Path::join_with_mode`],
+/// which otherwise behave like their [`push`] and [`join`] counterparts.
+///
+/// [`Path::join_with_mode`]: crate::Path::join_with_mode
+/// [`PathBuf::push_with_mode`]: crate::PathBuf::push_with_mode
+/// [`join`]: crate::Path::join
+/// [`push`]: crate::PathBuf::push
+///
+/// # Examples
+///
+/// ```
+/// use typed_path::{CheckedPathError, PushMode, UnixPathBuf};
+///
+/// let mut path = UnixPathBuf::from("/srv/www");
+/// path.push_with_mode("/etc/passwd", PushMode::Append).unwrap();
+/// assert_eq!(path, UnixPathBuf::from("/srv/www/etc/passwd"));
+///
+/// let mut path = UnixPathBuf::from("/srv/www");
+/// assert_eq!(
+///     path.push_with_mode("/etc/passwd", PushMode::Reject),
+///     Err(CheckedPathError::UnexpectedRoot),
+/// );
+/// assert_eq!(path, UnixPathBuf::from("/srv/www"));
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum PushMode {
+    /// Mirrors [`std::path::PathBuf::push`]: an absolute path (or, on Windows, a path with a
+    /// root or prefix) replaces all or part of the existing path.
+    Replace,
+
+    /// Never replaces the existing path. Any leading prefix and root of the incoming path are
+    /// dropped and the remainder is appended as if it were relative.
+    Append,
+
+    /// Never replaces the existing path. An incoming path with a leading prefix or root is
+    /// rejected with [`CheckedPathError::UnexpectedPrefix`] or
+    /// [`CheckedPathError::UnexpectedRoot`].
+    ///
+    /// [`CheckedPathError::UnexpectedPrefix`]: crate::CheckedPathError::UnexpectedPrefix
+    /// [`CheckedPathError::UnexpectedRoot`]: crate::CheckedPathError::UnexpectedRoot
+    Reject,
+}
+
+impl Default for PushMode {
+    /// Defaults to [`PushMode::Replace`], matching the behavior of [`std::path::PathBuf::push`].
+    #[inline]
+    fn default() -> Self {
+        Self::Replace
+    }
+}
+
+/// Policy describing how the parts of a Windows path after a verbatim (`\\?\`) prefix are read.
+///
+/// Win32 hands a verbatim path to the filesystem as it is, so `.` and `..` are ordinary names and
+/// `/` is an ordinary character within them. [`WindowsPath::components`] follows Win32 and std in
+/// this, but paths written by hand or produced by other tools often use the verbatim prefix only to
+/// lift the length limit, expecting the rest to be read like any other path. This policy picks
+/// between the two, and is consumed consistently by
+/// [`WindowsPath::components_with_verbatim_mode`], [`WindowsPath::join_with_verbatim_mode`], and
+/// [`WindowsPath::normalize_with_verbatim_mode`] and their UTF-8 counterparts. Paths without a
+/// verbatim prefix are read the same way either way.
+///
+/// [`WindowsPath::components`]: crate::WindowsPath::components
+/// [`WindowsPath::components_with_verbatim_mode`]:
+///     crate::WindowsPath::components_with_verbatim_mode
+/// [`WindowsPath::join_with_verbatim_mode`]: crate::WindowsPath::join_with_verbatim_mode
+/// [`WindowsPath::normalize_with_verbatim_mode`]: crate::WindowsPath::normalize_with_verbatim_mode
+///
+/// # Examples
+///
+/// ```
+/// use typed_path::{Component, VerbatimMode, WindowsPath, WindowsPathBuf};
+///
+/// let path = WindowsPath::new(r"\\?\C:\logs\..\app/./out.txt");
+///
+/// let faithful: Vec<_> = path
+///     .components_with_verbatim_mode(VerbatimMode::Faithful)
+///     .map(|c| c.as_bytes())
+///     .collect();
+/// assert_eq!(faithful, [&br"\\?\C:"[..], br"\", b"logs", b"..", b"app/./out.txt"]);
+///
+/// let normal: Vec<_> = path
+///     .components_with_verbatim_mode(VerbatimMode::Normal)
+///     .map(|c| c.as_bytes())
+///     .collect();
+/// assert_eq!(normal, [&br"\\?\C:"[..], br"\", b"logs", b"..", b"app", b"out.txt"]);
+///
+/// assert_eq!(
+///     path.normalize_with_verbatim_mode(VerbatimMode::Normal),
+///     WindowsPathBuf::from(r"\\?\C:\app\out.txt"),
+/// );
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum VerbatimMode {
+    /// Reads verbatim paths the way Win32 does: only `\` separates components, and `.` and `..`
+    /// are kept as they are, never removed or resolved.
+    Faithful,
+
+    /// Reads verbatim paths like any other path: `/` also separates components, `.` is removed,
+    /// and `..` can be resolved. The verbatim prefix itself is kept.
+    Normal,
+}
+
+impl Default for VerbatimMode {
+    /// Defaults to [`VerbatimMode::Faithful`], matching the behavior of
+    /// [`std::path::Path::components`].
+    #[inline]
+    fn default() -> Self {
+        Self::Faithful
+    }
+}
+
+/// Policy describing how a path of one encoding is converted when adjoined onto a path of another
+/// encoding.
+///
+/// This is consumed by methods like [`TypedPath::join_typed`] and [`TypedPathBuf::push_typed`].
+/// Paths that already share the same encoding are adjoined as-is.
+///
+/// [`TypedPath::join_typed`]: crate::TypedPath::join_typed
+/// [`TypedPathBuf::push_typed`]: crate::TypedPathBuf::push_typed
+///
+/// # Examples
+///
+/// ```
+/// use typed_path::{CheckedPathError, ConversionPolicy, TypedPath};
+///
+/// let base = TypedPath::derive("/srv/www");
+///
+/// let path = base.join_typed(TypedPath::windows(r"static\app.js"), ConversionPolicy::Lossy);
+/// assert_eq!(path.unwrap().as_bytes(), b"/srv/www/static/app.js");
+///
+/// let path = base.join_typed(TypedPath::derive(r"C:static\app.js"), ConversionPolicy::Checked);
+/// assert_eq!(path, Err(CheckedPathError::UnexpectedPrefix));
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ConversionPolicy {
+    /// Converts the path the same way as [`TypedPath::with_unix_encoding`] and
+    /// [`TypedPath::with_windows_encoding`], which never fails but may drop or carry over parts
+    /// that cannot be represented in the target encoding, such as a Windows prefix.
+    ///
+    /// [`TypedPath::with_unix_encoding`]: crate::TypedPath::with_unix_encoding
+    /// [`TypedPath::with_windows_encoding`]: crate::TypedPath::with_windows_encoding
+    Lossy,
+
+    /// Converts the path the same way as [`TypedPath::with_unix_encoding_checked`] and
+    /// [`TypedPath::with_windows_encoding_checked`], additionally rejecting a Windows prefix
+    /// with [`CheckedPathError::UnexpectedPrefix`] rather than dropping it.
+    ///
+    /// [`CheckedPathError::UnexpectedPrefix`]: crate::CheckedPathError::UnexpectedPrefix
+    /// [`TypedPath::with_unix_encoding_checked`]: crate::TypedPath::with_unix_encoding_checked
+    /// [`TypedPath::with_windows_encoding_checked`]:
+    ///     crate::TypedPath::with_windows_encoding_checked
+    Checked,
+}
+
+impl Default for ConversionPolicy {
+    /// Defaults to [`ConversionPolicy::Lossy`], matching the behavior of converting paths
+    /// between encodings elsewhere.
+    #[inline]
+    fn default() -> Self {
+        Self::Lossy
+    }
+}
+
+/// Policy describing how bytes or characters that cannot be represented are handled when
+/// converting a path lossily.
+///
+/// This is consumed by [`Path::to_utf8_lossy_with`], which replaces sequences that are not valid
+/// UTF-8, and by methods like [`Path::with_encoding_lossy_with`], which replace bytes that are
+/// not allowed within a filename of the target encoding.
+///
+/// [`Path::to_utf8_lossy_with`]: crate::Path::to_utf8_lossy_with
+/// [`Path::with_encoding_lossy_with`]: crate::Path::with_encoding_lossy_with
+///
+/// # Examples
+///
+/// ```
+/// use typed_path::{LossyPolicy, Path, UnixEncoding, Utf8UnixEncoding, WindowsEncoding};
+///
+/// let path = Path::<UnixEncoding>::new(b"/tmp/fo\xffo:bar.txt");
+///
+/// let utf8 = path.to_utf8_lossy_with::<Utf8UnixEncoding>(LossyPolicy::Replace('_'));
+/// assert_eq!(utf8.unwrap().as_str(), "/tmp/fo_o:bar.txt");
+///
+/// let utf8 = path.to_utf8_lossy_with::<Utf8UnixEncoding>(LossyPolicy::PercentEncode);
+/// assert_eq!(utf8.unwrap().as_str(), "/tmp/fo%FFo:bar.txt");
+///
+/// let windows = path.with_encoding_lossy_with::<WindowsEncoding>(LossyPolicy::Replace('_'));
+/// assert_eq!(windows.unwrap().as_bytes(), b"\\tmp\\fo\xffo_bar.txt");
+///
+/// assert!(path.to_utf8_lossy_with::<Utf8UnixEncoding>(LossyPolicy::Error).is_err());
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum LossyPolicy {
+    /// Replaces each sequence that cannot be represented with the given character, such as `_`
+    /// or [`char::REPLACEMENT_CHARACTER`].
+    Replace(char),
+
+    /// Replaces each byte that cannot be represented with a `%` followed by its value as two
+    /// uppercase hexadecimal digits.
+    ///
+    /// Any `%` already present is kept as-is, so the result cannot always be decoded back into
+    /// the original path.
+    PercentEncode,
+
+    /// Fails the conversion instead of replacing anything.
+    Error,
+}
+
+impl Default for LossyPolicy {
+    /// Defaults to replacing with [`char::REPLACEMENT_CHARACTER`], matching the behavior of
+    /// [`String::from_utf8_lossy`].
+    #[inline]
+    fn default() -> Self {
+        Self::Replace(char::REPLACEMENT_CHARACTER)
+    }
+}
+
+impl LossyPolicy {
+    /// Decodes `bytes` as UTF-8, handling invalid sequences according to the policy
+    pub(crate) fn decode<'a>(&self, bytes: &'a [u8]) -> Result<Cow<'a, str>, Utf8Error> {
+        let mut out = String::new();
+        let mut rest = bytes;
+
+        loop {
+            let err = match core::str::from_utf8(rest) {
+                Ok(s) if out.is_empty() => return Ok(Cow::Borrowed(s)),
+                Ok(s) => {
+                    out.push_str(s);
+                    return Ok(Cow::Owned(out));
+                }
+                Err(err) => err,
+            };
+
+            let (valid, invalid) = rest.split_at(err.valid_up_to());
+
+            // SAFETY: everything up to the error was validated as UTF-8
+            out.push_str(unsafe { core::str::from_utf8_unchecked(valid) });
+
+            let (invalid, after) = invalid.split_at(err.error_len().unwrap_or(invalid.len()));
+            match self {
+                Self::Replace(c) => out.push(*c),
+                Self::PercentEncode => {
+                    for &b in invalid {
+                        // SAFETY: percent-encoded bytes are always ascii
+                        out.push_str(unsafe { core::str::from_utf8_unchecked(&percent_encode(b)) });
+                    }
+                }
+                Self::Error => return Err(err),
+            }
+
+            rest = after;
+        }
+    }
+
+    /// Rewrites each byte of `bytes` rejected by `is_valid` according to the policy, returning
+    /// [`None`] if the policy is [`LossyPolicy::Error`]
+    pub(crate) fn sanitize(&self, bytes: &[u8], is_valid: impl Fn(u8) -> bool) -> Option<Vec<u8>> {
+        let mut out = Vec::with_capacity(bytes.len());
+
+        for &b in bytes {
+            if is_valid(b) {
+                out.push(b);
+                continue;
+            }
+
+            match self {
+                Self::Replace(c) => out.extend_from_slice(c.encode_utf8(&mut [0; 4]).as_bytes()),
+                Self::PercentEncode => out.extend_from_slice(&percent_encode(b)),
+                Self::Error => return None,
+            }
+        }
+
+        Some(out)
+    }
+
+    /// Rewrites each character of `s` rejected by `is_valid` according to the policy, returning
+    /// [`None`] if the policy is [`LossyPolicy::Error`]
+    pub(crate) fn sanitize_str(&self, s: &str, is_valid: impl Fn(char) -> bool) -> Option<String> {
+        let mut out = String::with_capacity(s.len());
+
+        for c in s.chars() {
+            if is_valid(c) {
+                out.push(c);
+                continue;
+            }
+
+            match self {
+                Self::Replace(r) => out.push(*r),
+                Self::PercentEncode => {
+                    for &b in c.encode_utf8(&mut [0; 4]).as_bytes() {
+                        // SAFETY: percent-encoded bytes are always ascii
+                        out.push_str(unsafe { core::str::from_utf8_unchecked(&percent_encode(b)) });
+                    }
+                }
+                Self::Error => return None,
+            }
+        }
+
+        Some(out)
+    }
+}
+
+/// Toggles controlling how a path is normalized by methods like [`Path::normalize_with`].
+///
+/// The default options behave like [`Path::normalize`]:
+///
+/// * duplicate separators are collapsed into the encoding's primary separator
+/// * `.` components are removed
+/// * `..` components are resolved against preceding normal components
+/// * leading `..` components that cannot be resolved are discarded
+/// * trailing separators are trimmed
+/// * drive letters keep their original case
+///
+/// [`Path::normalize`]: crate::Path::normalize
+/// [`Path::normalize_with`]: crate::Path::normalize_with
+///
+/// # Examples
+///
+/// ```
+/// use typed_path::{NormalizationOptions, UnixPath, UnixPathBuf};
+///
+/// let options = NormalizationOptions::new()
+///     .preserve_leading_parent(true)
+///     .trim_trailing_separator(false);
+///
+/// assert_eq!(
+///     UnixPath::new("../foo//bar/../baz/").normalize_with(&options),
+///     UnixPathBuf::from("../foo/baz/"),
+/// );
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct NormalizationOptions {
+    collapse_separators: bool,
+    remove_current: bool,
+    resolve_parent: bool,
+    preserve_leading_parent: bool,
+    trim_trailing_separator: bool,
+    lowercase_drive_letter: bool,
+}
+
+impl NormalizationOptions {
+    /// Creates a new set of options that behave like [`Path::normalize`].
+    ///
+    /// [`Path::normalize`]: crate::Path::normalize
+    pub const fn new() -> Self {
+        Self {
+            collapse_separators: true,
+            remove_current: true,
+            resolve_parent: true,
+            preserve_leading_parent: false,
+            trim_trailing_separator: true,
+            lowercase_drive_letter: false,
+        }
+    }
+
+    /// When `true`, sequential separators are replaced by a single instance of the encoding's
+    /// primary separator (`/` on Unix and `\` on Windows). When `false`, separators are kept
+    /// exactly as they were written.
+    pub fn collapse_separators(mut self, yes: bool) -> Self {
+        self.collapse_separators = yes;
+        self
+    }
+
+    /// When `true`, `.` components are removed.
+    pub fn remove_current(mut self, yes: bool) -> Self {
+        self.remove_current = yes;
+        self
+    }
+
+    /// When `true`, each `..` component removes the normal component preceding it.
+    pub fn resolve_parent(mut self, yes: bool) -> Self {
+        self.resolve_parent = yes;
+        self
+    }
+
+    /// When `true` and resolving `..`, any `..` without a preceding normal component is kept if
+    /// the path has no root. When `false`, such components are discarded.
+    ///
+    /// A `..` directly after a root is always discarded when resolving `..`.
+    pub fn preserve_leading_parent(mut self, yes: bool) -> Self {
+        self.preserve_leading_parent = yes;
+        self
+    }
+
+    /// When `true`, any separators after the last component are removed.
+    pub fn trim_trailing_separator(mut self, yes: bool) -> Self {
+        self.trim_trailing_separator = yes;
+        self
+    }
+
+    /// When `true`, the drive letter of a disk prefix (e.g. `C:` or `\\?\C:`) is made lowercase.
+    pub fn lowercase_drive_letter(mut self, yes: bool) -> Self {
+        self.lowercase_drive_letter = yes;
+        self
+    }
+
+    /// Normalizes the `components` of a path, producing the pieces that, when concatenated, make
+    /// up the new path.
+    ///
+    /// `rooted` indicates whether the original path has a root and `separator` is the primary
+    /// separator of the encoding.
+    pub(crate) fn apply<'a>(
+        &self,
+        components: &[RawComponent<'a>],
+        rooted: bool,
+        separator: &'a [u8],
+    ) -> Vec<Cow<'a, [u8]>> {
+        let anchor_len = components
+            .iter()
+            .take_while(|c| matches!(c.kind, ComponentKind::Prefix | ComponentKind::RootDir))
+            .count();
+        let (anchor, body) = components.split_at(anchor_len);
+
+        // Attach to each component of the body the gap that precedes it rather than follows it,
+        // so removing a component also removes the separators that led up to it
+        let mut kept: Vec<(&RawComponent<'a>, &'a [u8])> = Vec::new();
+        let mut pending: Option<&'a [u8]> = None;
+        let mut preceding = anchor.last().map(|c| c.gap).unwrap_or_default();
+
+        for component in body {
+            let gap = preceding;
+            preceding = component.gap;
+
+            match component.kind {
+                ComponentKind::CurDir if self.remove_current => {
+                    pending.get_or_insert(gap);
+                }
+                ComponentKind::ParentDir if self.resolve_parent => {
+                    if matches!(kept.last(), Some((c, _)) if c.kind == ComponentKind::Normal) {
+                        let (_, popped_gap) = kept.pop().unwrap();
+
+                        // Carry over a `.` found just before the `..` when the popped component
+                        // was not preceded by one itself
+                        pending = if self.count_current(popped_gap) == 0 {
+                            Some(gap)
+                        } else {
+                            Some(popped_gap)
+                        };
+                    } else if !rooted && self.preserve_leading_parent {
+                        kept.push((component, pending.take().unwrap_or(gap)));
+                    } else {
+                        pending.get_or_insert(gap);
+                    }
+                }
+                _ => kept.push((component, pending.take().unwrap_or(gap))),
+            }
+        }
+
+        let mut pieces = Vec::new();
+
+        for (i, component) in anchor.iter().enumerate() {
+            let bytes = if self.collapse_separators {
+                component.canonical
+            } else {
+                component.raw
+            };
+
+            if i == 0 && component.kind == ComponentKind::Prefix && self.lowercase_drive_letter {
+                pieces.push(lowercase_drive_letter(bytes));
+            } else {
+                pieces.push(Cow::Borrowed(bytes));
+            }
+
+            // Without any body, the gap after the anchor continues the anchor itself
+            if !self.collapse_separators && body.is_empty() && i + 1 == anchor.len() {
+                pieces.push(Cow::Borrowed(self.strip_current(component.gap)));
+            }
+        }
+
+        if self.collapse_separators {
+            // The anchor always ends with either a separator or a prefix that must not be
+            // followed by one (e.g. `C:`), so separators only go between body components
+            let mut needs_sep = false;
+            let mut push = |pieces: &mut Vec<Cow<'a, [u8]>>, bytes: &'a [u8]| {
+                if needs_sep {
+                    pieces.push(Cow::Borrowed(separator));
+                }
+                pieces.push(Cow::Borrowed(bytes));
+                needs_sep = true;
+            };
+
+            for (component, gap) in kept.iter() {
+                for _ in 0..self.count_current(gap) {
+                    push(&mut pieces, CURRENT_DIR);
+                }
+                push(&mut pieces, component.canonical);
+            }
+
+            if let (Some(tail), false) = (body.last().map(|c| c.gap), kept.is_empty()) {
+                // A `.` that led up to a popped component has no component left to precede
+                let popped = pending.unwrap_or_default();
+                for _ in 0..self.count_current(popped) + self.count_current(tail) {
+                    push(&mut pieces, CURRENT_DIR);
+                }
+
+                let tail = self.strip_current(tail);
+                if !self.trim_trailing_separator && matches!(tail.last(), Some(b) if *b != b'.') {
+                    pieces.push(Cow::Borrowed(separator));
+                }
+            }
+        } else {
+            for (component, gap) in kept.iter() {
+                pieces.push(Cow::Borrowed(self.strip_current(gap)));
+                pieces.push(Cow::Borrowed(component.raw));
+            }
+
+            if let (Some(tail), false) = (body.last().map(|c| c.gap), kept.is_empty()) {
+                // A `.` that led up to a popped component has no component left to precede
+                let popped = pending
+                    .filter(|gap| !self.remove_current && gap.contains(&b'.'))
+                    .unwrap_or_default();
+                let through_last_current =
+                    |gap: &'a [u8]| match gap.iter().rposition(|b| *b == b'.') {
+                        Some(i) => &gap[..=i],
+                        None => &[],
+                    };
+
+                if !self.trim_trailing_separator {
+                    pieces.push(Cow::Borrowed(popped));
+                    pieces.push(Cow::Borrowed(self.strip_current(tail)));
+                } else if !self.remove_current {
+                    // Keep any `.` within the tail, dropping only the separators after the last
+                    let tail = through_last_current(tail);
+                    if tail.is_empty() {
+                        pieces.push(Cow::Borrowed(through_last_current(popped)));
+                    } else {
+                        pieces.push(Cow::Borrowed(popped));
+                        pieces.push(Cow::Borrowed(tail));
+                    }
+                }
+            }
+        }
+
+        pieces
+    }
+
+    /// Counts the `.` found within a gap of separators that are being kept
+    fn count_current(&self, gap: &[u8]) -> usize {
+        if self.remove_current {
+            0
+        } else {
+            gap.iter().filter(|b| **b == b'.').count()
+        }
+    }
+
+    /// Removes each `.` within a gap of separators alongside the separators that follow it
+    fn strip_current<'a>(&self, gap: &'a [u8]) -> &'a [u8] {
+        // As a gap only contains separators and `.`, everything from the first `.` onward is
+        // either a `.` or a separator following one
+        match gap.iter().position(|b| *b == b'.') {
+            Some(i) if self.remove_current => &gap[..i],
+            _ => gap,
+        }
+    }
+}
+
+/// Current directory, which is the same for every encoding and is also what gaps between
+/// components are scanned for
+const CURRENT_DIR: &[u8] = b".";
+
+impl Default for NormalizationOptions {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Function comparing the bytes of two components, used by [`PathEquivalence::component_eq`]
+pub type ComponentEq = fn(&[u8], &[u8]) -> bool;
+
+/// Rules describing when two paths refer to the same place despite being spelled differently, as
+/// used by methods like [`Path::equivalent_with`].
+///
+/// The default rules are those of [`Path::normalized_eq`]:
+///
+/// * separators and `.` components are never significant
+/// * `..` components are lexically resolved, keeping any leading `..` of a relative path
+/// * components are compared case-sensitively for Unix paths and ascii case-insensitively for
+///   Windows paths
+///
+/// [`Path::equivalent_with`]: crate::Path::equivalent_with
+/// [`Path::normalized_eq`]: crate::Path::normalized_eq
+///
+/// # Examples
+///
+/// ```
+/// use typed_path::{PathEquivalence, UnixPath};
+///
+/// // Treat unix paths as case-insensitive, like on a default macOS volume
+/// let rules = PathEquivalence::new().case_sensitive(false);
+/// assert!(UnixPath::new("/Users/Me/../me/Docs").equivalent_with("/users/me/docs", &rules));
+///
+/// // Provide a custom comparison for individual components
+/// let rules = PathEquivalence::new().component_eq(|a, b| a.starts_with(b) || b.starts_with(a));
+/// assert!(UnixPath::new("/usr/local").equivalent_with("/us/loc", &rules));
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct PathEquivalence {
+    resolve_parent: bool,
+    case_sensitive: Option<bool>,
+    component_eq: Option<ComponentEq>,
+}
+
+impl PathEquivalence {
+    /// Creates a new set of rules that behave like [`Path::normalized_eq`].
+    ///
+    /// [`Path::normalized_eq`]: crate::Path::normalized_eq
+    pub const fn new() -> Self {
+        Self {
+            resolve_parent: true,
+            case_sensitive: None,
+            component_eq: None,
+        }
+    }
+
+    /// When `true`, `..` components are lexically resolved before comparing. When `false`, they
+    /// are compared like any other component.
+    pub fn resolve_parent(mut self, yes: bool) -> Self {
+        self.resolve_parent = yes;
+        self
+    }
+
+    /// Overrides whether components are compared case-sensitively, which otherwise depends on the
+    /// encoding of the paths. Case-insensitive comparisons only consider ascii characters.
+    pub fn case_sensitive(mut self, yes: bool) -> Self {
+        self.case_sensitive = Some(yes);
+        self
+    }
+
+    /// Uses `f` to compare the bytes of individual components (including any prefix or root),
+    /// taking precedence over [`PathEquivalence::case_sensitive`]. Any `/` within a Windows prefix
+    /// is replaced with `\` before being passed to `f`.
+    pub fn component_eq(mut self, f: ComponentEq) -> Self {
+        self.component_eq = Some(f);
+        self
+    }
+
+    /// Returns true if `resolve_parent` is enabled and either of the paths contains a `..`,
+    /// meaning the paths need to be normalized before being compared.
+    pub(crate) fn needs_resolve(&self, a_has_parent: bool, b_has_parent: bool) -> bool {
+        self.resolve_parent && (a_has_parent || b_has_parent)
+    }
+
+    /// Compares the kinds and bytes of components from two paths, where `windows` indicates
+    /// whether the paths use a Windows encoding.
+    pub(crate) fn iter_eq<'a, 'b>(
+        &self,
+        windows: bool,
+        mut a: impl Iterator<Item = (ComponentKind, &'a [u8])>,
+        mut b: impl Iterator<Item = (ComponentKind, &'b [u8])>,
+    ) -> bool {
+        let case_sensitive = self.case_sensitive.unwrap_or(!windows);
+
+        loop {
+            match (a.next(), b.next()) {
+                (Some((x_kind, x)), Some((y_kind, y))) => {
+                    if x_kind != y_kind {
+                        return false;
+                    }
+
+                    // Separators within a Windows prefix are as insignificant as anywhere else
+                    let x = comparable_component_bytes(x_kind, x, windows);
+                    let y = comparable_component_bytes(y_kind, y, windows);
+
+                    let eq = match self.component_eq {
+                        Some(f) => f(&x, &y),
+                        None if case_sensitive => x == y,
+                        None => x.eq_ignore_ascii_case(&y),
+                    };
+
+                    if !eq {
+                        return false;
+                    }
+                }
+                (None, None) => return true,
+                _ => return false,
+            }
+        }
+    }
+}
+
+impl Default for PathEquivalence {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Pattern used to generate alternative file names when a name is already taken, as used by
+/// methods like [`Path::uniquify_with`].
+///
+/// Each candidate places a counter between the stem and the extension of the original file name,
+/// surrounded by a configurable prefix and suffix. The default pattern produces `file (1).txt`,
+/// `file (2).txt`, and so on.
+///
+/// [`Path::uniquify_with`]: crate::Path::uniquify_with
+///
+/// # Examples
+///
+/// ```
+/// use typed_path::{UniquifyOptions, UnixPath};
+///
+/// let options = UniquifyOptions::new().prefix("-").suffix("").start(2);
+/// let taken = ["/tmp/log.txt", "/tmp/log-2.txt"];
+///
+/// let path = UnixPath::new("/tmp/log.txt").uniquify_with(&options, |p| {
+///     taken.iter().any(|t| p.as_bytes() == t.as_bytes())
+/// });
+/// assert_eq!(path.unwrap().as_bytes(), b"/tmp/log-3.txt");
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct UniquifyOptions {
+    prefix: &'static str,
+    suffix: &'static str,
+    start: usize,
+    max_component_len: Option<usize>,
+}
+
+impl UniquifyOptions {
+    /// Creates a new pattern that behaves like [`Path::uniquify`].
+    ///
+    /// [`Path::uniquify`]: crate::Path::uniquify
+    pub const fn new() -> Self {
+        Self {
+            prefix: " (",
+            suffix: ")",
+            start: 1,
+            max_component_len: Some(255),
+        }
+    }
+
+    /// Sets the text placed between the stem and the counter, which is ` (` by default.
+    pub fn prefix(mut self, prefix: &'static str) -> Self {
+        self.prefix = prefix;
+        self
+    }
+
+    /// Sets the text placed between the counter and the extension, which is `)` by default.
+    pub fn suffix(mut self, suffix: &'static str) -> Self {
+        self.suffix = suffix;
+        self
+    }
+
+    /// Sets the first value of the counter, which is `1` by default.
+    pub fn start(mut self, start: usize) -> Self {
+        self.start = start;
+        self
+    }
+
+    /// Sets the maximum length of a candidate file name in bytes, or `None` for no limit. The
+    /// stem is shortened to make room for the counter and extension, which is `255` by default.
+    pub fn max_component_len(mut self, max: Option<usize>) -> Self {
+        self.max_component_len = max;
+        self
+    }
+
+    /// Returns the value of the counter for the first candidate.
+    pub(crate) fn first(&self) -> usize {
+        self.start
+    }
+
+    /// Produces the candidate file name numbered `n` from the `stem` and `extension` of the
+    /// original file name.
+    ///
+    /// As the stem is only ever cut at a character boundary, the result is valid UTF-8 whenever
+    /// the stem and extension are.
+    pub(crate) fn file_name(&self, stem: &[u8], extension: Option<&[u8]>, n: usize) -> Vec<u8> {
+        let counter = alloc::format!("{}{}{}", self.prefix, n, self.suffix);
+        let extension_len = extension.map(|ext| ext.len() + 1).unwrap_or(0);
+        let stem = match self.max_component_len {
+            Some(max) => {
+                let budget = max.saturating_sub(counter.len() + extension_len);
+                &stem[..floor_char_boundary(stem, budget)]
+            }
+            None => stem,
+        };
+
+        let mut name = Vec::with_capacity(stem.len() + counter.len() + extension_len);
+        name.extend_from_slice(stem);
+        name.extend_from_slice(counter.as_bytes());
+        if let Some(ext) = extension {
+            name.push(b'.');
+            name.extend_from_slice(ext);
+        }
+        name
+    }
+}
+
+impl Default for UniquifyOptions {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Cost model for the component-aware edit distance used by methods like
+/// [`Path::suggest_nearest_with`] to find the closest match for a mistyped path.
+///
+/// Two paths are compared component by component: a component can be edited into another one
+/// character at a time, or inserted and removed as a whole, which costs [`component`] on top of
+/// inserting or removing each of its characters. Making `component` larger than the character
+/// costs prefers candidates with the same layout of directories over ones that merely share
+/// letters.
+///
+/// [`Path::suggest_nearest_with`]: crate::Path::suggest_nearest_with
+/// [`component`]: SuggestOptions::component
+///
+/// # Examples
+///
+/// ```
+/// use typed_path::{SuggestOptions, UnixPath};
+///
+/// let path = UnixPath::new("src/Main.rs");
+/// assert_eq!(path.edit_distance("src/main.rs", &SuggestOptions::new()), 1);
+///
+/// // Differences in ascii case can be made free, such as for case-insensitive file systems
+/// let options = SuggestOptions::new().case_change(0);
+/// assert_eq!(path.edit_distance("src/main.rs", &options), 0);
+///
+/// // Adding a whole component costs extra on top of its characters
+/// assert_eq!(path.edit_distance("src/Main.rs/a", &options), 3);
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct SuggestOptions {
+    insert: usize,
+    delete: usize,
+    substitute: usize,
+    case_change: usize,
+    component: usize,
+    max_distance: Option<usize>,
+}
+
+impl SuggestOptions {
+    /// Creates a new cost model where every character edit costs `1`, inserting or removing a
+    /// component costs an extra `2`, and suggestions are limited to a third of the length of the
+    /// target.
+    pub const fn new() -> Self {
+        Self {
+            insert: 1,
+            delete: 1,
+            substitute: 1,
+            case_change: 1,
+            component: 2,
+            max_distance: None,
+        }
+    }
+
+    /// Sets the cost of inserting a character, which is `1` by default.
+    pub fn insert(mut self, cost: usize) -> Self {
+        self.insert = cost;
+        self
+    }
+
+    /// Sets the cost of removing a character, which is `1` by default.
+    pub fn delete(mut self, cost: usize) -> Self {
+        self.delete = cost;
+        self
+    }
+
+    /// Sets the cost of replacing a character with a different one, which is `1` by default.
+    pub fn substitute(mut self, cost: usize) -> Self {
+        self.substitute = cost;
+        self
+    }
+
+    /// Sets the cost of replacing a character with the same ascii letter in a different case,
+    /// which is `1` by default.
+    pub fn case_change(mut self, cost: usize) -> Self {
+        self.case_change = cost;
+        self
+    }
+
+    /// Sets the extra cost of inserting or removing a whole component, paid on top of the cost of
+    /// its characters, which is `2` by default.
+    pub fn component(mut self, cost: usize) -> Self {
+        self.component = cost;
+        self
+    }
+
+    /// Sets the largest distance at which a candidate is still suggested. `None`, the default,
+    /// allows a third of the length of the target, and at least `1`.
+    pub fn max_distance(mut self, max: Option<usize>) -> Self {
+        self.max_distance = max;
+        self
+    }
+
+    /// Returns the largest distance allowed for a target of `len` characters.
+    pub(crate) fn limit(&self, len: usize) -> usize {
+        self.max_distance.unwrap_or_else(|| cmp::max(len / 3, 1))
+    }
+
+    /// Returns the cheapest way to edit the components `a` into the components `b`, using
+    /// `eq_ignore_case` to find characters that only differ in case.
+    pub(crate) fn distance<C, E>(
+        &self,
+        a: &[C],
+        b: &[C],
+        eq_ignore_case: fn(&E, &E) -> bool,
+    ) -> usize
+    where
+        C: AsRef<[E]>,
+        E: PartialEq,
+    {
+        let whole = |c: &C, cost: usize| {
+            self.component
+                .saturating_add(c.as_ref().len().saturating_mul(cost))
+        };
+
+        edit_distance(
+            a,
+            b,
+            |x| whole(x, self.delete),
+            |y| whole(y, self.insert),
+            |x, y| {
+                edit_distance(
+                    x.as_ref(),
+                    y.as_ref(),
+                    |_| self.delete,
+                    |_| self.insert,
+                    |x, y| {
+                        if x == y {
+                            0
+                        } else if eq_ignore_case(x, y) {
+                            self.case_change
+                        } else {
+                            self.substitute
+                        }
+                    },
+                )
+            },
+        )
+    }
+}
+
+impl Default for SuggestOptions {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Computes the Levenshtein distance between `a` and `b` with the given costs, keeping a single
+/// row of the table at a time
+fn edit_distance<E>(
+    a: &[E],
+    b: &[E],
+    delete: impl Fn(&E) -> usize,
+    insert: impl Fn(&E) -> usize,
+    substitute: impl Fn(&E, &E) -> usize,
+) -> usize {
+    let mut row = Vec::with_capacity(b.len() + 1);
+    row.push(0usize);
+    for y in b {
+        let last = row[row.len() - 1];
+        row.push(last.saturating_add(insert(y)));
+    }
+
+    for x in a {
+        let mut diagonal = row[0];
+        row[0] = row[0].saturating_add(delete(x));
+        for (j, y) in b.iter().enumerate() {
+            let cost = cmp::min(
+                diagonal.saturating_add(substitute(x, y)),
+                cmp::min(
+                    row[j + 1].saturating_add(delete(x)),
+                    row[j].saturating_add(insert(y)),
+                ),
+            );
+            diagonal = row[j + 1];
+            row[j + 1] = cost;
+        }
+    }
+
+    row[b.len()]
+}
+
+/// Lowercases the drive letter of a disk prefix like `C:` or `\\?\C:`, leaving any other prefix
+/// such as `\\?\PICTURES:` alone
+fn lowercase_drive_letter(prefix: &[u8]) -> Cow<'_, [u8]> {
+    match WindowsPrefixComponent::try_from(prefix).map(|p| p.kind()) {
+        Ok(WindowsPrefix::Disk(letter) | WindowsPrefix::VerbatimDisk(letter))
+            if letter.is_ascii_uppercase() =>
+        {
+            // The drive letter of a disk prefix is always right before its trailing colon
+            let mut prefix = prefix.to_vec();
+            let i = prefix.len() - 2;
+            prefix[i] = prefix[i].to_ascii_lowercase();
+            Cow::Owned(prefix)
+        }
+        _ => Cow::Borrowed(prefix),
+    }
+}
+
+/// Returns the bytes of a component of kind `kind` to compare against those of another
+/// component, with the separators within a Windows prefix folded by [`fold_prefix_separators`]
+pub(crate) fn comparable_component_bytes(
+    kind: ComponentKind,
+    bytes: &[u8],
+    windows: bool,
+) -> Cow<'_, [u8]> {
+    if windows && kind == ComponentKind::Prefix {
+        fold_prefix_separators(bytes)
+    } else {
+        Cow::Borrowed(bytes)
+    }
+}
+
+/// Replaces every `/` within a Windows prefix with `\`, as both separate the parts of a prefix
+/// like `\\server\share` or `\\?\C:`
+fn fold_prefix_separators(prefix: &[u8]) -> Cow<'_, [u8]> {
+    if prefix.contains(&b'/') {
+        Cow::Owned(
+            prefix
+                .iter()
+                .map(|b| if *b == b'/' { b'\\' } else { *b })
+                .collect(),
+        )
+    } else {
+        Cow::Borrowed(prefix)
+    }
+}
+
+/// Component of a path alongside the raw bytes it occupied within the original path
+#[derive(Copy, Clone, Debug)]
+pub(crate) struct RawComponent<'a> {
+    pub kind: ComponentKind,
+
+    /// Bytes of the component as reported by the component itself (e.g. `\` for a root)
+    pub canonical: &'a [u8],
+
+    /// Bytes of the component as written in the original path
+    pub raw: &'a [u8],
+
+    /// Separators (and, outside of verbatim paths, skipped `.`) following the component
+    pub gap: &'a [u8],
+}
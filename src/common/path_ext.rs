@@ -0,0 +1,554 @@
+use crate::no_std_compat::*;
+use crate::{
+    Component, ComponentKind, Components, Encoding, Path, PathBuf, PathLike, Pattern, PatternError,
+    StripPrefixError, StripPrefixHint, Utf8Component, Utf8Components, Utf8Encoding, Utf8Path,
+    Utf8PathBuf, WithNameError,
+};
+
+/// Helpers named and behaving like those of Python's [`pathlib.PurePath`], for code being ported
+/// from Python or written by people who know it better than [`std::path`].
+///
+/// This trait is implemented for every [`Path`] and [`Utf8Path`], and comes with the same caveats
+/// as pathlib itself: everything is computed from the path as written, without touching the
+/// filesystem.
+///
+/// [`pathlib.PurePath`]: https://docs.python.org/3/library/pathlib.html#pure-paths
+/// [`std::path`]: https://doc.rust-lang.org/std/path/index.html
+///
+/// # Examples
+///
+/// ```
+/// use typed_path::{PathExt, Utf8UnixPath};
+///
+/// let path = Utf8UnixPath::new("/srv/data/archive.tar.gz");
+/// assert_eq!(path.parts(), ["/", "srv", "data", "archive.tar.gz"]);
+/// assert_eq!(path.suffix(), ".gz");
+/// assert_eq!(path.suffixes(), [".tar", ".gz"]);
+/// assert_eq!(path.with_name("backup.zip").unwrap(), "/srv/data/backup.zip");
+/// assert!(path.match_glob("data/*.gz").unwrap());
+/// ```
+pub trait PathExt: PathLike {
+    /// Returns the pieces of the path, where the first is the prefix and root together, as
+    /// written, if the path has either.
+    ///
+    /// Like pathlib, `.` components are left out while `..` components are kept.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{PathExt, Utf8WindowsPath, WindowsPath};
+    ///
+    /// assert_eq!(
+    ///     Utf8WindowsPath::new(r"C:\Program Files\.\app").parts(),
+    ///     [r"C:\", "Program Files", "app"],
+    /// );
+    /// assert_eq!(Utf8WindowsPath::new(r"C:a\..").parts(), ["C:", "a", ".."]);
+    /// assert!(WindowsPath::new(".").parts().is_empty());
+    /// ```
+    fn parts(&self) -> Vec<&Self::Str>;
+
+    /// Returns the extension of the file name, including its leading `.`, or an empty string if
+    /// there is none.
+    ///
+    /// Unlike [`Path::extension`], a file name ending in `.` has no suffix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{PathExt, Utf8UnixPath};
+    ///
+    /// assert_eq!(Utf8UnixPath::new("lib/lib.rs").suffix(), ".rs");
+    /// assert_eq!(Utf8UnixPath::new("lib/.profile").suffix(), "");
+    /// assert_eq!(Utf8UnixPath::new("lib/version.").suffix(), "");
+    /// ```
+    fn suffix(&self) -> &Self::Str;
+
+    /// Returns every extension of the file name, each including its leading `.`.
+    ///
+    /// Leading dots make a file hidden rather than start an extension, and a file name ending in
+    /// `.` has no suffixes at all.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{PathExt, Utf8UnixPath};
+    ///
+    /// assert_eq!(Utf8UnixPath::new("dist/app.tar.gz").suffixes(), [".tar", ".gz"]);
+    /// assert_eq!(Utf8UnixPath::new(".config.bak").suffixes(), [".bak"]);
+    /// assert!(Utf8UnixPath::new("notes.").suffixes().is_empty());
+    /// ```
+    fn suffixes(&self) -> Vec<&Self::Str>;
+
+    /// Returns the path with its final component replaced by `name`.
+    ///
+    /// Unlike [`Path::with_file_name`], this replaces a final `..` rather than appending to it.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`WithNameError::EmptyName`] if the path does not end in a normal or `..`
+    /// component, such as `/` or `.`, and [`WithNameError::InvalidName`] if `name` would not be
+    /// read back as a single normal or `..` component.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{PathExt, Utf8UnixPath, WithNameError};
+    ///
+    /// let path = Utf8UnixPath::new("docs/guide.md");
+    /// assert_eq!(path.with_name("index.md").unwrap(), "docs/index.md");
+    /// assert_eq!(path.with_name("a/b"), Err(WithNameError::InvalidName));
+    /// assert_eq!(Utf8UnixPath::new("/").with_name("a"), Err(WithNameError::EmptyName));
+    /// ```
+    fn with_name(&self, name: &Self::Str) -> Result<Self::Owned, WithNameError>;
+
+    /// Returns true if the path matches `pattern`, compared one component at a time with
+    /// [`Pattern`].
+    ///
+    /// A relative pattern matches the final components of the path, while a pattern with a prefix
+    /// or root must match the whole path. Components are compared case-insensitively for
+    /// encodings that are not case sensitive, and an empty pattern matches nothing.
+    ///
+    /// As each component is matched on its own, `*` cannot span components and `**` behaves like
+    /// `*`; use [`Pattern::matches_path`] to match across components.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PatternError`] if a component of `pattern` is not a valid [`Pattern`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{PathExt, Utf8UnixPath, Utf8WindowsPath};
+    ///
+    /// let path = Utf8UnixPath::new("/src/lib.rs");
+    /// assert!(path.match_glob("*.rs").unwrap());
+    /// assert!(path.match_glob("src/*.rs").unwrap());
+    /// assert!(path.match_glob("/*/*.rs").unwrap());
+    /// assert!(!path.match_glob("/*.rs").unwrap());
+    ///
+    /// assert!(Utf8WindowsPath::new(r"C:\Src\LIB.RS").match_glob("c:/src/*.rs").unwrap());
+    /// ```
+    fn match_glob(&self, pattern: &str) -> Result<bool, PatternError>;
+
+    /// Returns the path relative to `base`, which must be one of its ancestors.
+    ///
+    /// Unlike the inherent [`Path::relative_to`], this never walks up with `..`, so it behaves
+    /// like [`Path::strip_prefix`], except ignoring case for encodings that are not case
+    /// sensitive. As the inherent method takes precedence, call this one as
+    /// `PathExt::relative_to(path, base)`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`StripPrefixError`] if `base` is not an ancestor of the path.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{PathExt, Utf8UnixPath, Utf8WindowsPath};
+    ///
+    /// let path = Utf8WindowsPath::new(r"C:\Users\Me\notes.txt");
+    /// assert_eq!(
+    ///     PathExt::relative_to(path, Utf8WindowsPath::new(r"c:\users")).unwrap(),
+    ///     r"Me\notes.txt",
+    /// );
+    ///
+    /// let path = Utf8UnixPath::new("/etc/hosts");
+    /// assert!(PathExt::relative_to(path, Utf8UnixPath::new("/usr")).is_err());
+    /// ```
+    fn relative_to(&self, base: &Self) -> Result<Self::Owned, StripPrefixError>;
+}
+
+impl<T> PathExt for Path<T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    fn parts(&self) -> Vec<&[u8]> {
+        let (anchor, rest) = split_anchor(self);
+        let mut parts = Vec::with_capacity(rest.len() + 1);
+        if !anchor.is_empty() {
+            parts.push(anchor);
+        }
+        parts.extend(rest);
+        parts
+    }
+
+    fn suffix(&self) -> &[u8] {
+        match self.file_name() {
+            Some(name) => &name[suffix_start(name).unwrap_or(name.len())..],
+            None => &[],
+        }
+    }
+
+    fn suffixes(&self) -> Vec<&[u8]> {
+        match self.file_name() {
+            Some(name) => suffix_ranges(name)
+                .into_iter()
+                .map(|(start, end)| &name[start..end])
+                .collect(),
+            None => Vec::new(),
+        }
+    }
+
+    fn with_name(&self, name: &[u8]) -> Result<PathBuf<T>, WithNameError> {
+        match self.components().next_back() {
+            Some(component) if component.is_normal() || component.is_parent() => {}
+            _ => return Err(WithNameError::EmptyName),
+        }
+
+        // A valid name is read back as exactly one component spanning all of it
+        let mut components = Path::<T>::new(name).components();
+        match (components.next(), components.next()) {
+            (Some(component), None)
+                if (component.is_normal() || component.is_parent())
+                    && component.len() == name.len() => {}
+            _ => return Err(WithNameError::InvalidName),
+        }
+
+        Ok(self.parent().unwrap_or_else(|| Path::new("")).join(name))
+    }
+
+    fn match_glob(&self, pattern: &str) -> Result<bool, PatternError> {
+        let (pattern_anchor, pattern_parts) = split_anchor(Path::<T>::new(pattern));
+        let (anchor, parts) = split_anchor(self);
+        if pattern_anchor.is_empty() && pattern_parts.is_empty() {
+            return Ok(false);
+        }
+
+        if !pattern_anchor.is_empty() {
+            let (pattern_anchor, anchor) = (Path::<T>::new(pattern_anchor), Path::<T>::new(anchor));
+            let same_anchor = if T::info().case_sensitive {
+                pattern_anchor == anchor
+            } else {
+                matches!(
+                    anchor.strip_prefix_with(pattern_anchor, StripPrefixHint::IgnoreCase),
+                    Ok(rest) if rest.as_bytes().is_empty()
+                )
+            };
+
+            if !same_anchor || pattern_parts.len() != parts.len() {
+                return Ok(false);
+            }
+        } else if pattern_parts.len() > parts.len() {
+            return Ok(false);
+        }
+
+        for (part, pattern) in parts.iter().rev().zip(pattern_parts.iter().rev()) {
+            // Each part came from a `&str`, and splitting on ASCII separators keeps it valid
+            let pattern = core::str::from_utf8(pattern).unwrap_or_default();
+            if !Pattern::new(pattern)?.matches_path(Path::<T>::new(part)) {
+                return Ok(false);
+            }
+        }
+
+        Ok(true)
+    }
+
+    fn relative_to(&self, base: &Self) -> Result<PathBuf<T>, StripPrefixError> {
+        if T::info().case_sensitive {
+            self.strip_prefix(base).map(Path::to_path_buf)
+        } else {
+            self.strip_prefix_with(base, StripPrefixHint::IgnoreCase)
+        }
+    }
+}
+
+impl<T> PathExt for Utf8Path<T>
+where
+    T: for<'enc> Utf8Encoding<'enc>,
+{
+    fn parts(&self) -> Vec<&str> {
+        let (anchor, rest) = split_utf8_anchor(self);
+        let mut parts = Vec::with_capacity(rest.len() + 1);
+        if !anchor.is_empty() {
+            parts.push(anchor);
+        }
+        parts.extend(rest);
+        parts
+    }
+
+    fn suffix(&self) -> &str {
+        match self.file_name() {
+            Some(name) => &name[suffix_start(name.as_bytes()).unwrap_or(name.len())..],
+            None => "",
+        }
+    }
+
+    fn suffixes(&self) -> Vec<&str> {
+        match self.file_name() {
+            Some(name) => suffix_ranges(name.as_bytes())
+                .into_iter()
+                .map(|(start, end)| &name[start..end])
+                .collect(),
+            None => Vec::new(),
+        }
+    }
+
+    fn with_name(&self, name: &str) -> Result<Utf8PathBuf<T>, WithNameError> {
+        match self.components().next_back() {
+            Some(component) if component.is_normal() || component.is_parent() => {}
+            _ => return Err(WithNameError::EmptyName),
+        }
+
+        // A valid name is read back as exactly one component spanning all of it
+        let mut components = Utf8Path::<T>::new(name).components();
+        match (components.next(), components.next()) {
+            (Some(component), None)
+                if (component.is_normal() || component.is_parent())
+                    && component.len() == name.len() => {}
+            _ => return Err(WithNameError::InvalidName),
+        }
+
+        Ok(self
+            .parent()
+            .unwrap_or_else(|| Utf8Path::new(""))
+            .join(name))
+    }
+
+    fn match_glob(&self, pattern: &str) -> Result<bool, PatternError> {
+        let (pattern_anchor, pattern_parts) = split_utf8_anchor(Utf8Path::<T>::new(pattern));
+        let (anchor, parts) = split_utf8_anchor(self);
+        if pattern_anchor.is_empty() && pattern_parts.is_empty() {
+            return Ok(false);
+        }
+
+        if !pattern_anchor.is_empty() {
+            let (pattern_anchor, anchor) = (
+                Utf8Path::<T>::new(pattern_anchor),
+                Utf8Path::<T>::new(anchor),
+            );
+            let same_anchor = if T::info().case_sensitive {
+                pattern_anchor == anchor
+            } else {
+                matches!(
+                    anchor.strip_prefix_with(pattern_anchor, StripPrefixHint::IgnoreCase),
+                    Ok(rest) if rest.as_str().is_empty()
+                )
+            };
+
+            if !same_anchor || pattern_parts.len() != parts.len() {
+                return Ok(false);
+            }
+        } else if pattern_parts.len() > parts.len() {
+            return Ok(false);
+        }
+
+        for (part, pattern) in parts.iter().rev().zip(pattern_parts.iter().rev()) {
+            if !Pattern::new(pattern)?.matches_utf8_path(Utf8Path::<T>::new(part)) {
+                return Ok(false);
+            }
+        }
+
+        Ok(true)
+    }
+
+    fn relative_to(&self, base: &Self) -> Result<Utf8PathBuf<T>, StripPrefixError> {
+        if T::info().case_sensitive {
+            self.strip_prefix(base).map(Utf8Path::to_path_buf)
+        } else {
+            self.strip_prefix_with(base, StripPrefixHint::IgnoreCase)
+        }
+    }
+}
+
+/// Splits a path into its prefix and root as written, and the components that follow them other
+/// than `.`
+fn split_anchor<T>(path: &Path<T>) -> (&[u8], Vec<&[u8]>)
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    let bytes = path.as_bytes();
+    let mut components = path.components();
+    let mut anchor_len = 0;
+    let mut parts = Vec::new();
+
+    loop {
+        let rest = components.as_bytes();
+        match components.next() {
+            Some(component) if component.is_root() || component.kind() == ComponentKind::Prefix => {
+                anchor_len = bytes.len() - rest.len() + component.len();
+            }
+            Some(component) if component.is_current() => {}
+            Some(component) => parts.push(component.as_bytes()),
+            None => break,
+        }
+    }
+
+    (&bytes[..anchor_len], parts)
+}
+
+/// Splits a path into its prefix and root as written, and the components that follow them other
+/// than `.`
+fn split_utf8_anchor<T>(path: &Utf8Path<T>) -> (&str, Vec<&str>)
+where
+    T: for<'enc> Utf8Encoding<'enc>,
+{
+    let s = path.as_str();
+    let mut components = path.components();
+    let mut anchor_len = 0;
+    let mut parts = Vec::new();
+
+    loop {
+        let rest = components.as_str();
+        match components.next() {
+            Some(component) if component.is_root() || component.kind() == ComponentKind::Prefix => {
+                anchor_len = s.len() - rest.len() + component.len();
+            }
+            Some(component) if component.is_current() => {}
+            Some(component) => parts.push(component.as_str()),
+            None => break,
+        }
+    }
+
+    (&s[..anchor_len], parts)
+}
+
+/// Returns where the suffix of `name` starts, which is its last `.` unless that is the first or
+/// last byte
+fn suffix_start(name: &[u8]) -> Option<usize> {
+    match name.iter().rposition(|b| *b == b'.') {
+        Some(i) if i > 0 && i + 1 < name.len() => Some(i),
+        _ => None,
+    }
+}
+
+/// Returns the range of each suffix of `name`, which start at every `.` after any leading ones
+fn suffix_ranges(name: &[u8]) -> Vec<(usize, usize)> {
+    if name.last() == Some(&b'.') {
+        return Vec::new();
+    }
+
+    let leading = name.iter().take_while(|b| **b == b'.').count();
+    let starts: Vec<usize> = (leading + 1..name.len())
+        .filter(|i| name[*i] == b'.')
+        .collect();
+    starts
+        .iter()
+        .enumerate()
+        .map(|(i, start)| (*start, starts.get(i + 1).copied().unwrap_or(name.len())))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{UnixPath, Utf8UnixPath, Utf8WindowsPath, WindowsPath};
+
+    #[test]
+    fn parts_should_match_pathlib() {
+        assert_eq!(Utf8UnixPath::new("").parts(), Vec::<&str>::new());
+        assert_eq!(Utf8UnixPath::new("./.").parts(), Vec::<&str>::new());
+        assert_eq!(Utf8UnixPath::new("/").parts(), ["/"]);
+        assert_eq!(Utf8UnixPath::new("../a/./b/").parts(), ["..", "a", "b"]);
+        assert_eq!(
+            Utf8WindowsPath::new(r"\\server\share\a").parts(),
+            [r"\\server\share\", "a"]
+        );
+        assert_eq!(Utf8WindowsPath::new(r"C:a").parts(), ["C:", "a"]);
+        assert_eq!(Utf8WindowsPath::new(r"\a").parts(), [r"\", "a"]);
+        assert_eq!(
+            WindowsPath::new(r"C:/a\b").parts(),
+            [b"C:/".as_slice(), b"a", b"b"]
+        );
+    }
+
+    #[test]
+    fn suffixes_should_match_pathlib() {
+        for (name, suffix, suffixes) in [
+            ("a", "", &[][..]),
+            ("a.b", ".b", &[".b"][..]),
+            ("a.tar.gz", ".gz", &[".tar", ".gz"][..]),
+            (".a", "", &[][..]),
+            (".b.c", ".c", &[".c"][..]),
+            ("..a", ".a", &[][..]),
+            ("a.", "", &[][..]),
+            ("a. b", ". b", &[". b"][..]),
+            ("a..b", ".b", &[".", ".b"][..]),
+            ("..", "", &[][..]),
+        ] {
+            let path = Utf8UnixPath::new(name);
+            assert_eq!(path.suffix(), suffix, "{name}");
+            assert_eq!(path.suffixes(), suffixes, "{name}");
+
+            let path = UnixPath::new(name);
+            assert_eq!(path.suffix(), suffix.as_bytes(), "{name}");
+            assert_eq!(path.suffixes().len(), suffixes.len(), "{name}");
+        }
+    }
+
+    #[test]
+    fn with_name_should_match_pathlib() {
+        let with_name = |path: &str, name: &str| {
+            Utf8UnixPath::new(path)
+                .with_name(name)
+                .map(|path| path.into_string())
+        };
+        assert_eq!(with_name("a", "b"), Ok(String::from("b")));
+        assert_eq!(with_name("/a/b", "c"), Ok(String::from("/a/c")));
+        assert_eq!(with_name("a/..", "x"), Ok(String::from("a/x")));
+        assert_eq!(with_name("a/b", ".."), Ok(String::from("a/..")));
+        assert_eq!(with_name("", "a"), Err(WithNameError::EmptyName));
+        assert_eq!(with_name(".", "a"), Err(WithNameError::EmptyName));
+        assert_eq!(with_name("/", "a"), Err(WithNameError::EmptyName));
+        for name in ["", ".", "a/", "/a", "a/b"] {
+            assert_eq!(
+                with_name("a", name),
+                Err(WithNameError::InvalidName),
+                "{name}"
+            );
+        }
+
+        let path = Utf8WindowsPath::new(r"C:\a\b");
+        assert_eq!(path.with_name("c").unwrap(), r"C:\a\c");
+        assert_eq!(path.with_name("D:"), Err(WithNameError::InvalidName));
+        assert_eq!(path.with_name(r"c\d"), Err(WithNameError::InvalidName));
+        assert_eq!(
+            Utf8WindowsPath::new(r"C:").with_name("a"),
+            Err(WithNameError::EmptyName)
+        );
+        assert_eq!(
+            WindowsPath::new(r"C:a").with_name(b"b").unwrap(),
+            WindowsPath::new("C:b")
+        );
+    }
+
+    #[test]
+    fn match_glob_should_match_pathlib() {
+        let unix = |path: &str, pattern: &str| Utf8UnixPath::new(path).match_glob(pattern);
+        assert_eq!(unix("a/b.py", "*.py"), Ok(true));
+        assert_eq!(unix("/a/b/c.py", "b/*.py"), Ok(true));
+        assert_eq!(unix("/a/b/c.py", "a/*.py"), Ok(false));
+        assert_eq!(unix("/a.py", "/*.py"), Ok(true));
+        assert_eq!(unix("a/b.py", "/*.py"), Ok(false));
+        assert_eq!(unix("/a/b.py", "/*.py"), Ok(false));
+        assert_eq!(unix("a.py", "b/*.py"), Ok(false));
+        assert_eq!(unix("a/b.py", "**"), Ok(true));
+        assert_eq!(unix("A.PY", "*.py"), Ok(false));
+        assert_eq!(unix("a", ""), Ok(false));
+        assert_eq!(unix("a", "[b"), Err(PatternError::UnclosedClass(0)));
+
+        let windows = |path: &str, pattern: &str| Utf8WindowsPath::new(path).match_glob(pattern);
+        assert_eq!(windows(r"C:\a\B.PY", "b.py"), Ok(true));
+        assert_eq!(windows(r"C:\a\b.py", "c:/*/*.py"), Ok(true));
+        assert_eq!(windows(r"C:\a\b.py", r"D:\*\*.py"), Ok(false));
+        assert_eq!(windows(r"C:a\b.py", r"C:\*\*.py"), Ok(false));
+        assert_eq!(WindowsPath::new(r"C:\a\B.PY").match_glob("a/b.*"), Ok(true));
+    }
+
+    #[test]
+    fn relative_to_should_not_walk_up() {
+        let path = UnixPath::new("/a/b");
+        assert_eq!(
+            PathExt::relative_to(path, UnixPath::new("/a")).unwrap(),
+            UnixPath::new("b")
+        );
+        assert_eq!(PathExt::relative_to(path, path).unwrap(), UnixPath::new(""));
+        assert!(PathExt::relative_to(path, UnixPath::new("/A")).is_err());
+        assert!(PathExt::relative_to(path, UnixPath::new("/a/c")).is_err());
+
+        let path = WindowsPath::new(r"C:\A\b");
+        assert_eq!(
+            PathExt::relative_to(path, WindowsPath::new("c:/a")).unwrap(),
+            WindowsPath::new("b")
+        );
+    }
+}
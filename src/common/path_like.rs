@@ -0,0 +1,236 @@
+use core::borrow::Borrow;
+
+use crate::no_std_compat::*;
+use crate::{private, Encoding, Path, PathBuf, Utf8Encoding, Utf8Path, Utf8PathBuf};
+
+/// Common read-only API shared by every path type regardless of its encoding, allowing a single
+/// generic function to work with [`Path`] and [`Utf8Path`] of any encoding.
+///
+/// This trait is implemented by [`UnixPath`], [`WindowsPath`], [`Utf8UnixPath`],
+/// [`Utf8WindowsPath`], and generally every [`Path`] and [`Utf8Path`]. Each method behaves the
+/// same as the inherent method of the same name.
+///
+/// This trait is sealed and cannot be implemented outside of this crate.
+///
+/// [`UnixPath`]: crate::UnixPath
+/// [`WindowsPath`]: crate::WindowsPath
+/// [`Utf8UnixPath`]: crate::Utf8UnixPath
+/// [`Utf8WindowsPath`]: crate::Utf8WindowsPath
+///
+/// # Examples
+///
+/// ```
+/// use typed_path::{PathLike, UnixPath, Utf8WindowsPath};
+///
+/// fn depth<P: PathLike + ?Sized>(path: &P) -> usize {
+///     path.iter().count()
+/// }
+///
+/// fn sibling<P: PathLike + ?Sized>(path: &P, name: &P) -> Option<P::Owned> {
+///     path.parent().map(|parent| parent.join(name))
+/// }
+///
+/// assert_eq!(depth(UnixPath::new("/usr/bin/env")), 4);
+/// assert_eq!(depth(Utf8WindowsPath::new(r"C:\Windows")), 3);
+///
+/// let path = sibling(Utf8WindowsPath::new(r"C:\logs\app.log"), Utf8WindowsPath::new("app.old"));
+/// assert_eq!(path.unwrap().as_str(), r"C:\logs\app.old");
+/// ```
+pub trait PathLike: private::Sealed {
+    /// Type of the individual pieces of the path, either [`[u8]`](slice) or [`str`].
+    type Str: ?Sized;
+
+    /// Owned form of the path, such as [`PathBuf`] for [`Path`].
+    type Owned: Borrow<Self>;
+
+    /// Yields the underlying bytes of the path.
+    fn as_bytes(&self) -> &[u8];
+
+    /// Produces an iterator over the components of the path viewed as [`Self::Str`].
+    ///
+    /// See [`Path::iter`] for more details.
+    fn iter(&self) -> Box<dyn Iterator<Item = &Self::Str> + '_>;
+
+    /// Returns the final component of the path, if there is one.
+    ///
+    /// See [`Path::file_name`] for more details.
+    fn file_name(&self) -> Option<&Self::Str>;
+
+    /// Extracts the stem (non-extension) portion of [`file_name`](PathLike::file_name).
+    ///
+    /// See [`Path::file_stem`] for more details.
+    fn file_stem(&self) -> Option<&Self::Str>;
+
+    /// Extracts the extension of [`file_name`](PathLike::file_name), if possible.
+    ///
+    /// See [`Path::extension`] for more details.
+    fn extension(&self) -> Option<&Self::Str>;
+
+    /// Returns the path without its final component, if there is one.
+    ///
+    /// See [`Path::parent`] for more details.
+    fn parent(&self) -> Option<&Self>;
+
+    /// Returns `true` if the path has a root.
+    ///
+    /// See [`Path::has_root`] for more details.
+    fn has_root(&self) -> bool;
+
+    /// Returns `true` if the path is absolute, i.e., if it is independent of the current
+    /// directory.
+    ///
+    /// See [`Path::is_absolute`] for more details.
+    fn is_absolute(&self) -> bool;
+
+    /// Returns `true` if the path is relative, i.e., not absolute.
+    fn is_relative(&self) -> bool {
+        !self.is_absolute()
+    }
+
+    /// Determines whether `base` is a prefix of `self`.
+    ///
+    /// See [`Path::starts_with`] for more details.
+    fn starts_with(&self, base: &Self) -> bool;
+
+    /// Determines whether `child` is a suffix of `self`.
+    ///
+    /// See [`Path::ends_with`] for more details.
+    fn ends_with(&self, child: &Self) -> bool;
+
+    /// Creates an owned path with `path` adjoined to `self`.
+    ///
+    /// See [`Path::join`] for more details.
+    fn join(&self, path: &Self) -> Self::Owned;
+
+    /// Converts the path to its owned form.
+    fn to_path_buf(&self) -> Self::Owned;
+
+    /// Produces an owned key whose plain byte order matches the order of paths as trees.
+    ///
+    /// See [`Path::sort_key`] for more details.
+    fn sort_key(&self) -> Vec<u8>;
+}
+
+impl<T> private::Sealed for Path<T> where T: for<'enc> Encoding<'enc> {}
+
+impl<T> PathLike for Path<T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    type Str = [u8];
+    type Owned = PathBuf<T>;
+
+    fn as_bytes(&self) -> &[u8] {
+        Path::as_bytes(self)
+    }
+
+    fn iter(&self) -> Box<dyn Iterator<Item = &[u8]> + '_> {
+        Box::new(Path::iter(self))
+    }
+
+    fn file_name(&self) -> Option<&[u8]> {
+        Path::file_name(self)
+    }
+
+    fn file_stem(&self) -> Option<&[u8]> {
+        Path::file_stem(self)
+    }
+
+    fn extension(&self) -> Option<&[u8]> {
+        Path::extension(self)
+    }
+
+    fn parent(&self) -> Option<&Self> {
+        Path::parent(self)
+    }
+
+    fn has_root(&self) -> bool {
+        Path::has_root(self)
+    }
+
+    fn is_absolute(&self) -> bool {
+        Path::is_absolute(self)
+    }
+
+    fn starts_with(&self, base: &Self) -> bool {
+        Path::starts_with(self, base)
+    }
+
+    fn ends_with(&self, child: &Self) -> bool {
+        Path::ends_with(self, child)
+    }
+
+    fn join(&self, path: &Self) -> PathBuf<T> {
+        Path::join(self, path)
+    }
+
+    fn to_path_buf(&self) -> PathBuf<T> {
+        Path::to_path_buf(self)
+    }
+
+    fn sort_key(&self) -> Vec<u8> {
+        Path::sort_key(self)
+    }
+}
+
+impl<T> private::Sealed for Utf8Path<T> where T: for<'enc> Utf8Encoding<'enc> {}
+
+impl<T> PathLike for Utf8Path<T>
+where
+    T: for<'enc> Utf8Encoding<'enc>,
+{
+    type Str = str;
+    type Owned = Utf8PathBuf<T>;
+
+    fn as_bytes(&self) -> &[u8] {
+        Utf8Path::as_str(self).as_bytes()
+    }
+
+    fn iter(&self) -> Box<dyn Iterator<Item = &str> + '_> {
+        Box::new(Utf8Path::iter(self))
+    }
+
+    fn file_name(&self) -> Option<&str> {
+        Utf8Path::file_name(self)
+    }
+
+    fn file_stem(&self) -> Option<&str> {
+        Utf8Path::file_stem(self)
+    }
+
+    fn extension(&self) -> Option<&str> {
+        Utf8Path::extension(self)
+    }
+
+    fn parent(&self) -> Option<&Self> {
+        Utf8Path::parent(self)
+    }
+
+    fn has_root(&self) -> bool {
+        Utf8Path::has_root(self)
+    }
+
+    fn is_absolute(&self) -> bool {
+        Utf8Path::is_absolute(self)
+    }
+
+    fn starts_with(&self, base: &Self) -> bool {
+        Utf8Path::starts_with(self, base)
+    }
+
+    fn ends_with(&self, child: &Self) -> bool {
+        Utf8Path::ends_with(self, child)
+    }
+
+    fn join(&self, path: &Self) -> Utf8PathBuf<T> {
+        Utf8Path::join(self, path)
+    }
+
+    fn to_path_buf(&self) -> Utf8PathBuf<T> {
+        Utf8Path::to_path_buf(self)
+    }
+
+    fn sort_key(&self) -> Vec<u8> {
+        Utf8Path::sort_key(self)
+    }
+}
@@ -0,0 +1,1130 @@
+use core::fmt;
+use core::str::FromStr;
+
+use crate::common::{EncodingInfo, PatternError};
+use crate::no_std_compat::*;
+use crate::{Encoding, Path, Utf8Encoding, Utf8Path};
+
+/// A glob pattern that can be matched against paths of any encoding.
+///
+/// Patterns use the familiar shell syntax:
+///
+/// * `?` matches any single character other than a separator
+/// * `*` matches any sequence of characters other than a separator
+/// * `**` as an entire component matches any number of components, including none
+/// * `[abc]` and `[a-z]` match one of the listed characters, while `[!abc]` matches any character
+///   that is not listed, neither ever matching a separator
+/// * `{a,b}` matches any of the comma-separated alternatives, and `{1..3}` any of the numbers or
+///   characters in a sequence, as described by [`Pattern::expand_braces`]
+///
+/// Separators within the pattern are interpreted using the encoding of the path being matched,
+/// so `/` and `\` are both separators when matching a Windows path. As `\` cannot be used to
+/// escape metacharacters for this reason, they are instead matched literally by wrapping them in
+/// brackets, such as `[*]` or `[{]`.
+///
+/// A pattern must match the entire path, and is compared case-insensitively for encodings that
+/// are not case sensitive. Both of these behaviors, alongside a few others, can be adjusted
+/// through [`MatchOptions`].
+///
+/// # Examples
+///
+/// ```
+/// use typed_path::{Pattern, UnixPath, WindowsPath};
+///
+/// let pattern = Pattern::new("src/**/*.rs").unwrap();
+/// assert!(pattern.matches_path(UnixPath::new("src/lib.rs")));
+/// assert!(pattern.matches_path(UnixPath::new("src/common/path.rs")));
+/// assert!(!pattern.matches_path(UnixPath::new("src/lib.rs.bak")));
+/// assert!(!pattern.matches_path(UnixPath::new("tests/lib.rs")));
+///
+/// // Windows paths can use either separator and ignore case
+/// assert!(pattern.matches_path(WindowsPath::new(r"SRC\Common\Path.RS")));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Pattern {
+    original: String,
+
+    /// Tokens of each alternative produced by brace expansion, any of which can match
+    alternatives: Vec<Vec<Token>>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+enum Token {
+    /// Literal character, which is a separator if the encoding says so
+    Char(char),
+
+    /// `?`
+    AnyChar,
+
+    /// `*`
+    AnyChars,
+
+    /// `**` followed by a separator, matching any number of components and their separators
+    AnyComponents,
+
+    /// `**` at the end of the pattern, matching everything that remains
+    AnyRemaining,
+
+    /// `[...]` or `[!...]`, holding inclusive ranges of characters
+    Class {
+        negated: bool,
+        ranges: Vec<(char, char)>,
+    },
+}
+
+impl Pattern {
+    /// Parses `pattern` into a new [`Pattern`], failing if it contains an unclosed or invalid
+    /// character class.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Pattern, PatternError};
+    ///
+    /// assert!(Pattern::new("*.[ch]").is_ok());
+    /// assert_eq!(Pattern::new("*.[ch"), Err(PatternError::UnclosedClass(2)));
+    /// assert_eq!(Pattern::new("[z-a]"), Err(PatternError::InvalidRange(1)));
+    /// ```
+    pub fn new(pattern: &str) -> Result<Self, PatternError> {
+        trace_span!("Pattern::new", pattern);
+
+        let alternatives = Self::expand_braces(pattern)
+            .iter()
+            .map(|alternative| tokenize(alternative))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self {
+            original: pattern.to_string(),
+            alternatives,
+        })
+    }
+
+    /// Expands the braces within `pattern` the same way as bash, producing the patterns that
+    /// [`Pattern::new`] would match any of.
+    ///
+    /// * `{a,b,c}` is replaced by each of the comma-separated alternatives, which may be empty or
+    ///   contain further braces
+    /// * `{x..y}` and `{x..y..step}` are replaced by each integer or character from `x` to `y`,
+    ///   counting down when `y` is smaller, and padding integers with zeros to the same width when
+    ///   either end starts with `0`
+    ///
+    /// Any braces that fit neither form, such as `{a}`, are left as they are, as are braces
+    /// within a character class.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::Pattern;
+    ///
+    /// assert_eq!(
+    ///     Pattern::expand_braces("src/{lib,main}.rs"),
+    ///     ["src/lib.rs", "src/main.rs"],
+    /// );
+    /// assert_eq!(
+    ///     Pattern::expand_braces("{a,b}{1..2}"),
+    ///     ["a1", "a2", "b1", "b2"],
+    /// );
+    /// assert_eq!(
+    ///     Pattern::expand_braces("log.{08..10}.gz"),
+    ///     ["log.08.gz", "log.09.gz", "log.10.gz"],
+    /// );
+    /// assert_eq!(Pattern::expand_braces("{e..a..2}"), ["e", "c", "a"]);
+    /// assert_eq!(Pattern::expand_braces("{a}[{,}]"), ["{a}[{,}]"]);
+    /// ```
+    pub fn expand_braces(pattern: &str) -> Vec<String> {
+        let mut expanded = Vec::new();
+        expand_braces_into(pattern, &mut expanded);
+        expanded
+    }
+
+    /// Escapes any characters within `path` that have special meaning in a pattern by wrapping
+    /// them in brackets, producing a pattern that only matches `path` itself.
+    ///
+    /// Separators are left as they are, so they continue to be interpreted using the encoding of
+    /// the path being matched. This makes the escaped path safe to embed within a larger pattern,
+    /// including as an alternative within braces.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Pattern, Utf8WindowsPath};
+    ///
+    /// let escaped = Pattern::escape(r"C:\Users\me\[draft] {v2}");
+    /// assert_eq!(escaped, r"C:\Users\me\[[]draft[]] [{]v2[}]");
+    ///
+    /// let pattern = Pattern::new(&format!(r"{}\**\*.txt", escaped)).unwrap();
+    /// let path = Utf8WindowsPath::new(r"C:\Users\me\[draft] {v2}\notes\todo.txt");
+    /// assert!(pattern.matches_utf8_path(path));
+    ///
+    /// let path = Utf8WindowsPath::new(r"C:\Users\me\d {v2}\todo.txt");
+    /// assert!(!pattern.matches_utf8_path(path));
+    /// ```
+    pub fn escape(path: &str) -> String {
+        let mut escaped = String::with_capacity(path.len());
+        for c in path.chars() {
+            if matches!(c, '?' | '*' | '[' | ']' | '{' | '}' | ',') {
+                escaped.push('[');
+                escaped.push(c);
+                escaped.push(']');
+            } else {
+                escaped.push(c);
+            }
+        }
+        escaped
+    }
+
+    /// Returns the pattern as it was originally written.
+    pub fn as_str(&self) -> &str {
+        &self.original
+    }
+
+    /// Returns true if the pattern matches the entirety of `path`, using the separators and case
+    /// sensitivity of its encoding.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Pattern, UnixPath, WindowsPath};
+    ///
+    /// let pattern = Pattern::new("docs/*.md").unwrap();
+    /// assert!(pattern.matches_path(UnixPath::new("docs/README.md")));
+    /// assert!(!pattern.matches_path(UnixPath::new("docs/guide/README.md")));
+    ///
+    /// // `\` is not a separator for Unix paths
+    /// assert!(!pattern.matches_path(UnixPath::new(r"docs\README.md")));
+    /// assert!(pattern.matches_path(WindowsPath::new(r"docs\README.md")));
+    /// ```
+    pub fn matches_path<T>(&self, path: &Path<T>) -> bool
+    where
+        T: for<'enc> Encoding<'enc>,
+    {
+        self.matches_path_with(path, &MatchOptions::new())
+    }
+
+    /// Returns true if the pattern matches the entirety of `path`, using the separators of its
+    /// encoding and the given `options`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{MatchOptions, Pattern, UnixPath};
+    ///
+    /// let pattern = Pattern::new("src/*.rs").unwrap();
+    /// let options = MatchOptions::new().normalize(true);
+    /// assert!(!pattern.matches_path(UnixPath::new("src/../src/./lib.rs")));
+    /// assert!(pattern.matches_path_with(UnixPath::new("src/../src/./lib.rs"), &options));
+    /// ```
+    pub fn matches_path_with<T>(&self, path: &Path<T>, options: &MatchOptions) -> bool
+    where
+        T: for<'enc> Encoding<'enc>,
+    {
+        if options.normalize {
+            self.matches_bytes(path.normalize().as_bytes(), T::info(), options)
+        } else {
+            self.matches_bytes(path.as_bytes(), T::info(), options)
+        }
+    }
+
+    /// Returns true if the pattern matches the entirety of `path`, using the separators and case
+    /// sensitivity of its encoding.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Pattern, Utf8UnixPath};
+    ///
+    /// let pattern = Pattern::new("[!.]*/**").unwrap();
+    /// assert!(pattern.matches_utf8_path(Utf8UnixPath::new("src/lib.rs")));
+    /// assert!(!pattern.matches_utf8_path(Utf8UnixPath::new(".git/config")));
+    /// ```
+    pub fn matches_utf8_path<T>(&self, path: &Utf8Path<T>) -> bool
+    where
+        T: for<'enc> Utf8Encoding<'enc>,
+    {
+        self.matches_utf8_path_with(path, &MatchOptions::new())
+    }
+
+    /// Returns true if the pattern matches the entirety of `path`, using the separators of its
+    /// encoding and the given `options`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{MatchOptions, Pattern, Utf8WindowsPath};
+    ///
+    /// let pattern = Pattern::new("*.txt").unwrap();
+    /// let options = MatchOptions::new().case_sensitive(Some(true));
+    /// assert!(pattern.matches_utf8_path(Utf8WindowsPath::new("NOTES.TXT")));
+    /// assert!(!pattern.matches_utf8_path_with(Utf8WindowsPath::new("NOTES.TXT"), &options));
+    /// ```
+    pub fn matches_utf8_path_with<T>(&self, path: &Utf8Path<T>, options: &MatchOptions) -> bool
+    where
+        T: for<'enc> Utf8Encoding<'enc>,
+    {
+        if options.normalize {
+            self.matches_bytes(path.normalize().as_str().as_bytes(), T::info(), options)
+        } else {
+            self.matches_bytes(path.as_str().as_bytes(), T::info(), options)
+        }
+    }
+
+    fn matches_bytes(&self, path: &[u8], info: EncodingInfo, options: &MatchOptions) -> bool {
+        let matcher = Matcher {
+            path,
+            info,
+            case_sensitive: options.case_sensitive.unwrap_or(info.case_sensitive),
+            literal_separator: options.require_literal_separator,
+            literal_leading_dot: options.require_literal_leading_dot,
+        };
+
+        self.alternatives
+            .iter()
+            .any(|tokens| matcher.matches(tokens, 0))
+    }
+
+    /// Translates the pattern into an equivalent regular expression for paths of the encoding
+    /// described by `info`, anchored to match entire paths.
+    ///
+    /// The expression uses the syntax of the `regex` crate, limiting classes through
+    /// intersection so that they never match separators.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Encoding, Pattern, UnixEncoding, WindowsEncoding};
+    ///
+    /// let pattern = Pattern::new("**/*.rs").unwrap();
+    /// assert_eq!(
+    ///     pattern.to_regex_string(UnixEncoding::info()),
+    ///     r"^(?:[^/]*[/])*[^/]*\.rs$",
+    /// );
+    /// assert_eq!(
+    ///     pattern.to_regex_string(WindowsEncoding::info()),
+    ///     r"(?i)^(?:[^\\/]*[\\/])*[^\\/]*\.rs$",
+    /// );
+    /// ```
+    pub fn to_regex_string(&self, info: EncodingInfo) -> String {
+        let mut separators = String::new();
+        push_class_escaped(&mut separators, info.separator);
+        for c in info.alt_separators {
+            push_class_escaped(&mut separators, *c);
+        }
+
+        let mut regex = String::from(if info.case_sensitive { "^" } else { "(?i)^" });
+        if let [tokens] = self.alternatives.as_slice() {
+            push_regex(&mut regex, tokens, &separators, &info);
+        } else {
+            regex.push_str("(?:");
+            for (i, tokens) in self.alternatives.iter().enumerate() {
+                if i > 0 {
+                    regex.push('|');
+                }
+                push_regex(&mut regex, tokens, &separators, &info);
+            }
+            regex.push(')');
+        }
+
+        regex.push('$');
+        regex
+    }
+}
+
+impl fmt::Display for Pattern {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.original)
+    }
+}
+
+impl FromStr for Pattern {
+    type Err = PatternError;
+
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::new(s)
+    }
+}
+
+/// Options that adjust how a [`Pattern`] is matched against a path, as used by methods like
+/// [`Pattern::matches_path_with`].
+///
+/// The default options behave like [`Pattern::matches_path`]:
+///
+/// * case sensitivity follows the encoding of the path
+/// * wildcards and classes never match a separator
+/// * wildcards and classes can match a `.` at the start of a component
+/// * paths are matched as they are written, without being normalized
+///
+/// # Examples
+///
+/// ```
+/// use typed_path::{MatchOptions, Pattern, UnixPath};
+///
+/// let pattern = Pattern::new("src/*").unwrap();
+/// let options = MatchOptions::new()
+///     .require_literal_separator(false)
+///     .require_literal_leading_dot(true);
+///
+/// assert!(pattern.matches_path_with(UnixPath::new("src/common/path.rs"), &options));
+/// assert!(!pattern.matches_path_with(UnixPath::new("src/.hidden"), &options));
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct MatchOptions {
+    case_sensitive: Option<bool>,
+    require_literal_separator: bool,
+    require_literal_leading_dot: bool,
+    normalize: bool,
+}
+
+impl MatchOptions {
+    /// Creates a new set of options that behave like [`Pattern::matches_path`].
+    pub const fn new() -> Self {
+        Self {
+            case_sensitive: None,
+            require_literal_separator: true,
+            require_literal_leading_dot: false,
+            normalize: false,
+        }
+    }
+
+    /// Sets whether characters are compared case-sensitively, or `None` to follow the encoding
+    /// of the path being matched, which is the default. Insensitive comparisons only ignore ASCII
+    /// case.
+    pub fn case_sensitive(mut self, case_sensitive: Option<bool>) -> Self {
+        self.case_sensitive = case_sensitive;
+        self
+    }
+
+    /// When `false`, `*`, `?`, and classes can also match separators, letting `*` span multiple
+    /// components. This is `true` by default.
+    pub fn require_literal_separator(mut self, yes: bool) -> Self {
+        self.require_literal_separator = yes;
+        self
+    }
+
+    /// When `true`, a `.` at the start of a component is only matched by a literal `.` in the
+    /// pattern, so wildcards, classes, and `**` skip hidden files and directories. This is
+    /// `false` by default.
+    pub fn require_literal_leading_dot(mut self, yes: bool) -> Self {
+        self.require_literal_leading_dot = yes;
+        self
+    }
+
+    /// When `true`, paths are normalized as if by [`Path::normalize`] before being matched. This
+    /// is `false` by default.
+    ///
+    /// [`Path::normalize`]: crate::Path::normalize
+    pub fn normalize(mut self, yes: bool) -> Self {
+        self.normalize = yes;
+        self
+    }
+}
+
+impl Default for MatchOptions {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Parses a pattern that has already gone through brace expansion into its tokens
+fn tokenize(pattern: &str) -> Result<Vec<Token>, PatternError> {
+    let chars: Vec<(usize, char)> = pattern.char_indices().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+
+    while i < chars.len() {
+        match chars[i].1 {
+            '?' => {
+                tokens.push(Token::AnyChar);
+                i += 1;
+            }
+            '*' => {
+                let mut end = i;
+                while end < chars.len() && chars[end].1 == '*' {
+                    end += 1;
+                }
+
+                // `**` only has special meaning as an entire component, otherwise acting
+                // like `*`
+                let at_start = i == 0 || is_pattern_separator(chars[i - 1].1);
+                if end - i == 2 && at_start {
+                    match chars.get(end) {
+                        None => {
+                            tokens.push(Token::AnyRemaining);
+                            i = end;
+                            continue;
+                        }
+                        Some((_, c)) if is_pattern_separator(*c) => {
+                            tokens.push(Token::AnyComponents);
+                            i = end + 1;
+                            continue;
+                        }
+                        _ => {}
+                    }
+                }
+
+                tokens.push(Token::AnyChars);
+                i = end;
+            }
+            '[' => {
+                let (token, next) = parse_class(&chars, i)?;
+                tokens.push(token);
+                i = next;
+            }
+            c => {
+                tokens.push(Token::Char(c));
+                i += 1;
+            }
+        }
+    }
+
+    Ok(tokens)
+}
+
+fn expand_braces_into(pattern: &str, expanded: &mut Vec<String>) {
+    let (open, close, parts) = match find_braces(pattern) {
+        Some(found) => found,
+        None => {
+            expanded.push(pattern.to_string());
+            return;
+        }
+    };
+
+    let (before, after) = (&pattern[..open], &pattern[close + 1..]);
+    let mut afters = Vec::new();
+    expand_braces_into(after, &mut afters);
+
+    for part in parts {
+        let mut middles = Vec::new();
+        expand_braces_into(&part, &mut middles);
+        for middle in &middles {
+            for after in &afters {
+                expanded.push(alloc::format!("{}{}{}", before, middle, after));
+            }
+        }
+    }
+}
+
+/// Finds the first braces within `pattern` that can be expanded, returning the byte offsets of
+/// the opening and closing brace alongside what should replace them
+fn find_braces(pattern: &str) -> Option<(usize, usize, Vec<String>)> {
+    let chars: Vec<(usize, char)> = pattern.char_indices().collect();
+    let mut i = 0;
+
+    while i < chars.len() {
+        match chars[i].1 {
+            '[' => {
+                i = parse_class(&chars, i)
+                    .map(|(_, next)| next)
+                    .unwrap_or(i + 1);
+            }
+            '{' => {
+                if let Some(found) = parse_braces(pattern, &chars, i) {
+                    return Some(found);
+                }
+                i += 1;
+            }
+            _ => i += 1,
+        }
+    }
+
+    None
+}
+
+/// Parses the braces opened at `chars[open]`, if they are balanced and hold either alternatives
+/// or a sequence
+fn parse_braces(
+    pattern: &str,
+    chars: &[(usize, char)],
+    open: usize,
+) -> Option<(usize, usize, Vec<String>)> {
+    let mut depth = 0;
+    let mut commas = Vec::new();
+    let mut i = open + 1;
+
+    while i < chars.len() {
+        match chars[i].1 {
+            '[' => {
+                i = parse_class(chars, i).map(|(_, next)| next).unwrap_or(i + 1);
+                continue;
+            }
+            '{' => depth += 1,
+            '}' if depth > 0 => depth -= 1,
+            '}' => {
+                let (start, end) = (chars[open].0 + 1, chars[i].0);
+                let parts = if commas.is_empty() {
+                    parse_sequence(&pattern[start..end])?
+                } else {
+                    let mut bounds = Vec::with_capacity(commas.len() + 2);
+                    bounds.push(start);
+                    bounds.extend(commas.iter().map(|comma| comma + 1));
+                    let ends = commas.iter().copied().chain(core::iter::once(end));
+                    bounds
+                        .into_iter()
+                        .zip(ends)
+                        .map(|(start, end)| pattern[start..end].to_string())
+                        .collect()
+                };
+
+                return Some((chars[open].0, end, parts));
+            }
+            ',' if depth == 0 => commas.push(chars[i].0),
+            _ => {}
+        }
+        i += 1;
+    }
+
+    None
+}
+
+/// Parses `x..y` or `x..y..step` into the sequence of integers or characters it describes
+fn parse_sequence(content: &str) -> Option<Vec<String>> {
+    let mut pieces = content.split("..");
+    let (first, last) = (pieces.next()?, pieces.next()?);
+    let step = match pieces.next() {
+        Some(step) => step.parse::<i64>().ok()?.checked_abs()?.max(1),
+        None => 1,
+    };
+    if pieces.next().is_some() {
+        return None;
+    }
+
+    if let (Ok(start), Ok(end)) = (first.parse::<i64>(), last.parse::<i64>()) {
+        let is_padded = |s: &str| {
+            let digits = s.strip_prefix('-').unwrap_or(s);
+            digits.len() > 1 && digits.starts_with('0')
+        };
+        let width = if is_padded(first) || is_padded(last) {
+            first.len().max(last.len())
+        } else {
+            0
+        };
+
+        return Some(
+            sequence(start, end, step)
+                .into_iter()
+                .map(|n| alloc::format!("{:0width$}", n, width = width))
+                .collect(),
+        );
+    }
+
+    let (mut first, mut last) = (first.chars(), last.chars());
+    match (first.next(), first.next(), last.next(), last.next()) {
+        // An integer cannot be paired with a character
+        (Some(start), None, Some(end), None)
+            if !start.is_ascii_digit() && !end.is_ascii_digit() =>
+        {
+            Some(
+                sequence(start as i64, end as i64, step)
+                    .into_iter()
+                    .filter_map(|n| char::from_u32(n as u32))
+                    .map(String::from)
+                    .collect(),
+            )
+        }
+        _ => None,
+    }
+}
+
+/// Counts from `start` to `end` by `step`, stopping before passing `end`
+fn sequence(start: i64, end: i64, step: i64) -> Vec<i64> {
+    let mut values = vec![start];
+    let mut n = start;
+    loop {
+        let next = if start <= end {
+            n.checked_add(step).filter(|next| *next <= end)
+        } else {
+            n.checked_sub(step).filter(|next| *next >= end)
+        };
+
+        match next {
+            Some(next) => {
+                values.push(next);
+                n = next;
+            }
+            None => return values,
+        }
+    }
+}
+
+/// Characters that could act as a separator within a pattern for some encoding
+fn is_pattern_separator(c: char) -> bool {
+    c == '/' || c == '\\'
+}
+
+/// Parses the class starting with the `[` at `chars[start]`, returning it alongside the index
+/// following its closing `]`
+fn parse_class(chars: &[(usize, char)], start: usize) -> Result<(Token, usize), PatternError> {
+    let mut i = start + 1;
+    let negated = matches!(chars.get(i), Some((_, '!')));
+    if negated {
+        i += 1;
+    }
+
+    // A `]` immediately following the opening of the class is part of the class
+    let first = i;
+    let mut ranges = Vec::new();
+    while i < chars.len() {
+        let (pos, lo) = chars[i];
+        if lo == ']' && i > first {
+            return Ok((Token::Class { negated, ranges }, i + 1));
+        }
+
+        match (chars.get(i + 1), chars.get(i + 2)) {
+            (Some((_, '-')), Some((_, hi))) if *hi != ']' => {
+                if *hi < lo {
+                    return Err(PatternError::InvalidRange(pos));
+                }
+
+                ranges.push((lo, *hi));
+                i += 3;
+            }
+            _ => {
+                ranges.push((lo, lo));
+                i += 1;
+            }
+        }
+    }
+
+    Err(PatternError::UnclosedClass(chars[start].0))
+}
+
+/// Path being matched alongside the settings that apply to it
+struct Matcher<'a> {
+    path: &'a [u8],
+    info: EncodingInfo,
+    case_sensitive: bool,
+    literal_separator: bool,
+    literal_leading_dot: bool,
+}
+
+impl Matcher<'_> {
+    /// Returns true if `tokens` match the entirety of the path from byte `i` onward
+    fn matches(&self, tokens: &[Token], i: usize) -> bool {
+        let (token, rest) = match tokens.split_first() {
+            Some(x) => x,
+            None => return i == self.path.len(),
+        };
+
+        match token {
+            Token::Char(c) if self.info.is_separator_char(*c) => {
+                self.is_separator(i) && self.matches(rest, i + 1)
+            }
+            Token::Char(c) => match next_char(&self.path[i..]) {
+                (Some(actual), len) if chars_eq(*c, actual, self.case_sensitive) => {
+                    self.matches(rest, i + len)
+                }
+                _ => false,
+            },
+            Token::AnyChar => match next_char(&self.path[i..]) {
+                (_, 0) => false,
+                (_, len) => !self.is_literal_only(i) && self.matches(rest, i + len),
+            },
+            Token::AnyChars => {
+                let mut j = i;
+                loop {
+                    if self.matches(rest, j) {
+                        return true;
+                    }
+
+                    if j == self.path.len() || self.is_literal_only(j) {
+                        return false;
+                    }
+
+                    j += next_char(&self.path[j..]).1;
+                }
+            }
+            Token::AnyComponents => {
+                self.matches(rest, i)
+                    || (i..self.path.len()).any(|j| {
+                        self.is_separator(j)
+                            && !self.has_hidden_component(i, j)
+                            && self.matches(rest, j + 1)
+                    })
+            }
+            Token::AnyRemaining => !self.has_hidden_component(i, self.path.len()),
+            Token::Class { negated, ranges } => match next_char(&self.path[i..]) {
+                (_, 0) => false,
+                _ if self.is_literal_only(i) => false,
+                (c, len) => {
+                    // Bytes that are not valid UTF-8 can only be matched by a negated class
+                    let found = c
+                        .map(|c| class_contains(ranges, c, self.case_sensitive))
+                        .unwrap_or(false);
+                    found != *negated && self.matches(rest, i + len)
+                }
+            },
+        }
+    }
+
+    fn is_separator(&self, i: usize) -> bool {
+        self.path
+            .get(i)
+            .map(|b| self.info.is_separator(*b))
+            .unwrap_or(false)
+    }
+
+    /// Returns true if byte `i` starts a component that begins with a `.` which must be matched
+    /// literally
+    fn is_hidden(&self, i: usize) -> bool {
+        self.literal_leading_dot
+            && self.path.get(i) == Some(&b'.')
+            && (i == 0 || self.is_separator(i - 1))
+    }
+
+    /// Returns true if the character at byte `i` can only be matched by a literal in the pattern
+    fn is_literal_only(&self, i: usize) -> bool {
+        (self.literal_separator && self.is_separator(i)) || self.is_hidden(i)
+    }
+
+    /// Returns true if any hidden component starts within bytes `start..end`
+    fn has_hidden_component(&self, start: usize, end: usize) -> bool {
+        (start..end).any(|i| self.is_hidden(i))
+    }
+}
+
+/// Decodes the character at the start of `bytes`, returning its length in bytes, which is `1`
+/// for a byte that does not start a valid UTF-8 sequence and `0` when `bytes` is empty
+fn next_char(bytes: &[u8]) -> (Option<char>, usize) {
+    for len in 1..=bytes.len().min(4) {
+        if let Ok(s) = core::str::from_utf8(&bytes[..len]) {
+            return (s.chars().next(), len);
+        }
+    }
+
+    (None, bytes.len().min(1))
+}
+
+fn chars_eq(a: char, b: char, case_sensitive: bool) -> bool {
+    a == b || (!case_sensitive && a.eq_ignore_ascii_case(&b))
+}
+
+fn class_contains(ranges: &[(char, char)], c: char, case_sensitive: bool) -> bool {
+    let contains = |c: char| ranges.iter().any(|(lo, hi)| *lo <= c && c <= *hi);
+    contains(c)
+        || (!case_sensitive
+            && (contains(c.to_ascii_lowercase()) || contains(c.to_ascii_uppercase())))
+}
+
+/// Pushes the regular expression equivalent to `tokens` onto `regex`, where `separators` holds
+/// the escaped separators of the encoding described by `info`
+fn push_regex(regex: &mut String, tokens: &[Token], separators: &str, info: &EncodingInfo) {
+    for token in tokens {
+        match token {
+            Token::Char(c) if info.is_separator_char(*c) => {
+                regex.push('[');
+                regex.push_str(separators);
+                regex.push(']');
+            }
+            Token::Char(c) => {
+                if is_regex_meta(*c) {
+                    regex.push('\\');
+                }
+                regex.push(*c);
+            }
+            Token::AnyChar => {
+                regex.push_str("[^");
+                regex.push_str(separators);
+                regex.push(']');
+            }
+            Token::AnyChars => {
+                regex.push_str("[^");
+                regex.push_str(separators);
+                regex.push_str("]*");
+            }
+            Token::AnyComponents => {
+                regex.push_str("(?:[^");
+                regex.push_str(separators);
+                regex.push_str("]*[");
+                regex.push_str(separators);
+                regex.push_str("])*");
+            }
+            Token::AnyRemaining => regex.push_str("(?s:.*)"),
+            Token::Class { negated, ranges } => {
+                regex.push_str(if *negated { "[^" } else { "[" });
+                for (lo, hi) in ranges {
+                    push_class_escaped(regex, *lo);
+                    if lo != hi {
+                        regex.push('-');
+                        push_class_escaped(regex, *hi);
+                    }
+                }
+
+                if *negated {
+                    regex.push_str(separators);
+                    regex.push(']');
+                } else {
+                    regex.push_str("&&[^");
+                    regex.push_str(separators);
+                    regex.push_str("]]");
+                }
+            }
+        }
+    }
+}
+
+fn is_regex_meta(c: char) -> bool {
+    matches!(
+        c,
+        '\\' | '.'
+            | '+'
+            | '*'
+            | '?'
+            | '('
+            | ')'
+            | '|'
+            | '['
+            | ']'
+            | '{'
+            | '}'
+            | '^'
+            | '$'
+            | '#'
+            | '&'
+            | '-'
+            | '~'
+    )
+}
+
+/// Pushes `c` onto `s` such that it is treated literally within a regex character class
+fn push_class_escaped(s: &mut String, c: char) {
+    if matches!(c, '\\' | '[' | ']' | '^' | '-' | '&' | '~') {
+        s.push('\\');
+    }
+    s.push(c);
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::format;
+
+    use super::*;
+    use crate::{UnixEncoding, UnixPath, WindowsEncoding, WindowsPath};
+
+    fn unix(pattern: &str, path: &str) -> bool {
+        Pattern::new(pattern)
+            .unwrap()
+            .matches_path(UnixPath::new(path))
+    }
+
+    fn windows(pattern: &str, path: &str) -> bool {
+        Pattern::new(pattern)
+            .unwrap()
+            .matches_path(WindowsPath::new(path))
+    }
+
+    #[test]
+    fn new_should_fail_for_invalid_classes() {
+        assert_eq!(Pattern::new("["), Err(PatternError::UnclosedClass(0)));
+        assert_eq!(Pattern::new("a[]"), Err(PatternError::UnclosedClass(1)));
+        assert_eq!(Pattern::new("[!]"), Err(PatternError::UnclosedClass(0)));
+        assert_eq!(Pattern::new("[a-Z]"), Err(PatternError::InvalidRange(1)));
+    }
+
+    #[test]
+    fn wildcards_should_not_cross_separators() {
+        assert!(unix("*", ""));
+        assert!(unix("*", "file"));
+        assert!(!unix("*", "dir/file"));
+        assert!(unix("*/*", "dir/file"));
+        assert!(unix("a?c", "abc"));
+        assert!(!unix("a?c", "a/c"));
+        assert!(!unix("a?c", "ac"));
+        assert!(unix("*.tar.*", "file.tar.gz"));
+        assert!(unix("a*b*c", "aXbYbZc"));
+        assert!(!unix("a*b*c", "aXbYbZ"));
+
+        // `?` matches a whole character, not a single byte
+        assert!(unix("caf?", "caf\u{e9}"));
+        assert!(!unix("caf??", "caf\u{e9}"));
+    }
+
+    #[test]
+    fn double_star_should_match_any_number_of_components() {
+        assert!(unix("**", ""));
+        assert!(unix("**", "a/b/c"));
+        assert!(unix("**/c", "c"));
+        assert!(unix("**/c", "a/b/c"));
+        assert!(!unix("**/c", "a/bc"));
+        assert!(unix("a/**/c", "a/c"));
+        assert!(unix("a/**/c", "a/b/b/c"));
+        assert!(unix("a/**", "a/"));
+        assert!(unix("a/**", "a/b/c"));
+        assert!(!unix("a/**", "a"));
+
+        // Not an entire component, so acts like `*`
+        assert!(unix("a**", "abc"));
+        assert!(!unix("a**", "ab/c"));
+    }
+
+    #[test]
+    fn classes_should_match_listed_characters_other_than_separators() {
+        assert!(unix("[abc]", "b"));
+        assert!(!unix("[abc]", "d"));
+        assert!(unix("[a-c]x", "bx"));
+        assert!(unix("[!a-c]x", "dx"));
+        assert!(!unix("[!a-c]x", "bx"));
+        assert!(unix("[]]", "]"));
+        assert!(unix("[!]]", "a"));
+        assert!(unix("[*?]", "*"));
+        assert!(unix("[a-]", "-"));
+        assert!(!unix("a[/]b", "a/b"));
+        assert!(!unix("a[!x]b", "a/b"));
+
+        // Bytes that are not UTF-8 only match negated classes
+        let path = UnixPath::new(b"\xff");
+        assert!(Pattern::new("[!a]").unwrap().matches_path(path));
+        assert!(!Pattern::new("[a]").unwrap().matches_path(path));
+        assert!(Pattern::new("?").unwrap().matches_path(path));
+    }
+
+    #[test]
+    fn matching_should_use_separators_and_case_sensitivity_of_encoding() {
+        assert!(!unix("a/b", r"a\b"));
+        assert!(unix(r"a\b", r"a\b"));
+        assert!(!unix("A/B", "a/b"));
+
+        assert!(windows("a/b", r"a\b"));
+        assert!(windows(r"a\b", "a/b"));
+        assert!(windows("A/[B]*", "a/bc"));
+        assert!(windows(r"C:\**\*.TXT", r"c:\Users\me\notes.txt"));
+    }
+
+    #[test]
+    fn match_options_should_adjust_matching() {
+        let matches = |pattern: &str, path: &str, options: MatchOptions| {
+            Pattern::new(pattern)
+                .unwrap()
+                .matches_path_with(UnixPath::new(path), &options)
+        };
+
+        let options = MatchOptions::new().case_sensitive(Some(false));
+        assert!(matches("A/[B]*", "a/bc", options));
+        assert!(!Pattern::new("A")
+            .unwrap()
+            .matches_path_with(WindowsPath::new("a"), &options.case_sensitive(Some(true))));
+
+        let options = MatchOptions::new().require_literal_separator(false);
+        assert!(matches("a*c", "ab/bc", options));
+        assert!(matches("a?c", "a/c", options));
+        assert!(matches("a[!x]c", "a/c", options));
+        assert!(!matches("a*d", "ab/bc", options));
+
+        let options = MatchOptions::new().require_literal_leading_dot(true);
+        assert!(!matches("*", ".git", options));
+        assert!(!matches("?git", ".git", options));
+        assert!(!matches("[.]git", ".git", options));
+        assert!(matches(".*", ".git", options));
+        assert!(matches("a*", "a.b", options));
+        assert!(matches("a/.*/c", "a/.b/c", options));
+        assert!(!matches("**/c", "a/.b/c", options));
+        assert!(!matches("a/**", "a/b/.c", options));
+        assert!(matches("**/.b/c", "a/.b/c", options));
+        assert!(matches("**", "a/b/c", options));
+
+        let options = MatchOptions::new().normalize(true);
+        assert!(matches("a/c", "a/./b/../c/", options));
+        assert!(!matches("a/c", "a/./b/../c/", MatchOptions::new()));
+    }
+
+    #[test]
+    fn expand_braces_should_follow_bash() {
+        assert_eq!(Pattern::expand_braces(""), [""]);
+        assert_eq!(Pattern::expand_braces("a{,b}"), ["a", "ab"]);
+        assert_eq!(Pattern::expand_braces("{a,b{c,d}}e"), ["ae", "bce", "bde"]);
+        assert_eq!(Pattern::expand_braces("{a{b,c}"), ["{ab", "{ac"]);
+        assert_eq!(Pattern::expand_braces("{a}{b,c}"), ["{a}b", "{a}c"]);
+        assert_eq!(Pattern::expand_braces("{}"), ["{}"]);
+        assert_eq!(Pattern::expand_braces("{a,b"), ["{a,b"]);
+        assert_eq!(Pattern::expand_braces("{[,]}"), ["{[,]}"]);
+        assert_eq!(Pattern::expand_braces("{[,],x}"), ["[,]", "x"]);
+
+        assert_eq!(Pattern::expand_braces("{3..1}"), ["3", "2", "1"]);
+        assert_eq!(Pattern::expand_braces("{1..10..4}"), ["1", "5", "9"]);
+        assert_eq!(Pattern::expand_braces("{-1..1}"), ["-1", "0", "1"]);
+        assert_eq!(Pattern::expand_braces("{-01..1}"), ["-01", "000", "001"]);
+        assert_eq!(Pattern::expand_braces("{x..z}"), ["x", "y", "z"]);
+        assert_eq!(Pattern::expand_braces("{a..1}"), ["{a..1}"]);
+        assert_eq!(Pattern::expand_braces("{1..2..3..4}"), ["{1..2..3..4}"]);
+    }
+
+    #[test]
+    fn braces_should_match_any_alternative() {
+        assert!(unix("*.{rs,toml}", "Cargo.toml"));
+        assert!(unix("*.{rs,toml}", "lib.rs"));
+        assert!(!unix("*.{rs,toml}", "lib.c"));
+        assert!(unix("part{1..3}.bin", "part2.bin"));
+        assert!(!unix("part{1..3}.bin", "part4.bin"));
+        assert!(unix("{src,tests}/**/*.rs", "tests/it/main.rs"));
+        assert!(unix("[{]a}", "{a}"));
+    }
+
+    #[test]
+    fn escape_should_produce_pattern_matching_only_the_path() {
+        let names = [
+            "plain",
+            "a?b",
+            "**",
+            "[!x]",
+            "{a,b}",
+            "{1..2}",
+            "]",
+            "x,y",
+            "caf\u{e9}",
+        ];
+        for name in names {
+            let escaped = Pattern::escape(name);
+            assert!(unix(&escaped, name), "{:?}", name);
+            assert_eq!(Pattern::expand_braces(&escaped).len(), 1);
+        }
+
+        assert!(!unix(&Pattern::escape("a?b"), "axb"));
+        assert!(!unix(&Pattern::escape("**"), "a/b"));
+
+        // Escaped paths can be used as an alternative
+        let pattern = format!("{{{},other}}/*", Pattern::escape("x,y"));
+        assert!(unix(&pattern, "x,y/file"));
+        assert!(!unix(&pattern, "x/file"));
+    }
+
+    #[test]
+    fn to_regex_string_should_escape_literals_and_limit_classes() {
+        let info = UnixEncoding::info();
+        assert_eq!(
+            Pattern::new("a.b+c").unwrap().to_regex_string(info),
+            r"^a\.b\+c$"
+        );
+        assert_eq!(Pattern::new("?").unwrap().to_regex_string(info), "^[^/]$");
+        assert_eq!(
+            Pattern::new("a/**").unwrap().to_regex_string(info),
+            "^a[/](?s:.*)$"
+        );
+        assert_eq!(
+            Pattern::new("[a-c^]").unwrap().to_regex_string(info),
+            r"^[a-c\^&&[^/]]$"
+        );
+        assert_eq!(
+            Pattern::new("[!a]").unwrap().to_regex_string(info),
+            "^[^a/]$"
+        );
+        assert_eq!(
+            Pattern::new(r"a\b").unwrap().to_regex_string(info),
+            r"^a\\b$"
+        );
+
+        assert_eq!(
+            Pattern::new("*.{c,h}").unwrap().to_regex_string(info),
+            r"^(?:[^/]*\.c|[^/]*\.h)$"
+        );
+
+        let info = WindowsEncoding::info();
+        assert_eq!(
+            Pattern::new(r"a\b/c").unwrap().to_regex_string(info),
+            r"(?i)^a[\\/]b[\\/]c$"
+        );
+        assert_eq!(
+            Pattern::new("[!a]").unwrap().to_regex_string(info),
+            r"(?i)^[^a\\/]$"
+        );
+    }
+}
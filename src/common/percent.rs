@@ -0,0 +1,104 @@
+use crate::no_std_compat::*;
+
+/// Encodes `byte` as `%XX` using uppercase hexadecimal digits
+pub(crate) fn percent_encode(byte: u8) -> [u8; 3] {
+    const HEX: &[u8; 16] = b"0123456789ABCDEF";
+    [b'%', HEX[(byte >> 4) as usize], HEX[(byte & 0xf) as usize]]
+}
+
+/// Appends `segment` to `out`, percent-encoding every byte that is not a `pchar` as defined by
+/// [RFC 3986](https://www.rfc-editor.org/rfc/rfc3986#section-3.3)
+pub(crate) fn encode_segment(segment: &[u8], out: &mut String) {
+    encode_with(segment, out, is_pchar);
+}
+
+/// Appends `host` to `out`, percent-encoding every byte that is not allowed within a `reg-name`
+/// as defined by [RFC 3986](https://www.rfc-editor.org/rfc/rfc3986#section-3.2.2)
+pub(crate) fn encode_host(host: &[u8], out: &mut String) {
+    encode_with(host, out, |b| b != b':' && b != b'@' && is_pchar(b));
+}
+
+fn encode_with(bytes: &[u8], out: &mut String, keep: impl Fn(u8) -> bool) {
+    for &b in bytes {
+        if keep(b) {
+            out.push(b as char);
+            continue;
+        }
+
+        for &x in percent_encode(b).iter() {
+            out.push(x as char);
+        }
+    }
+}
+
+/// Decodes every `%XX` sequence within `segment`, returning `None` if any `%` does not start one
+pub(crate) fn decode_segment_strict(segment: &str) -> Option<Vec<u8>> {
+    let bytes = segment.as_bytes();
+    let is_escape = |i: usize| {
+        let hex = |i: usize| bytes.get(i).copied().and_then(hex_value).is_some();
+        hex(i + 1) && hex(i + 2)
+    };
+
+    if (0..bytes.len()).all(|i| bytes[i] != b'%' || is_escape(i)) {
+        Some(decode_segment(segment))
+    } else {
+        None
+    }
+}
+
+/// Decodes every `%XX` sequence within `segment`, leaving malformed sequences as they are
+pub(crate) fn decode_segment(segment: &str) -> Vec<u8> {
+    let bytes = segment.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+
+    while i < bytes.len() {
+        if bytes[i] == b'%' && i + 2 < bytes.len() {
+            if let (Some(hi), Some(lo)) = (hex_value(bytes[i + 1]), hex_value(bytes[i + 2])) {
+                out.push((hi << 4) | lo);
+                i += 3;
+                continue;
+            }
+        }
+
+        out.push(bytes[i]);
+        i += 1;
+    }
+
+    out
+}
+
+/// Returns whether `b` may appear unencoded within a path segment of a URI
+fn is_pchar(b: u8) -> bool {
+    // unreserved / sub-delims / ":" / "@"
+    b.is_ascii_alphanumeric()
+        || matches!(
+            b,
+            b'-' | b'.'
+                | b'_'
+                | b'~'
+                | b'!'
+                | b'$'
+                | b'&'
+                | b'\''
+                | b'('
+                | b')'
+                | b'*'
+                | b'+'
+                | b','
+                | b';'
+                | b'='
+                | b':'
+                | b'@'
+        )
+}
+
+/// Returns the value of the hexadecimal digit `byte`
+fn hex_value(byte: u8) -> Option<u8> {
+    match byte {
+        b'0'..=b'9' => Some(byte - b'0'),
+        b'a'..=b'f' => Some(byte - b'a' + 10),
+        b'A'..=b'F' => Some(byte - b'A' + 10),
+        _ => None,
+    }
+}
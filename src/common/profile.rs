@@ -0,0 +1,580 @@
+use crate::common::ProfileError;
+use crate::no_std_compat::*;
+use crate::plan9::constants::MAX_ELEMENT_LEN;
+use crate::windows::constants::RESERVED_DEVICE_NAMES_STR;
+
+/// Set of bytes allowed within a file or directory name by a [`FilesystemProfile`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum CharacterSet {
+    /// Any byte that is not otherwise disallowed by the encoding of the path
+    Any,
+
+    /// The POSIX portable filename character set, made up of `A-Z`, `a-z`, `0-9`, `.`, `_`, and
+    /// `-`
+    PosixPortable,
+
+    /// Printable ascii characters, made up of space (`0x20`) through `~` (`0x7E`), which is all
+    /// that protocols like FTP are guaranteed to carry in a path
+    PrintableAscii,
+}
+
+impl CharacterSet {
+    /// Returns true if `byte` is a member of the character set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::CharacterSet;
+    ///
+    /// assert!(CharacterSet::Any.contains(b' '));
+    /// assert!(CharacterSet::PosixPortable.contains(b'_'));
+    /// assert!(!CharacterSet::PosixPortable.contains(b' '));
+    /// assert!(CharacterSet::PrintableAscii.contains(b' '));
+    /// assert!(!CharacterSet::PrintableAscii.contains(b'\t'));
+    /// ```
+    pub fn contains(self, byte: u8) -> bool {
+        match self {
+            Self::Any => true,
+            Self::PosixPortable => {
+                byte.is_ascii_alphanumeric() || byte == b'.' || byte == b'_' || byte == b'-'
+            }
+            Self::PrintableAscii => byte.is_ascii_graphic() || byte == b' ',
+        }
+    }
+}
+
+/// How a [`FilesystemProfile`] treats ascii control characters, such as `\n` and `\t`, within a
+/// file or directory name.
+///
+/// Unix allows every byte but `/` and `\0` within a name, yet names holding a newline or other
+/// control character break most of what consumes them, from shell scripts and line-based logs to
+/// terminals that interpret escape sequences.
+///
+/// # Examples
+///
+/// ```
+/// use typed_path::{
+///     ControlCharPolicy, FilesystemProfile, IllegalCharReason, ProfileError, UnixPath,
+/// };
+///
+/// let path = UnixPath::new("uploads/report\n.pdf");
+///
+/// let profile = FilesystemProfile::new().control_chars(ControlCharPolicy::Warn);
+/// assert_eq!(path.validate_for(&profile), Ok(()));
+/// assert_eq!(
+///     path.find_illegal_chars(&profile).collect::<Vec<_>>(),
+///     [(14, b'\n', IllegalCharReason::Control)],
+/// );
+///
+/// let profile = FilesystemProfile::new().control_chars(ControlCharPolicy::Reject);
+/// assert_eq!(path.validate_for(&profile), Err(ProfileError::ControlByte(b'\n')));
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ControlCharPolicy {
+    /// Control characters are allowed unless the character set or the encoding of the path
+    /// disallows them
+    Allow,
+
+    /// Control characters pass validation, but are still reported by methods like
+    /// [`Path::find_illegal_chars`]
+    ///
+    /// [`Path::find_illegal_chars`]: crate::Path::find_illegal_chars
+    Warn,
+
+    /// Control characters fail validation with [`ProfileError::ControlByte`]
+    ///
+    /// [`ProfileError::ControlByte`]: crate::ProfileError::ControlByte
+    Reject,
+}
+
+impl Default for ControlCharPolicy {
+    /// Defaults to [`ControlCharPolicy::Allow`], leaving control characters to the character set
+    /// and encoding of the path.
+    #[inline]
+    fn default() -> Self {
+        Self::Allow
+    }
+}
+
+/// Reason that a byte was reported by methods like [`Path::find_illegal_chars`].
+///
+/// [`Path::find_illegal_chars`]: crate::Path::find_illegal_chars
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum IllegalCharReason {
+    /// An ascii control character, such as `\0` or `\n`, that is not allowed by the encoding of
+    /// the path, the character set of the profile, or its [`ControlCharPolicy`]
+    Control,
+
+    /// A character that the encoding of the path never allows within a name, such as `*` on
+    /// Windows
+    Reserved,
+
+    /// A character outside of the character set of the profile
+    Disallowed,
+
+    /// Part of a sequence that is not valid UTF-8 when the profile requires UTF-8
+    NonUtf8,
+}
+
+/// Restrictions placed on paths by a target filesystem, used by methods like
+/// [`Path::validate_for`] to check that a path can be reproduced there as-is.
+///
+/// These restrictions are in addition to those of the path's encoding, which are checked by
+/// [`Path::is_valid`]. Only normal components are checked against the character set and the
+/// component length limit, while the path length limit applies to all of the bytes of the path.
+///
+/// The default profile places no restrictions on paths.
+///
+/// [`Path::is_valid`]: crate::Path::is_valid
+/// [`Path::validate_for`]: crate::Path::validate_for
+///
+/// # Examples
+///
+/// ```
+/// use typed_path::{CharacterSet, FilesystemProfile, ProfileError, UnixPath};
+///
+/// let profile = FilesystemProfile::new()
+///     .charset(CharacterSet::PosixPortable)
+///     .max_component_len(Some(8));
+///
+/// assert!(UnixPath::new("/usr/lib/libc.so").is_valid_for(&profile));
+/// assert_eq!(
+///     UnixPath::new("/usr/lib/libc.so.6.bak").validate_for(&profile),
+///     Err(ProfileError::ComponentTooLong { len: 13, max: 8 }),
+/// );
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct FilesystemProfile {
+    charset: CharacterSet,
+    max_component_len: Option<usize>,
+    max_path_len: Option<usize>,
+    allow_leading_hyphen: bool,
+    reserved_names: &'static [&'static str],
+    require_utf8: bool,
+    control_chars: ControlCharPolicy,
+}
+
+impl FilesystemProfile {
+    /// Creates a new profile that places no restrictions on paths.
+    pub const fn new() -> Self {
+        Self {
+            charset: CharacterSet::Any,
+            max_component_len: None,
+            max_path_len: None,
+            allow_leading_hyphen: true,
+            reserved_names: &[],
+            require_utf8: false,
+            control_chars: ControlCharPolicy::Allow,
+        }
+    }
+
+    /// Creates a profile for strictly portable POSIX paths, which are readable on any conforming
+    /// system no matter how constrained:
+    ///
+    /// * names only use the [`CharacterSet::PosixPortable`] character set
+    /// * names do not start with `-`
+    /// * names are at most 14 bytes long (`_POSIX_NAME_MAX`)
+    /// * paths are at most 255 bytes long (`_POSIX_PATH_MAX`, less the terminating null)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{FilesystemProfile, ProfileError, UnixPath};
+    ///
+    /// let profile = FilesystemProfile::posix_portable();
+    ///
+    /// assert!(UnixPath::new("docs/README.txt").is_valid_for(&profile));
+    /// assert_eq!(
+    ///     UnixPath::new("docs/read me.txt").validate_for(&profile),
+    ///     Err(ProfileError::DisallowedByte(b' ')),
+    /// );
+    /// assert_eq!(
+    ///     UnixPath::new("docs/-rf").validate_for(&profile),
+    ///     Err(ProfileError::LeadingHyphen),
+    /// );
+    /// assert_eq!(
+    ///     UnixPath::new("docs/introduction.txt").validate_for(&profile),
+    ///     Err(ProfileError::ComponentTooLong { len: 16, max: 14 }),
+    /// );
+    /// ```
+    pub const fn posix_portable() -> Self {
+        Self {
+            charset: CharacterSet::PosixPortable,
+            max_component_len: Some(14),
+            max_path_len: Some(255),
+            allow_leading_hyphen: false,
+            reserved_names: &[],
+            require_utf8: false,
+            control_chars: ControlCharPolicy::Allow,
+        }
+    }
+
+    /// Creates a profile for paths sent to a remote POSIX host over protocols like SFTP or FTP,
+    /// where only [`CharacterSet::PrintableAscii`] names are guaranteed to survive the trip
+    /// unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{FilesystemProfile, ProfileError, UnixPath};
+    ///
+    /// let profile = FilesystemProfile::remote_posix();
+    ///
+    /// assert!(UnixPath::new("uploads/Quarterly Report.pdf").is_valid_for(&profile));
+    /// assert_eq!(
+    ///     UnixPath::new("uploads/bad\nname").validate_for(&profile),
+    ///     Err(ProfileError::DisallowedByte(b'\n')),
+    /// );
+    /// ```
+    pub const fn remote_posix() -> Self {
+        Self {
+            charset: CharacterSet::PrintableAscii,
+            max_component_len: None,
+            max_path_len: None,
+            allow_leading_hyphen: true,
+            reserved_names: &[],
+            require_utf8: false,
+            control_chars: ControlCharPolicy::Allow,
+        }
+    }
+
+    /// Creates a profile for paths that can be created on Windows:
+    ///
+    /// * names are not a reserved device name such as `CON` or `LPT1`
+    /// * names do not contain ascii control characters, which Windows never allows
+    /// * names are valid UTF-8, so they can be converted to UTF-16
+    /// * names are at most 255 bytes long
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{FilesystemProfile, ProfileError, UnixPath};
+    ///
+    /// let profile = FilesystemProfile::windows();
+    ///
+    /// assert!(UnixPath::new("docs/console.txt").is_valid_for(&profile));
+    /// assert_eq!(
+    ///     UnixPath::new("docs/con.txt").validate_for(&profile),
+    ///     Err(ProfileError::ReservedName),
+    /// );
+    /// ```
+    pub const fn windows() -> Self {
+        Self {
+            charset: CharacterSet::Any,
+            max_component_len: Some(255),
+            max_path_len: None,
+            allow_leading_hyphen: true,
+            reserved_names: RESERVED_DEVICE_NAMES_STR,
+            require_utf8: true,
+            control_chars: ControlCharPolicy::Reject,
+        }
+    }
+
+    /// Creates a profile for names that can be walked by 9P clients and servers:
+    ///
+    /// * names are valid UTF-8, as all strings in 9P messages are
+    /// * names are at most [`MAX_ELEMENT_LEN`] bytes long, the limit of a 9P string
+    ///
+    /// Older servers speaking the original 9P protocol limit names to 27 bytes, which can be
+    /// checked by tightening the profile with `.max_component_len(Some(27))`.
+    ///
+    /// [`MAX_ELEMENT_LEN`]: crate::constants::plan9::MAX_ELEMENT_LEN
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{FilesystemProfile, Plan9Path, ProfileError};
+    ///
+    /// let profile = FilesystemProfile::plan9();
+    ///
+    /// assert!(Plan9Path::new("#I0/tcp/clone").is_valid_for(&profile));
+    /// assert_eq!(
+    ///     Plan9Path::new(b"/tmp/\xff").validate_for(&profile),
+    ///     Err(ProfileError::InvalidUtf8),
+    /// );
+    ///
+    /// let profile = profile.max_component_len(Some(27));
+    /// assert_eq!(
+    ///     Plan9Path::new("/lib/a-rather-long-file-names.txt").validate_for(&profile),
+    ///     Err(ProfileError::ComponentTooLong { len: 28, max: 27 }),
+    /// );
+    /// ```
+    pub const fn plan9() -> Self {
+        Self {
+            charset: CharacterSet::Any,
+            max_component_len: Some(MAX_ELEMENT_LEN),
+            max_path_len: None,
+            allow_leading_hyphen: true,
+            reserved_names: &[],
+            require_utf8: true,
+            control_chars: ControlCharPolicy::Allow,
+        }
+    }
+
+    /// Sets the bytes allowed within names.
+    pub fn charset(mut self, charset: CharacterSet) -> Self {
+        self.charset = charset;
+        self
+    }
+
+    /// Sets the maximum length of a name in bytes, or `None` for no limit.
+    pub fn max_component_len(mut self, max: Option<usize>) -> Self {
+        self.max_component_len = max;
+        self
+    }
+
+    /// Sets the maximum length of a path in bytes, or `None` for no limit.
+    pub fn max_path_len(mut self, max: Option<usize>) -> Self {
+        self.max_path_len = max;
+        self
+    }
+
+    /// When `false`, names may not start with `-`, as they could be mistaken for command-line
+    /// options.
+    pub fn allow_leading_hyphen(mut self, yes: bool) -> Self {
+        self.allow_leading_hyphen = yes;
+        self
+    }
+
+    /// Sets the names that may not be used, compared in the same manner as Windows device names.
+    /// A name is reserved if the part before its first `.`, ignoring trailing spaces, matches
+    /// one of `names` when ignoring ascii case, so `nul`, `NUL.txt`, and `Nul .tar.gz` are all
+    /// reserved by `NUL`.
+    pub fn reserved_names(mut self, names: &'static [&'static str]) -> Self {
+        self.reserved_names = names;
+        self
+    }
+
+    /// When `true`, names must be valid UTF-8.
+    pub fn require_utf8(mut self, yes: bool) -> Self {
+        self.require_utf8 = yes;
+        self
+    }
+
+    /// Sets how ascii control characters within names are treated.
+    pub fn control_chars(mut self, policy: ControlCharPolicy) -> Self {
+        self.control_chars = policy;
+        self
+    }
+
+    /// Checks the bytes of a single name against the profile.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{FilesystemProfile, ProfileError};
+    ///
+    /// let profile = FilesystemProfile::posix_portable();
+    /// assert_eq!(profile.check_component(b"file.txt"), Ok(()));
+    /// assert_eq!(profile.check_component(b"file~"), Err(ProfileError::DisallowedByte(b'~')));
+    /// ```
+    pub fn check_component(&self, bytes: &[u8]) -> Result<(), ProfileError> {
+        if self.control_chars == ControlCharPolicy::Reject {
+            if let Some(b) = bytes.iter().find(|b| b.is_ascii_control()) {
+                return Err(ProfileError::ControlByte(*b));
+            }
+        }
+
+        if let Some(b) = bytes.iter().find(|b| !self.charset.contains(**b)) {
+            return Err(ProfileError::DisallowedByte(*b));
+        }
+
+        if self.require_utf8 && core::str::from_utf8(bytes).is_err() {
+            return Err(ProfileError::InvalidUtf8);
+        }
+
+        if !self.allow_leading_hyphen && bytes.starts_with(b"-") {
+            return Err(ProfileError::LeadingHyphen);
+        }
+
+        if self.is_reserved(bytes) {
+            return Err(ProfileError::ReservedName);
+        }
+
+        match self.max_component_len {
+            Some(max) if bytes.len() > max => Err(ProfileError::ComponentTooLong {
+                len: bytes.len(),
+                max,
+            }),
+            _ => Ok(()),
+        }
+    }
+
+    fn is_reserved(&self, bytes: &[u8]) -> bool {
+        let stem = match bytes.iter().position(|b| *b == b'.') {
+            Some(i) => &bytes[..i],
+            None => bytes,
+        };
+        let len = stem
+            .iter()
+            .rposition(|b| *b != b' ')
+            .map(|i| i + 1)
+            .unwrap_or(0);
+        let stem = &stem[..len];
+
+        self.reserved_names
+            .iter()
+            .any(|name| name.as_bytes().eq_ignore_ascii_case(stem))
+    }
+
+    /// Pushes every byte of the name `bytes` that is not allowed by `disallowed` or the profile onto
+    /// `illegal`, offsetting positions by `offset`
+    pub(crate) fn find_illegal_bytes(
+        &self,
+        bytes: &[u8],
+        disallowed: &[u8],
+        offset: usize,
+        illegal: &mut Vec<(usize, u8, IllegalCharReason)>,
+    ) {
+        let mut utf8_errors = Utf8Errors::new(bytes);
+        for (i, b) in bytes.iter().copied().enumerate() {
+            let is_disallowed = disallowed.contains(&b);
+            let in_charset = self.charset.contains(b);
+            let flags_control = self.control_chars != ControlCharPolicy::Allow;
+            let reason = if b.is_ascii_control() && (is_disallowed || !in_charset || flags_control)
+            {
+                Some(IllegalCharReason::Control)
+            } else if is_disallowed {
+                Some(IllegalCharReason::Reserved)
+            } else if !in_charset {
+                Some(IllegalCharReason::Disallowed)
+            } else if self.require_utf8 && utf8_errors.contains(i) {
+                Some(IllegalCharReason::NonUtf8)
+            } else {
+                None
+            };
+
+            if let Some(reason) = reason {
+                illegal.push((offset + i, b, reason));
+            }
+        }
+    }
+
+    /// Checks the length of an entire path against the profile.
+    pub(crate) fn check_path_len(&self, len: usize) -> Result<(), ProfileError> {
+        match self.max_path_len {
+            Some(max) if len > max => Err(ProfileError::PathTooLong { len, max }),
+            _ => Ok(()),
+        }
+    }
+}
+
+impl Default for FilesystemProfile {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Positions of the bytes within a slice that are not part of a valid UTF-8 sequence, visited in
+/// increasing order
+struct Utf8Errors<'a> {
+    bytes: &'a [u8],
+    start: usize,
+    invalid: core::ops::Range<usize>,
+}
+
+impl<'a> Utf8Errors<'a> {
+    fn new(bytes: &'a [u8]) -> Self {
+        Self {
+            bytes,
+            start: 0,
+            invalid: 0..0,
+        }
+    }
+
+    /// Returns true if the byte at `i` is not part of a valid UTF-8 sequence, where `i` never
+    /// decreases between calls
+    fn contains(&mut self, i: usize) -> bool {
+        while i >= self.invalid.end && self.start < self.bytes.len() {
+            match core::str::from_utf8(&self.bytes[self.start..]) {
+                Ok(_) => self.start = self.bytes.len(),
+                Err(e) => {
+                    let from = self.start + e.valid_up_to();
+                    let len = e.error_len().unwrap_or(self.bytes.len() - from);
+                    self.invalid = from..from + len;
+                    self.start = from + len;
+                }
+            }
+        }
+
+        self.invalid.contains(&i)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reserved_names_should_ignore_case_extensions_and_trailing_spaces() {
+        let profile = FilesystemProfile::windows();
+        for name in ["con", "CON", "Con.txt", "nul .tar.gz", "lpt1.", "AUX  "] {
+            assert_eq!(
+                profile.check_component(name.as_bytes()),
+                Err(ProfileError::ReservedName),
+                "{:?}",
+                name
+            );
+        }
+
+        for name in ["console", "acon", " con", "con_", "lpt10", ".con"] {
+            assert_eq!(
+                profile.check_component(name.as_bytes()),
+                Ok(()),
+                "{:?}",
+                name
+            );
+        }
+    }
+
+    #[test]
+    fn control_chars_should_follow_the_policy() {
+        let name = b"a\nb\0\x7f";
+        let check = |policy| {
+            let profile = FilesystemProfile::new().control_chars(policy);
+            let mut illegal = Vec::new();
+            profile.find_illegal_bytes(name, b"\0", 0, &mut illegal);
+            (profile.check_component(name), illegal.len())
+        };
+
+        assert_eq!(check(ControlCharPolicy::Allow), (Ok(()), 1));
+        assert_eq!(check(ControlCharPolicy::Warn), (Ok(()), 3));
+        assert_eq!(
+            check(ControlCharPolicy::Reject),
+            (Err(ProfileError::ControlByte(b'\n')), 3)
+        );
+
+        // Rejecting takes precedence over the character set
+        let profile = FilesystemProfile::posix_portable().control_chars(ControlCharPolicy::Reject);
+        assert_eq!(
+            profile.check_component(b"a b\t"),
+            Err(ProfileError::ControlByte(b'\t'))
+        );
+    }
+
+    #[test]
+    fn find_illegal_bytes_should_report_each_byte_of_invalid_utf8_sequences() {
+        let profile = FilesystemProfile::new().require_utf8(true);
+        let mut illegal = Vec::new();
+        let name = b"a\xffb\xe2\x82c\xe2\x82\xac\xf0\x9f";
+        profile.find_illegal_bytes(name, b"\0", 10, &mut illegal);
+
+        let positions: Vec<_> = illegal.iter().map(|(i, _, _)| *i).collect();
+        assert_eq!(positions, [11, 13, 14, 19, 20]);
+        assert!(illegal
+            .iter()
+            .all(|(_, _, reason)| *reason == IllegalCharReason::NonUtf8));
+
+        // Without requiring UTF-8, only the bytes disallowed by the encoding are reported
+        let mut illegal = Vec::new();
+        FilesystemProfile::new().find_illegal_bytes(b"\xff\0:", b"\0:", 0, &mut illegal);
+        assert_eq!(
+            illegal,
+            [
+                (1, b'\0', IllegalCharReason::Control),
+                (2, b':', IllegalCharReason::Reserved),
+            ]
+        );
+    }
+}
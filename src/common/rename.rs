@@ -0,0 +1,333 @@
+use alloc::collections::{BTreeMap, BTreeSet};
+
+use crate::common::{PathTemplate, RenameError, TemplateError, TemplateMatch};
+use crate::no_std_compat::*;
+use crate::{Utf8Encoding, Utf8Path, Utf8PathBuf};
+
+/// A single step of a rename plan, moving the first path to the second.
+pub type Rename<T> = (Utf8PathBuf<T>, Utf8PathBuf<T>);
+
+/// Plans a bulk rename of paths by capturing variables with one [`PathTemplate`] and rendering
+/// the new names with another.
+///
+/// Planning never touches the filesystem: it only works out which paths move where, in an order
+/// that can be applied one rename at a time, and rejects plans whose renames would be ambiguous,
+/// collide with each other, overwrite a path that stays put, or form a cycle.
+///
+/// # Examples
+///
+/// ```
+/// use typed_path::{PathTemplate, RenameError, RenamePlanner, Utf8UnixPath, Utf8UnixPathBuf};
+///
+/// let planner = RenamePlanner::new(
+///     PathTemplate::new("photos/IMG_{n}.jpg").unwrap(),
+///     PathTemplate::new("photos/{n}.jpg").unwrap(),
+/// )
+/// .unwrap();
+///
+/// let paths = [
+///     Utf8UnixPath::new("photos/IMG_1.jpg"),
+///     Utf8UnixPath::new("photos/IMG_2.jpg"),
+///     Utf8UnixPath::new("photos/notes.txt"),
+/// ];
+/// assert_eq!(
+///     planner.plan(paths),
+///     Ok(vec![
+///         (Utf8UnixPathBuf::from("photos/IMG_1.jpg"), Utf8UnixPathBuf::from("photos/1.jpg")),
+///         (Utf8UnixPathBuf::from("photos/IMG_2.jpg"), Utf8UnixPathBuf::from("photos/2.jpg")),
+///     ]),
+/// );
+///
+/// // Renaming onto a path that is not itself moved is rejected
+/// let paths = [Utf8UnixPath::new("photos/IMG_1.jpg"), Utf8UnixPath::new("photos/1.jpg")];
+/// assert_eq!(
+///     planner.plan(paths),
+///     Err(RenameError::Overwrite {
+///         from: String::from("photos/IMG_1.jpg"),
+///         to: String::from("photos/1.jpg"),
+///     }),
+/// );
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RenamePlanner {
+    from: PathTemplate,
+    to: PathTemplate,
+}
+
+impl RenamePlanner {
+    /// Creates a planner that renames paths matching `from` to `to`, failing with
+    /// [`TemplateError::MissingVariable`] if `to` uses a variable that `from` never captures.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{PathTemplate, RenamePlanner, TemplateError};
+    ///
+    /// let from = PathTemplate::new("{name}.txt").unwrap();
+    /// let to = PathTemplate::new("{name}.{ext}").unwrap();
+    /// assert_eq!(
+    ///     RenamePlanner::new(from, to).unwrap_err(),
+    ///     TemplateError::MissingVariable(String::from("ext")),
+    /// );
+    /// ```
+    pub fn new(from: PathTemplate, to: PathTemplate) -> Result<Self, TemplateError> {
+        if let Some(name) = to
+            .variables()
+            .find(|name| !from.variables().any(|n| n == *name))
+        {
+            return Err(TemplateError::MissingVariable(name.to_string()));
+        }
+
+        Ok(Self { from, to })
+    }
+
+    /// Returns the template that selects paths and captures their variables.
+    pub fn from_template(&self) -> &PathTemplate {
+        &self.from
+    }
+
+    /// Returns the template that renders the new name of each path.
+    pub fn to_template(&self) -> &PathTemplate {
+        &self.to
+    }
+
+    /// Plans the renames of `paths`, returning `(from, to)` pairs in an order where no rename
+    /// replaces a path before that path has been moved out of the way.
+    ///
+    /// Paths that do not fit the source template, or whose new name is the same as their current
+    /// one, are left out of the plan but still count as existing paths that must not be
+    /// overwritten.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{PathTemplate, RenameError, RenamePlanner, Utf8UnixPath, Utf8UnixPathBuf};
+    ///
+    /// // Shifting names along a chain renames the end of the chain first
+    /// let planner = RenamePlanner::new(
+    ///     PathTemplate::new("log.{n}").unwrap(),
+    ///     PathTemplate::new("log.{n}.{n}").unwrap(),
+    /// )
+    /// .unwrap();
+    /// let plan = planner
+    ///     .plan([Utf8UnixPath::new("log.1"), Utf8UnixPath::new("log.1.1")])
+    ///     .unwrap();
+    /// assert_eq!(plan[0].1, Utf8UnixPathBuf::from("log.1.1.1.1"));
+    /// assert_eq!(plan[1].1, Utf8UnixPathBuf::from("log.1.1"));
+    ///
+    /// // Swapping two names needs a temporary name, so it is rejected
+    /// let planner = RenamePlanner::new(
+    ///     PathTemplate::new("{a}-{b}").unwrap(),
+    ///     PathTemplate::new("{b}-{a}").unwrap(),
+    /// )
+    /// .unwrap();
+    /// assert_eq!(
+    ///     planner.plan([Utf8UnixPath::new("x-y"), Utf8UnixPath::new("y-x")]),
+    ///     Err(RenameError::Cycle(vec![String::from("x-y"), String::from("y-x")])),
+    /// );
+    /// ```
+    pub fn plan<T, I, P>(&self, paths: I) -> Result<Vec<Rename<T>>, RenameError>
+    where
+        T: for<'enc> Utf8Encoding<'enc>,
+        I: IntoIterator<Item = P>,
+        P: AsRef<Utf8Path<T>>,
+    {
+        let mut existing = BTreeSet::new();
+        let mut renames = Vec::new();
+
+        for path in paths {
+            let path = path.as_ref();
+            if !existing.insert(path.to_path_buf()) {
+                continue;
+            }
+
+            let vars = match self.from.extract_unambiguous(path) {
+                TemplateMatch::None => continue,
+                TemplateMatch::Unique(vars) => vars,
+                TemplateMatch::Ambiguous => {
+                    return Err(RenameError::Ambiguous(path.as_str().to_string()))
+                }
+            };
+
+            let target = self
+                .to
+                .render::<T>(&vars)
+                .map_err(|error| RenameError::Template {
+                    path: path.as_str().to_string(),
+                    error,
+                })?;
+
+            if target != path {
+                renames.push((path.to_path_buf(), target));
+            }
+        }
+
+        // Every target must be claimed by a single rename
+        let mut targets: BTreeMap<&Utf8Path<T>, Vec<&Utf8Path<T>>> = BTreeMap::new();
+        for (from, to) in &renames {
+            targets.entry(to).or_default().push(from);
+        }
+        for (_, to) in &renames {
+            let sources = &targets[to.as_path()];
+            if sources.len() > 1 {
+                return Err(RenameError::Collision {
+                    target: to.as_str().to_string(),
+                    sources: sources.iter().map(|p| p.as_str().to_string()).collect(),
+                });
+            }
+        }
+
+        // Targets may only replace existing paths that are moved away first
+        let sources: BTreeMap<&Utf8Path<T>, usize> = renames
+            .iter()
+            .enumerate()
+            .map(|(i, (from, _))| (from.as_path(), i))
+            .collect();
+        let mut order = Vec::with_capacity(renames.len());
+        let mut done = vec![false; renames.len()];
+        let mut on_chain = vec![false; renames.len()];
+
+        for start in 0..renames.len() {
+            let mut chain: Vec<usize> = Vec::new();
+            let mut i = start;
+            while !done[i] {
+                if on_chain[i] {
+                    let pos = chain.iter().position(|j| *j == i).unwrap_or(0);
+                    return Err(RenameError::Cycle(
+                        chain[pos..]
+                            .iter()
+                            .map(|j| renames[*j].0.as_str().to_string())
+                            .collect(),
+                    ));
+                }
+
+                on_chain[i] = true;
+                chain.push(i);
+
+                let to = renames[i].1.as_path();
+                match sources.get(to) {
+                    Some(j) => i = *j,
+                    None if existing.contains(to) => {
+                        return Err(RenameError::Overwrite {
+                            from: renames[i].0.as_str().to_string(),
+                            to: to.as_str().to_string(),
+                        })
+                    }
+                    None => break,
+                }
+            }
+
+            for i in chain.into_iter().rev() {
+                done[i] = true;
+                order.push(i);
+            }
+        }
+
+        let mut renames: Vec<_> = renames.into_iter().map(Some).collect();
+        Ok(order
+            .into_iter()
+            .filter_map(|i| renames[i].take())
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Utf8UnixPath, Utf8UnixPathBuf, Utf8WindowsPath, Utf8WindowsPathBuf};
+
+    fn planner(from: &str, to: &str) -> RenamePlanner {
+        RenamePlanner::new(
+            PathTemplate::new(from).unwrap(),
+            PathTemplate::new(to).unwrap(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn plan_should_skip_unmatched_and_unchanged_paths() {
+        let planner = planner("{dir}/{name}.JPG", "{dir}/{name}.jpg");
+        let plan = planner
+            .plan([
+                Utf8UnixPath::new("a/x.JPG"),
+                Utf8UnixPath::new("a/y.png"),
+                Utf8UnixPath::new("a/x.JPG"),
+            ])
+            .unwrap();
+        assert_eq!(
+            plan,
+            [(
+                Utf8UnixPathBuf::from("a/x.JPG"),
+                Utf8UnixPathBuf::from("a/x.jpg")
+            )]
+        );
+
+        // Windows literals ignore case, so already renamed paths still match but stay put
+        let plan = planner
+            .plan([
+                Utf8WindowsPath::new(r"a\x.jpg"),
+                Utf8WindowsPath::new(r"b\y.JPG"),
+            ])
+            .unwrap();
+        assert_eq!(
+            plan,
+            [(
+                Utf8WindowsPathBuf::from(r"b\y.JPG"),
+                Utf8WindowsPathBuf::from(r"b\y.jpg")
+            )]
+        );
+    }
+
+    #[test]
+    fn plan_should_detect_collisions() {
+        let planner = planner("{dir}/{name}", "flat/{name}");
+        assert_eq!(
+            planner.plan([
+                Utf8UnixPath::new("a/readme"),
+                Utf8UnixPath::new("b/notes"),
+                Utf8UnixPath::new("c/readme"),
+            ]),
+            Err(RenameError::Collision {
+                target: String::from("flat/readme"),
+                sources: vec![String::from("a/readme"), String::from("c/readme")],
+            })
+        );
+    }
+
+    #[test]
+    fn plan_should_detect_ambiguity() {
+        let planner = planner("{a}_{b}", "{b}/{a}");
+        assert_eq!(
+            planner.plan([Utf8UnixPath::new("x_y"), Utf8UnixPath::new("x_y_z")]),
+            Err(RenameError::Ambiguous(String::from("x_y_z")))
+        );
+    }
+
+    #[test]
+    fn plan_should_order_chains_and_reject_cycles() {
+        let backup = planner("{n}.bak", "{n}.bak.bak");
+        let plan = backup
+            .plan([
+                Utf8UnixPath::new("a.bak"),
+                Utf8UnixPath::new("a.bak.bak"),
+                Utf8UnixPath::new("a.bak.bak.bak"),
+            ])
+            .unwrap();
+        let from: Vec<_> = plan.iter().map(|(from, _)| from.as_str()).collect();
+        assert_eq!(from, ["a.bak.bak.bak", "a.bak.bak", "a.bak"]);
+
+        let rotate = planner("{a}.{b}.{c}", "{b}.{c}.{a}");
+        assert_eq!(
+            rotate.plan([
+                Utf8UnixPath::new("x.y.z"),
+                Utf8UnixPath::new("y.z.x"),
+                Utf8UnixPath::new("z.x.y"),
+            ]),
+            Err(RenameError::Cycle(vec![
+                String::from("x.y.z"),
+                String::from("y.z.x"),
+                String::from("z.x.y"),
+            ]))
+        );
+    }
+}
@@ -0,0 +1,136 @@
+use core::ops::Bound;
+
+use crate::no_std_compat::*;
+use crate::ComponentKind;
+
+/// Byte marking the end of a component, following [`ESCAPE`]
+const END: u8 = 0x01;
+
+/// Byte that introduces either an escaped zero byte or the end of a component
+const ESCAPE: u8 = 0x00;
+
+/// Byte standing for a zero byte within a component, following [`ESCAPE`]
+const ESCAPED_ZERO: u8 = 0xFF;
+
+/// Appends the sort key encoding of a component of `kind` whose contents are `bytes` to `key`.
+///
+/// Each component is written as a byte ranking its kind, followed by its contents with zero
+/// bytes escaped, followed by a terminator that sorts below every byte of the contents. This
+/// means that keys compare component by component, and a path sorts right before its
+/// descendants, which all sort before the next sibling of the path (e.g. `foo` < `foo/bar` <
+/// `foo-bar`).
+pub(crate) fn push_component(key: &mut Vec<u8>, kind: ComponentKind, bytes: &[u8]) {
+    key.push(match kind {
+        ComponentKind::Prefix => 1,
+        ComponentKind::RootDir => 2,
+        ComponentKind::CurDir => 3,
+        ComponentKind::ParentDir => 4,
+        ComponentKind::Normal => 5,
+    });
+
+    for &byte in bytes {
+        if byte == 0 {
+            key.extend_from_slice(&[ESCAPE, ESCAPED_ZERO]);
+        } else {
+            key.push(byte);
+        }
+    }
+
+    key.extend_from_slice(&[ESCAPE, END]);
+}
+
+/// Returns the bounds covering exactly the keys of the path with the sort `key` and all of its
+/// descendants.
+///
+/// As every component ends with a terminator, raising the last terminator produces a key that
+/// sorts after all descendants but before any sibling sharing a prefix. The empty path has no
+/// components, so every key falls within its subtree.
+pub(crate) fn subtree_bounds(key: Vec<u8>) -> (Bound<Vec<u8>>, Bound<Vec<u8>>) {
+    if key.is_empty() {
+        return (Bound::Included(key), Bound::Unbounded);
+    }
+
+    let mut end = key.clone();
+    if let Some(last) = end.last_mut() {
+        *last = END + 1;
+    }
+    (Bound::Included(key), Bound::Excluded(end))
+}
+
+/// Calls `f` with the number of components and the end of each prefix of the sort `key` that is
+/// itself the key of an ancestor of the path, starting with the empty prefix and ending with the
+/// whole key.
+pub(crate) fn for_each_ancestor(key: &[u8], mut f: impl FnMut(usize, usize)) {
+    f(0, 0);
+
+    let mut depth = 0;
+    let mut i = 0;
+    while i < key.len() {
+        if key[i] == ESCAPE {
+            i += 2;
+            if key.get(i - 1) == Some(&END) {
+                depth += 1;
+                f(depth, i);
+            }
+        } else {
+            i += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(components: &[&[u8]]) -> Vec<u8> {
+        let mut key = Vec::new();
+        for bytes in components {
+            push_component(&mut key, ComponentKind::Normal, bytes);
+        }
+        key
+    }
+
+    #[test]
+    fn push_component_should_keep_subtrees_contiguous() {
+        let mut keys = vec![
+            key(&[b"foo-bar"]),
+            key(&[b"foo", b"bar"]),
+            key(&[b"foo\0"]),
+            key(&[b"foo"]),
+            key(&[b"fo"]),
+            key(&[b"foo", b"\0"]),
+        ];
+        keys.sort();
+        assert_eq!(
+            keys,
+            [
+                key(&[b"fo"]),
+                key(&[b"foo"]),
+                key(&[b"foo", b"\0"]),
+                key(&[b"foo", b"bar"]),
+                key(&[b"foo\0"]),
+                key(&[b"foo-bar"]),
+            ]
+        );
+    }
+
+    #[test]
+    fn subtree_bounds_should_cover_only_descendants() {
+        let bounds = subtree_bounds(key(&[b"foo"]));
+        let within = |k: Vec<u8>| core::ops::RangeBounds::contains(&bounds, &k);
+
+        assert!(within(key(&[b"foo"])));
+        assert!(within(key(&[b"foo", b"bar"])));
+        assert!(within(key(&[b"foo", b"\0", b"\xff"])));
+        assert!(!within(key(&[b"fo"])));
+        assert!(!within(key(&[b"foo-bar"])));
+        assert!(!within(key(&[b"foo\0"])));
+        assert!(!within(key(&[b"foo\x01"])));
+
+        let bounds = subtree_bounds(Vec::new());
+        assert!(core::ops::RangeBounds::contains(
+            &bounds,
+            &key(&[b"anything"])
+        ));
+    }
+}
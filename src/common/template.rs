@@ -0,0 +1,525 @@
+use alloc::collections::BTreeMap;
+use core::fmt;
+use core::str::FromStr;
+
+use crate::common::{EncodingInfo, TemplateError};
+use crate::no_std_compat::*;
+use crate::{Utf8Encoding, Utf8Path, Utf8PathBuf};
+
+/// A path containing named placeholders, such as `logs/{year}/{month}/{name}.{ext}`, that can be
+/// rendered into a path by substituting variables or used to extract those variables back out of
+/// a path.
+///
+/// Placeholders are written as `{name}`, while `{{` and `}}` stand for a literal `{` and `}`. Each
+/// variable stands for a non-empty part of a single component, so values can never contain a
+/// separator, and the same variable may appear more than once as long as it always has the same
+/// value.
+///
+/// Separators within the template are interpreted using the encoding of the path being rendered
+/// or matched, so `/` and `\` are both separators for Windows paths.
+///
+/// # Examples
+///
+/// ```
+/// use typed_path::{PathTemplate, Utf8UnixPath, Utf8UnixPathBuf, Utf8WindowsPathBuf};
+///
+/// let template = PathTemplate::new("logs/{year}/{month}/{name}.{ext}").unwrap();
+///
+/// let path = Utf8UnixPath::new("logs/2024/05/app.tar.gz");
+/// let vars = template.extract(path).unwrap();
+/// assert_eq!(vars["year"], "2024");
+/// assert_eq!(vars["name"], "app.tar");
+/// assert_eq!(vars["ext"], "gz");
+///
+/// assert_eq!(template.render(&vars), Ok(Utf8UnixPathBuf::from("logs/2024/05/app.tar.gz")));
+/// assert_eq!(
+///     template.render(&vars),
+///     Ok(Utf8WindowsPathBuf::from(r"logs\2024\05\app.tar.gz")),
+/// );
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct PathTemplate {
+    original: String,
+    pieces: Vec<Piece>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+enum Piece {
+    /// Literal text, whose separators are interpreted by the encoding
+    Literal(String),
+
+    /// `{name}`
+    Variable(String),
+}
+
+impl PathTemplate {
+    /// Parses `template` into a new [`PathTemplate`], failing if it contains an unclosed or empty
+    /// placeholder, or a `}` that does not close one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{PathTemplate, TemplateError};
+    ///
+    /// assert!(PathTemplate::new("{{raw}}/{name}").is_ok());
+    /// assert_eq!(PathTemplate::new("a/{name"), Err(TemplateError::UnclosedPlaceholder(2)));
+    /// assert_eq!(PathTemplate::new("a/{}"), Err(TemplateError::EmptyName(2)));
+    /// assert_eq!(PathTemplate::new("a}"), Err(TemplateError::UnmatchedBrace(1)));
+    /// ```
+    pub fn new(template: &str) -> Result<Self, TemplateError> {
+        let mut pieces = Vec::new();
+        let mut literal = String::new();
+        let mut chars = template.char_indices().peekable();
+
+        while let Some((pos, c)) = chars.next() {
+            match c {
+                '{' if matches!(chars.peek(), Some((_, '{'))) => {
+                    chars.next();
+                    literal.push('{');
+                }
+                '}' if matches!(chars.peek(), Some((_, '}'))) => {
+                    chars.next();
+                    literal.push('}');
+                }
+                '{' => {
+                    let mut name = String::new();
+                    loop {
+                        match chars.next() {
+                            Some((_, '}')) => break,
+                            Some((_, '{')) | None => {
+                                return Err(TemplateError::UnclosedPlaceholder(pos))
+                            }
+                            Some((_, c)) => name.push(c),
+                        }
+                    }
+
+                    if name.is_empty() {
+                        return Err(TemplateError::EmptyName(pos));
+                    }
+
+                    if !literal.is_empty() {
+                        pieces.push(Piece::Literal(core::mem::take(&mut literal)));
+                    }
+                    pieces.push(Piece::Variable(name));
+                }
+                '}' => return Err(TemplateError::UnmatchedBrace(pos)),
+                c => literal.push(c),
+            }
+        }
+
+        if !literal.is_empty() {
+            pieces.push(Piece::Literal(literal));
+        }
+
+        Ok(Self {
+            original: template.to_string(),
+            pieces,
+        })
+    }
+
+    /// Returns the template as it was originally written.
+    pub fn as_str(&self) -> &str {
+        &self.original
+    }
+
+    /// Returns an iterator over the names of the variables within the template, in the order
+    /// they appear and including any repeats.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::PathTemplate;
+    ///
+    /// let template = PathTemplate::new("{user}/{year}/{user}.log").unwrap();
+    /// assert_eq!(template.variables().collect::<Vec<_>>(), ["user", "year", "user"]);
+    /// ```
+    pub fn variables(&self) -> impl Iterator<Item = &str> + '_ {
+        self.pieces.iter().filter_map(|piece| match piece {
+            Piece::Variable(name) => Some(name.as_str()),
+            Piece::Literal(_) => None,
+        })
+    }
+
+    /// Renders the template into a path by replacing each placeholder with its value in `vars`,
+    /// writing separators using the primary separator of the encoding.
+    ///
+    /// Fails if a variable is missing, or if its value is empty or contains a separator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::BTreeMap;
+    /// use typed_path::{PathTemplate, TemplateError, Utf8UnixPathBuf};
+    ///
+    /// let template = PathTemplate::new("/srv/{site}/index.html").unwrap();
+    ///
+    /// let mut vars = BTreeMap::new();
+    /// vars.insert(String::from("site"), String::from("example.com"));
+    /// assert_eq!(
+    ///     template.render(&vars),
+    ///     Ok(Utf8UnixPathBuf::from("/srv/example.com/index.html")),
+    /// );
+    ///
+    /// vars.insert(String::from("site"), String::from("../etc"));
+    /// assert_eq!(
+    ///     template.render::<typed_path::Utf8UnixEncoding>(&vars),
+    ///     Err(TemplateError::InvalidValue(String::from("site"))),
+    /// );
+    /// ```
+    pub fn render<T>(
+        &self,
+        vars: &BTreeMap<String, String>,
+    ) -> Result<Utf8PathBuf<T>, TemplateError>
+    where
+        T: for<'enc> Utf8Encoding<'enc>,
+    {
+        self.render_with(|name| vars.get(name))
+    }
+
+    /// Renders the template into a path by replacing each placeholder with the value produced by
+    /// `lookup` for its name, writing separators using the primary separator of the encoding.
+    ///
+    /// Fails if `lookup` returns `None`, or if a value is empty or contains a separator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{PathTemplate, Utf8WindowsPathBuf};
+    ///
+    /// let template = PathTemplate::new("C:/cache/{key}.bin").unwrap();
+    /// let path = template.render_with(|name| match name {
+    ///     "key" => Some("a1b2"),
+    ///     _ => None,
+    /// });
+    /// assert_eq!(path, Ok(Utf8WindowsPathBuf::from(r"C:\cache\a1b2.bin")));
+    /// ```
+    pub fn render_with<T, F, S>(&self, mut lookup: F) -> Result<Utf8PathBuf<T>, TemplateError>
+    where
+        T: for<'enc> Utf8Encoding<'enc>,
+        F: FnMut(&str) -> Option<S>,
+        S: AsRef<str>,
+    {
+        let info = T::info();
+        let mut path = String::with_capacity(self.original.len());
+
+        for piece in &self.pieces {
+            match piece {
+                Piece::Literal(literal) => {
+                    for c in literal.chars() {
+                        path.push(if info.is_separator_char(c) {
+                            info.separator
+                        } else {
+                            c
+                        });
+                    }
+                }
+                Piece::Variable(name) => {
+                    let value =
+                        lookup(name).ok_or_else(|| TemplateError::MissingVariable(name.clone()))?;
+                    let value = value.as_ref();
+                    if value.is_empty() || value.chars().any(|c| info.is_separator_char(c)) {
+                        return Err(TemplateError::InvalidValue(name.clone()));
+                    }
+                    path.push_str(value);
+                }
+            }
+        }
+
+        Ok(Utf8PathBuf::from(path))
+    }
+
+    /// Matches the entirety of `path` against the template, returning the value captured by each
+    /// variable, or `None` if the path does not fit the template.
+    ///
+    /// Literal text is compared case-insensitively for encodings that are not case sensitive.
+    /// When the split between variables is ambiguous, earlier variables capture as much as they
+    /// can.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{PathTemplate, Utf8UnixPath, Utf8WindowsPath};
+    ///
+    /// let template = PathTemplate::new("users/{id}/{id}.json").unwrap();
+    ///
+    /// let vars = template.extract(Utf8UnixPath::new("users/42/42.json")).unwrap();
+    /// assert_eq!(vars["id"], "42");
+    ///
+    /// // Repeated variables must capture the same value
+    /// assert_eq!(template.extract(Utf8UnixPath::new("users/42/43.json")), None);
+    ///
+    /// // Windows paths can use either separator and ignore case
+    /// let vars = template.extract(Utf8WindowsPath::new(r"USERS\7/7.JSON")).unwrap();
+    /// assert_eq!(vars["id"], "7");
+    /// ```
+    pub fn extract<T>(&self, path: &Utf8Path<T>) -> Option<BTreeMap<String, String>>
+    where
+        T: for<'enc> Utf8Encoding<'enc>,
+    {
+        let mut vars = None;
+        extract_pieces(
+            &self.pieces,
+            path.as_str(),
+            &T::info(),
+            &mut Vec::new(),
+            &mut |captures| {
+                vars = Some(to_vars(captures));
+                true
+            },
+        );
+        vars
+    }
+
+    /// Like [`PathTemplate::extract`], but returns [`TemplateMatch::Ambiguous`] instead of
+    /// picking a split when the variables can capture the path in more than one way.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{PathTemplate, TemplateMatch, Utf8UnixPath};
+    ///
+    /// let template = PathTemplate::new("{name}.{ext}").unwrap();
+    /// assert!(matches!(
+    ///     template.extract_unambiguous(Utf8UnixPath::new("app.log")),
+    ///     TemplateMatch::Unique(vars) if vars["name"] == "app",
+    /// ));
+    /// assert_eq!(
+    ///     template.extract_unambiguous(Utf8UnixPath::new("app.tar.gz")),
+    ///     TemplateMatch::Ambiguous,
+    /// );
+    /// assert_eq!(
+    ///     template.extract_unambiguous(Utf8UnixPath::new("README")),
+    ///     TemplateMatch::None,
+    /// );
+    /// ```
+    pub fn extract_unambiguous<T>(&self, path: &Utf8Path<T>) -> TemplateMatch
+    where
+        T: for<'enc> Utf8Encoding<'enc>,
+    {
+        let mut result = TemplateMatch::None;
+        extract_pieces(
+            &self.pieces,
+            path.as_str(),
+            &T::info(),
+            &mut Vec::new(),
+            &mut |captures| match result {
+                TemplateMatch::None => {
+                    result = TemplateMatch::Unique(to_vars(captures));
+                    false
+                }
+                _ => {
+                    result = TemplateMatch::Ambiguous;
+                    true
+                }
+            },
+        );
+        result
+    }
+}
+
+impl fmt::Display for PathTemplate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.original)
+    }
+}
+
+impl FromStr for PathTemplate {
+    type Err = TemplateError;
+
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::new(s)
+    }
+}
+
+/// The result of [`PathTemplate::extract_unambiguous`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum TemplateMatch {
+    /// The path does not fit the template.
+    None,
+
+    /// The path fits the template in exactly one way, capturing these variables.
+    Unique(BTreeMap<String, String>),
+
+    /// The path fits the template in more than one way.
+    Ambiguous,
+}
+
+fn to_vars(captures: &[(&str, &str)]) -> BTreeMap<String, String> {
+    captures
+        .iter()
+        .map(|(name, value)| (name.to_string(), value.to_string()))
+        .collect()
+}
+
+/// Walks every way that `pieces` can match the entirety of `path`, from earlier variables
+/// capturing the most to the least, passing the value of each variable to `found`; returns true
+/// as soon as `found` does to stop the search
+fn extract_pieces<'a, F>(
+    pieces: &'a [Piece],
+    path: &'a str,
+    info: &EncodingInfo,
+    captures: &mut Vec<(&'a str, &'a str)>,
+    found: &mut F,
+) -> bool
+where
+    F: FnMut(&[(&'a str, &'a str)]) -> bool,
+{
+    let (piece, rest) = match pieces.split_first() {
+        Some(x) => x,
+        None => return path.is_empty() && found(captures),
+    };
+
+    match piece {
+        Piece::Literal(literal) => match strip_literal(path, literal, info) {
+            Some(path) => extract_pieces(rest, path, info, captures, found),
+            None => false,
+        },
+        Piece::Variable(name) => {
+            if let Some((_, value)) = captures.iter().find(|(n, _)| n == name) {
+                return path
+                    .strip_prefix(*value)
+                    .map(|path| extract_pieces(rest, path, info, captures, found))
+                    .unwrap_or(false);
+            }
+
+            // Values never extend past the end of the component
+            let end = path
+                .char_indices()
+                .find(|(_, c)| info.is_separator_char(*c))
+                .map(|(i, _)| i)
+                .unwrap_or(path.len());
+
+            let ends = path[..end]
+                .char_indices()
+                .skip(1)
+                .map(|(i, _)| i)
+                .chain(core::iter::once(end))
+                .filter(|i| *i > 0);
+            for i in ends.collect::<Vec<_>>().into_iter().rev() {
+                captures.push((name, &path[..i]));
+                let stop = extract_pieces(rest, &path[i..], info, captures, found);
+                captures.pop();
+                if stop {
+                    return true;
+                }
+            }
+
+            false
+        }
+    }
+}
+
+/// Strips `literal` from the start of `path`, treating separators as equivalent and ignoring
+/// ascii case if the encoding is not case sensitive
+fn strip_literal<'a>(path: &'a str, literal: &str, info: &EncodingInfo) -> Option<&'a str> {
+    let mut chars = path.char_indices();
+    for expected in literal.chars() {
+        let (_, actual) = chars.next()?;
+        let is_eq = if info.is_separator_char(expected) {
+            info.is_separator_char(actual)
+        } else if info.case_sensitive {
+            expected == actual
+        } else {
+            expected.eq_ignore_ascii_case(&actual)
+        };
+
+        if !is_eq {
+            return None;
+        }
+    }
+
+    Some(chars.as_str())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Utf8UnixEncoding, Utf8UnixPath, Utf8UnixPathBuf, Utf8WindowsPath};
+
+    fn vars(pairs: &[(&str, &str)]) -> BTreeMap<String, String> {
+        pairs
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn new_should_support_escaped_braces() {
+        let template = PathTemplate::new("{{a}}/{b}}}").unwrap();
+        assert_eq!(template.variables().collect::<Vec<_>>(), ["b"]);
+        assert_eq!(
+            template.render(&vars(&[("b", "x")])),
+            Ok(Utf8UnixPathBuf::from("{a}/x}"))
+        );
+
+        assert_eq!(
+            PathTemplate::new("{a{b}"),
+            Err(TemplateError::UnclosedPlaceholder(0))
+        );
+    }
+
+    #[test]
+    fn render_should_fail_for_missing_or_invalid_values() {
+        let template = PathTemplate::new("{a}/{b}").unwrap();
+        assert_eq!(
+            template.render::<Utf8UnixEncoding>(&vars(&[("a", "x")])),
+            Err(TemplateError::MissingVariable(String::from("b")))
+        );
+        assert_eq!(
+            template.render::<Utf8UnixEncoding>(&vars(&[("a", ""), ("b", "y")])),
+            Err(TemplateError::InvalidValue(String::from("a")))
+        );
+
+        // `\` is only a separator for Windows paths
+        let values = vars(&[("a", r"x\y"), ("b", "z")]);
+        assert_eq!(
+            template.render(&values),
+            Ok(Utf8UnixPathBuf::from(r"x\y/z"))
+        );
+        assert_eq!(
+            template.render::<crate::Utf8WindowsEncoding>(&values),
+            Err(TemplateError::InvalidValue(String::from("a")))
+        );
+    }
+
+    #[test]
+    fn extract_should_capture_within_components() {
+        let template = PathTemplate::new("{dir}/{name}.{ext}").unwrap();
+        assert_eq!(
+            template.extract(Utf8UnixPath::new("a/b.c.d")),
+            Some(vars(&[("dir", "a"), ("name", "b.c"), ("ext", "d")]))
+        );
+        assert_eq!(template.extract(Utf8UnixPath::new("a/b/c.d")), None);
+        assert_eq!(template.extract(Utf8UnixPath::new("a/.d")), None);
+        assert_eq!(template.extract(Utf8UnixPath::new("a/b.")), None);
+
+        let template = PathTemplate::new("{a}{b}").unwrap();
+        assert_eq!(
+            template.extract(Utf8UnixPath::new("caf\u{e9}")),
+            Some(vars(&[("a", "caf"), ("b", "\u{e9}")]))
+        );
+
+        let template = PathTemplate::new("Logs/{n}").unwrap();
+        assert_eq!(template.extract(Utf8UnixPath::new("logs/1")), None);
+        assert_eq!(
+            template.extract(Utf8WindowsPath::new(r"logs\1")),
+            Some(vars(&[("n", "1")]))
+        );
+    }
+
+    #[test]
+    fn extract_should_invert_render() {
+        let template = PathTemplate::new("logs/{year}/{month}/{name}-{year}.{ext}").unwrap();
+        let values = vars(&[
+            ("year", "2024"),
+            ("month", "05"),
+            ("name", "app-v2"),
+            ("ext", "log"),
+        ]);
+
+        let path: Utf8UnixPathBuf = template.render(&values).unwrap();
+        assert_eq!(path, Utf8UnixPathBuf::from("logs/2024/05/app-v2-2024.log"));
+        assert_eq!(template.extract(&path), Some(values));
+    }
+}
@@ -0,0 +1,176 @@
+use crate::no_std_compat::*;
+
+/// Splits `name` into the pieces that, when concatenated, make up `name` shortened to at most
+/// `max` bytes.
+///
+/// When the name has an extension that fits within `max` alongside at least one byte of the stem,
+/// the stem is shortened and the extension is kept. Otherwise, the name is cut as a whole. Names
+/// are never cut in the middle of a UTF-8 sequence, so the result may be shorter than `max`.
+pub(crate) fn truncate_name(name: &[u8], max: usize) -> (&[u8], &[u8]) {
+    if name.len() <= max {
+        return (name, &[]);
+    }
+
+    // A leading dot (e.g. `.bashrc`) does not start an extension
+    if let Some(dot) = name.iter().rposition(|b| *b == b'.').filter(|i| *i > 0) {
+        let (stem, ext) = name.split_at(dot);
+        if ext.len() < max {
+            let cut = floor_char_boundary(stem, max - ext.len());
+            if cut > 0 {
+                return (&stem[..cut], ext);
+            }
+        }
+    }
+
+    (&name[..floor_char_boundary(name, max)], &[])
+}
+
+/// Returns the largest index no greater than `max` that does not fall in the middle of a UTF-8
+/// sequence, treating bytes that are not UTF-8 as their own characters.
+pub(crate) fn floor_char_boundary(bytes: &[u8], max: usize) -> usize {
+    if max >= bytes.len() {
+        return bytes.len();
+    }
+
+    // A UTF-8 sequence has at most three continuation bytes
+    let mut i = max;
+    while i > 0 && max - i < 3 && is_continuation(bytes[i]) {
+        i -= 1;
+    }
+
+    if is_continuation(bytes[i]) {
+        max
+    } else {
+        i
+    }
+}
+
+fn is_continuation(byte: u8) -> bool {
+    byte & 0b1100_0000 == 0b1000_0000
+}
+
+/// Returns the width of `s` when displayed, counted in chars, or in terminal columns with the
+/// `unicode-width` feature.
+pub(crate) fn display_width(s: &str) -> usize {
+    #[cfg(feature = "unicode-width")]
+    {
+        unicode_width::UnicodeWidthStr::width(s)
+    }
+
+    #[cfg(not(feature = "unicode-width"))]
+    {
+        s.chars().count()
+    }
+}
+
+/// Renders the path made of `head` (its prefix and root), `dirs`, and `name` within `max_width`
+/// columns by replacing as many of the leading `dirs` as needed with `…`.
+///
+/// The head and name are always kept whole, even when they alone are wider than `max_width`.
+pub(crate) fn abbreviate(
+    head: &str,
+    dirs: &[&str],
+    name: &str,
+    separator: char,
+    max_width: usize,
+) -> String {
+    let separator_width = display_width(separator.encode_utf8(&mut [0; 4]));
+    let mut width =
+        display_width(head) + display_width("…") + separator_width + display_width(name);
+
+    let mut start = dirs.len();
+    while start > 0 {
+        let dir_width = display_width(dirs[start - 1]) + separator_width;
+        if width + dir_width > max_width {
+            break;
+        }
+        width += dir_width;
+        start -= 1;
+    }
+
+    let mut s = String::with_capacity(max_width);
+    s.push_str(head);
+    s.push('…');
+    for dir in &dirs[start..] {
+        s.push(separator);
+        s.push_str(dir);
+    }
+    s.push(separator);
+    s.push_str(name);
+    s
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn truncate_name_should_keep_names_that_fit() {
+        assert_eq!(
+            truncate_name(b"file.txt", 8),
+            (b"file.txt".as_slice(), b"".as_slice())
+        );
+        assert_eq!(truncate_name(b"", 0), (b"".as_slice(), b"".as_slice()));
+    }
+
+    #[test]
+    fn truncate_name_should_preserve_extension_when_possible() {
+        assert_eq!(
+            truncate_name(b"filename.txt", 8),
+            (b"file".as_slice(), b".txt".as_slice())
+        );
+        assert_eq!(
+            truncate_name(b"archive.tar.gz", 9),
+            (b"archiv".as_slice(), b".gz".as_slice())
+        );
+
+        // Extension does not leave room for the stem
+        assert_eq!(
+            truncate_name(b"file.text", 5),
+            (b"file.".as_slice(), b"".as_slice())
+        );
+
+        // Leading dot is not an extension
+        assert_eq!(
+            truncate_name(b".bashrc", 3),
+            (b".ba".as_slice(), b"".as_slice())
+        );
+    }
+
+    #[test]
+    fn truncate_name_should_not_split_utf8_sequences() {
+        // 'é' is two bytes and '🦀' is four bytes
+        let name = "caf\u{e9}.txt".as_bytes();
+        assert_eq!(
+            truncate_name(name, 8),
+            (b"caf".as_slice(), b".txt".as_slice())
+        );
+
+        let name = "\u{1f980}\u{1f980}".as_bytes();
+        assert_eq!(
+            truncate_name(name, 7),
+            ("\u{1f980}".as_bytes(), b"".as_slice())
+        );
+        assert_eq!(truncate_name(name, 3), (b"".as_slice(), b"".as_slice()));
+    }
+
+    #[test]
+    fn truncate_name_should_cut_non_utf8_bytes_anywhere() {
+        let name = [0x80, 0x80, 0x80, 0x80, 0x80];
+        assert_eq!(truncate_name(&name, 4), (&name[..4], b"".as_slice()));
+    }
+
+    #[test]
+    fn abbreviate_should_measure_width_in_display_columns() {
+        let dirs = ["a", "日本"];
+
+        // Wide characters take up two columns each when measured with `unicode-width`
+        #[cfg(feature = "unicode-width")]
+        assert_eq!(abbreviate("/", &dirs, "f", '/', 7), "/…/f");
+
+        #[cfg(not(feature = "unicode-width"))]
+        assert_eq!(abbreviate("/", &dirs, "f", '/', 7), "/…/日本/f");
+
+        assert_eq!(abbreviate("/", &dirs, "f", '/', 0), "/…/f");
+    }
+}
@@ -1,16 +1,38 @@
+#[macro_use]
+mod absolute;
+mod batch;
+mod builder;
 mod components;
+mod cow;
+mod directory;
+mod hashed;
 mod iter;
 mod path;
 mod pathbuf;
+mod preparsed;
+mod segmented;
+mod segments;
+mod validated;
 
 use core::hash::Hasher;
 
+pub use absolute::*;
+pub use batch::*;
+pub use builder::*;
 pub use components::*;
+pub use cow::*;
+pub use directory::*;
+pub use hashed::*;
 pub use iter::*;
 pub use path::*;
 pub use pathbuf::*;
+pub use preparsed::*;
+pub use segmented::*;
+pub use segments::*;
+pub use validated::*;
 
 use crate::common::errors::CheckedPathError;
+use crate::common::EncodingInfo;
 use crate::no_std_compat::*;
 use crate::private;
 
@@ -22,6 +44,36 @@ pub trait Utf8Encoding<'a>: private::Sealed {
     /// Static label representing encoding type
     fn label() -> &'static str;
 
+    /// Name of the encoding, matching [`Utf8Encoding::label`]
+    const NAME: &'static str;
+
+    /// Separator placed between components when building a path
+    const SEPARATOR: char;
+
+    /// Additional separators recognized when parsing a path
+    const ALT_SEPARATORS: &'static [char];
+
+    /// Whether components are compared case-sensitively
+    const CASE_SENSITIVE: bool;
+
+    /// Bytes that are never allowed within a file or directory name, including separators
+    const DISALLOWED_FILENAME_BYTES: &'static [u8];
+
+    /// Returns true if `c` is either the primary or an alternate separator of the encoding
+    fn is_separator(c: char) -> bool {
+        Self::info().is_separator_char(c)
+    }
+
+    /// Returns the [`EncodingInfo`] describing the conventions of the encoding
+    fn info() -> EncodingInfo {
+        EncodingInfo {
+            name: Self::NAME,
+            separator: Self::SEPARATOR,
+            alt_separators: Self::ALT_SEPARATORS,
+            case_sensitive: Self::CASE_SENSITIVE,
+        }
+    }
+
     /// Produces an iterator of [`Utf8Component`]s over the given the byte slice (`path`)
     fn components(path: &'a str) -> Self::Components;
 
@@ -38,4 +90,10 @@ pub trait Utf8Encoding<'a>: private::Sealed {
     /// 3. `path` cannot contain invalid filename characters.
     /// 4. `path` cannot contain parent components such that the current path would be escaped.
     fn push_checked(current_path: &mut String, path: &str) -> Result<(), CheckedPathError>;
+
+    /// Returns true if `prefix`, the str of a prefix component, has every part that its kind
+    /// requires. Encodings without malformed prefixes accept any prefix.
+    fn is_valid_prefix(_prefix: &str) -> bool {
+        true
+    }
 }
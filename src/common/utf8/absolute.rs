@@ -0,0 +1,662 @@
+use alloc::borrow::ToOwned;
+use core::borrow::Borrow;
+use core::hash::{Hash, Hasher};
+use core::ops::Deref;
+use core::{cmp, fmt};
+
+use crate::common::PathKindError;
+use crate::{
+    ComponentKind, DifferentPrefixError, Utf8Component, Utf8Encoding, Utf8Path, Utf8PathBuf,
+};
+
+/// A [`Utf8Path`] that is known to be absolute (akin to [`Utf8Path::is_absolute`]).
+///
+/// Joining a [`Utf8RelPath`] onto an `Utf8AbsPath` always produces another [`Utf8AbsPathBuf`], and
+/// [`Utf8AbsPath::relative_to`] always produces a [`Utf8RelPathBuf`], so neither needs to be checked
+/// again. The full [`Utf8Path`] API is available through [`Deref`].
+///
+/// # Examples
+///
+/// ```
+/// use typed_path::{Utf8AbsPath, PathKindError, Utf8RelPath, Utf8UnixEncoding};
+///
+/// // NOTE: A path cannot be created on its own without a defined encoding
+/// let base = Utf8AbsPath::<Utf8UnixEncoding>::new("/srv/app").unwrap();
+/// let path = base.join(Utf8RelPath::new("logs/today.txt").unwrap());
+/// assert_eq!(path.as_str(), "/srv/app/logs/today.txt");
+///
+/// assert_eq!(
+///     Utf8AbsPath::<Utf8UnixEncoding>::new("srv/app"),
+///     Err(PathKindError::NotAbsolute),
+/// );
+/// ```
+#[repr(transparent)]
+pub struct Utf8AbsPath<T>
+where
+    T: for<'enc> Utf8Encoding<'enc>,
+{
+    inner: Utf8Path<T>,
+}
+
+/// A [`Utf8Path`] that is known to be relative, meaning that it has neither a prefix nor a root.
+///
+/// This is stricter than [`Utf8Path::is_relative`], which also holds for Windows paths like `C:foo`
+/// and `\foo`. Those would replace part of any path they are joined onto, whereas a `Utf8RelPath`
+/// only ever extends it.
+///
+/// # Examples
+///
+/// ```
+/// use typed_path::{PathKindError, Utf8RelPath, Utf8WindowsEncoding};
+///
+/// // NOTE: A path cannot be created on its own without a defined encoding
+/// let path = Utf8RelPath::<Utf8WindowsEncoding>::new(r"..\logs").unwrap();
+/// assert_eq!(path.join(Utf8RelPath::new("today.txt").unwrap()).as_str(), r"..\logs\today.txt");
+///
+/// assert_eq!(
+///     Utf8RelPath::<Utf8WindowsEncoding>::new(r"C:logs"),
+///     Err(PathKindError::NotRelative),
+/// );
+/// assert_eq!(
+///     Utf8RelPath::<Utf8WindowsEncoding>::new(r"\logs"),
+///     Err(PathKindError::NotRelative),
+/// );
+/// ```
+#[repr(transparent)]
+pub struct Utf8RelPath<T>
+where
+    T: for<'enc> Utf8Encoding<'enc>,
+{
+    inner: Utf8Path<T>,
+}
+
+/// An owned [`Utf8AbsPath`] (akin to [`Utf8PathBuf`]).
+///
+/// # Examples
+///
+/// ```
+/// use typed_path::{Utf8AbsPathBuf, Utf8RelPath, Utf8WindowsEncoding};
+///
+/// // NOTE: A path cannot be created on its own without a defined encoding
+/// let mut path = Utf8AbsPathBuf::<Utf8WindowsEncoding>::new(r"C:\srv").unwrap();
+/// path.push(Utf8RelPath::new("app").unwrap());
+/// assert_eq!(path.as_str(), r"C:\srv\app");
+///
+/// assert!(path.pop());
+/// assert!(path.pop());
+/// assert!(!path.pop());
+/// assert_eq!(path.as_str(), r"C:\");
+/// ```
+pub struct Utf8AbsPathBuf<T>
+where
+    T: for<'enc> Utf8Encoding<'enc>,
+{
+    inner: Utf8PathBuf<T>,
+}
+
+/// An owned [`Utf8RelPath`] (akin to [`Utf8PathBuf`]).
+///
+/// # Examples
+///
+/// ```
+/// use typed_path::{Utf8RelPath, Utf8RelPathBuf, Utf8UnixEncoding};
+///
+/// // NOTE: A path cannot be created on its own without a defined encoding
+/// let mut path = Utf8RelPathBuf::<Utf8UnixEncoding>::new("logs").unwrap();
+/// path.push(Utf8RelPath::new("today.txt").unwrap());
+/// assert_eq!(path.as_str(), "logs/today.txt");
+/// ```
+pub struct Utf8RelPathBuf<T>
+where
+    T: for<'enc> Utf8Encoding<'enc>,
+{
+    inner: Utf8PathBuf<T>,
+}
+
+/// Returns true if `path` has neither a prefix nor a root, so pushing it onto another path only
+/// ever extends that path
+fn is_strictly_relative<T>(path: &Utf8Path<T>) -> bool
+where
+    T: for<'enc> Utf8Encoding<'enc>,
+{
+    !matches!(
+        path.components().next().map(|c| c.kind()),
+        Some(ComponentKind::Prefix | ComponentKind::RootDir)
+    )
+}
+
+impl<T> Utf8AbsPath<T>
+where
+    T: for<'enc> Utf8Encoding<'enc>,
+{
+    /// Wraps `s` as an `Utf8AbsPath` if it is absolute.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PathKindError::NotAbsolute`] if [`Utf8Path::is_absolute`] does not hold.
+    ///
+    /// [`PathKindError::NotAbsolute`]: crate::PathKindError::NotAbsolute
+    pub fn new<S: AsRef<str> + ?Sized>(s: &S) -> Result<&Self, PathKindError> {
+        let path = Utf8Path::new(s);
+        if path.is_absolute() {
+            Ok(Self::new_unchecked(path))
+        } else {
+            Err(PathKindError::NotAbsolute)
+        }
+    }
+
+    fn new_unchecked(path: &Utf8Path<T>) -> &Self {
+        unsafe { &*(path as *const Utf8Path<T> as *const Self) }
+    }
+
+    /// Coerces to a [`Utf8Path`] slice.
+    #[inline]
+    pub fn as_path(&self) -> &Utf8Path<T> {
+        &self.inner
+    }
+
+    /// Converts to an owned [`Utf8AbsPathBuf`].
+    pub fn to_path_buf(&self) -> Utf8AbsPathBuf<T> {
+        Utf8AbsPathBuf {
+            inner: self.inner.to_path_buf(),
+        }
+    }
+
+    /// Returns the path without its final component, if it still names an absolute path.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Utf8AbsPath, Utf8UnixEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// let path = Utf8AbsPath::<Utf8UnixEncoding>::new("/srv/app").unwrap();
+    /// assert_eq!(path.parent().unwrap().as_str(), "/srv");
+    /// assert_eq!(Utf8AbsPath::<Utf8UnixEncoding>::new("/").unwrap().parent(), None);
+    /// ```
+    pub fn parent(&self) -> Option<&Self> {
+        self.inner
+            .parent()
+            .filter(|path| path.is_absolute())
+            .map(Self::new_unchecked)
+    }
+
+    /// Creates an owned [`Utf8AbsPathBuf`] with `path` adjoined to `self`.
+    pub fn join<P: AsRef<Utf8RelPath<T>>>(&self, path: P) -> Utf8AbsPathBuf<T> {
+        Utf8AbsPathBuf {
+            inner: self.inner.join(path.as_ref().as_path()),
+        }
+    }
+
+    /// Returns the [`Utf8RelPathBuf`] that leads from `base` to `self`.
+    ///
+    /// See [`Utf8Path::relative_to`] for more details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Utf8AbsPath, Utf8WindowsEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// let path = Utf8AbsPath::<Utf8WindowsEncoding>::new(r"C:\Users\Public").unwrap();
+    /// let base = Utf8AbsPath::new(r"C:\Users\me").unwrap();
+    /// assert_eq!(path.relative_to(base).unwrap().as_str(), r"..\Public");
+    ///
+    /// assert!(path.relative_to(Utf8AbsPath::new(r"D:\Users").unwrap()).is_err());
+    /// ```
+    pub fn relative_to<P: AsRef<Utf8AbsPath<T>>>(
+        &self,
+        base: P,
+    ) -> Result<Utf8RelPathBuf<T>, DifferentPrefixError> {
+        // Two absolute paths with the same anchor differ only by `..` and normal components
+        self.inner
+            .relative_to(base.as_ref().as_path())
+            .map(|inner| Utf8RelPathBuf { inner })
+    }
+}
+
+impl<T> Utf8RelPath<T>
+where
+    T: for<'enc> Utf8Encoding<'enc>,
+{
+    /// Wraps `s` as a `Utf8RelPath` if it has neither a prefix nor a root.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PathKindError::NotRelative`] if the path begins with a prefix or a root.
+    ///
+    /// [`PathKindError::NotRelative`]: crate::PathKindError::NotRelative
+    pub fn new<S: AsRef<str> + ?Sized>(s: &S) -> Result<&Self, PathKindError> {
+        let path = Utf8Path::new(s);
+        if is_strictly_relative(path) {
+            Ok(Self::new_unchecked(path))
+        } else {
+            Err(PathKindError::NotRelative)
+        }
+    }
+
+    fn new_unchecked(path: &Utf8Path<T>) -> &Self {
+        unsafe { &*(path as *const Utf8Path<T> as *const Self) }
+    }
+
+    /// Coerces to a [`Utf8Path`] slice.
+    #[inline]
+    pub fn as_path(&self) -> &Utf8Path<T> {
+        &self.inner
+    }
+
+    /// Converts to an owned [`Utf8RelPathBuf`].
+    pub fn to_path_buf(&self) -> Utf8RelPathBuf<T> {
+        Utf8RelPathBuf {
+            inner: self.inner.to_path_buf(),
+        }
+    }
+
+    /// Returns the path without its final component, or [`None`] if it is empty.
+    pub fn parent(&self) -> Option<&Self> {
+        self.inner.parent().map(Self::new_unchecked)
+    }
+
+    /// Creates an owned [`Utf8RelPathBuf`] with `path` adjoined to `self`.
+    pub fn join<P: AsRef<Utf8RelPath<T>>>(&self, path: P) -> Utf8RelPathBuf<T> {
+        Utf8RelPathBuf {
+            inner: self.inner.join(path.as_ref().as_path()),
+        }
+    }
+}
+
+impl<T> Utf8AbsPathBuf<T>
+where
+    T: for<'enc> Utf8Encoding<'enc>,
+{
+    /// Wraps `path` as an `Utf8AbsPathBuf` if it is absolute.
+    ///
+    /// See [`Utf8AbsPath::new`] for more details.
+    pub fn new<P: Into<Utf8PathBuf<T>>>(path: P) -> Result<Self, PathKindError> {
+        let inner = path.into();
+        Utf8AbsPath::<T>::new(inner.as_str())?;
+        Ok(Self { inner })
+    }
+
+    /// Coerces to an [`Utf8AbsPath`] slice.
+    #[inline]
+    pub fn as_abs_path(&self) -> &Utf8AbsPath<T> {
+        Utf8AbsPath::new_unchecked(&self.inner)
+    }
+
+    /// Extends `self` with `path`, which keeps `self` absolute.
+    pub fn push<P: AsRef<Utf8RelPath<T>>>(&mut self, path: P) {
+        self.inner.push(path.as_ref().as_path());
+    }
+
+    /// Truncates `self` to [`Utf8AbsPath::parent`], returning false and leaving `self` unchanged if
+    /// there is no such parent.
+    pub fn pop(&mut self) -> bool {
+        match self.as_abs_path().parent().map(|p| p.as_str().len()) {
+            Some(len) => {
+                self.inner.inner.truncate(len);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Consumes the `Utf8AbsPathBuf`, yielding its [`Utf8PathBuf`].
+    #[inline]
+    pub fn into_path_buf(self) -> Utf8PathBuf<T> {
+        self.inner
+    }
+}
+
+impl<T> Utf8RelPathBuf<T>
+where
+    T: for<'enc> Utf8Encoding<'enc>,
+{
+    /// Wraps `path` as a `Utf8RelPathBuf` if it has neither a prefix nor a root.
+    ///
+    /// See [`Utf8RelPath::new`] for more details.
+    pub fn new<P: Into<Utf8PathBuf<T>>>(path: P) -> Result<Self, PathKindError> {
+        let inner = path.into();
+        Utf8RelPath::<T>::new(inner.as_str())?;
+        Ok(Self { inner })
+    }
+
+    /// Coerces to a [`Utf8RelPath`] slice.
+    #[inline]
+    pub fn as_rel_path(&self) -> &Utf8RelPath<T> {
+        Utf8RelPath::new_unchecked(&self.inner)
+    }
+
+    /// Extends `self` with `path`, which keeps `self` relative.
+    pub fn push<P: AsRef<Utf8RelPath<T>>>(&mut self, path: P) {
+        self.inner.push(path.as_ref().as_path());
+    }
+
+    /// Truncates `self` to [`Utf8RelPath::parent`], returning false if `self` is empty.
+    pub fn pop(&mut self) -> bool {
+        self.inner.pop()
+    }
+
+    /// Consumes the `Utf8RelPathBuf`, yielding its [`Utf8PathBuf`].
+    #[inline]
+    pub fn into_path_buf(self) -> Utf8PathBuf<T> {
+        self.inner
+    }
+}
+
+/// Implements the traits shared by a wrapper around [`Utf8Path`] and its owned counterpart
+macro_rules! impl_path_wrapper {
+    ($path:ident, $buf:ident, $as_path:ident) => {
+        impl<T> fmt::Debug for $path<T>
+        where
+            T: for<'enc> Utf8Encoding<'enc>,
+        {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.debug_tuple(stringify!($path))
+                    .field(&&self.inner)
+                    .finish()
+            }
+        }
+
+        impl<T> fmt::Debug for $buf<T>
+        where
+            T: for<'enc> Utf8Encoding<'enc>,
+        {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.debug_tuple(stringify!($buf)).field(&self.inner).finish()
+            }
+        }
+
+        impl<T> Clone for $buf<T>
+        where
+            T: for<'enc> Utf8Encoding<'enc>,
+        {
+            #[inline]
+            fn clone(&self) -> Self {
+                Self {
+                    inner: self.inner.clone(),
+                }
+            }
+        }
+
+        impl<T> Deref for $path<T>
+        where
+            T: for<'enc> Utf8Encoding<'enc>,
+        {
+            type Target = Utf8Path<T>;
+
+            #[inline]
+            fn deref(&self) -> &Self::Target {
+                &self.inner
+            }
+        }
+
+        impl<T> Deref for $buf<T>
+        where
+            T: for<'enc> Utf8Encoding<'enc>,
+        {
+            type Target = $path<T>;
+
+            #[inline]
+            fn deref(&self) -> &Self::Target {
+                self.$as_path()
+            }
+        }
+
+        impl<T> AsRef<$path<T>> for $path<T>
+        where
+            T: for<'enc> Utf8Encoding<'enc>,
+        {
+            #[inline]
+            fn as_ref(&self) -> &$path<T> {
+                self
+            }
+        }
+
+        impl<T> AsRef<$path<T>> for $buf<T>
+        where
+            T: for<'enc> Utf8Encoding<'enc>,
+        {
+            #[inline]
+            fn as_ref(&self) -> &$path<T> {
+                self.$as_path()
+            }
+        }
+
+        impl<T> AsRef<Utf8Path<T>> for $path<T>
+        where
+            T: for<'enc> Utf8Encoding<'enc>,
+        {
+            #[inline]
+            fn as_ref(&self) -> &Utf8Path<T> {
+                &self.inner
+            }
+        }
+
+        impl<T> AsRef<Utf8Path<T>> for $buf<T>
+        where
+            T: for<'enc> Utf8Encoding<'enc>,
+        {
+            #[inline]
+            fn as_ref(&self) -> &Utf8Path<T> {
+                &self.inner
+            }
+        }
+
+        impl<T> AsRef<str> for $path<T>
+        where
+            T: for<'enc> Utf8Encoding<'enc>,
+        {
+            #[inline]
+            fn as_ref(&self) -> &str {
+                self.inner.as_str()
+            }
+        }
+
+        impl<T> AsRef<str> for $buf<T>
+        where
+            T: for<'enc> Utf8Encoding<'enc>,
+        {
+            #[inline]
+            fn as_ref(&self) -> &str {
+                self.inner.as_str()
+            }
+        }
+
+        impl<T> Borrow<$path<T>> for $buf<T>
+        where
+            T: for<'enc> Utf8Encoding<'enc>,
+        {
+            #[inline]
+            fn borrow(&self) -> &$path<T> {
+                self.$as_path()
+            }
+        }
+
+        impl<T> ToOwned for $path<T>
+        where
+            T: for<'enc> Utf8Encoding<'enc>,
+        {
+            type Owned = $buf<T>;
+
+            #[inline]
+            fn to_owned(&self) -> Self::Owned {
+                self.to_path_buf()
+            }
+        }
+
+        impl<T> PartialEq for $path<T>
+        where
+            T: for<'enc> Utf8Encoding<'enc>,
+        {
+            #[inline]
+            fn eq(&self, other: &Self) -> bool {
+                self.inner == other.inner
+            }
+        }
+
+        impl<T> Eq for $path<T> where T: for<'enc> Utf8Encoding<'enc> {}
+
+        impl<T> PartialEq for $buf<T>
+        where
+            T: for<'enc> Utf8Encoding<'enc>,
+        {
+            #[inline]
+            fn eq(&self, other: &Self) -> bool {
+                self.inner == other.inner
+            }
+        }
+
+        impl<T> Eq for $buf<T> where T: for<'enc> Utf8Encoding<'enc> {}
+
+        impl<T> PartialOrd for $path<T>
+        where
+            T: for<'enc> Utf8Encoding<'enc>,
+        {
+            #[inline]
+            fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl<T> Ord for $path<T>
+        where
+            T: for<'enc> Utf8Encoding<'enc>,
+        {
+            #[inline]
+            fn cmp(&self, other: &Self) -> cmp::Ordering {
+                self.inner.cmp(&other.inner)
+            }
+        }
+
+        impl<T> PartialOrd for $buf<T>
+        where
+            T: for<'enc> Utf8Encoding<'enc>,
+        {
+            #[inline]
+            fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl<T> Ord for $buf<T>
+        where
+            T: for<'enc> Utf8Encoding<'enc>,
+        {
+            #[inline]
+            fn cmp(&self, other: &Self) -> cmp::Ordering {
+                self.inner.cmp(&other.inner)
+            }
+        }
+
+        impl<T> Hash for $path<T>
+        where
+            T: for<'enc> Utf8Encoding<'enc>,
+        {
+            #[inline]
+            fn hash<H: Hasher>(&self, h: &mut H) {
+                self.inner.hash(h)
+            }
+        }
+
+        impl<T> Hash for $buf<T>
+        where
+            T: for<'enc> Utf8Encoding<'enc>,
+        {
+            #[inline]
+            fn hash<H: Hasher>(&self, h: &mut H) {
+                self.inner.hash(h)
+            }
+        }
+
+        impl<'a, T> TryFrom<&'a Utf8Path<T>> for &'a $path<T>
+        where
+            T: for<'enc> Utf8Encoding<'enc>,
+        {
+            type Error = PathKindError;
+
+            #[inline]
+            fn try_from(path: &'a Utf8Path<T>) -> Result<Self, Self::Error> {
+                $path::new(path.as_str())
+            }
+        }
+
+        impl<T> TryFrom<Utf8PathBuf<T>> for $buf<T>
+        where
+            T: for<'enc> Utf8Encoding<'enc>,
+        {
+            type Error = PathKindError;
+
+            #[inline]
+            fn try_from(path: Utf8PathBuf<T>) -> Result<Self, Self::Error> {
+                $path::<T>::new(path.as_str())?;
+                Ok(Self { inner: path })
+            }
+        }
+
+        impl<T> From<$buf<T>> for Utf8PathBuf<T>
+        where
+            T: for<'enc> Utf8Encoding<'enc>,
+        {
+            #[inline]
+            fn from(path: $buf<T>) -> Self {
+                path.inner
+            }
+        }
+    };
+}
+
+impl_path_wrapper!(Utf8AbsPath, Utf8AbsPathBuf, as_abs_path);
+impl_path_wrapper!(Utf8RelPath, Utf8RelPathBuf, as_rel_path);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Utf8Plan9Encoding, Utf8UnixEncoding, Utf8WindowsEncoding};
+
+    #[test]
+    fn rel_path_should_reject_anything_that_would_replace_part_of_a_path() {
+        for path in ["", ".", "..", "a", "a/b", "../a"] {
+            assert!(Utf8RelPath::<Utf8UnixEncoding>::new(path).is_ok(), "{path}");
+            assert!(
+                Utf8RelPath::<Utf8WindowsEncoding>::new(path).is_ok(),
+                "{path}"
+            );
+        }
+
+        for path in ["/", "/a"] {
+            assert!(
+                Utf8RelPath::<Utf8UnixEncoding>::new(path).is_err(),
+                "{path}"
+            );
+        }
+
+        for path in [r"\a", r"C:", r"C:a", r"\\server\share", r"\\?\a"] {
+            assert!(
+                Utf8RelPath::<Utf8WindowsEncoding>::new(path).is_err(),
+                "{path}"
+            );
+        }
+
+        assert!(Utf8RelPath::<Utf8Plan9Encoding>::new("#c/cons").is_err());
+    }
+
+    #[test]
+    fn abs_path_should_stay_absolute_through_parents_and_joins() {
+        for path in [r"C:\a\b", r"\\server\share\a", r"\\?\C:\a", r"\\.\COM1\a"] {
+            let path = Utf8AbsPath::<Utf8WindowsEncoding>::new(path).unwrap();
+            let mut parent = Some(path);
+            while let Some(path) = parent {
+                assert!(path.is_absolute(), "{path:?}");
+                assert!(path.join(Utf8RelPath::new(r"..\x").unwrap()).is_absolute());
+                parent = path.parent();
+            }
+        }
+
+        let mut path = Utf8AbsPathBuf::<Utf8UnixEncoding>::new("/a/b").unwrap();
+        assert!(path.pop());
+        assert!(path.pop());
+        assert!(!path.pop());
+        assert_eq!(path.as_str(), "/");
+    }
+}
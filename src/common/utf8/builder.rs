@@ -0,0 +1,298 @@
+use core::fmt;
+
+use crate::no_std_compat::*;
+use crate::{SegmentError, SegmentErrorKind, Utf8Encoding, Utf8Path, Utf8PathBuf};
+
+/// A builder assembling a path out of individually validated segments.
+///
+/// Each segment is held to the same rules as [`Utf8PathBuf::extend_checked`], being exactly one
+/// normal component, and then to every validator added through [`Utf8PathBuilder::validate`].
+/// Nothing is checked until [`Utf8PathBuilder::build`], which reports the first segment to fail, so
+/// segments taken straight from user input can be chained without handling each one in turn.
+///
+/// # Examples
+///
+/// ```
+/// use typed_path::{Utf8PathBuilder, Utf8PathBuf, SegmentErrorKind, Utf8UnixEncoding};
+///
+/// // NOTE: A path cannot be created on its own without a defined encoding
+/// let builder = Utf8PathBuilder::<Utf8UnixEncoding>::new()
+///     .root()
+///     .push_segment("srv")
+///     .push_segment("uploads")
+///     .push_segment("avatar")
+///     .extension("png")
+///     .validate(|segment| segment.len() <= 16);
+/// assert_eq!(builder.build(), Ok(Utf8PathBuf::from("/srv/uploads/avatar.png")));
+///
+/// let err = builder.push_segment("..").build().unwrap_err();
+/// assert_eq!((err.index(), err.kind()), (3, SegmentErrorKind::Traversal));
+/// ```
+pub struct Utf8PathBuilder<'a, T>
+where
+    T: for<'enc> Utf8Encoding<'enc>,
+{
+    base: Utf8PathBuf<T>,
+    segments: Vec<String>,
+    validators: Vec<Validator<'a>>,
+}
+
+impl<'a, T> Utf8PathBuilder<'a, T>
+where
+    T: for<'enc> Utf8Encoding<'enc>,
+{
+    /// Creates a builder for a relative path with no segments.
+    #[inline]
+    pub fn new() -> Self {
+        Self::from_base(Utf8PathBuf::new())
+    }
+
+    /// Creates a builder whose segments are pushed onto `base`, which is trusted as it is and
+    /// never validated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Utf8PathBuilder, Utf8PathBuf, Utf8WindowsEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// let path = Utf8PathBuilder::<Utf8WindowsEncoding>::from_base(r"C:\Users")
+    ///     .push_segment("me")
+    ///     .build();
+    /// assert_eq!(path, Ok(Utf8PathBuf::from(r"C:\Users\me")));
+    /// ```
+    pub fn from_base<P: Into<Utf8PathBuf<T>>>(base: P) -> Self {
+        Self {
+            base: base.into(),
+            segments: Vec::new(),
+            validators: Vec::new(),
+        }
+    }
+
+    /// Starts the path at a root directory, keeping any prefix of the base.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Utf8PathBuilder, Utf8PathBuf, Utf8WindowsEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// let path = Utf8PathBuilder::<Utf8WindowsEncoding>::from_base("D:")
+    ///     .root()
+    ///     .push_segment("data")
+    ///     .build();
+    /// assert_eq!(path, Ok(Utf8PathBuf::from(r"D:\data")));
+    /// ```
+    pub fn root(mut self) -> Self {
+        if !self.base.has_root() {
+            let mut separator = [0; 4];
+            self.base.push(&*T::SEPARATOR.encode_utf8(&mut separator));
+        }
+        self
+    }
+
+    /// Appends `segment` to the path, to be validated once the path is built.
+    pub fn push_segment<S: AsRef<str>>(mut self, segment: S) -> Self {
+        self.segments.push(segment.as_ref().to_string());
+        self
+    }
+
+    /// Replaces the extension of the last segment with `extension`, or removes it if
+    /// `extension` is empty, following [`Utf8Path::with_extension`].
+    ///
+    /// With no segments to extend, the path fails to build with [`SegmentErrorKind::Empty`].
+    pub fn extension<S: AsRef<str>>(mut self, extension: S) -> Self {
+        match self.segments.last_mut() {
+            Some(segment) => {
+                *segment = Utf8Path::<T>::new(segment.as_str())
+                    .with_extension(extension)
+                    .into_string();
+            }
+            None => self.segments.push(String::new()),
+        }
+        self
+    }
+
+    /// Adds a check that every segment must pass, where returning `false` rejects the segment
+    /// with [`SegmentErrorKind::Rejected`].
+    ///
+    /// Validators apply to every segment, including those pushed before the validator was added,
+    /// and only see segments that already passed the checks of [`Utf8PathBuf::extend_checked`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Utf8PathBuilder, SegmentErrorKind, Utf8UnixEncoding};
+    ///
+    /// let allowed = ["images", "logo.png"];
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// let err = Utf8PathBuilder::<Utf8UnixEncoding>::new()
+    ///     .push_segment("images")
+    ///     .push_segment("secret.png")
+    ///     .validate(|segment| allowed.iter().any(|name| *name == segment))
+    ///     .build()
+    ///     .unwrap_err();
+    /// assert_eq!((err.index(), err.kind()), (1, SegmentErrorKind::Rejected));
+    /// ```
+    pub fn validate<F>(mut self, validator: F) -> Self
+    where
+        F: Fn(&str) -> bool + 'a,
+    {
+        self.validators.push(Box::new(validator));
+        self
+    }
+
+    /// Validates every segment and builds the path.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`SegmentError`] for the first segment that is not exactly one normal component,
+    /// as described by [`Utf8PathBuf::extend_checked`], or that a validator rejects.
+    pub fn build(&self) -> Result<Utf8PathBuf<T>, SegmentError> {
+        let mut path = self.base.clone();
+        for (index, segment) in self.segments.iter().enumerate() {
+            let result = Utf8PathBuf::<T>::check_segment(segment).and_then(|_| {
+                if self.validators.iter().all(|validator| validator(segment)) {
+                    Ok(())
+                } else {
+                    Err(SegmentErrorKind::Rejected)
+                }
+            });
+
+            if let Err(kind) = result {
+                return Err(SegmentError { index, kind });
+            }
+            path.push(Utf8Path::new(segment));
+        }
+
+        Ok(path)
+    }
+}
+
+impl<'a, T> Default for Utf8PathBuilder<'a, T>
+where
+    T: for<'enc> Utf8Encoding<'enc>,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a, T> fmt::Debug for Utf8PathBuilder<'a, T>
+where
+    T: for<'enc> Utf8Encoding<'enc>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Utf8PathBuilder")
+            .field("base", &self.base)
+            .field(
+                "segments",
+                &self
+                    .segments
+                    .iter()
+                    .map(|segment| Utf8Path::<T>::new(segment))
+                    .collect::<Vec<_>>(),
+            )
+            .field("validators", &self.validators.len())
+            .finish()
+    }
+}
+
+/// Check added through `validate`, returning false to reject a segment
+type Validator<'a> = Box<dyn Fn(&str) -> bool + 'a>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Utf8UnixEncoding, Utf8WindowsEncoding};
+
+    #[test]
+    fn build_should_report_the_first_rejected_segment() {
+        let builder = Utf8PathBuilder::<Utf8UnixEncoding>::new()
+            .push_segment("a")
+            .push_segment("b/c")
+            .push_segment("");
+        assert_eq!(
+            builder.build(),
+            Err(SegmentError {
+                index: 1,
+                kind: SegmentErrorKind::Separator
+            })
+        );
+
+        let builder = Utf8PathBuilder::<Utf8WindowsEncoding>::new()
+            .push_segment("a")
+            .push_segment("C:");
+        assert_eq!(
+            builder.build(),
+            Err(SegmentError {
+                index: 1,
+                kind: SegmentErrorKind::Prefix
+            })
+        );
+    }
+
+    #[test]
+    fn validators_should_only_see_valid_segments() {
+        let builder = Utf8PathBuilder::<Utf8UnixEncoding>::new()
+            .push_segment("a")
+            .push_segment(".")
+            .validate(|segment| {
+                assert_ne!(segment, ".");
+                true
+            });
+        assert_eq!(
+            builder.build(),
+            Err(SegmentError {
+                index: 1,
+                kind: SegmentErrorKind::Traversal
+            })
+        );
+    }
+
+    #[test]
+    fn extension_should_replace_that_of_the_last_segment() {
+        let build = |builder: Utf8PathBuilder<'_, Utf8UnixEncoding>| builder.build();
+        assert_eq!(
+            build(Utf8PathBuilder::new().push_segment("a.tar").extension("gz")),
+            Ok(Utf8PathBuf::from("a.gz"))
+        );
+        assert_eq!(
+            build(Utf8PathBuilder::new().push_segment("a.txt").extension("")),
+            Ok(Utf8PathBuf::from("a"))
+        );
+        assert_eq!(
+            build(Utf8PathBuilder::new().push_segment("a").extension("b/c")),
+            Err(SegmentError {
+                index: 0,
+                kind: SegmentErrorKind::Separator
+            })
+        );
+        assert_eq!(
+            build(Utf8PathBuilder::new().extension("rs")),
+            Err(SegmentError {
+                index: 0,
+                kind: SegmentErrorKind::Empty
+            })
+        );
+    }
+
+    #[test]
+    fn root_should_keep_the_prefix_of_the_base() {
+        let build = |base: &str| {
+            Utf8PathBuilder::<Utf8WindowsEncoding>::from_base(base)
+                .root()
+                .push_segment("a")
+                .build()
+        };
+        assert_eq!(build(""), Ok(Utf8PathBuf::from(r"\a")));
+        assert_eq!(build("C:"), Ok(Utf8PathBuf::from(r"C:\a")));
+        assert_eq!(build(r"C:\b"), Ok(Utf8PathBuf::from(r"C:\b\a")));
+        assert_eq!(
+            build(r"\\server\share"),
+            Ok(Utf8PathBuf::from(r"\\server\share\a"))
+        );
+    }
+}
@@ -1,7 +1,9 @@
+mod adapters;
 mod component;
 
 use core::{cmp, fmt, iter};
 
+pub use adapters::*;
 pub use component::*;
 
 use crate::private;
@@ -51,4 +53,130 @@ pub trait Utf8Components<'a>:
     ///     * has a prefix followed by a separator, e.g., `c:\windows` but not `c:windows`
     ///     * has any non-disk prefix, e.g., `\\server\share`
     fn has_root(&self) -> bool;
+
+    /// Creates an iterator over at most the next `n` components that can still report the rest
+    /// of the path through [`Utf8TakeDepth::as_path`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Utf8Components, Utf8UnixComponent, Utf8UnixPath};
+    ///
+    /// let components = Utf8UnixPath::new("/usr/local/bin").components().take_depth(2);
+    /// assert_eq!(
+    ///     components.collect::<Vec<_>>(),
+    ///     [Utf8UnixComponent::RootDir, Utf8UnixComponent::Normal("usr")],
+    /// );
+    /// ```
+    fn take_depth(self, n: usize) -> Utf8TakeDepth<Self> {
+        Utf8TakeDepth::new(self, n)
+    }
+
+    /// Creates an iterator that skips any prefix and root at the start of the path.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Utf8Components, Utf8WindowsComponent, Utf8WindowsPath};
+    ///
+    /// let components = Utf8WindowsPath::new(r"\\server\share\dir").components().skip_root();
+    /// assert_eq!(components.collect::<Vec<_>>(), [Utf8WindowsComponent::Normal("dir")]);
+    /// ```
+    fn skip_root(self) -> Utf8SkipRoot<Self> {
+        Utf8SkipRoot::new(self)
+    }
+
+    /// Creates an iterator over the strings of only the normal components, skipping any prefix,
+    /// root, current, or parent components.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Utf8Components, Utf8UnixPath};
+    ///
+    /// let components = Utf8UnixPath::new("/a/./b/../c").components().normal_only();
+    /// assert_eq!(components.collect::<Vec<_>>(), ["a", "b", "c"]);
+    /// ```
+    fn normal_only(self) -> Utf8NormalOnly<'a, Self> {
+        Utf8NormalOnly::new(self)
+    }
+
+    /// Creates an iterator over every run of `n` consecutive components, like [`slice::windows`].
+    ///
+    /// Each window is itself an iterator over its components, and no windows are yielded if the
+    /// path has fewer than `n` components.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Utf8Component, Utf8Components, Utf8UnixPath};
+    ///
+    /// let path = Utf8UnixPath::new("a/b/c");
+    /// let windows: Vec<Vec<_>> = path
+    ///     .components()
+    ///     .component_windows(2)
+    ///     .map(|window| window.map(|c| c.as_str()).collect())
+    ///     .collect();
+    /// assert_eq!(windows, [["a", "b"], ["b", "c"]]);
+    /// ```
+    fn component_windows(self, n: usize) -> Utf8ComponentWindows<Self> {
+        Utf8ComponentWindows::new(self, n)
+    }
+
+    /// Creates an iterator over each component alongside the component that follows it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Utf8Component, Utf8Components, Utf8UnixPath};
+    ///
+    /// // Find the packages installed within a node_modules directory
+    /// let path = Utf8UnixPath::new("app/node_modules/left-pad/index.js");
+    /// let packages: Vec<_> = path
+    ///     .components()
+    ///     .component_pairs()
+    ///     .filter(|(dir, _)| dir.as_str() == "node_modules")
+    ///     .map(|(_, pkg)| pkg.as_str())
+    ///     .collect();
+    /// assert_eq!(packages, ["left-pad"]);
+    /// ```
+    fn component_pairs(self) -> Utf8ComponentPairs<Self> {
+        Utf8ComponentPairs::new(self)
+    }
+}
+
+/// Extracts the portion of the path remaining for iteration without the trailing separators and
+/// current directory components that will never be yielded, which is the slice returned by
+/// `std::path::Components::as_path`.
+pub(crate) fn remaining_str<'a, C>(components: &C) -> &'a str
+where
+    C: Utf8Components<'a>,
+{
+    let remaining = components.as_str();
+    let mut rest = components.clone();
+    let last = match rest.next_back() {
+        Some(component) => component,
+        None => return &remaining[..0],
+    };
+
+    // The last component starts somewhere after the bytes left once it has been consumed
+    let start = rest.as_str().len();
+    let s = last.as_str();
+    let end = if last.is_normal() {
+        s.as_ptr() as usize - remaining.as_ptr() as usize + s.len()
+    } else if last.is_current() || last.is_parent() {
+        remaining[start..]
+            .find(s)
+            .map(|i| start + i + s.len())
+            .unwrap_or(remaining.len())
+    } else {
+        // Prefix and root can only be preceded by each other, never by separators
+        start + last.len()
+    };
+
+    &remaining[..end]
 }
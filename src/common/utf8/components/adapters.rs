@@ -0,0 +1,287 @@
+use core::iter::FusedIterator;
+use core::marker::PhantomData;
+
+use super::remaining_str;
+use crate::{Utf8Component, Utf8Components, Utf8Encoding, Utf8Path};
+
+/// An iterator over at most `n` [`Utf8Component`]s, created by [`Utf8Components::take_depth`].
+///
+/// Unlike [`Iterator::take`], the rest of the path can still be retrieved through
+/// [`Utf8TakeDepth::as_path`].
+#[derive(Clone, Debug)]
+pub struct Utf8TakeDepth<I> {
+    iter: I,
+    remaining: usize,
+}
+
+impl<I> Utf8TakeDepth<I> {
+    pub(crate) fn new(iter: I, n: usize) -> Self {
+        Self { iter, remaining: n }
+    }
+}
+
+impl<'a, I> Utf8TakeDepth<I>
+where
+    I: Utf8Components<'a>,
+{
+    /// Extracts a string slice corresponding to the portion of the path not yet consumed, including
+    /// any components beyond the depth limit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Utf8Components, Utf8Path, Utf8UnixEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// let mut components = Utf8Path::<Utf8UnixEncoding>::new("/srv/www/site/index.html")
+    ///     .components()
+    ///     .take_depth(2);
+    /// assert_eq!(components.by_ref().count(), 2);
+    ///
+    /// assert_eq!(Utf8Path::<Utf8UnixEncoding>::new("www/site/index.html"), components.as_path());
+    /// ```
+    pub fn as_path<T>(&self) -> &'a Utf8Path<T>
+    where
+        T: for<'enc> Utf8Encoding<'enc>,
+    {
+        Utf8Path::new(remaining_str(&self.iter))
+    }
+}
+
+impl<I> Iterator for Utf8TakeDepth<I>
+where
+    I: Iterator,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        self.remaining -= 1;
+        self.iter.next()
+    }
+}
+
+impl<I> FusedIterator for Utf8TakeDepth<I> where I: FusedIterator {}
+
+/// An iterator over the [`Utf8Component`]s of a path after any prefix and root, created by
+/// [`Utf8Components::skip_root`].
+#[derive(Clone, Debug)]
+pub struct Utf8SkipRoot<I> {
+    iter: I,
+}
+
+impl<'a, I> Utf8SkipRoot<I>
+where
+    I: Utf8Components<'a>,
+{
+    pub(crate) fn new(mut iter: I) -> Self {
+        let mut rest = iter.clone();
+        while let Some(component) = rest.next() {
+            // Anything other than a normal, current, or parent component is a prefix or root
+            if component.is_normal() || component.is_current() || component.is_parent() {
+                break;
+            }
+            iter = rest.clone();
+        }
+
+        Self { iter }
+    }
+
+    /// Extracts a string slice corresponding to the portion of the path remaining for iteration,
+    /// which never includes a prefix or root.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Utf8Components, Utf8Path, Utf8WindowsEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// let components = Utf8Path::<Utf8WindowsEncoding>::new(r"C:\Users\me")
+    ///     .components()
+    ///     .skip_root();
+    /// assert_eq!(Utf8Path::<Utf8WindowsEncoding>::new(r"Users\me"), components.as_path());
+    /// ```
+    pub fn as_path<T>(&self) -> &'a Utf8Path<T>
+    where
+        T: for<'enc> Utf8Encoding<'enc>,
+    {
+        Utf8Path::new(remaining_str(&self.iter))
+    }
+}
+
+impl<I> Iterator for Utf8SkipRoot<I>
+where
+    I: Iterator,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next()
+    }
+}
+
+impl<I> DoubleEndedIterator for Utf8SkipRoot<I>
+where
+    I: DoubleEndedIterator,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back()
+    }
+}
+
+impl<I> FusedIterator for Utf8SkipRoot<I> where I: FusedIterator {}
+
+/// An iterator over the strings of only the normal [`Utf8Component`]s of a path, created by
+/// [`Utf8Components::normal_only`].
+#[derive(Clone, Debug)]
+pub struct Utf8NormalOnly<'a, I> {
+    iter: I,
+    _marker: PhantomData<&'a str>,
+}
+
+impl<I> Utf8NormalOnly<'_, I> {
+    pub(crate) fn new(iter: I) -> Self {
+        Self {
+            iter,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<'a, I> Utf8NormalOnly<'a, I>
+where
+    I: Utf8Components<'a>,
+{
+    /// Extracts a string slice corresponding to the portion of the path remaining for iteration.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Utf8Components, Utf8Path, Utf8UnixEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// let mut components = Utf8Path::<Utf8UnixEncoding>::new("/a/../b/./c")
+    ///     .components()
+    ///     .normal_only();
+    /// assert_eq!(components.next(), Some("a"));
+    /// assert_eq!(components.next(), Some("b"));
+    ///
+    /// assert_eq!(Utf8Path::<Utf8UnixEncoding>::new("c"), components.as_path());
+    /// ```
+    pub fn as_path<T>(&self) -> &'a Utf8Path<T>
+    where
+        T: for<'enc> Utf8Encoding<'enc>,
+    {
+        Utf8Path::new(remaining_str(&self.iter))
+    }
+}
+
+impl<'a, I> Iterator for Utf8NormalOnly<'a, I>
+where
+    I: Utf8Components<'a>,
+{
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter
+            .by_ref()
+            .find(|c| c.is_normal())
+            .map(|c| c.as_str())
+    }
+}
+
+impl<'a, I> DoubleEndedIterator for Utf8NormalOnly<'a, I>
+where
+    I: Utf8Components<'a>,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter
+            .by_ref()
+            .rfind(|c| c.is_normal())
+            .map(|c| c.as_str())
+    }
+}
+
+impl<'a, I> FusedIterator for Utf8NormalOnly<'a, I> where I: Utf8Components<'a> {}
+
+/// An iterator over overlapping windows of `n` [`Utf8Component`]s, created by
+/// [`Utf8Components::component_windows`].
+///
+/// Each window is a [`Utf8TakeDepth`] over the components it covers, so no components are
+/// collected.
+#[derive(Clone, Debug)]
+pub struct Utf8ComponentWindows<I> {
+    iter: I,
+    n: usize,
+}
+
+impl<I> Utf8ComponentWindows<I> {
+    pub(crate) fn new(iter: I, n: usize) -> Self {
+        assert!(n != 0, "window size must be non-zero");
+        Self { iter, n }
+    }
+}
+
+impl<I> Iterator for Utf8ComponentWindows<I>
+where
+    I: Iterator + Clone,
+{
+    type Item = Utf8TakeDepth<I>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.iter.clone().take(self.n).count() < self.n {
+            return None;
+        }
+
+        let window = Utf8TakeDepth::new(self.iter.clone(), self.n);
+        self.iter.next();
+        Some(window)
+    }
+}
+
+impl<I> FusedIterator for Utf8ComponentWindows<I> where I: Iterator + Clone {}
+
+/// An iterator over each [`Utf8Component`] alongside the one following it, created by
+/// [`Utf8Components::component_pairs`].
+#[derive(Clone, Debug)]
+pub struct Utf8ComponentPairs<I>
+where
+    I: Iterator,
+{
+    iter: I,
+    prev: Option<I::Item>,
+}
+
+impl<I> Utf8ComponentPairs<I>
+where
+    I: Iterator,
+{
+    pub(crate) fn new(mut iter: I) -> Self {
+        let prev = iter.next();
+        Self { iter, prev }
+    }
+}
+
+impl<I> Iterator for Utf8ComponentPairs<I>
+where
+    I: Iterator,
+    I::Item: Clone,
+{
+    type Item = (I::Item, I::Item);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let next = self.iter.next()?;
+        let prev = self.prev.replace(next.clone())?;
+        Some((prev, next))
+    }
+}
+
+impl<I> FusedIterator for Utf8ComponentPairs<I>
+where
+    I: FusedIterator,
+    I::Item: Clone,
+{
+}
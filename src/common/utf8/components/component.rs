@@ -1,6 +1,6 @@
 use core::fmt;
 
-use crate::private;
+use crate::{private, ComponentKind};
 
 /// Interface representing a component in a [`Utf8Path`]
 ///
@@ -102,4 +102,46 @@ pub trait Utf8Component<'a>:
 
     /// Returns a current directory [`Utf8Component`].
     fn current() -> Self;
+
+    /// Returns the [`ComponentKind`] of this component, for code that is generic over encodings.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{ComponentKind, Utf8Component, Utf8Components, Utf8UnixPath};
+    ///
+    /// let kinds: Vec<_> = Utf8UnixPath::new("/./a").components().map(|c| c.kind()).collect();
+    /// assert_eq!(kinds, [ComponentKind::RootDir, ComponentKind::Normal]);
+    /// ```
+    fn kind(&self) -> ComponentKind {
+        if self.is_normal() {
+            ComponentKind::Normal
+        } else if self.is_parent() {
+            ComponentKind::ParentDir
+        } else if self.is_current() {
+            ComponentKind::CurDir
+        } else if *self == Self::root() {
+            ComponentKind::RootDir
+        } else {
+            ComponentKind::Prefix
+        }
+    }
+
+    /// Returns the string of this component if it is a normal component.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Utf8Component, Utf8UnixComponent};
+    ///
+    /// assert_eq!(Utf8UnixComponent::Normal("file.txt").as_normal_str(), Some("file.txt"));
+    /// assert_eq!(Utf8UnixComponent::ParentDir.as_normal_str(), None);
+    /// ```
+    fn as_normal_str(&self) -> Option<&'a str> {
+        if self.is_normal() {
+            Some(self.as_str())
+        } else {
+            None
+        }
+    }
 }
@@ -0,0 +1,363 @@
+use alloc::borrow::Cow;
+use core::borrow::Borrow;
+use core::hash::{Hash, Hasher};
+use core::marker::PhantomData;
+use core::ops::Deref;
+use core::{cmp, fmt};
+
+use crate::{Utf8Component, Utf8Encoding, Utf8Path, Utf8PathBuf};
+
+/// A clone-on-write [`Utf8Path`], holding either a borrowed path or an owned path.
+///
+/// This is the UTF-8 counterpart of [`CowPath`](crate::CowPath). Functions that only sometimes
+/// rewrite a path, like [`Utf8Path::normalize_cow`], return this to avoid allocating when the
+/// input is already in the desired form. The full [`Utf8Path`] API is available through
+/// [`Deref`], and [`CowUtf8Path::into_owned`] produces a [`Utf8PathBuf`] when one is needed.
+///
+/// # Examples
+///
+/// ```
+/// use typed_path::{CowUtf8Path, Utf8Path, Utf8UnixEncoding};
+///
+/// // NOTE: A path cannot be created on its own without a defined encoding
+/// let path = Utf8Path::<Utf8UnixEncoding>::new("/etc/hosts");
+/// let cow = path.normalize_cow();
+/// assert!(cow.is_borrowed());
+/// assert_eq!(cow.file_name(), Some("hosts"));
+///
+/// let cow: CowUtf8Path<Utf8UnixEncoding> = Utf8Path::new("/etc/../hosts").normalize_cow();
+/// assert!(cow.is_owned());
+/// assert_eq!(cow.into_owned(), Utf8Path::new("/hosts"));
+/// ```
+pub struct CowUtf8Path<'a, T>
+where
+    T: for<'enc> Utf8Encoding<'enc>,
+{
+    _encoding: PhantomData<T>,
+    inner: Cow<'a, str>,
+}
+
+impl<'a, T> CowUtf8Path<'a, T>
+where
+    T: for<'enc> Utf8Encoding<'enc>,
+{
+    /// Wraps a borrowed [`Utf8Path`].
+    #[inline]
+    pub fn borrowed(path: &'a Utf8Path<T>) -> Self {
+        Self {
+            _encoding: PhantomData,
+            inner: Cow::Borrowed(path.as_str()),
+        }
+    }
+
+    /// Wraps an owned [`Utf8PathBuf`].
+    #[inline]
+    pub fn owned(path: Utf8PathBuf<T>) -> Self {
+        Self {
+            _encoding: PhantomData,
+            inner: Cow::Owned(path.into_string()),
+        }
+    }
+
+    /// Returns true if the path is borrowed.
+    #[inline]
+    pub fn is_borrowed(&self) -> bool {
+        matches!(self.inner, Cow::Borrowed(_))
+    }
+
+    /// Returns true if the path is owned.
+    #[inline]
+    pub fn is_owned(&self) -> bool {
+        !self.is_borrowed()
+    }
+
+    /// Coerces to a [`Utf8Path`] slice.
+    #[inline]
+    pub fn as_path(&self) -> &Utf8Path<T> {
+        Utf8Path::new(&*self.inner)
+    }
+
+    /// Extracts the owned [`Utf8PathBuf`], cloning the path if it is borrowed.
+    #[inline]
+    pub fn into_owned(self) -> Utf8PathBuf<T> {
+        Utf8PathBuf::from(self.inner.into_owned())
+    }
+
+    /// Consumes the path, yielding the underlying string.
+    #[inline]
+    pub fn into_inner(self) -> Cow<'a, str> {
+        self.inner
+    }
+}
+
+/// Returns true if [`Utf8Path::normalize`] would produce the bytes of `path` unchanged, erring on
+/// the side of false for anything other than components joined by the primary separator
+pub(crate) fn is_normalized<T>(path: &Utf8Path<T>) -> bool
+where
+    T: for<'enc> Utf8Encoding<'enc>,
+{
+    let bytes = path.as_str().as_bytes();
+    let mut pos = 0;
+    let mut after_normal = false;
+
+    for component in path.components() {
+        if component.is_current() || component.is_parent() {
+            return false;
+        }
+
+        // Normalizing pushes each component in turn, which only adds a separator after a normal
+        // component
+        if after_normal {
+            if bytes.get(pos) != Some(&(T::SEPARATOR as u8)) {
+                return false;
+            }
+            pos += 1;
+        }
+
+        if !bytes[pos..].starts_with(component.as_str().as_bytes()) {
+            return false;
+        }
+        pos += component.len();
+        after_normal = component.is_normal();
+    }
+
+    pos == bytes.len()
+}
+
+impl<T> Clone for CowUtf8Path<'_, T>
+where
+    T: for<'enc> Utf8Encoding<'enc>,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            _encoding: PhantomData,
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<T> fmt::Debug for CowUtf8Path<'_, T>
+where
+    T: for<'enc> Utf8Encoding<'enc>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CowUtf8Path")
+            .field("_encoding", &T::label())
+            .field("inner", &self.inner)
+            .finish()
+    }
+}
+
+impl<T> Deref for CowUtf8Path<'_, T>
+where
+    T: for<'enc> Utf8Encoding<'enc>,
+{
+    type Target = Utf8Path<T>;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        self.as_path()
+    }
+}
+
+impl<T> AsRef<Utf8Path<T>> for CowUtf8Path<'_, T>
+where
+    T: for<'enc> Utf8Encoding<'enc>,
+{
+    #[inline]
+    fn as_ref(&self) -> &Utf8Path<T> {
+        self.as_path()
+    }
+}
+
+impl<T> AsRef<str> for CowUtf8Path<'_, T>
+where
+    T: for<'enc> Utf8Encoding<'enc>,
+{
+    #[inline]
+    fn as_ref(&self) -> &str {
+        self.inner.as_ref()
+    }
+}
+
+impl<T> Borrow<Utf8Path<T>> for CowUtf8Path<'_, T>
+where
+    T: for<'enc> Utf8Encoding<'enc>,
+{
+    #[inline]
+    fn borrow(&self) -> &Utf8Path<T> {
+        self.as_path()
+    }
+}
+
+impl<T> PartialEq for CowUtf8Path<'_, T>
+where
+    T: for<'enc> Utf8Encoding<'enc>,
+{
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.as_path() == other.as_path()
+    }
+}
+
+impl<T> Eq for CowUtf8Path<'_, T> where T: for<'enc> Utf8Encoding<'enc> {}
+
+impl<T> PartialOrd for CowUtf8Path<'_, T>
+where
+    T: for<'enc> Utf8Encoding<'enc>,
+{
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T> Ord for CowUtf8Path<'_, T>
+where
+    T: for<'enc> Utf8Encoding<'enc>,
+{
+    #[inline]
+    fn cmp(&self, other: &Self) -> cmp::Ordering {
+        self.as_path().cmp(other.as_path())
+    }
+}
+
+impl<T> Hash for CowUtf8Path<'_, T>
+where
+    T: for<'enc> Utf8Encoding<'enc>,
+{
+    #[inline]
+    fn hash<H: Hasher>(&self, h: &mut H) {
+        self.as_path().hash(h)
+    }
+}
+
+impl<'a, T> From<&'a Utf8Path<T>> for CowUtf8Path<'a, T>
+where
+    T: for<'enc> Utf8Encoding<'enc>,
+{
+    #[inline]
+    fn from(path: &'a Utf8Path<T>) -> Self {
+        Self::borrowed(path)
+    }
+}
+
+impl<'a, T> From<&'a Utf8PathBuf<T>> for CowUtf8Path<'a, T>
+where
+    T: for<'enc> Utf8Encoding<'enc>,
+{
+    #[inline]
+    fn from(path: &'a Utf8PathBuf<T>) -> Self {
+        Self::borrowed(path.as_path())
+    }
+}
+
+impl<T> From<Utf8PathBuf<T>> for CowUtf8Path<'_, T>
+where
+    T: for<'enc> Utf8Encoding<'enc>,
+{
+    #[inline]
+    fn from(path: Utf8PathBuf<T>) -> Self {
+        Self::owned(path)
+    }
+}
+
+impl<'a, T> From<Cow<'a, Utf8Path<T>>> for CowUtf8Path<'a, T>
+where
+    T: for<'enc> Utf8Encoding<'enc>,
+{
+    #[inline]
+    fn from(path: Cow<'a, Utf8Path<T>>) -> Self {
+        match path {
+            Cow::Borrowed(path) => Self::borrowed(path),
+            Cow::Owned(path) => Self::owned(path),
+        }
+    }
+}
+
+impl<'a, T> From<CowUtf8Path<'a, T>> for Cow<'a, Utf8Path<T>>
+where
+    T: for<'enc> Utf8Encoding<'enc>,
+{
+    #[inline]
+    fn from(path: CowUtf8Path<'a, T>) -> Self {
+        match path.inner {
+            Cow::Borrowed(s) => Cow::Borrowed(Utf8Path::new(s)),
+            Cow::Owned(s) => Cow::Owned(Utf8PathBuf::from(s)),
+        }
+    }
+}
+
+impl<T> From<CowUtf8Path<'_, T>> for Utf8PathBuf<T>
+where
+    T: for<'enc> Utf8Encoding<'enc>,
+{
+    #[inline]
+    fn from(path: CowUtf8Path<'_, T>) -> Self {
+        path.into_owned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Utf8Plan9Encoding, Utf8PowerShellEncoding, Utf8UnixEncoding, Utf8WindowsEncoding};
+
+    fn check<T>(paths: &[&str])
+    where
+        T: for<'enc> Utf8Encoding<'enc>,
+    {
+        for path in paths {
+            let path = Utf8Path::<T>::new(path);
+            if is_normalized(path) {
+                assert_eq!(path.normalize().as_str(), path.as_str(), "{path:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn is_normalized_should_only_pass_paths_that_normalize_to_themselves() {
+        let paths = [
+            "",
+            ".",
+            "..",
+            "a",
+            "a/",
+            "a//b",
+            "/",
+            "//",
+            "/a",
+            "/a/b",
+            "a/./b",
+            "a/../b",
+            r"C:",
+            r"C:a",
+            r"C:\",
+            r"C:\a\b",
+            r"C:/a",
+            r"\\server\share",
+            r"\\server\share\a",
+            r"\\?\C:\a\.",
+            "#c",
+            "#c/cons",
+            "HKLM:",
+            r"HKLM:\Software",
+            r"FileSystem::C:\a",
+        ];
+
+        check::<Utf8UnixEncoding>(&paths);
+        check::<Utf8WindowsEncoding>(&paths);
+        check::<Utf8Plan9Encoding>(&paths);
+        check::<Utf8PowerShellEncoding>(&paths);
+
+        assert!(is_normalized(Utf8Path::<Utf8UnixEncoding>::new("/a/b")));
+        assert!(is_normalized(Utf8Path::<Utf8WindowsEncoding>::new(
+            r"C:\a\b"
+        )));
+        assert!(!is_normalized(Utf8Path::<Utf8UnixEncoding>::new("a//b")));
+        assert!(!is_normalized(Utf8Path::<Utf8WindowsEncoding>::new(
+            r"C:/a"
+        )));
+    }
+}
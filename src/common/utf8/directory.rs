@@ -0,0 +1,373 @@
+use alloc::borrow::ToOwned;
+use core::borrow::Borrow;
+use core::hash::{Hash, Hasher};
+use core::ops::Deref;
+use core::{cmp, fmt};
+
+use crate::common::PathKindError;
+use crate::{Utf8Component, Utf8Encoding, Utf8Path, Utf8PathBuf};
+
+/// A [`Utf8Path`] that denotes a directory, judged purely by how it is written.
+///
+/// Any path that is not a [`Utf8FilePath`] is a directory path, meaning that it is empty, ends with a
+/// separator, or ends with a component other than a normal one, such as `..` or a root. There is
+/// no way to join onto a [`Utf8FilePath`], so wrapping the base of a join in a `Utf8DirectoryPath`
+/// ensures nothing is ever placed beneath a file.
+///
+/// # Examples
+///
+/// ```
+/// use typed_path::{Utf8DirectoryPath, Utf8FilePath, PathKindError, Utf8UnixEncoding};
+///
+/// // NOTE: A path cannot be created on its own without a defined encoding
+/// let dir = Utf8DirectoryPath::<Utf8UnixEncoding>::new("/srv/app/").unwrap();
+/// let file = dir.join_file(Utf8FilePath::new("logs/today.txt").unwrap());
+/// assert_eq!(file.as_str(), "/srv/app/logs/today.txt");
+///
+/// assert_eq!(
+///     Utf8DirectoryPath::<Utf8UnixEncoding>::new("/srv/app"),
+///     Err(PathKindError::NotDirectory),
+/// );
+/// assert!(Utf8DirectoryPath::<Utf8UnixEncoding>::new("/srv/app/..").is_ok());
+/// ```
+#[repr(transparent)]
+pub struct Utf8DirectoryPath<T>
+where
+    T: for<'enc> Utf8Encoding<'enc>,
+{
+    inner: Utf8Path<T>,
+}
+
+/// A [`Utf8Path`] that denotes a file, judged purely by how it is written.
+///
+/// A file path ends with a normal component, written without any separator or `.` after it, so
+/// it always has a [`Utf8FilePath::file_name`] and a [`Utf8FilePath::parent`] directory.
+///
+/// # Examples
+///
+/// ```
+/// use typed_path::{Utf8FilePath, PathKindError, Utf8WindowsEncoding};
+///
+/// // NOTE: A path cannot be created on its own without a defined encoding
+/// let file = Utf8FilePath::<Utf8WindowsEncoding>::new(r"C:\logs\today.txt").unwrap();
+/// assert_eq!(file.file_name(), "today.txt");
+/// assert_eq!(file.parent().as_str(), r"C:\logs\");
+///
+/// assert_eq!(
+///     Utf8FilePath::<Utf8WindowsEncoding>::new(r"C:\logs\"),
+///     Err(PathKindError::NotFile),
+/// );
+/// ```
+#[repr(transparent)]
+pub struct Utf8FilePath<T>
+where
+    T: for<'enc> Utf8Encoding<'enc>,
+{
+    inner: Utf8Path<T>,
+}
+
+/// An owned [`Utf8DirectoryPath`] (akin to [`Utf8PathBuf`]).
+///
+/// # Examples
+///
+/// ```
+/// use typed_path::{Utf8DirectoryPathBuf, Utf8FilePath, Utf8UnixEncoding};
+///
+/// // NOTE: A pathbuf cannot be created on its own without a defined encoding
+/// let mut dir = Utf8DirectoryPathBuf::<Utf8UnixEncoding>::new("/srv");
+/// assert_eq!(dir.as_str(), "/srv/");
+///
+/// dir.push_dir("app");
+/// assert_eq!(dir.as_str(), "/srv/app/");
+///
+/// let file = dir.into_file(Utf8FilePath::new("config.toml").unwrap());
+/// assert_eq!(file.as_str(), "/srv/app/config.toml");
+/// ```
+pub struct Utf8DirectoryPathBuf<T>
+where
+    T: for<'enc> Utf8Encoding<'enc>,
+{
+    inner: Utf8PathBuf<T>,
+}
+
+/// An owned [`Utf8FilePath`] (akin to [`Utf8PathBuf`]).
+///
+/// # Examples
+///
+/// ```
+/// use typed_path::{Utf8FilePathBuf, Utf8UnixEncoding};
+///
+/// // NOTE: A pathbuf cannot be created on its own without a defined encoding
+/// let file = Utf8FilePathBuf::<Utf8UnixEncoding>::new("logs/today.txt").unwrap();
+/// assert_eq!(file.parent().as_str(), "logs/");
+/// assert!(Utf8FilePathBuf::<Utf8UnixEncoding>::new("logs/").is_err());
+/// ```
+pub struct Utf8FilePathBuf<T>
+where
+    T: for<'enc> Utf8Encoding<'enc>,
+{
+    inner: Utf8PathBuf<T>,
+}
+
+/// Returns the length of the file name ending `path`, if the path ends with a normal component
+/// written without anything after it
+fn file_name_len<T>(path: &Utf8Path<T>) -> Option<usize>
+where
+    T: for<'enc> Utf8Encoding<'enc>,
+{
+    let s = path.as_str();
+    match path.components().next_back() {
+        Some(component)
+            if component.is_normal()
+                && s.ends_with(component.as_str())
+                && matches!(s.chars().next_back(), Some(c) if !T::is_separator(c)) =>
+        {
+            Some(component.len())
+        }
+        _ => None,
+    }
+}
+
+impl<T> Utf8DirectoryPath<T>
+where
+    T: for<'enc> Utf8Encoding<'enc>,
+{
+    /// Wraps `s` as a `Utf8DirectoryPath` if it is not written as a [`Utf8FilePath`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PathKindError::NotDirectory`] if the path ends with a file name.
+    ///
+    /// [`PathKindError::NotDirectory`]: crate::PathKindError::NotDirectory
+    pub fn new<S: AsRef<str> + ?Sized>(s: &S) -> Result<&Self, PathKindError> {
+        let path = Utf8Path::new(s);
+        match file_name_len(path) {
+            Some(_) => Err(PathKindError::NotDirectory),
+            None => Ok(Self::new_unchecked(path)),
+        }
+    }
+
+    fn new_unchecked(path: &Utf8Path<T>) -> &Self {
+        unsafe { &*(path as *const Utf8Path<T> as *const Self) }
+    }
+
+    /// Coerces to a [`Utf8Path`] slice.
+    #[inline]
+    pub fn as_path(&self) -> &Utf8Path<T> {
+        &self.inner
+    }
+
+    /// Converts to an owned [`Utf8DirectoryPathBuf`].
+    pub fn to_path_buf(&self) -> Utf8DirectoryPathBuf<T> {
+        Utf8DirectoryPathBuf {
+            inner: self.inner.to_path_buf(),
+        }
+    }
+
+    /// Creates an owned [`Utf8DirectoryPathBuf`] with `path` adjoined to `self`, marking the result
+    /// as a directory like [`Utf8DirectoryPathBuf::new`].
+    pub fn join_dir<P: AsRef<Utf8Path<T>>>(&self, path: P) -> Utf8DirectoryPathBuf<T> {
+        Utf8DirectoryPathBuf::new(self.inner.join(path))
+    }
+
+    /// Creates an owned [`Utf8FilePathBuf`] with `path` adjoined to `self`.
+    pub fn join_file<P: AsRef<Utf8FilePath<T>>>(&self, path: P) -> Utf8FilePathBuf<T> {
+        Utf8FilePathBuf {
+            inner: self.inner.join(path.as_ref().as_path()),
+        }
+    }
+}
+
+impl<T> Utf8FilePath<T>
+where
+    T: for<'enc> Utf8Encoding<'enc>,
+{
+    /// Wraps `s` as a `Utf8FilePath` if it ends with a normal component written without any
+    /// separator or `.` after it.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PathKindError::NotFile`] if the path is a [`Utf8DirectoryPath`].
+    ///
+    /// [`PathKindError::NotFile`]: crate::PathKindError::NotFile
+    pub fn new<S: AsRef<str> + ?Sized>(s: &S) -> Result<&Self, PathKindError> {
+        let path = Utf8Path::new(s);
+        match file_name_len(path) {
+            Some(_) => Ok(Self::new_unchecked(path)),
+            None => Err(PathKindError::NotFile),
+        }
+    }
+
+    fn new_unchecked(path: &Utf8Path<T>) -> &Self {
+        unsafe { &*(path as *const Utf8Path<T> as *const Self) }
+    }
+
+    /// Coerces to a [`Utf8Path`] slice.
+    #[inline]
+    pub fn as_path(&self) -> &Utf8Path<T> {
+        &self.inner
+    }
+
+    /// Converts to an owned [`Utf8FilePathBuf`].
+    pub fn to_path_buf(&self) -> Utf8FilePathBuf<T> {
+        Utf8FilePathBuf {
+            inner: self.inner.to_path_buf(),
+        }
+    }
+
+    /// Returns the final component of the path.
+    pub fn file_name(&self) -> &str {
+        let s = self.inner.as_str();
+        &s[s.len() - self.name_len()..]
+    }
+
+    /// Returns everything before [`Utf8FilePath::file_name`], including the separator before it, as
+    /// the directory holding the file. The directory is empty if the path is only a file name.
+    pub fn parent(&self) -> &Utf8DirectoryPath<T> {
+        let s = self.inner.as_str();
+
+        // What precedes a normal component is nothing, a separator, or a prefix like `C:`
+        Utf8DirectoryPath::new_unchecked(Utf8Path::new(&s[..s.len() - self.name_len()]))
+    }
+
+    fn name_len(&self) -> usize {
+        file_name_len(&self.inner).unwrap_or(0)
+    }
+}
+
+impl<T> Utf8DirectoryPathBuf<T>
+where
+    T: for<'enc> Utf8Encoding<'enc>,
+{
+    /// Wraps `path` as a `Utf8DirectoryPathBuf`, adding a separator to the end if it would otherwise
+    /// be written as a [`Utf8FilePath`].
+    ///
+    /// Use [`TryFrom`] instead to reject such paths.
+    pub fn new<P: Into<Utf8PathBuf<T>>>(path: P) -> Self {
+        let mut inner = path.into();
+        if file_name_len(&inner).is_some() {
+            inner.inner.push(T::SEPARATOR);
+        }
+
+        Self { inner }
+    }
+
+    /// Coerces to a [`Utf8DirectoryPath`] slice.
+    #[inline]
+    pub fn as_directory_path(&self) -> &Utf8DirectoryPath<T> {
+        Utf8DirectoryPath::new_unchecked(&self.inner)
+    }
+
+    /// Extends `self` with `path`, marking the result as a directory like
+    /// [`Utf8DirectoryPathBuf::new`].
+    pub fn push_dir<P: AsRef<Utf8Path<T>>>(&mut self, path: P) {
+        self.inner.push(path);
+        if file_name_len(&self.inner).is_some() {
+            self.inner.inner.push(T::SEPARATOR);
+        }
+    }
+
+    /// Consumes `self`, extending it with the file `path`.
+    pub fn into_file<P: AsRef<Utf8FilePath<T>>>(mut self, path: P) -> Utf8FilePathBuf<T> {
+        self.inner.push(path.as_ref().as_path());
+        Utf8FilePathBuf { inner: self.inner }
+    }
+
+    /// Consumes the `Utf8DirectoryPathBuf`, yielding its [`Utf8PathBuf`].
+    #[inline]
+    pub fn into_path_buf(self) -> Utf8PathBuf<T> {
+        self.inner
+    }
+}
+
+impl<T> Utf8FilePathBuf<T>
+where
+    T: for<'enc> Utf8Encoding<'enc>,
+{
+    /// Wraps `path` as a `Utf8FilePathBuf` if it ends with a file name.
+    ///
+    /// See [`Utf8FilePath::new`] for more details.
+    pub fn new<P: Into<Utf8PathBuf<T>>>(path: P) -> Result<Self, PathKindError> {
+        let inner = path.into();
+        Utf8FilePath::<T>::new(inner.as_str())?;
+        Ok(Self { inner })
+    }
+
+    /// Coerces to a [`Utf8FilePath`] slice.
+    #[inline]
+    pub fn as_file_path(&self) -> &Utf8FilePath<T> {
+        Utf8FilePath::new_unchecked(&self.inner)
+    }
+
+    /// Consumes `self`, yielding the [`Utf8DirectoryPathBuf`] that holds the file.
+    pub fn into_parent(mut self) -> Utf8DirectoryPathBuf<T> {
+        let len = self.parent().as_str().len();
+        self.inner.inner.truncate(len);
+        Utf8DirectoryPathBuf { inner: self.inner }
+    }
+
+    /// Consumes the `Utf8FilePathBuf`, yielding its [`Utf8PathBuf`].
+    #[inline]
+    pub fn into_path_buf(self) -> Utf8PathBuf<T> {
+        self.inner
+    }
+}
+
+impl_path_wrapper!(Utf8DirectoryPath, Utf8DirectoryPathBuf, as_directory_path);
+impl_path_wrapper!(Utf8FilePath, Utf8FilePathBuf, as_file_path);
+
+#[cfg(test)]
+mod tests {
+    use alloc::format;
+
+    use super::*;
+    use crate::{Utf8Plan9Encoding, Utf8UnixEncoding, Utf8WindowsEncoding};
+
+    fn check<T>(files: &[&str], dirs: &[&str])
+    where
+        T: for<'enc> Utf8Encoding<'enc>,
+    {
+        for path in files {
+            let file = Utf8FilePath::<T>::new(path).unwrap();
+            assert!(Utf8DirectoryPath::<T>::new(path).is_err(), "{path}");
+            assert_eq!(
+                [file.parent().as_str(), file.file_name()].concat(),
+                *path,
+                "{path}"
+            );
+
+            let name = Utf8FilePath::new(file.file_name()).unwrap();
+            assert_eq!(file.parent().join_file(name).as_str(), *path);
+
+            let dir = Utf8DirectoryPathBuf::<T>::new(*path);
+            assert_eq!(dir.as_str(), format!("{path}{}", T::SEPARATOR));
+        }
+
+        for path in dirs {
+            assert!(Utf8DirectoryPath::<T>::new(path).is_ok(), "{path}");
+            assert!(Utf8FilePath::<T>::new(path).is_err(), "{path}");
+            assert_eq!(Utf8DirectoryPathBuf::<T>::new(*path).as_str(), *path);
+        }
+    }
+
+    #[test]
+    fn should_split_paths_into_files_and_directories() {
+        check::<Utf8UnixEncoding>(
+            &["a", "/a", "a/b", "./a", "a//b", "../a"],
+            &["", "/", ".", "..", "a/", "a/.", "a/..", "/a//"],
+        );
+        check::<Utf8WindowsEncoding>(
+            &["a", r"C:a", r"C:\a", r"\\server\share\a", r"\\?\C:\a\b"],
+            &[
+                "",
+                r"C:",
+                r"C:\",
+                r"a\",
+                r"a/",
+                r"\\server\share",
+                r"\\?\C:\a\",
+            ],
+        );
+        check::<Utf8Plan9Encoding>(&["#c/cons", "a/b"], &["#c", "#c/", "a/"]);
+    }
+}
@@ -0,0 +1,226 @@
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::ops::Deref;
+
+use crate::common::fnv::FnvHasher;
+use crate::{Utf8Encoding, Utf8Path, Utf8PathBuf};
+
+/// An owned [`Utf8PathBuf`] that computes its hash once on construction and reuses it afterwards.
+///
+/// Hashing a path parses it into components each time, which adds up when the same path is
+/// inserted into or looked up in many maps. This wrapper feeds the cached hash to hashers
+/// instead, and compares by cached hash before comparing the underlying bytes.
+///
+/// This is the UTF-8 counterpart of [`HashedPathBuf`](crate::HashedPathBuf). Because equality is
+/// by bytes, paths that differ only in ways [`Utf8Path`] equality ignores, such as repeated
+/// separators, are distinct as a `Utf8HashedPathBuf`. Normalize paths beforehand if they need to
+/// collapse into a single entry.
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::HashSet;
+/// use typed_path::{Utf8HashedPathBuf, Utf8UnixEncoding};
+///
+/// // NOTE: A path cannot be created on its own without a defined encoding
+/// let path = Utf8HashedPathBuf::<Utf8UnixEncoding>::from("/usr/lib/libc.so");
+///
+/// let mut set = HashSet::new();
+/// set.insert(path.clone());
+/// assert!(set.contains(&path));
+///
+/// // Derefs to the underlying path
+/// assert_eq!(path.file_name(), Some("libc.so"));
+/// ```
+pub struct Utf8HashedPathBuf<T>
+where
+    T: for<'enc> Utf8Encoding<'enc>,
+{
+    hash: u64,
+    path: Utf8PathBuf<T>,
+}
+
+impl<T> Utf8HashedPathBuf<T>
+where
+    T: for<'enc> Utf8Encoding<'enc>,
+{
+    /// Wraps `path`, computing its hash.
+    pub fn new(path: Utf8PathBuf<T>) -> Self {
+        let mut hasher = FnvHasher::default();
+        path.hash(&mut hasher);
+
+        Self {
+            hash: hasher.finish(),
+            path,
+        }
+    }
+
+    /// Returns the hash computed when the path was wrapped.
+    ///
+    /// The value is stable for a given path and encoding within a build of this crate, but is
+    /// not meant to be persisted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Utf8HashedPathBuf, Utf8UnixEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// let a = Utf8HashedPathBuf::<Utf8UnixEncoding>::from("/etc/hosts");
+    /// let b = Utf8HashedPathBuf::<Utf8UnixEncoding>::from("/etc//hosts");
+    ///
+    /// // The hash follows the components of the path
+    /// assert_eq!(a.cached_hash(), b.cached_hash());
+    /// ```
+    #[inline]
+    pub fn cached_hash(&self) -> u64 {
+        self.hash
+    }
+
+    /// Coerces to a [`Utf8Path`] slice.
+    #[inline]
+    pub fn as_path(&self) -> &Utf8Path<T> {
+        &self.path
+    }
+
+    /// Consumes the wrapper, yielding the inner [`Utf8PathBuf`].
+    #[inline]
+    pub fn into_path_buf(self) -> Utf8PathBuf<T> {
+        self.path
+    }
+}
+
+impl<T> Clone for Utf8HashedPathBuf<T>
+where
+    T: for<'enc> Utf8Encoding<'enc>,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            hash: self.hash,
+            path: self.path.clone(),
+        }
+    }
+}
+
+impl<T> fmt::Debug for Utf8HashedPathBuf<T>
+where
+    T: for<'enc> Utf8Encoding<'enc>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Utf8HashedPathBuf")
+            .field("hash", &self.hash)
+            .field("path", &self.path)
+            .finish()
+    }
+}
+
+impl<T> PartialEq for Utf8HashedPathBuf<T>
+where
+    T: for<'enc> Utf8Encoding<'enc>,
+{
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.hash == other.hash && self.path.as_str() == other.path.as_str()
+    }
+}
+
+impl<T> Eq for Utf8HashedPathBuf<T> where T: for<'enc> Utf8Encoding<'enc> {}
+
+impl<T> Hash for Utf8HashedPathBuf<T>
+where
+    T: for<'enc> Utf8Encoding<'enc>,
+{
+    #[inline]
+    fn hash<H: Hasher>(&self, h: &mut H) {
+        h.write_u64(self.hash)
+    }
+}
+
+impl<T> Deref for Utf8HashedPathBuf<T>
+where
+    T: for<'enc> Utf8Encoding<'enc>,
+{
+    type Target = Utf8Path<T>;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.path
+    }
+}
+
+impl<T> AsRef<Utf8Path<T>> for Utf8HashedPathBuf<T>
+where
+    T: for<'enc> Utf8Encoding<'enc>,
+{
+    #[inline]
+    fn as_ref(&self) -> &Utf8Path<T> {
+        &self.path
+    }
+}
+
+impl<T> From<Utf8PathBuf<T>> for Utf8HashedPathBuf<T>
+where
+    T: for<'enc> Utf8Encoding<'enc>,
+{
+    #[inline]
+    fn from(path: Utf8PathBuf<T>) -> Self {
+        Self::new(path)
+    }
+}
+
+impl<T, V> From<&V> for Utf8HashedPathBuf<T>
+where
+    T: for<'enc> Utf8Encoding<'enc>,
+    V: ?Sized + AsRef<str>,
+{
+    #[inline]
+    fn from(s: &V) -> Self {
+        Self::new(Utf8PathBuf::from(s))
+    }
+}
+
+impl<T> From<Utf8HashedPathBuf<T>> for Utf8PathBuf<T>
+where
+    T: for<'enc> Utf8Encoding<'enc>,
+{
+    #[inline]
+    fn from(path: Utf8HashedPathBuf<T>) -> Self {
+        path.into_path_buf()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Utf8UnixEncoding, Utf8WindowsEncoding};
+
+    #[test]
+    fn should_compare_by_bytes_and_hash_by_components() {
+        let a = Utf8HashedPathBuf::<Utf8UnixEncoding>::from("a/b");
+        let b = Utf8HashedPathBuf::<Utf8UnixEncoding>::from("a//b/");
+        let c = Utf8HashedPathBuf::<Utf8UnixEncoding>::from("a/c");
+
+        assert_eq!(a, a.clone());
+        assert_eq!(a.cached_hash(), b.cached_hash());
+        assert_ne!(a, b);
+        assert_ne!(a, c);
+    }
+
+    #[test]
+    fn hash_should_write_only_the_cached_hash() {
+        let path = Utf8HashedPathBuf::<Utf8WindowsEncoding>::from(r"C:\Windows\System32");
+
+        let mut expected = FnvHasher::default();
+        expected.write_u64(path.cached_hash());
+
+        let mut actual = FnvHasher::default();
+        path.hash(&mut actual);
+
+        assert_eq!(actual.finish(), expected.finish());
+        assert_eq!(
+            Utf8PathBuf::from(path),
+            Utf8PathBuf::<Utf8WindowsEncoding>::from(r"C:\Windows\System32")
+        );
+    }
+}
@@ -2,7 +2,8 @@ use core::fmt;
 use core::iter::FusedIterator;
 use core::marker::PhantomData;
 
-use crate::{Utf8Component, Utf8Components, Utf8Encoding, Utf8Path};
+use crate::common::remaining_str;
+use crate::{Utf8Component, Utf8Encoding, Utf8Path};
 
 /// An iterator over the [`Utf8Component`]s of a [`Utf8Path`], as [`str`] slices.
 ///
@@ -44,8 +45,8 @@ where
     ///
     /// assert_eq!(Utf8Path::<Utf8UnixEncoding>::new("foo/bar.txt"), iter.as_path());
     /// ```
-    pub fn as_path(&self) -> &Utf8Path<T> {
-        Utf8Path::new(self.inner.as_str())
+    pub fn as_path(&self) -> &'a Utf8Path<T> {
+        Utf8Path::new(remaining_str(&self.inner))
     }
 }
 
@@ -158,6 +159,9 @@ where
     T: for<'enc> Utf8Encoding<'enc>,
 {
     pub(crate) next: Option<&'a Utf8Path<T>>,
+
+    /// Ancestor after which iteration stops, if any
+    pub(crate) until: Option<&'a Utf8Path<T>>,
 }
 
 impl<'a, T> Iterator for Utf8Ancestors<'a, T>
@@ -169,7 +173,10 @@ where
     #[inline]
     fn next(&mut self) -> Option<Self::Item> {
         let next = self.next;
-        self.next = next.and_then(Utf8Path::parent);
+        self.next = match (next, self.until) {
+            (Some(path), Some(until)) if path == until => None,
+            _ => next.and_then(Utf8Path::parent),
+        };
         next
     }
 }
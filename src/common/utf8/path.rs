@@ -1,15 +1,28 @@
+mod home_display;
+
 use alloc::borrow::{Cow, ToOwned};
 use alloc::rc::Rc;
 use alloc::sync::Arc;
 use core::hash::{Hash, Hasher};
 use core::marker::PhantomData;
-use core::str::Utf8Error;
+use core::ops::{Bound, Range};
 use core::{cmp, fmt};
 
+pub use home_display::Utf8HomeDisplay;
+
+use super::cow::is_normalized;
+use crate::common::options::{comparable_component_bytes, RawComponent};
+use crate::common::sort_key;
+use crate::common::truncate::{abbreviate, display_width, truncate_name};
+use crate::common::{mime, percent};
 use crate::no_std_compat::*;
 use crate::{
-    CheckedPathError, Encoding, Path, StripPrefixError, Utf8Ancestors, Utf8Component,
-    Utf8Components, Utf8Encoding, Utf8Iter, Utf8PathBuf,
+    CheckedPathError, Component, ComponentKind, Components, CowUtf8Path, DifferentPrefixError,
+    Encoding, FilesystemProfile, IllegalCharReason, LossyPolicy, NormalizationOptions, Path,
+    PathBuf, PathEquivalence, ProfileError, PushMode, StrictPathError, StripPrefixError,
+    StripPrefixHint, SuggestOptions, UniquifyOptions, Utf8Ancestors, Utf8Component, Utf8Components,
+    Utf8Encoding, Utf8Iter, Utf8PathBuf, Utf8PathError, Utf8SegmentsWithSeparators,
+    Utf8ValidatedComponents, WindowsEncoding,
 };
 
 /// A slice of a path (akin to [`str`]).
@@ -69,6 +82,40 @@ use crate::{
 /// let path = Utf8NativePath::new("/foo/bar.txt");
 /// ```
 ///
+/// # Empty paths
+///
+/// The empty path, available as [`Utf8Path::EMPTY`], is a relative path with no components that
+/// behaves the same with every encoding:
+///
+/// * it has no [`parent`], [`file_name`], [`file_stem`], or [`extension`], and its only ancestor
+///   is itself
+/// * joining a path onto it yields that path, and joining it onto a path leaves that path as it
+///   is; unlike `std::path`, no trailing separator is added
+/// * it [`starts_with`] and [`ends_with`] itself, and every path starts with it
+/// * [`normalize`] leaves it unchanged, while [`clean`] turns it into `.`
+///
+/// ```
+/// use typed_path::{Utf8Path, Utf8UnixEncoding};
+///
+/// // NOTE: A path cannot be created on its own without a defined encoding
+/// let empty = Utf8Path::<Utf8UnixEncoding>::EMPTY;
+/// assert!(empty.is_empty());
+/// assert_eq!(empty.parent(), None);
+/// assert_eq!(empty.file_name(), None);
+/// assert_eq!(empty.components().count(), 0);
+/// assert_eq!(empty.join("foo"), Utf8Path::new("foo"));
+/// assert_eq!(Utf8Path::<Utf8UnixEncoding>::new("foo").join(empty), Utf8Path::new("foo"));
+/// ```
+///
+/// [`parent`]: Utf8Path::parent
+/// [`file_name`]: Utf8Path::file_name
+/// [`file_stem`]: Utf8Path::file_stem
+/// [`extension`]: Utf8Path::extension
+/// [`starts_with`]: Utf8Path::starts_with
+/// [`ends_with`]: Utf8Path::ends_with
+/// [`normalize`]: Utf8Path::normalize
+/// [`clean`]: Utf8Path::clean
+///
 /// [`Utf8NativePath`]: crate::Utf8NativePath
 #[repr(transparent)]
 pub struct Utf8Path<T>
@@ -82,6 +129,25 @@ where
     pub(crate) inner: str,
 }
 
+impl<T> Utf8Path<T>
+where
+    T: for<'enc> Utf8Encoding<'enc> + 'static,
+{
+    /// The empty path, which has no components.
+    ///
+    /// See [the type-level documentation](Utf8Path#empty-paths) for how it behaves.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Utf8Path, Utf8UnixEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// assert_eq!(Utf8Path::<Utf8UnixEncoding>::EMPTY, Utf8Path::new(""));
+    /// ```
+    pub const EMPTY: &'static Self = unsafe { &*("" as *const str as *const Self) };
+}
+
 impl<T> Utf8Path<T>
 where
     T: for<'enc> Utf8Encoding<'enc>,
@@ -126,6 +192,35 @@ where
         unsafe { &*(s.as_ref() as *const str as *const Self) }
     }
 
+    /// Like [`Utf8Path::new`] for a str slice, but usable in `const` contexts.
+    #[inline]
+    pub(crate) const fn from_str_const(s: &str) -> &Self {
+        unsafe { &*(s as *const str as *const Self) }
+    }
+
+    /// Like [`Utf8Path::new`], but only succeeds if the path passes
+    /// [`Utf8Path::validate_strict`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{StrictPathError, Utf8Path, Utf8WindowsEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// let path = Utf8Path::<Utf8WindowsEncoding>::try_new_strict(r"C:\logs\today.txt").unwrap();
+    /// assert_eq!(path, Utf8Path::new(r"C:\logs\today.txt"));
+    ///
+    /// assert_eq!(
+    ///     Utf8Path::<Utf8WindowsEncoding>::try_new_strict(r"C:\logs\to:day.txt"),
+    ///     Err(StrictPathError::InvalidByte { index: 10, byte: b':' }),
+    /// );
+    /// ```
+    pub fn try_new_strict<S: AsRef<str> + ?Sized>(s: &S) -> Result<&Self, StrictPathError> {
+        let path = Self::new(s);
+        path.validate_strict()?;
+        Ok(path)
+    }
+
     /// Yields the underlying [`str`] slice.
     ///
     /// # Examples
@@ -159,6 +254,24 @@ where
         }
     }
 
+    /// Returns `true` if the path is empty, meaning it has no bytes at all.
+    ///
+    /// A path like `.` or `./` is not empty even though it has no components.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Utf8Path, Utf8UnixEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// assert!(Utf8Path::<Utf8UnixEncoding>::new("").is_empty());
+    /// assert!(!Utf8Path::<Utf8UnixEncoding>::new(".").is_empty());
+    /// ```
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+
     /// Returns `true` if the `Utf8Path` is absolute, i.e., if it is independent of
     /// the current directory.
     ///
@@ -223,6 +336,214 @@ where
         self.components().all(|c| c.is_valid())
     }
 
+    /// Checks the path against the rules of its encoding, returning the first violation found.
+    ///
+    /// See [`Path::validate_strict`] for more details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{StrictPathError, Utf8Path, Utf8WindowsEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// assert_eq!(
+    ///     Utf8Path::<Utf8WindowsEncoding>::new(r"\\?\UNC\server\share").validate_strict(),
+    ///     Ok(()),
+    /// );
+    /// assert_eq!(
+    ///     Utf8Path::<Utf8WindowsEncoding>::new(r"\\.\").validate_strict(),
+    ///     Err(StrictPathError::MalformedPrefix),
+    /// );
+    /// ```
+    pub fn validate_strict(&self) -> Result<(), StrictPathError> {
+        let path = self.as_str();
+
+        for component in self.components() {
+            match component.kind() {
+                ComponentKind::Prefix if !T::is_valid_prefix(component.as_str()) => {
+                    return Err(StrictPathError::MalformedPrefix);
+                }
+                ComponentKind::Normal => {
+                    // Normal components always borrow from the path being iterated
+                    let bytes = component.as_str().as_bytes();
+                    let start = bytes.as_ptr() as usize - path.as_ptr() as usize;
+
+                    if let Some(i) = bytes
+                        .iter()
+                        .position(|b| T::DISALLOWED_FILENAME_BYTES.contains(b))
+                    {
+                        return Err(StrictPathError::InvalidByte {
+                            index: start + i,
+                            byte: bytes[i],
+                        });
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        match path.bytes().position(|b| b == b'\0') {
+            Some(index) => Err(StrictPathError::InvalidByte { index, byte: b'\0' }),
+            None => Ok(()),
+        }
+    }
+
+    /// Returns `true` if the path satisfies the restrictions of `profile`.
+    ///
+    /// See [`Utf8Path::validate_for`] for more details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{FilesystemProfile, Utf8Path, Utf8UnixEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// let profile = FilesystemProfile::posix_portable();
+    /// assert!(Utf8Path::<Utf8UnixEncoding>::new("/tmp/foo.txt").is_valid_for(&profile));
+    /// assert!(!Utf8Path::<Utf8UnixEncoding>::new("/tmp/foo bar.txt").is_valid_for(&profile));
+    /// ```
+    pub fn is_valid_for(&self, profile: &FilesystemProfile) -> bool {
+        self.validate_for(profile).is_ok()
+    }
+
+    /// Checks the path against the restrictions of `profile`, returning the first violation
+    /// found.
+    ///
+    /// See [`Path::validate_for`] for more details.
+    ///
+    /// [`Path::validate_for`]: crate::Path::validate_for
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{FilesystemProfile, ProfileError, Utf8Path, Utf8UnixEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// let profile = FilesystemProfile::posix_portable();
+    /// assert_eq!(
+    ///     Utf8Path::<Utf8UnixEncoding>::new("/tmp/café").validate_for(&profile),
+    ///     Err(ProfileError::DisallowedByte(0xC3)),
+    /// );
+    /// ```
+    pub fn validate_for(&self, profile: &FilesystemProfile) -> Result<(), ProfileError> {
+        for component in self.components() {
+            if component.is_normal() {
+                profile.check_component(component.as_str().as_bytes())?;
+            }
+        }
+
+        profile.check_path_len(self.as_str().len())
+    }
+
+    /// Produces an iterator over the [`Utf8Component`]s of the path that reports every normal
+    /// component violating its encoding or the restrictions of `profile`, rather than stopping at
+    /// the first one like [`Utf8Path::validate_for`].
+    ///
+    /// Each component that fails is replaced by a [`ComponentError`] describing its first problem
+    /// alongside the range of bytes it occupies within the path. The length of the entire path is
+    /// not checked.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{FilesystemProfile, ProfileError, Utf8Path, Utf8UnixEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// let path = Utf8Path::<Utf8UnixEncoding>::new("/srv/-rf/ok/a b");
+    /// let errors: Vec<_> = path
+    ///     .validated_components(&FilesystemProfile::posix_portable())
+    ///     .filter_map(Result::err)
+    ///     .map(|e| (e.span(), e.kind().clone()))
+    ///     .collect();
+    ///
+    /// assert_eq!(
+    ///     errors,
+    ///     [
+    ///         (5..8, ProfileError::LeadingHyphen),
+    ///         (12..15, ProfileError::DisallowedByte(b' ')),
+    ///     ],
+    /// );
+    /// ```
+    ///
+    /// [`ComponentError`]: crate::ComponentError
+    pub fn validated_components(
+        &self,
+        profile: &FilesystemProfile,
+    ) -> Utf8ValidatedComponents<'_, T> {
+        Utf8ValidatedComponents::new(self.as_str(), profile)
+    }
+
+    /// Finds every byte within the normal components of the path that is not allowed by its
+    /// encoding or by `profile`, returning the position of each byte within the path alongside
+    /// why it was reported.
+    ///
+    /// A control character that is not allowed, or that the profile warns about or rejects, is
+    /// reported as [`IllegalCharReason::Control`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{FilesystemProfile, IllegalCharReason, Utf8Path, Utf8UnixEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// let path = Utf8Path::<Utf8UnixEncoding>::new("/tmp/my\tnotes/caf\u{e9}");
+    /// let illegal: Vec<_> = path
+    ///     .find_illegal_chars(&FilesystemProfile::posix_portable())
+    ///     .collect();
+    ///
+    /// assert_eq!(
+    ///     illegal,
+    ///     [
+    ///         (7, b'\t', IllegalCharReason::Control),
+    ///         (17, 0xc3, IllegalCharReason::Disallowed),
+    ///         (18, 0xa9, IllegalCharReason::Disallowed),
+    ///     ],
+    /// );
+    /// ```
+    ///
+    /// [`IllegalCharReason::Control`]: crate::IllegalCharReason::Control
+    pub fn find_illegal_chars(
+        &self,
+        profile: &FilesystemProfile,
+    ) -> impl Iterator<Item = (usize, u8, IllegalCharReason)> {
+        let mut illegal = Vec::new();
+        for component in self.components() {
+            if component.is_normal() {
+                let s = component.as_str();
+                let offset = s.as_ptr() as usize - self.as_str().as_ptr() as usize;
+                profile.find_illegal_bytes(
+                    s.as_bytes(),
+                    T::DISALLOWED_FILENAME_BYTES,
+                    offset,
+                    &mut illegal,
+                );
+            }
+        }
+
+        illegal.into_iter()
+    }
+
+    /// Returns `true` if any character of the path is an ascii control character, such as `\n`,
+    /// `\t`, or `\0`.
+    ///
+    /// See [`Path::contains_control_bytes`] for more details.
+    ///
+    /// [`Path::contains_control_bytes`]: crate::Path::contains_control_bytes
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Utf8Path, Utf8UnixEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// assert!(Utf8Path::<Utf8UnixEncoding>::new("/tmp/evil\tname").contains_control_bytes());
+    /// assert!(!Utf8Path::<Utf8UnixEncoding>::new("/tmp/caf\u{e9}").contains_control_bytes());
+    /// ```
+    #[inline]
+    pub fn contains_control_bytes(&self) -> bool {
+        self.as_str().bytes().any(|b| b.is_ascii_control())
+    }
+
     /// Returns `true` if the `Utf8Path` has a root.
     ///
     /// * On Unix ([`Utf8UnixPath`]), a path has a root if it begins with `/`.
@@ -248,6 +569,128 @@ where
         self.components().has_root()
     }
 
+    /// Returns the prefix and root at the start of the path, if it has either, such as `/`, `C:\`,
+    /// or `\\server\share\`.
+    ///
+    /// Any separators that follow the root are included. A prefix without a root, such as `C:` in
+    /// `C:foo`, is returned on its own.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Utf8Path, Utf8UnixEncoding, Utf8WindowsEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// let path = Utf8Path::<Utf8UnixEncoding>::new("/etc/passwd");
+    /// assert_eq!(path.root(), Some(Utf8Path::new("/")));
+    /// assert_eq!(Utf8Path::<Utf8UnixEncoding>::new("etc/passwd").root(), None);
+    ///
+    /// let path = Utf8Path::<Utf8WindowsEncoding>::new(r"\\server\share\dir");
+    /// assert_eq!(path.root(), Some(Utf8Path::new(r"\\server\share\")));
+    /// ```
+    pub fn root(&self) -> Option<&Self> {
+        self.split_root().0
+    }
+
+    /// Splits the path into its prefix and root, as returned by [`Utf8Path::root`], and the
+    /// relative remainder of the path.
+    ///
+    /// Joining the two pieces back together always produces the original path.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Utf8Path, Utf8WindowsEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// let (root, rest) = Utf8Path::<Utf8WindowsEncoding>::new(r"C:\Users\me").split_root();
+    /// assert_eq!(root, Some(Utf8Path::new(r"C:\")));
+    /// assert_eq!(rest, Utf8Path::new(r"Users\me"));
+    /// ```
+    pub fn split_root(&self) -> (Option<&Self>, &Self) {
+        let s = self.as_str();
+        let mut prefix_len = 0;
+        let mut has_root = false;
+        for component in self.components() {
+            if component.is_normal() || component.is_current() || component.is_parent() {
+                break;
+            }
+
+            // Anything else is either a root directory, which is a lone separator, or a prefix,
+            // which may also count as a root
+            let mut chars = component.as_str().chars();
+            match (chars.next(), chars.next()) {
+                (Some(c), None) if T::is_separator(c) => has_root = true,
+                _ => {
+                    prefix_len = component.len();
+                    has_root |= component.is_root();
+                }
+            }
+        }
+
+        let mut end = prefix_len;
+        if has_root {
+            end += s[prefix_len..]
+                .chars()
+                .take_while(|c| T::is_separator(*c))
+                .map(char::len_utf8)
+                .sum::<usize>();
+        }
+
+        if end == 0 {
+            (None, self)
+        } else {
+            (Some(Self::new(&s[..end])), Self::new(&s[end..]))
+        }
+    }
+
+    /// Returns `true` if the path is made up of nothing more than a root, such as `/`, `C:\`, or
+    /// `\\server\share`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Utf8Path, Utf8UnixEncoding, Utf8WindowsEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// assert!(Utf8Path::<Utf8UnixEncoding>::new("/").is_root());
+    /// assert!(!Utf8Path::<Utf8UnixEncoding>::new("/etc").is_root());
+    ///
+    /// assert!(Utf8Path::<Utf8WindowsEncoding>::new(r"C:\").is_root());
+    /// assert!(Utf8Path::<Utf8WindowsEncoding>::new(r"\\server\share").is_root());
+    /// assert!(!Utf8Path::<Utf8WindowsEncoding>::new("C:").is_root());
+    /// ```
+    pub fn is_root(&self) -> bool {
+        self.has_root()
+            && self
+                .components()
+                .all(|c| !(c.is_normal() || c.is_current() || c.is_parent()))
+    }
+
+    /// Returns `true` if the path is made up of nothing more than a prefix without a root, such as
+    /// `C:`, which refers to the current directory of a drive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Utf8Path, Utf8UnixEncoding, Utf8WindowsEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// assert!(Utf8Path::<Utf8WindowsEncoding>::new("C:").is_prefix_only());
+    /// assert!(!Utf8Path::<Utf8WindowsEncoding>::new(r"C:\").is_prefix_only());
+    /// assert!(!Utf8Path::<Utf8WindowsEncoding>::new("C:foo").is_prefix_only());
+    /// assert!(!Utf8Path::<Utf8UnixEncoding>::new("").is_prefix_only());
+    /// ```
+    pub fn is_prefix_only(&self) -> bool {
+        let mut components = self.components();
+        !self.has_root()
+            && components
+                .next()
+                .map(|c| !(c.is_normal() || c.is_current() || c.is_parent()))
+                .unwrap_or(false)
+            && components.next().is_none()
+    }
+
     /// Returns the `Utf8Path` without its final component, if there is one.
     ///
     /// Returns [`None`] if the path terminates in a root or prefix.
@@ -310,7 +753,69 @@ where
     /// [`parent`]: Utf8Path::parent
     #[inline]
     pub fn ancestors(&self) -> Utf8Ancestors<T> {
-        Utf8Ancestors { next: Some(self) }
+        Utf8Ancestors {
+            next: Some(self),
+            until: None,
+        }
+    }
+
+    /// Produces an iterator over `Utf8Path` and its ancestors that stops after yielding `base`.
+    ///
+    /// This behaves the same as [`Utf8Path::ancestors`], except that iteration ends once an
+    /// ancestor equal to `base` has been yielded. If `base` is not an ancestor of `self`, all
+    /// ancestors are yielded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Utf8Path, Utf8UnixEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// let path = Utf8Path::<Utf8UnixEncoding>::new("/home/user/project/src/bin");
+    ///
+    /// let mut ancestors = path.ancestors_until("/home/user/project");
+    /// assert_eq!(ancestors.next(), Some(Utf8Path::new("/home/user/project/src/bin")));
+    /// assert_eq!(ancestors.next(), Some(Utf8Path::new("/home/user/project/src")));
+    /// assert_eq!(ancestors.next(), Some(Utf8Path::new("/home/user/project")));
+    /// assert_eq!(ancestors.next(), None);
+    ///
+    /// // Walking up to find a project root marker
+    /// let root = path
+    ///     .ancestors_until("/home/user")
+    ///     .find(|p| p.file_name() == Some("project"));
+    /// assert_eq!(root, Some(Utf8Path::new("/home/user/project")));
+    ///
+    /// assert_eq!(path.ancestors_until("/srv").count(), path.ancestors().count());
+    /// ```
+    pub fn ancestors_until<P: AsRef<Utf8Path<T>>>(&self, base: P) -> Utf8Ancestors<'_, T> {
+        let base = base.as_ref();
+        Utf8Ancestors {
+            next: Some(self),
+            until: self.ancestors().find(|p| *p == base),
+        }
+    }
+
+    /// Returns the `n`th ancestor of the path, or [`None`] if the path has fewer than `n`
+    /// parents.
+    ///
+    /// Calling `nth_parent(0)` returns the path itself, and `nth_parent(1)` is the same as
+    /// [`Utf8Path::parent`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Utf8Path, Utf8UnixEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// let path = Utf8Path::<Utf8UnixEncoding>::new("/foo/bar/baz");
+    ///
+    /// assert_eq!(path.nth_parent(0), Some(path));
+    /// assert_eq!(path.nth_parent(2), Some(Utf8Path::new("/foo")));
+    /// assert_eq!(path.nth_parent(3), Some(Utf8Path::new("/")));
+    /// assert_eq!(path.nth_parent(4), None);
+    /// ```
+    pub fn nth_parent(&self, n: usize) -> Option<&Self> {
+        self.ancestors().nth(n)
     }
 
     /// Returns the final component of the `Utf8Path`, if there is one.
@@ -385,42 +890,260 @@ where
     fn _strip_prefix(&self, base: &Utf8Path<T>) -> Result<&Utf8Path<T>, StripPrefixError> {
         match helpers::iter_after(self.components(), base.components()) {
             Some(c) => Ok(Utf8Path::new(c.as_str())),
-            None => Err(StripPrefixError(())),
+            None => Err(StripPrefixError {
+                hint: StripPrefixHint::ALL
+                    .into_iter()
+                    .find(|hint| self.relaxed_strip_prefix(base, *hint).is_some()),
+            }),
         }
     }
 
-    /// Determines whether `base` is a prefix of `self`.
+    /// Like [`Utf8Path::strip_prefix`], but compares components the relaxed way described by
+    /// `hint`, as suggested by [`StripPrefixError::would_match_if`].
     ///
-    /// Only considers whole path components to match.
+    /// The remainder is owned, as it is taken from the normalized form of `self` when `hint`
+    /// [normalizes](StripPrefixHint::normalizes).
+    ///
+    /// # Errors
+    ///
+    /// If `base` is not a prefix of `self` even under `hint`, returns [`Err`] holding any more
+    /// relaxed hint under which it would be.
     ///
     /// # Examples
     ///
     /// ```
-    /// use typed_path::{Utf8Path, Utf8UnixEncoding};
+    /// use typed_path::{StripPrefixHint, Utf8WindowsPath, Utf8WindowsPathBuf};
     ///
-    /// // NOTE: A path cannot be created on its own without a defined encoding
-    /// let path = Utf8Path::<Utf8UnixEncoding>::new("/etc/passwd");
+    /// let path = Utf8WindowsPath::new(r"\\server\share\reports\q1.csv");
+    /// let base = Utf8WindowsPath::new(r"//SERVER/share/");
     ///
-    /// assert!(path.starts_with("/etc"));
-    /// assert!(path.starts_with("/etc/"));
-    /// assert!(path.starts_with("/etc/passwd"));
-    /// assert!(path.starts_with("/etc/passwd/")); // extra slash is okay
-    /// assert!(path.starts_with("/etc/passwd///")); // multiple extra slashes are okay
+    /// let err = path.strip_prefix(base).unwrap_err();
+    /// assert_eq!(err.would_match_if(), Some(StripPrefixHint::IgnoreCaseAndNormalize));
     ///
-    /// assert!(!path.starts_with("/e"));
-    /// assert!(!path.starts_with("/etc/passwd.txt"));
+    /// let err = path.strip_prefix_with(base, StripPrefixHint::IgnoreCase).unwrap_err();
+    /// assert_eq!(err.would_match_if(), Some(StripPrefixHint::IgnoreCaseAndNormalize));
     ///
-    /// assert!(!Utf8Path::<Utf8UnixEncoding>::new("/etc/foo.rs").starts_with("/etc/foo"));
+    /// assert_eq!(
+    ///     path.strip_prefix_with(base, StripPrefixHint::IgnoreCaseAndNormalize),
+    ///     Ok(Utf8WindowsPathBuf::from(r"reports\q1.csv")),
+    /// );
     /// ```
-    pub fn starts_with<P>(&self, base: P) -> bool
+    ///
+    /// [`StripPrefixError::would_match_if`]: crate::StripPrefixError::would_match_if
+    pub fn strip_prefix_with<P>(
+        &self,
+        base: P,
+        hint: StripPrefixHint,
+    ) -> Result<Utf8PathBuf<T>, StripPrefixError>
     where
         P: AsRef<Utf8Path<T>>,
     {
-        self._starts_with(base.as_ref())
-    }
-
-    fn _starts_with(&self, base: &Utf8Path<T>) -> bool {
-        helpers::iter_after(self.components(), base.components()).is_some()
+        let base = base.as_ref();
+        self.relaxed_strip_prefix(base, hint)
+            .ok_or_else(|| StripPrefixError {
+                hint: StripPrefixHint::ALL
+                    .into_iter()
+                    .skip_while(|h| *h != hint)
+                    .find(|h| self.relaxed_strip_prefix(base, *h).is_some()),
+            })
+    }
+
+    fn relaxed_strip_prefix(
+        &self,
+        base: &Utf8Path<T>,
+        hint: StripPrefixHint,
+    ) -> Option<Utf8PathBuf<T>> {
+        let has_parent = |path: &Utf8Path<T>| path.components().any(|c| c.is_parent());
+
+        // Normalizing only matters for `..`, as `.` and separators are handled while comparing
+        let normalized;
+        let (path, base) = if hint.normalizes() && (has_parent(self) || has_parent(base)) {
+            let options = NormalizationOptions::new().preserve_leading_parent(true);
+            normalized = (self.normalize_with(&options), base.normalize_with(&options));
+            (normalized.0.as_path(), normalized.1.as_path())
+        } else {
+            (self, base)
+        };
+
+        let skip = hint.normalizes();
+        let mut components = path.components();
+        for b in base.components().filter(|c| !(skip && c.is_current())) {
+            let a = loop {
+                match components.next() {
+                    Some(c) if skip && c.is_current() => continue,
+                    c => break c?,
+                }
+            };
+
+            if !hint.bytes_eq(a.as_str().as_bytes(), b.as_str().as_bytes(), |b| {
+                b.is_ascii() && T::is_separator(b as char)
+            }) {
+                return None;
+            }
+        }
+
+        Some(Utf8Path::new(components.as_str()).to_path_buf())
+    }
+
+    /// Returns a relative path that, when joined onto `base`, refers to the same location as
+    /// `self`.
+    ///
+    /// Both paths are [`cleaned`] first, after which `..` components are added for each component
+    /// of `base` that is not shared with `self`. Components are compared using the case rules of
+    /// the encoding, as with [`Utf8Path::is_ancestor_of`]. If both paths are the same, the result
+    /// is empty.
+    ///
+    /// [`cleaned`]: Utf8Path::clean
+    ///
+    /// # Errors
+    ///
+    /// If `self` and `base` begin with different anchors, meaning their prefix, root, or leading
+    /// `..` components differ, returns a [`DifferentPrefixError`] carrying both anchors. This is
+    /// the case for paths on different Windows drives, for an absolute and a relative path, and
+    /// for a relative path that escapes further above its starting point than the other.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Utf8Path, Utf8UnixEncoding, Utf8WindowsEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// let path = Utf8Path::<Utf8UnixEncoding>::new("/usr/share/doc");
+    ///
+    /// assert_eq!(path.relative_to("/usr/lib").unwrap().as_str(), "../share/doc");
+    /// assert_eq!(path.relative_to("/usr").unwrap().as_str(), "share/doc");
+    /// assert_eq!(path.relative_to("/usr/share/doc/rust").unwrap().as_str(), "..");
+    /// assert!(path.relative_to("usr").is_err());
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// let path = Utf8Path::<Utf8WindowsEncoding>::new(r"C:\Users\Public");
+    ///
+    /// assert_eq!(path.relative_to(r"c:\users\me").unwrap().as_str(), r"..\Public");
+    ///
+    /// let err = path.relative_to(r"D:\Users").unwrap_err();
+    /// assert_eq!(err.prefix(), br"C:\");
+    /// assert_eq!(err.base_prefix(), br"D:\");
+    /// ```
+    pub fn relative_to<P>(&self, base: P) -> Result<Utf8PathBuf<T>, DifferentPrefixError>
+    where
+        P: AsRef<Utf8Path<T>>,
+    {
+        self._relative_to(base.as_ref())
+    }
+
+    fn _relative_to(&self, base: &Utf8Path<T>) -> Result<Utf8PathBuf<T>, DifferentPrefixError> {
+        let windows = self.has_windows_encoding();
+        let case_sensitive = !windows;
+        let eq = |a: &str, b: &str| a == b || !case_sensitive && a.eq_ignore_ascii_case(b);
+
+        let path = self.clean();
+        let base = base.clean();
+        let (path_anchor, path_normals) = path.split_relative_anchor();
+        let (base_anchor, base_normals) = base.split_relative_anchor();
+
+        let same_anchor = {
+            let mut a = Utf8Path::<T>::new(path_anchor).components();
+            let mut b = Utf8Path::<T>::new(base_anchor).components();
+            loop {
+                match (a.next(), b.next()) {
+                    (Some(x), Some(y)) => {
+                        // Separators within a Windows prefix are as insignificant as anywhere else
+                        let x =
+                            comparable_component_bytes(x.kind(), x.as_str().as_bytes(), windows);
+                        let y =
+                            comparable_component_bytes(y.kind(), y.as_str().as_bytes(), windows);
+                        if !(x == y || windows && x.eq_ignore_ascii_case(&y)) {
+                            break false;
+                        }
+                    }
+                    (None, None) => break true,
+                    _ => break false,
+                }
+            }
+        };
+
+        if !same_anchor {
+            return Err(DifferentPrefixError {
+                prefix: path_anchor.as_bytes().to_vec(),
+                base_prefix: base_anchor.as_bytes().to_vec(),
+            });
+        }
+
+        let common = path_normals
+            .iter()
+            .zip(base_normals.iter())
+            .take_while(|(a, b)| eq(a, b))
+            .count();
+
+        let parent = <
+            <<T as Utf8Encoding>::Components as Utf8Components>::Component
+            as Utf8Component
+        >::parent();
+        let mut relative = Utf8PathBuf::new();
+        for _ in common..base_normals.len() {
+            relative.push(parent.as_str());
+        }
+        for normal in &path_normals[common..] {
+            relative.push(normal);
+        }
+
+        Ok(relative)
+    }
+
+    /// Splits a cleaned path into its anchor, consisting of any prefix, root, and leading `..`
+    /// components, and the normal components that follow it
+    fn split_relative_anchor(&self) -> (&str, Vec<&str>) {
+        let mut components = self.components();
+        let mut anchor_len = 0;
+        let mut normals = Vec::new();
+
+        loop {
+            let rest = components.as_str();
+            match components.next() {
+                Some(component) if component.is_normal() => normals.push(component.as_str()),
+                Some(component) if normals.is_empty() && !component.is_current() => {
+                    anchor_len = self.inner.len() - rest.len() + component.len();
+                }
+                Some(_) => {}
+                None => break,
+            }
+        }
+
+        (&self.inner[..anchor_len], normals)
+    }
+
+    /// Determines whether `base` is a prefix of `self`.
+    ///
+    /// Only considers whole path components to match.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Utf8Path, Utf8UnixEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// let path = Utf8Path::<Utf8UnixEncoding>::new("/etc/passwd");
+    ///
+    /// assert!(path.starts_with("/etc"));
+    /// assert!(path.starts_with("/etc/"));
+    /// assert!(path.starts_with("/etc/passwd"));
+    /// assert!(path.starts_with("/etc/passwd/")); // extra slash is okay
+    /// assert!(path.starts_with("/etc/passwd///")); // multiple extra slashes are okay
+    ///
+    /// assert!(!path.starts_with("/e"));
+    /// assert!(!path.starts_with("/etc/passwd.txt"));
+    ///
+    /// assert!(!Utf8Path::<Utf8UnixEncoding>::new("/etc/foo.rs").starts_with("/etc/foo"));
+    /// ```
+    pub fn starts_with<P>(&self, base: P) -> bool
+    where
+        P: AsRef<Utf8Path<T>>,
+    {
+        self._starts_with(base.as_ref())
+    }
+
+    fn _starts_with(&self, base: &Utf8Path<T>) -> bool {
+        helpers::iter_after(self.components(), base.components()).is_some()
     }
 
     /// Determines whether `child` is a suffix of `self`.
@@ -453,16 +1176,41 @@ where
         helpers::iter_after(self.components().rev(), child.components().rev()).is_some()
     }
 
-    /// Extracts the stem (non-extension) portion of [`self.file_name`].
+    /// Determines whether `self` is a strict ancestor of `other`, meaning that `other` begins
+    /// with all of the components of `self` followed by at least one more component.
     ///
-    /// [`self.file_name`]: Utf8Path::file_name
+    /// Components are compared whole, using the case rules of the encoding: Unix paths are
+    /// compared case-sensitively, whereas Windows paths (including their prefixes) are compared
+    /// ascii case-insensitively. No normalization is performed, so `.` and `..` components are
+    /// compared as-is.
     ///
-    /// The stem is:
+    /// # Examples
     ///
-    /// * [`None`], if there is no file name;
-    /// * The entire file name if there is no embedded `.`;
-    /// * The entire file name if the file name begins with `.` and has no other `.`s within;
-    /// * Otherwise, the portion of the file name before the final `.`
+    /// ```
+    /// use typed_path::{Utf8Path, Utf8UnixEncoding, Utf8WindowsEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// let path = Utf8Path::<Utf8UnixEncoding>::new("/etc");
+    ///
+    /// assert!(path.is_ancestor_of("/etc/passwd"));
+    /// assert!(!path.is_ancestor_of("/etc"));
+    /// assert!(!path.is_ancestor_of("/etcetera"));
+    /// assert!(!path.is_ancestor_of("/ETC/passwd"));
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// let path = Utf8Path::<Utf8WindowsEncoding>::new(r"C:\Windows");
+    ///
+    /// assert!(path.is_ancestor_of(r"c:\windows\System32"));
+    /// assert!(path.is_ancestor_of(r"C:/WINDOWS/System32"));
+    /// assert!(!path.is_ancestor_of(r"D:\Windows\System32"));
+    /// ```
+    pub fn is_ancestor_of<P: AsRef<Utf8Path<T>>>(&self, other: P) -> bool {
+        self._is_ancestor_of(other.as_ref(), true)
+    }
+
+    /// Determines whether `self` is either the same path as `other` or one of its ancestors.
+    ///
+    /// See [`Utf8Path::is_ancestor_of`] for how components are compared.
     ///
     /// # Examples
     ///
@@ -470,26 +1218,20 @@ where
     /// use typed_path::{Utf8Path, Utf8UnixEncoding};
     ///
     /// // NOTE: A path cannot be created on its own without a defined encoding
-    /// assert_eq!("foo", Utf8Path::<Utf8UnixEncoding>::new("foo.rs").file_stem().unwrap());
-    /// assert_eq!("foo.tar", Utf8Path::<Utf8UnixEncoding>::new("foo.tar.gz").file_stem().unwrap());
-    /// ```
+    /// let path = Utf8Path::<Utf8UnixEncoding>::new("/etc");
     ///
-    pub fn file_stem(&self) -> Option<&str> {
-        self.file_name()
-            .map(helpers::rsplit_file_at_dot)
-            .and_then(|(before, after)| before.or(after))
+    /// assert!(path.is_inclusive_ancestor_of("/etc/passwd"));
+    /// assert!(path.is_inclusive_ancestor_of("/etc/"));
+    /// assert!(!path.is_inclusive_ancestor_of("/"));
+    /// ```
+    pub fn is_inclusive_ancestor_of<P: AsRef<Utf8Path<T>>>(&self, other: P) -> bool {
+        self._is_ancestor_of(other.as_ref(), false)
     }
 
-    /// Extracts the extension of [`self.file_name`], if possible.
-    ///
-    /// The extension is:
-    ///
-    /// * [`None`], if there is no file name;
-    /// * [`None`], if there is no embedded `.`;
-    /// * [`None`], if the file name begins with `.` and has no other `.`s within;
-    /// * Otherwise, the portion of the file name after the final `.`
+    /// Determines whether `self` is a strict descendant of `other`, meaning that `self` begins
+    /// with all of the components of `other` followed by at least one more component.
     ///
-    /// [`self.file_name`]: Utf8Path::file_name
+    /// See [`Utf8Path::is_ancestor_of`] for how components are compared.
     ///
     /// # Examples
     ///
@@ -497,132 +1239,778 @@ where
     /// use typed_path::{Utf8Path, Utf8UnixEncoding};
     ///
     /// // NOTE: A path cannot be created on its own without a defined encoding
-    /// assert_eq!("rs", Utf8Path::<Utf8UnixEncoding>::new("foo.rs").extension().unwrap());
-    /// assert_eq!("gz", Utf8Path::<Utf8UnixEncoding>::new("foo.tar.gz").extension().unwrap());
+    /// let path = Utf8Path::<Utf8UnixEncoding>::new("/etc/passwd");
+    ///
+    /// assert!(path.is_descendant_of("/etc"));
+    /// assert!(path.is_descendant_of("/"));
+    /// assert!(!path.is_descendant_of("/etc/passwd"));
+    /// assert!(!path.is_descendant_of("/et"));
     /// ```
-    pub fn extension(&self) -> Option<&str> {
-        self.file_name()
-            .map(helpers::rsplit_file_at_dot)
-            .and_then(|(before, after)| before.and(after))
+    pub fn is_descendant_of<P: AsRef<Utf8Path<T>>>(&self, other: P) -> bool {
+        other.as_ref()._is_ancestor_of(self, true)
     }
 
-    /// Returns an owned [`Utf8PathBuf`] by resolving `..` and `.` segments.
+    /// Determines whether `self` is either the same path as `other` or one of its descendants.
     ///
-    /// When multiple, sequential path segment separation characters are found (e.g. `/` for Unix
-    /// and either `\` or `/` on Windows), they are replaced by a single instance of the
-    /// platform-specific path segment separator (`/` on Unix and `\` on Windows).
+    /// See [`Utf8Path::is_ancestor_of`] for how components are compared.
     ///
     /// # Examples
     ///
     /// ```
-    /// use typed_path::{Utf8Path, Utf8PathBuf, Utf8UnixEncoding};
+    /// use typed_path::{Utf8Path, Utf8UnixEncoding};
     ///
     /// // NOTE: A path cannot be created on its own without a defined encoding
-    /// assert_eq!(
-    ///     Utf8Path::<Utf8UnixEncoding>::new("foo/bar//baz/./asdf/quux/..").normalize(),
-    ///     Utf8PathBuf::from("foo/bar/baz/asdf"),
-    /// );
+    /// let path = Utf8Path::<Utf8UnixEncoding>::new("/etc/passwd");
+    ///
+    /// assert!(path.is_inclusive_descendant_of("/etc"));
+    /// assert!(path.is_inclusive_descendant_of("/etc/passwd"));
+    /// assert!(!path.is_inclusive_descendant_of("/etc/passwd/shadow"));
     /// ```
+    pub fn is_inclusive_descendant_of<P: AsRef<Utf8Path<T>>>(&self, other: P) -> bool {
+        other.as_ref()._is_ancestor_of(self, false)
+    }
+
+    fn _is_ancestor_of(&self, other: &Utf8Path<T>, strict: bool) -> bool {
+        let windows = self.has_windows_encoding();
+        let mut ours = self.components();
+        let mut theirs = other.components();
+
+        loop {
+            match (ours.next(), theirs.next()) {
+                (Some(a), Some(b)) => {
+                    // Separators within a Windows prefix are as insignificant as anywhere else
+                    let a = comparable_component_bytes(a.kind(), a.as_str().as_bytes(), windows);
+                    let b = comparable_component_bytes(b.kind(), b.as_str().as_bytes(), windows);
+                    if !(a == b || windows && a.eq_ignore_ascii_case(&b)) {
+                        return false;
+                    }
+                }
+                (Some(_), None) => return false,
+                (None, rest) => return !strict || rest.is_some(),
+            }
+        }
+    }
+
+    /// Determines whether `needle` appears anywhere within `self` as a sequence of whole
+    /// components.
     ///
-    /// When starting with a root directory, any `..` segment whose parent is the root directory
-    /// will be filtered out:
+    /// See [`Utf8Path::find_subpath`] for more details.
+    ///
+    /// # Examples
     ///
     /// ```
-    /// use typed_path::{Utf8Path, Utf8PathBuf, Utf8UnixEncoding};
+    /// use typed_path::{Utf8Path, Utf8UnixEncoding};
     ///
     /// // NOTE: A path cannot be created on its own without a defined encoding
-    /// assert_eq!(
-    ///     Utf8Path::<Utf8UnixEncoding>::new("/../foo").normalize(),
-    ///     Utf8PathBuf::from("/foo"),
-    /// );
+    /// let path = Utf8Path::<Utf8UnixEncoding>::new("/repo/node_modules/.bin/tsc");
+    ///
+    /// assert!(path.contains_path("node_modules/.bin"));
+    /// assert!(path.contains_path("/repo"));
+    /// assert!(!path.contains_path("node_modules/tsc"));
+    /// assert!(!path.contains_path("modules"));
     /// ```
+    pub fn contains_path<P: AsRef<Utf8Path<T>>>(&self, needle: P) -> bool {
+        self._find_subpath(needle.as_ref()).is_some()
+    }
+
+    /// Locates the first occurrence of `needle` within `self` as a sequence of whole components,
+    /// returning the index of the first matching component alongside the span of bytes within
+    /// `self` covered by the matching components.
     ///
-    /// If any `..` is left unresolved as the path is relative and no parent is found, it is
-    /// discarded:
+    /// Components are compared the same way as with [`Utf8Path::starts_with`]. An empty
+    /// `needle` matches at the start of `self`.
+    ///
+    /// # Examples
     ///
     /// ```
-    /// use typed_path::{Utf8Path, Utf8PathBuf, Utf8UnixEncoding, Utf8WindowsEncoding};
+    /// use typed_path::{Utf8Path, Utf8UnixEncoding};
     ///
-    /// assert_eq!(
-    ///     Utf8Path::<Utf8UnixEncoding>::new("../foo/..").normalize(),
-    ///     Utf8PathBuf::from(""),
-    /// );
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// let path = Utf8Path::<Utf8UnixEncoding>::new("/repo//node_modules/.bin/tsc");
     ///
-    /// //Windows prefixes also count this way, but the prefix remains
-    /// assert_eq!(
-    ///     Utf8Path::<Utf8WindowsEncoding>::new(r"C:..\foo\..").normalize(),
-    ///     Utf8PathBuf::from(r"C:"),
-    /// );
+    /// let (index, span) = path.find_subpath("node_modules/.bin").unwrap();
+    /// assert_eq!(index, 2);
+    /// assert_eq!(&path.as_str()[span], "node_modules/.bin");
+    ///
+    /// assert_eq!(path.find_subpath("bin"), None);
     /// ```
-    pub fn normalize(&self) -> Utf8PathBuf<T> {
-        let mut components = Vec::new();
-        for component in self.components() {
-            if !component.is_current() && !component.is_parent() {
-                components.push(component);
-            } else if component.is_parent() {
-                if let Some(last) = components.last() {
-                    if last.is_normal() {
-                        components.pop();
-                    }
-                }
+    pub fn find_subpath<P: AsRef<Utf8Path<T>>>(&self, needle: P) -> Option<(usize, Range<usize>)> {
+        self._find_subpath(needle.as_ref())
+    }
+
+    fn _find_subpath(&self, needle: &Utf8Path<T>) -> Option<(usize, Range<usize>)> {
+        let mut components = self.components();
+        let mut index = 0;
+
+        loop {
+            if let Some((span, _)) = self.match_subpath(&components, needle) {
+                return Some((index, span));
             }
-        }
 
-        let mut path = Utf8PathBuf::<T>::new();
+            components.next()?;
+            index += 1;
+        }
+    }
 
-        for component in components {
-            path.push(component.as_str());
+    /// Attempts to match `needle` against the components of `self` starting at `components`,
+    /// returning the span of the matching components and the components remaining after them
+    fn match_subpath<'a>(
+        &'a self,
+        components: &<T as Utf8Encoding<'a>>::Components,
+        needle: &'a Utf8Path<T>,
+    ) -> Option<(Range<usize>, <T as Utf8Encoding<'a>>::Components)> {
+        let offset = |rest: &str| self.inner.len() - rest.len();
+        let start = offset(components.as_str());
+        let mut end = start;
+        let mut candidate = components.clone();
+        for a in needle.components() {
+            let rest = candidate.as_str();
+            match candidate.next() {
+                Some(b) if a == b => end = offset(rest) + b.len(),
+                _ => return None,
+            }
         }
 
-        path
+        Some((start..end, candidate))
     }
 
-    /// Converts a path to an absolute form by [`normalizing`] the path, returning a
-    /// [`Utf8PathBuf`].
+    /// Creates an owned [`Utf8PathBuf`] where every non-overlapping occurrence of the components of
+    /// `from` within `self` is replaced with `to`.
     ///
-    /// In the case that the path is relative, the current working directory is prepended prior to
-    /// normalizing.
+    /// Occurrences are located the same way as with [`Utf8Path::find_subpath`], so a `from` that
+    /// begins with a root or prefix only matches at the start of `self`. Bytes of `self` outside
+    /// of the replaced components, including separators, are kept as-is. Replacing with an empty
+    /// path removes the matching components entirely.
     ///
-    /// [`normalizing`]: Utf8Path::normalize
+    /// If `from` has no components, the path is returned unchanged.
     ///
     /// # Examples
     ///
     /// ```
-    /// use typed_path::{utils, Utf8Path, Utf8UnixEncoding};
+    /// use typed_path::{Utf8Path, Utf8UnixEncoding, Utf8WindowsEncoding};
     ///
-    /// // With an absolute path, it is just normalized
-    /// let path = Utf8Path::<Utf8UnixEncoding>::new("/a/b/../c/./d");
-    /// assert_eq!(path.absolutize().unwrap(), Utf8Path::new("/a/c/d"));
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// let path = Utf8Path::<Utf8UnixEncoding>::new("/repo/src/lib/src/main.rs");
     ///
-    /// // With a relative path, it is first joined with the current working directory
-    /// // and then normalized
-    /// let cwd = utils::utf8_current_dir().unwrap().with_encoding::<Utf8UnixEncoding>();
-    /// let path = cwd.join(Utf8Path::new("a/b/../c/./d"));
-    /// assert_eq!(path.absolutize().unwrap(), cwd.join(Utf8Path::new("a/c/d")));
+    /// assert_eq!(
+    ///     path.replace_components("src", "generated").as_str(),
+    ///     "/repo/generated/lib/generated/main.rs",
+    /// );
+    /// assert_eq!(path.replace_components("lib/src", "").as_str(), "/repo/src/main.rs");
+    ///
+    /// // Anchored components only match at the start of the path
+    /// assert_eq!(
+    ///     path.replace_components("/repo", "/mnt/repo").as_str(),
+    ///     "/mnt/repo/src/lib/src/main.rs",
+    /// );
+    /// assert_eq!(path.replace_components("/src", "gen").as_str(), path.as_str());
+    ///
+    /// // Windows components are matched across either separator
+    /// let path = Utf8Path::<Utf8WindowsEncoding>::new(r"C:\repo/src\main.rs");
+    /// assert_eq!(path.replace_components(r"repo\src", "out").as_str(), r"C:\out\main.rs");
     /// ```
-    #[cfg(all(feature = "std", not(target_family = "wasm")))]
-    pub fn absolutize(&self) -> std::io::Result<Utf8PathBuf<T>> {
-        if self.is_absolute() {
-            Ok(self.normalize())
-        } else {
-            // Get the cwd as a native path and convert to this path's encoding
-            let cwd = crate::utils::utf8_current_dir()?.with_encoding();
+    pub fn replace_components<P, Q>(&self, from: P, to: Q) -> Utf8PathBuf<T>
+    where
+        P: AsRef<Utf8Path<T>>,
+        Q: AsRef<Utf8Path<T>>,
+    {
+        self._replace_components(from.as_ref(), to.as_ref())
+    }
 
-            Ok(cwd.join(self).normalize())
+    fn _replace_components(&self, from: &Utf8Path<T>, to: &Utf8Path<T>) -> Utf8PathBuf<T> {
+        if from.components().next().is_none() {
+            return self.to_path_buf();
+        }
+
+        let mut inner = String::with_capacity(self.inner.len());
+        let mut cursor = 0;
+        let mut components = self.components();
+
+        loop {
+            if let Some((span, rest)) = self.match_subpath(&components, from) {
+                inner.push_str(&self.inner[cursor..span.start]);
+
+                // NOTE: When removing components, also drop the separators that follow them so
+                //       that the neighboring components are not left with an empty gap
+                if to.inner.is_empty() && !rest.as_str().is_empty() {
+                    cursor = self.inner.len() - rest.as_str().len();
+                } else {
+                    inner.push_str(&to.inner);
+                    cursor = span.end;
+                }
+
+                components = rest;
+            } else if components.next().is_none() {
+                break;
+            }
+        }
+
+        inner.push_str(&self.inner[cursor..]);
+        Utf8PathBuf {
+            _encoding: PhantomData,
+            inner,
         }
     }
 
-    /// Creates an owned [`Utf8PathBuf`] with `path` adjoined to `self`.
+    /// Creates an owned [`Utf8PathBuf`] where each component of `self` is replaced with the string
+    /// returned by `f`.
     ///
-    /// See [`Utf8PathBuf::push`] for more details on what it means to adjoin a path.
+    /// Separators between components are kept as-is, so mapping each component to itself
+    /// reproduces the original path other than any root being written with the encoding's
+    /// separator.
     ///
     /// # Examples
     ///
     /// ```
-    /// use typed_path::{Utf8Path, Utf8PathBuf, Utf8UnixEncoding};
+    /// use typed_path::{Utf8Component, Utf8Path, Utf8UnixEncoding};
     ///
     /// // NOTE: A path cannot be created on its own without a defined encoding
-    /// assert_eq!(
+    /// let path = Utf8Path::<Utf8UnixEncoding>::new("/repo/src/main.rs");
+    ///
+    /// let mapped = path.map_components(|c| {
+    ///     if c.as_str() == "src" {
+    ///         "generated"
+    ///     } else {
+    ///         c.as_str()
+    ///     }
+    /// });
+    /// assert_eq!(mapped.as_str(), "/repo/generated/main.rs");
+    ///
+    /// let upper = path.map_components(|c| c.as_str().to_ascii_uppercase());
+    /// assert_eq!(upper.as_str(), "/REPO/SRC/MAIN.RS");
+    /// ```
+    pub fn map_components<'a, F, C>(&'a self, mut f: F) -> Utf8PathBuf<T>
+    where
+        F: FnMut(<<T as Utf8Encoding<'a>>::Components as Utf8Components<'a>>::Component) -> C,
+        C: AsRef<str>,
+    {
+        let mut inner = String::with_capacity(self.inner.len());
+        let mut cursor = 0;
+        let mut components = self.components();
+
+        loop {
+            let start = self.inner.len() - components.as_str().len();
+            let component = match components.next() {
+                Some(component) => component,
+                None => break,
+            };
+
+            inner.push_str(&self.inner[cursor..start]);
+            cursor = start + component.len();
+            inner.push_str(f(component).as_ref());
+        }
+
+        inner.push_str(&self.inner[cursor..]);
+        Utf8PathBuf {
+            _encoding: PhantomData,
+            inner,
+        }
+    }
+
+    /// Determines whether `other` refers to the same place as `self` after lexical
+    /// normalization, using the case rules of the encoding.
+    ///
+    /// See [`Path::normalized_eq`] for more details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Utf8Path, Utf8UnixEncoding, Utf8WindowsEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// let path = Utf8Path::<Utf8UnixEncoding>::new("/etc/./ssh//config");
+    /// assert!(path.normalized_eq("/etc/ssh/config"));
+    /// assert!(path.normalized_eq("/etc/ssl/../ssh/config"));
+    /// assert!(!path.normalized_eq("/etc/SSH/config"));
+    ///
+    /// // Windows paths ignore ascii case
+    /// let path = Utf8Path::<Utf8WindowsEncoding>::new(r"C:\Program Files\App");
+    /// assert!(path.normalized_eq(r"c:/PROGRAM FILES/app/"));
+    /// ```
+    pub fn normalized_eq<P: AsRef<Utf8Path<T>>>(&self, other: P) -> bool {
+        self._equivalent_with(other.as_ref(), &PathEquivalence::new())
+    }
+
+    /// Determines whether `other` refers to the same place as `self` using the given
+    /// [`PathEquivalence`] rules.
+    ///
+    /// See [`Path::normalized_eq`] for the rules used by default.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{PathEquivalence, Utf8Path, Utf8UnixEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// let path = Utf8Path::<Utf8UnixEncoding>::new("/a/b/../c");
+    ///
+    /// assert!(path.equivalent_with("/a/c", &PathEquivalence::new()));
+    /// assert!(!path.equivalent_with("/a/c", &PathEquivalence::new().resolve_parent(false)));
+    /// assert!(path.equivalent_with("/A/C", &PathEquivalence::new().case_sensitive(false)));
+    /// ```
+    pub fn equivalent_with<P: AsRef<Utf8Path<T>>>(
+        &self,
+        other: P,
+        rules: &PathEquivalence,
+    ) -> bool {
+        self._equivalent_with(other.as_ref(), rules)
+    }
+
+    fn _equivalent_with(&self, other: &Utf8Path<T>, rules: &PathEquivalence) -> bool {
+        let windows = self.has_windows_encoding();
+        let has_parent = |path: &Utf8Path<T>| path.components().any(|c| c.is_parent());
+
+        if rules.needs_resolve(has_parent(self), has_parent(other)) {
+            let options = NormalizationOptions::new().preserve_leading_parent(true);
+            let (a, b) = (
+                self.normalize_with(&options),
+                other.normalize_with(&options),
+            );
+            rules.iter_eq(
+                windows,
+                a.components().map(|c| (c.kind(), c.as_str().as_bytes())),
+                b.components().map(|c| (c.kind(), c.as_str().as_bytes())),
+            )
+        } else {
+            rules.iter_eq(
+                windows,
+                self.components()
+                    .filter(|c| !c.is_current())
+                    .map(|c| (c.kind(), c.as_str().as_bytes())),
+                other
+                    .components()
+                    .filter(|c| !c.is_current())
+                    .map(|c| (c.kind(), c.as_str().as_bytes())),
+            )
+        }
+    }
+
+    /// Creates an owned [`Utf8PathBuf`] like `self` but with its component text made lowercase,
+    /// as defined by [`str::to_lowercase`].
+    ///
+    /// The structure of a prefix is kept intact, such as the `UNC` of a Windows `\\?\UNC\` prefix
+    /// which would otherwise stop being recognized. Windows prefixes still have their drive
+    /// letter, server, share, and names transformed, while the prefixes of other encodings (like
+    /// Plan 9 device names, whose case is significant) are left as they are.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Utf8Path, Utf8PathBuf, Utf8WindowsEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// let path = Utf8Path::<Utf8WindowsEncoding>::new(r"\\?\UNC\Server\Share\ÉTÉ\Notes.TXT");
+    /// assert_eq!(
+    ///     path.to_lowercase(),
+    ///     Utf8PathBuf::from(r"\\?\UNC\server\share\été\notes.txt"),
+    /// );
+    /// ```
+    pub fn to_lowercase(&self) -> Utf8PathBuf<T> {
+        self.to_case(str::to_lowercase)
+    }
+
+    /// Creates an owned [`Utf8PathBuf`] like `self` but with its component text made uppercase,
+    /// as defined by [`str::to_uppercase`].
+    ///
+    /// See [`Utf8Path::to_lowercase`] for how prefixes are handled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Utf8Path, Utf8PathBuf, Utf8WindowsEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// let path = Utf8Path::<Utf8WindowsEncoding>::new(r"\\?\UNC\server\share\straße");
+    /// assert_eq!(path.to_uppercase(), Utf8PathBuf::from(r"\\?\UNC\SERVER\SHARE\STRASSE"));
+    /// ```
+    pub fn to_uppercase(&self) -> Utf8PathBuf<T> {
+        self.to_case(str::to_uppercase)
+    }
+
+    fn to_case(&self, f: fn(&str) -> String) -> Utf8PathBuf<T> {
+        let s = self.as_str();
+        let keep = self.case_preserved_range();
+
+        let mut cased = f(&s[..keep.start]);
+        cased.push_str(&s[keep.clone()]);
+        cased.push_str(&f(&s[keep.end..]));
+        Utf8PathBuf::from(cased)
+    }
+
+    /// Returns the range of bytes that case transforms leave alone, which is the `UNC` of a
+    /// Windows verbatim UNC prefix or the whole prefix of any other encoding
+    pub(crate) fn case_preserved_range(&self) -> Range<usize> {
+        match self.components().next() {
+            Some(c) if c.kind() == ComponentKind::Prefix => {
+                if self.has_windows_encoding() {
+                    WindowsEncoding::case_preserved_range(self.as_str().as_bytes())
+                } else {
+                    0..c.len()
+                }
+            }
+            _ => 0..0,
+        }
+    }
+
+    /// Extracts the stem (non-extension) portion of [`self.file_name`].
+    ///
+    /// [`self.file_name`]: Utf8Path::file_name
+    ///
+    /// The stem is:
+    ///
+    /// * [`None`], if there is no file name;
+    /// * The entire file name if there is no embedded `.`;
+    /// * The entire file name if the file name begins with `.` and has no other `.`s within;
+    /// * Otherwise, the portion of the file name before the final `.`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Utf8Path, Utf8UnixEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// assert_eq!("foo", Utf8Path::<Utf8UnixEncoding>::new("foo.rs").file_stem().unwrap());
+    /// assert_eq!("foo.tar", Utf8Path::<Utf8UnixEncoding>::new("foo.tar.gz").file_stem().unwrap());
+    /// ```
+    ///
+    pub fn file_stem(&self) -> Option<&str> {
+        self.file_name()
+            .map(helpers::rsplit_file_at_dot)
+            .and_then(|(before, after)| before.or(after))
+    }
+
+    /// Extracts the extension of [`self.file_name`], if possible.
+    ///
+    /// The extension is:
+    ///
+    /// * [`None`], if there is no file name;
+    /// * [`None`], if there is no embedded `.`;
+    /// * [`None`], if the file name begins with `.` and has no other `.`s within;
+    /// * Otherwise, the portion of the file name after the final `.`
+    ///
+    /// [`self.file_name`]: Utf8Path::file_name
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Utf8Path, Utf8UnixEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// assert_eq!("rs", Utf8Path::<Utf8UnixEncoding>::new("foo.rs").extension().unwrap());
+    /// assert_eq!("gz", Utf8Path::<Utf8UnixEncoding>::new("foo.tar.gz").extension().unwrap());
+    /// ```
+    pub fn extension(&self) -> Option<&str> {
+        self.file_name()
+            .map(helpers::rsplit_file_at_dot)
+            .and_then(|(before, after)| before.and(after))
+    }
+
+    /// Returns an owned [`Utf8PathBuf`] by resolving `..` and `.` segments.
+    ///
+    /// When multiple, sequential path segment separation characters are found (e.g. `/` for Unix
+    /// and either `\` or `/` on Windows), they are replaced by a single instance of the
+    /// platform-specific path segment separator (`/` on Unix and `\` on Windows).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Utf8Path, Utf8PathBuf, Utf8UnixEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// assert_eq!(
+    ///     Utf8Path::<Utf8UnixEncoding>::new("foo/bar//baz/./asdf/quux/..").normalize(),
+    ///     Utf8PathBuf::from("foo/bar/baz/asdf"),
+    /// );
+    /// ```
+    ///
+    /// When starting with a root directory, any `..` segment whose parent is the root directory
+    /// will be filtered out:
+    ///
+    /// ```
+    /// use typed_path::{Utf8Path, Utf8PathBuf, Utf8UnixEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// assert_eq!(
+    ///     Utf8Path::<Utf8UnixEncoding>::new("/../foo").normalize(),
+    ///     Utf8PathBuf::from("/foo"),
+    /// );
+    /// ```
+    ///
+    /// If any `..` is left unresolved as the path is relative and no parent is found, it is
+    /// discarded:
+    ///
+    /// ```
+    /// use typed_path::{Utf8Path, Utf8PathBuf, Utf8UnixEncoding, Utf8WindowsEncoding};
+    ///
+    /// assert_eq!(
+    ///     Utf8Path::<Utf8UnixEncoding>::new("../foo/..").normalize(),
+    ///     Utf8PathBuf::from(""),
+    /// );
+    ///
+    /// //Windows prefixes also count this way, but the prefix remains
+    /// assert_eq!(
+    ///     Utf8Path::<Utf8WindowsEncoding>::new(r"C:..\foo\..").normalize(),
+    ///     Utf8PathBuf::from(r"C:"),
+    /// );
+    /// ```
+    pub fn normalize(&self) -> Utf8PathBuf<T> {
+        trace_span!("normalize", encoding = T::label(), len = self.inner.len());
+
+        let mut components = Vec::new();
+        for component in self.components() {
+            if !component.is_current() && !component.is_parent() {
+                components.push(component);
+            } else if component.is_parent() {
+                if let Some(last) = components.last() {
+                    if last.is_normal() {
+                        components.pop();
+                    }
+                }
+            }
+        }
+
+        let mut path = Utf8PathBuf::<T>::new();
+        let current = <<<T as Utf8Encoding>::Components as Utf8Components>::Component as Utf8Component>::current();
+        let separator =
+            <<<T as Utf8Encoding>::Components as Utf8Components>::Component as Utf8Component>::root(
+            );
+        let mut components = components.into_iter().peekable();
+
+        while let Some(component) = components.next() {
+            match components.peek() {
+                // Push a prefix together with the root following it, as pushing a rooted path can
+                // replace the prefix, such as for Plan 9 device roots
+                Some(root)
+                    if component.kind() == ComponentKind::Prefix
+                        && root.kind() == ComponentKind::RootDir =>
+                {
+                    let mut anchor = component.as_str().to_string();
+                    anchor.push_str(root.as_str());
+                    path.push(anchor.as_str());
+                    components.next();
+                }
+
+                // Keep a normal component leading the path from being read as something else,
+                // such as `#c` after resolving `./#c` for Plan 9 or `C:` after resolving `.\C:`
+                // for Windows
+                _ if path.as_str().is_empty()
+                    && component.is_normal()
+                    && matches!(
+                        Utf8Path::<T>::new(component.as_str()).components().next(),
+                        Some(c) if !c.is_normal()
+                    ) =>
+                {
+                    let mut relative = current.as_str().to_string();
+                    relative.push_str(separator.as_str());
+                    relative.push_str(component.as_str());
+                    path.push(relative.as_str());
+                }
+
+                _ => path.push(component.as_str()),
+            }
+        }
+
+        path
+    }
+
+    /// Normalizes the path like [`Utf8Path::normalize`], borrowing `self` instead of allocating
+    /// when it is already normalized.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Utf8Path, Utf8WindowsEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// let path = Utf8Path::<Utf8WindowsEncoding>::new(r"C:\Users\me");
+    /// assert!(path.normalize_cow().is_borrowed());
+    ///
+    /// let path = Utf8Path::<Utf8WindowsEncoding>::new(r"C:\Users\.\me");
+    /// assert!(path.normalize_cow().is_owned());
+    /// assert_eq!(path.normalize_cow().as_path(), Utf8Path::new(r"C:\Users\me"));
+    /// ```
+    pub fn normalize_cow(&self) -> CowUtf8Path<'_, T> {
+        if is_normalized(self) {
+            return CowUtf8Path::borrowed(self);
+        }
+
+        let normalized = self.normalize();
+        if normalized.as_str() == self.as_str() {
+            CowUtf8Path::borrowed(self)
+        } else {
+            CowUtf8Path::owned(normalized)
+        }
+    }
+
+    /// Returns an owned [`Utf8PathBuf`] by lexically cleaning the path in the same way as Go's
+    /// `path/filepath.Clean`.
+    ///
+    /// See [`Path::clean`] for more details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Utf8Path, Utf8PathBuf, Utf8UnixEncoding, Utf8WindowsEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// assert_eq!(
+    ///     Utf8Path::<Utf8UnixEncoding>::new("a/../../b/./c/").clean(),
+    ///     Utf8PathBuf::from("../b/c"),
+    /// );
+    /// assert_eq!(Utf8Path::<Utf8UnixEncoding>::new("/../a").clean(), Utf8PathBuf::from("/a"));
+    /// assert_eq!(Utf8Path::<Utf8UnixEncoding>::new("a/..").clean(), Utf8PathBuf::from("."));
+    ///
+    /// assert_eq!(
+    ///     Utf8Path::<Utf8WindowsEncoding>::new(r"C:a\..").clean(),
+    ///     Utf8PathBuf::from("C:."),
+    /// );
+    /// ```
+    pub fn clean(&self) -> Utf8PathBuf<T> {
+        let mut path =
+            self.normalize_with(&NormalizationOptions::new().preserve_leading_parent(true));
+
+        if !path.has_root() && path.split_anchor().1.as_str().is_empty() {
+            // NOTE: Extend directly, as pushing `.` onto a prefix is a no-op
+            path.inner.push_str(<
+                <<T as Utf8Encoding>::Components as Utf8Components>::Component
+                as Utf8Component
+            >::current().as_str());
+        }
+
+        path
+    }
+
+    /// Returns an owned [`Utf8PathBuf`] by normalizing the path using the given
+    /// [`NormalizationOptions`].
+    ///
+    /// With the default options, this is the same as [`Utf8Path::normalize`].
+    ///
+    /// See [`Path::normalize_with`] for more details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{NormalizationOptions, Utf8Path, Utf8PathBuf, Utf8UnixEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// let path = Utf8Path::<Utf8UnixEncoding>::new("../foo//./bar/..");
+    ///
+    /// assert_eq!(
+    ///     path.normalize_with(&NormalizationOptions::new()),
+    ///     Utf8PathBuf::from("foo"),
+    /// );
+    ///
+    /// assert_eq!(
+    ///     path.normalize_with(&NormalizationOptions::new().preserve_leading_parent(true)),
+    ///     Utf8PathBuf::from("../foo"),
+    /// );
+    ///
+    /// assert_eq!(
+    ///     path.normalize_with(
+    ///         &NormalizationOptions::new()
+    ///             .collapse_separators(false)
+    ///             .remove_current(false)
+    ///             .resolve_parent(false)
+    ///     ),
+    ///     Utf8PathBuf::from("../foo//./bar/.."),
+    /// );
+    /// ```
+    pub fn normalize_with(&self, options: &NormalizationOptions) -> Utf8PathBuf<T> {
+        trace_span!(
+            "normalize_with",
+            encoding = T::label(),
+            len = self.inner.len()
+        );
+
+        let separator =
+            <<<T as Utf8Encoding>::Components as Utf8Components>::Component as Utf8Component>::root(
+            );
+        let components = self.raw_components();
+        let mut path = Utf8PathBuf::<T>::new();
+
+        for piece in options.apply(&components, self.has_root(), separator.as_str().as_bytes()) {
+            // SAFETY: every piece is either a slice of our str split at an ascii character, or
+            //         a copy of one with only ascii characters changed
+            path.inner
+                .push_str(unsafe { core::str::from_utf8_unchecked(&piece) });
+        }
+
+        path
+    }
+
+    /// Returns each component of the path alongside the raw bytes it occupies.
+    pub(crate) fn raw_components(&self) -> Vec<RawComponent<'_>> {
+        let mut components = self.components();
+        let mut raw = Vec::new();
+
+        loop {
+            let rest = components.as_str();
+            let component = match components.next() {
+                Some(component) => component,
+                None => break,
+            };
+
+            // Consuming a component also consumes the separators that follow it
+            let consumed = &rest[..rest.len() - components.as_str().len()];
+            let (bytes, gap) = consumed.as_bytes().split_at(component.len());
+
+            raw.push(RawComponent {
+                kind: component.kind(),
+                canonical: component.as_str().as_bytes(),
+                raw: bytes,
+                gap,
+            });
+        }
+
+        raw
+    }
+
+    /// Converts a path to an absolute form by [`normalizing`] the path, returning a
+    /// [`Utf8PathBuf`].
+    ///
+    /// In the case that the path is relative, the current working directory is prepended prior to
+    /// normalizing.
+    ///
+    /// [`normalizing`]: Utf8Path::normalize
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{utils, Utf8Path, Utf8UnixEncoding};
+    ///
+    /// // With an absolute path, it is just normalized
+    /// let path = Utf8Path::<Utf8UnixEncoding>::new("/a/b/../c/./d");
+    /// assert_eq!(path.absolutize().unwrap(), Utf8Path::new("/a/c/d"));
+    ///
+    /// // With a relative path, it is first joined with the current working directory
+    /// // and then normalized
+    /// let cwd = utils::utf8_current_dir().unwrap().with_encoding::<Utf8UnixEncoding>();
+    /// let path = cwd.join(Utf8Path::new("a/b/../c/./d"));
+    /// assert_eq!(path.absolutize().unwrap(), cwd.join(Utf8Path::new("a/c/d")));
+    /// ```
+    #[cfg(all(feature = "std", not(target_family = "wasm")))]
+    pub fn absolutize(&self) -> std::io::Result<Utf8PathBuf<T>> {
+        if self.is_absolute() {
+            Ok(self.normalize())
+        } else {
+            // Get the cwd as a native path and convert to this path's encoding
+            let cwd = crate::utils::utf8_current_dir()?.with_encoding();
+
+            Ok(cwd.join(self).normalize())
+        }
+    }
+
+    /// Creates an owned [`Utf8PathBuf`] with `path` adjoined to `self`.
+    ///
+    /// See [`Utf8PathBuf::push`] for more details on what it means to adjoin a path.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Utf8Path, Utf8PathBuf, Utf8UnixEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// assert_eq!(
     ///     Utf8Path::<Utf8UnixEncoding>::new("/etc").join("passwd"),
     ///     Utf8PathBuf::from("/etc/passwd"),
     /// );
@@ -637,94 +2025,556 @@ where
         buf
     }
 
-    /// Creates an owned [`Utf8PathBuf`] with `path` adjoined to `self`, checking the `path` to
-    /// ensure it is safe to join. _When dealing with user-provided paths, this is the preferred
-    /// method._
+    /// Creates an owned [`Utf8PathBuf`] with `path` adjoined to `self`, checking the `path` to
+    /// ensure it is safe to join. _When dealing with user-provided paths, this is the preferred
+    /// method._
+    ///
+    /// See [`Utf8PathBuf::push_checked`] for more details on what it means to adjoin a path
+    /// safely.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{CheckedPathError, Utf8Path, Utf8PathBuf, Utf8UnixEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// let path = Utf8Path::<Utf8UnixEncoding>::new("/etc");
+    ///
+    /// // A valid path can be joined onto the existing one
+    /// assert_eq!(path.join_checked("passwd"), Ok(Utf8PathBuf::from("/etc/passwd")));
+    ///
+    /// // An invalid path will result in an error
+    /// assert_eq!(path.join_checked("/sneaky/replacement"), Err(CheckedPathError::UnexpectedRoot));
+    /// ```
+    pub fn join_checked<P: AsRef<Utf8Path<T>>>(
+        &self,
+        path: P,
+    ) -> Result<Utf8PathBuf<T>, CheckedPathError> {
+        self._join_checked(path.as_ref())
+    }
+
+    fn _join_checked(&self, path: &Utf8Path<T>) -> Result<Utf8PathBuf<T>, CheckedPathError> {
+        let mut buf = self.to_path_buf();
+        buf.push_checked(path)?;
+        Ok(buf)
+    }
+
+    /// Creates an owned [`Utf8PathBuf`] with `path` adjoined to `self` using the given [`PushMode`]
+    /// to decide what happens when `path` has its own root or prefix.
+    ///
+    /// See [`Utf8PathBuf::push_with_mode`] for more details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{CheckedPathError, PushMode, Utf8Path, Utf8PathBuf, Utf8UnixEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// let path = Utf8Path::<Utf8UnixEncoding>::new("/srv");
+    ///
+    /// assert_eq!(path.join_with_mode("/etc", PushMode::Replace), Ok(Utf8PathBuf::from("/etc")));
+    /// assert_eq!(
+    ///     path.join_with_mode("/etc", PushMode::Append),
+    ///     Ok(Utf8PathBuf::from("/srv/etc")),
+    /// );
+    /// assert_eq!(
+    ///     path.join_with_mode("/etc", PushMode::Reject),
+    ///     Err(CheckedPathError::UnexpectedRoot),
+    /// );
+    /// ```
+    pub fn join_with_mode<P: AsRef<Utf8Path<T>>>(
+        &self,
+        path: P,
+        mode: PushMode,
+    ) -> Result<Utf8PathBuf<T>, CheckedPathError> {
+        let mut buf = self.to_path_buf();
+        buf.push_with_mode(path, mode)?;
+        Ok(buf)
+    }
+
+    /// Creates an owned [`Utf8PathBuf`] with `path` adjoined to `self`, never replacing `self` even
+    /// if `path` is absolute. This is [`join_with_mode`] using [`PushMode::Append`].
+    ///
+    /// [`join_with_mode`]: Utf8Path::join_with_mode
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Utf8Path, Utf8PathBuf, Utf8UnixEncoding, Utf8WindowsEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// let path = Utf8Path::<Utf8UnixEncoding>::new("/srv/www");
+    /// assert_eq!(path.join_keeping_base("/index.html"), Utf8PathBuf::from("/srv/www/index.html"));
+    ///
+    /// // Windows prefixes are dropped alongside the root
+    /// let path = Utf8Path::<Utf8WindowsEncoding>::new(r"C:\srv");
+    /// assert_eq!(path.join_keeping_base(r"D:\www"), Utf8PathBuf::from(r"C:\srv\www"));
+    /// ```
+    pub fn join_keeping_base<P: AsRef<Utf8Path<T>>>(&self, path: P) -> Utf8PathBuf<T> {
+        let mut buf = self.to_path_buf();
+        buf.push_keeping_base(path);
+        buf
+    }
+
+    /// Creates an owned [`Utf8PathBuf`] with `path` adjoined to `self`, failing if `path` has a
+    /// root or prefix. This is [`join_with_mode`] using [`PushMode::Reject`].
+    ///
+    /// Unlike [`join_checked`], no other validation is performed on `path`.
+    ///
+    /// [`join_checked`]: Utf8Path::join_checked
+    /// [`join_with_mode`]: Utf8Path::join_with_mode
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{CheckedPathError, Utf8Path, Utf8PathBuf, Utf8UnixEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// let path = Utf8Path::<Utf8UnixEncoding>::new("/srv");
+    /// assert_eq!(path.join_relative_only("../etc"), Ok(Utf8PathBuf::from("/srv/../etc")));
+    /// assert_eq!(path.join_relative_only("/etc"), Err(CheckedPathError::UnexpectedRoot));
+    /// ```
+    pub fn join_relative_only<P: AsRef<Utf8Path<T>>>(
+        &self,
+        path: P,
+    ) -> Result<Utf8PathBuf<T>, CheckedPathError> {
+        self.join_with_mode(path, PushMode::Reject)
+    }
+
+    /// Splits any leading prefix and root components off of the path, returning the relative
+    /// remainder alongside the error that [`PushMode::Reject`] would report for them.
+    pub(crate) fn split_anchor(&self) -> (Option<CheckedPathError>, &Utf8Path<T>) {
+        let mut components = self.components();
+        let mut error = None;
+
+        loop {
+            let rest = components.as_str();
+            match components.next() {
+                Some(c) if !c.is_normal() && !c.is_parent() && !c.is_current() => {
+                    if error.is_none() {
+                        error = Some(if c == Utf8Component::root() {
+                            CheckedPathError::UnexpectedRoot
+                        } else {
+                            CheckedPathError::UnexpectedPrefix
+                        });
+                    }
+                }
+                _ => return (error, Utf8Path::new(rest)),
+            }
+        }
+    }
+
+    /// Creates an owned [`Utf8PathBuf`] like `self` but with the given file name.
+    ///
+    /// See [`Utf8PathBuf::set_file_name`] for more details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Utf8Path, Utf8PathBuf, Utf8UnixEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// let path = Utf8Path::<Utf8UnixEncoding>::new("/tmp/foo.txt");
+    /// assert_eq!(path.with_file_name("bar.txt"), Utf8PathBuf::from("/tmp/bar.txt"));
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// let path = Utf8Path::<Utf8UnixEncoding>::new("/tmp");
+    /// assert_eq!(path.with_file_name("var"), Utf8PathBuf::from("/var"));
+    /// ```
+    pub fn with_file_name<S: AsRef<str>>(&self, file_name: S) -> Utf8PathBuf<T> {
+        self._with_file_name(file_name.as_ref())
+    }
+
+    fn _with_file_name(&self, file_name: &str) -> Utf8PathBuf<T> {
+        let mut buf = self.to_path_buf();
+        buf.set_file_name(file_name);
+        buf
+    }
+
+    /// Creates an owned [`Utf8PathBuf`] like `self` but with the given extension.
+    ///
+    /// See [`Utf8PathBuf::set_extension`] for more details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Utf8Path, Utf8PathBuf, Utf8UnixEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// let path = Utf8Path::<Utf8UnixEncoding>::new("foo.rs");
+    /// assert_eq!(path.with_extension("txt"), Utf8PathBuf::from("foo.txt"));
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// let path = Utf8Path::<Utf8UnixEncoding>::new("foo.tar.gz");
+    /// assert_eq!(path.with_extension(""), Utf8PathBuf::from("foo.tar"));
+    /// assert_eq!(path.with_extension("xz"), Utf8PathBuf::from("foo.tar.xz"));
+    /// assert_eq!(path.with_extension("").with_extension("txt"), Utf8PathBuf::from("foo.txt"));
+    /// ```
+    pub fn with_extension<S: AsRef<str>>(&self, extension: S) -> Utf8PathBuf<T> {
+        self._with_extension(extension.as_ref())
+    }
+
+    fn _with_extension(&self, extension: &str) -> Utf8PathBuf<T> {
+        let mut buf = self.to_path_buf();
+        buf.set_extension(extension);
+        buf
+    }
+
+    /// Returns true if the [`file_name`] of the path marks it as hidden by convention alone,
+    /// without consulting any filesystem attributes.
+    ///
+    /// See [`Path::is_hidden_by_name`] for more details.
+    ///
+    /// [`file_name`]: Utf8Path::file_name
+    /// [`Path::is_hidden_by_name`]: crate::Path::is_hidden_by_name
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Utf8UnixPath, Utf8WindowsPath};
+    ///
+    /// assert!(Utf8UnixPath::new("/home/user/.cache").is_hidden_by_name());
+    /// assert!(Utf8WindowsPath::new(r"C:\Share\Desktop.ini").is_hidden_by_name());
+    /// assert!(!Utf8WindowsPath::new(r"C:\Share\budget.xlsx").is_hidden_by_name());
+    /// ```
+    pub fn is_hidden_by_name(&self) -> bool {
+        match self.file_name() {
+            Some(name) => {
+                name.starts_with('.')
+                    || name.starts_with("~$")
+                    || name.eq_ignore_ascii_case("desktop.ini")
+                    || name.eq_ignore_ascii_case("thumbs.db")
+            }
+            None => false,
+        }
+    }
+
+    /// Guesses the media type of the path's content from its [`extension`], ignoring ascii case.
+    ///
+    /// See [`Path::mime_type`] for more details.
+    ///
+    /// [`extension`]: Utf8Path::extension
+    /// [`Path::mime_type`]: crate::Path::mime_type
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::Utf8UnixPath;
+    ///
+    /// assert_eq!(Utf8UnixPath::new("/assets/app.js").mime_type(), Some("text/javascript"));
+    /// assert_eq!(Utf8UnixPath::new("/assets/font.woff2").mime_type(), Some("font/woff2"));
+    /// assert_eq!(Utf8UnixPath::new("/assets/.env").mime_type(), None);
+    /// ```
+    pub fn mime_type(&self) -> Option<&'static str> {
+        mime::from_extension(self.extension()?.as_bytes())
+    }
+
+    /// Produces a path like `self` whose file name is not taken according to `exists`, trying
+    /// `file.txt`, `file (1).txt`, `file (2).txt`, and so on.
+    ///
+    /// See [`Path::uniquify`] for more details.
+    ///
+    /// [`Path::uniquify`]: crate::Path::uniquify
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Utf8Path, Utf8WindowsEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// let taken = [r"C:\Downloads\report.pdf", r"C:\Downloads\report (1).pdf"];
+    /// let path = Utf8Path::<Utf8WindowsEncoding>::new(r"C:\Downloads\report.pdf")
+    ///     .uniquify(|p| taken.contains(&p.as_str()))
+    ///     .unwrap();
+    /// assert_eq!(path.as_str(), r"C:\Downloads\report (2).pdf");
+    /// ```
+    pub fn uniquify<F>(&self, exists: F) -> Option<Utf8PathBuf<T>>
+    where
+        F: FnMut(&Utf8Path<T>) -> bool,
+    {
+        self.uniquify_with(&UniquifyOptions::new(), exists)
+    }
+
+    /// Produces a path like `self` whose file name is not taken according to `exists`, using
+    /// `options` to generate alternative file names.
+    ///
+    /// See [`Path::uniquify_with`] for more details.
+    ///
+    /// [`Path::uniquify_with`]: crate::Path::uniquify_with
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{UniquifyOptions, Utf8Path, Utf8UnixEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// let options = UniquifyOptions::new().max_component_len(Some(12));
+    /// let path = Utf8Path::<Utf8UnixEncoding>::new("/photos/été.jpg")
+    ///     .uniquify_with(&options, |p| p.as_str().ends_with("été.jpg"))
+    ///     .unwrap();
+    /// assert_eq!(path.as_str(), "/photos/ét (1).jpg");
+    /// ```
+    pub fn uniquify_with<F>(
+        &self,
+        options: &UniquifyOptions,
+        mut exists: F,
+    ) -> Option<Utf8PathBuf<T>>
+    where
+        F: FnMut(&Utf8Path<T>) -> bool,
+    {
+        let stem = self.file_stem()?;
+        if !exists(self) {
+            return Some(self.to_path_buf());
+        }
+
+        let extension = self.extension();
+        let mut n = options.first();
+        loop {
+            let name = options.file_name(stem.as_bytes(), extension.map(str::as_bytes), n);
+
+            // NOTE: Safe as the name is built from the valid UTF-8 of the stem, counter, and
+            //       extension, where the stem is only cut at a character boundary
+            let name = unsafe { String::from_utf8_unchecked(name) };
+            let candidate = self.with_file_name(name);
+            if !exists(&candidate) {
+                return Some(candidate);
+            }
+            n = n.checked_add(1)?;
+        }
+    }
+
+    /// Returns the cost of editing `self` into `other` according to the cost model in `options`,
+    /// comparing the two paths component by component and character by character.
+    ///
+    /// See [`Path::edit_distance`] for more details.
+    ///
+    /// [`Path::edit_distance`]: crate::Path::edit_distance
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Utf8Path, SuggestOptions, Utf8UnixEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// let path = Utf8Path::<Utf8UnixEncoding>::new("src/mian.rs");
+    /// assert_eq!(path.edit_distance("src/main.rs", &SuggestOptions::new()), 2);
+    ///
+    /// // Removing the `src` component costs its three characters and the component itself
+    /// assert_eq!(path.edit_distance("mian.rs", &SuggestOptions::new()), 5);
+    /// assert_eq!(path.edit_distance("mian.rs", &SuggestOptions::new().component(0)), 3);
+    /// ```
+    pub fn edit_distance<P: AsRef<Utf8Path<T>>>(
+        &self,
+        other: P,
+        options: &SuggestOptions,
+    ) -> usize {
+        let a: Vec<_> = self
+            .components()
+            .map(|c| c.as_str().chars().collect::<Vec<_>>())
+            .collect();
+        let b: Vec<_> = other
+            .as_ref()
+            .components()
+            .map(|c| c.as_str().chars().collect::<Vec<_>>())
+            .collect();
+        options.distance(&a, &b, char::eq_ignore_ascii_case)
+    }
+
+    /// Returns the candidate closest to `self` by [`Utf8Path::edit_distance`] using the default
+    /// [`SuggestOptions`], or `None` if no candidate is within a third of the length of `self` in
+    /// characters.
+    ///
+    /// See [`Path::suggest_nearest`] for more details.
+    ///
+    /// [`Path::suggest_nearest`]: crate::Path::suggest_nearest
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Utf8Path, Utf8UnixEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// let candidates = ["src/lib.rs", "src/main.rs", "tests/main.rs"];
+    ///
+    /// let path = Utf8Path::<Utf8UnixEncoding>::new("src/mian.rs");
+    /// assert_eq!(path.suggest_nearest(candidates), Some("src/main.rs"));
+    ///
+    /// let path = Utf8Path::<Utf8UnixEncoding>::new("docs/guide.md");
+    /// assert_eq!(path.suggest_nearest(candidates), None);
+    /// ```
+    pub fn suggest_nearest<I, P>(&self, candidates: I) -> Option<P>
+    where
+        I: IntoIterator<Item = P>,
+        P: AsRef<Utf8Path<T>>,
+    {
+        self.suggest_nearest_with(candidates, &SuggestOptions::new())
+    }
+
+    /// Returns the candidate closest to `self` by [`Utf8Path::edit_distance`] using the cost model
+    /// in `options`, or `None` if no candidate is within its maximum distance.
+    ///
+    /// See [`Path::suggest_nearest_with`] for more details.
+    ///
+    /// [`Path::suggest_nearest_with`]: crate::Path::suggest_nearest_with
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Utf8Path, SuggestOptions, Utf8WindowsEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// let candidates = [r"C:\Users\Public", r"C:\Users\public2"];
+    /// let path = Utf8Path::<Utf8WindowsEncoding>::new(r"C:\users\PUBLIC");
+    ///
+    /// let options = SuggestOptions::new().case_change(0).max_distance(Some(0));
+    /// assert_eq!(path.suggest_nearest_with(candidates, &options), Some(r"C:\Users\Public"));
+    /// ```
+    pub fn suggest_nearest_with<I, P>(&self, candidates: I, options: &SuggestOptions) -> Option<P>
+    where
+        I: IntoIterator<Item = P>,
+        P: AsRef<Utf8Path<T>>,
+    {
+        let mut limit = options.limit(self.as_str().chars().count());
+        let mut best = None;
+        for candidate in candidates {
+            let distance = self.edit_distance(&candidate, options);
+            if distance <= limit {
+                // Only a strictly closer candidate can replace the first one found
+                limit = match distance.checked_sub(1) {
+                    Some(limit) => limit,
+                    None => return Some(candidate),
+                };
+                best = Some(candidate);
+            }
+        }
+        best
+    }
+
+    /// Renders `self` for display within `max_width` columns by replacing leading directories with
+    /// `…`, always keeping the prefix, root, and file name.
     ///
-    /// See [`Utf8PathBuf::push_checked`] for more details on what it means to adjoin a path
-    /// safely.
+    /// See [`Path::abbreviate`] for more details.
+    ///
+    /// [`Path::abbreviate`]: crate::Path::abbreviate
     ///
     /// # Examples
     ///
     /// ```
-    /// use typed_path::{CheckedPathError, Utf8Path, Utf8PathBuf, Utf8UnixEncoding};
+    /// use typed_path::{Utf8Path, Utf8UnixEncoding};
     ///
     /// // NOTE: A path cannot be created on its own without a defined encoding
-    /// let path = Utf8Path::<Utf8UnixEncoding>::new("/etc");
-    ///
-    /// // A valid path can be joined onto the existing one
-    /// assert_eq!(path.join_checked("passwd"), Ok(Utf8PathBuf::from("/etc/passwd")));
-    ///
-    /// // An invalid path will result in an error
-    /// assert_eq!(path.join_checked("/sneaky/replacement"), Err(CheckedPathError::UnexpectedRoot));
-    /// ```
-    pub fn join_checked<P: AsRef<Utf8Path<T>>>(
-        &self,
-        path: P,
-    ) -> Result<Utf8PathBuf<T>, CheckedPathError> {
-        self._join_checked(path.as_ref())
-    }
+    /// let path = Utf8Path::<Utf8UnixEncoding>::new("projects/café/src/lib.rs");
+    /// assert_eq!(path.abbreviate(17), "…/café/src/lib.rs");
+    /// assert_eq!(path.abbreviate(16), "…/src/lib.rs");
+    /// ```
+    pub fn abbreviate(&self, max_width: usize) -> String {
+        let path = self.as_str();
+        if display_width(path) <= max_width {
+            return path.to_string();
+        }
 
-    fn _join_checked(&self, path: &Utf8Path<T>) -> Result<Utf8PathBuf<T>, CheckedPathError> {
-        let mut buf = self.to_path_buf();
-        buf.push_checked(path)?;
-        Ok(buf)
+        let mut head = String::new();
+        let mut rest = Vec::new();
+        for component in self.components() {
+            match component.kind() {
+                ComponentKind::Prefix | ComponentKind::RootDir if rest.is_empty() => {
+                    head.push_str(component.as_str())
+                }
+                _ => rest.push(component.as_str()),
+            }
+        }
+
+        match rest.split_last() {
+            Some((name, dirs)) if !dirs.is_empty() => {
+                abbreviate(&head, dirs, name, T::info().separator, max_width)
+            }
+            _ => path.to_string(),
+        }
     }
 
-    /// Creates an owned [`Utf8PathBuf`] like `self` but with the given file name.
+    /// Creates an owned [`Utf8PathBuf`] like `self` but with every normal component shortened to
+    /// at most `n_bytes` bytes.
     ///
-    /// See [`Utf8PathBuf::set_file_name`] for more details.
+    /// See [`Path::truncate_component_to`] for more details.
+    ///
+    /// [`Path::truncate_component_to`]: crate::Path::truncate_component_to
     ///
     /// # Examples
     ///
     /// ```
-    /// use typed_path::{Utf8Path, Utf8PathBuf, Utf8UnixEncoding};
-    ///
-    /// // NOTE: A path cannot be created on its own without a defined encoding
-    /// let path = Utf8Path::<Utf8UnixEncoding>::new("/tmp/foo.txt");
-    /// assert_eq!(path.with_file_name("bar.txt"), Utf8PathBuf::from("/tmp/bar.txt"));
+    /// use typed_path::{Utf8Path, Utf8UnixEncoding};
     ///
     /// // NOTE: A path cannot be created on its own without a defined encoding
-    /// let path = Utf8Path::<Utf8UnixEncoding>::new("/tmp");
-    /// assert_eq!(path.with_file_name("var"), Utf8PathBuf::from("/var"));
+    /// let path = Utf8Path::<Utf8UnixEncoding>::new("/$HOME/日本語のファイル.txt");
+    /// assert_eq!(path.truncate_component_to(16).as_str(), "/$HOME/日本語の.txt");
     /// ```
-    pub fn with_file_name<S: AsRef<str>>(&self, file_name: S) -> Utf8PathBuf<T> {
-        self._with_file_name(file_name.as_ref())
-    }
+    pub fn truncate_component_to(&self, n_bytes: usize) -> Utf8PathBuf<T> {
+        let mut path = String::new();
 
-    fn _with_file_name(&self, file_name: &str) -> Utf8PathBuf<T> {
-        let mut buf = self.to_path_buf();
-        buf.set_file_name(file_name);
-        buf
+        for component in self.raw_components() {
+            let (stem, ext) = if component.kind == ComponentKind::Normal {
+                truncate_name(component.raw, n_bytes)
+            } else {
+                (component.raw, &[][..])
+            };
+
+            for bytes in [stem, ext, component.gap] {
+                // NOTE: Safe as every piece is cut from the path at a character boundary
+                path.push_str(unsafe { core::str::from_utf8_unchecked(bytes) });
+            }
+        }
+
+        Utf8PathBuf::from(path)
     }
 
-    /// Creates an owned [`Utf8PathBuf`] like `self` but with the given extension.
+    /// Creates an owned [`Utf8PathBuf`] like `self` but with its [`file_name`] shortened such
+    /// that the entire path is at most `total_bytes` bytes, preserving the extension when
+    /// possible.
     ///
-    /// See [`Utf8PathBuf::set_extension`] for more details.
+    /// See [`Path::fit_within`] for more details.
+    ///
+    /// [`file_name`]: Utf8Path::file_name
+    /// [`Path::fit_within`]: crate::Path::fit_within
     ///
     /// # Examples
     ///
     /// ```
-    /// use typed_path::{Utf8Path, Utf8PathBuf, Utf8UnixEncoding};
-    ///
-    /// // NOTE: A path cannot be created on its own without a defined encoding
-    /// let path = Utf8Path::<Utf8UnixEncoding>::new("foo.rs");
-    /// assert_eq!(path.with_extension("txt"), Utf8PathBuf::from("foo.txt"));
+    /// use typed_path::{Utf8Path, Utf8WindowsEncoding};
     ///
     /// // NOTE: A path cannot be created on its own without a defined encoding
-    /// let path = Utf8Path::<Utf8UnixEncoding>::new("foo.tar.gz");
-    /// assert_eq!(path.with_extension(""), Utf8PathBuf::from("foo.tar"));
-    /// assert_eq!(path.with_extension("xz"), Utf8PathBuf::from("foo.tar.xz"));
-    /// assert_eq!(path.with_extension("").with_extension("txt"), Utf8PathBuf::from("foo.txt"));
-    /// ```
-    pub fn with_extension<S: AsRef<str>>(&self, extension: S) -> Utf8PathBuf<T> {
-        self._with_extension(extension.as_ref())
-    }
+    /// let path = Utf8Path::<Utf8WindowsEncoding>::new(r"C:\cache\données.bin");
+    /// assert_eq!(path.fit_within(17).unwrap().as_str(), r"C:\cache\donn.bin");
+    /// assert_eq!(path.fit_within(16).unwrap().as_str(), r"C:\cache\don.bin");
+    /// ```
+    pub fn fit_within(&self, total_bytes: usize) -> Option<Utf8PathBuf<T>> {
+        let len = self.as_str().len();
+        if len <= total_bytes {
+            return Some(self.to_path_buf());
+        }
 
-    fn _with_extension(&self, extension: &str) -> Utf8PathBuf<T> {
-        let mut buf = self.to_path_buf();
-        buf.set_extension(extension);
-        buf
+        let components = self.raw_components();
+        let (last, rest) = components.split_last()?;
+        if last.kind != ComponentKind::Normal {
+            return None;
+        }
+
+        let excess = len - total_bytes;
+        let (stem, ext) = truncate_name(last.raw, last.raw.len().checked_sub(excess)?);
+        if stem.is_empty() {
+            return None;
+        }
+
+        let mut path = String::new();
+        let pieces = rest
+            .iter()
+            .flat_map(|c| [c.raw, c.gap])
+            .chain([stem, ext, last.gap]);
+        for bytes in pieces {
+            // NOTE: Safe as every piece is cut from the path at a character boundary
+            path.push_str(unsafe { core::str::from_utf8_unchecked(bytes) });
+        }
+
+        Some(Utf8PathBuf::from(path))
     }
 
     /// Produces an iterator over the [`Utf8Component`]s of the path.
@@ -789,6 +2639,126 @@ where
         Utf8Iter::new(self.components())
     }
 
+    /// Produces an iterator over the path as it was written, alternating between components and
+    /// runs of separators.
+    ///
+    /// Unlike [`Utf8Path::components`], nothing is normalized away: repeated separators stay in a
+    /// single [`Utf8PathSegment::Separator`], a root directory is yielded as part of the
+    /// separators that follow it, and any `.` that parsing would skip is yielded as a component.
+    /// Joining every segment reproduces the path exactly.
+    ///
+    /// [`Utf8PathSegment::Separator`]: crate::Utf8PathSegment::Separator
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Utf8Path, Utf8PathSegment, Utf8WindowsEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// let path = Utf8Path::<Utf8WindowsEncoding>::new(r"C:\\Users/me");
+    /// assert_eq!(
+    ///     path.segments_with_separators().collect::<Vec<_>>(),
+    ///     [
+    ///         Utf8PathSegment::Component("C:"),
+    ///         Utf8PathSegment::Separator(r"\\"),
+    ///         Utf8PathSegment::Component("Users"),
+    ///         Utf8PathSegment::Separator("/"),
+    ///         Utf8PathSegment::Component("me"),
+    ///     ],
+    /// );
+    /// ```
+    #[inline]
+    pub fn segments_with_separators(&self) -> Utf8SegmentsWithSeparators<'_, T> {
+        Utf8SegmentsWithSeparators::new(self.as_str())
+    }
+
+    /// Returns an object that implements [`Display`] for printing `self` relative to the `home`
+    /// directory, such as `~/project/x` for `/home/me/project/x`, or `%USERPROFILE%\Desktop` for
+    /// Windows paths. Paths outside of `home` are printed in full.
+    ///
+    /// See [`Path::display_relative_to_home`] for more details.
+    ///
+    /// [`Display`]: fmt::Display
+    /// [`Path::display_relative_to_home`]: crate::Path::display_relative_to_home
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Utf8Path, Utf8UnixEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// let home = Utf8Path::<Utf8UnixEncoding>::new("/home/me");
+    /// let path = Utf8Path::<Utf8UnixEncoding>::new("/home/me/project/x");
+    /// assert_eq!(path.display_relative_to_home(home).to_string(), "~/project/x");
+    /// assert_eq!(
+    ///     path.display_relative_to_home(home).placeholder("$HOME").to_string(),
+    ///     "$HOME/project/x",
+    /// );
+    /// ```
+    #[inline]
+    pub fn display_relative_to_home<'a>(&'a self, home: &'a Utf8Path<T>) -> Utf8HomeDisplay<'a, T> {
+        Utf8HomeDisplay {
+            path: self,
+            home,
+            placeholder: if self.has_windows_encoding() {
+                "%USERPROFILE%"
+            } else {
+                "~"
+            },
+        }
+    }
+
+    /// Produces an owned key whose plain byte order matches the order of paths as trees, so that
+    /// paths can be stored in key-value stores without a custom comparator.
+    ///
+    /// See [`Path::sort_key`] for more details.
+    ///
+    /// [`Path::sort_key`]: crate::Path::sort_key
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Utf8Path, Utf8WindowsEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// let key = |s| Utf8Path::<Utf8WindowsEncoding>::new(s).sort_key();
+    /// assert!(key(r"C:\foo") < key(r"C:\foo\bar"));
+    /// assert!(key(r"C:\foo\bar") < key(r"C:\foo-bar"));
+    /// assert_eq!(key(r"C:\foo\bar"), key(r"C:/foo//bar/"));
+    /// ```
+    pub fn sort_key(&self) -> Vec<u8> {
+        let mut key = Vec::with_capacity(self.inner.len() + 8);
+        for component in self.components() {
+            sort_key::push_component(&mut key, component.kind(), component.as_str().as_bytes());
+        }
+        key
+    }
+
+    /// Returns the bounds of the [sort keys] covering exactly `self` and its descendants, for
+    /// prefix scans over a key-value store ordered by sort key.
+    ///
+    /// See [`Path::range_bounds_for_subtree`] for more details.
+    ///
+    /// [sort keys]: Utf8Path::sort_key
+    /// [`Path::range_bounds_for_subtree`]: crate::Path::range_bounds_for_subtree
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::ops::RangeBounds;
+    /// use typed_path::{Utf8Path, Utf8WindowsEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// let key = |s| Utf8Path::<Utf8WindowsEncoding>::new(s).sort_key();
+    /// let bounds = Utf8Path::<Utf8WindowsEncoding>::new(r"C:\foo").range_bounds_for_subtree();
+    ///
+    /// assert!(bounds.contains(&key(r"C:\foo\bar.txt")));
+    /// assert!(!bounds.contains(&key(r"C:\foo-bar")));
+    /// ```
+    pub fn range_bounds_for_subtree(&self) -> (Bound<Vec<u8>>, Bound<Vec<u8>>) {
+        sort_key::subtree_bounds(self.sort_key())
+    }
+
     /// Creates an owned [`Utf8PathBuf`] like `self` but with a different encoding.
     ///
     /// # Note
@@ -825,6 +2795,13 @@ where
     where
         U: for<'enc> Utf8Encoding<'enc>,
     {
+        trace_span!(
+            "with_encoding",
+            from = T::label(),
+            to = U::label(),
+            len = self.inner.len(),
+        );
+
         // If we're the same, just return the path buf, which
         // we do with a fancy trick to convert it
         if T::label() == U::label() {
@@ -905,6 +2882,13 @@ where
     where
         U: for<'enc> Utf8Encoding<'enc>,
     {
+        trace_span!(
+            "with_encoding_checked",
+            from = T::label(),
+            to = U::label(),
+            len = self.inner.len(),
+        );
+
         let mut path = Utf8PathBuf::new();
 
         // For root, current, and parent we specially handle to convert to the appropriate type,
@@ -934,6 +2918,232 @@ where
         Ok(path)
     }
 
+    /// Like [`with_encoding_checked`], creates an owned [`Utf8PathBuf`] like `self` but with a
+    /// different encoding. Additionally, any character of a normal component that cannot appear
+    /// within a filename of the new encoding is handled according to `policy`.
+    ///
+    /// See [`Path::with_encoding_lossy_with`] for more details.
+    ///
+    /// [`with_encoding_checked`]: Utf8Path::with_encoding_checked
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CheckedPathError::InvalidFilename`] if a component cannot be represented and
+    /// `policy` is [`LossyPolicy::Error`], or if the replacement itself is not allowed within a
+    /// filename of the new encoding.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{LossyPolicy, Utf8Path, Utf8UnixEncoding, Utf8WindowsEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// let unix_path = Utf8Path::<Utf8UnixEncoding>::new("/notes/ü:ber?.txt");
+    ///
+    /// let windows_path = unix_path
+    ///     .with_encoding_lossy_with::<Utf8WindowsEncoding>(LossyPolicy::Replace('_'))
+    ///     .unwrap();
+    /// assert_eq!(windows_path.as_str(), r"\notes\ü_ber_.txt");
+    ///
+    /// let windows_path = unix_path
+    ///     .with_encoding_lossy_with::<Utf8WindowsEncoding>(LossyPolicy::PercentEncode)
+    ///     .unwrap();
+    /// assert_eq!(windows_path.as_str(), r"\notes\ü%3Aber%3F.txt");
+    /// ```
+    pub fn with_encoding_lossy_with<U>(
+        &self,
+        policy: LossyPolicy,
+    ) -> Result<Utf8PathBuf<U>, CheckedPathError>
+    where
+        U: for<'enc> Utf8Encoding<'enc>,
+    {
+        trace_span!(
+            "with_encoding_lossy_with",
+            from = T::label(),
+            to = U::label(),
+            len = self.inner.len(),
+        );
+
+        let mut path = Utf8PathBuf::new();
+
+        // For root, current, and parent we specially handle to convert to the appropriate type,
+        // otherwise we make sure each normal component survives as a single valid filename
+        for component in self.components() {
+            if component.is_root() {
+                path.push(<
+                        <<U as Utf8Encoding>::Components as Utf8Components>::Component
+                        as Utf8Component
+                    >::root().as_str());
+            } else if component.is_current() {
+                path.push(<
+                        <<U as Utf8Encoding>::Components as Utf8Components>::Component
+                        as Utf8Component
+                    >::current().as_str());
+            } else if component.is_parent() {
+                path.push(<
+                        <<U as Utf8Encoding>::Components as Utf8Components>::Component
+                        as Utf8Component
+                    >::parent().as_str());
+            } else if Utf8Path::<U>::new(component.as_str()).is_single_filename() {
+                path.push_checked(component.as_str())?;
+            } else {
+                let s = policy
+                    .sanitize_str(component.as_str(), Utf8Path::<U>::is_filename_char)
+                    .ok_or(CheckedPathError::InvalidFilename)?;
+
+                if !Utf8Path::<U>::new(&s).is_single_filename() {
+                    return Err(CheckedPathError::InvalidFilename);
+                }
+
+                path.push_checked(s)?;
+            }
+        }
+
+        Ok(path)
+    }
+
+    /// Returns whether the path consists of exactly one valid normal component
+    fn is_single_filename(&self) -> bool {
+        let mut components = self.components();
+        match (components.next(), components.next()) {
+            (Some(component), None) => component.is_normal() && component.is_valid(),
+            _ => false,
+        }
+    }
+
+    /// Returns whether `c` is allowed within a normal component of the encoding
+    fn is_filename_char(c: char) -> bool {
+        // NOTE: Only ascii characters are ever disallowed or given special meaning by an encoding
+        !c.is_ascii()
+            || Utf8Path::<T>::new(&['a', c, 'a'].iter().collect::<String>()).is_single_filename()
+    }
+
+    /// Encodes the path as the path of a URI, joining its components with `/` and
+    /// percent-encoding every character of a component that is not allowed within a path segment
+    /// by [RFC 3986](https://www.rfc-editor.org/rfc/rfc3986#section-3.3).
+    ///
+    /// A root becomes a leading `/` regardless of the encoding of the path, and a prefix is
+    /// encoded like any other component.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Utf8Path, Utf8UnixEncoding, Utf8WindowsEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// let path = Utf8Path::<Utf8UnixEncoding>::new("/docs/My Files/résumé?.pdf");
+    /// assert_eq!(path.encode_percent(), "/docs/My%20Files/r%C3%A9sum%C3%A9%3F.pdf");
+    ///
+    /// let path = Utf8Path::<Utf8WindowsEncoding>::new(r"C:\Users\100%\notes.txt");
+    /// assert_eq!(path.encode_percent(), "C:/Users/100%25/notes.txt");
+    /// ```
+    pub fn encode_percent(&self) -> String {
+        let mut out = String::with_capacity(self.inner.len());
+        let mut needs_separator = false;
+
+        for component in self.components() {
+            if component.is_root() {
+                out.push('/');
+                needs_separator = false;
+                continue;
+            }
+
+            if needs_separator {
+                out.push('/');
+            }
+
+            percent::encode_segment(component.as_str().as_bytes(), &mut out);
+
+            // A prefix like `C:` is directly followed by the rest of the path
+            needs_separator =
+                component.is_normal() || component.is_current() || component.is_parent();
+        }
+
+        out
+    }
+
+    /// Decodes the path as the path of a URI, percent-decoding each of its components into a
+    /// [`PathBuf`] of the given encoding. Malformed percent sequences are kept as they are.
+    ///
+    /// Each decoded component must remain a single component and is added using
+    /// [`PathBuf::push_checked`], so a component cannot smuggle in a separator, root, or invalid
+    /// character through its encoded form.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CheckedPathError`] if a decoded component is not a valid filename of the
+    /// encoding or would otherwise violate the rules of [`PathBuf::push_checked`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{CheckedPathError, UnixEncoding, Utf8Path, Utf8UnixEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// let url_path = Utf8Path::<Utf8UnixEncoding>::new("/docs/My%20Files/r%C3%A9sum%C3%A9.pdf");
+    /// let path = url_path.decode_percent::<UnixEncoding>().unwrap();
+    /// assert_eq!(path.as_bytes(), "/docs/My Files/résumé.pdf".as_bytes());
+    ///
+    /// // Non-UTF-8 bytes can be decoded too
+    /// let url_path = Utf8Path::<Utf8UnixEncoding>::new("/tmp/%FF");
+    /// let path = url_path.decode_percent::<UnixEncoding>().unwrap();
+    /// assert_eq!(path.as_bytes(), b"/tmp/\xff");
+    ///
+    /// // Encoded separators are rejected rather than creating new components
+    /// let url_path = Utf8Path::<Utf8UnixEncoding>::new("/docs/a%2Fb");
+    /// assert_eq!(
+    ///     url_path.decode_percent::<UnixEncoding>(),
+    ///     Err(CheckedPathError::InvalidFilename),
+    /// );
+    /// ```
+    pub fn decode_percent<U>(&self) -> Result<PathBuf<U>, CheckedPathError>
+    where
+        U: for<'enc> Encoding<'enc>,
+    {
+        let mut path = PathBuf::new();
+
+        for component in self.components() {
+            if component.is_root() {
+                path.push(
+                    <<<U as Encoding>::Components as Components>::Component as Component>::root()
+                        .as_bytes(),
+                );
+            } else if component.is_current() {
+                path.push(
+                    <<<U as Encoding>::Components as Components>::Component as Component>::current(
+                    )
+                    .as_bytes(),
+                );
+            } else if component.is_parent() {
+                path.push(
+                    <<<U as Encoding>::Components as Components>::Component as Component>::parent()
+                        .as_bytes(),
+                );
+            } else if component.is_normal() {
+                let bytes = percent::decode_segment(component.as_str());
+
+                // Each decoded component must remain exactly one component of the new path
+                let is_single = {
+                    let mut components = Path::<U>::new(&bytes).components();
+                    match (components.next(), components.next()) {
+                        (Some(c), None) => c.len() == bytes.len(),
+                        _ => false,
+                    }
+                };
+
+                if !is_single {
+                    return Err(CheckedPathError::InvalidFilename);
+                }
+
+                path.push_checked(bytes)?;
+            } else {
+                path.push(percent::decode_segment(component.as_str()));
+            }
+        }
+
+        Ok(path)
+    }
+
     /// Converts a [`Box<Utf8Path>`](Box) into a
     /// [`Utf8PathBuf`] without copying or allocating.
     pub fn into_path_buf(self: Box<Utf8Path<T>>) -> Utf8PathBuf<T> {
@@ -950,8 +3160,8 @@ where
     ///
     /// # Errors
     ///
-    /// Returns `Err` if the path is not UTF-8 with a description as to why the
-    /// provided component is not UTF-8.
+    /// Returns [`Utf8PathError`] if the path is not UTF-8, reporting the byte offset where the
+    /// path stops being valid UTF-8 alongside the valid portion of the path.
     ///
     /// # Examples
     ///
@@ -961,12 +3171,24 @@ where
     /// let path = Path::<UnixEncoding>::new(&[0xf0, 0x9f, 0x92, 0x96]);
     /// let utf8_path = Utf8Path::<Utf8UnixEncoding>::from_bytes_path(&path).unwrap();
     /// assert_eq!(utf8_path.as_str(), "💖");
+    ///
+    /// let path = Path::<UnixEncoding>::new(b"/tmp/\xf0\x9f");
+    /// let err = Utf8Path::<Utf8UnixEncoding>::from_bytes_path(&path).unwrap_err();
+    /// assert_eq!(err.valid_up_to(), 5);
+    /// assert_eq!(err.error_len(), None);
+    /// assert_eq!(err.invalid_bytes(), b"\xf0\x9f");
     /// ```
-    pub fn from_bytes_path<U>(path: &Path<U>) -> Result<&Self, Utf8Error>
+    pub fn from_bytes_path<U>(path: &Path<U>) -> Result<&Self, Utf8PathError>
     where
         U: for<'enc> Encoding<'enc>,
     {
-        Ok(Self::new(core::str::from_utf8(path.as_bytes())?))
+        match core::str::from_utf8(path.as_bytes()) {
+            Ok(s) => Ok(Self::new(s)),
+            Err(error) => Err(Utf8PathError {
+                bytes: path.as_bytes().to_vec(),
+                error,
+            }),
+        }
     }
 
     /// Converts a non-UTF-8 [`Path`] to a UTF-8 [`Utf8Path`] without checking that the path
@@ -1434,7 +3656,7 @@ mod helpers {
             return (Some(file), None);
         }
 
-        let mut iter = file.rsplitn(2, |b: char| b == '.');
+        let mut iter = file.rsplitn(2, '.');
         let after = iter.next();
         let before = iter.next();
         if before == Some("") {
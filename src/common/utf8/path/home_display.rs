@@ -0,0 +1,91 @@
+use core::fmt;
+
+use crate::{Utf8Components, Utf8Encoding, Utf8Path};
+
+/// Helper struct for printing paths within the home directory relative to it, such as
+/// `~/project/x`.
+///
+/// It is created by the [`display_relative_to_home`](Utf8Path::display_relative_to_home) method on
+/// [`Utf8Path`]. Paths outside of the home directory are printed in full.
+///
+/// # Examples
+///
+/// ```
+/// use typed_path::{Utf8Path, Utf8UnixEncoding};
+///
+/// // NOTE: A path cannot be created on its own without a defined encoding
+/// let home = Utf8Path::<Utf8UnixEncoding>::new("/home/me");
+/// let path = Utf8Path::<Utf8UnixEncoding>::new("/home/me/project/x");
+///
+/// println!("{}", path.display_relative_to_home(home));
+/// ```
+pub struct Utf8HomeDisplay<'a, T>
+where
+    T: for<'enc> Utf8Encoding<'enc>,
+{
+    pub(crate) path: &'a Utf8Path<T>,
+    pub(crate) home: &'a Utf8Path<T>,
+    pub(crate) placeholder: &'a str,
+}
+
+impl<'a, T> Utf8HomeDisplay<'a, T>
+where
+    T: for<'enc> Utf8Encoding<'enc>,
+{
+    /// Sets the text that stands for the home directory, which is `%USERPROFILE%` for Windows
+    /// paths and `~` otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::Utf8WindowsPath;
+    ///
+    /// let home = Utf8WindowsPath::new(r"C:\Users\me");
+    /// let path = Utf8WindowsPath::new(r"C:\Users\me\Desktop");
+    ///
+    /// let display = path.display_relative_to_home(home).placeholder("~");
+    /// assert_eq!(display.to_string(), r"~\Desktop");
+    /// ```
+    pub fn placeholder(mut self, placeholder: &'a str) -> Self {
+        self.placeholder = placeholder;
+        self
+    }
+}
+
+impl<T> fmt::Debug for Utf8HomeDisplay<'_, T>
+where
+    T: for<'enc> Utf8Encoding<'enc>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Utf8HomeDisplay")
+            .field("path", &self.path)
+            .field("home", &self.home)
+            .field("placeholder", &self.placeholder)
+            .finish()
+    }
+}
+
+impl<T> fmt::Display for Utf8HomeDisplay<'_, T>
+where
+    T: for<'enc> Utf8Encoding<'enc>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.home.components().next().is_none()
+            || !self.path.is_inclusive_descendant_of(self.home)
+        {
+            return f.write_str(self.path.as_str());
+        }
+
+        let mut components = self.path.components();
+        for _ in self.home.components() {
+            components.next();
+        }
+
+        f.write_str(self.placeholder)?;
+        let rest = components.as_str();
+        if !rest.is_empty() {
+            write!(f, "{}{}", T::info().separator, rest)?;
+        }
+        Ok(())
+    }
+}
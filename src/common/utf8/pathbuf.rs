@@ -1,6 +1,5 @@
 use alloc::borrow::Cow;
 use alloc::collections::TryReserveError;
-use alloc::string::FromUtf8Error;
 use core::borrow::Borrow;
 use core::hash::{Hash, Hasher};
 use core::iter::{Extend, FromIterator};
@@ -10,7 +9,10 @@ use core::str::FromStr;
 use core::{cmp, fmt};
 
 use crate::no_std_compat::*;
-use crate::{CheckedPathError, Encoding, PathBuf, Utf8Encoding, Utf8Iter, Utf8Path};
+use crate::{
+    CheckedPathError, Encoding, PathBuf, PushMode, SegmentError, SegmentErrorKind, StrictPathError,
+    Utf8Component, Utf8Components, Utf8Encoding, Utf8Iter, Utf8Path, Utf8PathError,
+};
 
 /// An owned, mutable path that mirrors [`std::path::PathBuf`], but operatings using a
 /// [`Utf8Encoding`] to determine how to parse the underlying str.
@@ -122,6 +124,32 @@ where
         }
     }
 
+    /// Creates a `Utf8PathBuf` out of `path`, only succeeding if it passes
+    /// [`Utf8Path::validate_strict`].
+    ///
+    /// Like [`Utf8Path::try_new_strict`], but takes ownership of `path` so that an owned
+    /// [`String`] is kept without copying it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{StrictPathError, Utf8PathBuf, Utf8WindowsEncoding};
+    ///
+    /// // NOTE: A pathbuf cannot be created on its own without a defined encoding
+    /// let path = Utf8PathBuf::<Utf8WindowsEncoding>::try_from_strict(r"C:\logs\today.txt").unwrap();
+    /// assert_eq!(path, Utf8PathBuf::from(r"C:\logs\today.txt"));
+    ///
+    /// assert_eq!(
+    ///     Utf8PathBuf::<Utf8WindowsEncoding>::try_from_strict(String::from(r"C:\logs\to:day.txt")),
+    ///     Err(StrictPathError::InvalidByte { index: 10, byte: b':' }),
+    /// );
+    /// ```
+    pub fn try_from_strict<S: Into<String>>(path: S) -> Result<Self, StrictPathError> {
+        let path = Utf8PathBuf::from(path.into());
+        path.validate_strict()?;
+        Ok(path)
+    }
+
     /// Coerces to a [`Utf8Path`] slice.
     ///
     /// # Examples
@@ -151,6 +179,9 @@ where
     ///   and `path` is not empty, the new path is normalized: all references
     ///   to `.` and `..` are removed.
     ///
+    /// If `path` is empty, `self` is left unchanged. Unlike `std::path::PathBuf::push`, no
+    /// trailing separator is added.
+    ///
     /// [`Utf8WindowsPathBuf`]: crate::Utf8WindowsPathBuf
     ///
     /// # Examples
@@ -237,6 +268,198 @@ where
         T::push_checked(&mut self.inner, path.as_ref().as_str())
     }
 
+    /// Like [`Utf8PathBuf::push`], extends `self` with `path`, but uses the given [`PushMode`] to
+    /// decide what happens when `path` has its own root or prefix.
+    ///
+    /// * [`PushMode::Replace`] behaves exactly like [`Utf8PathBuf::push`] and never fails.
+    /// * [`PushMode::Append`] drops any prefix and root from `path` and appends the rest as if
+    ///   it were relative, so `self` is never replaced. This never fails.
+    /// * [`PushMode::Reject`] fails if `path` has a prefix or root, leaving `self` untouched.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{
+    ///     CheckedPathError, PushMode, Utf8PathBuf, Utf8UnixEncoding, Utf8WindowsEncoding,
+    /// };
+    ///
+    /// // NOTE: A pathbuf cannot be created on its own without a defined encoding
+    /// let mut path = Utf8PathBuf::<Utf8UnixEncoding>::from("/srv/www");
+    /// path.push_with_mode("/etc", PushMode::Append).unwrap();
+    /// assert_eq!(path, Utf8PathBuf::from("/srv/www/etc"));
+    ///
+    /// assert_eq!(
+    ///     path.push_with_mode("/etc", PushMode::Reject),
+    ///     Err(CheckedPathError::UnexpectedRoot),
+    /// );
+    ///
+    /// path.push_with_mode("/etc", PushMode::Replace).unwrap();
+    /// assert_eq!(path, Utf8PathBuf::from("/etc"));
+    ///
+    /// // Windows prefixes are reported separately from roots
+    /// let mut path = Utf8PathBuf::<Utf8WindowsEncoding>::from(r"C:\srv");
+    /// assert_eq!(
+    ///     path.push_with_mode(r"D:\www", PushMode::Reject),
+    ///     Err(CheckedPathError::UnexpectedPrefix),
+    /// );
+    /// assert_eq!(
+    ///     path.push_with_mode(r"\www", PushMode::Reject),
+    ///     Err(CheckedPathError::UnexpectedRoot),
+    /// );
+    /// ```
+    pub fn push_with_mode<P: AsRef<Utf8Path<T>>>(
+        &mut self,
+        path: P,
+        mode: PushMode,
+    ) -> Result<(), CheckedPathError> {
+        let path = path.as_ref();
+        match mode {
+            PushMode::Replace => self.push(path),
+            PushMode::Append => {
+                let (_, rest) = path.split_anchor();
+                self.push(rest);
+            }
+            PushMode::Reject => match path.split_anchor() {
+                (Some(error), _) => return Err(error),
+                (None, _) => self.push(path),
+            },
+        }
+
+        Ok(())
+    }
+
+    /// Extends `self` with `path`, never replacing `self` even if `path` is absolute. This is
+    /// [`push_with_mode`] using [`PushMode::Append`].
+    ///
+    /// [`push_with_mode`]: Utf8PathBuf::push_with_mode
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Utf8PathBuf, Utf8UnixEncoding};
+    ///
+    /// // NOTE: A pathbuf cannot be created on its own without a defined encoding
+    /// let mut path = Utf8PathBuf::<Utf8UnixEncoding>::from("/srv/www");
+    /// path.push_keeping_base("/index.html");
+    /// assert_eq!(path, Utf8PathBuf::from("/srv/www/index.html"));
+    /// ```
+    pub fn push_keeping_base<P: AsRef<Utf8Path<T>>>(&mut self, path: P) {
+        let (_, rest) = path.as_ref().split_anchor();
+        self.push(rest);
+    }
+
+    /// Extends `self` with `path`, failing if `path` has a root or prefix. This is
+    /// [`push_with_mode`] using [`PushMode::Reject`].
+    ///
+    /// [`push_with_mode`]: Utf8PathBuf::push_with_mode
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{CheckedPathError, Utf8PathBuf, Utf8UnixEncoding};
+    ///
+    /// // NOTE: A pathbuf cannot be created on its own without a defined encoding
+    /// let mut path = Utf8PathBuf::<Utf8UnixEncoding>::from("/srv");
+    /// assert_eq!(path.push_relative_only("/etc"), Err(CheckedPathError::UnexpectedRoot));
+    /// assert_eq!(path.push_relative_only("www"), Ok(()));
+    /// assert_eq!(path, Utf8PathBuf::from("/srv/www"));
+    /// ```
+    pub fn push_relative_only<P: AsRef<Utf8Path<T>>>(
+        &mut self,
+        path: P,
+    ) -> Result<(), CheckedPathError> {
+        self.push_with_mode(path, PushMode::Reject)
+    }
+
+    /// Builds a relative path out of `segments`, each of which must be exactly one normal
+    /// component. See [`Utf8PathBuf::extend_checked`] for the segments that are rejected.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{SegmentErrorKind, Utf8PathBuf, Utf8UnixEncoding};
+    ///
+    /// // NOTE: A pathbuf cannot be created on its own without a defined encoding
+    /// let path =
+    ///     Utf8PathBuf::<Utf8UnixEncoding>::from_segments(["static", "css", "site.css"]).unwrap();
+    /// assert_eq!(path, Utf8PathBuf::from("static/css/site.css"));
+    ///
+    /// let err =
+    ///     Utf8PathBuf::<Utf8UnixEncoding>::from_segments(["static", "..", "secret"]).unwrap_err();
+    /// assert_eq!(err.index(), 1);
+    /// assert_eq!(err.kind(), SegmentErrorKind::Traversal);
+    /// ```
+    pub fn from_segments<I, S>(segments: I) -> Result<Self, SegmentError>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let mut path = Self::new();
+        path.extend_checked(segments)?;
+        Ok(path)
+    }
+
+    /// Pushes each of `segments` onto `self`, where every segment must be exactly one normal
+    /// component, so the result can never escape `self` or replace it.
+    ///
+    /// A segment is rejected if it is empty, contains a separator, is `.` or `..`, would be read
+    /// as a prefix, or contains characters the encoding does not allow in filenames. The error
+    /// holds the position of the first rejected segment, and `self` is left as it was before the
+    /// call.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{SegmentErrorKind, Utf8PathBuf, Utf8WindowsEncoding};
+    ///
+    /// // NOTE: A pathbuf cannot be created on its own without a defined encoding
+    /// let mut path = Utf8PathBuf::<Utf8WindowsEncoding>::from(r"C:\www");
+    /// path.extend_checked(["img", "logo.png"]).unwrap();
+    /// assert_eq!(path, Utf8PathBuf::from(r"C:\www\img\logo.png"));
+    ///
+    /// let err = path.extend_checked(["a", r"b\c"]).unwrap_err();
+    /// assert_eq!((err.index(), err.kind()), (1, SegmentErrorKind::Separator));
+    ///
+    /// let err = path.extend_checked(["D:"]).unwrap_err();
+    /// assert_eq!((err.index(), err.kind()), (0, SegmentErrorKind::Prefix));
+    ///
+    /// // Nothing is pushed when a segment is rejected
+    /// assert_eq!(path, Utf8PathBuf::from(r"C:\www\img\logo.png"));
+    /// ```
+    pub fn extend_checked<I, S>(&mut self, segments: I) -> Result<(), SegmentError>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let len = self.inner.len();
+        for (index, segment) in segments.into_iter().enumerate() {
+            let segment = segment.as_ref();
+            if let Err(kind) = Self::check_segment(segment) {
+                self.inner.truncate(len);
+                return Err(SegmentError { index, kind });
+            }
+            self.push(Utf8Path::new(segment));
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn check_segment(segment: &str) -> Result<(), SegmentErrorKind> {
+        if segment.is_empty() {
+            return Err(SegmentErrorKind::Empty);
+        } else if segment.chars().any(T::is_separator) {
+            return Err(SegmentErrorKind::Separator);
+        }
+
+        let mut components = Utf8Path::<T>::new(segment).components();
+        match (components.next(), components.next()) {
+            (Some(c), None) if c.is_normal() && c.is_valid() => Ok(()),
+            (Some(c), None) if c.is_normal() => Err(SegmentErrorKind::InvalidFilename),
+            (Some(c), None) if c.is_current() || c.is_parent() => Err(SegmentErrorKind::Traversal),
+            _ => Err(SegmentErrorKind::Prefix),
+        }
+    }
+
     /// Truncates `self` to [`self.parent`].
     ///
     /// Returns `false` and does nothing if [`self.parent`] is [`None`].
@@ -267,6 +490,252 @@ where
         }
     }
 
+    /// Inserts `component` into `self` so that it becomes the component at position `index`,
+    /// shifting all components after it to the right.
+    ///
+    /// An `index` equal to the number of components appends `component` to the end of the path.
+    /// Unlike [`Utf8PathBuf::push`], `component` never replaces the existing path: any prefix or root
+    /// of `component` is left out, so only its relative part is inserted. Inserting an empty path
+    /// does nothing.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is greater than the number of components in the path, or if `index`
+    /// falls on the prefix or root of the path.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Utf8PathBuf, Utf8UnixEncoding, Utf8WindowsEncoding};
+    ///
+    /// // NOTE: A pathbuf cannot be created on its own without a defined encoding
+    /// let mut p = Utf8PathBuf::<Utf8UnixEncoding>::from("/usr/lib");
+    ///
+    /// // Components are `/`, `usr`, and `lib`
+    /// p.insert_component(3, "bin");
+    /// assert_eq!(p.as_str(), "/usr/lib/bin");
+    ///
+    /// p.insert_component(1, "opt/local");
+    /// assert_eq!(p.as_str(), "/opt/local/usr/lib/bin");
+    ///
+    /// // Components following a drive-relative prefix stay drive-relative
+    /// let mut p = Utf8PathBuf::<Utf8WindowsEncoding>::from("C:");
+    /// p.insert_component(1, "foo");
+    /// assert_eq!(p.as_str(), "C:foo");
+    /// ```
+    pub fn insert_component<P: AsRef<Utf8Path<T>>>(&mut self, index: usize, component: P) {
+        self._insert_component(index, component.as_ref())
+    }
+
+    fn _insert_component(&mut self, index: usize, component: &Utf8Path<T>) {
+        let spans = self.component_spans();
+        assert!(
+            index <= spans.len(),
+            "index {index} out of range for path with {} components",
+            spans.len()
+        );
+
+        let anchor_len = spans.iter().take_while(|span| !span.separated).count();
+        assert!(
+            index >= anchor_len,
+            "index {index} falls on the prefix or root of the path"
+        );
+
+        let component = component.split_anchor().1;
+        if component.inner.is_empty() {
+            return;
+        }
+
+        let separator =
+            <<<T as Utf8Encoding>::Components as Utf8Components>::Component as Utf8Component>::root(
+            );
+        let separator = separator.as_str();
+
+        match spans.get(index) {
+            Some(span) => {
+                let mut s = component.inner.to_string();
+                if !s.ends_with(separator) {
+                    s.push_str(separator);
+                }
+                self.inner.insert_str(span.start, &s);
+            }
+            None => {
+                if let Some(span) = index.checked_sub(1).map(|i| &spans[i]) {
+                    self.inner.truncate(span.end);
+                    if span.separated {
+                        self.inner.push_str(separator);
+                    }
+                }
+                self.inner.push_str(&component.inner);
+            }
+        }
+    }
+
+    /// Removes the component at position `index` within `self`, shifting all components after it
+    /// to the left.
+    ///
+    /// Returns `false` and does nothing if `index` is out of range. Otherwise, returns `true`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Utf8PathBuf, Utf8UnixEncoding};
+    ///
+    /// // NOTE: A pathbuf cannot be created on its own without a defined encoding
+    /// let mut p = Utf8PathBuf::<Utf8UnixEncoding>::from("/usr/local/lib");
+    ///
+    /// assert!(p.remove_component(2));
+    /// assert_eq!(p.as_str(), "/usr/lib");
+    ///
+    /// assert!(p.remove_component(2));
+    /// assert_eq!(p.as_str(), "/usr");
+    ///
+    /// // Removing the root makes the path relative
+    /// assert!(p.remove_component(0));
+    /// assert_eq!(p.as_str(), "usr");
+    ///
+    /// assert!(!p.remove_component(1));
+    /// ```
+    pub fn remove_component(&mut self, index: usize) -> bool {
+        let spans = self.component_spans();
+        if index >= spans.len() {
+            return false;
+        }
+
+        // Remove the separators that follow the component, or those that precede it when it is
+        // the last component of the path
+        match spans.get(index + 1) {
+            Some(next) => {
+                self.inner.replace_range(spans[index].start..next.start, "");
+            }
+            None => match index.checked_sub(1) {
+                Some(i) => self.inner.truncate(spans[i].end),
+                None => self.inner.clear(),
+            },
+        }
+
+        true
+    }
+
+    /// Retains only the components of `self` for which `f` returns `true`, passing each
+    /// component of the path in order.
+    ///
+    /// The separators between retained components are kept as-is.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Utf8Component, Utf8PathBuf, Utf8UnixEncoding};
+    ///
+    /// // NOTE: A pathbuf cannot be created on its own without a defined encoding
+    /// let mut p = Utf8PathBuf::<Utf8UnixEncoding>::from("./build/out/../debug/main.o");
+    ///
+    /// p.retain_components(|c| !c.is_current() && !c.is_parent());
+    /// assert_eq!(p.as_str(), "build/out/debug/main.o");
+    ///
+    /// p.retain_components(|c| c.as_str() != "out");
+    /// assert_eq!(p.as_str(), "build/debug/main.o");
+    ///
+    /// let mut p = Utf8PathBuf::<Utf8UnixEncoding>::from("/usr/local/lib");
+    /// p.retain_components(|c| c.as_str() != "usr");
+    /// assert_eq!(p.as_str(), "/local/lib");
+    /// ```
+    pub fn retain_components<F>(&mut self, mut f: F)
+    where
+        F: for<'a> FnMut(
+            <<T as Utf8Encoding<'a>>::Components as Utf8Components<'a>>::Component,
+        ) -> bool,
+    {
+        let mut inner = String::with_capacity(self.inner.len());
+        let mut last_end = 0;
+        let mut keep = false;
+        let mut separated = false;
+
+        {
+            let mut components = self.components();
+            loop {
+                let start = self.inner.len() - components.as_str().len();
+                let component = match components.next() {
+                    Some(component) => component,
+                    None => break,
+                };
+
+                let end = start + component.len();
+                let is_separated =
+                    component.is_normal() || component.is_parent() || component.is_current();
+                keep = f(component);
+                if keep {
+                    if separated {
+                        inner.push_str(&self.inner[last_end..start]);
+                    }
+                    inner.push_str(&self.inner[start..end]);
+                    separated = is_separated;
+                }
+
+                last_end = end;
+            }
+        }
+
+        // Any trailing separators are kept only alongside the last component
+        if keep {
+            inner.push_str(&self.inner[last_end..]);
+        }
+
+        self.inner = inner;
+    }
+
+    /// Shortens `self` to its first `len` components, removing any separators that follow them.
+    ///
+    /// If `len` is greater than or equal to the number of components in the path, this has no
+    /// effect.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Utf8PathBuf, Utf8UnixEncoding};
+    ///
+    /// // NOTE: A pathbuf cannot be created on its own without a defined encoding
+    /// let mut p = Utf8PathBuf::<Utf8UnixEncoding>::from("/usr/local/lib/");
+    ///
+    /// p.truncate_components(5);
+    /// assert_eq!(p.as_str(), "/usr/local/lib/");
+    ///
+    /// p.truncate_components(2);
+    /// assert_eq!(p.as_str(), "/usr");
+    ///
+    /// p.truncate_components(0);
+    /// assert!(p.as_str().is_empty());
+    /// ```
+    pub fn truncate_components(&mut self, len: usize) {
+        let spans = self.component_spans();
+        if len < spans.len() {
+            match len.checked_sub(1) {
+                Some(i) => self.inner.truncate(spans[i].end),
+                None => self.inner.clear(),
+            }
+        }
+    }
+
+    /// Returns the span of each component within `self`, parsing the path only once
+    fn component_spans(&self) -> Vec<ComponentSpan> {
+        let mut components = self.components();
+        let mut spans = Vec::new();
+
+        loop {
+            let start = self.inner.len() - components.as_str().len();
+            match components.next() {
+                Some(component) => spans.push(ComponentSpan {
+                    start,
+                    end: start + component.len(),
+                    separated: component.is_normal()
+                        || component.is_parent()
+                        || component.is_current(),
+                }),
+                None => return spans,
+            }
+        }
+    }
+
     /// Updates [`self.file_name`] to `file_name`.
     ///
     /// If [`self.file_name`] was [`None`], this is equivalent to pushing
@@ -363,6 +832,51 @@ where
         true
     }
 
+    /// Makes every ascii letter of the component text of `self` lowercase in place, without
+    /// reallocating.
+    ///
+    /// See [`Utf8Path::to_lowercase`] for how prefixes are handled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Utf8PathBuf, Utf8WindowsEncoding};
+    ///
+    /// // NOTE: A pathbuf cannot be created on its own without a defined encoding
+    /// let mut path =
+    ///     Utf8PathBuf::<Utf8WindowsEncoding>::from(r"\\?\UNC\Server\Share\Logs\App.LOG");
+    /// path.make_ascii_lowercase();
+    /// assert_eq!(path, Utf8PathBuf::from(r"\\?\UNC\server\share\logs\app.log"));
+    /// ```
+    pub fn make_ascii_lowercase(&mut self) {
+        let keep = self.case_preserved_range();
+        self.inner[..keep.start].make_ascii_lowercase();
+        self.inner[keep.end..].make_ascii_lowercase();
+    }
+
+    /// Makes every ascii letter of the component text of `self` uppercase in place, without
+    /// reallocating.
+    ///
+    /// See [`Utf8Path::to_lowercase`] for how prefixes are handled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Utf8PathBuf, Utf8WindowsEncoding};
+    ///
+    /// // NOTE: A pathbuf cannot be created on its own without a defined encoding
+    /// let mut path = Utf8PathBuf::<Utf8WindowsEncoding>::from(r"\\?\unc\server\share");
+    /// path.make_ascii_uppercase();
+    ///
+    /// // `unc` is a verbatim name rather than the marker of a UNC prefix, so it is kept as is
+    /// assert_eq!(path, Utf8PathBuf::from(r"\\?\unc\SERVER\SHARE"));
+    /// ```
+    pub fn make_ascii_uppercase(&mut self) {
+        let keep = self.case_preserved_range();
+        self.inner[..keep.start].make_ascii_uppercase();
+        self.inner[keep.end..].make_ascii_uppercase();
+    }
+
     /// Consumes the `PathBuf`, yielding its internal [`String`] storage.
     ///
     /// # Examples
@@ -378,6 +892,21 @@ where
         self.inner
     }
 
+    /// Consumes the `PathBuf`, yielding the [`Vec<u8>`] storage of its internal [`String`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Utf8PathBuf, Utf8UnixEncoding};
+    ///
+    /// let p = Utf8PathBuf::<Utf8UnixEncoding>::from("/the/head");
+    /// assert_eq!(p.into_bytes(), b"/the/head");
+    /// ```
+    #[inline]
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.inner.into_bytes()
+    }
+
     /// Converts this [`Utf8PathBuf`] into a [boxed](Box) [`Utf8Path`].
     #[inline]
     pub fn into_boxed_path(self) -> Box<Utf8Path<T>> {
@@ -454,8 +983,8 @@ where
     ///
     /// # Errors
     ///
-    /// Returns `Err` if the path is not UTF-8 with a description as to why the
-    /// provided component is not UTF-8.
+    /// Returns [`Utf8PathError`] if the path is not UTF-8, reporting the byte offset where the
+    /// path stops being valid UTF-8 and keeping the original bytes of the path.
     ///
     /// # Examples
     ///
@@ -465,8 +994,14 @@ where
     /// let path_buf = PathBuf::<UnixEncoding>::from(&[0xf0, 0x9f, 0x92, 0x96]);
     /// let utf8_path_buf = Utf8PathBuf::<Utf8UnixEncoding>::from_bytes_path_buf(path_buf).unwrap();
     /// assert_eq!(utf8_path_buf.as_str(), "💖");
+    ///
+    /// let path_buf = PathBuf::<UnixEncoding>::from(b"/tmp/\xff.txt");
+    /// let err = Utf8PathBuf::<Utf8UnixEncoding>::from_bytes_path_buf(path_buf).unwrap_err();
+    /// assert_eq!(err.valid_up_to(), 5);
+    /// assert_eq!(err.valid_prefix(), "/tmp/");
+    /// assert_eq!(err.into_bytes(), b"/tmp/\xff.txt");
     /// ```
-    pub fn from_bytes_path_buf<U>(path_buf: PathBuf<U>) -> Result<Self, FromUtf8Error>
+    pub fn from_bytes_path_buf<U>(path_buf: PathBuf<U>) -> Result<Self, Utf8PathError>
     where
         U: for<'enc> Encoding<'enc>,
     {
@@ -700,6 +1235,45 @@ where
     }
 }
 
+impl<T> TryFrom<Vec<u8>> for Utf8PathBuf<T>
+where
+    T: for<'enc> Utf8Encoding<'enc>,
+{
+    type Error = Utf8PathError;
+
+    /// Converts a [`Vec<u8>`] into a [`Utf8PathBuf`], failing if it is not valid UTF-8
+    ///
+    /// This conversion does not allocate or copy memory.
+    ///
+    /// ```
+    /// use std::convert::TryFrom;
+    /// use typed_path::Utf8UnixPathBuf;
+    ///
+    /// let path = Utf8UnixPathBuf::try_from(b"/tmp/file.txt".to_vec()).unwrap();
+    /// assert_eq!(path, Utf8UnixPathBuf::from("/tmp/file.txt"));
+    ///
+    /// let err = Utf8UnixPathBuf::try_from(b"/tmp/caf\xe9".to_vec()).unwrap_err();
+    /// assert_eq!(err.into_bytes(), b"/tmp/caf\xe9");
+    /// ```
+    #[inline]
+    fn try_from(bytes: Vec<u8>) -> Result<Self, Self::Error> {
+        Ok(Utf8PathBuf::from(String::from_utf8(bytes)?))
+    }
+}
+
+impl<T> From<Utf8PathBuf<T>> for Vec<u8>
+where
+    T: for<'enc> Utf8Encoding<'enc>,
+{
+    /// Converts a [`Utf8PathBuf`] into a [`Vec<u8>`]
+    ///
+    /// This conversion does not allocate or copy memory.
+    #[inline]
+    fn from(path_buf: Utf8PathBuf<T>) -> Self {
+        path_buf.inner.into_bytes()
+    }
+}
+
 impl<T> FromStr for Utf8PathBuf<T>
 where
     T: for<'enc> Utf8Encoding<'enc>,
@@ -778,3 +1352,12 @@ where
         self.components().cmp(other.components())
     }
 }
+
+/// Location of a single component within the bytes of a [`Utf8PathBuf`]
+struct ComponentSpan {
+    start: usize,
+    end: usize,
+
+    /// Whether a separator is needed between this component and the next
+    separated: bool,
+}
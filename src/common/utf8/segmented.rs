@@ -0,0 +1,469 @@
+use core::fmt;
+use core::marker::PhantomData;
+
+use crate::common::errors::CheckedPathError;
+use crate::no_std_compat::*;
+use crate::{Utf8Component, Utf8Components, Utf8Encoding, Utf8Path, Utf8PathBuf};
+
+/// An owned UTF-8 path that stores its components as separate segments rather than as one
+/// contiguous string.
+///
+/// This is the UTF-8 counterpart of [`SegmentedPathBuf`](crate::SegmentedPathBuf). Reading the
+/// depth or file name of a [`Utf8PathBuf`], or removing its last component, parses the path each
+/// time. A `Utf8SegmentedPathBuf` parses once on construction, so these are constant time and
+/// editing a component in the middle of the path does not shift the rest. The tradeoff is an
+/// allocation per segment, so convert back with [`Utf8SegmentedPathBuf::to_path_buf`] when the
+/// path needs to be passed along.
+///
+/// The prefix and root of the path, such as `/` or `C:\`, are kept as they were written and are
+/// not counted as segments.
+///
+/// # Examples
+///
+/// ```
+/// use typed_path::{Utf8PathBuf, Utf8SegmentedPathBuf, Utf8WindowsEncoding};
+///
+/// // NOTE: A path cannot be created on its own without a defined encoding
+/// let mut path = Utf8SegmentedPathBuf::<Utf8WindowsEncoding>::from(r"C:\Users\me\notes.txt");
+/// assert_eq!(path.depth(), 3);
+/// assert_eq!(path.file_name(), Some("notes.txt"));
+///
+/// path.replace(1, "you").unwrap();
+/// path.pop();
+/// path.push("docs");
+/// assert_eq!(path.to_path_buf(), Utf8PathBuf::from(r"C:\Users\you\docs"));
+/// ```
+pub struct Utf8SegmentedPathBuf<T>
+where
+    T: for<'enc> Utf8Encoding<'enc>,
+{
+    _encoding: PhantomData<T>,
+    root: String,
+    segments: Vec<String>,
+}
+
+impl<T> Utf8SegmentedPathBuf<T>
+where
+    T: for<'enc> Utf8Encoding<'enc>,
+{
+    /// Allocates an empty `Utf8SegmentedPathBuf`.
+    pub fn new() -> Self {
+        Self {
+            _encoding: PhantomData,
+            root: String::new(),
+            segments: Vec::new(),
+        }
+    }
+
+    /// Returns the prefix and root of the path as written, or [`None`] if the path is relative.
+    ///
+    /// See [`Utf8Path::root`] for more information.
+    pub fn root(&self) -> Option<&Utf8Path<T>> {
+        if self.root.is_empty() {
+            None
+        } else {
+            Some(Utf8Path::new(&self.root))
+        }
+    }
+
+    /// Returns the number of segments after the prefix and root.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Utf8SegmentedPathBuf, Utf8UnixEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// assert_eq!(Utf8SegmentedPathBuf::<Utf8UnixEncoding>::from("/usr//lib/").depth(), 2);
+    /// assert_eq!(Utf8SegmentedPathBuf::<Utf8UnixEncoding>::from("../lib").depth(), 2);
+    /// assert_eq!(Utf8SegmentedPathBuf::<Utf8UnixEncoding>::from("/").depth(), 0);
+    /// ```
+    #[inline]
+    pub fn depth(&self) -> usize {
+        self.segments.len()
+    }
+
+    /// Returns the segment at `index`, or [`None`] if `index` is out of bounds.
+    #[inline]
+    pub fn segment(&self, index: usize) -> Option<&str> {
+        self.segments.get(index).map(String::as_str)
+    }
+
+    /// Returns an iterator over the segments after the prefix and root.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Utf8SegmentedPathBuf, Utf8UnixEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// let path = Utf8SegmentedPathBuf::<Utf8UnixEncoding>::from("/srv/./www");
+    /// assert_eq!(
+    ///     path.segments().collect::<Vec<_>>(),
+    ///     ["srv", "www"],
+    /// );
+    /// ```
+    pub fn segments(&self) -> impl DoubleEndedIterator<Item = &str> + ExactSizeIterator + '_ {
+        self.segments.iter().map(String::as_str)
+    }
+
+    /// Returns the final segment if it is a normal component, matching [`Utf8Path::file_name`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Utf8SegmentedPathBuf, Utf8UnixEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// let path = Utf8SegmentedPathBuf::<Utf8UnixEncoding>::from("/usr/bin/");
+    /// assert_eq!(path.file_name(), Some("bin"));
+    ///
+    /// assert_eq!(Utf8SegmentedPathBuf::<Utf8UnixEncoding>::from("tmp/..").file_name(), None);
+    /// assert_eq!(Utf8SegmentedPathBuf::<Utf8UnixEncoding>::from("/").file_name(), None);
+    /// ```
+    pub fn file_name(&self) -> Option<&str> {
+        let last = self.segments.last()?;
+        match T::components(last).next() {
+            Some(component) if component.is_normal() => Some(last),
+            _ => None,
+        }
+    }
+
+    /// Returns the path without its final segment, or [`None`] if there are no segments.
+    ///
+    /// Unlike [`Utf8Path::parent`], this clones the remaining segments. Use
+    /// [`Utf8SegmentedPathBuf::pop`] to drop the final segment in place.
+    pub fn parent(&self) -> Option<Self> {
+        let (_, rest) = self.segments.split_last()?;
+        Some(Self {
+            _encoding: PhantomData,
+            root: self.root.clone(),
+            segments: rest.to_vec(),
+        })
+    }
+
+    /// Removes the final segment, returning `false` if there are no segments to remove.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Utf8SegmentedPathBuf, Utf8UnixEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// let mut path = Utf8SegmentedPathBuf::<Utf8UnixEncoding>::from("/spirited/away.rs");
+    /// assert!(path.pop());
+    /// assert!(path.pop());
+    /// assert!(!path.pop());
+    /// assert_eq!(path.to_path_buf(), "/");
+    /// ```
+    #[inline]
+    pub fn pop(&mut self) -> bool {
+        self.segments.pop().is_some()
+    }
+
+    /// Shortens the path to at most `depth` segments, keeping the prefix and root.
+    #[inline]
+    pub fn truncate(&mut self, depth: usize) {
+        self.segments.truncate(depth);
+    }
+
+    /// Extends `self` with `path`, following the same rules as [`Utf8PathBuf::push`].
+    ///
+    /// Pushing a relative path appends its segments. Pushing a path with a prefix or root
+    /// rebuilds the segments from the combined path, as it may replace `self` entirely.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Utf8SegmentedPathBuf, Utf8UnixEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// let mut path = Utf8SegmentedPathBuf::<Utf8UnixEncoding>::from("/tmp");
+    /// path.push("a/b");
+    /// assert_eq!(path.depth(), 3);
+    ///
+    /// path.push("/etc");
+    /// assert_eq!(path.to_path_buf(), "/etc");
+    /// ```
+    pub fn push<P: AsRef<Utf8Path<T>>>(&mut self, path: P) {
+        let path = path.as_ref();
+        match path.split_root() {
+            // Current directory components only survive at the start of a path
+            (None, rest) => {
+                let keep_current = self.root.is_empty() && self.segments.is_empty();
+                self.segments.extend(
+                    rest.components()
+                        .filter(|c| keep_current || !c.is_current())
+                        .map(|c| c.as_str().to_string()),
+                )
+            }
+            (Some(_), _) => {
+                let mut buf = self.to_path_buf();
+                buf.push(path);
+                *self = Self::from(buf);
+            }
+        }
+    }
+
+    /// Inserts `segment` at `index`, shifting every segment after it.
+    ///
+    /// Returns an error if `segment` is not exactly one component without a prefix or root.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is greater than [`Utf8SegmentedPathBuf::depth`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{CheckedPathError, Utf8SegmentedPathBuf, Utf8UnixEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// let mut path = Utf8SegmentedPathBuf::<Utf8UnixEncoding>::from("/usr/bin");
+    /// assert_eq!(path.insert(1, "local"), Ok(()));
+    /// assert_eq!(path.to_path_buf(), "/usr/local/bin");
+    ///
+    /// assert_eq!(path.insert(0, "a/b"), Err(CheckedPathError::InvalidFilename));
+    /// assert_eq!(path.insert(0, "/a"), Err(CheckedPathError::UnexpectedRoot));
+    /// ```
+    pub fn insert<S: AsRef<str>>(
+        &mut self,
+        index: usize,
+        segment: S,
+    ) -> Result<(), CheckedPathError> {
+        let segment = Self::check_segment(segment.as_ref())?;
+        self.segments.insert(index, segment);
+        Ok(())
+    }
+
+    /// Replaces the segment at `index` with `segment`, returning the segment it replaced.
+    ///
+    /// Returns an error if `segment` is not exactly one component without a prefix or root.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn replace<S: AsRef<str>>(
+        &mut self,
+        index: usize,
+        segment: S,
+    ) -> Result<String, CheckedPathError> {
+        let segment = Self::check_segment(segment.as_ref())?;
+        Ok(core::mem::replace(&mut self.segments[index], segment))
+    }
+
+    /// Removes and returns the segment at `index`, shifting every segment after it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    #[inline]
+    pub fn remove(&mut self, index: usize) -> String {
+        self.segments.remove(index)
+    }
+
+    /// Joins the prefix, root, and segments back into a contiguous [`Utf8PathBuf`].
+    pub fn to_path_buf(&self) -> Utf8PathBuf<T> {
+        let len = self.root.len() + self.segments.iter().map(|s| s.len() + 1).sum::<usize>();
+        let mut s = String::with_capacity(len);
+        s.push_str(&self.root);
+
+        for (i, segment) in self.segments.iter().enumerate() {
+            if i > 0 {
+                s.push(T::SEPARATOR);
+            }
+            s.push_str(segment);
+        }
+
+        Utf8PathBuf::from(s)
+    }
+
+    fn check_segment(segment: &str) -> Result<String, CheckedPathError> {
+        let mut components = T::components(segment);
+        let component = components.next().ok_or(CheckedPathError::InvalidFilename)?;
+
+        // Anything other than a normal, current, or parent component is a prefix or root
+        if !(component.is_normal() || component.is_current() || component.is_parent()) {
+            let mut chars = component.as_str().chars();
+            return match (chars.next(), chars.next()) {
+                (Some(c), None) if T::is_separator(c) => Err(CheckedPathError::UnexpectedRoot),
+                _ => Err(CheckedPathError::UnexpectedPrefix),
+            };
+        }
+
+        let has_disallowed_bytes = component.is_normal()
+            && segment
+                .as_bytes()
+                .iter()
+                .any(|b| T::DISALLOWED_FILENAME_BYTES.contains(b));
+        if has_disallowed_bytes || components.next().is_some() || component.as_str() != segment {
+            return Err(CheckedPathError::InvalidFilename);
+        }
+
+        Ok(segment.to_string())
+    }
+}
+
+impl<T> Default for Utf8SegmentedPathBuf<T>
+where
+    T: for<'enc> Utf8Encoding<'enc>,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Clone for Utf8SegmentedPathBuf<T>
+where
+    T: for<'enc> Utf8Encoding<'enc>,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            _encoding: PhantomData,
+            root: self.root.clone(),
+            segments: self.segments.clone(),
+        }
+    }
+}
+
+impl<T> fmt::Debug for Utf8SegmentedPathBuf<T>
+where
+    T: for<'enc> Utf8Encoding<'enc>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Utf8SegmentedPathBuf")
+            .field("_encoding", &T::label())
+            .field("root", &self.root)
+            .field("segments", &self.segments)
+            .finish()
+    }
+}
+
+impl<T> PartialEq for Utf8SegmentedPathBuf<T>
+where
+    T: for<'enc> Utf8Encoding<'enc>,
+{
+    /// Compares the prefix and root like [`Utf8Path`] does, and the segments by their bytes.
+    fn eq(&self, other: &Self) -> bool {
+        Utf8Path::<T>::new(&self.root) == Utf8Path::<T>::new(&other.root)
+            && self.segments == other.segments
+    }
+}
+
+impl<T> Eq for Utf8SegmentedPathBuf<T> where T: for<'enc> Utf8Encoding<'enc> {}
+
+impl<T> From<Utf8PathBuf<T>> for Utf8SegmentedPathBuf<T>
+where
+    T: for<'enc> Utf8Encoding<'enc>,
+{
+    #[inline]
+    fn from(path: Utf8PathBuf<T>) -> Self {
+        Self::from(path.as_path())
+    }
+}
+
+impl<T, V> From<&V> for Utf8SegmentedPathBuf<T>
+where
+    T: for<'enc> Utf8Encoding<'enc>,
+    V: ?Sized + AsRef<str>,
+{
+    fn from(s: &V) -> Self {
+        let path = Utf8Path::<T>::new(s);
+
+        // Segments come from the components of the whole path, as a prefix like `\\?\` can
+        // change how the components after it are parsed
+        Self {
+            _encoding: PhantomData,
+            root: path
+                .root()
+                .map(|root| root.as_str().to_string())
+                .unwrap_or_default(),
+            segments: path
+                .components()
+                .skip_root()
+                .map(|component| component.as_str().to_string())
+                .collect(),
+        }
+    }
+}
+
+impl<T> From<Utf8SegmentedPathBuf<T>> for Utf8PathBuf<T>
+where
+    T: for<'enc> Utf8Encoding<'enc>,
+{
+    #[inline]
+    fn from(path: Utf8SegmentedPathBuf<T>) -> Self {
+        path.to_path_buf()
+    }
+}
+
+impl<T> From<&Utf8SegmentedPathBuf<T>> for Utf8PathBuf<T>
+where
+    T: for<'enc> Utf8Encoding<'enc>,
+{
+    #[inline]
+    fn from(path: &Utf8SegmentedPathBuf<T>) -> Self {
+        path.to_path_buf()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Utf8UnixEncoding, Utf8WindowsEncoding};
+
+    #[test]
+    fn should_round_trip_through_path_buf() {
+        for path in ["", "/", "a", "./a/../b/", "//usr//lib", "/a/b/c.txt"] {
+            let segmented = Utf8SegmentedPathBuf::<Utf8UnixEncoding>::from(path);
+            assert_eq!(segmented.to_path_buf(), Utf8PathBuf::from(path), "{path}");
+        }
+
+        for path in [
+            r"C:",
+            r"C:a\b",
+            r"C:\a\b",
+            r"\\server\share\a",
+            r"\\?\C:\a\.",
+        ] {
+            let segmented = Utf8SegmentedPathBuf::<Utf8WindowsEncoding>::from(path);
+            assert_eq!(segmented.to_path_buf(), Utf8PathBuf::from(path), "{path}");
+        }
+    }
+
+    #[test]
+    fn segment_edits_should_reject_anything_but_a_single_component() {
+        let mut path = Utf8SegmentedPathBuf::<Utf8WindowsEncoding>::from(r"C:\a");
+        assert_eq!(path.insert(0, ""), Err(CheckedPathError::InvalidFilename));
+        assert_eq!(
+            path.insert(0, r"b\"),
+            Err(CheckedPathError::InvalidFilename)
+        );
+        assert_eq!(
+            path.insert(0, "b?c"),
+            Err(CheckedPathError::InvalidFilename)
+        );
+        assert_eq!(
+            path.insert(0, "D:"),
+            Err(CheckedPathError::UnexpectedPrefix)
+        );
+        assert_eq!(path.insert(0, "/"), Err(CheckedPathError::UnexpectedRoot));
+        assert_eq!(path.replace(0, ".."), Ok("a".to_string()));
+        assert_eq!(path.insert(1, "b"), Ok(()));
+        assert_eq!(path.to_path_buf(), Utf8PathBuf::from(r"C:\..\b"));
+    }
+
+    #[test]
+    fn push_with_root_should_follow_path_buf_push() {
+        let mut path = Utf8SegmentedPathBuf::<Utf8WindowsEncoding>::from(r"C:\a\b");
+        path.push(r"\c");
+        assert_eq!(path.to_path_buf(), Utf8PathBuf::from(r"C:\c"));
+        assert_eq!(path.depth(), 1);
+
+        path.push(r"D:d");
+        assert_eq!(path.root(), Some(Utf8Path::new("D:")));
+        assert_eq!(path.segments().collect::<Vec<_>>(), ["d"]);
+    }
+}
@@ -0,0 +1,198 @@
+use core::iter::FusedIterator;
+
+use crate::{Utf8Component, Utf8Components, Utf8Encoding};
+
+/// A piece of a path exactly as it was written, yielded by
+/// [`Path::segments_with_separators`](crate::Utf8Path::segments_with_separators).
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Utf8PathSegment<'a> {
+    /// A component, including any prefix like `C:` and any `.` that parsing would skip
+    Component(&'a str),
+
+    /// A run of one or more separators, including any root directory
+    Separator(&'a str),
+}
+
+impl<'a> Utf8PathSegment<'a> {
+    /// Returns the segment as they appear in the path.
+    #[inline]
+    pub fn as_str(&self) -> &'a str {
+        match self {
+            Self::Component(s) | Self::Separator(s) => s,
+        }
+    }
+
+    /// Returns true if the segment is a component.
+    #[inline]
+    pub fn is_component(&self) -> bool {
+        matches!(self, Self::Component(_))
+    }
+
+    /// Returns true if the segment is a run of separators.
+    #[inline]
+    pub fn is_separator(&self) -> bool {
+        matches!(self, Self::Separator(_))
+    }
+}
+
+/// An iterator over the [`Utf8PathSegment`]s of a [`Utf8Path`], which together cover every byte of the
+/// path.
+///
+/// This `struct` is created by the [`segments_with_separators`] method on [`Utf8Path`].
+/// See its documentation for more.
+///
+/// [`Utf8Path`]: crate::Utf8Path
+/// [`segments_with_separators`]: crate::Utf8Path::segments_with_separators
+#[derive(Clone)]
+pub struct Utf8SegmentsWithSeparators<'a, T>
+where
+    T: Utf8Encoding<'a>,
+{
+    components: <T as Utf8Encoding<'a>>::Components,
+
+    /// Part of the path following the last component yielded, which hold only separators and skipped `.`
+    gap: &'a str,
+}
+
+impl<'a, T> Utf8SegmentsWithSeparators<'a, T>
+where
+    T: for<'enc> Utf8Encoding<'enc> + 'a,
+{
+    pub(crate) fn new(path: &'a str) -> Self {
+        Self {
+            components: T::components(path),
+            gap: "",
+        }
+    }
+
+    fn separator_len(s: &str) -> usize {
+        s.find(|c| !T::is_separator(c)).unwrap_or(s.len())
+    }
+}
+
+impl<'a, T> Iterator for Utf8SegmentsWithSeparators<'a, T>
+where
+    T: for<'enc> Utf8Encoding<'enc> + 'a,
+{
+    type Item = Utf8PathSegment<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if !self.gap.is_empty() {
+            let len = Self::separator_len(self.gap);
+            let (segment, rest) = if len > 0 {
+                let (s, rest) = self.gap.split_at(len);
+                (Utf8PathSegment::Separator(s), rest)
+            } else {
+                let len = self.gap.find(T::is_separator).unwrap_or(self.gap.len());
+                let (s, rest) = self.gap.split_at(len);
+                (Utf8PathSegment::Component(s), rest)
+            };
+
+            self.gap = rest;
+            return Some(segment);
+        }
+
+        let rest = self.components.as_str();
+        let component = self.components.next()?;
+
+        // Consuming a component also consumes the separators and skipped `.` that follow it
+        let consumed = &rest[..rest.len() - self.components.as_str().len()];
+        let (s, gap) = consumed.split_at(component.len());
+
+        if !s.is_empty() && Self::separator_len(s) == s.len() {
+            // A root directory is a separator itself, so it joins the separators after it
+            let (s, gap) = consumed.split_at(s.len() + Self::separator_len(gap));
+            self.gap = gap;
+            Some(Utf8PathSegment::Separator(s))
+        } else {
+            self.gap = gap;
+            Some(Utf8PathSegment::Component(s))
+        }
+    }
+}
+
+impl<'a, T> FusedIterator for Utf8SegmentsWithSeparators<'a, T> where
+    T: for<'enc> Utf8Encoding<'enc> + 'a
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::String;
+    use alloc::vec::Vec;
+
+    use super::*;
+    use crate::{Utf8UnixEncoding, Utf8WindowsEncoding};
+
+    fn segments<'a, T>(path: &'a str) -> Vec<Utf8PathSegment<'a>>
+    where
+        T: for<'enc> Utf8Encoding<'enc> + 'a,
+    {
+        Utf8SegmentsWithSeparators::<T>::new(path).collect()
+    }
+
+    #[test]
+    fn should_cover_every_byte_of_the_path() {
+        let paths = [
+            "",
+            "/",
+            "//",
+            "a",
+            "a/",
+            "./a",
+            "a/./b",
+            "a//.//b/",
+            "/../a",
+            r"C:",
+            r"C:a\b",
+            r"C:\\a/b\",
+            r"\\server\share\a",
+            r"\\?\C:\a\.\b",
+            r"\\.\COM1",
+        ];
+
+        for path in paths {
+            for segments in [
+                segments::<Utf8UnixEncoding>(path),
+                segments::<Utf8WindowsEncoding>(path),
+            ] {
+                let joined: String = segments.iter().map(|s| s.as_str()).collect();
+                assert_eq!(joined, path, "{path}");
+
+                // Separators never follow separators, and no segment is empty
+                for pair in segments.windows(2) {
+                    assert!(
+                        !(pair[0].is_separator() && pair[1].is_separator()),
+                        "{path}"
+                    );
+                }
+                assert!(segments.iter().all(|s| !s.as_str().is_empty()), "{path}");
+            }
+        }
+    }
+
+    #[test]
+    fn should_yield_skipped_current_dirs_as_components() {
+        assert_eq!(
+            segments::<Utf8UnixEncoding>("//a/./b//"),
+            [
+                Utf8PathSegment::Separator("//"),
+                Utf8PathSegment::Component("a"),
+                Utf8PathSegment::Separator("/"),
+                Utf8PathSegment::Component("."),
+                Utf8PathSegment::Separator("/"),
+                Utf8PathSegment::Component("b"),
+                Utf8PathSegment::Separator("//"),
+            ]
+        );
+
+        assert_eq!(
+            segments::<Utf8WindowsEncoding>(r"C:\/x"),
+            [
+                Utf8PathSegment::Component("C:"),
+                Utf8PathSegment::Separator(r"\/"),
+                Utf8PathSegment::Component("x"),
+            ]
+        );
+    }
+}
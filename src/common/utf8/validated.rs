@@ -0,0 +1,88 @@
+use core::iter::FusedIterator;
+
+use crate::common::{ComponentError, FilesystemProfile, ProfileError};
+use crate::{Utf8Component, Utf8Encoding};
+
+/// An iterator over the [`Utf8Component`]s of a [`Utf8Path`] that checks each normal component
+/// against its encoding and a [`FilesystemProfile`].
+///
+/// This `struct` is created by the [`validated_components`] method on [`Utf8Path`].
+/// See its documentation for more.
+///
+/// [`Utf8Path`]: crate::Utf8Path
+/// [`validated_components`]: crate::Utf8Path::validated_components
+#[derive(Clone)]
+pub struct Utf8ValidatedComponents<'a, T>
+where
+    T: Utf8Encoding<'a>,
+{
+    path: &'a str,
+    inner: <T as Utf8Encoding<'a>>::Components,
+    profile: FilesystemProfile,
+}
+
+impl<'a, T> Utf8ValidatedComponents<'a, T>
+where
+    T: for<'enc> Utf8Encoding<'enc> + 'a,
+{
+    pub(crate) fn new(path: &'a str, profile: &FilesystemProfile) -> Self {
+        Self {
+            path,
+            inner: T::components(path),
+            profile: *profile,
+        }
+    }
+
+    fn validate(
+        &self,
+        component: <<T as Utf8Encoding<'a>>::Components as Iterator>::Item,
+    ) -> Result<<<T as Utf8Encoding<'a>>::Components as Iterator>::Item, ComponentError> {
+        if !component.is_normal() {
+            return Ok(component);
+        }
+
+        // Normal components always borrow from the path being iterated
+        let bytes = component.as_str().as_bytes();
+        let start = bytes.as_ptr() as usize - self.path.as_ptr() as usize;
+        let span = start..start + bytes.len();
+
+        let result = match bytes
+            .iter()
+            .find(|b| T::DISALLOWED_FILENAME_BYTES.contains(b))
+        {
+            Some(b) => Err(ProfileError::InvalidByte(*b)),
+            None => self.profile.check_component(bytes),
+        };
+
+        result
+            .map(|_| component)
+            .map_err(|kind| ComponentError { span, kind })
+    }
+}
+
+impl<'a, T> Iterator for Utf8ValidatedComponents<'a, T>
+where
+    T: for<'enc> Utf8Encoding<'enc> + 'a,
+{
+    type Item = Result<<<T as Utf8Encoding<'a>>::Components as Iterator>::Item, ComponentError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let component = self.inner.next()?;
+        Some(self.validate(component))
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for Utf8ValidatedComponents<'a, T>
+where
+    T: for<'enc> Utf8Encoding<'enc> + 'a,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let component = self.inner.next_back()?;
+        Some(self.validate(component))
+    }
+}
+
+impl<'a, T> FusedIterator for Utf8ValidatedComponents<'a, T> where
+    T: for<'enc> Utf8Encoding<'enc> + 'a
+{
+}
@@ -0,0 +1,47 @@
+use crate::common::WireFormatError;
+use crate::no_std_compat::*;
+
+/// Appends `value` to `out` as an unsigned LEB128 integer
+pub(crate) fn write(mut value: usize, out: &mut Vec<u8>) {
+    loop {
+        let byte = (value & 0x7f) as u8;
+        value >>= 7;
+        if value == 0 {
+            out.push(byte);
+            break;
+        }
+        out.push(byte | 0x80);
+    }
+}
+
+/// Reads an unsigned LEB128 integer from the front of `input`, returning it alongside the
+/// remaining input
+pub(crate) fn read(mut input: &[u8]) -> Result<(usize, &[u8]), WireFormatError> {
+    let mut value: usize = 0;
+    let mut shift = 0;
+
+    loop {
+        let (&byte, rest) = input.split_first().ok_or(WireFormatError::UnexpectedEnd)?;
+        input = rest;
+
+        let bits = (byte & 0x7f) as usize;
+        if shift >= usize::BITS || (bits << shift) >> shift != bits {
+            return Err(WireFormatError::LengthOverflow);
+        }
+        value |= bits << shift;
+        shift += 7;
+
+        if byte & 0x80 == 0 {
+            return Ok((value, input));
+        }
+    }
+}
+
+/// Reads `len` bytes from the front of `input`, returning them alongside the remaining input
+pub(crate) fn read_bytes(input: &[u8], len: usize) -> Result<(&[u8], &[u8]), WireFormatError> {
+    if input.len() < len {
+        return Err(WireFormatError::UnexpectedEnd);
+    }
+
+    Ok(input.split_at(len))
+}
@@ -0,0 +1,82 @@
+#[cfg(any(all(feature = "std", windows), test))]
+use crate::no_std_compat::*;
+
+/// Encodes UTF-16 code units as [WTF-8](https://simonsapin.github.io/wtf-8/), which is UTF-8
+/// extended to represent unpaired surrogates as their own three byte sequence.
+///
+/// This is lossless for any string produced by Windows, including ones that are not valid UTF-16.
+#[cfg(any(all(feature = "std", windows), test))]
+pub(crate) fn from_wide<I: IntoIterator<Item = u16>>(units: I) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    for unit in char::decode_utf16(units) {
+        match unit {
+            Ok(c) => {
+                let mut buf = [0; 4];
+                bytes.extend_from_slice(c.encode_utf8(&mut buf).as_bytes());
+            }
+            Err(e) => {
+                let s = e.unpaired_surrogate();
+                bytes.extend_from_slice(&[
+                    0xe0 | (s >> 12) as u8,
+                    0x80 | ((s >> 6) & 0x3f) as u8,
+                    0x80 | (s & 0x3f) as u8,
+                ]);
+            }
+        }
+    }
+    bytes
+}
+
+/// Returns the number of UTF-16 code units that `bytes` would take up once widened, counting
+/// each encoded surrogate as one unit and each other invalid sequence as the one replacement
+/// character it would be decoded into.
+pub(crate) fn utf16_len(bytes: &[u8]) -> usize {
+    let mut len = 0;
+    let mut rest = bytes;
+    loop {
+        match core::str::from_utf8(rest) {
+            Ok(s) => return len + s.encode_utf16().count(),
+            Err(e) => {
+                let (valid, invalid) = rest.split_at(e.valid_up_to());
+                len += core::str::from_utf8(valid).map_or(0, |s| s.encode_utf16().count());
+                len += 1;
+
+                let skip = match invalid {
+                    [0xed, 0xa0..=0xbf, 0x80..=0xbf, ..] => 3,
+                    _ => e.error_len().unwrap_or(invalid.len()),
+                };
+                rest = &invalid[skip..];
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_wide_should_match_utf8_for_valid_utf16() {
+        let s = "C:\\caf\u{e9}\\\u{1f980}";
+        assert_eq!(from_wide(s.encode_utf16()), s.as_bytes());
+    }
+
+    #[test]
+    fn from_wide_should_encode_unpaired_surrogates() {
+        // Lone lead surrogate followed by a character, then a lone trail surrogate
+        assert_eq!(
+            from_wide([0xd83e, 0x61, 0xdd80]),
+            [0xed, 0xa0, 0xbe, b'a', 0xed, 0xb6, 0x80]
+        );
+    }
+
+    #[test]
+    fn utf16_len_should_count_the_units_of_the_widened_bytes() {
+        for s in ["", "C:\\a", "caf\u{e9}", "\u{1f980}\u{1f980}"] {
+            assert_eq!(utf16_len(s.as_bytes()), s.encode_utf16().count(), "{s}");
+        }
+
+        assert_eq!(utf16_len(&from_wide([0xd83e, 0x61, 0xdd80])), 3);
+        assert_eq!(utf16_len(b"a\xffb\xe2\x82"), 4);
+    }
+}
@@ -0,0 +1,306 @@
+//! Differential checks of the native encoding against [`std::path`].
+//!
+//! typed-path mirrors the parsing rules of [`std::path::Path`], so on a Unix host
+//! [`UnixPath`](crate::UnixPath) should agree with it, and on a Windows host
+//! [`WindowsPath`](crate::WindowsPath) should. [`compat_check`] runs both over a corpus of paths
+//! and reports every disagreement, which lets downstream crates confirm in their own CI that
+//! the paths they care about behave the same either way. [`CORPUS`] is the corpus the crate
+//! checks itself against, and can be chained with your own.
+//!
+//! # Examples
+//!
+//! ```
+//! use typed_path::compat::{compat_check, CORPUS};
+//!
+//! let corpus = CORPUS.iter().copied().chain(["assets/textures/stone.png", "../build"]);
+//! if let Err(mismatches) = compat_check(corpus) {
+//!     for mismatch in &mismatches {
+//!         eprintln!("{mismatch}");
+//!     }
+//!     panic!("{} mismatches with std", mismatches.len());
+//! }
+//! ```
+
+use std::error::Error;
+use std::fmt;
+use std::path::Path as StdPath;
+
+use crate::{Utf8Component, Utf8NativePath};
+
+/// Paths covering the corner cases of both Unix and Windows parsing, including every kind of
+/// Windows prefix, drive-relative paths, repeated and mixed separators, `.` and `..` in every
+/// position, and file names with leading, trailing, and repeated dots.
+pub const CORPUS: &[&str] = &[
+    // Empty, current, and parent
+    "",
+    ".",
+    "..",
+    "./",
+    "./.",
+    "../..",
+    "./../a/",
+    "../a/..",
+    "a/../.",
+    // Roots and separators
+    "/",
+    "//",
+    "///",
+    "/.",
+    "/..",
+    "/./a",
+    "//a//b//",
+    "a//b///c",
+    // Relative and absolute
+    "a",
+    "a/",
+    "a/b",
+    "/a/b/",
+    "a/.",
+    "a/./",
+    "a/b/./",
+    "./a/./b/.",
+    // File names
+    ".a",
+    "a.",
+    "a..",
+    "..a",
+    "a.b",
+    "a.b.c",
+    "a/.hidden",
+    "a/archive.tar.gz",
+    "a/b./",
+    "a/b. ",
+    "a/ b",
+    // Backslash separators
+    r"\",
+    r"\a",
+    r"a\b",
+    r"a\.\b",
+    r"a\..\b",
+    r"a/b\c",
+    r"\\",
+    // Disks
+    "C:",
+    r"C:\",
+    "C:/",
+    "C:a",
+    r"C:a\b",
+    r"C:\a\b",
+    r"C:\a\..\b",
+    r"C:..\a",
+    r"c:\a",
+    r"C:\a.",
+    r"C:\a\.",
+    // UNC
+    r"\\server",
+    r"\\server\share",
+    r"\\server\share\",
+    r"\\server\share\a",
+    r"\\server\share\a\..",
+    r"//server/share/a",
+    // Verbatim
+    r"\\?\C:",
+    r"\\?\C:\",
+    r"\\?\C:\a",
+    r"\\?\C:\a\.\b",
+    r"\\?\C:\a\..\b",
+    r"\\?\C:/a",
+    r"\\?\UNC\server\share",
+    r"\\?\UNC\server\share\a",
+    r"\\?\pictures",
+    r"\\?\pictures\a",
+    r"\\?\Volume{1234}\a",
+    // Device namespace
+    r"\\.\COM1",
+    r"\\.\COM1\a",
+    r"\\.\pipe\name",
+    r"\\.\C:\a",
+];
+
+/// A way in which the native encoding disagreed with [`std::path`] about a path, returned by
+/// [`compat_check`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CompatMismatch {
+    path: String,
+    check: &'static str,
+    expected: String,
+    actual: String,
+}
+
+impl CompatMismatch {
+    /// Returns the path that was checked.
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// Returns the name of the check that failed, such as `components` or `parent`.
+    pub fn check(&self) -> &'static str {
+        self.check
+    }
+
+    /// Returns the result from [`std::path`], formatted with [`Debug`](fmt::Debug).
+    pub fn expected(&self) -> &str {
+        &self.expected
+    }
+
+    /// Returns the result from the native encoding, formatted with [`Debug`](fmt::Debug).
+    pub fn actual(&self) -> &str {
+        &self.actual
+    }
+}
+
+impl fmt::Display for CompatMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} of {:?} differs from std: expected {}, got {}",
+            self.check, self.path, self.expected, self.actual
+        )
+    }
+}
+
+impl Error for CompatMismatch {}
+
+/// Compares the native encoding against [`std::path`] for every path in `corpus`, returning
+/// every mismatch found.
+///
+/// Each path is checked for its components, from the front and the back, `file_name`,
+/// `file_stem`, `extension`, `parent`, `is_absolute`, `has_root`, joining a child, and for
+/// `starts_with` and `strip_prefix` with its parent.
+///
+/// On Windows, std treats every prefix except a plain disk like `C:` as implying a root, while
+/// this crate only calls a path absolute once a root follows its prefix. Expect `is_absolute`
+/// mismatches for paths like `\\server\share` there, and `has_root` ones too for `\\?\C:`.
+///
+/// # Errors
+///
+/// Returns every [`CompatMismatch`] found, in the order the paths were given.
+pub fn compat_check<I, S>(corpus: I) -> Result<(), Vec<CompatMismatch>>
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<str>,
+{
+    let mut mismatches = Vec::new();
+    for path in corpus {
+        check_path(path.as_ref(), &mut mismatches);
+    }
+
+    if mismatches.is_empty() {
+        Ok(())
+    } else {
+        Err(mismatches)
+    }
+}
+
+fn check_path(path: &str, mismatches: &mut Vec<CompatMismatch>) {
+    let ours = Utf8NativePath::new(path);
+    let theirs = StdPath::new(path);
+
+    let mut check = |check: &'static str, expected: &dyn fmt::Debug, actual: &dyn fmt::Debug| {
+        let expected = format!("{expected:?}");
+        let actual = format!("{actual:?}");
+        if expected != actual {
+            mismatches.push(CompatMismatch {
+                path: path.to_string(),
+                check,
+                expected,
+                actual,
+            });
+        }
+    };
+
+    check("components", &std_components(theirs), &components(ours));
+    check(
+        "components_rev",
+        &theirs
+            .components()
+            .rev()
+            .map(|c| c.as_os_str().to_str())
+            .collect::<Vec<_>>(),
+        &ours
+            .components()
+            .rev()
+            .map(|c| Some(c.as_str()))
+            .collect::<Vec<_>>(),
+    );
+    check(
+        "file_name",
+        &theirs.file_name().and_then(|s| s.to_str()),
+        &ours.file_name(),
+    );
+    check(
+        "file_stem",
+        &theirs.file_stem().and_then(|s| s.to_str()),
+        &ours.file_stem(),
+    );
+    check(
+        "extension",
+        &theirs.extension().and_then(|s| s.to_str()),
+        &ours.extension(),
+    );
+    check(
+        "parent",
+        &theirs.parent().and_then(StdPath::to_str),
+        &ours.parent().map(Utf8NativePath::as_str),
+    );
+    check("is_absolute", &theirs.is_absolute(), &ours.is_absolute());
+    check("has_root", &theirs.has_root(), &ours.has_root());
+    check(
+        "join",
+        &theirs.join("child").to_str().map(String::from),
+        &Some(ours.join("child").into_string()),
+    );
+
+    // The bytes left over may keep separators that std drops, so compare them by component
+    let base = ours.parent().unwrap_or(ours);
+    let std_base = StdPath::new(base.as_str());
+    check(
+        "starts_with",
+        &theirs.starts_with(std_base),
+        &ours.starts_with(base),
+    );
+    check(
+        "strip_prefix",
+        &theirs.strip_prefix(std_base).ok().map(std_components),
+        &ours.strip_prefix(base).ok().map(components),
+    );
+}
+
+fn components(path: &Utf8NativePath) -> Vec<Option<&str>> {
+    path.components().map(|c| Some(c.as_str())).collect()
+}
+
+fn std_components(path: &StdPath) -> Vec<Option<&str>> {
+    path.components().map(|c| c.as_os_str().to_str()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[cfg_attr(
+        windows,
+        ignore = "prefixes without a root are not yet reconciled with std"
+    )]
+    fn corpus_should_match_std() {
+        if let Err(mismatches) = compat_check(CORPUS) {
+            let report: Vec<String> = mismatches.iter().map(ToString::to_string).collect();
+            panic!("{}", report.join("\n"));
+        }
+    }
+
+    #[test]
+    fn mismatch_should_describe_the_failed_check() {
+        let mismatch = CompatMismatch {
+            path: String::from("a"),
+            check: "parent",
+            expected: String::from("Some(\"\")"),
+            actual: String::from("None"),
+        };
+        assert_eq!(
+            mismatch.to_string(),
+            "parent of \"a\" differs from std: expected Some(\"\"), got None"
+        );
+    }
+}
@@ -14,6 +14,8 @@ use crate::{
 
 #[cfg(all(feature = "std", not(target_family = "wasm")))]
 use crate::native::{Utf8NativePath, Utf8NativePathBuf};
+#[cfg(all(feature = "std", unix))]
+use crate::unix::{UnixPath, UnixPathBuf};
 
 /// Interface to try to perform a cheap reference-to-reference conversion.
 pub trait TryAsRef<T: ?Sized> {
@@ -312,6 +314,12 @@ impl<'a> TryFrom<StdComponent<'a>> for WindowsComponent<'a> {
 impl AsRef<StdPath> for Utf8NativePath {
     /// Converts a native utf8 path (based on compilation family) into [`std::path::Path`].
     ///
+    /// As the native path is [`Utf8UnixPath`] on Unix and [`Utf8WindowsPath`] on Windows, either
+    /// can be passed directly to functions like [`std::fs::metadata`] on its own platform.
+    ///
+    /// [`Utf8UnixPath`]: crate::Utf8UnixPath
+    /// [`Utf8WindowsPath`]: crate::Utf8WindowsPath
+    ///
     /// ```
     /// use typed_path::Utf8NativePath;
     /// use std::path::Path;
@@ -344,6 +352,45 @@ impl AsRef<StdPath> for Utf8NativePathBuf {
     }
 }
 
+#[cfg(all(feature = "std", unix))]
+impl AsRef<StdPath> for UnixPath {
+    /// Converts a Unix path into [`std::path::Path`] when running on Unix, keeping any bytes that
+    /// are not valid UTF-8, so it can be passed directly to functions like [`std::fs::metadata`].
+    ///
+    /// ```
+    /// use typed_path::UnixPath;
+    /// use std::path::Path;
+    ///
+    /// let unix_path = UnixPath::new(b"/tmp/caf\xe9");
+    /// let std_path: &Path = unix_path.as_ref();
+    /// assert_eq!(std_path.file_name().map(|x| x.len()), Some(4));
+    ///
+    /// assert!(std::fs::metadata(UnixPath::new("/")).unwrap().is_dir());
+    /// ```
+    fn as_ref(&self) -> &StdPath {
+        StdPath::new(AsRef::<OsStr>::as_ref(self))
+    }
+}
+
+#[cfg(all(feature = "std", unix))]
+impl AsRef<StdPath> for UnixPathBuf {
+    /// Converts a Unix pathbuf into [`std::path::Path`] when running on Unix, keeping any bytes
+    /// that are not valid UTF-8.
+    ///
+    /// ```
+    /// use typed_path::UnixPathBuf;
+    /// use std::path::Path;
+    ///
+    /// let unix_path_buf = UnixPathBuf::from("/tmp/some_file.txt");
+    /// let std_path: &Path = unix_path_buf.as_ref();
+    ///
+    /// assert_eq!(std_path, Path::new("/tmp/some_file.txt"));
+    /// ```
+    fn as_ref(&self) -> &StdPath {
+        StdPath::new(AsRef::<OsStr>::as_ref(self))
+    }
+}
+
 #[cfg(all(feature = "std", not(target_family = "wasm")))]
 impl<'a> From<&'a Utf8NativePath> for StdPathBuf {
     /// Converts a native utf8 path (based on compilation family) into [`std::path::PathBuf`].
@@ -454,6 +501,160 @@ mod common {
     }
 }
 
+#[cfg(all(feature = "std", any(unix, windows)))]
+mod os_string {
+    use std::ffi::{OsStr, OsString};
+
+    use super::*;
+    use crate::{TypedPathBuf, Utf8Encoding, Utf8PathBuf, Utf8PathError, Utf8TypedPathBuf};
+
+    /// Returns the bytes of `s` as-is on Unix, or encoded as WTF-8 on Windows
+    fn os_string_into_bytes(s: OsString) -> Vec<u8> {
+        #[cfg(unix)]
+        {
+            std::os::unix::ffi::OsStringExt::into_vec(s)
+        }
+
+        #[cfg(windows)]
+        {
+            use std::os::windows::ffi::OsStrExt;
+            crate::common::wtf8::from_wide(s.encode_wide())
+        }
+    }
+
+    impl<T> From<OsString> for PathBuf<T>
+    where
+        T: for<'enc> Encoding<'enc>,
+    {
+        /// Converts an [`OsString`] into a [`PathBuf`], keeping its bytes as-is on Unix without
+        /// copying them, or encoding it as [WTF-8](https://simonsapin.github.io/wtf-8/) on
+        /// Windows, both of which are lossless.
+        ///
+        /// ```
+        /// use std::ffi::OsString;
+        /// use typed_path::UnixPathBuf;
+        ///
+        /// let path = UnixPathBuf::from(OsString::from("/tmp/some_file.txt"));
+        /// assert_eq!(path, UnixPathBuf::from("/tmp/some_file.txt"));
+        ///
+        /// // Bytes that are not UTF-8 are kept on Unix
+        /// #[cfg(unix)]
+        /// {
+        ///     use std::os::unix::ffi::OsStringExt;
+        ///
+        ///     let path = UnixPathBuf::from(OsString::from_vec(b"caf\xe9".to_vec()));
+        ///     assert_eq!(path.as_bytes(), b"caf\xe9");
+        /// }
+        /// ```
+        #[inline]
+        fn from(s: OsString) -> Self {
+            PathBuf::from(os_string_into_bytes(s))
+        }
+    }
+
+    impl<T> TryFrom<OsString> for Utf8PathBuf<T>
+    where
+        T: for<'enc> Utf8Encoding<'enc>,
+    {
+        type Error = Utf8PathError;
+
+        /// Converts an [`OsString`] into a [`Utf8PathBuf`], failing if it is not valid Unicode.
+        ///
+        /// ```
+        /// use std::convert::TryFrom;
+        /// use std::ffi::OsString;
+        /// use typed_path::Utf8UnixPathBuf;
+        ///
+        /// let path = Utf8UnixPathBuf::try_from(OsString::from("/tmp/some_file.txt")).unwrap();
+        /// assert_eq!(path, Utf8UnixPathBuf::from("/tmp/some_file.txt"));
+        ///
+        /// #[cfg(unix)]
+        /// {
+        ///     use std::os::unix::ffi::OsStringExt;
+        ///
+        ///     let err = Utf8UnixPathBuf::try_from(OsString::from_vec(b"caf\xe9".to_vec()))
+        ///         .unwrap_err();
+        ///     assert_eq!(err.valid_prefix(), "caf");
+        /// }
+        /// ```
+        fn try_from(s: OsString) -> Result<Self, Self::Error> {
+            Ok(Utf8PathBuf::from(String::from_utf8(os_string_into_bytes(
+                s,
+            ))?))
+        }
+    }
+
+    impl From<&OsStr> for TypedPathBuf {
+        /// Converts an [`OsStr`] into a [`TypedPathBuf`] tagged with the encoding given by
+        /// [`TypedPath::native_kind`], the same as [`TypedPathBuf::from_native`].
+        ///
+        /// [`TypedPath::native_kind`]: crate::TypedPath::native_kind
+        ///
+        /// ```
+        /// use std::ffi::OsStr;
+        /// use typed_path::{PathType, TypedPath, TypedPathBuf};
+        ///
+        /// let path = TypedPathBuf::from(OsStr::new("file.txt"));
+        /// assert_eq!(path.is_windows(), TypedPath::native_kind() == PathType::Windows);
+        /// assert_eq!(path.as_bytes(), b"file.txt");
+        /// ```
+        #[inline]
+        fn from(s: &OsStr) -> Self {
+            TypedPathBuf::from_native(StdPath::new(s))
+        }
+    }
+
+    impl From<OsString> for TypedPathBuf {
+        /// Converts an [`OsString`] into a [`TypedPathBuf`] tagged with the encoding given by
+        /// [`TypedPath::native_kind`], without copying its bytes on Unix.
+        ///
+        /// [`TypedPath::native_kind`]: crate::TypedPath::native_kind
+        fn from(s: OsString) -> Self {
+            let bytes = os_string_into_bytes(s);
+            if cfg!(windows) {
+                TypedPathBuf::Windows(PathBuf::from(bytes))
+            } else {
+                TypedPathBuf::Unix(PathBuf::from(bytes))
+            }
+        }
+    }
+
+    impl TryFrom<&OsStr> for Utf8TypedPathBuf {
+        type Error = Utf8PathError;
+
+        /// Converts an [`OsStr`] into a [`Utf8TypedPathBuf`] tagged with the encoding given by
+        /// [`TypedPath::native_kind`], failing if it is not valid Unicode.
+        ///
+        /// [`TypedPath::native_kind`]: crate::TypedPath::native_kind
+        ///
+        /// ```
+        /// use std::convert::TryFrom;
+        /// use std::ffi::OsStr;
+        /// use typed_path::Utf8TypedPathBuf;
+        ///
+        /// let path = Utf8TypedPathBuf::try_from(OsStr::new("file.txt")).unwrap();
+        /// assert_eq!(path.as_str(), "file.txt");
+        /// ```
+        #[inline]
+        fn try_from(s: &OsStr) -> Result<Self, Self::Error> {
+            Utf8TypedPathBuf::try_from(TypedPathBuf::from(s))
+        }
+    }
+
+    impl TryFrom<OsString> for Utf8TypedPathBuf {
+        type Error = Utf8PathError;
+
+        /// Converts an [`OsString`] into a [`Utf8TypedPathBuf`] tagged with the encoding given
+        /// by [`TypedPath::native_kind`], failing if it is not valid Unicode.
+        ///
+        /// [`TypedPath::native_kind`]: crate::TypedPath::native_kind
+        #[inline]
+        fn try_from(s: OsString) -> Result<Self, Self::Error> {
+            Utf8TypedPathBuf::try_from(TypedPathBuf::from(s))
+        }
+    }
+}
+
 #[cfg(test)]
 #[cfg(feature = "std")]
 mod tests {
@@ -511,6 +712,29 @@ mod tests {
         );
     }
 
+    #[test]
+    #[cfg(unix)]
+    fn from_os_string_should_keep_bytes_that_are_not_utf8_on_unix() {
+        use std::ffi::{OsStr, OsString};
+        use std::os::unix::ffi::{OsStrExt, OsStringExt};
+
+        use crate::{TypedPathBuf, Utf8TypedPathBuf, Utf8UnixPathBuf};
+
+        let bytes = b"/tmp/caf\xe9";
+        let path = UnixPathBuf::from(OsString::from_vec(bytes.to_vec()));
+        assert_eq!(path.as_bytes(), bytes);
+        assert_eq!(OsString::from(path).into_vec(), bytes);
+
+        let path = TypedPathBuf::from(OsStr::from_bytes(bytes));
+        assert!(path.is_unix());
+        assert_eq!(path.as_bytes(), bytes);
+
+        let err = Utf8UnixPathBuf::try_from(OsString::from_vec(bytes.to_vec())).unwrap_err();
+        assert_eq!(err.into_bytes(), bytes);
+        let err = Utf8TypedPathBuf::try_from(OsStr::from_bytes(bytes)).unwrap_err();
+        assert_eq!(err.valid_prefix(), "/tmp/caf");
+    }
+
     #[test]
     #[cfg(not(windows))]
     fn try_from_windows_component_to_std_component_should_fail_for_prefix_on_non_windows() {
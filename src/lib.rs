@@ -19,14 +19,27 @@ mod no_std_compat {
 
 #[macro_use]
 mod common;
+#[cfg(all(feature = "compat", any(unix, windows)))]
+pub mod compat;
 mod convert;
 #[cfg(not(target_family = "wasm"))]
 mod native;
+mod plan9;
+mod portable;
+mod powershell;
+mod schemed;
+#[cfg(feature = "security")]
+pub mod security;
+#[cfg(feature = "serde")]
+pub mod serde_impls;
+#[cfg(feature = "testdata")]
+pub mod testdata;
 mod typed;
 mod unix;
 #[cfg(all(feature = "std", not(target_family = "wasm")))]
 pub mod utils;
 mod windows;
+mod zos;
 
 mod private {
     /// Used to mark traits as sealed to prevent implements from others outside of this crate
@@ -37,15 +50,44 @@ pub use common::*;
 pub use convert::*;
 #[cfg(not(target_family = "wasm"))]
 pub use native::*;
+pub use plan9::*;
+pub use portable::*;
+pub use powershell::*;
+pub use schemed::*;
 pub use typed::*;
 pub use unix::*;
 pub use windows::*;
+pub use zos::*;
 
 /// Contains constants associated with different path formats.
 pub mod constants {
+    use super::plan9::constants as plan9_constants;
+    use super::portable::constants as portable_constants;
+    use super::powershell::constants as powershell_constants;
+    use super::schemed::constants as schemed_constants;
     use super::unix::constants as unix_constants;
     use super::windows::constants as windows_constants;
 
+    /// Contains constants associated with Plan 9 paths.
+    pub mod plan9 {
+        pub use super::plan9_constants::*;
+    }
+
+    /// Contains constants associated with portable paths.
+    pub mod portable {
+        pub use super::portable_constants::*;
+    }
+
+    /// Contains constants associated with PowerShell paths.
+    pub mod powershell {
+        pub use super::powershell_constants::*;
+    }
+
+    /// Contains constants associated with schemed paths.
+    pub mod schemed {
+        pub use super::schemed_constants::*;
+    }
+
     /// Contains constants associated with Unix paths.
     pub mod unix {
         pub use super::unix_constants::*;
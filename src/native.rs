@@ -1,6 +1,31 @@
 pub use self::non_utf8::*;
 pub use self::utf8::*;
 
+#[cfg(all(test, feature = "std"))]
+const CONFORMANCE_SAMPLES: &[&str] = &[
+    "",
+    ".",
+    "..",
+    "/",
+    "//",
+    "a",
+    "a/b",
+    "/a/b/",
+    "./",
+    "./.",
+    "a/.",
+    "a/./",
+    "./a/./b/.",
+    "a//b///c",
+    "../a/..",
+    "/./a",
+    "/..",
+    "a/b/./",
+    "//a//b//",
+    "a/../.",
+    "./../a/",
+];
+
 mod non_utf8 {
     /// [`Encoding`](crate::Encoding) that is native to the platform during compilation
     #[cfg(unix)]
@@ -43,6 +68,74 @@ mod non_utf8 {
             let path = NativePathBuf::from("hello.txt");
             assert_eq!(path, path.clone());
         }
+
+        /// Walks `path` with both our components and those of the standard library, consuming
+        /// from the front when `front(step)` is true and from the back otherwise
+        #[cfg(feature = "std")]
+        fn assert_components_match_std(path: &str, front: fn(usize) -> bool) {
+            use crate::Component;
+
+            let mut ours = NativePath::new(path).components();
+            let mut theirs = std::path::Path::new(path).components();
+
+            for step in 0.. {
+                let expected = theirs.as_path().to_str().unwrap();
+                assert_eq!(
+                    ours.as_path::<NativeEncoding>().as_bytes(),
+                    expected.as_bytes(),
+                    "as_path of {path:?} at step {step}",
+                );
+
+                let (a, b) = if front(step) {
+                    (ours.next(), theirs.next())
+                } else {
+                    (ours.next_back(), theirs.next_back())
+                };
+                assert_eq!(
+                    a.map(|c| c.as_bytes()),
+                    b.map(|c| c.as_os_str().to_str().unwrap().as_bytes()),
+                    "component of {path:?} at step {step}",
+                );
+
+                if a.is_none() {
+                    break;
+                }
+            }
+
+            // Both iterators are fused
+            assert!(ours.next().is_none() && ours.next_back().is_none());
+        }
+
+        #[cfg(feature = "std")]
+        #[test]
+        fn components_should_match_std() {
+            for path in crate::native::CONFORMANCE_SAMPLES {
+                assert_components_match_std(path, |_| true);
+                assert_components_match_std(path, |_| false);
+                assert_components_match_std(path, |step| step % 2 == 0);
+                assert_components_match_std(path, |step| step % 2 == 1);
+            }
+        }
+
+        #[cfg(feature = "std")]
+        #[test]
+        fn iter_should_match_std() {
+            for path in crate::native::CONFORMANCE_SAMPLES {
+                let mut ours = NativePath::new(path).iter();
+                let mut theirs = std::path::Path::new(path).iter();
+                loop {
+                    let expected = theirs.as_path().to_str().unwrap();
+                    assert_eq!(ours.as_path().as_bytes(), expected.as_bytes(), "{path:?}");
+
+                    let a = ours.next_back();
+                    let b = theirs.next_back().map(|c| c.to_str().unwrap().as_bytes());
+                    assert_eq!(a, b, "{path:?}");
+                    if a.is_none() {
+                        break;
+                    }
+                }
+            }
+        }
     }
 }
 
@@ -88,5 +181,82 @@ mod utf8 {
             let path = Utf8NativePathBuf::from("hello.txt");
             assert_eq!(path, path.clone());
         }
+
+        /// Walks `path` with both our components and those of the standard library, consuming
+        /// from the front when `front(step)` is true and from the back otherwise
+        #[cfg(feature = "std")]
+        fn assert_components_match_std(path: &str, front: fn(usize) -> bool) {
+            use crate::Utf8Component;
+
+            let mut ours = Utf8NativePath::new(path).components();
+            let mut theirs = std::path::Path::new(path).components();
+
+            for step in 0.. {
+                assert_eq!(
+                    ours.as_path::<Utf8NativeEncoding>().as_str(),
+                    theirs.as_path().to_str().unwrap(),
+                    "as_path of {path:?} at step {step}",
+                );
+
+                let (a, b) = if front(step) {
+                    (ours.next(), theirs.next())
+                } else {
+                    (ours.next_back(), theirs.next_back())
+                };
+                assert_eq!(
+                    a.map(|c| c.as_str()),
+                    b.map(|c| c.as_os_str().to_str().unwrap()),
+                    "component of {path:?} at step {step}",
+                );
+
+                if a.is_none() {
+                    break;
+                }
+            }
+
+            // Both iterators are fused
+            assert!(ours.next().is_none() && ours.next_back().is_none());
+        }
+
+        #[cfg(feature = "std")]
+        #[test]
+        fn components_should_match_std() {
+            for path in crate::native::CONFORMANCE_SAMPLES {
+                assert_components_match_std(path, |_| true);
+                assert_components_match_std(path, |_| false);
+                assert_components_match_std(path, |step| step % 2 == 0);
+                assert_components_match_std(path, |step| step % 2 == 1);
+            }
+        }
+
+        #[cfg(feature = "std")]
+        #[test]
+        fn typed_components_should_match_std() {
+            for path in crate::native::CONFORMANCE_SAMPLES {
+                #[cfg(unix)]
+                let mut ours = crate::Utf8TypedPath::unix(path).components();
+                #[cfg(windows)]
+                let mut ours = crate::Utf8TypedPath::windows(path).components();
+                let mut theirs = std::path::Path::new(path).components();
+                loop {
+                    assert_eq!(
+                        ours.as_path().as_str(),
+                        theirs.as_path().to_str().unwrap(),
+                        "{path:?}",
+                    );
+
+                    let a = ours.next();
+                    let b = theirs.next();
+                    assert_eq!(
+                        a.map(|c| c.as_str()),
+                        b.map(|c| c.as_os_str().to_str().unwrap()),
+                        "{path:?}",
+                    );
+                    if a.is_none() {
+                        break;
+                    }
+                }
+            }
+        }
     }
 }
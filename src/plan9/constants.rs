@@ -0,0 +1,43 @@
+/// The primary separator of path components for Plan 9 paths
+pub const SEPARATOR: char = '/';
+
+/// The primary separator of path components for Plan 9 paths
+pub const SEPARATOR_STR: &str = "/";
+
+/// Byte that starts the name of a kernel device root, such as `#c` or `#I0`
+pub const DEVICE_MARKER: u8 = b'#';
+
+/// Path component value that represents the parent directory
+pub const PARENT_DIR: &[u8] = b"..";
+
+/// Path component value that represents the parent directory
+pub const PARENT_DIR_STR: &str = "..";
+
+/// Path component value that represents the current directory
+pub const CURRENT_DIR: &[u8] = b".";
+
+/// Path component value that represents the current directory
+pub const CURRENT_DIR_STR: &str = ".";
+
+/// Maximum number of names that can be walked by a single 9P `Twalk` message (`MAXWELEM`)
+pub const MAX_WALK_ELEMENTS: usize = 16;
+
+/// Maximum length in bytes of a name sent over 9P, whose strings have a 16-bit length
+pub const MAX_ELEMENT_LEN: usize = u16::MAX as usize;
+
+/// Bytes that are not allowed in file or directory names, which are the same bytes rejected by
+/// the Plan 9 kernel: `/`, ascii control characters, and `DEL`
+pub const DISALLOWED_FILENAME_BYTES: [u8; 34] = [
+    b'/', b'\0', 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d,
+    0x0e, 0x0f, 0x10, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17, 0x18, 0x19, 0x1a, 0x1b, 0x1c, 0x1d,
+    0x1e, 0x1f, 0x7f,
+];
+
+/// Bytes that are not allowed in file or directory names, which are the same bytes rejected by
+/// the Plan 9 kernel: `/`, ascii control characters, and `DEL`
+pub const DISALLOWED_FILENAME_CHARS: [char; 34] = [
+    '/', '\0', '\u{01}', '\u{02}', '\u{03}', '\u{04}', '\u{05}', '\u{06}', '\u{07}', '\u{08}',
+    '\u{09}', '\u{0a}', '\u{0b}', '\u{0c}', '\u{0d}', '\u{0e}', '\u{0f}', '\u{10}', '\u{11}',
+    '\u{12}', '\u{13}', '\u{14}', '\u{15}', '\u{16}', '\u{17}', '\u{18}', '\u{19}', '\u{1a}',
+    '\u{1b}', '\u{1c}', '\u{1d}', '\u{1e}', '\u{1f}', '\u{7f}',
+];
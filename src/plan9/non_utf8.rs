@@ -0,0 +1,368 @@
+mod components;
+
+use core::fmt;
+use core::hash::Hasher;
+
+pub use components::*;
+
+use super::constants::*;
+use crate::common::CheckedPathError;
+use crate::no_std_compat::*;
+use crate::{private, Component, Encoding, Path, PathBuf};
+
+/// Represents a Plan 9 [`Path`]
+pub type Plan9Path = Path<Plan9Encoding>;
+
+/// Represents a Plan 9 [`PathBuf`]
+pub type Plan9PathBuf = PathBuf<Plan9Encoding>;
+
+/// Represents a Plan 9 [`Encoding`] for paths within a per-process namespace, as walked by 9P
+/// clients and servers.
+///
+/// Components follow the same rules as [`UnixEncoding`](crate::UnixEncoding), with the addition
+/// of kernel device roots such as `#c` (the console device) or `#I0` (the first IP stack). A
+/// device root is a single prefix component and is only recognized at the start of a path, so
+/// `a/#c` is a relative path with the normal component `#c`.
+///
+/// A rooted path names the root of the namespace rather than that of a device, so pushing `/cons`
+/// onto `#c` replaces it. Rebuilding a path like `#c/cons` from its components therefore needs
+/// the device root and the root directory pushed together, as [`Path::normalize`] does.
+///
+/// Because binds and mounts can place any tree beneath any directory, the parent of a directory
+/// is only known to the file server. The encoding never assumes `..` cancels out the preceding
+/// component, so it is kept as an element to walk by [`Plan9Path::walk_names`]. Lexical methods
+/// like [`Path::normalize`] still resolve it and should only be used where no binds are involved.
+///
+/// # Examples
+///
+/// ```
+/// use typed_path::Plan9Path;
+///
+/// let path = Plan9Path::new("#I0/tcp/clone");
+/// assert_eq!(path.device_root(), Some(b"#I0".as_slice()));
+/// assert_eq!(path.walk_names(), [b"tcp".as_slice(), b"clone".as_slice()]);
+///
+/// // Rooted paths replace the path when joined, while a device root is only recognized at the
+/// // start of the whole path
+/// assert_eq!(path.join("/dev/cons").as_bytes(), b"/dev/cons");
+/// assert_eq!(path.join("#c/cons").as_bytes(), b"#I0/tcp/clone/#c/cons");
+/// ```
+#[derive(Copy, Clone)]
+pub struct Plan9Encoding;
+
+impl private::Sealed for Plan9Encoding {}
+
+impl<'a> Encoding<'a> for Plan9Encoding {
+    type Components = Plan9Components<'a>;
+
+    const NAME: &'static str = "plan9";
+    const SEPARATOR: char = SEPARATOR;
+    const ALT_SEPARATORS: &'static [char] = &[];
+    const CASE_SENSITIVE: bool = true;
+    const DISALLOWED_FILENAME_BYTES: &'static [u8] = &DISALLOWED_FILENAME_BYTES;
+
+    fn label() -> &'static str {
+        "plan9"
+    }
+
+    fn components(path: &'a [u8]) -> Self::Components {
+        Plan9Components::new(path)
+    }
+
+    fn hash<H: Hasher>(path: &[u8], h: &mut H) {
+        let mut bytes_hashed = 0;
+
+        // Hash what equality compares, which are the components once normalized by parsing
+        for component in Self::components(path) {
+            let to_hash = component.as_bytes();
+            h.write(to_hash);
+            bytes_hashed += to_hash.len();
+        }
+
+        h.write_usize(bytes_hashed);
+    }
+
+    fn push(current_path: &mut Vec<u8>, path: &[u8]) {
+        if path.is_empty() {
+            return;
+        }
+
+        // Rooted path will replace entirely, otherwise check if we need to add our separator,
+        // and add it if the separator is missing. A device root is only recognized at the start
+        // of the whole path, so pushing `#c` onto a non-empty path adds a normal component
+        //
+        // Otherwise, if our current path is not empty, we will append the provided path
+        // to the end with a separator inbetween
+        if path.starts_with(SEPARATOR_STR.as_bytes()) {
+            current_path.clear();
+        } else if !current_path.is_empty() && !current_path.ends_with(SEPARATOR_STR.as_bytes()) {
+            current_path.push(SEPARATOR as u8);
+        }
+
+        current_path.extend_from_slice(path);
+    }
+
+    fn push_checked(current_path: &mut Vec<u8>, path: &[u8]) -> Result<(), CheckedPathError> {
+        // As we scan through path components, we maintain a count of normal components that
+        // have not been popped off as a result of a parent component. If we ever reach a
+        // parent component without any preceding normal components remaining, this violates
+        // pushing onto our path and represents a path traversal attack.
+        let mut normal_cnt = 0;
+        for component in Plan9Path::new(path).components() {
+            match component {
+                Plan9Component::Prefix(_) => return Err(CheckedPathError::UnexpectedPrefix),
+                Plan9Component::RootDir => return Err(CheckedPathError::UnexpectedRoot),
+                Plan9Component::ParentDir if normal_cnt == 0 => {
+                    return Err(CheckedPathError::PathTraversalAttack)
+                }
+                Plan9Component::ParentDir => normal_cnt -= 1,
+                Plan9Component::Normal(bytes) => {
+                    for b in bytes {
+                        if DISALLOWED_FILENAME_BYTES.contains(b) {
+                            return Err(CheckedPathError::InvalidFilename);
+                        }
+                    }
+                    normal_cnt += 1;
+                }
+                _ => continue,
+            }
+        }
+
+        Self::push(current_path, path);
+        Ok(())
+    }
+}
+
+impl fmt::Debug for Plan9Encoding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Plan9Encoding").finish()
+    }
+}
+
+impl fmt::Display for Plan9Encoding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Plan9Encoding")
+    }
+}
+
+impl<T> Path<T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    /// Returns true if the encoding for the path is Plan 9.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Plan9Path, UnixPath};
+    ///
+    /// assert!(Plan9Path::new("#c/cons").has_plan9_encoding());
+    /// assert!(!UnixPath::new("/some/path").has_plan9_encoding());
+    /// ```
+    pub fn has_plan9_encoding(&self) -> bool {
+        T::label() == Plan9Encoding::label()
+    }
+
+    /// Creates an owned [`PathBuf`] like `self` but using [`Plan9Encoding`].
+    ///
+    /// See [`Path::with_encoding`] for more information.
+    pub fn with_plan9_encoding(&self) -> PathBuf<Plan9Encoding> {
+        self.with_encoding()
+    }
+
+    /// Creates an owned [`PathBuf`] like `self` but using [`Plan9Encoding`], ensuring it is a
+    /// valid Plan 9 path.
+    ///
+    /// See [`Path::with_encoding_checked`] for more information.
+    pub fn with_plan9_encoding_checked(&self) -> Result<PathBuf<Plan9Encoding>, CheckedPathError> {
+        self.with_encoding_checked()
+    }
+}
+
+impl Plan9Path {
+    /// Returns the kernel device root at the start of the path, such as `#c` or `#I0`, or `None`
+    /// if the path does not start with one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::Plan9Path;
+    ///
+    /// assert_eq!(Plan9Path::new("#c/cons").device_root(), Some(b"#c".as_slice()));
+    /// assert_eq!(Plan9Path::new("#s").device_root(), Some(b"#s".as_slice()));
+    /// assert_eq!(Plan9Path::new("/dev/cons").device_root(), None);
+    /// assert_eq!(Plan9Path::new("dev/#c").device_root(), None);
+    /// ```
+    pub fn device_root(&self) -> Option<&[u8]> {
+        prefix_len(self.as_bytes()).map(|len| &self.as_bytes()[..len])
+    }
+
+    /// Returns the names to send in 9P `Twalk` messages to reach this path from the directory it
+    /// starts at, which is the device root, the root of the namespace, or the current directory.
+    ///
+    /// Current directory components are skipped, while parent directory components are kept as
+    /// `..` for the file server to resolve, since binds can make the parent of a directory
+    /// differ from the directory preceding it in the path. A single `Twalk` carries at most
+    /// [`MAX_WALK_ELEMENTS`] names, so longer walks need to be split across messages.
+    ///
+    /// [`MAX_WALK_ELEMENTS`]: crate::constants::plan9::MAX_WALK_ELEMENTS
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::constants::plan9::MAX_WALK_ELEMENTS;
+    /// use typed_path::Plan9Path;
+    ///
+    /// let path = Plan9Path::new("/n/remote/./usr/../lib");
+    /// assert_eq!(
+    ///     path.walk_names(),
+    ///     [
+    ///         b"n".as_slice(),
+    ///         b"remote".as_slice(),
+    ///         b"usr".as_slice(),
+    ///         b"..".as_slice(),
+    ///         b"lib".as_slice(),
+    ///     ],
+    /// );
+    ///
+    /// for names in path.walk_names().chunks(MAX_WALK_ELEMENTS) {
+    ///     assert!(names.len() <= MAX_WALK_ELEMENTS);
+    /// }
+    /// ```
+    pub fn walk_names(&self) -> Vec<&[u8]> {
+        self.components()
+            .filter(|c| c.is_normal() || c.is_parent())
+            .map(|c| c.as_bytes())
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn push_should_replace_current_path_with_provided_path_if_provided_path_is_absolute() {
+        let mut current_path = vec![];
+        Plan9Encoding::push(&mut current_path, b"#c/cons");
+        assert_eq!(current_path, b"#c/cons");
+
+        let mut current_path = b"#I0/tcp".to_vec();
+        Plan9Encoding::push(&mut current_path, b"/dev/cons");
+        assert_eq!(current_path, b"/dev/cons");
+    }
+
+    #[test]
+    fn push_should_only_treat_a_device_root_as_such_at_the_start_of_the_path() {
+        let mut current_path = b"/dev".to_vec();
+        Plan9Encoding::push(&mut current_path, b"#s");
+        assert_eq!(current_path, b"/dev/#s");
+
+        let mut current_path = b"#c".to_vec();
+        Plan9Encoding::push(&mut current_path, b"#s/x");
+        assert_eq!(current_path, b"#c/#s/x");
+
+        let mut current_path = b"a/".to_vec();
+        Plan9Encoding::push(&mut current_path, b"#b");
+        assert_eq!(current_path, b"a/#b");
+    }
+
+    #[test]
+    fn components_should_collect_back_into_the_same_path() {
+        for path in [
+            "/dev/#x/y",
+            "a/#b",
+            "#c",
+            "/n/#I0/tcp/#x",
+            "./#c",
+            "../#c/..",
+            "a/../#b",
+        ] {
+            let path = Plan9Path::new(path);
+            assert_eq!(
+                path.components().collect::<Plan9PathBuf>(),
+                path,
+                "{:?}",
+                path
+            );
+        }
+    }
+
+    #[test]
+    fn normalize_should_keep_device_roots_and_components_starting_with_a_device_marker() {
+        let check = |path: &str, expected: &str| {
+            assert_eq!(
+                Plan9Path::new(path).normalize().as_bytes(),
+                expected.as_bytes(),
+                "{:?}",
+                path
+            );
+        };
+
+        check("/dev/#x/y", "/dev/#x/y");
+        check("a/#b", "a/#b");
+        check("/dev/./#x/../#y", "/dev/#y");
+        check("#c/cons", "#c/cons");
+        check("#c/./cons/..", "#c/");
+        check("#c", "#c");
+        check("./#c", "./#c");
+        check("a/../#b/c", "./#b/c");
+    }
+
+    #[test]
+    fn push_should_append_path_to_current_path_with_a_separator_if_provided_path_is_relative() {
+        let mut current_path = vec![];
+        Plan9Encoding::push(&mut current_path, b"abc");
+        assert_eq!(current_path, b"abc");
+
+        let mut current_path = b"#I0".to_vec();
+        Plan9Encoding::push(&mut current_path, b"tcp");
+        assert_eq!(current_path, b"#I0/tcp");
+
+        let mut current_path = b"/n/".to_vec();
+        Plan9Encoding::push(&mut current_path, b"remote/#c");
+        assert_eq!(current_path, b"/n/remote/#c");
+    }
+
+    #[test]
+    fn push_checked_should_fail_if_providing_a_device_root_or_escaping_path() {
+        let mut current_path = b"#I0/tcp".to_vec();
+        assert_eq!(
+            Plan9Encoding::push_checked(&mut current_path, b"#c"),
+            Err(CheckedPathError::UnexpectedPrefix)
+        );
+        assert_eq!(
+            Plan9Encoding::push_checked(&mut current_path, b"/abc"),
+            Err(CheckedPathError::UnexpectedRoot)
+        );
+        assert_eq!(
+            Plan9Encoding::push_checked(&mut current_path, b"abc/../.."),
+            Err(CheckedPathError::PathTraversalAttack)
+        );
+        assert_eq!(
+            Plan9Encoding::push_checked(&mut current_path, b"inva\x7flid"),
+            Err(CheckedPathError::InvalidFilename)
+        );
+        assert_eq!(current_path, b"#I0/tcp");
+
+        assert_eq!(
+            Plan9Encoding::push_checked(&mut current_path, b"0/ctl"),
+            Ok(())
+        );
+        assert_eq!(current_path, b"#I0/tcp/0/ctl");
+    }
+
+    #[test]
+    fn walk_names_should_keep_parent_dirs_and_skip_roots() {
+        assert_eq!(
+            Plan9Path::new("#I0/./tcp/../udp/").walk_names(),
+            [b"tcp".as_slice(), b"..".as_slice(), b"udp".as_slice()]
+        );
+        assert_eq!(
+            Plan9Path::new("../lib").walk_names(),
+            [b"..".as_slice(), b"lib".as_slice()]
+        );
+        assert!(Plan9Path::new("/").walk_names().is_empty());
+        assert!(Plan9Path::new("#c").walk_names().is_empty());
+    }
+}
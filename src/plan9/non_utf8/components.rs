@@ -0,0 +1,246 @@
+mod component;
+
+use core::{cmp, fmt, iter};
+
+pub use component::*;
+
+use crate::common::remaining_path;
+use crate::plan9::constants::DEVICE_MARKER;
+use crate::unix::UnixComponents;
+use crate::{private, Components, Encoding, Path};
+
+/// Iterator over the [`Plan9Component`]s of a Plan 9 path.
+///
+/// A kernel device root such as `#c` is yielded first as a single [`Plan9Component::Prefix`],
+/// followed by the components of the rest of the path using the same rules as
+/// [`UnixEncoding`](crate::UnixEncoding). Unlike `std::path`, a path is absolute both when it
+/// starts with `/` and when it starts with a device root.
+#[derive(Clone)]
+pub struct Plan9Components<'a> {
+    path: &'a [u8],
+
+    /// Prefix that has yet to be yielded, which always starts the remaining path
+    prefix: Option<&'a [u8]>,
+    inner: UnixComponents<'a>,
+}
+
+impl<'a> Plan9Components<'a> {
+    pub(crate) fn new(path: &'a [u8]) -> Self {
+        let (prefix, rest) = match prefix_len(path) {
+            Some(len) => (Some(&path[..len]), &path[len..]),
+            None => (None, path),
+        };
+
+        Self {
+            path,
+            prefix,
+            inner: UnixComponents::new(rest),
+        }
+    }
+
+    /// Extracts a slice corresponding to the portion of the path remaining for iteration.
+    ///
+    /// As with `std::path::Components::as_path`, trailing separators and current directory
+    /// components that will not be yielded are left out.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Path, Plan9Encoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// let mut components = Path::<Plan9Encoding>::new("#I0/tcp/clone").components();
+    /// components.next();
+    /// components.next();
+    ///
+    /// assert_eq!(Path::<Plan9Encoding>::new("tcp/clone"), components.as_path());
+    /// ```
+    pub fn as_path<T>(&self) -> &'a Path<T>
+    where
+        T: for<'enc> Encoding<'enc>,
+    {
+        Path::new(remaining_path(self))
+    }
+}
+
+/// Returns the length of the device root at the start of `path`, if it has one.
+///
+/// A device root is `#` followed by the character naming the kernel device and an optional
+/// specifier, such as `#c` or `#I0`, lasting up to the next separator.
+pub(crate) fn prefix_len(path: &[u8]) -> Option<usize> {
+    if path.first() != Some(&DEVICE_MARKER) {
+        return None;
+    }
+
+    let len = path.iter().position(|b| *b == b'/').unwrap_or(path.len());
+    if len > 1 {
+        Some(len)
+    } else {
+        None
+    }
+}
+
+impl private::Sealed for Plan9Components<'_> {}
+
+impl<'a> Components<'a> for Plan9Components<'a> {
+    type Component = Plan9Component<'a>;
+
+    fn as_bytes(&self) -> &'a [u8] {
+        let rest = self.inner.as_bytes();
+        match self.prefix {
+            // Nothing has been taken from the front of the path part while the prefix remains
+            Some(prefix) => &self.path[..prefix.len() + rest.len()],
+            None => rest,
+        }
+    }
+
+    /// Returns true if the path starts with a device root or the root of the namespace
+    fn is_absolute(&self) -> bool {
+        self.has_root()
+    }
+
+    fn has_root(&self) -> bool {
+        self.prefix.is_some() || self.inner.has_root()
+    }
+}
+
+impl AsRef<[u8]> for Plan9Components<'_> {
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl<T> AsRef<Path<T>> for Plan9Components<'_>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    #[inline]
+    fn as_ref(&self) -> &Path<T> {
+        Path::new(self.as_bytes())
+    }
+}
+
+impl<'a> fmt::Debug for Plan9Components<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        struct DebugHelper<'a>(Plan9Components<'a>);
+
+        impl<'a> fmt::Debug for DebugHelper<'a> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.debug_list().entries(self.0.clone()).finish()
+            }
+        }
+
+        f.debug_tuple("Plan9Components")
+            .field(&DebugHelper(self.clone()))
+            .finish()
+    }
+}
+
+impl<'a> Iterator for Plan9Components<'a> {
+    type Item = <Self as Components<'a>>::Component;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.prefix.take() {
+            Some(prefix) => Some(Plan9Component::Prefix(prefix)),
+            None => self.inner.next().map(Plan9Component::from),
+        }
+    }
+}
+
+impl<'a> DoubleEndedIterator for Plan9Components<'a> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        match self.inner.next_back() {
+            Some(component) => Some(Plan9Component::from(component)),
+            None => self.prefix.take().map(Plan9Component::Prefix),
+        }
+    }
+}
+
+impl<'a> iter::FusedIterator for Plan9Components<'a> {}
+
+impl<'a> cmp::PartialEq for Plan9Components<'a> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.clone().eq(other.clone())
+    }
+}
+
+impl<'a> cmp::Eq for Plan9Components<'a> {}
+
+impl<'a> cmp::PartialOrd for Plan9Components<'a> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<'a> cmp::Ord for Plan9Components<'a> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> cmp::Ordering {
+        self.clone().cmp(other.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::no_std_compat::*;
+
+    #[test]
+    fn prefix_len_should_only_match_a_named_device() {
+        assert_eq!(prefix_len(b"#c/cons"), Some(2));
+        assert_eq!(prefix_len(b"#I0/tcp"), Some(3));
+        assert_eq!(prefix_len("#\u{3b1}".as_bytes()), Some(3));
+        assert_eq!(prefix_len(b"#s"), Some(2));
+
+        assert_eq!(prefix_len(b"#/cons"), None);
+        assert_eq!(prefix_len(b"#"), None);
+        assert_eq!(prefix_len(b"/#c"), None);
+        assert_eq!(prefix_len(b"c#"), None);
+        assert_eq!(prefix_len(b""), None);
+    }
+
+    #[test]
+    fn should_yield_prefix_then_path_part_from_either_end() {
+        let path = b"#I0//tcp/./clone/";
+        let expected = [
+            Plan9Component::Prefix(b"#I0"),
+            Plan9Component::RootDir,
+            Plan9Component::Normal(b"tcp"),
+            Plan9Component::Normal(b"clone"),
+        ];
+
+        assert_eq!(Plan9Components::new(path).collect::<Vec<_>>(), expected);
+
+        let mut reversed = Plan9Components::new(path).rev().collect::<Vec<_>>();
+        reversed.reverse();
+        assert_eq!(reversed, expected);
+
+        let mut components = Plan9Components::new(path);
+        assert_eq!(
+            components.next_back(),
+            Some(Plan9Component::Normal(b"clone"))
+        );
+        assert_eq!(components.as_bytes(), b"#I0//tcp");
+        assert_eq!(components.next(), Some(Plan9Component::Prefix(b"#I0")));
+        assert_eq!(components.as_bytes(), b"//tcp");
+    }
+
+    #[test]
+    fn should_treat_paths_without_a_device_like_unix_paths() {
+        assert_eq!(
+            Plan9Components::new(b"./a/../b#c").collect::<Vec<_>>(),
+            [
+                Plan9Component::CurDir,
+                Plan9Component::Normal(b"a"),
+                Plan9Component::ParentDir,
+                Plan9Component::Normal(b"b#c"),
+            ]
+        );
+
+        assert!(Plan9Components::new(b"/a").is_absolute());
+        assert!(Plan9Components::new(b"#c").is_absolute());
+        assert!(!Plan9Components::new(b"a/#c").is_absolute());
+    }
+}
@@ -0,0 +1,296 @@
+use crate::plan9::constants::{CURRENT_DIR, DISALLOWED_FILENAME_BYTES, PARENT_DIR, SEPARATOR_STR};
+use crate::plan9::Plan9Components;
+use crate::unix::UnixComponent;
+use crate::{private, Component, Encoding, ParseError, Path};
+
+/// Byte slice version of [`std::path::Component`] that represents a Plan 9 component
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Plan9Component<'a> {
+    /// The kernel device root at the start of the path, such as `#c` or `#I0`
+    Prefix(&'a [u8]),
+    RootDir,
+    CurDir,
+    ParentDir,
+    Normal(&'a [u8]),
+}
+
+impl private::Sealed for Plan9Component<'_> {}
+
+impl<'a> Plan9Component<'a> {
+    /// Returns path representing this specific component
+    pub fn as_path<T>(&self) -> &Path<T>
+    where
+        T: for<'enc> Encoding<'enc>,
+    {
+        Path::new(self.as_bytes())
+    }
+
+    /// Returns true if represents a kernel device root
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::Plan9Component;
+    /// use std::convert::TryFrom;
+    ///
+    /// let prefix = Plan9Component::try_from("#c").unwrap();
+    /// assert!(prefix.is_prefix());
+    ///
+    /// let root_dir = Plan9Component::try_from("/").unwrap();
+    /// assert!(!root_dir.is_prefix());
+    /// ```
+    pub fn is_prefix(&self) -> bool {
+        matches!(self, Self::Prefix(_))
+    }
+}
+
+impl<'a> Component<'a> for Plan9Component<'a> {
+    /// Extracts the underlying [`[u8]`] slice
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Component, Plan9Path};
+    ///
+    /// let path = Plan9Path::new(b"#c/tmp/foo/../bar.txt");
+    /// let components: Vec<_> = path.components().map(|comp| comp.as_bytes()).collect();
+    /// assert_eq!(&components, &[
+    ///     b"#c".as_slice(),
+    ///     b"/".as_slice(),
+    ///     b"tmp".as_slice(),
+    ///     b"foo".as_slice(),
+    ///     b"..".as_slice(),
+    ///     b"bar.txt".as_slice(),
+    /// ]);
+    /// ```
+    fn as_bytes(&self) -> &'a [u8] {
+        match self {
+            Self::Prefix(prefix) => prefix,
+            Self::RootDir => SEPARATOR_STR.as_bytes(),
+            Self::CurDir => CURRENT_DIR,
+            Self::ParentDir => PARENT_DIR,
+            Self::Normal(path) => path,
+        }
+    }
+
+    /// Returns true if is the root dir component or a kernel device root, which always points at
+    /// the root of the device's file tree
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Component, Plan9Component};
+    /// use std::convert::TryFrom;
+    ///
+    /// let root_dir = Plan9Component::try_from(b"/").unwrap();
+    /// assert!(root_dir.is_root());
+    ///
+    /// let prefix = Plan9Component::try_from(b"#I0").unwrap();
+    /// assert!(prefix.is_root());
+    ///
+    /// let normal = Plan9Component::try_from(b"file.txt").unwrap();
+    /// assert!(!normal.is_root());
+    /// ```
+    fn is_root(&self) -> bool {
+        matches!(self, Self::Prefix(_) | Self::RootDir)
+    }
+
+    /// Returns true if is a normal component
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Component, Plan9Component};
+    /// use std::convert::TryFrom;
+    ///
+    /// let normal = Plan9Component::try_from(b"file.txt").unwrap();
+    /// assert!(normal.is_normal());
+    ///
+    /// let root_dir = Plan9Component::try_from(b"/").unwrap();
+    /// assert!(!root_dir.is_normal());
+    /// ```
+    fn is_normal(&self) -> bool {
+        matches!(self, Self::Normal(_))
+    }
+
+    /// Returns true if is a parent directory component
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Component, Plan9Component};
+    /// use std::convert::TryFrom;
+    ///
+    /// let parent = Plan9Component::try_from("..").unwrap();
+    /// assert!(parent.is_parent());
+    ///
+    /// let root_dir = Plan9Component::try_from("/").unwrap();
+    /// assert!(!root_dir.is_parent());
+    /// ```
+    fn is_parent(&self) -> bool {
+        matches!(self, Self::ParentDir)
+    }
+
+    /// Returns true if is the current directory component
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Component, Plan9Component};
+    /// use std::convert::TryFrom;
+    ///
+    /// let current = Plan9Component::try_from(".").unwrap();
+    /// assert!(current.is_current());
+    ///
+    /// let root_dir = Plan9Component::try_from("/").unwrap();
+    /// assert!(!root_dir.is_current());
+    /// ```
+    fn is_current(&self) -> bool {
+        matches!(self, Self::CurDir)
+    }
+
+    /// Returns true if this component is valid.
+    ///
+    /// A component can only be invalid if it represents a normal component with bytes that are
+    /// disallowed by the encoding.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Component, Plan9Component};
+    ///
+    /// assert!(Plan9Component::Prefix(b"#c").is_valid());
+    /// assert!(Plan9Component::RootDir.is_valid());
+    /// assert!(Plan9Component::ParentDir.is_valid());
+    /// assert!(Plan9Component::CurDir.is_valid());
+    /// assert!(Plan9Component::Normal(b"abc").is_valid());
+    /// assert!(!Plan9Component::Normal(b"\0").is_valid());
+    /// ```
+    fn is_valid(&self) -> bool {
+        match self {
+            Self::Prefix(_) | Self::RootDir | Self::ParentDir | Self::CurDir => true,
+            Self::Normal(bytes) => !bytes.iter().any(|b| DISALLOWED_FILENAME_BYTES.contains(b)),
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.as_bytes().len()
+    }
+
+    /// Returns the root directory component.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Component, Plan9Component};
+    ///
+    /// assert_eq!(Plan9Component::root(), Plan9Component::RootDir);
+    /// ```
+    fn root() -> Self {
+        Self::RootDir
+    }
+
+    /// Returns the parent directory component.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Component, Plan9Component};
+    ///
+    /// assert_eq!(Plan9Component::parent(), Plan9Component::ParentDir);
+    /// ```
+    fn parent() -> Self {
+        Self::ParentDir
+    }
+
+    /// Returns the current directory component.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Component, Plan9Component};
+    ///
+    /// assert_eq!(Plan9Component::current(), Plan9Component::CurDir);
+    /// ```
+    fn current() -> Self {
+        Self::CurDir
+    }
+}
+
+impl<'a> From<UnixComponent<'a>> for Plan9Component<'a> {
+    fn from(component: UnixComponent<'a>) -> Self {
+        match component {
+            UnixComponent::RootDir => Self::RootDir,
+            UnixComponent::CurDir => Self::CurDir,
+            UnixComponent::ParentDir => Self::ParentDir,
+            UnixComponent::Normal(bytes) => Self::Normal(bytes),
+        }
+    }
+}
+
+impl AsRef<[u8]> for Plan9Component<'_> {
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl<T> AsRef<Path<T>> for Plan9Component<'_>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    #[inline]
+    fn as_ref(&self) -> &Path<T> {
+        Path::new(self.as_bytes())
+    }
+}
+
+impl<'a> TryFrom<&'a [u8]> for Plan9Component<'a> {
+    type Error = ParseError;
+
+    /// Parses the byte slice into a [`Plan9Component`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::Plan9Component;
+    /// use std::convert::TryFrom;
+    ///
+    /// // Supports parsing standard Plan 9 path components
+    /// assert_eq!(Plan9Component::try_from(b"#c"), Ok(Plan9Component::Prefix(b"#c")));
+    /// assert_eq!(Plan9Component::try_from(b"/"), Ok(Plan9Component::RootDir));
+    /// assert_eq!(Plan9Component::try_from(b"."), Ok(Plan9Component::CurDir));
+    /// assert_eq!(Plan9Component::try_from(b".."), Ok(Plan9Component::ParentDir));
+    /// assert_eq!(Plan9Component::try_from(b"file.txt"), Ok(Plan9Component::Normal(b"file.txt")));
+    /// assert_eq!(Plan9Component::try_from(b"dir/"), Ok(Plan9Component::Normal(b"dir")));
+    ///
+    /// // Parsing more than one component will fail
+    /// assert!(Plan9Component::try_from(b"#c/cons").is_err());
+    /// ```
+    fn try_from(path: &'a [u8]) -> Result<Self, Self::Error> {
+        let mut components = Plan9Components::new(path);
+
+        let component = components.next().ok_or("no component found")?;
+        if components.next().is_some() {
+            return Err("found more than one component");
+        }
+
+        Ok(component)
+    }
+}
+
+impl<'a, const N: usize> TryFrom<&'a [u8; N]> for Plan9Component<'a> {
+    type Error = ParseError;
+
+    fn try_from(path: &'a [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from(path.as_slice())
+    }
+}
+
+impl<'a> TryFrom<&'a str> for Plan9Component<'a> {
+    type Error = ParseError;
+
+    fn try_from(path: &'a str) -> Result<Self, Self::Error> {
+        Self::try_from(path.as_bytes())
+    }
+}
@@ -0,0 +1,183 @@
+mod components;
+
+use core::fmt;
+use core::hash::Hasher;
+
+pub use components::*;
+
+use crate::common::CheckedPathError;
+use crate::no_std_compat::*;
+use crate::{
+    private, Encoding, Plan9Encoding, Plan9Path, Utf8Component, Utf8Encoding, Utf8Path, Utf8PathBuf,
+};
+
+/// Represents a Plan 9 [`Utf8Path`]
+pub type Utf8Plan9Path = Utf8Path<Utf8Plan9Encoding>;
+
+/// Represents a Plan 9 [`Utf8PathBuf`]
+pub type Utf8Plan9PathBuf = Utf8PathBuf<Utf8Plan9Encoding>;
+
+/// Represents a Plan 9 [`Utf8Encoding`]
+///
+/// See [`Plan9Encoding`] for more details.
+#[derive(Copy, Clone)]
+pub struct Utf8Plan9Encoding;
+
+impl private::Sealed for Utf8Plan9Encoding {}
+
+impl<'a> Utf8Encoding<'a> for Utf8Plan9Encoding {
+    type Components = Utf8Plan9Components<'a>;
+
+    const NAME: &'static str = <Plan9Encoding as Encoding<'a>>::NAME;
+    const SEPARATOR: char = <Plan9Encoding as Encoding<'a>>::SEPARATOR;
+    const ALT_SEPARATORS: &'static [char] = <Plan9Encoding as Encoding<'a>>::ALT_SEPARATORS;
+    const CASE_SENSITIVE: bool = <Plan9Encoding as Encoding<'a>>::CASE_SENSITIVE;
+    const DISALLOWED_FILENAME_BYTES: &'static [u8] =
+        <Plan9Encoding as Encoding<'a>>::DISALLOWED_FILENAME_BYTES;
+
+    fn label() -> &'static str {
+        "plan9"
+    }
+
+    fn components(path: &'a str) -> Self::Components {
+        Utf8Plan9Components::new(path)
+    }
+
+    fn hash<H: Hasher>(path: &str, h: &mut H) {
+        Plan9Encoding::hash(path.as_bytes(), h);
+    }
+
+    fn push(current_path: &mut String, path: &str) {
+        unsafe {
+            Plan9Encoding::push(current_path.as_mut_vec(), path.as_bytes());
+        }
+    }
+
+    fn push_checked(current_path: &mut String, path: &str) -> Result<(), CheckedPathError> {
+        unsafe { Plan9Encoding::push_checked(current_path.as_mut_vec(), path.as_bytes()) }
+    }
+}
+
+impl fmt::Debug for Utf8Plan9Encoding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Utf8Plan9Encoding").finish()
+    }
+}
+
+impl fmt::Display for Utf8Plan9Encoding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Utf8Plan9Encoding")
+    }
+}
+
+impl<T> Utf8Path<T>
+where
+    T: for<'enc> Utf8Encoding<'enc>,
+{
+    /// Returns true if the encoding for the path is Plan 9.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Utf8Plan9Path, Utf8UnixPath};
+    ///
+    /// assert!(Utf8Plan9Path::new("#c/cons").has_plan9_encoding());
+    /// assert!(!Utf8UnixPath::new("/some/path").has_plan9_encoding());
+    /// ```
+    pub fn has_plan9_encoding(&self) -> bool {
+        T::label() == Utf8Plan9Encoding::label()
+    }
+
+    /// Creates an owned [`Utf8PathBuf`] like `self` but using [`Utf8Plan9Encoding`].
+    ///
+    /// See [`Utf8Path::with_encoding`] for more information.
+    pub fn with_plan9_encoding(&self) -> Utf8PathBuf<Utf8Plan9Encoding> {
+        self.with_encoding()
+    }
+
+    /// Creates an owned [`Utf8PathBuf`] like `self` but using [`Utf8Plan9Encoding`], ensuring it
+    /// is a valid Plan 9 path.
+    ///
+    /// See [`Utf8Path::with_encoding_checked`] for more information.
+    pub fn with_plan9_encoding_checked(
+        &self,
+    ) -> Result<Utf8PathBuf<Utf8Plan9Encoding>, CheckedPathError> {
+        self.with_encoding_checked()
+    }
+}
+
+impl Utf8Plan9Path {
+    /// Returns the kernel device root at the start of the path, such as `#c` or `#I0`, or `None`
+    /// if the path does not start with one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::Utf8Plan9Path;
+    ///
+    /// assert_eq!(Utf8Plan9Path::new("#c/cons").device_root(), Some("#c"));
+    /// assert_eq!(Utf8Plan9Path::new("/dev/cons").device_root(), None);
+    /// ```
+    pub fn device_root(&self) -> Option<&str> {
+        let len = Plan9Path::new(self.as_str()).device_root()?.len();
+        Some(&self.as_str()[..len])
+    }
+
+    /// Returns the names to send in 9P `Twalk` messages to reach this path from the directory it
+    /// starts at.
+    ///
+    /// See [`Plan9Path::walk_names`] for more details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::Utf8Plan9Path;
+    ///
+    /// let path = Utf8Plan9Path::new("#I0/./tcp/../udp");
+    /// assert_eq!(path.walk_names(), ["tcp", "..", "udp"]);
+    /// ```
+    pub fn walk_names(&self) -> Vec<&str> {
+        self.components()
+            .filter(|c| c.is_normal() || c.is_parent())
+            .map(|c| c.as_str())
+            .collect()
+    }
+}
+
+impl_cmp_utf8_bytes!(Utf8Plan9Encoding, Plan9Encoding);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn push_should_follow_the_rules_of_the_non_utf8_encoding() {
+        let mut current_path = String::from("#I0/tcp");
+        Utf8Plan9Encoding::push(&mut current_path, "clone");
+        assert_eq!(current_path, "#I0/tcp/clone");
+
+        Utf8Plan9Encoding::push(&mut current_path, "/dev/cons");
+        assert_eq!(current_path, "/dev/cons");
+
+        Utf8Plan9Encoding::push(&mut current_path, "#c");
+        assert_eq!(current_path, "/dev/cons/#c");
+
+        let mut current_path = String::new();
+        Utf8Plan9Encoding::push(&mut current_path, "#c");
+        assert_eq!(current_path, "#c");
+    }
+
+    #[test]
+    fn push_checked_should_fail_if_providing_a_device_root_or_root() {
+        let mut current_path = String::from("#I0");
+        assert_eq!(
+            Utf8Plan9Encoding::push_checked(&mut current_path, "#c"),
+            Err(CheckedPathError::UnexpectedPrefix)
+        );
+        assert_eq!(
+            Utf8Plan9Encoding::push_checked(&mut current_path, "/abc"),
+            Err(CheckedPathError::UnexpectedRoot)
+        );
+        assert_eq!(current_path, "#I0");
+    }
+}
@@ -0,0 +1,374 @@
+use core::fmt;
+use core::str::Utf8Error;
+
+use crate::plan9::constants::{
+    CURRENT_DIR_STR, DISALLOWED_FILENAME_CHARS, PARENT_DIR_STR, SEPARATOR_STR,
+};
+use crate::plan9::{Plan9Component, Utf8Plan9Components};
+use crate::{private, ParseError, Utf8Component, Utf8Encoding, Utf8Path};
+
+/// `str` slice version of [`std::path::Component`] that represents a Plan 9 component
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Utf8Plan9Component<'a> {
+    /// The kernel device root at the start of the path, such as `#c` or `#I0`
+    Prefix(&'a str),
+    RootDir,
+    CurDir,
+    ParentDir,
+    Normal(&'a str),
+}
+
+impl<'a> Utf8Plan9Component<'a> {
+    /// Converts a non-UTF-8 [`Plan9Component`] to a UTF-8 [`Utf8Plan9Component`]  by checking
+    /// that the component contains valid UTF-8.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if the component is not UTF-8 with a description as to why the
+    /// provided component is not UTF-8.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use typed_path::{Utf8Component, Plan9Component, Utf8Plan9Component};
+    ///
+    /// // some bytes, in a vector
+    /// let component = Plan9Component::Normal(&[240, 159, 146, 150]);
+    ///
+    /// // We know these bytes are valid, so just use `unwrap()`.
+    /// let utf8_component = Utf8Plan9Component::from_utf8(&component).unwrap();
+    ///
+    /// assert_eq!("💖", utf8_component.as_str());
+    /// ```
+    ///
+    /// Incorrect bytes:
+    ///
+    /// ```
+    /// use typed_path::{Plan9Component, Utf8Plan9Component};
+    ///
+    /// // some invalid bytes, in a vector
+    /// let component = Plan9Component::Normal(&[0, 159, 146, 150]);
+    ///
+    /// assert!(Utf8Plan9Component::from_utf8(&component).is_err());
+    /// ```
+    ///
+    /// See the docs for [`Utf8Error`] for more details on the kinds of
+    /// errors that can be returned.
+    pub fn from_utf8(component: &Plan9Component<'a>) -> Result<Self, Utf8Error> {
+        Ok(match component {
+            Plan9Component::Prefix(x) => Self::Prefix(core::str::from_utf8(x)?),
+            Plan9Component::RootDir => Self::RootDir,
+            Plan9Component::ParentDir => Self::ParentDir,
+            Plan9Component::CurDir => Self::CurDir,
+            Plan9Component::Normal(x) => Self::Normal(core::str::from_utf8(x)?),
+        })
+    }
+
+    /// Converts a non-UTF-8 [`Plan9Component`] to a UTF-8 [`Utf8Plan9Component`] without
+    /// checking that the string contains valid UTF-8.
+    ///
+    /// See the safe version, [`from_utf8`], for more information.
+    ///
+    /// [`from_utf8`]: Utf8Plan9Component::from_utf8
+    ///
+    /// # Safety
+    ///
+    /// The bytes passed in must be valid UTF-8.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use typed_path::{Utf8Component, Plan9Component, Utf8Plan9Component};
+    ///
+    /// // some bytes, in a vector
+    /// let component = Plan9Component::Normal(&[240, 159, 146, 150]);
+    ///
+    /// let utf8_component = unsafe {
+    ///     Utf8Plan9Component::from_utf8_unchecked(&component)
+    /// };
+    ///
+    /// assert_eq!("💖", utf8_component.as_str());
+    /// ```
+    pub unsafe fn from_utf8_unchecked(component: &Plan9Component<'a>) -> Self {
+        match component {
+            Plan9Component::Prefix(x) => Self::Prefix(core::str::from_utf8_unchecked(x)),
+            Plan9Component::RootDir => Self::RootDir,
+            Plan9Component::ParentDir => Self::ParentDir,
+            Plan9Component::CurDir => Self::CurDir,
+            Plan9Component::Normal(x) => Self::Normal(core::str::from_utf8_unchecked(x)),
+        }
+    }
+}
+
+impl private::Sealed for Utf8Plan9Component<'_> {}
+
+impl<'a> Utf8Plan9Component<'a> {
+    /// Returns path representing this specific component
+    pub fn as_path<T>(&self) -> &Utf8Path<T>
+    where
+        T: for<'enc> Utf8Encoding<'enc>,
+    {
+        Utf8Path::new(self.as_str())
+    }
+
+    /// Returns true if represents a kernel device root
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::Utf8Plan9Component;
+    /// use std::convert::TryFrom;
+    ///
+    /// let prefix = Utf8Plan9Component::try_from("#c").unwrap();
+    /// assert!(prefix.is_prefix());
+    ///
+    /// let root_dir = Utf8Plan9Component::try_from("/").unwrap();
+    /// assert!(!root_dir.is_prefix());
+    /// ```
+    pub fn is_prefix(&self) -> bool {
+        matches!(self, Self::Prefix(_))
+    }
+}
+
+impl<'a> Utf8Component<'a> for Utf8Plan9Component<'a> {
+    /// Extracts the underlying [`str`] slice
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Utf8Component, Utf8Plan9Path};
+    ///
+    /// let path = Utf8Plan9Path::new("#c/tmp/foo/../bar.txt");
+    /// let components: Vec<_> = path.components().map(|comp| comp.as_str()).collect();
+    /// assert_eq!(&components, &["#c", "/", "tmp", "foo", "..", "bar.txt"]);
+    /// ```
+    fn as_str(&self) -> &'a str {
+        match self {
+            Self::Prefix(prefix) => prefix,
+            Self::RootDir => SEPARATOR_STR,
+            Self::CurDir => CURRENT_DIR_STR,
+            Self::ParentDir => PARENT_DIR_STR,
+            Self::Normal(path) => path,
+        }
+    }
+
+    /// Returns true if is the root dir component or a kernel device root, which always points at
+    /// the root of the device's file tree
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Utf8Component, Utf8Plan9Component};
+    /// use std::convert::TryFrom;
+    ///
+    /// let root_dir = Utf8Plan9Component::try_from("/").unwrap();
+    /// assert!(root_dir.is_root());
+    ///
+    /// let prefix = Utf8Plan9Component::try_from("#I0").unwrap();
+    /// assert!(prefix.is_root());
+    ///
+    /// let normal = Utf8Plan9Component::try_from("file.txt").unwrap();
+    /// assert!(!normal.is_root());
+    /// ```
+    fn is_root(&self) -> bool {
+        matches!(self, Self::Prefix(_) | Self::RootDir)
+    }
+
+    /// Returns true if is a normal component
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Utf8Component, Utf8Plan9Component};
+    /// use std::convert::TryFrom;
+    ///
+    /// let normal = Utf8Plan9Component::try_from("file.txt").unwrap();
+    /// assert!(normal.is_normal());
+    ///
+    /// let root_dir = Utf8Plan9Component::try_from("/").unwrap();
+    /// assert!(!root_dir.is_normal());
+    /// ```
+    fn is_normal(&self) -> bool {
+        matches!(self, Self::Normal(_))
+    }
+
+    /// Returns true if is a parent directory component
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Utf8Component, Utf8Plan9Component};
+    /// use std::convert::TryFrom;
+    ///
+    /// let parent = Utf8Plan9Component::try_from("..").unwrap();
+    /// assert!(parent.is_parent());
+    ///
+    /// let root_dir = Utf8Plan9Component::try_from("/").unwrap();
+    /// assert!(!root_dir.is_parent());
+    /// ```
+    fn is_parent(&self) -> bool {
+        matches!(self, Self::ParentDir)
+    }
+
+    /// Returns true if is the current directory component
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Utf8Component, Utf8Plan9Component};
+    /// use std::convert::TryFrom;
+    ///
+    /// let current = Utf8Plan9Component::try_from(".").unwrap();
+    /// assert!(current.is_current());
+    ///
+    /// let root_dir = Utf8Plan9Component::try_from("/").unwrap();
+    /// assert!(!root_dir.is_current());
+    /// ```
+    fn is_current(&self) -> bool {
+        matches!(self, Self::CurDir)
+    }
+
+    /// Returns true if this component is valid.
+    ///
+    /// A component can only be invalid if it represents a normal component with characters that
+    /// are disallowed by the encoding.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Utf8Component, Utf8Plan9Component};
+    ///
+    /// assert!(Utf8Plan9Component::Prefix("#c").is_valid());
+    /// assert!(Utf8Plan9Component::RootDir.is_valid());
+    /// assert!(Utf8Plan9Component::ParentDir.is_valid());
+    /// assert!(Utf8Plan9Component::CurDir.is_valid());
+    /// assert!(Utf8Plan9Component::Normal("abc").is_valid());
+    /// assert!(!Utf8Plan9Component::Normal("\0").is_valid());
+    /// ```
+    fn is_valid(&self) -> bool {
+        match self {
+            Self::Prefix(_) | Self::RootDir | Self::ParentDir | Self::CurDir => true,
+            Self::Normal(s) => !s.chars().any(|c| DISALLOWED_FILENAME_CHARS.contains(&c)),
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.as_str().len()
+    }
+
+    /// Returns the root directory component.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Utf8Component, Utf8Plan9Component};
+    ///
+    /// assert_eq!(Utf8Plan9Component::root(), Utf8Plan9Component::RootDir);
+    /// ```
+    fn root() -> Self {
+        Self::RootDir
+    }
+
+    /// Returns the parent directory component.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Utf8Component, Utf8Plan9Component};
+    ///
+    /// assert_eq!(Utf8Plan9Component::parent(), Utf8Plan9Component::ParentDir);
+    /// ```
+    fn parent() -> Self {
+        Self::ParentDir
+    }
+
+    /// Returns the current directory component.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Utf8Component, Utf8Plan9Component};
+    ///
+    /// assert_eq!(Utf8Plan9Component::current(), Utf8Plan9Component::CurDir);
+    /// ```
+    fn current() -> Self {
+        Self::CurDir
+    }
+}
+
+impl fmt::Display for Utf8Plan9Component<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl AsRef<[u8]> for Utf8Plan9Component<'_> {
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        self.as_str().as_bytes()
+    }
+}
+
+impl AsRef<str> for Utf8Plan9Component<'_> {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<T> AsRef<Utf8Path<T>> for Utf8Plan9Component<'_>
+where
+    T: for<'enc> Utf8Encoding<'enc>,
+{
+    #[inline]
+    fn as_ref(&self) -> &Utf8Path<T> {
+        Utf8Path::new(self.as_str())
+    }
+}
+
+impl<'a> TryFrom<Plan9Component<'a>> for Utf8Plan9Component<'a> {
+    type Error = Utf8Error;
+
+    #[inline]
+    fn try_from(component: Plan9Component<'a>) -> Result<Self, Self::Error> {
+        Self::from_utf8(&component)
+    }
+}
+
+impl<'a> TryFrom<&'a str> for Utf8Plan9Component<'a> {
+    type Error = ParseError;
+
+    /// Parses the `str` slice into a [`Utf8Plan9Component`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::Utf8Plan9Component;
+    /// use std::convert::TryFrom;
+    ///
+    /// // Supports parsing standard Plan 9 path components
+    /// assert_eq!(Utf8Plan9Component::try_from("#c"), Ok(Utf8Plan9Component::Prefix("#c")));
+    /// assert_eq!(Utf8Plan9Component::try_from("/"), Ok(Utf8Plan9Component::RootDir));
+    /// assert_eq!(Utf8Plan9Component::try_from("."), Ok(Utf8Plan9Component::CurDir));
+    /// assert_eq!(Utf8Plan9Component::try_from(".."), Ok(Utf8Plan9Component::ParentDir));
+    /// assert_eq!(Utf8Plan9Component::try_from("file.txt"), Ok(Utf8Plan9Component::Normal("file.txt")));
+    /// assert_eq!(Utf8Plan9Component::try_from("dir/"), Ok(Utf8Plan9Component::Normal("dir")));
+    ///
+    /// // Parsing more than one component will fail
+    /// assert!(Utf8Plan9Component::try_from("#c/cons").is_err());
+    /// ```
+    fn try_from(path: &'a str) -> Result<Self, Self::Error> {
+        let mut components = Utf8Plan9Components::new(path);
+
+        let component = components.next().ok_or("no component found")?;
+        if components.next().is_some() {
+            return Err("found more than one component");
+        }
+
+        Ok(component)
+    }
+}
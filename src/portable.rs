@@ -0,0 +1,8 @@
+pub(crate) mod constants;
+mod non_utf8;
+mod separator;
+mod utf8;
+
+pub use non_utf8::*;
+pub use separator::*;
+pub use utf8::*;
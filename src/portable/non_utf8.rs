@@ -0,0 +1,396 @@
+mod components;
+
+use core::fmt;
+use core::hash::Hasher;
+
+pub use components::*;
+
+use super::constants::*;
+use crate::common::CheckedPathError;
+use crate::no_std_compat::*;
+use crate::{private, Components, Encoding, Path, PathBuf, PortableSeparator};
+
+/// Represents a portable [`Path`]
+pub type PortablePath = Path<PortableEncoding>;
+
+/// Represents a portable [`PathBuf`]
+pub type PortablePathBuf = PathBuf<PortableEncoding>;
+
+/// Represents a portable [`Encoding`] that accepts both `/` and `\` as separators when parsing.
+///
+/// Unlike [`WindowsEncoding`](crate::WindowsEncoding), there is no prefix grammar: a path is
+/// either rooted by a leading separator or relative. This makes it a good fit for ingesting paths
+/// from user configuration that may mix both styles. Use [`PortablePath::with_separator`] to
+/// render a path with a single canonical separator.
+///
+/// # Examples
+///
+/// ```
+/// use typed_path::{PortablePath, PortableSeparator};
+///
+/// let path = PortablePath::new(r"assets\textures/stone.png");
+/// assert_eq!(
+///     path.iter().collect::<Vec<_>>(),
+///     [b"assets".as_slice(), b"textures", b"stone.png"],
+/// );
+///
+/// let path = path.with_separator(PortableSeparator::Slash);
+/// assert_eq!(path.as_bytes(), b"assets/textures/stone.png");
+/// ```
+#[derive(Copy, Clone)]
+pub struct PortableEncoding;
+
+impl private::Sealed for PortableEncoding {}
+
+impl<'a> Encoding<'a> for PortableEncoding {
+    type Components = PortableComponents<'a>;
+
+    const NAME: &'static str = "portable";
+    const SEPARATOR: char = SEPARATOR;
+    const ALT_SEPARATORS: &'static [char] = &[ALT_SEPARATOR];
+    const CASE_SENSITIVE: bool = true;
+    const DISALLOWED_FILENAME_BYTES: &'static [u8] = &DISALLOWED_FILENAME_BYTES;
+
+    fn label() -> &'static str {
+        "portable"
+    }
+
+    fn components(path: &'a [u8]) -> Self::Components {
+        PortableComponents::new(path)
+    }
+
+    fn hash<H: Hasher>(path: &[u8], h: &mut H) {
+        let mut component_start = 0;
+        let mut bytes_hashed = 0;
+
+        for i in 0..path.len() {
+            let is_sep = is_separator(path[i]);
+            if is_sep {
+                if i > component_start {
+                    let to_hash = &path[component_start..i];
+                    h.write(to_hash);
+                    bytes_hashed += to_hash.len();
+                }
+
+                // skip over separator and optionally a following CurDir item
+                // since components() would normalize these away.
+                component_start = i + 1;
+
+                let tail = &path[component_start..];
+
+                component_start += match tail {
+                    [b'.'] => 1,
+                    [b'.', sep, ..] if is_separator(*sep) => 1,
+                    _ => 0,
+                };
+            }
+        }
+
+        if component_start < path.len() {
+            let to_hash = &path[component_start..];
+            h.write(to_hash);
+            bytes_hashed += to_hash.len();
+        }
+
+        h.write_usize(bytes_hashed);
+    }
+
+    fn push(current_path: &mut Vec<u8>, path: &[u8]) {
+        if path.is_empty() {
+            return;
+        }
+
+        // Absolute path will replace entirely, otherwise check if we need to add our separator,
+        // and add it if the separator is missing
+        //
+        // Otherwise, if our current path is not empty, we will append the provided path
+        // to the end with a separator inbetween
+        if Self::components(path).is_absolute() {
+            current_path.clear();
+        } else if !current_path.is_empty()
+            && !current_path
+                .last()
+                .map(|b| is_separator(*b))
+                .unwrap_or(false)
+        {
+            current_path.push(SEPARATOR as u8);
+        }
+
+        current_path.extend_from_slice(path);
+    }
+
+    fn push_checked(current_path: &mut Vec<u8>, path: &[u8]) -> Result<(), CheckedPathError> {
+        // As we scan through path components, we maintain a count of normal components that
+        // have not been popped off as a result of a parent component. If we ever reach a
+        // parent component without any preceding normal components remaining, this violates
+        // pushing onto our path and represents a path traversal attack.
+        let mut normal_cnt = 0;
+        for component in PortablePath::new(path).components() {
+            match component {
+                PortableComponent::RootDir => return Err(CheckedPathError::UnexpectedRoot),
+                PortableComponent::ParentDir if normal_cnt == 0 => {
+                    return Err(CheckedPathError::PathTraversalAttack)
+                }
+                PortableComponent::ParentDir => normal_cnt -= 1,
+                PortableComponent::Normal(bytes) => {
+                    for b in bytes {
+                        if DISALLOWED_FILENAME_BYTES.contains(b) {
+                            return Err(CheckedPathError::InvalidFilename);
+                        }
+                    }
+                    normal_cnt += 1;
+                }
+                _ => continue,
+            }
+        }
+
+        Self::push(current_path, path);
+        Ok(())
+    }
+}
+
+impl fmt::Debug for PortableEncoding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PortableEncoding").finish()
+    }
+}
+
+impl fmt::Display for PortableEncoding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "PortableEncoding")
+    }
+}
+
+impl<T> Path<T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    /// Returns true if the encoding for the path is portable.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{PortablePath, UnixPath};
+    ///
+    /// assert!(PortablePath::new(r"\some/path").has_portable_encoding());
+    /// assert!(!UnixPath::new("/some/path").has_portable_encoding());
+    /// ```
+    pub fn has_portable_encoding(&self) -> bool {
+        T::label() == PortableEncoding::label()
+    }
+
+    /// Creates an owned [`PathBuf`] like `self` but using [`PortableEncoding`].
+    ///
+    /// See [`Path::with_encoding`] for more information.
+    pub fn with_portable_encoding(&self) -> PathBuf<PortableEncoding> {
+        self.with_encoding()
+    }
+
+    /// Creates an owned [`PathBuf`] like `self` but using [`PortableEncoding`], ensuring it is a
+    /// valid portable path.
+    ///
+    /// See [`Path::with_encoding_checked`] for more information.
+    pub fn with_portable_encoding_checked(
+        &self,
+    ) -> Result<PathBuf<PortableEncoding>, CheckedPathError> {
+        self.with_encoding_checked()
+    }
+}
+
+impl PortablePath {
+    /// Creates an owned [`PortablePathBuf`] like `self` but with every separator replaced by
+    /// `separator`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{PortablePath, PortableSeparator};
+    ///
+    /// let path = PortablePath::new(r"/some\mixed/path\");
+    /// assert_eq!(
+    ///     path.with_separator(PortableSeparator::Backslash).as_bytes(),
+    ///     br"\some\mixed\path\",
+    /// );
+    /// assert_eq!(
+    ///     path.with_separator(PortableSeparator::Slash).as_bytes(),
+    ///     b"/some/mixed/path/",
+    /// );
+    /// ```
+    pub fn with_separator(&self, separator: PortableSeparator) -> PortablePathBuf {
+        let sep = separator.as_char() as u8;
+        let bytes: Vec<u8> = self
+            .as_bytes()
+            .iter()
+            .map(|b| if is_separator(*b) { sep } else { *b })
+            .collect();
+        PortablePathBuf::from(bytes)
+    }
+}
+
+/// Returns true if `b` is either the primary or the alternate portable separator
+pub(crate) fn is_separator(b: u8) -> bool {
+    b == SEPARATOR as u8 || b == ALT_SEPARATOR as u8
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn push_should_replace_current_path_with_provided_path_if_provided_path_is_absolute() {
+        // Empty current path will just become the provided path
+        let mut current_path = vec![];
+        PortableEncoding::push(&mut current_path, b"/abc");
+        assert_eq!(current_path, b"/abc");
+
+        // Non-empty relative current path will be replaced with the provided path
+        let mut current_path = b"some/path".to_vec();
+        PortableEncoding::push(&mut current_path, b"/abc");
+        assert_eq!(current_path, b"/abc");
+
+        // Non-empty absolute current path will be replaced with the provided path
+        let mut current_path = b"/some/path/".to_vec();
+        PortableEncoding::push(&mut current_path, b"/abc");
+        assert_eq!(current_path, b"/abc");
+    }
+
+    #[test]
+    fn push_should_append_path_to_current_path_with_a_separator_if_provided_path_is_relative() {
+        // Empty current path will just become the provided path
+        let mut current_path = vec![];
+        PortableEncoding::push(&mut current_path, b"abc");
+        assert_eq!(current_path, b"abc");
+
+        // Non-empty current path will have provided path appended
+        let mut current_path = b"some/path".to_vec();
+        PortableEncoding::push(&mut current_path, b"abc");
+        assert_eq!(current_path, b"some/path/abc");
+
+        // Non-empty current path ending in separator will have provided path appended without sep
+        let mut current_path = b"some/path/".to_vec();
+        PortableEncoding::push(&mut current_path, b"abc");
+        assert_eq!(current_path, b"some/path/abc");
+    }
+
+    #[test]
+    fn push_should_not_add_a_separator_if_current_path_ends_with_alt_separator() {
+        let mut current_path = b"some\\path\\".to_vec();
+        PortableEncoding::push(&mut current_path, b"abc");
+        assert_eq!(current_path, b"some\\path\\abc");
+
+        // A leading alternate separator is also a root
+        let mut current_path = b"some/path".to_vec();
+        PortableEncoding::push(&mut current_path, b"\\abc");
+        assert_eq!(current_path, b"\\abc");
+    }
+
+    #[test]
+    fn push_checked_should_fail_if_providing_an_absolute_path() {
+        // Empty current path will fail when pushing an absolute path
+        let mut current_path = vec![];
+        assert_eq!(
+            PortableEncoding::push_checked(&mut current_path, b"/abc"),
+            Err(CheckedPathError::UnexpectedRoot)
+        );
+        assert_eq!(current_path, b"");
+
+        // Non-empty relative current path will fail when pushing an absolute path
+        let mut current_path = b"some/path".to_vec();
+        assert_eq!(
+            PortableEncoding::push_checked(&mut current_path, b"/abc"),
+            Err(CheckedPathError::UnexpectedRoot)
+        );
+        assert_eq!(current_path, b"some/path");
+
+        // Non-empty absolute current path will fail when pushing an absolute path
+        let mut current_path = b"/some/path/".to_vec();
+        assert_eq!(
+            PortableEncoding::push_checked(&mut current_path, b"/abc"),
+            Err(CheckedPathError::UnexpectedRoot)
+        );
+        assert_eq!(current_path, b"/some/path/");
+    }
+
+    #[test]
+    fn push_checked_should_fail_if_providing_a_path_with_disallowed_filename_bytes() {
+        // Empty current path will fail when pushing a path containing disallowed filename bytes
+        let mut current_path = vec![];
+        assert_eq!(
+            PortableEncoding::push_checked(&mut current_path, b"some/inva\0lid/path"),
+            Err(CheckedPathError::InvalidFilename)
+        );
+        assert_eq!(current_path, b"");
+
+        // Non-empty relative current path will fail when pushing a path containing disallowed
+        // filename bytes
+        let mut current_path = b"some/path".to_vec();
+        assert_eq!(
+            PortableEncoding::push_checked(&mut current_path, b"some/inva\0lid/path"),
+            Err(CheckedPathError::InvalidFilename)
+        );
+        assert_eq!(current_path, b"some/path");
+
+        // Non-empty absolute current path will fail when pushing a path containing disallowed
+        // filename bytes
+        let mut current_path = b"/some/path/".to_vec();
+        assert_eq!(
+            PortableEncoding::push_checked(&mut current_path, b"some/inva\0lid/path"),
+            Err(CheckedPathError::InvalidFilename)
+        );
+        assert_eq!(current_path, b"/some/path/");
+    }
+
+    #[test]
+    fn push_checked_should_fail_if_providing_a_path_that_would_escape_the_current_path() {
+        // Empty current path will fail when pushing a path that would escape
+        let mut current_path = vec![];
+        assert_eq!(
+            PortableEncoding::push_checked(&mut current_path, b".."),
+            Err(CheckedPathError::PathTraversalAttack)
+        );
+        assert_eq!(current_path, b"");
+
+        // Non-empty relative current path will fail when pushing a path that would escape
+        let mut current_path = b"some/path".to_vec();
+        assert_eq!(
+            PortableEncoding::push_checked(&mut current_path, b".."),
+            Err(CheckedPathError::PathTraversalAttack)
+        );
+        assert_eq!(current_path, b"some/path");
+
+        // Non-empty absolute current path will fail when pushing a path that would escape
+        let mut current_path = b"/some/path/".to_vec();
+        assert_eq!(
+            PortableEncoding::push_checked(&mut current_path, b".."),
+            Err(CheckedPathError::PathTraversalAttack)
+        );
+        assert_eq!(current_path, b"/some/path/");
+    }
+
+    #[test]
+    fn push_checked_should_append_path_to_current_path_with_a_separator_if_does_not_violate_rules()
+    {
+        // Pushing a path that contains parent dirs, but does not escape the current path,
+        // should succeed
+        let mut current_path = vec![];
+        assert_eq!(
+            PortableEncoding::push_checked(&mut current_path, b"abc/../def/."),
+            Ok(()),
+        );
+        assert_eq!(current_path, b"abc/../def/.");
+
+        let mut current_path = b"some/path".to_vec();
+        assert_eq!(
+            PortableEncoding::push_checked(&mut current_path, b"abc/../def/."),
+            Ok(()),
+        );
+        assert_eq!(current_path, b"some/path/abc/../def/.");
+
+        let mut current_path = b"/some/path/".to_vec();
+        assert_eq!(
+            PortableEncoding::push_checked(&mut current_path, b"abc/../def/."),
+            Ok(()),
+        );
+        assert_eq!(current_path, b"/some/path/abc/../def/.");
+    }
+}
@@ -0,0 +1,146 @@
+mod component;
+mod parser;
+
+use core::{cmp, fmt, iter};
+
+pub use component::*;
+use parser::Parser;
+
+use crate::common::remaining_path;
+use crate::{private, Components, Encoding, Path};
+
+#[derive(Clone)]
+pub struct PortableComponents<'a> {
+    parser: Parser<'a>,
+}
+
+impl<'a> PortableComponents<'a> {
+    pub(crate) fn new(path: &'a [u8]) -> Self {
+        Self {
+            parser: Parser::new(path),
+        }
+    }
+
+    /// Extracts a slice corresponding to the portion of the path remaining for iteration.
+    ///
+    /// As with `std::path::Components::as_path`, trailing separators and current directory
+    /// components that will not be yielded are left out.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Path, PortableEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// let mut components = Path::<PortableEncoding>::new("/tmp/foo/bar.txt").components();
+    /// components.next();
+    /// components.next();
+    ///
+    /// assert_eq!(Path::<PortableEncoding>::new("foo/bar.txt"), components.as_path());
+    /// ```
+    pub fn as_path<T>(&self) -> &'a Path<T>
+    where
+        T: for<'enc> Encoding<'enc>,
+    {
+        Path::new(remaining_path(self))
+    }
+}
+
+impl private::Sealed for PortableComponents<'_> {}
+
+impl<'a> Components<'a> for PortableComponents<'a> {
+    type Component = PortableComponent<'a>;
+
+    fn as_bytes(&self) -> &'a [u8] {
+        self.parser.remaining()
+    }
+
+    fn is_absolute(&self) -> bool {
+        self.has_root()
+    }
+
+    fn has_root(&self) -> bool {
+        // Create a copy of our parser so we don't mutate state
+        let mut parser = self.parser.clone();
+
+        matches!(parser.next_front(), Ok(PortableComponent::RootDir))
+    }
+}
+
+impl AsRef<[u8]> for PortableComponents<'_> {
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl<T> AsRef<Path<T>> for PortableComponents<'_>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    #[inline]
+    fn as_ref(&self) -> &Path<T> {
+        Path::new(self.as_bytes())
+    }
+}
+
+impl<'a> fmt::Debug for PortableComponents<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        struct DebugHelper<'a>(PortableComponents<'a>);
+
+        impl<'a> fmt::Debug for DebugHelper<'a> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.debug_list().entries(self.0.clone()).finish()
+            }
+        }
+
+        f.debug_tuple("WindowsComponents")
+            .field(&DebugHelper(self.clone()))
+            .finish()
+    }
+}
+
+impl<'a> Iterator for PortableComponents<'a> {
+    type Item = <Self as Components<'a>>::Component;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.parser.next_front().ok()
+    }
+}
+
+impl<'a> DoubleEndedIterator for PortableComponents<'a> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.parser.next_back().ok()
+    }
+}
+
+impl<'a> iter::FusedIterator for PortableComponents<'a> {}
+
+impl<'a> cmp::PartialEq for PortableComponents<'a> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        let _self = Self::new(self.parser.remaining());
+        let _other = Self::new(other.parser.remaining());
+
+        _self.eq(_other)
+    }
+}
+
+impl<'a> cmp::Eq for PortableComponents<'a> {}
+
+impl<'a> cmp::PartialOrd for PortableComponents<'a> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<'a> cmp::Ord for PortableComponents<'a> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> cmp::Ordering {
+        let _self = Self::new(self.parser.remaining());
+        let _other = Self::new(other.parser.remaining());
+
+        _self.cmp(_other)
+    }
+}
@@ -0,0 +1,258 @@
+use crate::portable::constants::{
+    CURRENT_DIR, DISALLOWED_FILENAME_BYTES, PARENT_DIR, SEPARATOR_STR,
+};
+use crate::portable::PortableComponents;
+use crate::{private, Component, Encoding, ParseError, Path};
+
+/// Byte slice version of [`std::path::Component`] that represents a portable component
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum PortableComponent<'a> {
+    RootDir,
+    CurDir,
+    ParentDir,
+    Normal(&'a [u8]),
+}
+
+impl private::Sealed for PortableComponent<'_> {}
+
+impl<'a> PortableComponent<'a> {
+    /// Returns path representing this specific component
+    pub fn as_path<T>(&self) -> &Path<T>
+    where
+        T: for<'enc> Encoding<'enc>,
+    {
+        Path::new(self.as_bytes())
+    }
+}
+
+impl<'a> Component<'a> for PortableComponent<'a> {
+    /// Extracts the underlying [`[u8]`] slice
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Component, PortablePath};
+    ///
+    /// let path = PortablePath::new(b"/tmp/foo/../bar.txt");
+    /// let components: Vec<_> = path.components().map(|comp| comp.as_bytes()).collect();
+    /// assert_eq!(&components, &[
+    ///     b"/".as_slice(),
+    ///     b"tmp".as_slice(),
+    ///     b"foo".as_slice(),
+    ///     b"..".as_slice(),
+    ///     b"bar.txt".as_slice(),
+    /// ]);
+    /// ```
+    fn as_bytes(&self) -> &'a [u8] {
+        match self {
+            Self::RootDir => SEPARATOR_STR.as_bytes(),
+            Self::CurDir => CURRENT_DIR,
+            Self::ParentDir => PARENT_DIR,
+            Self::Normal(path) => path,
+        }
+    }
+
+    /// Returns true if is the root dir component
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Component, PortableComponent};
+    /// use std::convert::TryFrom;
+    ///
+    /// let root_dir = PortableComponent::try_from(b"/").unwrap();
+    /// assert!(root_dir.is_root());
+    ///
+    /// let normal = PortableComponent::try_from(b"file.txt").unwrap();
+    /// assert!(!normal.is_root());
+    /// ```
+    fn is_root(&self) -> bool {
+        matches!(self, Self::RootDir)
+    }
+
+    /// Returns true if is a normal component
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Component, PortableComponent};
+    /// use std::convert::TryFrom;
+    ///
+    /// let normal = PortableComponent::try_from(b"file.txt").unwrap();
+    /// assert!(normal.is_normal());
+    ///
+    /// let root_dir = PortableComponent::try_from(b"/").unwrap();
+    /// assert!(!root_dir.is_normal());
+    /// ```
+    fn is_normal(&self) -> bool {
+        matches!(self, Self::Normal(_))
+    }
+
+    /// Returns true if is a parent directory component
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Component, PortableComponent};
+    /// use std::convert::TryFrom;
+    ///
+    /// let parent = PortableComponent::try_from("..").unwrap();
+    /// assert!(parent.is_parent());
+    ///
+    /// let root_dir = PortableComponent::try_from("/").unwrap();
+    /// assert!(!root_dir.is_parent());
+    /// ```
+    fn is_parent(&self) -> bool {
+        matches!(self, Self::ParentDir)
+    }
+
+    /// Returns true if is the current directory component
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Component, PortableComponent};
+    /// use std::convert::TryFrom;
+    ///
+    /// let current = PortableComponent::try_from(".").unwrap();
+    /// assert!(current.is_current());
+    ///
+    /// let root_dir = PortableComponent::try_from("/").unwrap();
+    /// assert!(!root_dir.is_current());
+    /// ```
+    fn is_current(&self) -> bool {
+        matches!(self, Self::CurDir)
+    }
+
+    /// Returns true if this component is valid.
+    ///
+    /// A component can only be invalid if it represents a normal component with bytes that are
+    /// disallowed by the encoding.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Component, PortableComponent};
+    ///
+    /// assert!(PortableComponent::RootDir.is_valid());
+    /// assert!(PortableComponent::ParentDir.is_valid());
+    /// assert!(PortableComponent::CurDir.is_valid());
+    /// assert!(PortableComponent::Normal(b"abc").is_valid());
+    /// assert!(!PortableComponent::Normal(b"\0").is_valid());
+    /// ```
+    fn is_valid(&self) -> bool {
+        match self {
+            Self::RootDir | Self::ParentDir | Self::CurDir => true,
+            Self::Normal(bytes) => !bytes.iter().any(|b| DISALLOWED_FILENAME_BYTES.contains(b)),
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.as_bytes().len()
+    }
+
+    /// Returns the root directory component.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Component, PortableComponent};
+    ///
+    /// assert_eq!(PortableComponent::root(), PortableComponent::RootDir);
+    /// ```
+    fn root() -> Self {
+        Self::RootDir
+    }
+
+    /// Returns the parent directory component.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Component, PortableComponent};
+    ///
+    /// assert_eq!(PortableComponent::parent(), PortableComponent::ParentDir);
+    /// ```
+    fn parent() -> Self {
+        Self::ParentDir
+    }
+
+    /// Returns the current directory component.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Component, PortableComponent};
+    ///
+    /// assert_eq!(PortableComponent::current(), PortableComponent::CurDir);
+    /// ```
+    fn current() -> Self {
+        Self::CurDir
+    }
+}
+
+impl AsRef<[u8]> for PortableComponent<'_> {
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl<T> AsRef<Path<T>> for PortableComponent<'_>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    #[inline]
+    fn as_ref(&self) -> &Path<T> {
+        Path::new(self.as_bytes())
+    }
+}
+
+impl<'a> TryFrom<&'a [u8]> for PortableComponent<'a> {
+    type Error = ParseError;
+
+    /// Parses the byte slice into a [`PortableComponent`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::PortableComponent;
+    /// use std::convert::TryFrom;
+    ///
+    /// // Supports parsing standard portable path components
+    /// assert_eq!(PortableComponent::try_from(b"/"), Ok(PortableComponent::RootDir));
+    /// assert_eq!(PortableComponent::try_from(b"."), Ok(PortableComponent::CurDir));
+    /// assert_eq!(PortableComponent::try_from(b".."), Ok(PortableComponent::ParentDir));
+    /// assert_eq!(PortableComponent::try_from(b"file.txt"), Ok(PortableComponent::Normal(b"file.txt")));
+    /// assert_eq!(PortableComponent::try_from(b"dir/"), Ok(PortableComponent::Normal(b"dir")));
+    ///
+    /// // Parsing more than one component will fail
+    /// assert!(PortableComponent::try_from(b"/file").is_err());
+    /// ```
+    fn try_from(path: &'a [u8]) -> Result<Self, Self::Error> {
+        let mut components = PortableComponents::new(path);
+
+        let component = components.next().ok_or("no component found")?;
+        if components.next().is_some() {
+            return Err("found more than one component");
+        }
+
+        Ok(component)
+    }
+}
+
+impl<'a, const N: usize> TryFrom<&'a [u8; N]> for PortableComponent<'a> {
+    type Error = ParseError;
+
+    fn try_from(path: &'a [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from(path.as_slice())
+    }
+}
+
+impl<'a> TryFrom<&'a str> for PortableComponent<'a> {
+    type Error = ParseError;
+
+    fn try_from(path: &'a str) -> Result<Self, Self::Error> {
+        Self::try_from(path.as_bytes())
+    }
+}
@@ -0,0 +1,39 @@
+use core::fmt;
+
+use super::constants::{ALT_SEPARATOR, SEPARATOR};
+
+/// Canonical separator used when rendering a portable path, see
+/// [`PortablePath::with_separator`](crate::PortablePath::with_separator).
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum PortableSeparator {
+    /// Render using `/`, the primary separator
+    Slash,
+
+    /// Render using `\`, the alternate separator
+    Backslash,
+}
+
+impl PortableSeparator {
+    /// Returns the separator as a [`char`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::PortableSeparator;
+    ///
+    /// assert_eq!(PortableSeparator::Slash.as_char(), '/');
+    /// assert_eq!(PortableSeparator::Backslash.as_char(), '\\');
+    /// ```
+    pub const fn as_char(self) -> char {
+        match self {
+            Self::Slash => SEPARATOR,
+            Self::Backslash => ALT_SEPARATOR,
+        }
+    }
+}
+
+impl fmt::Display for PortableSeparator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_char())
+    }
+}
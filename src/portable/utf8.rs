@@ -0,0 +1,294 @@
+mod components;
+
+use core::fmt;
+use core::hash::Hasher;
+
+pub use components::*;
+
+use crate::common::CheckedPathError;
+use crate::no_std_compat::*;
+use crate::{
+    private, Encoding, PortableEncoding, PortableSeparator, Utf8Encoding, Utf8Path, Utf8PathBuf,
+};
+
+/// Represents a portable [`Utf8Path`]
+pub type Utf8PortablePath = Utf8Path<Utf8PortableEncoding>;
+
+/// Represents a portable [`Utf8PathBuf`]
+pub type Utf8PortablePathBuf = Utf8PathBuf<Utf8PortableEncoding>;
+
+/// Represents a portable [`Utf8Encoding`]
+#[derive(Copy, Clone)]
+pub struct Utf8PortableEncoding;
+
+impl private::Sealed for Utf8PortableEncoding {}
+
+impl<'a> Utf8Encoding<'a> for Utf8PortableEncoding {
+    type Components = Utf8PortableComponents<'a>;
+
+    const NAME: &'static str = <PortableEncoding as Encoding<'a>>::NAME;
+    const SEPARATOR: char = <PortableEncoding as Encoding<'a>>::SEPARATOR;
+    const ALT_SEPARATORS: &'static [char] = <PortableEncoding as Encoding<'a>>::ALT_SEPARATORS;
+    const CASE_SENSITIVE: bool = <PortableEncoding as Encoding<'a>>::CASE_SENSITIVE;
+    const DISALLOWED_FILENAME_BYTES: &'static [u8] =
+        <PortableEncoding as Encoding<'a>>::DISALLOWED_FILENAME_BYTES;
+
+    fn label() -> &'static str {
+        "portable"
+    }
+
+    fn components(path: &'a str) -> Self::Components {
+        Utf8PortableComponents::new(path)
+    }
+
+    fn hash<H: Hasher>(path: &str, h: &mut H) {
+        PortableEncoding::hash(path.as_bytes(), h);
+    }
+
+    fn push(current_path: &mut String, path: &str) {
+        unsafe {
+            PortableEncoding::push(current_path.as_mut_vec(), path.as_bytes());
+        }
+    }
+
+    fn push_checked(current_path: &mut String, path: &str) -> Result<(), CheckedPathError> {
+        unsafe { PortableEncoding::push_checked(current_path.as_mut_vec(), path.as_bytes()) }
+    }
+}
+
+impl fmt::Debug for Utf8PortableEncoding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Utf8PortableEncoding").finish()
+    }
+}
+
+impl fmt::Display for Utf8PortableEncoding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Utf8PortableEncoding")
+    }
+}
+
+impl<T> Utf8Path<T>
+where
+    T: for<'enc> Utf8Encoding<'enc>,
+{
+    /// Returns true if the encoding for the path is portable.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Utf8PortablePath, Utf8UnixPath};
+    ///
+    /// assert!(Utf8PortablePath::new(r"\some/path").has_portable_encoding());
+    /// assert!(!Utf8UnixPath::new("/some/path").has_portable_encoding());
+    /// ```
+    pub fn has_portable_encoding(&self) -> bool {
+        T::label() == Utf8PortableEncoding::label()
+    }
+
+    /// Creates an owned [`Utf8PathBuf`] like `self` but using [`Utf8PortableEncoding`].
+    ///
+    /// See [`Utf8Path::with_encoding`] for more information.
+    pub fn with_portable_encoding(&self) -> Utf8PathBuf<Utf8PortableEncoding> {
+        self.with_encoding()
+    }
+
+    /// Creates an owned [`Utf8PathBuf`] like `self` but using [`Utf8PortableEncoding`], ensuring it
+    /// is a valid portable path.
+    ///
+    /// See [`Utf8Path::with_encoding_checked`] for more information.
+    pub fn with_portable_encoding_checked(
+        &self,
+    ) -> Result<Utf8PathBuf<Utf8PortableEncoding>, CheckedPathError> {
+        self.with_encoding_checked()
+    }
+}
+
+impl Utf8PortablePath {
+    /// Creates an owned [`Utf8PortablePathBuf`] like `self` but with every separator replaced by
+    /// `separator`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{PortableSeparator, Utf8PortablePath};
+    ///
+    /// let path = Utf8PortablePath::new(r"/some\mixed/path\");
+    /// assert_eq!(
+    ///     path.with_separator(PortableSeparator::Backslash).as_str(),
+    ///     r"\some\mixed\path\",
+    /// );
+    /// assert_eq!(
+    ///     path.with_separator(PortableSeparator::Slash).as_str(),
+    ///     "/some/mixed/path/",
+    /// );
+    /// ```
+    pub fn with_separator(&self, separator: PortableSeparator) -> Utf8PortablePathBuf {
+        let sep = separator.as_char();
+        let s: String = self
+            .as_str()
+            .chars()
+            .map(|c| {
+                if Utf8PortableEncoding::is_separator(c) {
+                    sep
+                } else {
+                    c
+                }
+            })
+            .collect();
+        Utf8PortablePathBuf::from(s)
+    }
+}
+
+impl_cmp_utf8_bytes!(Utf8PortableEncoding, PortableEncoding);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn push_should_replace_current_path_with_provided_path_if_provided_path_is_absolute() {
+        // Empty current path will just become the provided path
+        let mut current_path = String::new();
+        Utf8PortableEncoding::push(&mut current_path, "/abc");
+        assert_eq!(current_path, "/abc");
+
+        // Non-empty relative current path will be replaced with the provided path
+        let mut current_path = String::from("some/path");
+        Utf8PortableEncoding::push(&mut current_path, "/abc");
+        assert_eq!(current_path, "/abc");
+
+        // Non-empty absolute current path will be replaced with the provided path
+        let mut current_path = String::from("/some/path/");
+        Utf8PortableEncoding::push(&mut current_path, "/abc");
+        assert_eq!(current_path, "/abc");
+    }
+
+    #[test]
+    fn push_should_append_path_to_current_path_with_a_separator_if_provided_path_is_relative() {
+        // Empty current path will just become the provided path
+        let mut current_path = String::new();
+        Utf8PortableEncoding::push(&mut current_path, "abc");
+        assert_eq!(current_path, "abc");
+
+        // Non-empty current path will have provided path appended
+        let mut current_path = String::from("some/path");
+        Utf8PortableEncoding::push(&mut current_path, "abc");
+        assert_eq!(current_path, "some/path/abc");
+
+        // Non-empty current path ending in separator will have provided path appended without sep
+        let mut current_path = String::from("some/path/");
+        Utf8PortableEncoding::push(&mut current_path, "abc");
+        assert_eq!(current_path, "some/path/abc");
+    }
+
+    #[test]
+    fn push_checked_should_fail_if_providing_an_absolute_path() {
+        // Empty current path will fail when pushing an absolute path
+        let mut current_path = String::new();
+        assert_eq!(
+            Utf8PortableEncoding::push_checked(&mut current_path, "/abc"),
+            Err(CheckedPathError::UnexpectedRoot)
+        );
+        assert_eq!(current_path, "");
+
+        // Non-empty relative current path will fail when pushing an absolute path
+        let mut current_path = String::from("some/path");
+        assert_eq!(
+            Utf8PortableEncoding::push_checked(&mut current_path, "/abc"),
+            Err(CheckedPathError::UnexpectedRoot)
+        );
+        assert_eq!(current_path, "some/path");
+
+        // Non-empty absolute current path will fail when pushing an absolute path
+        let mut current_path = String::from("/some/path/");
+        assert_eq!(
+            Utf8PortableEncoding::push_checked(&mut current_path, "/abc"),
+            Err(CheckedPathError::UnexpectedRoot)
+        );
+        assert_eq!(current_path, "/some/path/");
+    }
+
+    #[test]
+    fn push_checked_should_fail_if_providing_a_path_with_disallowed_filename_characters() {
+        // Empty current path will fail when pushing a path containing disallowed filename chars
+        let mut current_path = String::new();
+        assert_eq!(
+            Utf8PortableEncoding::push_checked(&mut current_path, "some/inva\0lid/path"),
+            Err(CheckedPathError::InvalidFilename)
+        );
+        assert_eq!(current_path, "");
+
+        // Non-empty relative current path will fail when pushing a path containing disallowed
+        // filename bytes
+        let mut current_path = String::from("some/path");
+        assert_eq!(
+            Utf8PortableEncoding::push_checked(&mut current_path, "some/inva\0lid/path"),
+            Err(CheckedPathError::InvalidFilename)
+        );
+        assert_eq!(current_path, "some/path");
+
+        // Non-empty absolute current path will fail when pushing a path containing disallowed
+        // filename bytes
+        let mut current_path = String::from("/some/path/");
+        assert_eq!(
+            Utf8PortableEncoding::push_checked(&mut current_path, "some/inva\0lid/path"),
+            Err(CheckedPathError::InvalidFilename)
+        );
+        assert_eq!(current_path, "/some/path/");
+    }
+
+    #[test]
+    fn push_checked_should_fail_if_providing_a_path_that_would_escape_the_current_path() {
+        // Empty current path will fail when pushing a path that would escape
+        let mut current_path = String::new();
+        assert_eq!(
+            Utf8PortableEncoding::push_checked(&mut current_path, ".."),
+            Err(CheckedPathError::PathTraversalAttack)
+        );
+        assert_eq!(current_path, "");
+
+        // Non-empty relative current path will fail when pushing a path that would escape
+        let mut current_path = String::from("some/path");
+        assert_eq!(
+            Utf8PortableEncoding::push_checked(&mut current_path, ".."),
+            Err(CheckedPathError::PathTraversalAttack)
+        );
+        assert_eq!(current_path, "some/path");
+
+        // Non-empty absolute current path will fail when pushing a path that would escape
+        let mut current_path = String::from("/some/path/");
+        assert_eq!(
+            Utf8PortableEncoding::push_checked(&mut current_path, ".."),
+            Err(CheckedPathError::PathTraversalAttack)
+        );
+        assert_eq!(current_path, "/some/path/");
+    }
+
+    #[test]
+    fn push_checked_should_append_path_to_current_path_with_a_separator_if_does_not_violate_rules()
+    {
+        // Pushing a path that contains parent dirs, but does not escape the current path,
+        // should succeed
+        let mut current_path = String::new();
+        assert_eq!(
+            Utf8PortableEncoding::push_checked(&mut current_path, "abc/../def/."),
+            Ok(()),
+        );
+        assert_eq!(current_path, "abc/../def/.");
+
+        let mut current_path = String::from("some/path");
+        assert_eq!(
+            Utf8PortableEncoding::push_checked(&mut current_path, "abc/../def/."),
+            Ok(()),
+        );
+        assert_eq!(current_path, "some/path/abc/../def/.");
+
+        let mut current_path = String::from("/some/path/");
+        assert_eq!(
+            Utf8PortableEncoding::push_checked(&mut current_path, "abc/../def/."),
+            Ok(()),
+        );
+        assert_eq!(current_path, "/some/path/abc/../def/.");
+    }
+}
@@ -0,0 +1,38 @@
+/// The primary separator of path components for PowerShell paths
+pub const SEPARATOR: char = '\\';
+
+/// The primary separator of path components for PowerShell paths
+pub const SEPARATOR_STR: &str = "\\";
+
+/// The alternate separator of path components for PowerShell paths
+pub const ALT_SEPARATOR: char = '/';
+
+/// The alternate separator of path components for PowerShell paths
+pub const ALT_SEPARATOR_STR: &str = "/";
+
+/// Delimiter following the name of a provider in a provider-qualified PowerShell path
+pub const PROVIDER_DELIMITER: &[u8] = b"::";
+
+/// Delimiter following the name of a provider in a provider-qualified PowerShell path
+pub const PROVIDER_DELIMITER_STR: &str = "::";
+
+/// Delimiter following the name of a PowerShell drive
+pub const DRIVE_DELIMITER: u8 = b':';
+
+/// Path component value that represents the parent directory
+pub const PARENT_DIR: &[u8] = b"..";
+
+/// Path component value that represents the parent directory
+pub const PARENT_DIR_STR: &str = "..";
+
+/// Path component value that represents the current directory
+pub const CURRENT_DIR: &[u8] = b".";
+
+/// Path component value that represents the current directory
+pub const CURRENT_DIR_STR: &str = ".";
+
+/// Bytes that are not allowed in file or directory names, which are the separators and null
+pub const DISALLOWED_FILENAME_BYTES: [u8; 3] = [b'\\', b'/', b'\0'];
+
+/// Bytes that are not allowed in file or directory names, which are the separators and null
+pub const DISALLOWED_FILENAME_CHARS: [char; 3] = ['\\', '/', '\0'];
@@ -0,0 +1,324 @@
+mod components;
+
+use core::fmt;
+use core::hash::Hasher;
+
+pub use components::*;
+
+use super::constants::*;
+use crate::common::CheckedPathError;
+use crate::no_std_compat::*;
+use crate::{private, Component, Components, Encoding, Path, PathBuf};
+
+/// Represents a PowerShell [`Path`]
+pub type PowerShellPath = Path<PowerShellEncoding>;
+
+/// Represents a PowerShell [`PathBuf`]
+pub type PowerShellPathBuf = PathBuf<PowerShellEncoding>;
+
+/// Represents a PowerShell [`Encoding`] for provider paths such as `HKLM:\Software\Vendor`,
+/// `Env:\PATH`, or `FileSystem::C:\Windows`.
+///
+/// A path can start with a provider like `Registry::` followed by an optional drive like `HKLM:`
+/// or a Windows prefix like `\\server\share`, which together make up a single prefix component.
+/// The rest of the path uses the same rules as [`WindowsEncoding`](crate::WindowsEncoding), with
+/// `\` as the separator and `/` as an alternate separator.
+///
+/// As with Windows, a path starting with a drive is only absolute when followed by a root, so
+/// `HKLM:Vendor` is relative to the current location of the `HKLM:` drive. A provider on its own
+/// refers to the root of the provider, so `Registry::HKEY_LOCAL_MACHINE` is absolute.
+///
+/// # Examples
+///
+/// ```
+/// use typed_path::PowerShellPath;
+///
+/// let path = PowerShellPath::new(r"FileSystem::C:\Users\me");
+/// assert_eq!(path.provider(), Some(b"FileSystem".as_slice()));
+/// assert_eq!(path.drive(), Some(b"C".as_slice()));
+/// assert!(path.is_absolute());
+///
+/// assert_eq!(
+///     PowerShellPath::new(r"HKLM:\Software").join("Vendor").as_bytes(),
+///     br"HKLM:\Software\Vendor",
+/// );
+///
+/// // Rooted paths replace only the part of the path after the prefix
+/// assert_eq!(PowerShellPath::new(r"Env:\PATH").join(r"\HOME").as_bytes(), br"Env:\HOME");
+/// ```
+#[derive(Copy, Clone)]
+pub struct PowerShellEncoding;
+
+impl private::Sealed for PowerShellEncoding {}
+
+impl<'a> Encoding<'a> for PowerShellEncoding {
+    type Components = PowerShellComponents<'a>;
+
+    const NAME: &'static str = "powershell";
+    const SEPARATOR: char = SEPARATOR;
+    const ALT_SEPARATORS: &'static [char] = &[ALT_SEPARATOR];
+    const CASE_SENSITIVE: bool = false;
+    const DISALLOWED_FILENAME_BYTES: &'static [u8] = &DISALLOWED_FILENAME_BYTES;
+
+    fn label() -> &'static str {
+        "powershell"
+    }
+
+    fn components(path: &'a [u8]) -> Self::Components {
+        PowerShellComponents::new(path)
+    }
+
+    fn hash<H: Hasher>(path: &[u8], h: &mut H) {
+        let mut bytes_hashed = 0;
+
+        // Hash what equality compares, which are the components once normalized by parsing
+        for component in Self::components(path) {
+            let to_hash = component.as_bytes();
+            h.write(to_hash);
+            bytes_hashed += to_hash.len();
+        }
+
+        h.write_usize(bytes_hashed);
+    }
+
+    fn push(current_path: &mut Vec<u8>, path: &[u8]) {
+        if path.is_empty() {
+            return;
+        }
+
+        // A path with its own prefix replaces entirely, while a rooted path only replaces the
+        // rest of the path and keeps the current prefix
+        //
+        // Otherwise, if our current path is not empty, we will append the provided path
+        // to the end with a separator inbetween, unless the current path is only a drive like
+        // `HKLM:` or a provider like `Registry::` where a separator would change the meaning
+        let current_prefix = parse_prefix(current_path);
+        let is_only_drive_or_provider = current_prefix.map(|(len, _)| len)
+            == Some(current_path.len())
+            && current_path.ends_with(&[DRIVE_DELIMITER]);
+        if parse_prefix(path).is_some() {
+            current_path.clear();
+        } else if Self::components(path).has_root() {
+            current_path.truncate(current_prefix.map(|(len, _)| len).unwrap_or(0));
+        } else if !current_path.is_empty()
+            && !current_path.ends_with(SEPARATOR_STR.as_bytes())
+            && !current_path.ends_with(ALT_SEPARATOR_STR.as_bytes())
+            && !is_only_drive_or_provider
+        {
+            current_path.push(SEPARATOR as u8);
+        }
+
+        current_path.extend_from_slice(path);
+    }
+
+    fn push_checked(current_path: &mut Vec<u8>, path: &[u8]) -> Result<(), CheckedPathError> {
+        // As we scan through path components, we maintain a count of normal components that
+        // have not been popped off as a result of a parent component. If we ever reach a
+        // parent component without any preceding normal components remaining, this violates
+        // pushing onto our path and represents a path traversal attack.
+        let mut normal_cnt = 0;
+        for component in PowerShellPath::new(path).components() {
+            match component {
+                PowerShellComponent::Prefix(_) => return Err(CheckedPathError::UnexpectedPrefix),
+                PowerShellComponent::RootDir => return Err(CheckedPathError::UnexpectedRoot),
+                PowerShellComponent::ParentDir if normal_cnt == 0 => {
+                    return Err(CheckedPathError::PathTraversalAttack)
+                }
+                PowerShellComponent::ParentDir => normal_cnt -= 1,
+                PowerShellComponent::Normal(bytes) => {
+                    for b in bytes {
+                        if DISALLOWED_FILENAME_BYTES.contains(b) {
+                            return Err(CheckedPathError::InvalidFilename);
+                        }
+                    }
+                    normal_cnt += 1;
+                }
+                _ => continue,
+            }
+        }
+
+        Self::push(current_path, path);
+        Ok(())
+    }
+}
+
+impl fmt::Debug for PowerShellEncoding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PowerShellEncoding").finish()
+    }
+}
+
+impl fmt::Display for PowerShellEncoding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "PowerShellEncoding")
+    }
+}
+
+impl<T> Path<T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    /// Returns true if the encoding for the path is PowerShell.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{PowerShellPath, UnixPath};
+    ///
+    /// assert!(PowerShellPath::new(r"HKLM:\Software").has_powershell_encoding());
+    /// assert!(!UnixPath::new("/some/path").has_powershell_encoding());
+    /// ```
+    pub fn has_powershell_encoding(&self) -> bool {
+        T::label() == PowerShellEncoding::label()
+    }
+
+    /// Creates an owned [`PathBuf`] like `self` but using [`PowerShellEncoding`].
+    ///
+    /// See [`Path::with_encoding`] for more information.
+    pub fn with_powershell_encoding(&self) -> PathBuf<PowerShellEncoding> {
+        self.with_encoding()
+    }
+
+    /// Creates an owned [`PathBuf`] like `self` but using [`PowerShellEncoding`], ensuring it is a
+    /// valid PowerShell path.
+    ///
+    /// See [`Path::with_encoding_checked`] for more information.
+    pub fn with_powershell_encoding_checked(
+        &self,
+    ) -> Result<PathBuf<PowerShellEncoding>, CheckedPathError> {
+        self.with_encoding_checked()
+    }
+}
+
+impl PowerShellPath {
+    /// Returns the name of the provider at the start of the path, without the trailing `::`, or
+    /// `None` if the path is not provider-qualified.
+    ///
+    /// A module-qualified provider like `Microsoft.PowerShell.Core\Registry` is returned as
+    /// written.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::PowerShellPath;
+    ///
+    /// let path = PowerShellPath::new(r"Registry::HKEY_LOCAL_MACHINE\Software");
+    /// assert_eq!(path.provider(), Some(b"Registry".as_slice()));
+    ///
+    /// let path = PowerShellPath::new(r"Microsoft.PowerShell.Core\FileSystem::C:\");
+    /// assert_eq!(path.provider(), Some(br"Microsoft.PowerShell.Core\FileSystem".as_slice()));
+    ///
+    /// assert_eq!(PowerShellPath::new(r"HKLM:\Software").provider(), None);
+    /// ```
+    pub fn provider(&self) -> Option<&[u8]> {
+        let len = provider_len(self.as_bytes())?;
+        Some(&self.as_bytes()[..len - PROVIDER_DELIMITER.len()])
+    }
+
+    /// Returns the name of the drive at the start of the path or following its provider, without
+    /// the trailing `:`, or `None` if the path does not have a drive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::PowerShellPath;
+    ///
+    /// assert_eq!(PowerShellPath::new(r"HKLM:\Software").drive(), Some(b"HKLM".as_slice()));
+    /// assert_eq!(PowerShellPath::new("Env:PATH").drive(), Some(b"Env".as_slice()));
+    /// assert_eq!(PowerShellPath::new(r"FileSystem::C:\x").drive(), Some(b"C".as_slice()));
+    ///
+    /// assert_eq!(PowerShellPath::new(r"Registry::HKEY_USERS").drive(), None);
+    /// assert_eq!(PowerShellPath::new(r"\\server\share").drive(), None);
+    /// ```
+    pub fn drive(&self) -> Option<&[u8]> {
+        let rest = &self.as_bytes()[provider_len(self.as_bytes()).unwrap_or(0)..];
+        let len = drive_len(rest)?;
+        Some(&rest[..len - 1])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn push_should_replace_current_path_with_provided_path_if_provided_path_has_a_prefix() {
+        let mut current_path = br"HKLM:\Software".to_vec();
+        PowerShellEncoding::push(&mut current_path, br"Env:\PATH");
+        assert_eq!(current_path, br"Env:\PATH");
+
+        let mut current_path = br"C:\Users".to_vec();
+        PowerShellEncoding::push(&mut current_path, b"HKCU:Vendor");
+        assert_eq!(current_path, b"HKCU:Vendor");
+
+        let mut current_path = b"Vendor".to_vec();
+        PowerShellEncoding::push(&mut current_path, br"Registry::HKEY_USERS");
+        assert_eq!(current_path, br"Registry::HKEY_USERS");
+    }
+
+    #[test]
+    fn push_should_replace_only_the_rest_of_the_path_if_provided_path_is_rooted() {
+        let mut current_path = br"HKLM:\Software\Vendor".to_vec();
+        PowerShellEncoding::push(&mut current_path, br"\System");
+        assert_eq!(current_path, br"HKLM:\System");
+
+        let mut current_path = br"FileSystem::C:".to_vec();
+        PowerShellEncoding::push(&mut current_path, b"/Users");
+        assert_eq!(current_path, br"FileSystem::C:/Users");
+
+        let mut current_path = br"\Software".to_vec();
+        PowerShellEncoding::push(&mut current_path, br"\System");
+        assert_eq!(current_path, br"\System");
+    }
+
+    #[test]
+    fn push_should_append_path_to_current_path_with_a_separator_if_provided_path_is_relative() {
+        let mut current_path = vec![];
+        PowerShellEncoding::push(&mut current_path, b"Vendor");
+        assert_eq!(current_path, b"Vendor");
+
+        let mut current_path = b"HKLM:".to_vec();
+        PowerShellEncoding::push(&mut current_path, b"Vendor");
+        assert_eq!(current_path, b"HKLM:Vendor");
+
+        let mut current_path = b"Registry::".to_vec();
+        PowerShellEncoding::push(&mut current_path, b"HKEY_USERS");
+        assert_eq!(current_path, b"Registry::HKEY_USERS");
+
+        let mut current_path = br"\\server\share".to_vec();
+        PowerShellEncoding::push(&mut current_path, b"dir");
+        assert_eq!(current_path, br"\\server\share\dir");
+
+        let mut current_path = br"HKLM:\Software/".to_vec();
+        PowerShellEncoding::push(&mut current_path, b"Vendor");
+        assert_eq!(current_path, br"HKLM:\Software/Vendor");
+    }
+
+    #[test]
+    fn push_checked_should_fail_if_providing_a_prefix_root_or_escaping_path() {
+        let mut current_path = br"HKLM:\Software".to_vec();
+        assert_eq!(
+            PowerShellEncoding::push_checked(&mut current_path, b"Env:"),
+            Err(CheckedPathError::UnexpectedPrefix)
+        );
+        assert_eq!(
+            PowerShellEncoding::push_checked(&mut current_path, br"\abc"),
+            Err(CheckedPathError::UnexpectedRoot)
+        );
+        assert_eq!(
+            PowerShellEncoding::push_checked(&mut current_path, br"abc\..\.."),
+            Err(CheckedPathError::PathTraversalAttack)
+        );
+        assert_eq!(
+            PowerShellEncoding::push_checked(&mut current_path, b"inva\0lid"),
+            Err(CheckedPathError::InvalidFilename)
+        );
+        assert_eq!(current_path, br"HKLM:\Software");
+
+        assert_eq!(
+            PowerShellEncoding::push_checked(&mut current_path, br"Vendor\Tool"),
+            Ok(())
+        );
+        assert_eq!(current_path, br"HKLM:\Software\Vendor\Tool");
+    }
+}
@@ -0,0 +1,337 @@
+mod component;
+
+use core::{cmp, fmt, iter};
+
+pub use component::*;
+
+use crate::common::remaining_path;
+use crate::powershell::constants::{DRIVE_DELIMITER, PROVIDER_DELIMITER};
+use crate::windows::WindowsComponents;
+use crate::{private, Components, Encoding, Path};
+
+/// Iterator over the [`PowerShellComponent`]s of a PowerShell path.
+///
+/// The provider and drive at the start of the path, such as `FileSystem::C:` or `HKLM:`, are
+/// yielded first as a single [`PowerShellComponent::Prefix`], followed by the components of the
+/// rest of the path using the same rules as [`WindowsEncoding`](crate::WindowsEncoding).
+#[derive(Clone)]
+pub struct PowerShellComponents<'a> {
+    path: &'a [u8],
+
+    /// Prefix that has yet to be yielded, which always starts the remaining path
+    prefix: Option<&'a [u8]>,
+
+    /// True if the prefix ends with a drive, which needs a root to be absolute
+    drive_relative: bool,
+
+    /// Position within the path where the rest of the path starts
+    inner_start: usize,
+    inner: WindowsComponents<'a>,
+}
+
+impl<'a> PowerShellComponents<'a> {
+    pub(crate) fn new(path: &'a [u8]) -> Self {
+        let (prefix, drive_relative) = match parse_prefix(path) {
+            Some((len, drive_relative)) => (Some(&path[..len]), drive_relative),
+            None => (None, false),
+        };
+
+        // Collapse separators following a prefix into a single root so the rest of the path is
+        // never mistaken for a UNC or verbatim prefix
+        let mut inner_start = prefix.map(<[u8]>::len).unwrap_or(0);
+        if inner_start > 0 {
+            while path[inner_start..]
+                .get(..2)
+                .map(|s| s.iter().all(|b| is_separator(*b)))
+                .unwrap_or(false)
+            {
+                inner_start += 1;
+            }
+        }
+
+        Self {
+            path,
+            prefix,
+            drive_relative,
+            inner_start,
+            inner: WindowsComponents::new(&path[inner_start..]),
+        }
+    }
+
+    /// Extracts a slice corresponding to the portion of the path remaining for iteration.
+    ///
+    /// As with `std::path::Components::as_path`, trailing separators and current directory
+    /// components that will not be yielded are left out.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Path, PowerShellEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// let mut components = Path::<PowerShellEncoding>::new(r"HKLM:\Software\Vendor").components();
+    /// components.next();
+    /// components.next();
+    ///
+    /// assert_eq!(Path::<PowerShellEncoding>::new(r"Software\Vendor"), components.as_path());
+    /// ```
+    pub fn as_path<T>(&self) -> &'a Path<T>
+    where
+        T: for<'enc> Encoding<'enc>,
+    {
+        Path::new(remaining_path(self))
+    }
+}
+
+fn is_separator(b: u8) -> bool {
+    b == b'\\' || b == b'/'
+}
+
+/// Returns the length of the provider at the start of `path` including its `::` delimiter, such
+/// as `FileSystem::` or `Microsoft.PowerShell.Core\Registry::`, if it has one.
+pub(crate) fn provider_len(path: &[u8]) -> Option<usize> {
+    let name_len = path
+        .iter()
+        .position(|b| !(b.is_ascii_alphanumeric() || matches!(b, b'_' | b'.' | b'\\')))?;
+
+    if name_len == 0
+        || !path[0].is_ascii_alphabetic()
+        || !path[name_len..].starts_with(PROVIDER_DELIMITER)
+    {
+        return None;
+    }
+
+    Some(name_len + PROVIDER_DELIMITER.len())
+}
+
+/// Returns the length of the drive at the start of `path` including its `:` delimiter, such as
+/// `C:`, `HKLM:`, or `Env:`, if it has one.
+pub(crate) fn drive_len(path: &[u8]) -> Option<usize> {
+    let name_len = path
+        .iter()
+        .position(|b| !(b.is_ascii_alphanumeric() || *b == b'_'))?;
+
+    if name_len == 0
+        || path[name_len] != DRIVE_DELIMITER
+        || path.get(name_len + 1) == Some(&DRIVE_DELIMITER)
+    {
+        return None;
+    }
+
+    Some(name_len + 1)
+}
+
+/// Returns the length of the prefix at the start of `path`, made up of an optional provider
+/// followed by either a drive or a Windows prefix like `\\server\share`, alongside whether the
+/// prefix ends with a drive.
+pub(crate) fn parse_prefix(path: &[u8]) -> Option<(usize, bool)> {
+    let provider = provider_len(path).unwrap_or(0);
+    let rest = &path[provider..];
+
+    let (len, drive_relative) = match drive_len(rest) {
+        Some(len) => (provider + len, true),
+        None => match WindowsComponents::new(rest).prefix_len() {
+            0 => (provider, false),
+            len => (provider + len, false),
+        },
+    };
+
+    // A disk that follows the drive, such as in `HKLM:C:`, is kept within the prefix so that it
+    // is never yielded as a component of its own, while anything starting with a separator is
+    // collapsed into a root by the caller instead
+    let rest = &path[len..];
+    let extra = if rest.first().copied().map(is_separator).unwrap_or(true) {
+        0
+    } else {
+        WindowsComponents::new(rest).prefix_len()
+    };
+
+    if len + extra > 0 {
+        Some((len + extra, drive_relative))
+    } else {
+        None
+    }
+}
+
+impl private::Sealed for PowerShellComponents<'_> {}
+
+impl<'a> Components<'a> for PowerShellComponents<'a> {
+    type Component = PowerShellComponent<'a>;
+
+    fn as_bytes(&self) -> &'a [u8] {
+        let rest = self.inner.as_bytes();
+        match self.prefix {
+            // Nothing has been taken from the front of the rest of the path while the prefix
+            // remains
+            Some(_) => &self.path[..self.inner_start + rest.len()],
+            None => rest,
+        }
+    }
+
+    /// Returns true if the path has a prefix and a root, where a provider or Windows prefix like
+    /// `\\server\share` provides a root on its own while a drive needs to be followed by a separator
+    fn is_absolute(&self) -> bool {
+        self.prefix.is_some() && self.has_root()
+    }
+
+    fn has_root(&self) -> bool {
+        (self.prefix.is_some() && !self.drive_relative) || self.inner.has_root()
+    }
+}
+
+impl AsRef<[u8]> for PowerShellComponents<'_> {
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl<T> AsRef<Path<T>> for PowerShellComponents<'_>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    #[inline]
+    fn as_ref(&self) -> &Path<T> {
+        Path::new(self.as_bytes())
+    }
+}
+
+impl<'a> fmt::Debug for PowerShellComponents<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        struct DebugHelper<'a>(PowerShellComponents<'a>);
+
+        impl<'a> fmt::Debug for DebugHelper<'a> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.debug_list().entries(self.0.clone()).finish()
+            }
+        }
+
+        f.debug_tuple("PowerShellComponents")
+            .field(&DebugHelper(self.clone()))
+            .finish()
+    }
+}
+
+impl<'a> Iterator for PowerShellComponents<'a> {
+    type Item = <Self as Components<'a>>::Component;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.prefix.take() {
+            Some(prefix) => Some(PowerShellComponent::Prefix(prefix)),
+            None => self.inner.next().map(PowerShellComponent::from),
+        }
+    }
+}
+
+impl<'a> DoubleEndedIterator for PowerShellComponents<'a> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        match self.inner.next_back() {
+            Some(component) => Some(PowerShellComponent::from(component)),
+            None => self.prefix.take().map(PowerShellComponent::Prefix),
+        }
+    }
+}
+
+impl<'a> iter::FusedIterator for PowerShellComponents<'a> {}
+
+impl<'a> cmp::PartialEq for PowerShellComponents<'a> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.clone().eq(other.clone())
+    }
+}
+
+impl<'a> cmp::Eq for PowerShellComponents<'a> {}
+
+impl<'a> cmp::PartialOrd for PowerShellComponents<'a> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<'a> cmp::Ord for PowerShellComponents<'a> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> cmp::Ordering {
+        self.clone().cmp(other.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::no_std_compat::*;
+
+    #[test]
+    fn parse_prefix_should_match_providers_drives_and_windows_prefixes() {
+        assert_eq!(parse_prefix(br"HKLM:\Software"), Some((5, true)));
+        assert_eq!(parse_prefix(b"Env:PATH"), Some((4, true)));
+        assert_eq!(parse_prefix(br"C:\x"), Some((2, true)));
+        assert_eq!(parse_prefix(br"FileSystem::C:\x"), Some((14, true)));
+        assert_eq!(
+            parse_prefix(br"Registry::HKEY_LOCAL_MACHINE\Software"),
+            Some((10, false))
+        );
+        assert_eq!(
+            parse_prefix(br"Microsoft.PowerShell.Core\FileSystem::C:\x"),
+            Some((40, true))
+        );
+        assert_eq!(
+            parse_prefix(br"FileSystem::\\server\share\x"),
+            Some((26, false))
+        );
+        assert_eq!(parse_prefix(br"\\server\share\x"), Some((14, false)));
+        assert_eq!(parse_prefix(br"HKLM:C:\x"), Some((7, true)));
+
+        assert_eq!(parse_prefix(br"\Software"), None);
+        assert_eq!(parse_prefix(b"HKLM::"), Some((6, false)));
+        assert_eq!(parse_prefix(b":x"), None);
+        assert_eq!(parse_prefix(b"relative"), None);
+        assert_eq!(parse_prefix(b""), None);
+    }
+
+    #[test]
+    fn should_yield_prefix_then_rest_of_path_from_either_end() {
+        let path = br"HKLM:\\Software/.\Vendor\";
+        let expected = [
+            PowerShellComponent::Prefix(b"HKLM:"),
+            PowerShellComponent::RootDir,
+            PowerShellComponent::Normal(b"Software"),
+            PowerShellComponent::Normal(b"Vendor"),
+        ];
+
+        assert_eq!(
+            PowerShellComponents::new(path).collect::<Vec<_>>(),
+            expected
+        );
+
+        let mut reversed = PowerShellComponents::new(path).rev().collect::<Vec<_>>();
+        reversed.reverse();
+        assert_eq!(reversed, expected);
+
+        let mut components = PowerShellComponents::new(path);
+        assert_eq!(
+            components.next_back(),
+            Some(PowerShellComponent::Normal(b"Vendor"))
+        );
+        assert_eq!(components.as_bytes(), br"HKLM:\\Software");
+        assert_eq!(
+            components.next(),
+            Some(PowerShellComponent::Prefix(b"HKLM:"))
+        );
+        assert_eq!(components.as_bytes(), br"\Software");
+    }
+
+    #[test]
+    fn should_only_be_absolute_with_a_root_or_a_non_drive_prefix() {
+        assert!(PowerShellComponents::new(br"Env:\PATH").is_absolute());
+        assert!(PowerShellComponents::new(br"Registry::HKEY_USERS").is_absolute());
+        assert!(PowerShellComponents::new(br"\\server\share").is_absolute());
+
+        assert!(!PowerShellComponents::new(b"Env:PATH").is_absolute());
+        assert!(!PowerShellComponents::new(b"Env:PATH").has_root());
+        assert!(PowerShellComponents::new(br"\PATH").has_root());
+        assert!(!PowerShellComponents::new(br"\PATH").is_absolute());
+        assert!(!PowerShellComponents::new(br".\a\..\b").is_absolute());
+    }
+}
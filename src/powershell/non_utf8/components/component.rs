@@ -0,0 +1,310 @@
+use super::parse_prefix;
+use crate::powershell::constants::{
+    CURRENT_DIR, DISALLOWED_FILENAME_BYTES, PARENT_DIR, SEPARATOR_STR,
+};
+use crate::powershell::PowerShellComponents;
+use crate::windows::WindowsComponent;
+use crate::{private, Component, Encoding, ParseError, Path};
+
+/// Byte slice version of [`std::path::Component`] that represents a PowerShell component
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum PowerShellComponent<'a> {
+    /// The provider and drive at the start of the path, such as `FileSystem::C:`, `HKLM:`, or
+    /// `Registry::`
+    Prefix(&'a [u8]),
+    RootDir,
+    CurDir,
+    ParentDir,
+    Normal(&'a [u8]),
+}
+
+impl private::Sealed for PowerShellComponent<'_> {}
+
+impl<'a> PowerShellComponent<'a> {
+    /// Returns path representing this specific component
+    pub fn as_path<T>(&self) -> &Path<T>
+    where
+        T: for<'enc> Encoding<'enc>,
+    {
+        Path::new(self.as_bytes())
+    }
+
+    /// Returns true if represents a provider or drive prefix
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::PowerShellComponent;
+    /// use std::convert::TryFrom;
+    ///
+    /// let prefix = PowerShellComponent::try_from("Env:").unwrap();
+    /// assert!(prefix.is_prefix());
+    ///
+    /// let root_dir = PowerShellComponent::try_from(r"\").unwrap();
+    /// assert!(!root_dir.is_prefix());
+    /// ```
+    pub fn is_prefix(&self) -> bool {
+        matches!(self, Self::Prefix(_))
+    }
+}
+
+impl<'a> Component<'a> for PowerShellComponent<'a> {
+    /// Extracts the underlying [`[u8]`] slice
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Component, PowerShellPath};
+    ///
+    /// let path = PowerShellPath::new(br"HKLM:\Software\Vendor\..\Tool");
+    /// let components: Vec<_> = path.components().map(|comp| comp.as_bytes()).collect();
+    /// assert_eq!(&components, &[
+    ///     b"HKLM:".as_slice(),
+    ///     br"\".as_slice(),
+    ///     b"Software".as_slice(),
+    ///     b"Vendor".as_slice(),
+    ///     b"..".as_slice(),
+    ///     b"Tool".as_slice(),
+    /// ]);
+    /// ```
+    fn as_bytes(&self) -> &'a [u8] {
+        match self {
+            Self::Prefix(prefix) => prefix,
+            Self::RootDir => SEPARATOR_STR.as_bytes(),
+            Self::CurDir => CURRENT_DIR,
+            Self::ParentDir => PARENT_DIR,
+            Self::Normal(path) => path,
+        }
+    }
+
+    /// Returns true if is the root dir component or a prefix that is not just a drive, as a drive
+    /// like `C:` needs to be followed by a root dir for the path to be absolute
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Component, PowerShellComponent};
+    /// use std::convert::TryFrom;
+    ///
+    /// let root_dir = PowerShellComponent::try_from(br"\").unwrap();
+    /// assert!(root_dir.is_root());
+    ///
+    /// let prefix = PowerShellComponent::try_from(b"Registry::").unwrap();
+    /// assert!(prefix.is_root());
+    ///
+    /// let drive = PowerShellComponent::try_from(b"HKLM:").unwrap();
+    /// assert!(!drive.is_root());
+    ///
+    /// let normal = PowerShellComponent::try_from(b"file.txt").unwrap();
+    /// assert!(!normal.is_root());
+    /// ```
+    fn is_root(&self) -> bool {
+        match self {
+            Self::RootDir => true,
+            Self::Prefix(prefix) => !parse_prefix(prefix)
+                .map(|(_, drive_relative)| drive_relative)
+                .unwrap_or(false),
+            _ => false,
+        }
+    }
+
+    /// Returns true if is a normal component
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Component, PowerShellComponent};
+    /// use std::convert::TryFrom;
+    ///
+    /// let normal = PowerShellComponent::try_from(b"file.txt").unwrap();
+    /// assert!(normal.is_normal());
+    ///
+    /// let root_dir = PowerShellComponent::try_from(br"\").unwrap();
+    /// assert!(!root_dir.is_normal());
+    /// ```
+    fn is_normal(&self) -> bool {
+        matches!(self, Self::Normal(_))
+    }
+
+    /// Returns true if is a parent directory component
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Component, PowerShellComponent};
+    /// use std::convert::TryFrom;
+    ///
+    /// let parent = PowerShellComponent::try_from("..").unwrap();
+    /// assert!(parent.is_parent());
+    ///
+    /// let root_dir = PowerShellComponent::try_from(r"\").unwrap();
+    /// assert!(!root_dir.is_parent());
+    /// ```
+    fn is_parent(&self) -> bool {
+        matches!(self, Self::ParentDir)
+    }
+
+    /// Returns true if is the current directory component
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Component, PowerShellComponent};
+    /// use std::convert::TryFrom;
+    ///
+    /// let current = PowerShellComponent::try_from(".").unwrap();
+    /// assert!(current.is_current());
+    ///
+    /// let root_dir = PowerShellComponent::try_from(r"\").unwrap();
+    /// assert!(!root_dir.is_current());
+    /// ```
+    fn is_current(&self) -> bool {
+        matches!(self, Self::CurDir)
+    }
+
+    /// Returns true if this component is valid.
+    ///
+    /// A component can only be invalid if it represents a normal component with bytes that are
+    /// disallowed by the encoding.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Component, PowerShellComponent};
+    ///
+    /// assert!(PowerShellComponent::Prefix(b"HKLM:").is_valid());
+    /// assert!(PowerShellComponent::RootDir.is_valid());
+    /// assert!(PowerShellComponent::ParentDir.is_valid());
+    /// assert!(PowerShellComponent::CurDir.is_valid());
+    /// assert!(PowerShellComponent::Normal(b"abc").is_valid());
+    /// assert!(!PowerShellComponent::Normal(b"\0").is_valid());
+    /// ```
+    fn is_valid(&self) -> bool {
+        match self {
+            Self::Prefix(_) | Self::RootDir | Self::ParentDir | Self::CurDir => true,
+            Self::Normal(bytes) => !bytes.iter().any(|b| DISALLOWED_FILENAME_BYTES.contains(b)),
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.as_bytes().len()
+    }
+
+    /// Returns the root directory component.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Component, PowerShellComponent};
+    ///
+    /// assert_eq!(PowerShellComponent::root(), PowerShellComponent::RootDir);
+    /// ```
+    fn root() -> Self {
+        Self::RootDir
+    }
+
+    /// Returns the parent directory component.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Component, PowerShellComponent};
+    ///
+    /// assert_eq!(PowerShellComponent::parent(), PowerShellComponent::ParentDir);
+    /// ```
+    fn parent() -> Self {
+        Self::ParentDir
+    }
+
+    /// Returns the current directory component.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Component, PowerShellComponent};
+    ///
+    /// assert_eq!(PowerShellComponent::current(), PowerShellComponent::CurDir);
+    /// ```
+    fn current() -> Self {
+        Self::CurDir
+    }
+}
+
+impl<'a> From<WindowsComponent<'a>> for PowerShellComponent<'a> {
+    fn from(component: WindowsComponent<'a>) -> Self {
+        match component {
+            WindowsComponent::Prefix(prefix) => Self::Prefix(prefix.as_bytes()),
+            WindowsComponent::RootDir => Self::RootDir,
+            WindowsComponent::CurDir => Self::CurDir,
+            WindowsComponent::ParentDir => Self::ParentDir,
+            WindowsComponent::Normal(bytes) => Self::Normal(bytes),
+        }
+    }
+}
+
+impl AsRef<[u8]> for PowerShellComponent<'_> {
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl<T> AsRef<Path<T>> for PowerShellComponent<'_>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    #[inline]
+    fn as_ref(&self) -> &Path<T> {
+        Path::new(self.as_bytes())
+    }
+}
+
+impl<'a> TryFrom<&'a [u8]> for PowerShellComponent<'a> {
+    type Error = ParseError;
+
+    /// Parses the byte slice into a [`PowerShellComponent`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::PowerShellComponent;
+    /// use std::convert::TryFrom;
+    ///
+    /// // Supports parsing standard PowerShell path components
+    /// assert_eq!(PowerShellComponent::try_from(b"Env:"), Ok(PowerShellComponent::Prefix(b"Env:")));
+    /// assert_eq!(PowerShellComponent::try_from(br"\"), Ok(PowerShellComponent::RootDir));
+    /// assert_eq!(PowerShellComponent::try_from(b"."), Ok(PowerShellComponent::CurDir));
+    /// assert_eq!(PowerShellComponent::try_from(b".."), Ok(PowerShellComponent::ParentDir));
+    /// assert_eq!(PowerShellComponent::try_from(b"file.txt"), Ok(PowerShellComponent::Normal(b"file.txt")));
+    /// assert_eq!(PowerShellComponent::try_from(br"dir\"), Ok(PowerShellComponent::Normal(b"dir")));
+    ///
+    /// // Parsing more than one component will fail
+    /// assert!(PowerShellComponent::try_from(br"Env:\PATH").is_err());
+    /// ```
+    fn try_from(path: &'a [u8]) -> Result<Self, Self::Error> {
+        let mut components = PowerShellComponents::new(path);
+
+        let component = components.next().ok_or("no component found")?;
+        if components.next().is_some() {
+            return Err("found more than one component");
+        }
+
+        Ok(component)
+    }
+}
+
+impl<'a, const N: usize> TryFrom<&'a [u8; N]> for PowerShellComponent<'a> {
+    type Error = ParseError;
+
+    fn try_from(path: &'a [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from(path.as_slice())
+    }
+}
+
+impl<'a> TryFrom<&'a str> for PowerShellComponent<'a> {
+    type Error = ParseError;
+
+    fn try_from(path: &'a str) -> Result<Self, Self::Error> {
+        Self::try_from(path.as_bytes())
+    }
+}
@@ -0,0 +1,184 @@
+mod components;
+
+use core::fmt;
+use core::hash::Hasher;
+
+pub use components::*;
+
+use crate::common::CheckedPathError;
+use crate::no_std_compat::*;
+use crate::{
+    private, Encoding, PowerShellEncoding, PowerShellPath, Utf8Encoding, Utf8Path, Utf8PathBuf,
+};
+
+/// Represents a PowerShell [`Utf8Path`]
+pub type Utf8PowerShellPath = Utf8Path<Utf8PowerShellEncoding>;
+
+/// Represents a PowerShell [`Utf8PathBuf`]
+pub type Utf8PowerShellPathBuf = Utf8PathBuf<Utf8PowerShellEncoding>;
+
+/// Represents a PowerShell [`Utf8Encoding`]
+///
+/// See [`PowerShellEncoding`] for more details.
+#[derive(Copy, Clone)]
+pub struct Utf8PowerShellEncoding;
+
+impl private::Sealed for Utf8PowerShellEncoding {}
+
+impl<'a> Utf8Encoding<'a> for Utf8PowerShellEncoding {
+    type Components = Utf8PowerShellComponents<'a>;
+
+    const NAME: &'static str = <PowerShellEncoding as Encoding<'a>>::NAME;
+    const SEPARATOR: char = <PowerShellEncoding as Encoding<'a>>::SEPARATOR;
+    const ALT_SEPARATORS: &'static [char] = <PowerShellEncoding as Encoding<'a>>::ALT_SEPARATORS;
+    const CASE_SENSITIVE: bool = <PowerShellEncoding as Encoding<'a>>::CASE_SENSITIVE;
+    const DISALLOWED_FILENAME_BYTES: &'static [u8] =
+        <PowerShellEncoding as Encoding<'a>>::DISALLOWED_FILENAME_BYTES;
+
+    fn label() -> &'static str {
+        "powershell"
+    }
+
+    fn components(path: &'a str) -> Self::Components {
+        Utf8PowerShellComponents::new(path)
+    }
+
+    fn hash<H: Hasher>(path: &str, h: &mut H) {
+        PowerShellEncoding::hash(path.as_bytes(), h);
+    }
+
+    fn push(current_path: &mut String, path: &str) {
+        unsafe {
+            PowerShellEncoding::push(current_path.as_mut_vec(), path.as_bytes());
+        }
+    }
+
+    fn push_checked(current_path: &mut String, path: &str) -> Result<(), CheckedPathError> {
+        unsafe { PowerShellEncoding::push_checked(current_path.as_mut_vec(), path.as_bytes()) }
+    }
+}
+
+impl fmt::Debug for Utf8PowerShellEncoding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Utf8PowerShellEncoding").finish()
+    }
+}
+
+impl fmt::Display for Utf8PowerShellEncoding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Utf8PowerShellEncoding")
+    }
+}
+
+impl<T> Utf8Path<T>
+where
+    T: for<'enc> Utf8Encoding<'enc>,
+{
+    /// Returns true if the encoding for the path is PowerShell.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Utf8PowerShellPath, Utf8UnixPath};
+    ///
+    /// assert!(Utf8PowerShellPath::new(r"HKLM:\Software").has_powershell_encoding());
+    /// assert!(!Utf8UnixPath::new("/some/path").has_powershell_encoding());
+    /// ```
+    pub fn has_powershell_encoding(&self) -> bool {
+        T::label() == Utf8PowerShellEncoding::label()
+    }
+
+    /// Creates an owned [`Utf8PathBuf`] like `self` but using [`Utf8PowerShellEncoding`].
+    ///
+    /// See [`Utf8Path::with_encoding`] for more information.
+    pub fn with_powershell_encoding(&self) -> Utf8PathBuf<Utf8PowerShellEncoding> {
+        self.with_encoding()
+    }
+
+    /// Creates an owned [`Utf8PathBuf`] like `self` but using [`Utf8PowerShellEncoding`], ensuring it
+    /// is a valid PowerShell path.
+    ///
+    /// See [`Utf8Path::with_encoding_checked`] for more information.
+    pub fn with_powershell_encoding_checked(
+        &self,
+    ) -> Result<Utf8PathBuf<Utf8PowerShellEncoding>, CheckedPathError> {
+        self.with_encoding_checked()
+    }
+}
+
+impl Utf8PowerShellPath {
+    /// Returns the name of the provider at the start of the path, without the trailing `::`, or
+    /// `None` if the path is not provider-qualified.
+    ///
+    /// See [`PowerShellPath::provider`] for more details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::Utf8PowerShellPath;
+    ///
+    /// let path = Utf8PowerShellPath::new(r"FileSystem::C:\Users");
+    /// assert_eq!(path.provider(), Some("FileSystem"));
+    /// assert_eq!(Utf8PowerShellPath::new(r"C:\Users").provider(), None);
+    /// ```
+    pub fn provider(&self) -> Option<&str> {
+        let len = PowerShellPath::new(self.as_str()).provider()?.len();
+        Some(&self.as_str()[..len])
+    }
+
+    /// Returns the name of the drive at the start of the path or following its provider, without
+    /// the trailing `:`, or `None` if the path does not have a drive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::Utf8PowerShellPath;
+    ///
+    /// assert_eq!(Utf8PowerShellPath::new(r"Env:\PATH").drive(), Some("Env"));
+    /// assert_eq!(Utf8PowerShellPath::new(r"FileSystem::C:\x").drive(), Some("C"));
+    /// assert_eq!(Utf8PowerShellPath::new(r"Registry::HKEY_USERS").drive(), None);
+    /// ```
+    pub fn drive(&self) -> Option<&str> {
+        let path = PowerShellPath::new(self.as_str());
+        let start = path
+            .provider()
+            .map(|p| p.len() + crate::constants::powershell::PROVIDER_DELIMITER.len())
+            .unwrap_or(0);
+        let len = path.drive()?.len();
+        Some(&self.as_str()[start..start + len])
+    }
+}
+
+impl_cmp_utf8_bytes!(Utf8PowerShellEncoding, PowerShellEncoding);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn push_should_follow_the_rules_of_the_non_utf8_encoding() {
+        let mut current_path = String::from(r"HKLM:\Software");
+        Utf8PowerShellEncoding::push(&mut current_path, "Vendor");
+        assert_eq!(current_path, r"HKLM:\Software\Vendor");
+
+        Utf8PowerShellEncoding::push(&mut current_path, r"\System");
+        assert_eq!(current_path, r"HKLM:\System");
+
+        Utf8PowerShellEncoding::push(&mut current_path, r"Env:\PATH");
+        assert_eq!(current_path, r"Env:\PATH");
+    }
+
+    #[test]
+    fn push_checked_should_fail_if_providing_a_prefix_or_root() {
+        let mut current_path = String::from(r"HKLM:\Software");
+        assert_eq!(
+            Utf8PowerShellEncoding::push_checked(&mut current_path, "Env:"),
+            Err(CheckedPathError::UnexpectedPrefix)
+        );
+        assert_eq!(
+            Utf8PowerShellEncoding::push_checked(&mut current_path, r"\abc"),
+            Err(CheckedPathError::UnexpectedRoot)
+        );
+        assert_eq!(current_path, r"HKLM:\Software");
+    }
+}
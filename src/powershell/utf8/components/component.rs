@@ -0,0 +1,382 @@
+use core::fmt;
+use core::str::Utf8Error;
+
+use crate::powershell::constants::{
+    CURRENT_DIR_STR, DISALLOWED_FILENAME_CHARS, PARENT_DIR_STR, SEPARATOR_STR,
+};
+use crate::powershell::{PowerShellComponent, Utf8PowerShellComponents};
+use crate::{private, Component, ParseError, Utf8Component, Utf8Encoding, Utf8Path};
+
+/// `str` slice version of [`std::path::Component`] that represents a PowerShell component
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Utf8PowerShellComponent<'a> {
+    /// The provider and drive at the start of the path, such as `FileSystem::C:`, `HKLM:`, or
+    /// `Registry::`
+    Prefix(&'a str),
+    RootDir,
+    CurDir,
+    ParentDir,
+    Normal(&'a str),
+}
+
+impl<'a> Utf8PowerShellComponent<'a> {
+    /// Converts a non-UTF-8 [`PowerShellComponent`] to a UTF-8 [`Utf8PowerShellComponent`]  by checking
+    /// that the component contains valid UTF-8.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if the component is not UTF-8 with a description as to why the
+    /// provided component is not UTF-8.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use typed_path::{Utf8Component, PowerShellComponent, Utf8PowerShellComponent};
+    ///
+    /// // some bytes, in a vector
+    /// let component = PowerShellComponent::Normal(&[240, 159, 146, 150]);
+    ///
+    /// // We know these bytes are valid, so just use `unwrap()`.
+    /// let utf8_component = Utf8PowerShellComponent::from_utf8(&component).unwrap();
+    ///
+    /// assert_eq!("💖", utf8_component.as_str());
+    /// ```
+    ///
+    /// Incorrect bytes:
+    ///
+    /// ```
+    /// use typed_path::{PowerShellComponent, Utf8PowerShellComponent};
+    ///
+    /// // some invalid bytes, in a vector
+    /// let component = PowerShellComponent::Normal(&[0, 159, 146, 150]);
+    ///
+    /// assert!(Utf8PowerShellComponent::from_utf8(&component).is_err());
+    /// ```
+    ///
+    /// See the docs for [`Utf8Error`] for more details on the kinds of
+    /// errors that can be returned.
+    pub fn from_utf8(component: &PowerShellComponent<'a>) -> Result<Self, Utf8Error> {
+        Ok(match component {
+            PowerShellComponent::Prefix(x) => Self::Prefix(core::str::from_utf8(x)?),
+            PowerShellComponent::RootDir => Self::RootDir,
+            PowerShellComponent::ParentDir => Self::ParentDir,
+            PowerShellComponent::CurDir => Self::CurDir,
+            PowerShellComponent::Normal(x) => Self::Normal(core::str::from_utf8(x)?),
+        })
+    }
+
+    /// Converts a non-UTF-8 [`PowerShellComponent`] to a UTF-8 [`Utf8PowerShellComponent`] without
+    /// checking that the string contains valid UTF-8.
+    ///
+    /// See the safe version, [`from_utf8`], for more information.
+    ///
+    /// [`from_utf8`]: Utf8PowerShellComponent::from_utf8
+    ///
+    /// # Safety
+    ///
+    /// The bytes passed in must be valid UTF-8.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use typed_path::{Utf8Component, PowerShellComponent, Utf8PowerShellComponent};
+    ///
+    /// // some bytes, in a vector
+    /// let component = PowerShellComponent::Normal(&[240, 159, 146, 150]);
+    ///
+    /// let utf8_component = unsafe {
+    ///     Utf8PowerShellComponent::from_utf8_unchecked(&component)
+    /// };
+    ///
+    /// assert_eq!("💖", utf8_component.as_str());
+    /// ```
+    pub unsafe fn from_utf8_unchecked(component: &PowerShellComponent<'a>) -> Self {
+        match component {
+            PowerShellComponent::Prefix(x) => Self::Prefix(core::str::from_utf8_unchecked(x)),
+            PowerShellComponent::RootDir => Self::RootDir,
+            PowerShellComponent::ParentDir => Self::ParentDir,
+            PowerShellComponent::CurDir => Self::CurDir,
+            PowerShellComponent::Normal(x) => Self::Normal(core::str::from_utf8_unchecked(x)),
+        }
+    }
+}
+
+impl private::Sealed for Utf8PowerShellComponent<'_> {}
+
+impl<'a> Utf8PowerShellComponent<'a> {
+    /// Returns path representing this specific component
+    pub fn as_path<T>(&self) -> &Utf8Path<T>
+    where
+        T: for<'enc> Utf8Encoding<'enc>,
+    {
+        Utf8Path::new(self.as_str())
+    }
+
+    /// Returns true if represents a provider or drive prefix
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::Utf8PowerShellComponent;
+    /// use std::convert::TryFrom;
+    ///
+    /// let prefix = Utf8PowerShellComponent::try_from("Env:").unwrap();
+    /// assert!(prefix.is_prefix());
+    ///
+    /// let root_dir = Utf8PowerShellComponent::try_from(r"\").unwrap();
+    /// assert!(!root_dir.is_prefix());
+    /// ```
+    pub fn is_prefix(&self) -> bool {
+        matches!(self, Self::Prefix(_))
+    }
+}
+
+impl<'a> Utf8Component<'a> for Utf8PowerShellComponent<'a> {
+    /// Extracts the underlying [`str`] slice
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Utf8Component, Utf8PowerShellPath};
+    ///
+    /// let path = Utf8PowerShellPath::new(r"HKLM:\Software\Vendor\..\Tool");
+    /// let components: Vec<_> = path.components().map(|comp| comp.as_str()).collect();
+    /// assert_eq!(&components, &["HKLM:", r"\", "Software", "Vendor", "..", "Tool"]);
+    /// ```
+    fn as_str(&self) -> &'a str {
+        match self {
+            Self::Prefix(prefix) => prefix,
+            Self::RootDir => SEPARATOR_STR,
+            Self::CurDir => CURRENT_DIR_STR,
+            Self::ParentDir => PARENT_DIR_STR,
+            Self::Normal(path) => path,
+        }
+    }
+
+    /// Returns true if is the root dir component or a prefix that is not just a drive, as a drive
+    /// like `C:` needs to be followed by a root dir for the path to be absolute
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Utf8Component, Utf8PowerShellComponent};
+    /// use std::convert::TryFrom;
+    ///
+    /// let root_dir = Utf8PowerShellComponent::try_from(r"\").unwrap();
+    /// assert!(root_dir.is_root());
+    ///
+    /// let prefix = Utf8PowerShellComponent::try_from("Registry::").unwrap();
+    /// assert!(prefix.is_root());
+    ///
+    /// let drive = Utf8PowerShellComponent::try_from("HKLM:").unwrap();
+    /// assert!(!drive.is_root());
+    ///
+    /// let normal = Utf8PowerShellComponent::try_from("file.txt").unwrap();
+    /// assert!(!normal.is_root());
+    /// ```
+    fn is_root(&self) -> bool {
+        match self {
+            Self::RootDir => true,
+            Self::Prefix(prefix) => PowerShellComponent::Prefix(prefix.as_bytes()).is_root(),
+            _ => false,
+        }
+    }
+
+    /// Returns true if is a normal component
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Utf8Component, Utf8PowerShellComponent};
+    /// use std::convert::TryFrom;
+    ///
+    /// let normal = Utf8PowerShellComponent::try_from("file.txt").unwrap();
+    /// assert!(normal.is_normal());
+    ///
+    /// let root_dir = Utf8PowerShellComponent::try_from(r"\").unwrap();
+    /// assert!(!root_dir.is_normal());
+    /// ```
+    fn is_normal(&self) -> bool {
+        matches!(self, Self::Normal(_))
+    }
+
+    /// Returns true if is a parent directory component
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Utf8Component, Utf8PowerShellComponent};
+    /// use std::convert::TryFrom;
+    ///
+    /// let parent = Utf8PowerShellComponent::try_from("..").unwrap();
+    /// assert!(parent.is_parent());
+    ///
+    /// let root_dir = Utf8PowerShellComponent::try_from(r"\").unwrap();
+    /// assert!(!root_dir.is_parent());
+    /// ```
+    fn is_parent(&self) -> bool {
+        matches!(self, Self::ParentDir)
+    }
+
+    /// Returns true if is the current directory component
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Utf8Component, Utf8PowerShellComponent};
+    /// use std::convert::TryFrom;
+    ///
+    /// let current = Utf8PowerShellComponent::try_from(".").unwrap();
+    /// assert!(current.is_current());
+    ///
+    /// let root_dir = Utf8PowerShellComponent::try_from(r"\").unwrap();
+    /// assert!(!root_dir.is_current());
+    /// ```
+    fn is_current(&self) -> bool {
+        matches!(self, Self::CurDir)
+    }
+
+    /// Returns true if this component is valid.
+    ///
+    /// A component can only be invalid if it represents a normal component with characters that
+    /// are disallowed by the encoding.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Utf8Component, Utf8PowerShellComponent};
+    ///
+    /// assert!(Utf8PowerShellComponent::Prefix("HKLM:").is_valid());
+    /// assert!(Utf8PowerShellComponent::RootDir.is_valid());
+    /// assert!(Utf8PowerShellComponent::ParentDir.is_valid());
+    /// assert!(Utf8PowerShellComponent::CurDir.is_valid());
+    /// assert!(Utf8PowerShellComponent::Normal("abc").is_valid());
+    /// assert!(!Utf8PowerShellComponent::Normal("\0").is_valid());
+    /// ```
+    fn is_valid(&self) -> bool {
+        match self {
+            Self::Prefix(_) | Self::RootDir | Self::ParentDir | Self::CurDir => true,
+            Self::Normal(s) => !s.chars().any(|c| DISALLOWED_FILENAME_CHARS.contains(&c)),
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.as_str().len()
+    }
+
+    /// Returns the root directory component.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Utf8Component, Utf8PowerShellComponent};
+    ///
+    /// assert_eq!(Utf8PowerShellComponent::root(), Utf8PowerShellComponent::RootDir);
+    /// ```
+    fn root() -> Self {
+        Self::RootDir
+    }
+
+    /// Returns the parent directory component.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Utf8Component, Utf8PowerShellComponent};
+    ///
+    /// assert_eq!(Utf8PowerShellComponent::parent(), Utf8PowerShellComponent::ParentDir);
+    /// ```
+    fn parent() -> Self {
+        Self::ParentDir
+    }
+
+    /// Returns the current directory component.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Utf8Component, Utf8PowerShellComponent};
+    ///
+    /// assert_eq!(Utf8PowerShellComponent::current(), Utf8PowerShellComponent::CurDir);
+    /// ```
+    fn current() -> Self {
+        Self::CurDir
+    }
+}
+
+impl fmt::Display for Utf8PowerShellComponent<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl AsRef<[u8]> for Utf8PowerShellComponent<'_> {
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        self.as_str().as_bytes()
+    }
+}
+
+impl AsRef<str> for Utf8PowerShellComponent<'_> {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<T> AsRef<Utf8Path<T>> for Utf8PowerShellComponent<'_>
+where
+    T: for<'enc> Utf8Encoding<'enc>,
+{
+    #[inline]
+    fn as_ref(&self) -> &Utf8Path<T> {
+        Utf8Path::new(self.as_str())
+    }
+}
+
+impl<'a> TryFrom<PowerShellComponent<'a>> for Utf8PowerShellComponent<'a> {
+    type Error = Utf8Error;
+
+    #[inline]
+    fn try_from(component: PowerShellComponent<'a>) -> Result<Self, Self::Error> {
+        Self::from_utf8(&component)
+    }
+}
+
+impl<'a> TryFrom<&'a str> for Utf8PowerShellComponent<'a> {
+    type Error = ParseError;
+
+    /// Parses the `str` slice into a [`Utf8PowerShellComponent`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::Utf8PowerShellComponent;
+    /// use std::convert::TryFrom;
+    ///
+    /// // Supports parsing standard PowerShell path components
+    /// assert_eq!(Utf8PowerShellComponent::try_from("Env:"), Ok(Utf8PowerShellComponent::Prefix("Env:")));
+    /// assert_eq!(Utf8PowerShellComponent::try_from(r"\"), Ok(Utf8PowerShellComponent::RootDir));
+    /// assert_eq!(Utf8PowerShellComponent::try_from("."), Ok(Utf8PowerShellComponent::CurDir));
+    /// assert_eq!(Utf8PowerShellComponent::try_from(".."), Ok(Utf8PowerShellComponent::ParentDir));
+    /// assert_eq!(Utf8PowerShellComponent::try_from("file.txt"), Ok(Utf8PowerShellComponent::Normal("file.txt")));
+    /// assert_eq!(Utf8PowerShellComponent::try_from(r"dir\"), Ok(Utf8PowerShellComponent::Normal("dir")));
+    ///
+    /// // Parsing more than one component will fail
+    /// assert!(Utf8PowerShellComponent::try_from(r"Env:\PATH").is_err());
+    /// ```
+    fn try_from(path: &'a str) -> Result<Self, Self::Error> {
+        let mut components = Utf8PowerShellComponents::new(path);
+
+        let component = components.next().ok_or("no component found")?;
+        if components.next().is_some() {
+            return Err("found more than one component");
+        }
+
+        Ok(component)
+    }
+}
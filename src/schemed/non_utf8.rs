@@ -0,0 +1,311 @@
+mod components;
+
+use core::fmt;
+use core::hash::Hasher;
+
+pub use components::*;
+
+use super::constants::*;
+use crate::common::CheckedPathError;
+use crate::no_std_compat::*;
+use crate::{private, Component, Components, Encoding, Path, PathBuf};
+
+/// Represents a schemed [`Path`]
+pub type SchemedPath = Path<SchemedEncoding>;
+
+/// Represents a schemed [`PathBuf`]
+pub type SchemedPathBuf = PathBuf<SchemedEncoding>;
+
+/// Represents a schemed [`Encoding`] for paths within URIs of the form `scheme://authority/path`,
+/// such as `hdfs://namenode:8020/warehouse`, `gs://bucket/logs`, `s3://bucket/key`, or
+/// `abfs://container@account.dfs.core.windows.net/data`.
+///
+/// The `scheme://authority` part is a single prefix component and the path part uses the same
+/// rules as [`UnixEncoding`](crate::UnixEncoding). No query, fragment, or percent-decoding is
+/// involved, so any byte other than `/` is part of a component as written. A path without a
+/// scheme is treated as a plain relative (or rooted) path that can be joined onto a schemed one.
+///
+/// # Examples
+///
+/// ```
+/// use typed_path::SchemedPath;
+///
+/// let path = SchemedPath::new("hdfs://namenode:8020/warehouse/events/day=1");
+/// assert_eq!(path.scheme(), Some(b"hdfs".as_slice()));
+/// assert_eq!(path.authority(), Some(b"namenode:8020".as_slice()));
+/// assert_eq!(path.file_name(), Some(b"day=1".as_slice()));
+///
+/// let parent = path.parent().unwrap();
+/// assert_eq!(parent.as_bytes(), b"hdfs://namenode:8020/warehouse/events");
+/// assert_eq!(
+///     parent.join("day=2").as_bytes(),
+///     b"hdfs://namenode:8020/warehouse/events/day=2",
+/// );
+///
+/// // Rooted paths replace only the path part
+/// assert_eq!(path.join("/tmp").as_bytes(), b"hdfs://namenode:8020/tmp");
+/// ```
+#[derive(Copy, Clone)]
+pub struct SchemedEncoding;
+
+impl private::Sealed for SchemedEncoding {}
+
+impl<'a> Encoding<'a> for SchemedEncoding {
+    type Components = SchemedComponents<'a>;
+
+    const NAME: &'static str = "schemed";
+    const SEPARATOR: char = SEPARATOR;
+    const ALT_SEPARATORS: &'static [char] = &[];
+    const CASE_SENSITIVE: bool = true;
+    const DISALLOWED_FILENAME_BYTES: &'static [u8] = &DISALLOWED_FILENAME_BYTES;
+
+    fn label() -> &'static str {
+        "schemed"
+    }
+
+    fn components(path: &'a [u8]) -> Self::Components {
+        SchemedComponents::new(path)
+    }
+
+    fn hash<H: Hasher>(path: &[u8], h: &mut H) {
+        let mut bytes_hashed = 0;
+
+        // Hash what equality compares, which are the components once normalized by parsing
+        for component in Self::components(path) {
+            let to_hash = component.as_bytes();
+            h.write(to_hash);
+            bytes_hashed += to_hash.len();
+        }
+
+        h.write_usize(bytes_hashed);
+    }
+
+    fn push(current_path: &mut Vec<u8>, path: &[u8]) {
+        if path.is_empty() {
+            return;
+        }
+
+        // A path with its own prefix replaces entirely, while a rooted path only replaces the
+        // path part and keeps the current prefix
+        //
+        // Otherwise, if our current path is not empty, we will append the provided path
+        // to the end with a separator inbetween
+        if Self::components(path).is_absolute() {
+            current_path.clear();
+        } else if path.first() == Some(&(SEPARATOR as u8)) {
+            current_path.truncate(prefix_len(current_path).unwrap_or(0));
+        } else if !current_path.is_empty() && !current_path.ends_with(SEPARATOR_STR.as_bytes()) {
+            current_path.push(SEPARATOR as u8);
+        }
+
+        current_path.extend_from_slice(path);
+    }
+
+    fn push_checked(current_path: &mut Vec<u8>, path: &[u8]) -> Result<(), CheckedPathError> {
+        // As we scan through path components, we maintain a count of normal components that
+        // have not been popped off as a result of a parent component. If we ever reach a
+        // parent component without any preceding normal components remaining, this violates
+        // pushing onto our path and represents a path traversal attack.
+        let mut normal_cnt = 0;
+        for component in SchemedPath::new(path).components() {
+            match component {
+                SchemedComponent::Prefix(_) => return Err(CheckedPathError::UnexpectedPrefix),
+                SchemedComponent::RootDir => return Err(CheckedPathError::UnexpectedRoot),
+                SchemedComponent::ParentDir if normal_cnt == 0 => {
+                    return Err(CheckedPathError::PathTraversalAttack)
+                }
+                SchemedComponent::ParentDir => normal_cnt -= 1,
+                SchemedComponent::Normal(bytes) => {
+                    for b in bytes {
+                        if DISALLOWED_FILENAME_BYTES.contains(b) {
+                            return Err(CheckedPathError::InvalidFilename);
+                        }
+                    }
+                    normal_cnt += 1;
+                }
+                _ => continue,
+            }
+        }
+
+        Self::push(current_path, path);
+        Ok(())
+    }
+}
+
+impl fmt::Debug for SchemedEncoding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SchemedEncoding").finish()
+    }
+}
+
+impl fmt::Display for SchemedEncoding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "SchemedEncoding")
+    }
+}
+
+impl<T> Path<T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    /// Returns true if the encoding for the path is schemed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{SchemedPath, UnixPath};
+    ///
+    /// assert!(SchemedPath::new("s3://bucket/key").has_schemed_encoding());
+    /// assert!(!UnixPath::new("/some/path").has_schemed_encoding());
+    /// ```
+    pub fn has_schemed_encoding(&self) -> bool {
+        T::label() == SchemedEncoding::label()
+    }
+
+    /// Creates an owned [`PathBuf`] like `self` but using [`SchemedEncoding`].
+    ///
+    /// See [`Path::with_encoding`] for more information.
+    pub fn with_schemed_encoding(&self) -> PathBuf<SchemedEncoding> {
+        self.with_encoding()
+    }
+
+    /// Creates an owned [`PathBuf`] like `self` but using [`SchemedEncoding`], ensuring it is a
+    /// valid schemed path.
+    ///
+    /// See [`Path::with_encoding_checked`] for more information.
+    pub fn with_schemed_encoding_checked(
+        &self,
+    ) -> Result<PathBuf<SchemedEncoding>, CheckedPathError> {
+        self.with_encoding_checked()
+    }
+}
+
+impl SchemedPath {
+    /// Returns the scheme of the path, without the trailing `://`, or `None` if the path does not
+    /// start with a `scheme://authority` prefix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::SchemedPath;
+    ///
+    /// assert_eq!(SchemedPath::new("gs://bucket/logs").scheme(), Some(b"gs".as_slice()));
+    /// assert_eq!(SchemedPath::new("file:///tmp").scheme(), Some(b"file".as_slice()));
+    /// assert_eq!(SchemedPath::new("/logs").scheme(), None);
+    /// ```
+    pub fn scheme(&self) -> Option<&[u8]> {
+        let prefix = self.schemed_prefix()?;
+        let end = prefix
+            .windows(SCHEME_DELIMITER.len())
+            .position(|w| w == SCHEME_DELIMITER)?;
+        Some(&prefix[..end])
+    }
+
+    /// Returns the authority of the path, which is everything between the `://` and the start of
+    /// the path part, or `None` if the path does not start with a `scheme://authority` prefix.
+    ///
+    /// The authority is returned as written, including any user information or port.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::SchemedPath;
+    ///
+    /// let path = SchemedPath::new("abfs://container@account.dfs.core.windows.net/data");
+    /// assert_eq!(
+    ///     path.authority(),
+    ///     Some(b"container@account.dfs.core.windows.net".as_slice()),
+    /// );
+    ///
+    /// // An authority can be empty
+    /// assert_eq!(SchemedPath::new("file:///tmp").authority(), Some(b"".as_slice()));
+    /// assert_eq!(SchemedPath::new("data/part-0").authority(), None);
+    /// ```
+    pub fn authority(&self) -> Option<&[u8]> {
+        let prefix = self.schemed_prefix()?;
+        let scheme_len = self.scheme()?.len();
+        Some(&prefix[scheme_len + SCHEME_DELIMITER.len()..])
+    }
+
+    fn schemed_prefix(&self) -> Option<&[u8]> {
+        prefix_len(self.as_bytes()).map(|len| &self.as_bytes()[..len])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn push_should_replace_current_path_with_provided_path_if_provided_path_has_a_prefix() {
+        let mut current_path = vec![];
+        SchemedEncoding::push(&mut current_path, b"s3://bucket/key");
+        assert_eq!(current_path, b"s3://bucket/key");
+
+        let mut current_path = b"gs://other/path".to_vec();
+        SchemedEncoding::push(&mut current_path, b"s3://bucket/key");
+        assert_eq!(current_path, b"s3://bucket/key");
+
+        let mut current_path = b"some/path".to_vec();
+        SchemedEncoding::push(&mut current_path, b"s3://bucket");
+        assert_eq!(current_path, b"s3://bucket");
+    }
+
+    #[test]
+    fn push_should_replace_only_the_path_part_if_provided_path_is_rooted() {
+        let mut current_path = b"s3://bucket/some/path".to_vec();
+        SchemedEncoding::push(&mut current_path, b"/abc");
+        assert_eq!(current_path, b"s3://bucket/abc");
+
+        let mut current_path = b"s3://bucket".to_vec();
+        SchemedEncoding::push(&mut current_path, b"/abc");
+        assert_eq!(current_path, b"s3://bucket/abc");
+
+        let mut current_path = b"/some/path".to_vec();
+        SchemedEncoding::push(&mut current_path, b"/abc");
+        assert_eq!(current_path, b"/abc");
+    }
+
+    #[test]
+    fn push_should_append_path_to_current_path_with_a_separator_if_provided_path_is_relative() {
+        let mut current_path = vec![];
+        SchemedEncoding::push(&mut current_path, b"abc");
+        assert_eq!(current_path, b"abc");
+
+        let mut current_path = b"s3://bucket".to_vec();
+        SchemedEncoding::push(&mut current_path, b"abc");
+        assert_eq!(current_path, b"s3://bucket/abc");
+
+        let mut current_path = b"s3://bucket/some/path/".to_vec();
+        SchemedEncoding::push(&mut current_path, b"abc");
+        assert_eq!(current_path, b"s3://bucket/some/path/abc");
+    }
+
+    #[test]
+    fn push_checked_should_fail_if_providing_a_prefix_root_or_escaping_path() {
+        let mut current_path = b"s3://bucket/some/path".to_vec();
+        assert_eq!(
+            SchemedEncoding::push_checked(&mut current_path, b"gs://bucket"),
+            Err(CheckedPathError::UnexpectedPrefix)
+        );
+        assert_eq!(
+            SchemedEncoding::push_checked(&mut current_path, b"/abc"),
+            Err(CheckedPathError::UnexpectedRoot)
+        );
+        assert_eq!(
+            SchemedEncoding::push_checked(&mut current_path, b"abc/../.."),
+            Err(CheckedPathError::PathTraversalAttack)
+        );
+        assert_eq!(
+            SchemedEncoding::push_checked(&mut current_path, b"inva\0lid"),
+            Err(CheckedPathError::InvalidFilename)
+        );
+        assert_eq!(current_path, b"s3://bucket/some/path");
+
+        assert_eq!(
+            SchemedEncoding::push_checked(&mut current_path, b"abc/../def"),
+            Ok(())
+        );
+        assert_eq!(current_path, b"s3://bucket/some/path/abc/../def");
+    }
+}
@@ -0,0 +1,255 @@
+mod component;
+
+use core::{cmp, fmt, iter};
+
+pub use component::*;
+
+use crate::common::remaining_path;
+use crate::schemed::constants::SCHEME_DELIMITER;
+use crate::unix::UnixComponents;
+use crate::{private, Components, Encoding, Path};
+
+/// Iterator over the [`SchemedComponent`]s of a schemed path.
+///
+/// The `scheme://authority` part is yielded first as a single [`SchemedComponent::Prefix`],
+/// followed by the components of the path part using the same rules as
+/// [`UnixEncoding`](crate::UnixEncoding).
+#[derive(Clone)]
+pub struct SchemedComponents<'a> {
+    path: &'a [u8],
+
+    /// Prefix that has yet to be yielded, which always starts the remaining path
+    prefix: Option<&'a [u8]>,
+    inner: UnixComponents<'a>,
+}
+
+impl<'a> SchemedComponents<'a> {
+    pub(crate) fn new(path: &'a [u8]) -> Self {
+        let (prefix, rest) = match prefix_len(path) {
+            Some(len) => (Some(&path[..len]), &path[len..]),
+            None => (None, path),
+        };
+
+        Self {
+            path,
+            prefix,
+            inner: UnixComponents::new(rest),
+        }
+    }
+
+    /// Extracts a slice corresponding to the portion of the path remaining for iteration.
+    ///
+    /// As with `std::path::Components::as_path`, trailing separators and current directory
+    /// components that will not be yielded are left out.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Path, SchemedEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// let mut components = Path::<SchemedEncoding>::new("s3://bucket/data/part-0").components();
+    /// components.next();
+    /// components.next();
+    ///
+    /// assert_eq!(Path::<SchemedEncoding>::new("data/part-0"), components.as_path());
+    /// ```
+    pub fn as_path<T>(&self) -> &'a Path<T>
+    where
+        T: for<'enc> Encoding<'enc>,
+    {
+        Path::new(remaining_path(self))
+    }
+}
+
+/// Returns the length of the `scheme://authority` prefix at the start of `path`, if it has one.
+///
+/// The scheme follows [RFC 3986](https://www.rfc-editor.org/rfc/rfc3986#section-3.1), starting
+/// with a letter followed by letters, digits, `+`, `-`, or `.`. The authority is everything up to
+/// the next separator and may be empty, as in `file:///tmp`.
+pub(crate) fn prefix_len(path: &[u8]) -> Option<usize> {
+    let scheme_len = path
+        .iter()
+        .position(|b| !(b.is_ascii_alphanumeric() || matches!(b, b'+' | b'-' | b'.')))?;
+
+    if scheme_len == 0
+        || !path[0].is_ascii_alphabetic()
+        || !path[scheme_len..].starts_with(SCHEME_DELIMITER)
+    {
+        return None;
+    }
+
+    let authority_start = scheme_len + SCHEME_DELIMITER.len();
+    let authority_len = path[authority_start..]
+        .iter()
+        .position(|b| *b == b'/')
+        .unwrap_or(path.len() - authority_start);
+
+    Some(authority_start + authority_len)
+}
+
+impl private::Sealed for SchemedComponents<'_> {}
+
+impl<'a> Components<'a> for SchemedComponents<'a> {
+    type Component = SchemedComponent<'a>;
+
+    fn as_bytes(&self) -> &'a [u8] {
+        let rest = self.inner.as_bytes();
+        match self.prefix {
+            // Nothing has been taken from the front of the path part while the prefix remains
+            Some(prefix) => &self.path[..prefix.len() + rest.len()],
+            None => rest,
+        }
+    }
+
+    /// Returns true if the path has a `scheme://authority` prefix, which always refers to a
+    /// location independent of any other path
+    fn is_absolute(&self) -> bool {
+        self.prefix.is_some()
+    }
+
+    fn has_root(&self) -> bool {
+        self.prefix.is_some() || self.inner.has_root()
+    }
+}
+
+impl AsRef<[u8]> for SchemedComponents<'_> {
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl<T> AsRef<Path<T>> for SchemedComponents<'_>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    #[inline]
+    fn as_ref(&self) -> &Path<T> {
+        Path::new(self.as_bytes())
+    }
+}
+
+impl<'a> fmt::Debug for SchemedComponents<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        struct DebugHelper<'a>(SchemedComponents<'a>);
+
+        impl<'a> fmt::Debug for DebugHelper<'a> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.debug_list().entries(self.0.clone()).finish()
+            }
+        }
+
+        f.debug_tuple("SchemedComponents")
+            .field(&DebugHelper(self.clone()))
+            .finish()
+    }
+}
+
+impl<'a> Iterator for SchemedComponents<'a> {
+    type Item = <Self as Components<'a>>::Component;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.prefix.take() {
+            Some(prefix) => Some(SchemedComponent::Prefix(prefix)),
+            None => self.inner.next().map(SchemedComponent::from),
+        }
+    }
+}
+
+impl<'a> DoubleEndedIterator for SchemedComponents<'a> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        match self.inner.next_back() {
+            Some(component) => Some(SchemedComponent::from(component)),
+            None => self.prefix.take().map(SchemedComponent::Prefix),
+        }
+    }
+}
+
+impl<'a> iter::FusedIterator for SchemedComponents<'a> {}
+
+impl<'a> cmp::PartialEq for SchemedComponents<'a> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.clone().eq(other.clone())
+    }
+}
+
+impl<'a> cmp::Eq for SchemedComponents<'a> {}
+
+impl<'a> cmp::PartialOrd for SchemedComponents<'a> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<'a> cmp::Ord for SchemedComponents<'a> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> cmp::Ordering {
+        self.clone().cmp(other.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::no_std_compat::*;
+
+    #[test]
+    fn prefix_len_should_only_match_a_valid_scheme_followed_by_the_delimiter() {
+        assert_eq!(prefix_len(b"s3://bucket/key"), Some(11));
+        assert_eq!(prefix_len(b"hdfs://namenode:8020"), Some(20));
+        assert_eq!(prefix_len(b"file:///tmp"), Some(7));
+        assert_eq!(prefix_len(b"git+ssh://host"), Some(14));
+
+        assert_eq!(prefix_len(b"/s3://bucket"), None);
+        assert_eq!(prefix_len(b"3s://bucket"), None);
+        assert_eq!(prefix_len(b"s3:/bucket"), None);
+        assert_eq!(prefix_len(b"data/part-0"), None);
+        assert_eq!(prefix_len(b""), None);
+    }
+
+    #[test]
+    fn should_yield_prefix_then_path_part_from_either_end() {
+        let path = b"gs://bucket//a/./b/";
+        let expected = [
+            SchemedComponent::Prefix(b"gs://bucket"),
+            SchemedComponent::RootDir,
+            SchemedComponent::Normal(b"a"),
+            SchemedComponent::Normal(b"b"),
+        ];
+
+        assert_eq!(SchemedComponents::new(path).collect::<Vec<_>>(), expected);
+
+        let mut reversed = SchemedComponents::new(path).rev().collect::<Vec<_>>();
+        reversed.reverse();
+        assert_eq!(reversed, expected);
+
+        let mut components = SchemedComponents::new(path);
+        assert_eq!(components.next_back(), Some(SchemedComponent::Normal(b"b")));
+        assert_eq!(components.as_bytes(), b"gs://bucket//a");
+        assert_eq!(
+            components.next(),
+            Some(SchemedComponent::Prefix(b"gs://bucket"))
+        );
+        assert_eq!(components.as_bytes(), b"//a");
+    }
+
+    #[test]
+    fn should_treat_paths_without_a_scheme_like_unix_paths() {
+        assert_eq!(
+            SchemedComponents::new(b"./a/../b").collect::<Vec<_>>(),
+            [
+                SchemedComponent::CurDir,
+                SchemedComponent::Normal(b"a"),
+                SchemedComponent::ParentDir,
+                SchemedComponent::Normal(b"b"),
+            ]
+        );
+
+        assert!(SchemedComponents::new(b"/a").has_root());
+        assert!(!SchemedComponents::new(b"/a").is_absolute());
+        assert!(SchemedComponents::new(b"s3://bucket").is_absolute());
+    }
+}
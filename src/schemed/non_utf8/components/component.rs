@@ -0,0 +1,298 @@
+use crate::schemed::constants::{
+    CURRENT_DIR, DISALLOWED_FILENAME_BYTES, PARENT_DIR, SEPARATOR_STR,
+};
+use crate::schemed::SchemedComponents;
+use crate::unix::UnixComponent;
+use crate::{private, Component, Encoding, ParseError, Path};
+
+/// Byte slice version of [`std::path::Component`] that represents a schemed component
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum SchemedComponent<'a> {
+    /// The `scheme://authority` at the start of the path, such as `s3://bucket`
+    Prefix(&'a [u8]),
+    RootDir,
+    CurDir,
+    ParentDir,
+    Normal(&'a [u8]),
+}
+
+impl private::Sealed for SchemedComponent<'_> {}
+
+impl<'a> SchemedComponent<'a> {
+    /// Returns path representing this specific component
+    pub fn as_path<T>(&self) -> &Path<T>
+    where
+        T: for<'enc> Encoding<'enc>,
+    {
+        Path::new(self.as_bytes())
+    }
+
+    /// Returns true if represents a `scheme://authority` prefix
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::SchemedComponent;
+    /// use std::convert::TryFrom;
+    ///
+    /// let prefix = SchemedComponent::try_from("hdfs://namenode:8020").unwrap();
+    /// assert!(prefix.is_prefix());
+    ///
+    /// let root_dir = SchemedComponent::try_from("/").unwrap();
+    /// assert!(!root_dir.is_prefix());
+    /// ```
+    pub fn is_prefix(&self) -> bool {
+        matches!(self, Self::Prefix(_))
+    }
+}
+
+impl<'a> Component<'a> for SchemedComponent<'a> {
+    /// Extracts the underlying [`[u8]`] slice
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Component, SchemedPath};
+    ///
+    /// let path = SchemedPath::new(b"s3://bucket/tmp/foo/../bar.txt");
+    /// let components: Vec<_> = path.components().map(|comp| comp.as_bytes()).collect();
+    /// assert_eq!(&components, &[
+    ///     b"s3://bucket".as_slice(),
+    ///     b"/".as_slice(),
+    ///     b"tmp".as_slice(),
+    ///     b"foo".as_slice(),
+    ///     b"..".as_slice(),
+    ///     b"bar.txt".as_slice(),
+    /// ]);
+    /// ```
+    fn as_bytes(&self) -> &'a [u8] {
+        match self {
+            Self::Prefix(prefix) => prefix,
+            Self::RootDir => SEPARATOR_STR.as_bytes(),
+            Self::CurDir => CURRENT_DIR,
+            Self::ParentDir => PARENT_DIR,
+            Self::Normal(path) => path,
+        }
+    }
+
+    /// Returns true if is the root dir component or a `scheme://authority` prefix, which always
+    /// points at the root of the authority
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Component, SchemedComponent};
+    /// use std::convert::TryFrom;
+    ///
+    /// let root_dir = SchemedComponent::try_from(b"/").unwrap();
+    /// assert!(root_dir.is_root());
+    ///
+    /// let prefix = SchemedComponent::try_from(b"gs://bucket").unwrap();
+    /// assert!(prefix.is_root());
+    ///
+    /// let normal = SchemedComponent::try_from(b"file.txt").unwrap();
+    /// assert!(!normal.is_root());
+    /// ```
+    fn is_root(&self) -> bool {
+        matches!(self, Self::Prefix(_) | Self::RootDir)
+    }
+
+    /// Returns true if is a normal component
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Component, SchemedComponent};
+    /// use std::convert::TryFrom;
+    ///
+    /// let normal = SchemedComponent::try_from(b"file.txt").unwrap();
+    /// assert!(normal.is_normal());
+    ///
+    /// let root_dir = SchemedComponent::try_from(b"/").unwrap();
+    /// assert!(!root_dir.is_normal());
+    /// ```
+    fn is_normal(&self) -> bool {
+        matches!(self, Self::Normal(_))
+    }
+
+    /// Returns true if is a parent directory component
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Component, SchemedComponent};
+    /// use std::convert::TryFrom;
+    ///
+    /// let parent = SchemedComponent::try_from("..").unwrap();
+    /// assert!(parent.is_parent());
+    ///
+    /// let root_dir = SchemedComponent::try_from("/").unwrap();
+    /// assert!(!root_dir.is_parent());
+    /// ```
+    fn is_parent(&self) -> bool {
+        matches!(self, Self::ParentDir)
+    }
+
+    /// Returns true if is the current directory component
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Component, SchemedComponent};
+    /// use std::convert::TryFrom;
+    ///
+    /// let current = SchemedComponent::try_from(".").unwrap();
+    /// assert!(current.is_current());
+    ///
+    /// let root_dir = SchemedComponent::try_from("/").unwrap();
+    /// assert!(!root_dir.is_current());
+    /// ```
+    fn is_current(&self) -> bool {
+        matches!(self, Self::CurDir)
+    }
+
+    /// Returns true if this component is valid.
+    ///
+    /// A component can only be invalid if it represents a normal component with bytes that are
+    /// disallowed by the encoding.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Component, SchemedComponent};
+    ///
+    /// assert!(SchemedComponent::Prefix(b"s3://bucket").is_valid());
+    /// assert!(SchemedComponent::RootDir.is_valid());
+    /// assert!(SchemedComponent::ParentDir.is_valid());
+    /// assert!(SchemedComponent::CurDir.is_valid());
+    /// assert!(SchemedComponent::Normal(b"abc").is_valid());
+    /// assert!(!SchemedComponent::Normal(b"\0").is_valid());
+    /// ```
+    fn is_valid(&self) -> bool {
+        match self {
+            Self::Prefix(_) | Self::RootDir | Self::ParentDir | Self::CurDir => true,
+            Self::Normal(bytes) => !bytes.iter().any(|b| DISALLOWED_FILENAME_BYTES.contains(b)),
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.as_bytes().len()
+    }
+
+    /// Returns the root directory component.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Component, SchemedComponent};
+    ///
+    /// assert_eq!(SchemedComponent::root(), SchemedComponent::RootDir);
+    /// ```
+    fn root() -> Self {
+        Self::RootDir
+    }
+
+    /// Returns the parent directory component.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Component, SchemedComponent};
+    ///
+    /// assert_eq!(SchemedComponent::parent(), SchemedComponent::ParentDir);
+    /// ```
+    fn parent() -> Self {
+        Self::ParentDir
+    }
+
+    /// Returns the current directory component.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Component, SchemedComponent};
+    ///
+    /// assert_eq!(SchemedComponent::current(), SchemedComponent::CurDir);
+    /// ```
+    fn current() -> Self {
+        Self::CurDir
+    }
+}
+
+impl<'a> From<UnixComponent<'a>> for SchemedComponent<'a> {
+    fn from(component: UnixComponent<'a>) -> Self {
+        match component {
+            UnixComponent::RootDir => Self::RootDir,
+            UnixComponent::CurDir => Self::CurDir,
+            UnixComponent::ParentDir => Self::ParentDir,
+            UnixComponent::Normal(bytes) => Self::Normal(bytes),
+        }
+    }
+}
+
+impl AsRef<[u8]> for SchemedComponent<'_> {
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl<T> AsRef<Path<T>> for SchemedComponent<'_>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    #[inline]
+    fn as_ref(&self) -> &Path<T> {
+        Path::new(self.as_bytes())
+    }
+}
+
+impl<'a> TryFrom<&'a [u8]> for SchemedComponent<'a> {
+    type Error = ParseError;
+
+    /// Parses the byte slice into a [`SchemedComponent`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::SchemedComponent;
+    /// use std::convert::TryFrom;
+    ///
+    /// // Supports parsing standard schemed path components
+    /// assert_eq!(SchemedComponent::try_from(b"s3://bucket"), Ok(SchemedComponent::Prefix(b"s3://bucket")));
+    /// assert_eq!(SchemedComponent::try_from(b"/"), Ok(SchemedComponent::RootDir));
+    /// assert_eq!(SchemedComponent::try_from(b"."), Ok(SchemedComponent::CurDir));
+    /// assert_eq!(SchemedComponent::try_from(b".."), Ok(SchemedComponent::ParentDir));
+    /// assert_eq!(SchemedComponent::try_from(b"file.txt"), Ok(SchemedComponent::Normal(b"file.txt")));
+    /// assert_eq!(SchemedComponent::try_from(b"dir/"), Ok(SchemedComponent::Normal(b"dir")));
+    ///
+    /// // Parsing more than one component will fail
+    /// assert!(SchemedComponent::try_from(b"s3://bucket/file").is_err());
+    /// ```
+    fn try_from(path: &'a [u8]) -> Result<Self, Self::Error> {
+        let mut components = SchemedComponents::new(path);
+
+        let component = components.next().ok_or("no component found")?;
+        if components.next().is_some() {
+            return Err("found more than one component");
+        }
+
+        Ok(component)
+    }
+}
+
+impl<'a, const N: usize> TryFrom<&'a [u8; N]> for SchemedComponent<'a> {
+    type Error = ParseError;
+
+    fn try_from(path: &'a [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from(path.as_slice())
+    }
+}
+
+impl<'a> TryFrom<&'a str> for SchemedComponent<'a> {
+    type Error = ParseError;
+
+    fn try_from(path: &'a str) -> Result<Self, Self::Error> {
+        Self::try_from(path.as_bytes())
+    }
+}
@@ -0,0 +1,177 @@
+mod components;
+
+use core::fmt;
+use core::hash::Hasher;
+
+pub use components::*;
+
+use crate::common::CheckedPathError;
+use crate::no_std_compat::*;
+use crate::{private, Encoding, SchemedEncoding, SchemedPath, Utf8Encoding, Utf8Path, Utf8PathBuf};
+
+/// Represents a schemed [`Utf8Path`]
+pub type Utf8SchemedPath = Utf8Path<Utf8SchemedEncoding>;
+
+/// Represents a schemed [`Utf8PathBuf`]
+pub type Utf8SchemedPathBuf = Utf8PathBuf<Utf8SchemedEncoding>;
+
+/// Represents a schemed [`Utf8Encoding`]
+///
+/// See [`SchemedEncoding`] for more details.
+#[derive(Copy, Clone)]
+pub struct Utf8SchemedEncoding;
+
+impl private::Sealed for Utf8SchemedEncoding {}
+
+impl<'a> Utf8Encoding<'a> for Utf8SchemedEncoding {
+    type Components = Utf8SchemedComponents<'a>;
+
+    const NAME: &'static str = <SchemedEncoding as Encoding<'a>>::NAME;
+    const SEPARATOR: char = <SchemedEncoding as Encoding<'a>>::SEPARATOR;
+    const ALT_SEPARATORS: &'static [char] = <SchemedEncoding as Encoding<'a>>::ALT_SEPARATORS;
+    const CASE_SENSITIVE: bool = <SchemedEncoding as Encoding<'a>>::CASE_SENSITIVE;
+    const DISALLOWED_FILENAME_BYTES: &'static [u8] =
+        <SchemedEncoding as Encoding<'a>>::DISALLOWED_FILENAME_BYTES;
+
+    fn label() -> &'static str {
+        "schemed"
+    }
+
+    fn components(path: &'a str) -> Self::Components {
+        Utf8SchemedComponents::new(path)
+    }
+
+    fn hash<H: Hasher>(path: &str, h: &mut H) {
+        SchemedEncoding::hash(path.as_bytes(), h);
+    }
+
+    fn push(current_path: &mut String, path: &str) {
+        unsafe {
+            SchemedEncoding::push(current_path.as_mut_vec(), path.as_bytes());
+        }
+    }
+
+    fn push_checked(current_path: &mut String, path: &str) -> Result<(), CheckedPathError> {
+        unsafe { SchemedEncoding::push_checked(current_path.as_mut_vec(), path.as_bytes()) }
+    }
+}
+
+impl fmt::Debug for Utf8SchemedEncoding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Utf8SchemedEncoding").finish()
+    }
+}
+
+impl fmt::Display for Utf8SchemedEncoding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Utf8SchemedEncoding")
+    }
+}
+
+impl<T> Utf8Path<T>
+where
+    T: for<'enc> Utf8Encoding<'enc>,
+{
+    /// Returns true if the encoding for the path is schemed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Utf8SchemedPath, Utf8UnixPath};
+    ///
+    /// assert!(Utf8SchemedPath::new("s3://bucket/key").has_schemed_encoding());
+    /// assert!(!Utf8UnixPath::new("/some/path").has_schemed_encoding());
+    /// ```
+    pub fn has_schemed_encoding(&self) -> bool {
+        T::label() == Utf8SchemedEncoding::label()
+    }
+
+    /// Creates an owned [`Utf8PathBuf`] like `self` but using [`Utf8SchemedEncoding`].
+    ///
+    /// See [`Utf8Path::with_encoding`] for more information.
+    pub fn with_schemed_encoding(&self) -> Utf8PathBuf<Utf8SchemedEncoding> {
+        self.with_encoding()
+    }
+
+    /// Creates an owned [`Utf8PathBuf`] like `self` but using [`Utf8SchemedEncoding`], ensuring it
+    /// is a valid schemed path.
+    ///
+    /// See [`Utf8Path::with_encoding_checked`] for more information.
+    pub fn with_schemed_encoding_checked(
+        &self,
+    ) -> Result<Utf8PathBuf<Utf8SchemedEncoding>, CheckedPathError> {
+        self.with_encoding_checked()
+    }
+}
+
+impl Utf8SchemedPath {
+    /// Returns the scheme of the path, without the trailing `://`, or `None` if the path does not
+    /// start with a `scheme://authority` prefix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::Utf8SchemedPath;
+    ///
+    /// assert_eq!(Utf8SchemedPath::new("gs://bucket/logs").scheme(), Some("gs"));
+    /// assert_eq!(Utf8SchemedPath::new("/logs").scheme(), None);
+    /// ```
+    pub fn scheme(&self) -> Option<&str> {
+        let len = SchemedPath::new(self.as_str()).scheme()?.len();
+        Some(&self.as_str()[..len])
+    }
+
+    /// Returns the authority of the path as written, or `None` if the path does not start with a
+    /// `scheme://authority` prefix.
+    ///
+    /// See [`SchemedPath::authority`] for more details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::Utf8SchemedPath;
+    ///
+    /// let path = Utf8SchemedPath::new("hdfs://namenode:8020/warehouse");
+    /// assert_eq!(path.authority(), Some("namenode:8020"));
+    /// ```
+    pub fn authority(&self) -> Option<&str> {
+        let path = SchemedPath::new(self.as_str());
+        let start = path.scheme()?.len() + crate::constants::schemed::SCHEME_DELIMITER.len();
+        let len = path.authority()?.len();
+        Some(&self.as_str()[start..start + len])
+    }
+}
+
+impl_cmp_utf8_bytes!(Utf8SchemedEncoding, SchemedEncoding);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn push_should_follow_the_rules_of_the_non_utf8_encoding() {
+        let mut current_path = String::from("s3://bucket/some/path");
+        Utf8SchemedEncoding::push(&mut current_path, "abc");
+        assert_eq!(current_path, "s3://bucket/some/path/abc");
+
+        Utf8SchemedEncoding::push(&mut current_path, "/def");
+        assert_eq!(current_path, "s3://bucket/def");
+
+        Utf8SchemedEncoding::push(&mut current_path, "gs://other");
+        assert_eq!(current_path, "gs://other");
+    }
+
+    #[test]
+    fn push_checked_should_fail_if_providing_a_prefix_or_root() {
+        let mut current_path = String::from("s3://bucket");
+        assert_eq!(
+            Utf8SchemedEncoding::push_checked(&mut current_path, "gs://other"),
+            Err(CheckedPathError::UnexpectedPrefix)
+        );
+        assert_eq!(
+            Utf8SchemedEncoding::push_checked(&mut current_path, "/abc"),
+            Err(CheckedPathError::UnexpectedRoot)
+        );
+        assert_eq!(current_path, "s3://bucket");
+    }
+}
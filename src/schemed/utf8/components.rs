@@ -0,0 +1,149 @@
+mod component;
+
+use core::{cmp, fmt, iter};
+
+pub use component::*;
+
+use crate::common::remaining_str;
+use crate::schemed::SchemedComponents;
+use crate::{private, Components, Utf8Components, Utf8Encoding, Utf8Path};
+
+#[derive(Clone)]
+pub struct Utf8SchemedComponents<'a> {
+    inner: SchemedComponents<'a>,
+}
+
+impl<'a> Utf8SchemedComponents<'a> {
+    pub(crate) fn new(path: &'a str) -> Self {
+        Self {
+            inner: SchemedComponents::new(path.as_bytes()),
+        }
+    }
+
+    /// Extracts a slice corresponding to the portion of the path remaining for iteration.
+    ///
+    /// As with `std::path::Components::as_path`, trailing separators and current directory
+    /// components that will not be yielded are left out.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Utf8Path, Utf8SchemedEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// let mut components = Utf8Path::<Utf8SchemedEncoding>::new("s3://bucket/data/part-0").components();
+    /// components.next();
+    /// components.next();
+    ///
+    /// assert_eq!(Utf8Path::<Utf8SchemedEncoding>::new("data/part-0"), components.as_path());
+    /// ```
+    pub fn as_path<T>(&self) -> &'a Utf8Path<T>
+    where
+        T: for<'enc> Utf8Encoding<'enc>,
+    {
+        Utf8Path::new(remaining_str(self))
+    }
+}
+
+impl private::Sealed for Utf8SchemedComponents<'_> {}
+
+impl<'a> Utf8Components<'a> for Utf8SchemedComponents<'a> {
+    type Component = Utf8SchemedComponent<'a>;
+
+    fn as_str(&self) -> &'a str {
+        // NOTE: We know that the internal byte representation is UTF-8 compliant as we ensure that
+        //       the only input provided is UTF-8 and no modifications are made with non-UTF-8 bytes
+        unsafe { core::str::from_utf8_unchecked(self.inner.as_bytes()) }
+    }
+
+    fn is_absolute(&self) -> bool {
+        self.inner.is_absolute()
+    }
+
+    fn has_root(&self) -> bool {
+        self.inner.has_root()
+    }
+}
+
+impl AsRef<[u8]> for Utf8SchemedComponents<'_> {
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        self.as_str().as_bytes()
+    }
+}
+
+impl AsRef<str> for Utf8SchemedComponents<'_> {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<T> AsRef<Utf8Path<T>> for Utf8SchemedComponents<'_>
+where
+    T: for<'enc> Utf8Encoding<'enc>,
+{
+    #[inline]
+    fn as_ref(&self) -> &Utf8Path<T> {
+        Utf8Path::new(remaining_str(self))
+    }
+}
+
+impl<'a> fmt::Debug for Utf8SchemedComponents<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        struct DebugHelper<'a>(Utf8SchemedComponents<'a>);
+
+        impl<'a> fmt::Debug for DebugHelper<'a> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.debug_list().entries(self.0.clone()).finish()
+            }
+        }
+
+        f.debug_tuple("Utf8SchemedComponents")
+            .field(&DebugHelper(self.clone()))
+            .finish()
+    }
+}
+
+impl<'a> Iterator for Utf8SchemedComponents<'a> {
+    type Item = <Self as Utf8Components<'a>>::Component;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner
+            .next()
+            .map(|c| unsafe { Utf8SchemedComponent::from_utf8_unchecked(&c) })
+    }
+}
+
+impl<'a> DoubleEndedIterator for Utf8SchemedComponents<'a> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner
+            .next_back()
+            .map(|c| unsafe { Utf8SchemedComponent::from_utf8_unchecked(&c) })
+    }
+}
+
+impl<'a> iter::FusedIterator for Utf8SchemedComponents<'a> {}
+
+impl<'a> cmp::PartialEq for Utf8SchemedComponents<'a> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        PartialEq::eq(&self.inner, &other.inner)
+    }
+}
+
+impl<'a> cmp::Eq for Utf8SchemedComponents<'a> {}
+
+impl<'a> cmp::PartialOrd for Utf8SchemedComponents<'a> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<'a> cmp::Ord for Utf8SchemedComponents<'a> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> cmp::Ordering {
+        Ord::cmp(&self.inner, &other.inner)
+    }
+}
@@ -0,0 +1,374 @@
+use core::fmt;
+use core::str::Utf8Error;
+
+use crate::schemed::constants::{
+    CURRENT_DIR_STR, DISALLOWED_FILENAME_CHARS, PARENT_DIR_STR, SEPARATOR_STR,
+};
+use crate::schemed::{SchemedComponent, Utf8SchemedComponents};
+use crate::{private, ParseError, Utf8Component, Utf8Encoding, Utf8Path};
+
+/// `str` slice version of [`std::path::Component`] that represents a schemed component
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Utf8SchemedComponent<'a> {
+    /// The `scheme://authority` at the start of the path, such as `s3://bucket`
+    Prefix(&'a str),
+    RootDir,
+    CurDir,
+    ParentDir,
+    Normal(&'a str),
+}
+
+impl<'a> Utf8SchemedComponent<'a> {
+    /// Converts a non-UTF-8 [`SchemedComponent`] to a UTF-8 [`Utf8SchemedComponent`]  by checking
+    /// that the component contains valid UTF-8.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if the component is not UTF-8 with a description as to why the
+    /// provided component is not UTF-8.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use typed_path::{Utf8Component, SchemedComponent, Utf8SchemedComponent};
+    ///
+    /// // some bytes, in a vector
+    /// let component = SchemedComponent::Normal(&[240, 159, 146, 150]);
+    ///
+    /// // We know these bytes are valid, so just use `unwrap()`.
+    /// let utf8_component = Utf8SchemedComponent::from_utf8(&component).unwrap();
+    ///
+    /// assert_eq!("💖", utf8_component.as_str());
+    /// ```
+    ///
+    /// Incorrect bytes:
+    ///
+    /// ```
+    /// use typed_path::{SchemedComponent, Utf8SchemedComponent};
+    ///
+    /// // some invalid bytes, in a vector
+    /// let component = SchemedComponent::Normal(&[0, 159, 146, 150]);
+    ///
+    /// assert!(Utf8SchemedComponent::from_utf8(&component).is_err());
+    /// ```
+    ///
+    /// See the docs for [`Utf8Error`] for more details on the kinds of
+    /// errors that can be returned.
+    pub fn from_utf8(component: &SchemedComponent<'a>) -> Result<Self, Utf8Error> {
+        Ok(match component {
+            SchemedComponent::Prefix(x) => Self::Prefix(core::str::from_utf8(x)?),
+            SchemedComponent::RootDir => Self::RootDir,
+            SchemedComponent::ParentDir => Self::ParentDir,
+            SchemedComponent::CurDir => Self::CurDir,
+            SchemedComponent::Normal(x) => Self::Normal(core::str::from_utf8(x)?),
+        })
+    }
+
+    /// Converts a non-UTF-8 [`SchemedComponent`] to a UTF-8 [`Utf8SchemedComponent`] without
+    /// checking that the string contains valid UTF-8.
+    ///
+    /// See the safe version, [`from_utf8`], for more information.
+    ///
+    /// [`from_utf8`]: Utf8SchemedComponent::from_utf8
+    ///
+    /// # Safety
+    ///
+    /// The bytes passed in must be valid UTF-8.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use typed_path::{Utf8Component, SchemedComponent, Utf8SchemedComponent};
+    ///
+    /// // some bytes, in a vector
+    /// let component = SchemedComponent::Normal(&[240, 159, 146, 150]);
+    ///
+    /// let utf8_component = unsafe {
+    ///     Utf8SchemedComponent::from_utf8_unchecked(&component)
+    /// };
+    ///
+    /// assert_eq!("💖", utf8_component.as_str());
+    /// ```
+    pub unsafe fn from_utf8_unchecked(component: &SchemedComponent<'a>) -> Self {
+        match component {
+            SchemedComponent::Prefix(x) => Self::Prefix(core::str::from_utf8_unchecked(x)),
+            SchemedComponent::RootDir => Self::RootDir,
+            SchemedComponent::ParentDir => Self::ParentDir,
+            SchemedComponent::CurDir => Self::CurDir,
+            SchemedComponent::Normal(x) => Self::Normal(core::str::from_utf8_unchecked(x)),
+        }
+    }
+}
+
+impl private::Sealed for Utf8SchemedComponent<'_> {}
+
+impl<'a> Utf8SchemedComponent<'a> {
+    /// Returns path representing this specific component
+    pub fn as_path<T>(&self) -> &Utf8Path<T>
+    where
+        T: for<'enc> Utf8Encoding<'enc>,
+    {
+        Utf8Path::new(self.as_str())
+    }
+
+    /// Returns true if represents a `scheme://authority` prefix
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::Utf8SchemedComponent;
+    /// use std::convert::TryFrom;
+    ///
+    /// let prefix = Utf8SchemedComponent::try_from("hdfs://namenode:8020").unwrap();
+    /// assert!(prefix.is_prefix());
+    ///
+    /// let root_dir = Utf8SchemedComponent::try_from("/").unwrap();
+    /// assert!(!root_dir.is_prefix());
+    /// ```
+    pub fn is_prefix(&self) -> bool {
+        matches!(self, Self::Prefix(_))
+    }
+}
+
+impl<'a> Utf8Component<'a> for Utf8SchemedComponent<'a> {
+    /// Extracts the underlying [`str`] slice
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Utf8Component, Utf8SchemedPath};
+    ///
+    /// let path = Utf8SchemedPath::new("s3://bucket/tmp/foo/../bar.txt");
+    /// let components: Vec<_> = path.components().map(|comp| comp.as_str()).collect();
+    /// assert_eq!(&components, &["s3://bucket", "/", "tmp", "foo", "..", "bar.txt"]);
+    /// ```
+    fn as_str(&self) -> &'a str {
+        match self {
+            Self::Prefix(prefix) => prefix,
+            Self::RootDir => SEPARATOR_STR,
+            Self::CurDir => CURRENT_DIR_STR,
+            Self::ParentDir => PARENT_DIR_STR,
+            Self::Normal(path) => path,
+        }
+    }
+
+    /// Returns true if is the root dir component or a `scheme://authority` prefix, which always
+    /// points at the root of the authority
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Utf8Component, Utf8SchemedComponent};
+    /// use std::convert::TryFrom;
+    ///
+    /// let root_dir = Utf8SchemedComponent::try_from("/").unwrap();
+    /// assert!(root_dir.is_root());
+    ///
+    /// let prefix = Utf8SchemedComponent::try_from("gs://bucket").unwrap();
+    /// assert!(prefix.is_root());
+    ///
+    /// let normal = Utf8SchemedComponent::try_from("file.txt").unwrap();
+    /// assert!(!normal.is_root());
+    /// ```
+    fn is_root(&self) -> bool {
+        matches!(self, Self::Prefix(_) | Self::RootDir)
+    }
+
+    /// Returns true if is a normal component
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Utf8Component, Utf8SchemedComponent};
+    /// use std::convert::TryFrom;
+    ///
+    /// let normal = Utf8SchemedComponent::try_from("file.txt").unwrap();
+    /// assert!(normal.is_normal());
+    ///
+    /// let root_dir = Utf8SchemedComponent::try_from("/").unwrap();
+    /// assert!(!root_dir.is_normal());
+    /// ```
+    fn is_normal(&self) -> bool {
+        matches!(self, Self::Normal(_))
+    }
+
+    /// Returns true if is a parent directory component
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Utf8Component, Utf8SchemedComponent};
+    /// use std::convert::TryFrom;
+    ///
+    /// let parent = Utf8SchemedComponent::try_from("..").unwrap();
+    /// assert!(parent.is_parent());
+    ///
+    /// let root_dir = Utf8SchemedComponent::try_from("/").unwrap();
+    /// assert!(!root_dir.is_parent());
+    /// ```
+    fn is_parent(&self) -> bool {
+        matches!(self, Self::ParentDir)
+    }
+
+    /// Returns true if is the current directory component
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Utf8Component, Utf8SchemedComponent};
+    /// use std::convert::TryFrom;
+    ///
+    /// let current = Utf8SchemedComponent::try_from(".").unwrap();
+    /// assert!(current.is_current());
+    ///
+    /// let root_dir = Utf8SchemedComponent::try_from("/").unwrap();
+    /// assert!(!root_dir.is_current());
+    /// ```
+    fn is_current(&self) -> bool {
+        matches!(self, Self::CurDir)
+    }
+
+    /// Returns true if this component is valid.
+    ///
+    /// A component can only be invalid if it represents a normal component with characters that
+    /// are disallowed by the encoding.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Utf8Component, Utf8SchemedComponent};
+    ///
+    /// assert!(Utf8SchemedComponent::Prefix("s3://bucket").is_valid());
+    /// assert!(Utf8SchemedComponent::RootDir.is_valid());
+    /// assert!(Utf8SchemedComponent::ParentDir.is_valid());
+    /// assert!(Utf8SchemedComponent::CurDir.is_valid());
+    /// assert!(Utf8SchemedComponent::Normal("abc").is_valid());
+    /// assert!(!Utf8SchemedComponent::Normal("\0").is_valid());
+    /// ```
+    fn is_valid(&self) -> bool {
+        match self {
+            Self::Prefix(_) | Self::RootDir | Self::ParentDir | Self::CurDir => true,
+            Self::Normal(s) => !s.chars().any(|c| DISALLOWED_FILENAME_CHARS.contains(&c)),
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.as_str().len()
+    }
+
+    /// Returns the root directory component.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Utf8Component, Utf8SchemedComponent};
+    ///
+    /// assert_eq!(Utf8SchemedComponent::root(), Utf8SchemedComponent::RootDir);
+    /// ```
+    fn root() -> Self {
+        Self::RootDir
+    }
+
+    /// Returns the parent directory component.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Utf8Component, Utf8SchemedComponent};
+    ///
+    /// assert_eq!(Utf8SchemedComponent::parent(), Utf8SchemedComponent::ParentDir);
+    /// ```
+    fn parent() -> Self {
+        Self::ParentDir
+    }
+
+    /// Returns the current directory component.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Utf8Component, Utf8SchemedComponent};
+    ///
+    /// assert_eq!(Utf8SchemedComponent::current(), Utf8SchemedComponent::CurDir);
+    /// ```
+    fn current() -> Self {
+        Self::CurDir
+    }
+}
+
+impl fmt::Display for Utf8SchemedComponent<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl AsRef<[u8]> for Utf8SchemedComponent<'_> {
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        self.as_str().as_bytes()
+    }
+}
+
+impl AsRef<str> for Utf8SchemedComponent<'_> {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<T> AsRef<Utf8Path<T>> for Utf8SchemedComponent<'_>
+where
+    T: for<'enc> Utf8Encoding<'enc>,
+{
+    #[inline]
+    fn as_ref(&self) -> &Utf8Path<T> {
+        Utf8Path::new(self.as_str())
+    }
+}
+
+impl<'a> TryFrom<SchemedComponent<'a>> for Utf8SchemedComponent<'a> {
+    type Error = Utf8Error;
+
+    #[inline]
+    fn try_from(component: SchemedComponent<'a>) -> Result<Self, Self::Error> {
+        Self::from_utf8(&component)
+    }
+}
+
+impl<'a> TryFrom<&'a str> for Utf8SchemedComponent<'a> {
+    type Error = ParseError;
+
+    /// Parses the `str` slice into a [`Utf8SchemedComponent`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::Utf8SchemedComponent;
+    /// use std::convert::TryFrom;
+    ///
+    /// // Supports parsing standard schemed path components
+    /// assert_eq!(Utf8SchemedComponent::try_from("s3://bucket"), Ok(Utf8SchemedComponent::Prefix("s3://bucket")));
+    /// assert_eq!(Utf8SchemedComponent::try_from("/"), Ok(Utf8SchemedComponent::RootDir));
+    /// assert_eq!(Utf8SchemedComponent::try_from("."), Ok(Utf8SchemedComponent::CurDir));
+    /// assert_eq!(Utf8SchemedComponent::try_from(".."), Ok(Utf8SchemedComponent::ParentDir));
+    /// assert_eq!(Utf8SchemedComponent::try_from("file.txt"), Ok(Utf8SchemedComponent::Normal("file.txt")));
+    /// assert_eq!(Utf8SchemedComponent::try_from("dir/"), Ok(Utf8SchemedComponent::Normal("dir")));
+    ///
+    /// // Parsing more than one component will fail
+    /// assert!(Utf8SchemedComponent::try_from("s3://bucket/file").is_err());
+    /// ```
+    fn try_from(path: &'a str) -> Result<Self, Self::Error> {
+        let mut components = Utf8SchemedComponents::new(path);
+
+        let component = components.next().ok_or("no component found")?;
+        if components.next().is_some() {
+            return Err("found more than one component");
+        }
+
+        Ok(component)
+    }
+}
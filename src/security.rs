@@ -0,0 +1,286 @@
+//! Detection of path components spelled to look like something they are not.
+//!
+//! A name can be made to read differently than it is stored by hiding characters in it, such as
+//! bidirectional controls that reorder the text around them and zero-width characters that do
+//! not show at all, or by mixing letters from scripts that look alike, like the Cyrillic `р` and
+//! `а` in `раypal`. [`scan`] and [`scan_utf8`] flag each of these within the components of a
+//! path and return where they were found, which lets tools such as archive scanners report
+//! entries that spoof the names of other directories.
+//!
+//! Only Latin, Greek, Cyrillic, and Armenian, whose letters are the most easily confused with
+//! one another, are told apart when looking for mixed scripts. Mixing any other scripts, or
+//! mixing them with one of those four, is not flagged.
+//!
+//! # Examples
+//!
+//! ```
+//! use typed_path::security::{scan_utf8, SpoofKind};
+//! use typed_path::Utf8UnixPath;
+//!
+//! let path = Utf8UnixPath::new("sites/\u{440}\u{430}ypal/login.html");
+//! let spoofs = scan_utf8(path);
+//! assert_eq!(spoofs.len(), 1);
+//! assert_eq!(spoofs[0].kind(), SpoofKind::MixedScript);
+//! assert_eq!(spoofs[0].component(), 1);
+//! assert_eq!(&path.as_str()[spoofs[0].span()], "\u{440}\u{430}ypal");
+//!
+//! // Reverses the text that follows, displaying as `invoice_fdp.exe` in some places
+//! let path = Utf8UnixPath::new("invoice_\u{202e}exe.pdf");
+//! assert_eq!(scan_utf8(path)[0].kind(), SpoofKind::BidiControl);
+//! ```
+
+use core::ops::Range;
+
+use crate::no_std_compat::*;
+use crate::{
+    Component, Components, Encoding, Path, Utf8Component, Utf8Components, Utf8Encoding, Utf8Path,
+};
+
+/// The kind of spoofing found by [`scan`] and [`scan_utf8`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SpoofKind {
+    /// A bidirectional control character, such as the right-to-left override `U+202E`, which
+    /// changes the order in which the text around it is displayed.
+    BidiControl,
+
+    /// A character that takes up no space when displayed, such as the zero-width space `U+200B`
+    /// or the zero-width joiner `U+200D`.
+    ZeroWidth,
+
+    /// Letters from more than one of Latin, Greek, Cyrillic, and Armenian within a single
+    /// component.
+    MixedScript,
+}
+
+/// Spoofing found within a path by [`scan`] or [`scan_utf8`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Spoof {
+    kind: SpoofKind,
+    component: usize,
+    span: Range<usize>,
+}
+
+impl Spoof {
+    /// Returns the kind of spoofing found.
+    pub fn kind(&self) -> SpoofKind {
+        self.kind
+    }
+
+    /// Returns the index of the component it was found in, counting every component of the
+    /// path including any prefix or root.
+    pub fn component(&self) -> usize {
+        self.component
+    }
+
+    /// Returns the byte range within the path of the offending character, or of the whole
+    /// component for [`SpoofKind::MixedScript`].
+    pub fn span(&self) -> Range<usize> {
+        self.span.clone()
+    }
+}
+
+/// Scans every component of `path` for spoofing, returning what was found in the order of the
+/// path, where the mixed scripts of a component follow any characters flagged within it.
+///
+/// Bytes that are not valid UTF-8 are skipped over.
+///
+/// # Examples
+///
+/// ```
+/// use typed_path::security::{scan, SpoofKind};
+/// use typed_path::WindowsPath;
+///
+/// let path = WindowsPath::new(b"C:\\Users\\admin\xe2\x80\x8b\\Desktop");
+/// let spoofs = scan(path);
+/// assert_eq!(spoofs.len(), 1);
+/// assert_eq!(spoofs[0].kind(), SpoofKind::ZeroWidth);
+/// assert_eq!(spoofs[0].span(), 14..17);
+///
+/// assert!(scan(WindowsPath::new(r"C:\Users\admin\Desktop")).is_empty());
+/// ```
+pub fn scan<T>(path: &Path<T>) -> Vec<Spoof>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    let bytes = path.as_bytes();
+    let mut spoofs = Vec::new();
+    let mut components = path.components();
+    let mut index = 0;
+    loop {
+        // Each component starts where the bytes left by the previous one do
+        let start = bytes.len() - components.as_bytes().len();
+        match components.next() {
+            Some(component) => scan_component(component.as_bytes(), start, index, &mut spoofs),
+            None => break,
+        }
+        index += 1;
+    }
+
+    spoofs
+}
+
+/// Scans every component of `path` for spoofing.
+///
+/// See [`scan`] for more details.
+pub fn scan_utf8<T>(path: &Utf8Path<T>) -> Vec<Spoof>
+where
+    T: for<'enc> Utf8Encoding<'enc>,
+{
+    let bytes = path.as_str().as_bytes();
+    let mut spoofs = Vec::new();
+    let mut components = path.components();
+    let mut index = 0;
+    loop {
+        let start = bytes.len() - components.as_str().len();
+        match components.next() {
+            Some(component) => {
+                scan_component(component.as_str().as_bytes(), start, index, &mut spoofs)
+            }
+            None => break,
+        }
+        index += 1;
+    }
+
+    spoofs
+}
+
+/// Scripts told apart when looking for mixed scripts, as bits of a mask
+const LATIN: u8 = 1;
+const GREEK: u8 = 1 << 1;
+const CYRILLIC: u8 = 1 << 2;
+const ARMENIAN: u8 = 1 << 3;
+
+fn scan_component(component: &[u8], offset: usize, index: usize, spoofs: &mut Vec<Spoof>) {
+    let mut scripts = 0;
+    let mut start = 0;
+    while start < component.len() {
+        let (valid, next) = match core::str::from_utf8(&component[start..]) {
+            Ok(valid) => (valid, component.len()),
+            Err(err) => {
+                let end = start + err.valid_up_to();
+                let valid = core::str::from_utf8(&component[start..end])
+                    .expect("bytes up to the error are valid");
+                (
+                    valid,
+                    end + err.error_len().unwrap_or(component.len() - end),
+                )
+            }
+        };
+
+        for (i, c) in valid.char_indices() {
+            let kind = if is_bidi_control(c) {
+                SpoofKind::BidiControl
+            } else if is_zero_width(c) {
+                SpoofKind::ZeroWidth
+            } else {
+                scripts |= script(c);
+                continue;
+            };
+
+            let at = offset + start + i;
+            spoofs.push(Spoof {
+                kind,
+                component: index,
+                span: at..at + c.len_utf8(),
+            });
+        }
+
+        start = next;
+    }
+
+    if scripts.count_ones() > 1 {
+        spoofs.push(Spoof {
+            kind: SpoofKind::MixedScript,
+            component: index,
+            span: offset..offset + component.len(),
+        });
+    }
+}
+
+fn is_bidi_control(c: char) -> bool {
+    matches!(
+        c,
+        '\u{061c}' | '\u{200e}' | '\u{200f}' | '\u{202a}'..='\u{202e}' | '\u{2066}'..='\u{2069}'
+    )
+}
+
+fn is_zero_width(c: char) -> bool {
+    matches!(
+        c,
+        '\u{180e}' | '\u{200b}'..='\u{200d}' | '\u{2060}' | '\u{feff}'
+    )
+}
+
+/// Returns the bit of the script `c` belongs to, or zero for any other character
+fn script(c: char) -> u8 {
+    match c {
+        'A'..='Z' | 'a'..='z' => LATIN,
+        '\u{d7}' | '\u{f7}' => 0,
+        '\u{c0}'..='\u{24f}'
+        | '\u{1e00}'..='\u{1eff}'
+        | '\u{2c60}'..='\u{2c7f}'
+        | '\u{a720}'..='\u{a7ff}'
+        | '\u{ff21}'..='\u{ff3a}'
+        | '\u{ff41}'..='\u{ff5a}' => LATIN,
+        '\u{370}'..='\u{3ff}' | '\u{1f00}'..='\u{1fff}' => GREEK,
+        '\u{400}'..='\u{52f}'
+        | '\u{1c80}'..='\u{1c8f}'
+        | '\u{2de0}'..='\u{2dff}'
+        | '\u{a640}'..='\u{a69f}' => CYRILLIC,
+        '\u{531}'..='\u{58f}' | '\u{fb13}'..='\u{fb17}' => ARMENIAN,
+        _ => 0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{UnixPath, Utf8UnixPath, Utf8WindowsPath};
+
+    fn kinds(spoofs: &[Spoof]) -> Vec<(SpoofKind, usize, Range<usize>)> {
+        spoofs
+            .iter()
+            .map(|spoof| (spoof.kind(), spoof.component(), spoof.span()))
+            .collect()
+    }
+
+    #[test]
+    fn scan_should_flag_hidden_characters_and_mixed_scripts() {
+        let path = Utf8UnixPath::new("/a\u{200d}b/\u{202e}\u{3b1}x/plain");
+        assert_eq!(
+            kinds(&scan_utf8(path)),
+            [
+                (SpoofKind::ZeroWidth, 1, 2..5),
+                (SpoofKind::BidiControl, 2, 7..10),
+                (SpoofKind::MixedScript, 2, 7..13),
+            ]
+        );
+
+        // Scripts other than the four that are told apart may be mixed freely
+        for name in [
+            "caf\u{e9}",
+            "\u{65e5}\u{672c}doc",
+            "2-\u{43c}\u{438}\u{440}",
+            "\u{3c0}-3",
+        ] {
+            assert!(scan_utf8(Utf8UnixPath::new(name)).is_empty(), "{}", name);
+        }
+    }
+
+    #[test]
+    fn scan_should_report_spans_within_the_whole_path() {
+        let path = Utf8WindowsPath::new("\\\\\u{441}erver\\share\\a");
+        assert_eq!(
+            kinds(&scan_utf8(path)),
+            [(
+                SpoofKind::MixedScript,
+                0,
+                0..path.as_str().find("\\a").unwrap()
+            )]
+        );
+
+        // Invalid UTF-8 is skipped without hiding what follows
+        let path = UnixPath::new(b"x/\xff\xfe\xe2\x80\x8b/\xe2\x80");
+        assert_eq!(kinds(&scan(path)), [(SpoofKind::ZeroWidth, 1, 4..7)]);
+    }
+}
@@ -0,0 +1,435 @@
+//! Modules for use with `#[serde(with = "...")]` to control how individual path fields are
+//! serialized, without wrapping them in a newtype.
+//!
+//! * [`as_str`] stores any path as a string, failing to serialize paths that are not UTF-8.
+//! * [`as_bytes`] stores any non-UTF-8 path as raw bytes, which is compact in binary formats.
+//! * [`windows_string`] stores a Windows path as a string using `/` as the separator.
+//! * [`unix_string`] stores a Unix path as a string.
+//!
+//! Unlike [`as_str`], which derives the type of a [`TypedPathBuf`] or [`Utf8TypedPathBuf`] from
+//! the string when deserializing, [`windows_string`] and [`unix_string`] always produce a path of
+//! their respective encoding.
+//!
+//! # Examples
+//!
+//! ```
+//! use serde::{Deserialize, Serialize};
+//! use typed_path::{UnixPathBuf, WindowsPathBuf};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Config {
+//!     #[serde(with = "typed_path::serde_impls::windows_string")]
+//!     install_dir: WindowsPathBuf,
+//!
+//!     #[serde(with = "typed_path::serde_impls::as_str")]
+//!     cache_dir: UnixPathBuf,
+//! }
+//!
+//! let config = Config {
+//!     install_dir: WindowsPathBuf::from(r"C:\Program Files\app"),
+//!     cache_dir: UnixPathBuf::from("/var/cache/app"),
+//! };
+//!
+//! let json = serde_json::to_string(&config).unwrap();
+//! assert_eq!(
+//!     json,
+//!     r#"{"install_dir":"C:/Program Files/app","cache_dir":"/var/cache/app"}"#,
+//! );
+//!
+//! let config: Config = serde_json::from_str(&json).unwrap();
+//! assert_eq!(config.install_dir.as_bytes(), br"C:\Program Files\app");
+//! ```
+//!
+//! [`TypedPathBuf`]: crate::TypedPathBuf
+//! [`Utf8TypedPathBuf`]: crate::Utf8TypedPathBuf
+
+use core::fmt;
+
+use serde::de::{self, Deserializer, SeqAccess, Visitor};
+use serde::ser::{self, Serializer};
+use serde::Deserialize;
+
+use crate::no_std_compat::*;
+use crate::private;
+use crate::typed::{TypedPathBuf, Utf8TypedPathBuf};
+use crate::unix::{UnixPathBuf, Utf8UnixPathBuf};
+use crate::windows::{Utf8WindowsPathBuf, WindowsPathBuf};
+
+/// Serializes and deserializes a path as a string.
+///
+/// Works with any path that can be viewed as bytes and created from a [`String`], which includes
+/// [`PathBuf`], [`Utf8PathBuf`], [`TypedPathBuf`], and [`Utf8TypedPathBuf`]. Serializing fails if
+/// the path is not valid UTF-8.
+///
+/// [`PathBuf`]: crate::PathBuf
+/// [`Utf8PathBuf`]: crate::Utf8PathBuf
+/// [`TypedPathBuf`]: crate::TypedPathBuf
+/// [`Utf8TypedPathBuf`]: crate::Utf8TypedPathBuf
+///
+/// # Examples
+///
+/// ```
+/// use serde::{Deserialize, Serialize};
+/// use typed_path::Utf8TypedPathBuf;
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Entry {
+///     #[serde(with = "typed_path::serde_impls::as_str")]
+///     path: Utf8TypedPathBuf,
+/// }
+///
+/// let entry: Entry = serde_json::from_str(r#"{"path":"C:\\temp"}"#).unwrap();
+/// assert!(entry.path.is_windows());
+/// assert_eq!(serde_json::to_string(&entry).unwrap(), r#"{"path":"C:\\temp"}"#);
+/// ```
+pub mod as_str {
+    use super::*;
+
+    /// Serializes `path` as a string, failing if it is not valid UTF-8.
+    pub fn serialize<P, S>(path: &P, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        P: AsRef<[u8]> + ?Sized,
+        S: Serializer,
+    {
+        serializer.serialize_str(to_str::<S>(path.as_ref())?)
+    }
+
+    /// Deserializes a path from a string.
+    pub fn deserialize<'de, P, D>(deserializer: D) -> Result<P, D::Error>
+    where
+        P: From<String>,
+        D: Deserializer<'de>,
+    {
+        String::deserialize(deserializer).map(P::from)
+    }
+}
+
+/// Serializes and deserializes a path as raw bytes.
+///
+/// Works with any path that can be viewed as bytes and created from a [`Vec<u8>`], which
+/// includes [`PathBuf`] and [`TypedPathBuf`]. Paths that are not valid UTF-8 are preserved
+/// exactly. Deserializing also accepts a string or a sequence of bytes, for formats that do not
+/// support raw bytes.
+///
+/// [`PathBuf`]: crate::PathBuf
+/// [`TypedPathBuf`]: crate::TypedPathBuf
+///
+/// # Examples
+///
+/// ```
+/// use serde::{Deserialize, Serialize};
+/// use typed_path::UnixPathBuf;
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Entry {
+///     #[serde(with = "typed_path::serde_impls::as_bytes")]
+///     path: UnixPathBuf,
+/// }
+///
+/// let entry = Entry { path: UnixPathBuf::from(b"/tmp/\xff") };
+/// let json = serde_json::to_string(&entry).unwrap();
+/// assert_eq!(json, r#"{"path":[47,116,109,112,47,255]}"#);
+///
+/// let entry: Entry = serde_json::from_str(&json).unwrap();
+/// assert_eq!(entry.path.as_bytes(), b"/tmp/\xff");
+/// ```
+pub mod as_bytes {
+    use super::*;
+
+    /// Serializes `path` as raw bytes.
+    pub fn serialize<P, S>(path: &P, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        P: AsRef<[u8]> + ?Sized,
+        S: Serializer,
+    {
+        serializer.serialize_bytes(path.as_ref())
+    }
+
+    /// Deserializes a path from raw bytes, a string, or a sequence of bytes.
+    pub fn deserialize<'de, P, D>(deserializer: D) -> Result<P, D::Error>
+    where
+        P: From<Vec<u8>>,
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_byte_buf(BytesVisitor).map(P::from)
+    }
+}
+
+/// Serializes and deserializes a Windows path as a string using `/` as the separator.
+///
+/// Works with [`WindowsPathBuf`], [`Utf8WindowsPathBuf`], [`TypedPathBuf`], and
+/// [`Utf8TypedPathBuf`]. Serializing fails if the path is not valid UTF-8 or is a typed path of
+/// the Unix variant. Deserializing accepts either separator and always produces a Windows path.
+///
+/// Verbatim paths such as `\\?\C:\temp` do not treat `/` as a separator, and are therefore
+/// serialized as they are.
+///
+/// [`WindowsPathBuf`]: crate::WindowsPathBuf
+/// [`Utf8WindowsPathBuf`]: crate::Utf8WindowsPathBuf
+/// [`TypedPathBuf`]: crate::TypedPathBuf
+/// [`Utf8TypedPathBuf`]: crate::Utf8TypedPathBuf
+///
+/// # Examples
+///
+/// ```
+/// use serde::{Deserialize, Serialize};
+/// use typed_path::TypedPathBuf;
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Entry {
+///     #[serde(with = "typed_path::serde_impls::windows_string")]
+///     path: TypedPathBuf,
+/// }
+///
+/// // Without a prefix, this would be derived as a Unix path
+/// let entry: Entry = serde_json::from_str(r#"{"path":"/Users/me"}"#).unwrap();
+/// assert!(entry.path.is_windows());
+/// assert_eq!(entry.path.as_bytes(), br"\Users\me");
+/// ```
+pub mod windows_string {
+    use super::*;
+
+    /// Serializes the Windows `path` as a string using `/` as the separator.
+    pub fn serialize<P, S>(path: &P, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        P: WindowsStringPath,
+        S: Serializer,
+    {
+        let s = to_str::<S>(path.windows_bytes().map_err(ser::Error::custom)?)?;
+        if s.starts_with(r"\\?\") {
+            serializer.serialize_str(s)
+        } else {
+            serializer.serialize_str(&s.replace('\\', "/"))
+        }
+    }
+
+    /// Deserializes a Windows path from a string using either `/` or `\` as the separator.
+    pub fn deserialize<'de, P, D>(deserializer: D) -> Result<P, D::Error>
+    where
+        P: WindowsStringPath,
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        if s.starts_with(r"\\?\") {
+            Ok(P::from_windows_string(s))
+        } else {
+            Ok(P::from_windows_string(s.replace('/', "\\")))
+        }
+    }
+}
+
+/// Serializes and deserializes a Unix path as a string.
+///
+/// Works with [`UnixPathBuf`], [`Utf8UnixPathBuf`], [`TypedPathBuf`], and [`Utf8TypedPathBuf`].
+/// Serializing fails if the path is not valid UTF-8 or is a typed path of the Windows variant.
+/// Deserializing always produces a Unix path.
+///
+/// [`UnixPathBuf`]: crate::UnixPathBuf
+/// [`Utf8UnixPathBuf`]: crate::Utf8UnixPathBuf
+/// [`TypedPathBuf`]: crate::TypedPathBuf
+/// [`Utf8TypedPathBuf`]: crate::Utf8TypedPathBuf
+///
+/// # Examples
+///
+/// ```
+/// use serde::{Deserialize, Serialize};
+/// use typed_path::Utf8TypedPathBuf;
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Entry {
+///     #[serde(with = "typed_path::serde_impls::unix_string")]
+///     path: Utf8TypedPathBuf,
+/// }
+///
+/// // With a prefix, this would be derived as a Windows path
+/// let entry: Entry = serde_json::from_str(r#"{"path":"C:/weird/dir"}"#).unwrap();
+/// assert!(entry.path.is_unix());
+///
+/// let entry = Entry { path: Utf8TypedPathBuf::from_windows(r"C:\temp") };
+/// assert!(serde_json::to_string(&entry).is_err());
+/// ```
+pub mod unix_string {
+    use super::*;
+
+    /// Serializes the Unix `path` as a string.
+    pub fn serialize<P, S>(path: &P, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        P: UnixStringPath,
+        S: Serializer,
+    {
+        serializer.serialize_str(to_str::<S>(path.unix_bytes().map_err(ser::Error::custom)?)?)
+    }
+
+    /// Deserializes a Unix path from a string.
+    pub fn deserialize<'de, P, D>(deserializer: D) -> Result<P, D::Error>
+    where
+        P: UnixStringPath,
+        D: Deserializer<'de>,
+    {
+        String::deserialize(deserializer).map(P::from_unix_string)
+    }
+}
+
+/// Paths that can be stored using [`windows_string`].
+///
+/// This trait is sealed and cannot be implemented outside of this crate.
+pub trait WindowsStringPath: private::Sealed + Sized {
+    #[doc(hidden)]
+    fn windows_bytes(&self) -> Result<&[u8], &'static str>;
+
+    #[doc(hidden)]
+    fn from_windows_string(s: String) -> Self;
+}
+
+/// Paths that can be stored using [`unix_string`].
+///
+/// This trait is sealed and cannot be implemented outside of this crate.
+pub trait UnixStringPath: private::Sealed + Sized {
+    #[doc(hidden)]
+    fn unix_bytes(&self) -> Result<&[u8], &'static str>;
+
+    #[doc(hidden)]
+    fn from_unix_string(s: String) -> Self;
+}
+
+impl private::Sealed for WindowsPathBuf {}
+impl private::Sealed for Utf8WindowsPathBuf {}
+impl private::Sealed for UnixPathBuf {}
+impl private::Sealed for Utf8UnixPathBuf {}
+impl private::Sealed for TypedPathBuf {}
+impl private::Sealed for Utf8TypedPathBuf {}
+
+const EXPECTED_WINDOWS: &str = "expected a Windows path";
+const EXPECTED_UNIX: &str = "expected a Unix path";
+
+impl WindowsStringPath for WindowsPathBuf {
+    fn windows_bytes(&self) -> Result<&[u8], &'static str> {
+        Ok(self.as_bytes())
+    }
+
+    fn from_windows_string(s: String) -> Self {
+        Self::from(s)
+    }
+}
+
+impl WindowsStringPath for Utf8WindowsPathBuf {
+    fn windows_bytes(&self) -> Result<&[u8], &'static str> {
+        Ok(self.as_str().as_bytes())
+    }
+
+    fn from_windows_string(s: String) -> Self {
+        Self::from(s)
+    }
+}
+
+impl WindowsStringPath for TypedPathBuf {
+    fn windows_bytes(&self) -> Result<&[u8], &'static str> {
+        match self {
+            Self::Windows(path) => Ok(path.as_bytes()),
+            Self::Unix(_) => Err(EXPECTED_WINDOWS),
+        }
+    }
+
+    fn from_windows_string(s: String) -> Self {
+        Self::Windows(WindowsPathBuf::from(s))
+    }
+}
+
+impl WindowsStringPath for Utf8TypedPathBuf {
+    fn windows_bytes(&self) -> Result<&[u8], &'static str> {
+        match self {
+            Self::Windows(path) => Ok(path.as_str().as_bytes()),
+            Self::Unix(_) => Err(EXPECTED_WINDOWS),
+        }
+    }
+
+    fn from_windows_string(s: String) -> Self {
+        Self::Windows(Utf8WindowsPathBuf::from(s))
+    }
+}
+
+impl UnixStringPath for UnixPathBuf {
+    fn unix_bytes(&self) -> Result<&[u8], &'static str> {
+        Ok(self.as_bytes())
+    }
+
+    fn from_unix_string(s: String) -> Self {
+        Self::from(s)
+    }
+}
+
+impl UnixStringPath for Utf8UnixPathBuf {
+    fn unix_bytes(&self) -> Result<&[u8], &'static str> {
+        Ok(self.as_str().as_bytes())
+    }
+
+    fn from_unix_string(s: String) -> Self {
+        Self::from(s)
+    }
+}
+
+impl UnixStringPath for TypedPathBuf {
+    fn unix_bytes(&self) -> Result<&[u8], &'static str> {
+        match self {
+            Self::Unix(path) => Ok(path.as_bytes()),
+            Self::Windows(_) => Err(EXPECTED_UNIX),
+        }
+    }
+
+    fn from_unix_string(s: String) -> Self {
+        Self::Unix(UnixPathBuf::from(s))
+    }
+}
+
+impl UnixStringPath for Utf8TypedPathBuf {
+    fn unix_bytes(&self) -> Result<&[u8], &'static str> {
+        match self {
+            Self::Unix(path) => Ok(path.as_str().as_bytes()),
+            Self::Windows(_) => Err(EXPECTED_UNIX),
+        }
+    }
+
+    fn from_unix_string(s: String) -> Self {
+        Self::Unix(Utf8UnixPathBuf::from(s))
+    }
+}
+
+/// Converts `bytes` into a str, failing to serialize if they are not valid UTF-8
+fn to_str<S: Serializer>(bytes: &[u8]) -> Result<&str, S::Error> {
+    core::str::from_utf8(bytes).map_err(|_| ser::Error::custom("path is not valid UTF-8"))
+}
+
+/// Visitor that collects raw bytes, a string, or a sequence of bytes
+struct BytesVisitor;
+
+impl<'de> Visitor<'de> for BytesVisitor {
+    type Value = Vec<u8>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a path as bytes")
+    }
+
+    fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<Self::Value, E> {
+        Ok(v.to_vec())
+    }
+
+    fn visit_byte_buf<E: de::Error>(self, v: Vec<u8>) -> Result<Self::Value, E> {
+        Ok(v)
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        Ok(v.as_bytes().to_vec())
+    }
+
+    fn visit_string<E: de::Error>(self, v: String) -> Result<Self::Value, E> {
+        Ok(v.into_bytes())
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut bytes = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+        while let Some(byte) = seq.next_element()? {
+            bytes.push(byte);
+        }
+        Ok(bytes)
+    }
+}
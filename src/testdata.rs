@@ -0,0 +1,378 @@
+//! Windows paths that are easy to parse wrong, alongside how this crate parses them.
+//!
+//! Tools that parse Windows paths themselves, or hand them between languages, can run
+//! [`WINDOWS`] through their own parser and compare the results against the expectations
+//! recorded here, which the crate's own tests hold it to.
+//!
+//! # Examples
+//!
+//! ```
+//! use typed_path::testdata::WINDOWS;
+//! use typed_path::{Utf8Component, Utf8WindowsPath};
+//!
+//! for vector in WINDOWS {
+//!     // Stand-in for the parser being validated
+//!     let path = Utf8WindowsPath::new(vector.path());
+//!     let components: Vec<_> = path.components().map(|c| (c.kind(), c.as_str())).collect();
+//!     assert_eq!(components, vector.components(), "{}", vector.path());
+//! }
+//! ```
+
+use crate::ComponentKind::{self, CurDir, Normal, ParentDir, Prefix, RootDir};
+use crate::WindowsPrefix::{self, DeviceNS, Disk, Verbatim, VerbatimDisk, VerbatimUNC, UNC};
+
+/// A Windows path and the way it parses, found in [`WINDOWS`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct WindowsVector {
+    path: &'static str,
+    prefix: Option<WindowsPrefix<'static>>,
+    components: &'static [(ComponentKind, &'static str)],
+    is_absolute: bool,
+    file_name: Option<&'static str>,
+    parent: Option<&'static str>,
+}
+
+impl WindowsVector {
+    /// Returns the path as written.
+    pub const fn path(&self) -> &'static str {
+        self.path
+    }
+
+    /// Returns the kind of prefix the path starts with, if any.
+    pub const fn prefix(&self) -> Option<WindowsPrefix<'static>> {
+        self.prefix
+    }
+
+    /// Returns the kind and text of each component, where a root directory is always `\`
+    /// however it was written.
+    pub const fn components(&self) -> &'static [(ComponentKind, &'static str)] {
+        self.components
+    }
+
+    /// Returns true if the path is absolute, which requires both a prefix and a root directory.
+    pub const fn is_absolute(&self) -> bool {
+        self.is_absolute
+    }
+
+    /// Returns the final component if it is a normal component.
+    pub const fn file_name(&self) -> Option<&'static str> {
+        self.file_name
+    }
+
+    /// Returns the path without its final component, or [`None`] if it ends in a prefix that
+    /// implies a root or in a root directory.
+    pub const fn parent(&self) -> Option<&'static str> {
+        self.parent
+    }
+}
+
+const fn vector(
+    path: &'static str,
+    prefix: Option<WindowsPrefix<'static>>,
+    components: &'static [(ComponentKind, &'static str)],
+    is_absolute: bool,
+    file_name: Option<&'static str>,
+    parent: Option<&'static str>,
+) -> WindowsVector {
+    WindowsVector {
+        path,
+        prefix,
+        components,
+        is_absolute,
+        file_name,
+        parent,
+    }
+}
+
+/// Windows paths covering each kind of prefix and their corner cases.
+pub const WINDOWS: &[WindowsVector] = &[
+    // A disk with no root is relative to the current directory of that drive
+    vector(
+        "C:",
+        Some(Disk(b'C')),
+        &[(Prefix, "C:")],
+        false,
+        None,
+        Some(""),
+    ),
+    vector(
+        r"C:a\b",
+        Some(Disk(b'C')),
+        &[(Prefix, "C:"), (Normal, "a"), (Normal, "b")],
+        false,
+        Some("b"),
+        Some("C:a"),
+    ),
+    vector(
+        r"C:..\a",
+        Some(Disk(b'C')),
+        &[(Prefix, "C:"), (ParentDir, ".."), (Normal, "a")],
+        false,
+        Some("a"),
+        Some("C:.."),
+    ),
+    vector(
+        r"C:\",
+        Some(Disk(b'C')),
+        &[(Prefix, "C:"), (RootDir, r"\")],
+        true,
+        None,
+        None,
+    ),
+    // The prefix component keeps the case of its drive letter while the kind reports it in
+    // uppercase, and either separator makes a root
+    vector(
+        "c:/a/b",
+        Some(Disk(b'C')),
+        &[
+            (Prefix, "c:"),
+            (RootDir, r"\"),
+            (Normal, "a"),
+            (Normal, "b"),
+        ],
+        true,
+        Some("b"),
+        Some("c:/a"),
+    ),
+    // A root with no prefix is relative to the current drive
+    vector(
+        r"\a",
+        None,
+        &[(RootDir, r"\"), (Normal, "a")],
+        false,
+        Some("a"),
+        Some(r"\"),
+    ),
+    // UNC
+    vector(
+        r"\\server",
+        Some(UNC(b"server", b"")),
+        &[(Prefix, r"\\server")],
+        false,
+        None,
+        None,
+    ),
+    vector(
+        r"\\server\share",
+        Some(UNC(b"server", b"share")),
+        &[(Prefix, r"\\server\share")],
+        false,
+        None,
+        None,
+    ),
+    vector(
+        r"\\server\share\a",
+        Some(UNC(b"server", b"share")),
+        &[(Prefix, r"\\server\share"), (RootDir, r"\"), (Normal, "a")],
+        true,
+        Some("a"),
+        Some(r"\\server\share\"),
+    ),
+    // Verbatim paths keep `.` and do not treat `/` as a separator
+    vector(
+        r"\\?\C:",
+        Some(VerbatimDisk(b'C')),
+        &[(Prefix, r"\\?\C:")],
+        false,
+        None,
+        None,
+    ),
+    vector(
+        r"\\?\C:\a\.\b",
+        Some(VerbatimDisk(b'C')),
+        &[
+            (Prefix, r"\\?\C:"),
+            (RootDir, r"\"),
+            (Normal, "a"),
+            (CurDir, "."),
+            (Normal, "b"),
+        ],
+        true,
+        Some("b"),
+        Some(r"\\?\C:\a\."),
+    ),
+    vector(
+        r"\\?\C:/a",
+        Some(VerbatimDisk(b'C')),
+        &[(Prefix, r"\\?\C:"), (Normal, "/a")],
+        false,
+        Some("/a"),
+        Some(r"\\?\C:"),
+    ),
+    vector(
+        r"\\?\UNC\server\share\a",
+        Some(VerbatimUNC(b"server", b"share")),
+        &[
+            (Prefix, r"\\?\UNC\server\share"),
+            (RootDir, r"\"),
+            (Normal, "a"),
+        ],
+        true,
+        Some("a"),
+        Some(r"\\?\UNC\server\share\"),
+    ),
+    // `UNC` is only recognized in uppercase, and on its own it is just a verbatim name
+    vector(
+        r"\\?\UNC\",
+        Some(Verbatim(b"UNC")),
+        &[(Prefix, r"\\?\UNC"), (RootDir, r"\")],
+        true,
+        None,
+        None,
+    ),
+    vector(
+        r"\\?\unc\server\share",
+        Some(Verbatim(b"unc")),
+        &[
+            (Prefix, r"\\?\unc"),
+            (RootDir, r"\"),
+            (Normal, "server"),
+            (Normal, "share"),
+        ],
+        true,
+        Some("share"),
+        Some(r"\\?\unc\server"),
+    ),
+    vector(
+        r"\\?\pictures\a",
+        Some(Verbatim(b"pictures")),
+        &[(Prefix, r"\\?\pictures"), (RootDir, r"\"), (Normal, "a")],
+        true,
+        Some("a"),
+        Some(r"\\?\pictures\"),
+    ),
+    // Device namespace
+    vector(
+        r"\\.\COM1",
+        Some(DeviceNS(b"COM1")),
+        &[(Prefix, r"\\.\COM1")],
+        false,
+        None,
+        None,
+    ),
+    vector(
+        r"\\.\COM1\a",
+        Some(DeviceNS(b"COM1")),
+        &[(Prefix, r"\\.\COM1"), (RootDir, r"\"), (Normal, "a")],
+        true,
+        Some("a"),
+        Some(r"\\.\COM1\"),
+    ),
+    vector(
+        r"\\.\pipe\name",
+        Some(DeviceNS(b"pipe")),
+        &[(Prefix, r"\\.\pipe"), (RootDir, r"\"), (Normal, "name")],
+        true,
+        Some("name"),
+        Some(r"\\.\pipe\"),
+    ),
+    vector(
+        r"\\.\C:\a",
+        Some(DeviceNS(b"C:")),
+        &[(Prefix, r"\\.\C:"), (RootDir, r"\"), (Normal, "a")],
+        true,
+        Some("a"),
+        Some(r"\\.\C:\"),
+    ),
+    // Trailing dots and spaces, which Win32 strips, and device names are kept as written
+    vector(
+        r"a.\b.",
+        None,
+        &[(Normal, "a."), (Normal, "b.")],
+        false,
+        Some("b."),
+        Some("a."),
+    ),
+    vector(
+        r"C:\a. ",
+        Some(Disk(b'C')),
+        &[(Prefix, "C:"), (RootDir, r"\"), (Normal, "a. ")],
+        true,
+        Some("a. "),
+        Some(r"C:\"),
+    ),
+    vector(
+        r"\\?\C:\a.",
+        Some(VerbatimDisk(b'C')),
+        &[(Prefix, r"\\?\C:"), (RootDir, r"\"), (Normal, "a.")],
+        true,
+        Some("a."),
+        Some(r"\\?\C:\"),
+    ),
+    vector(
+        r"a\con.txt",
+        None,
+        &[(Normal, "a"), (Normal, "con.txt")],
+        false,
+        Some("con.txt"),
+        Some("a"),
+    ),
+    vector(
+        r"a\..\b",
+        None,
+        &[(Normal, "a"), (ParentDir, ".."), (Normal, "b")],
+        false,
+        Some("b"),
+        Some(r"a\.."),
+    ),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::no_std_compat::*;
+    use crate::{Component, Utf8Component, Utf8WindowsPath, WindowsPath};
+
+    #[test]
+    fn windows_vectors_should_match_parsing() {
+        for vector in WINDOWS {
+            let path = WindowsPath::new(vector.path());
+            let components: Vec<_> = path
+                .components()
+                .map(|c| (c.kind(), c.as_bytes()))
+                .collect();
+            let expected: Vec<_> = vector
+                .components()
+                .iter()
+                .map(|(kind, s)| (*kind, s.as_bytes()))
+                .collect();
+
+            assert_eq!(components, expected, "{}", vector.path());
+            assert_eq!(
+                path.components().prefix_kind(),
+                vector.prefix(),
+                "{}",
+                vector.path()
+            );
+            assert_eq!(
+                path.is_absolute(),
+                vector.is_absolute(),
+                "{}",
+                vector.path()
+            );
+            assert_eq!(
+                path.file_name(),
+                vector.file_name().map(str::as_bytes),
+                "{}",
+                vector.path()
+            );
+            assert_eq!(
+                path.parent().map(WindowsPath::as_bytes),
+                vector.parent().map(str::as_bytes),
+                "{}",
+                vector.path()
+            );
+
+            let path = Utf8WindowsPath::new(vector.path());
+            let components: Vec<_> = path.components().map(|c| (c.kind(), c.as_str())).collect();
+            assert_eq!(components, vector.components(), "{}", vector.path());
+            assert_eq!(path.file_name(), vector.file_name(), "{}", vector.path());
+            assert_eq!(
+                path.parent().map(Utf8WindowsPath::as_str),
+                vector.parent(),
+                "{}",
+                vector.path()
+            );
+        }
+    }
+}
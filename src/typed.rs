@@ -10,13 +10,55 @@ macro_rules! impl_typed_fn {
     };
 }
 
+/// Implements equality in both directions between a typed path and `$rhs` by comparing the
+/// `$repr` of each side, produced by `$f` for the typed path and [`AsRef`] for `$rhs`.
+macro_rules! impl_typed_cmp {
+    ($($lt:lifetime),* ; $lhs:ty, $rhs:ty, $repr:ty, $f:ident) => {
+        impl<$($lt),*> PartialEq<$rhs> for $lhs {
+            #[inline]
+            fn eq(&self, other: &$rhs) -> bool {
+                self.$f() == AsRef::<$repr>::as_ref(other)
+            }
+        }
+
+        impl<$($lt),*> PartialEq<$lhs> for $rhs {
+            #[inline]
+            fn eq(&self, other: &$lhs) -> bool {
+                AsRef::<$repr>::as_ref(self) == other.$f()
+            }
+        }
+    };
+}
+
+/// Implements equality in both directions between a byte-based and a UTF-8 typed path, which are
+/// equal when they are for the same platform and have the same underlying bytes.
+macro_rules! impl_typed_cmp_utf8 {
+    ($($lt:lifetime),* ; $lhs:ty, $rhs:ty) => {
+        impl<$($lt),*> PartialEq<$rhs> for $lhs {
+            #[inline]
+            fn eq(&self, other: &$rhs) -> bool {
+                self.is_unix() == other.is_unix() && self.as_bytes() == other.as_str().as_bytes()
+            }
+        }
+
+        impl<$($lt),*> PartialEq<$lhs> for $rhs {
+            #[inline]
+            fn eq(&self, other: &$lhs) -> bool {
+                other == self
+            }
+        }
+    };
+}
+
 mod non_utf8;
 mod utf8;
+mod wire;
 
 pub use non_utf8::*;
 pub use utf8::*;
 
 /// Represents the type of the path.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
 pub enum PathType {
     /// Path is for a Unix platform.
     Unix,
@@ -17,24 +17,43 @@ pub enum TypedComponents<'a> {
 impl<'a> TypedComponents<'a> {
     /// Extracts a slice corresponding to the portion of the path remaining for iteration.
     ///
+    /// As with `std::path::Components::as_path`, trailing separators and current directory
+    /// components that will not be yielded are left out.
+    ///
     /// # Examples
     ///
     /// ```
     /// use typed_path::TypedPath;
     ///
-    /// let mut components = TypedPath::derive("/tmp/foo/bar.txt").components();
-    /// components.next();
+    /// let mut components = TypedPath::derive("/tmp/foo/./").components();
     /// components.next();
     ///
-    /// assert_eq!(TypedPath::derive("foo/bar.txt"), components.to_path());
+    /// assert_eq!(TypedPath::derive("tmp/foo"), components.as_path());
     /// ```
-    pub fn to_path(&self) -> TypedPath<'a> {
+    pub fn as_path(&self) -> TypedPath<'a> {
         match self {
             Self::Unix(components) => TypedPath::Unix(components.as_path()),
             Self::Windows(components) => TypedPath::Windows(components.as_path()),
         }
     }
 
+    /// Extracts a slice corresponding to the portion of the path remaining for iteration.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::TypedPath;
+    ///
+    /// let mut components = TypedPath::derive("/tmp/foo/bar.txt").components();
+    /// components.next();
+    /// components.next();
+    ///
+    /// assert_eq!(TypedPath::derive("foo/bar.txt"), components.to_path());
+    /// ```
+    pub fn to_path(&self) -> TypedPath<'a> {
+        self.as_path()
+    }
+
     /// Extracts a slice corresponding to the portion of the path remaining for iteration.
     pub fn as_bytes(&self) -> &'a [u8] {
         impl_typed_fn!(self, as_bytes)
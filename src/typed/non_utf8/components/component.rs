@@ -1,7 +1,7 @@
 use crate::typed::TypedPath;
 use crate::unix::UnixComponent;
 use crate::windows::WindowsComponent;
-use crate::{private, Component};
+use crate::{private, Component, ComponentKind};
 
 /// Byte slice version of [`std::path::Component`] that represents either a Unix or Windows path
 /// component.
@@ -134,6 +134,29 @@ impl<'a> TypedComponent<'a> {
         impl_typed_fn!(self, is_current)
     }
 
+    /// Returns the [`ComponentKind`] of this component.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{ComponentKind, TypedPath};
+    ///
+    /// let path = TypedPath::derive(r"C:\..\file.txt");
+    /// let kinds: Vec<_> = path.components().map(|c| c.kind()).collect();
+    /// assert_eq!(
+    ///     kinds,
+    ///     [
+    ///         ComponentKind::Prefix,
+    ///         ComponentKind::RootDir,
+    ///         ComponentKind::ParentDir,
+    ///         ComponentKind::Normal,
+    ///     ],
+    /// );
+    /// ```
+    pub fn kind(&self) -> ComponentKind {
+        impl_typed_fn!(self, kind)
+    }
+
     /// Returns byte length of component.
     pub fn len(&self) -> usize {
         impl_typed_fn!(self, len)
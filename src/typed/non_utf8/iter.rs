@@ -22,24 +22,43 @@ pub enum TypedIter<'a> {
 impl<'a> TypedIter<'a> {
     /// Extracts a slice corresponding to the portion of the path remaining for iteration.
     ///
+    /// As with `std::path::Components::as_path`, trailing separators and current directory
+    /// components that will not be yielded are left out.
+    ///
     /// # Examples
     ///
     /// ```
     /// use typed_path::TypedPath;
     ///
-    /// let mut iter = TypedPath::derive("/tmp/foo/bar.txt").iter();
-    /// iter.next();
+    /// let mut iter = TypedPath::derive("/tmp/foo/./").iter();
     /// iter.next();
     ///
-    /// assert_eq!(TypedPath::derive("foo/bar.txt"), iter.to_path());
+    /// assert_eq!(TypedPath::derive("tmp/foo"), iter.as_path());
     /// ```
-    pub fn to_path(&self) -> TypedPath {
+    pub fn as_path(&self) -> TypedPath<'a> {
         match self {
             Self::Unix(it) => TypedPath::Unix(it.as_path()),
             Self::Windows(it) => TypedPath::Windows(it.as_path()),
         }
     }
 
+    /// Extracts a slice corresponding to the portion of the path remaining for iteration.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::TypedPath;
+    ///
+    /// let mut iter = TypedPath::derive("/tmp/foo/bar.txt").iter();
+    /// iter.next();
+    /// iter.next();
+    ///
+    /// assert_eq!(TypedPath::derive("foo/bar.txt"), iter.to_path());
+    /// ```
+    pub fn to_path(&self) -> TypedPath<'a> {
+        self.as_path()
+    }
+
     /// Returns reference to the underlying byte slice represented by this iterator.
     pub fn as_bytes(&self) -> &[u8] {
         impl_typed_fn!(self, as_ref)
@@ -1,5 +1,6 @@
 use alloc::borrow::Cow;
 use core::fmt;
+use core::ops::{Bound, Range};
 
 #[cfg(feature = "std")]
 use std::path::Path;
@@ -7,17 +8,25 @@ use std::path::Path;
 #[cfg(all(feature = "std", not(target_family = "wasm")))]
 use std::io;
 
-use crate::common::{CheckedPathError, StripPrefixError};
+use crate::common::{
+    CheckedPathError, ConversionPolicy, DifferentPrefixError, Encoding, EncodingInfo,
+    EscapedDisplay, NormalizationOptions, PathEquivalence, PushMode, StripPrefixError,
+};
 use crate::convert::TryAsRef;
-use crate::typed::{PathType, TypedAncestors, TypedComponents, TypedIter, TypedPathBuf};
-use crate::unix::UnixPath;
-use crate::windows::WindowsPath;
+use crate::no_std_compat::*;
+use crate::typed::wire;
+use crate::typed::{
+    PathType, TypedAncestors, TypedComponent, TypedComponents, TypedIter, TypedPathBuf,
+    Utf8TypedPath, Utf8TypedPathBuf,
+};
+use crate::unix::{UnixEncoding, UnixPath};
+use crate::windows::{WindowsEncoding, WindowsPath};
 
 /// Represents a path with a known type that can be one of:
 ///
 /// * [`UnixPath`]
 /// * [`WindowsPath`]
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, PartialEq, Eq)]
 pub enum TypedPath<'a> {
     Unix(&'a UnixPath),
     Windows(&'a WindowsPath),
@@ -44,6 +53,28 @@ impl<'a> TypedPath<'a> {
         Self::Windows(WindowsPath::new(s))
     }
 
+    /// Returns the type of path native to the platform during compilation, which is
+    /// [`PathType::Windows`] for Windows and [`PathType::Unix`] for everything else.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{PathType, TypedPath};
+    ///
+    /// if cfg!(windows) {
+    ///     assert_eq!(TypedPath::native_kind(), PathType::Windows);
+    /// } else {
+    ///     assert_eq!(TypedPath::native_kind(), PathType::Unix);
+    /// }
+    /// ```
+    pub const fn native_kind() -> PathType {
+        if cfg!(windows) {
+            PathType::Windows
+        } else {
+            PathType::Unix
+        }
+    }
+
     /// Creates a new typed path from a byte slice by determining if the path represents a Windows
     /// or Unix path. This is accomplished by first trying to parse as a Windows path. If the
     /// resulting path contains a prefix such as `C:` or begins with a `\`, it is assumed to be a
@@ -133,6 +164,53 @@ impl<'a> TypedPath<'a> {
         impl_typed_fn!(self, to_string_lossy)
     }
 
+    /// Returns an object that implements [`Display`] for printing the path with any non-Unicode
+    /// data escaped as `\xNN` rather than lossily replaced, optionally surrounded by quotes.
+    ///
+    /// See [`Path::escaped_display`] for more details.
+    ///
+    /// [`Display`]: core::fmt::Display
+    /// [`Path::escaped_display`]: crate::Path::escaped_display
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::TypedPath;
+    ///
+    /// let path = TypedPath::derive(&b"/tmp/fo\xf0\x90\x80.txt"[..]);
+    /// assert_eq!(path.escaped_display().to_string(), r"/tmp/fo\xF0\x90\x80.txt");
+    /// ```
+    #[inline]
+    pub fn escaped_display(&self) -> EscapedDisplay<'a> {
+        match self {
+            Self::Unix(p) => p.escaped_display(),
+            Self::Windows(p) => p.escaped_display(),
+        }
+    }
+
+    /// Encodes the path into a compact, versioned binary format that preserves both the encoding
+    /// of the path and its exact bytes.
+    ///
+    /// See [`TypedPathBuf::to_wire_bytes`] for more details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{TypedPath, TypedPathBuf};
+    ///
+    /// let path = TypedPath::windows(b"C:\\caf\xe9");
+    /// let decoded = TypedPathBuf::from_wire_bytes(&path.to_wire_bytes()).unwrap();
+    /// assert_eq!(decoded, path);
+    /// ```
+    pub fn to_wire_bytes(&self) -> Vec<u8> {
+        let r#type = match self {
+            Self::Unix(_) => PathType::Unix,
+            Self::Windows(_) => PathType::Windows,
+        };
+
+        wire::encode(r#type, self.as_bytes())
+    }
+
     /// Converts a [`TypedPath`] into a [`TypedPathBuf`].
     ///
     /// # Examples
@@ -217,6 +295,82 @@ impl<'a> TypedPath<'a> {
         impl_typed_fn!(self, has_root)
     }
 
+    /// Returns the prefix and root at the start of the [`TypedPath`], if it has either, such as `/`,
+    /// `C:\`, or `\\server\share\`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::TypedPath;
+    ///
+    /// assert_eq!(TypedPath::derive("/etc/passwd").root(), Some(TypedPath::derive("/")));
+    /// assert_eq!(TypedPath::derive(r"C:\Windows").root(), Some(TypedPath::derive(r"C:\")));
+    /// assert_eq!(TypedPath::derive("etc/passwd").root(), None);
+    /// ```
+    pub fn root(&self) -> Option<Self> {
+        self.split_root().0
+    }
+
+    /// Splits the [`TypedPath`] into its prefix and root, as returned by [`TypedPath::root`], and the
+    /// relative remainder of the path.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::TypedPath;
+    ///
+    /// let (root, rest) = TypedPath::derive(r"\\server\share\dir").split_root();
+    /// assert_eq!(root, Some(TypedPath::derive(r"\\server\share\")));
+    /// assert_eq!(rest, TypedPath::windows("dir"));
+    /// ```
+    pub fn split_root(&self) -> (Option<Self>, Self) {
+        match self {
+            Self::Unix(path) => {
+                let (root, rest) = path.split_root();
+                (root.map(Self::Unix), Self::Unix(rest))
+            }
+            Self::Windows(path) => {
+                let (root, rest) = path.split_root();
+                (root.map(Self::Windows), Self::Windows(rest))
+            }
+        }
+    }
+
+    /// Returns `true` if the [`TypedPath`] is made up of nothing more than a root, such as `/`, `C:\`,
+    /// or `\\server\share`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::TypedPath;
+    ///
+    /// assert!(TypedPath::derive("/").is_root());
+    /// assert!(TypedPath::derive(r"C:\").is_root());
+    /// assert!(!TypedPath::derive("C:").is_root());
+    /// assert!(!TypedPath::derive("/etc").is_root());
+    /// ```
+    #[inline]
+    pub fn is_root(&self) -> bool {
+        impl_typed_fn!(self, is_root)
+    }
+
+    /// Returns `true` if the [`TypedPath`] is made up of nothing more than a prefix without a root,
+    /// such as `C:`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::TypedPath;
+    ///
+    /// assert!(TypedPath::derive("C:").is_prefix_only());
+    /// assert!(!TypedPath::derive(r"C:\").is_prefix_only());
+    /// assert!(!TypedPath::derive("/").is_prefix_only());
+    /// ```
+    #[inline]
+    pub fn is_prefix_only(&self) -> bool {
+        impl_typed_fn!(self, is_prefix_only)
+    }
+
     /// Returns the [`TypedPath`] without its final component, if there is one.
     ///
     /// Returns [`None`] if the path terminates in a root or prefix.
@@ -277,6 +431,58 @@ impl<'a> TypedPath<'a> {
         }
     }
 
+    /// Produces an iterator over [`TypedPath`] and its ancestors that stops after yielding `base`.
+    ///
+    /// See [`Path::ancestors_until`] for more details.
+    ///
+    /// # Difference from Path
+    ///
+    /// Unlike [`Path::ancestors_until`], this implementation only supports types that implement
+    /// `AsRef<[u8]>` instead of `AsRef<Path>`.
+    ///
+    /// [`Path::ancestors_until`]: crate::Path::ancestors_until
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::TypedPath;
+    ///
+    /// let path = TypedPath::derive("/home/user/project/src");
+    /// let mut ancestors = path.ancestors_until("/home/user/project");
+    /// assert_eq!(ancestors.next(), Some(TypedPath::derive("/home/user/project/src")));
+    /// assert_eq!(ancestors.next(), Some(TypedPath::derive("/home/user/project")));
+    /// assert_eq!(ancestors.next(), None);
+    /// ```
+    pub fn ancestors_until(&self, base: impl AsRef<[u8]>) -> TypedAncestors<'a> {
+        match self {
+            Self::Unix(p) => TypedAncestors::Unix(p.ancestors_until(UnixPath::new(&base))),
+            Self::Windows(p) => TypedAncestors::Windows(p.ancestors_until(WindowsPath::new(&base))),
+        }
+    }
+
+    /// Returns the `n`th ancestor of the path, or [`None`] if the path has fewer than `n`
+    /// parents.
+    ///
+    /// See [`Path::nth_parent`] for more details.
+    ///
+    /// [`Path::nth_parent`]: crate::Path::nth_parent
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::TypedPath;
+    ///
+    /// let path = TypedPath::derive("/foo/bar/baz");
+    /// assert_eq!(path.nth_parent(2), Some(TypedPath::derive("/foo")));
+    /// assert_eq!(path.nth_parent(4), None);
+    /// ```
+    pub fn nth_parent(&self, n: usize) -> Option<Self> {
+        match self {
+            Self::Unix(p) => p.nth_parent(n).map(Self::Unix),
+            Self::Windows(p) => p.nth_parent(n).map(Self::Windows),
+        }
+    }
+
     /// Returns the final component of the [`TypedPath`], if there is one.
     ///
     /// If the path is a normal file, this is the file name. If it's the path of a directory, this
@@ -344,71 +550,379 @@ impl<'a> TypedPath<'a> {
         }
     }
 
-    /// Determines whether `base` is a prefix of `self`.
+    /// Returns a relative path that, when joined onto `base`, refers to the same location as
+    /// `self`.
+    ///
+    /// See [`Path::relative_to`] for more details.
+    ///
+    /// # Difference from Path
+    ///
+    /// Unlike [`Path::relative_to`], this implementation only supports types that implement
+    /// `AsRef<[u8]>` instead of `AsRef<Path>`.
+    ///
+    /// [`Path::relative_to`]: crate::Path::relative_to
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::TypedPath;
+    ///
+    /// let path = TypedPath::derive("/usr/share/doc");
+    /// assert_eq!(path.relative_to("/usr/lib").unwrap().as_bytes(), b"../share/doc");
+    ///
+    /// let path = TypedPath::derive(r"C:\Users\Public");
+    /// assert!(path.relative_to(r"D:\Users").is_err());
+    /// ```
+    pub fn relative_to(
+        &self,
+        base: impl AsRef<[u8]>,
+    ) -> Result<TypedPathBuf, DifferentPrefixError> {
+        Ok(match self {
+            Self::Unix(p) => TypedPathBuf::Unix(p.relative_to(UnixPath::new(&base))?),
+            Self::Windows(p) => TypedPathBuf::Windows(p.relative_to(WindowsPath::new(&base))?),
+        })
+    }
+
+    /// Determines whether `base` is a prefix of `self`.
+    ///
+    /// Only considers whole path components to match.
+    ///
+    /// # Difference from Path
+    ///
+    /// Unlike [`Path::starts_with`], this implementation only supports types that implement
+    /// `AsRef<[u8]>` instead of `AsRef<Path>`.
+    ///
+    /// [`Path::starts_with`]: crate::Path::starts_with
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::TypedPath;
+    ///
+    /// let path = TypedPath::derive("/etc/passwd");
+    ///
+    /// assert!(path.starts_with("/etc"));
+    /// assert!(path.starts_with("/etc/"));
+    /// assert!(path.starts_with("/etc/passwd"));
+    /// assert!(path.starts_with("/etc/passwd/")); // extra slash is okay
+    /// assert!(path.starts_with("/etc/passwd///")); // multiple extra slashes are okay
+    ///
+    /// assert!(!path.starts_with("/e"));
+    /// assert!(!path.starts_with("/etc/passwd.txt"));
+    ///
+    /// assert!(!TypedPath::derive("/etc/foo.rs").starts_with("/etc/foo"));
+    /// ```
+    pub fn starts_with(&self, base: impl AsRef<[u8]>) -> bool {
+        match self {
+            Self::Unix(p) => p.starts_with(UnixPath::new(&base)),
+            Self::Windows(p) => p.starts_with(WindowsPath::new(&base)),
+        }
+    }
+
+    /// Determines whether `child` is a suffix of `self`.
+    ///
+    /// Only considers whole path components to match.
+    ///
+    /// # Difference from Path
+    ///
+    /// Unlike [`Path::ends_with`], this implementation only supports types that implement
+    /// `AsRef<[u8]>` instead of `AsRef<Path>`.
+    ///
+    /// [`Path::ends_with`]: crate::Path::ends_with
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::TypedPath;
+    ///
+    /// let path = TypedPath::derive("/etc/resolv.conf");
+    ///
+    /// assert!(path.ends_with("resolv.conf"));
+    /// assert!(path.ends_with("etc/resolv.conf"));
+    /// assert!(path.ends_with("/etc/resolv.conf"));
+    ///
+    /// assert!(!path.ends_with("/resolv.conf"));
+    /// assert!(!path.ends_with("conf")); // use .extension() instead
+    /// ```
+    pub fn ends_with(&self, child: impl AsRef<[u8]>) -> bool {
+        match self {
+            Self::Unix(p) => p.ends_with(UnixPath::new(&child)),
+            Self::Windows(p) => p.ends_with(WindowsPath::new(&child)),
+        }
+    }
+
+    /// Determines whether `self` is a strict ancestor of `other`.
+    ///
+    /// See [`Path::is_ancestor_of`] for more details.
+    ///
+    /// # Difference from Path
+    ///
+    /// Unlike [`Path::is_ancestor_of`], this implementation only supports types that implement
+    /// `AsRef<[u8]>` instead of `AsRef<Path>`.
+    ///
+    /// [`Path::is_ancestor_of`]: crate::Path::is_ancestor_of
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::TypedPath;
+    ///
+    /// let path = TypedPath::derive("/etc");
+    /// assert!(path.is_ancestor_of("/etc/passwd"));
+    /// assert!(!path.is_ancestor_of("/etcetera"));
+    /// ```
+    pub fn is_ancestor_of(&self, other: impl AsRef<[u8]>) -> bool {
+        match self {
+            Self::Unix(p) => p.is_ancestor_of(UnixPath::new(&other)),
+            Self::Windows(p) => p.is_ancestor_of(WindowsPath::new(&other)),
+        }
+    }
+
+    /// Determines whether `self` is either the same path as `other` or one of its ancestors.
+    ///
+    /// See [`Path::is_inclusive_ancestor_of`] for more details.
+    ///
+    /// # Difference from Path
+    ///
+    /// Unlike [`Path::is_inclusive_ancestor_of`], this implementation only supports types that
+    /// implement `AsRef<[u8]>` instead of `AsRef<Path>`.
+    ///
+    /// [`Path::is_inclusive_ancestor_of`]: crate::Path::is_inclusive_ancestor_of
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::TypedPath;
+    ///
+    /// let path = TypedPath::derive("/etc");
+    /// assert!(path.is_inclusive_ancestor_of("/etc"));
+    /// ```
+    pub fn is_inclusive_ancestor_of(&self, other: impl AsRef<[u8]>) -> bool {
+        match self {
+            Self::Unix(p) => p.is_inclusive_ancestor_of(UnixPath::new(&other)),
+            Self::Windows(p) => p.is_inclusive_ancestor_of(WindowsPath::new(&other)),
+        }
+    }
+
+    /// Determines whether `self` is a strict descendant of `other`.
+    ///
+    /// See [`Path::is_descendant_of`] for more details.
+    ///
+    /// # Difference from Path
+    ///
+    /// Unlike [`Path::is_descendant_of`], this implementation only supports types that implement
+    /// `AsRef<[u8]>` instead of `AsRef<Path>`.
+    ///
+    /// [`Path::is_descendant_of`]: crate::Path::is_descendant_of
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::TypedPath;
+    ///
+    /// let path = TypedPath::derive("/etc/passwd");
+    /// assert!(path.is_descendant_of("/etc"));
+    /// assert!(!path.is_descendant_of("/et"));
+    /// ```
+    pub fn is_descendant_of(&self, other: impl AsRef<[u8]>) -> bool {
+        match self {
+            Self::Unix(p) => p.is_descendant_of(UnixPath::new(&other)),
+            Self::Windows(p) => p.is_descendant_of(WindowsPath::new(&other)),
+        }
+    }
+
+    /// Determines whether `self` is either the same path as `other` or one of its descendants.
+    ///
+    /// See [`Path::is_inclusive_descendant_of`] for more details.
+    ///
+    /// # Difference from Path
+    ///
+    /// Unlike [`Path::is_inclusive_descendant_of`], this implementation only supports types that
+    /// implement `AsRef<[u8]>` instead of `AsRef<Path>`.
+    ///
+    /// [`Path::is_inclusive_descendant_of`]: crate::Path::is_inclusive_descendant_of
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::TypedPath;
+    ///
+    /// let path = TypedPath::derive("/etc/passwd");
+    /// assert!(path.is_inclusive_descendant_of("/etc/passwd"));
+    /// ```
+    pub fn is_inclusive_descendant_of(&self, other: impl AsRef<[u8]>) -> bool {
+        match self {
+            Self::Unix(p) => p.is_inclusive_descendant_of(UnixPath::new(&other)),
+            Self::Windows(p) => p.is_inclusive_descendant_of(WindowsPath::new(&other)),
+        }
+    }
+
+    /// Determines whether `needle` appears anywhere within `self` as a sequence of whole
+    /// components.
+    ///
+    /// # Difference from Path
+    ///
+    /// Unlike [`Path::contains_path`], this implementation only supports types that implement
+    /// `AsRef<[u8]>` instead of `AsRef<Path>`.
+    ///
+    /// [`Path::contains_path`]: crate::Path::contains_path
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::TypedPath;
+    ///
+    /// let path = TypedPath::derive("/repo/node_modules/.bin/tsc");
+    /// assert!(path.contains_path("node_modules/.bin"));
+    /// assert!(!path.contains_path("node_modules/tsc"));
+    /// ```
+    pub fn contains_path(&self, needle: impl AsRef<[u8]>) -> bool {
+        match self {
+            Self::Unix(p) => p.contains_path(UnixPath::new(&needle)),
+            Self::Windows(p) => p.contains_path(WindowsPath::new(&needle)),
+        }
+    }
+
+    /// Locates the first occurrence of `needle` within `self` as a sequence of whole components,
+    /// returning the index of the first matching component alongside the span of bytes within
+    /// `self` covered by the matching components.
+    ///
+    /// # Difference from Path
+    ///
+    /// Unlike [`Path::find_subpath`], this implementation only supports types that implement
+    /// `AsRef<[u8]>` instead of `AsRef<Path>`.
+    ///
+    /// [`Path::find_subpath`]: crate::Path::find_subpath
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::TypedPath;
+    ///
+    /// let path = TypedPath::derive("/repo/node_modules/.bin/tsc");
+    /// assert_eq!(path.find_subpath("node_modules/.bin"), Some((2, 6..23)));
+    /// ```
+    pub fn find_subpath(&self, needle: impl AsRef<[u8]>) -> Option<(usize, Range<usize>)> {
+        match self {
+            Self::Unix(p) => p.find_subpath(UnixPath::new(&needle)),
+            Self::Windows(p) => p.find_subpath(WindowsPath::new(&needle)),
+        }
+    }
+
+    /// Creates an owned [`TypedPathBuf`] where every non-overlapping occurrence of the components
+    /// of `from` within `self` is replaced with `to`.
+    ///
+    /// See [`Path::replace_components`] for more details.
+    ///
+    /// # Difference from Path
+    ///
+    /// Unlike [`Path::replace_components`], this implementation only supports types that
+    /// implement `AsRef<[u8]>` instead of `AsRef<Path>`.
+    ///
+    /// [`Path::replace_components`]: crate::Path::replace_components
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::TypedPath;
+    ///
+    /// let path = TypedPath::derive("/repo/src/main.rs");
+    /// assert_eq!(
+    ///     path.replace_components("src", "generated").as_bytes(),
+    ///     b"/repo/generated/main.rs",
+    /// );
+    /// ```
+    pub fn replace_components(&self, from: impl AsRef<[u8]>, to: impl AsRef<[u8]>) -> TypedPathBuf {
+        match self {
+            Self::Unix(p) => {
+                TypedPathBuf::Unix(p.replace_components(UnixPath::new(&from), UnixPath::new(&to)))
+            }
+            Self::Windows(p) => TypedPathBuf::Windows(
+                p.replace_components(WindowsPath::new(&from), WindowsPath::new(&to)),
+            ),
+        }
+    }
+
+    /// Creates an owned [`TypedPathBuf`] where each component of `self` is replaced with the
+    /// bytes returned by `f`.
+    ///
+    /// See [`Path::map_components`] for more details.
+    ///
+    /// [`Path::map_components`]: crate::Path::map_components
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::TypedPath;
+    ///
+    /// let path = TypedPath::derive("/repo/src/main.rs");
+    /// let upper = path.map_components(|c| c.as_bytes().to_ascii_uppercase());
+    /// assert_eq!(upper.as_bytes(), b"/REPO/SRC/MAIN.RS");
+    /// ```
+    pub fn map_components<F, C>(&self, mut f: F) -> TypedPathBuf
+    where
+        F: FnMut(TypedComponent<'a>) -> C,
+        C: AsRef<[u8]>,
+    {
+        match self {
+            Self::Unix(p) => TypedPathBuf::Unix(p.map_components(|c| f(TypedComponent::Unix(c)))),
+            Self::Windows(p) => {
+                TypedPathBuf::Windows(p.map_components(|c| f(TypedComponent::Windows(c))))
+            }
+        }
+    }
+
+    /// Determines whether `other` refers to the same place as `self` after lexical
+    /// normalization, using the case rules of the encoding.
     ///
-    /// Only considers whole path components to match.
+    /// See [`Path::normalized_eq`] for more details.
     ///
     /// # Difference from Path
     ///
-    /// Unlike [`Path::starts_with`], this implementation only supports types that implement
+    /// Unlike [`Path::normalized_eq`], this implementation only supports types that implement
     /// `AsRef<[u8]>` instead of `AsRef<Path>`.
     ///
-    /// [`Path::starts_with`]: crate::Path::starts_with
+    /// [`Path::normalized_eq`]: crate::Path::normalized_eq
     ///
     /// # Examples
     ///
     /// ```
     /// use typed_path::TypedPath;
     ///
-    /// let path = TypedPath::derive("/etc/passwd");
-    ///
-    /// assert!(path.starts_with("/etc"));
-    /// assert!(path.starts_with("/etc/"));
-    /// assert!(path.starts_with("/etc/passwd"));
-    /// assert!(path.starts_with("/etc/passwd/")); // extra slash is okay
-    /// assert!(path.starts_with("/etc/passwd///")); // multiple extra slashes are okay
-    ///
-    /// assert!(!path.starts_with("/e"));
-    /// assert!(!path.starts_with("/etc/passwd.txt"));
-    ///
-    /// assert!(!TypedPath::derive("/etc/foo.rs").starts_with("/etc/foo"));
+    /// assert!(TypedPath::derive("/etc/./ssh//config").normalized_eq("/etc/ssl/../ssh/config"));
+    /// assert!(TypedPath::derive(r"C:\Windows").normalized_eq(r"c:\windows"));
     /// ```
-    pub fn starts_with(&self, base: impl AsRef<[u8]>) -> bool {
+    pub fn normalized_eq(&self, other: impl AsRef<[u8]>) -> bool {
         match self {
-            Self::Unix(p) => p.starts_with(UnixPath::new(&base)),
-            Self::Windows(p) => p.starts_with(WindowsPath::new(&base)),
+            Self::Unix(p) => p.normalized_eq(UnixPath::new(&other)),
+            Self::Windows(p) => p.normalized_eq(WindowsPath::new(&other)),
         }
     }
 
-    /// Determines whether `child` is a suffix of `self`.
+    /// Determines whether `other` refers to the same place as `self` using the given
+    /// [`PathEquivalence`] rules.
     ///
-    /// Only considers whole path components to match.
+    /// See [`Path::equivalent_with`] for more details.
     ///
     /// # Difference from Path
     ///
-    /// Unlike [`Path::ends_with`], this implementation only supports types that implement
+    /// Unlike [`Path::equivalent_with`], this implementation only supports types that implement
     /// `AsRef<[u8]>` instead of `AsRef<Path>`.
     ///
-    /// [`Path::ends_with`]: crate::Path::ends_with
+    /// [`Path::equivalent_with`]: crate::Path::equivalent_with
     ///
     /// # Examples
     ///
     /// ```
-    /// use typed_path::TypedPath;
+    /// use typed_path::{PathEquivalence, TypedPath};
     ///
-    /// let path = TypedPath::derive("/etc/resolv.conf");
-    ///
-    /// assert!(path.ends_with("resolv.conf"));
-    /// assert!(path.ends_with("etc/resolv.conf"));
-    /// assert!(path.ends_with("/etc/resolv.conf"));
-    ///
-    /// assert!(!path.ends_with("/resolv.conf"));
-    /// assert!(!path.ends_with("conf")); // use .extension() instead
+    /// let rules = PathEquivalence::new().case_sensitive(false);
+    /// assert!(TypedPath::derive("/Users/Me").equivalent_with("/users/me", &rules));
     /// ```
-    pub fn ends_with(&self, child: impl AsRef<[u8]>) -> bool {
+    pub fn equivalent_with(&self, other: impl AsRef<[u8]>, rules: &PathEquivalence) -> bool {
         match self {
-            Self::Unix(p) => p.ends_with(UnixPath::new(&child)),
-            Self::Windows(p) => p.ends_with(WindowsPath::new(&child)),
+            Self::Unix(p) => p.equivalent_with(UnixPath::new(&other), rules),
+            Self::Windows(p) => p.equivalent_with(WindowsPath::new(&other), rules),
         }
     }
 
@@ -460,6 +974,45 @@ impl<'a> TypedPath<'a> {
         impl_typed_fn!(self, extension)
     }
 
+    /// Returns true if the file name of the path marks it as hidden by convention alone, without
+    /// consulting any filesystem attributes.
+    ///
+    /// See [`Path::is_hidden_by_name`] for more details.
+    ///
+    /// [`Path::is_hidden_by_name`]: crate::Path::is_hidden_by_name
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::TypedPath;
+    ///
+    /// assert!(TypedPath::derive("/home/user/.profile").is_hidden_by_name());
+    /// assert!(TypedPath::derive(r"C:\Docs\~$notes.docx").is_hidden_by_name());
+    /// assert!(!TypedPath::derive(r"C:\Docs\notes.docx").is_hidden_by_name());
+    /// ```
+    pub fn is_hidden_by_name(&self) -> bool {
+        impl_typed_fn!(self, is_hidden_by_name)
+    }
+
+    /// Guesses the media type of the path's content from its extension, ignoring ascii case.
+    ///
+    /// See [`Path::mime_type`] for more details.
+    ///
+    /// [`Path::mime_type`]: crate::Path::mime_type
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::TypedPath;
+    ///
+    /// assert_eq!(TypedPath::derive(r"C:\www\photo.jpeg").mime_type(), Some("image/jpeg"));
+    /// assert_eq!(TypedPath::derive("/www/data.tar").mime_type(), Some("application/x-tar"));
+    /// assert_eq!(TypedPath::derive("/www/data.unknown").mime_type(), None);
+    /// ```
+    pub fn mime_type(&self) -> Option<&'static str> {
+        impl_typed_fn!(self, mime_type)
+    }
+
     /// Returns an owned [`TypedPathBuf`] by resolving `..` and `.` segments.
     ///
     /// When multiple, sequential path segment separation characters are found (e.g. `/` for Unix
@@ -514,6 +1067,53 @@ impl<'a> TypedPath<'a> {
         }
     }
 
+    /// Returns an owned [`TypedPathBuf`] by lexically cleaning the path in the same way as Go's
+    /// `path/filepath.Clean`.
+    ///
+    /// See [`Path::clean`] for more details.
+    ///
+    /// [`Path::clean`]: crate::Path::clean
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{TypedPath, TypedPathBuf};
+    ///
+    /// assert_eq!(TypedPath::derive("a/../../b/./c/").clean(), TypedPathBuf::from("../b/c"));
+    /// assert_eq!(TypedPath::derive("a/..").clean(), TypedPathBuf::from("."));
+    /// ```
+    pub fn clean(&self) -> TypedPathBuf {
+        match self {
+            Self::Unix(path) => TypedPathBuf::Unix(path.clean()),
+            Self::Windows(path) => TypedPathBuf::Windows(path.clean()),
+        }
+    }
+
+    /// Returns an owned [`TypedPathBuf`] by normalizing the path using the given
+    /// [`NormalizationOptions`].
+    ///
+    /// See [`Path::normalize_with`] for more details.
+    ///
+    /// [`Path::normalize_with`]: crate::Path::normalize_with
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{NormalizationOptions, TypedPath, TypedPathBuf};
+    ///
+    /// let options = NormalizationOptions::new().preserve_leading_parent(true);
+    /// assert_eq!(
+    ///     TypedPath::derive("../foo//./bar/..").normalize_with(&options),
+    ///     TypedPathBuf::from("../foo"),
+    /// );
+    /// ```
+    pub fn normalize_with(&self, options: &NormalizationOptions) -> TypedPathBuf {
+        match self {
+            Self::Unix(path) => TypedPathBuf::Unix(path.normalize_with(options)),
+            Self::Windows(path) => TypedPathBuf::Windows(path.normalize_with(options)),
+        }
+    }
+
     /// Converts a path to an absolute form by [`normalizing`] the path, returning a
     /// [`TypedPathBuf`].
     ///
@@ -609,6 +1209,137 @@ impl<'a> TypedPath<'a> {
         })
     }
 
+    /// Creates an owned [`TypedPathBuf`] with `path` adjoined to `self`, first converting `path` to
+    /// the encoding of `self` using `policy` if the two differ.
+    ///
+    /// See [`TypedPathBuf::push_typed`] for more details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{ConversionPolicy, TypedPath};
+    ///
+    /// let base = TypedPath::derive(r"C:\srv");
+    /// let path = base.join_typed(TypedPath::unix("www/index.html"), ConversionPolicy::Lossy);
+    /// assert_eq!(path.unwrap().as_bytes(), br"C:\srv\www\index.html");
+    /// ```
+    pub fn join_typed(
+        &self,
+        path: TypedPath<'_>,
+        policy: ConversionPolicy,
+    ) -> Result<TypedPathBuf, CheckedPathError> {
+        let mut buf = self.to_path_buf();
+        buf.push_typed(path, policy)?;
+        Ok(buf)
+    }
+
+    /// Creates an owned [`TypedPathBuf`] with `path` adjoined to `self`, failing if `path` uses a
+    /// different encoding and cannot be converted to the encoding of `self` without loss.
+    ///
+    /// This is the same as [`TypedPath::join_typed`] using [`ConversionPolicy::Checked`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{CheckedPathError, TypedPath};
+    ///
+    /// let base = TypedPath::derive("/srv");
+    /// assert_eq!(
+    ///     base.try_join(TypedPath::windows(r"www\index.html")).unwrap().as_bytes(),
+    ///     b"/srv/www/index.html",
+    /// );
+    /// assert_eq!(
+    ///     base.try_join(TypedPath::derive(r"D:\www")),
+    ///     Err(CheckedPathError::UnexpectedPrefix),
+    /// );
+    /// ```
+    pub fn try_join(&self, path: TypedPath<'_>) -> Result<TypedPathBuf, CheckedPathError> {
+        self.join_typed(path, ConversionPolicy::Checked)
+    }
+
+    /// Creates an owned [`TypedPathBuf`] with `path` adjoined to `self` using the given
+    /// [`PushMode`] to decide what happens when `path` has its own root or prefix.
+    ///
+    /// See [`PathBuf::push_with_mode`] for more details.
+    ///
+    /// [`PathBuf::push_with_mode`]: crate::PathBuf::push_with_mode
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{CheckedPathError, PushMode, TypedPath, TypedPathBuf};
+    ///
+    /// let path = TypedPath::derive("/srv");
+    /// assert_eq!(
+    ///     path.join_with_mode("/etc", PushMode::Append),
+    ///     Ok(TypedPathBuf::from("/srv/etc")),
+    /// );
+    /// assert_eq!(
+    ///     path.join_with_mode("/etc", PushMode::Reject),
+    ///     Err(CheckedPathError::UnexpectedRoot),
+    /// );
+    /// ```
+    pub fn join_with_mode(
+        &self,
+        path: impl AsRef<[u8]>,
+        mode: PushMode,
+    ) -> Result<TypedPathBuf, CheckedPathError> {
+        Ok(match self {
+            Self::Unix(p) => TypedPathBuf::Unix(p.join_with_mode(UnixPath::new(&path), mode)?),
+            Self::Windows(p) => {
+                TypedPathBuf::Windows(p.join_with_mode(WindowsPath::new(&path), mode)?)
+            }
+        })
+    }
+
+    /// Creates an owned [`TypedPathBuf`] with `path` adjoined to `self`, never replacing `self`
+    /// even if `path` is absolute.
+    ///
+    /// See [`Path::join_keeping_base`] for more details.
+    ///
+    /// [`Path::join_keeping_base`]: crate::Path::join_keeping_base
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{TypedPath, TypedPathBuf};
+    ///
+    /// let path = TypedPath::derive("/srv/www");
+    /// assert_eq!(
+    ///     path.join_keeping_base("/index.html"),
+    ///     TypedPathBuf::from("/srv/www/index.html"),
+    /// );
+    /// ```
+    pub fn join_keeping_base(&self, path: impl AsRef<[u8]>) -> TypedPathBuf {
+        match self {
+            Self::Unix(p) => TypedPathBuf::Unix(p.join_keeping_base(UnixPath::new(&path))),
+            Self::Windows(p) => TypedPathBuf::Windows(p.join_keeping_base(WindowsPath::new(&path))),
+        }
+    }
+
+    /// Creates an owned [`TypedPathBuf`] with `path` adjoined to `self`, failing if `path` has a
+    /// root or prefix.
+    ///
+    /// See [`Path::join_relative_only`] for more details.
+    ///
+    /// [`Path::join_relative_only`]: crate::Path::join_relative_only
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{CheckedPathError, TypedPath, TypedPathBuf};
+    ///
+    /// let path = TypedPath::derive("/srv");
+    /// assert_eq!(path.join_relative_only("www"), Ok(TypedPathBuf::from("/srv/www")));
+    /// assert_eq!(path.join_relative_only("/etc"), Err(CheckedPathError::UnexpectedRoot));
+    /// ```
+    pub fn join_relative_only(
+        &self,
+        path: impl AsRef<[u8]>,
+    ) -> Result<TypedPathBuf, CheckedPathError> {
+        self.join_with_mode(path, PushMode::Reject)
+    }
+
     /// Creates an owned [`TypedPathBuf`] like `self` but with the given file name.
     ///
     /// See [`TypedPathBuf::set_file_name`] for more details.
@@ -655,6 +1386,111 @@ impl<'a> TypedPath<'a> {
         }
     }
 
+    /// Creates an owned [`TypedPathBuf`] like `self` but with every normal component shortened
+    /// to at most `n_bytes` bytes.
+    ///
+    /// See [`Path::truncate_component_to`] for more details.
+    ///
+    /// [`Path::truncate_component_to`]: crate::Path::truncate_component_to
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::TypedPath;
+    ///
+    /// let path = TypedPath::derive("/photos/holiday_in_spain.jpeg");
+    /// assert_eq!(path.truncate_component_to(8).as_bytes(), b"/photos/hol.jpeg");
+    /// ```
+    pub fn truncate_component_to(&self, n_bytes: usize) -> TypedPathBuf {
+        match self {
+            Self::Unix(path) => TypedPathBuf::Unix(path.truncate_component_to(n_bytes)),
+            Self::Windows(path) => TypedPathBuf::Windows(path.truncate_component_to(n_bytes)),
+        }
+    }
+
+    /// Renders `self` for display within `max_width` columns by replacing leading directories with
+    /// `…`, always keeping the prefix, root, and file name.
+    ///
+    /// See [`Path::abbreviate`] for more details.
+    ///
+    /// [`Path::abbreviate`]: crate::Path::abbreviate
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::TypedPath;
+    ///
+    /// let path = TypedPath::derive(r"C:\Users\user\Documents\file.rs");
+    /// assert_eq!(path.abbreviate(22), r"C:\…\Documents\file.rs");
+    /// ```
+    pub fn abbreviate(&self, max_width: usize) -> String {
+        impl_typed_fn!(self, abbreviate, max_width)
+    }
+
+    /// Produces an owned key whose plain byte order matches the order of paths as trees, so that
+    /// paths can be stored in key-value stores without a custom comparator.
+    ///
+    /// See [`Path::sort_key`] for more details.
+    ///
+    /// [`Path::sort_key`]: crate::Path::sort_key
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::TypedPath;
+    ///
+    /// let key = |s| TypedPath::derive(s).sort_key();
+    /// assert!(key("/foo") < key("/foo/bar"));
+    /// assert!(key("/foo/bar") < key("/foo-bar"));
+    /// ```
+    pub fn sort_key(&self) -> Vec<u8> {
+        impl_typed_fn!(self, sort_key)
+    }
+
+    /// Returns the bounds of the sort keys covering exactly `self` and its descendants, for prefix
+    /// scans over a key-value store ordered by sort key.
+    ///
+    /// See [`Path::range_bounds_for_subtree`] for more details.
+    ///
+    /// [`Path::range_bounds_for_subtree`]: crate::Path::range_bounds_for_subtree
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::ops::RangeBounds;
+    /// use typed_path::TypedPath;
+    ///
+    /// let bounds = TypedPath::derive("/foo").range_bounds_for_subtree();
+    /// assert!(bounds.contains(&TypedPath::derive("/foo/bar").sort_key()));
+    /// assert!(!bounds.contains(&TypedPath::derive("/foo-bar").sort_key()));
+    /// ```
+    pub fn range_bounds_for_subtree(&self) -> (Bound<Vec<u8>>, Bound<Vec<u8>>) {
+        impl_typed_fn!(self, range_bounds_for_subtree)
+    }
+
+    /// Creates an owned [`TypedPathBuf`] like `self` but with its file name shortened such that
+    /// the entire path is at most `total_bytes` bytes.
+    ///
+    /// See [`Path::fit_within`] for more details.
+    ///
+    /// [`Path::fit_within`]: crate::Path::fit_within
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::TypedPath;
+    ///
+    /// let path = TypedPath::derive(r"C:\notes\meeting.md");
+    /// assert_eq!(path.fit_within(15).unwrap().as_bytes(), br"C:\notes\mee.md");
+    /// assert_eq!(path.fit_within(9), None);
+    /// ```
+    pub fn fit_within(&self, total_bytes: usize) -> Option<TypedPathBuf> {
+        match self {
+            Self::Unix(path) => path.fit_within(total_bytes).map(TypedPathBuf::Unix),
+            Self::Windows(path) => path.fit_within(total_bytes).map(TypedPathBuf::Windows),
+        }
+    }
+
     /// Produces an iterator over the [`TypedComponent`]s of the path.
     ///
     /// When parsing the path, there is a small amount of normalization:
@@ -757,6 +1593,56 @@ impl<'a> TypedPath<'a> {
         Display { path: self }
     }
 
+    /// Returns an object that implements [`Display`] for printing `self` relative to the `home`
+    /// directory, such as `~/project/x` or `%USERPROFILE%\Desktop`. Paths outside of `home`,
+    /// including those of a different encoding, are printed in full.
+    ///
+    /// See [`Path::display_relative_to_home`] for more details.
+    ///
+    /// [`Display`]: fmt::Display
+    /// [`Path::display_relative_to_home`]: crate::Path::display_relative_to_home
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::TypedPath;
+    ///
+    /// let home = TypedPath::derive(r"C:\Users\me");
+    /// let path = TypedPath::derive(r"C:\Users\me\Desktop");
+    /// assert_eq!(
+    ///     path.display_relative_to_home(&home).to_string(),
+    ///     r"%USERPROFILE%\Desktop",
+    /// );
+    ///
+    /// let path = TypedPath::derive("/home/me/Desktop");
+    /// assert_eq!(path.display_relative_to_home(&home).to_string(), "/home/me/Desktop");
+    /// ```
+    pub fn display_relative_to_home<'b>(
+        &'b self,
+        home: &'b TypedPath<'b>,
+    ) -> impl fmt::Display + 'b {
+        struct HomeDisplay<'a> {
+            path: &'a TypedPath<'a>,
+            home: &'a TypedPath<'a>,
+        }
+
+        impl fmt::Display for HomeDisplay<'_> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                match (self.path, self.home) {
+                    (TypedPath::Unix(path), TypedPath::Unix(home)) => {
+                        fmt::Display::fmt(&path.display_relative_to_home(home), f)
+                    }
+                    (TypedPath::Windows(path), TypedPath::Windows(home)) => {
+                        fmt::Display::fmt(&path.display_relative_to_home(home), f)
+                    }
+                    (path, _) => fmt::Display::fmt(&path.display(), f),
+                }
+            }
+        }
+
+        HomeDisplay { path: self, home }
+    }
+
     /// Returns true if this path represents a Unix path.
     #[inline]
     pub fn is_unix(&self) -> bool {
@@ -769,6 +1655,26 @@ impl<'a> TypedPath<'a> {
         matches!(self, Self::Windows(_))
     }
 
+    /// Returns the [`EncodingInfo`] describing the separators and case sensitivity of the
+    /// encoding used by this path.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::TypedPath;
+    ///
+    /// let path = TypedPath::windows(r"C:\temp");
+    /// let info = path.encoding_info();
+    /// assert_eq!(info.name, "windows");
+    /// assert!(path.as_bytes().iter().any(|&b| info.is_separator(b)));
+    /// ```
+    pub fn encoding_info(&self) -> EncodingInfo {
+        match self {
+            Self::Unix(_) => <UnixEncoding as Encoding>::info(),
+            Self::Windows(_) => <WindowsEncoding as Encoding>::info(),
+        }
+    }
+
     /// Converts this [`TypedPath`] into the Unix variant of [`TypedPathBuf`].
     pub fn with_unix_encoding(&self) -> TypedPathBuf {
         match self {
@@ -804,6 +1710,36 @@ impl<'a> TypedPath<'a> {
     }
 }
 
+impl fmt::Debug for TypedPath<'_> {
+    /// Writes the encoding of the path along with its bytes, escaped like a [`str`] with any
+    /// bytes that are not valid UTF-8 written as `\xNN`.
+    ///
+    /// ```
+    /// use typed_path::TypedPath;
+    ///
+    /// let path = TypedPath::derive(&b"C:\\caf\xe9"[..]);
+    /// assert_eq!(format!("{path:?}"), r#"Windows("C:\\caf\xE9")"#);
+    ///
+    /// // Valid UTF-8 is written the same as for `Utf8TypedPath`
+    /// use typed_path::Utf8TypedPath;
+    ///
+    /// let path = "/tmp/\"it's\"\t.txt";
+    /// assert_eq!(
+    ///     format!("{:?}", TypedPath::derive(path)),
+    ///     format!("{:?}", Utf8TypedPath::derive(path)),
+    /// );
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (name, path) = match self {
+            Self::Unix(path) => ("Unix", path.as_bytes()),
+            Self::Windows(path) => ("Windows", path.as_bytes()),
+        };
+        f.debug_tuple(name)
+            .field(&format_args!("{}", EscapedDisplay::new(path).debug()))
+            .finish()
+    }
+}
+
 impl<'a> From<&'a [u8]> for TypedPath<'a> {
     #[inline]
     fn from(s: &'a [u8]) -> Self {
@@ -858,3 +1794,12 @@ impl PartialEq<TypedPathBuf> for TypedPath<'_> {
         self.eq(&path.to_path())
     }
 }
+
+impl_typed_cmp!('a; TypedPath<'a>, [u8], [u8], as_bytes);
+impl_typed_cmp!('a, 'b; TypedPath<'a>, &'b [u8], [u8], as_bytes);
+impl_typed_cmp!('a; TypedPath<'a>, Vec<u8>, [u8], as_bytes);
+impl_typed_cmp!('a; TypedPath<'a>, str, [u8], as_bytes);
+impl_typed_cmp!('a, 'b; TypedPath<'a>, &'b str, [u8], as_bytes);
+impl_typed_cmp!('a; TypedPath<'a>, String, [u8], as_bytes);
+impl_typed_cmp_utf8!('a, 'b; TypedPath<'a>, Utf8TypedPath<'b>);
+impl_typed_cmp_utf8!('a; TypedPath<'a>, Utf8TypedPathBuf);
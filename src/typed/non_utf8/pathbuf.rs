@@ -1,21 +1,31 @@
 use alloc::borrow::Cow;
 use alloc::collections::TryReserveError;
 use core::convert::TryFrom;
+use core::fmt;
+use core::ops::{Bound, Range};
 
 #[cfg(feature = "std")]
 use std::{io, path::PathBuf};
 
-use crate::common::{CheckedPathError, StripPrefixError};
+use crate::common::{
+    CheckedPathError, ConversionPolicy, DifferentPrefixError, Encoding, EncodingInfo,
+    EscapedDisplay, NormalizationOptions, PathEquivalence, PushMode, StripPrefixError,
+    WireFormatError,
+};
 use crate::no_std_compat::*;
-use crate::typed::{PathType, TypedAncestors, TypedComponents, TypedIter, TypedPath};
-use crate::unix::{UnixPath, UnixPathBuf};
-use crate::windows::{WindowsPath, WindowsPathBuf};
+use crate::typed::wire;
+use crate::typed::{
+    PathType, TypedAncestors, TypedComponent, TypedComponents, TypedIter, TypedPath, Utf8TypedPath,
+    Utf8TypedPathBuf,
+};
+use crate::unix::{UnixEncoding, UnixPath, UnixPathBuf};
+use crate::windows::{WindowsEncoding, WindowsPath, WindowsPathBuf};
 
 /// Represents a pathbuf with a known type that can be one of:
 ///
 /// * [`UnixPathBuf`]
 /// * [`WindowsPathBuf`]
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, PartialEq, Eq)]
 pub enum TypedPathBuf {
     Unix(UnixPathBuf),
     Windows(WindowsPathBuf),
@@ -34,6 +44,26 @@ impl TypedPathBuf {
         matches!(self, Self::Windows(_))
     }
 
+    /// Returns the [`EncodingInfo`] describing the separators and case sensitivity of the
+    /// encoding used by this path.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::TypedPathBuf;
+    ///
+    /// let path = TypedPathBuf::from_windows(r"C:\temp");
+    /// let info = path.encoding_info();
+    /// assert_eq!(info.name, "windows");
+    /// assert!(path.as_bytes().iter().any(|&b| info.is_separator(b)));
+    /// ```
+    pub fn encoding_info(&self) -> EncodingInfo {
+        match self {
+            Self::Unix(_) => <UnixEncoding as Encoding>::info(),
+            Self::Windows(_) => <WindowsEncoding as Encoding>::info(),
+        }
+    }
+
     /// Converts this [`TypedPathBuf`] into the Unix variant.
     pub fn with_unix_encoding(&self) -> TypedPathBuf {
         match self {
@@ -120,6 +150,138 @@ impl TypedPathBuf {
         Self::Windows(WindowsPathBuf::from(s.as_ref()))
     }
 
+    /// Creates a new [`TypedPathBuf`] from a [`std::path::Path`] of the platform running the
+    /// code, tagged with the encoding given by [`TypedPath::native_kind`].
+    ///
+    /// The bytes of the path are kept as-is on Unix, while Windows paths are converted to
+    /// [WTF-8](https://simonsapin.github.io/wtf-8/), which matches UTF-8 for any path that is
+    /// valid Unicode and keeps unpaired surrogates otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::path::Path;
+    /// use typed_path::{PathType, TypedPath, TypedPathBuf};
+    ///
+    /// let path = TypedPathBuf::from_native(Path::new("some").join("file.txt"));
+    /// assert_eq!(path.is_windows(), TypedPath::native_kind() == PathType::Windows);
+    /// assert_eq!(path.file_name(), Some(b"file.txt".as_slice()));
+    ///
+    /// // Bytes that are not UTF-8 are kept on Unix
+    /// #[cfg(unix)]
+    /// {
+    ///     use std::ffi::OsStr;
+    ///     use std::os::unix::ffi::OsStrExt;
+    ///
+    ///     let path = TypedPathBuf::from_native(Path::new(OsStr::from_bytes(b"caf\xe9")));
+    ///     assert_eq!(path.as_bytes(), b"caf\xe9");
+    /// }
+    /// ```
+    #[cfg(all(feature = "std", any(unix, windows)))]
+    pub fn from_native(path: impl AsRef<std::path::Path>) -> Self {
+        #[cfg(unix)]
+        {
+            use std::os::unix::ffi::OsStrExt;
+            Self::from_unix(path.as_ref().as_os_str().as_bytes())
+        }
+
+        #[cfg(windows)]
+        {
+            use std::os::windows::ffi::OsStrExt;
+            Self::from_windows(crate::common::wtf8::from_wide(
+                path.as_ref().as_os_str().encode_wide(),
+            ))
+        }
+    }
+
+    /// Decodes a [`TypedPathBuf`] from bytes produced by [`to_wire_bytes`], preserving both the
+    /// encoding of the path and its exact bytes.
+    ///
+    /// [`to_wire_bytes`]: TypedPathBuf::to_wire_bytes
+    ///
+    /// # Errors
+    ///
+    /// Returns [`WireFormatError`] if the bytes are not a single path in the wire format.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{TypedPathBuf, WireFormatError};
+    ///
+    /// let path = TypedPathBuf::from_windows(b"C:\\caf\xe9");
+    /// let bytes = path.to_wire_bytes();
+    ///
+    /// let decoded = TypedPathBuf::from_wire_bytes(&bytes).unwrap();
+    /// assert!(decoded.is_windows());
+    /// assert_eq!(decoded.as_bytes(), b"C:\\caf\xe9");
+    ///
+    /// assert_eq!(
+    ///     TypedPathBuf::from_wire_bytes(&bytes[..bytes.len() - 1]),
+    ///     Err(WireFormatError::UnexpectedEnd),
+    /// );
+    /// ```
+    pub fn from_wire_bytes(bytes: &[u8]) -> Result<Self, WireFormatError> {
+        match Self::read_wire_bytes(bytes)? {
+            (path, []) => Ok(path),
+            _ => Err(WireFormatError::TrailingBytes),
+        }
+    }
+
+    /// Decodes a [`TypedPathBuf`] from the front of bytes produced by [`to_wire_bytes`],
+    /// returning the path alongside the remaining bytes. This allows reading several paths
+    /// written back to back.
+    ///
+    /// [`to_wire_bytes`]: TypedPathBuf::to_wire_bytes
+    ///
+    /// # Errors
+    ///
+    /// Returns [`WireFormatError`] if the bytes do not begin with a path in the wire format.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::TypedPathBuf;
+    ///
+    /// let mut bytes = TypedPathBuf::from_unix("/etc").to_wire_bytes();
+    /// bytes.extend(TypedPathBuf::from_windows(r"C:\Windows").to_wire_bytes());
+    ///
+    /// let (first, rest) = TypedPathBuf::read_wire_bytes(&bytes).unwrap();
+    /// let (second, rest) = TypedPathBuf::read_wire_bytes(rest).unwrap();
+    ///
+    /// assert!(first.is_unix());
+    /// assert!(second.is_windows());
+    /// assert!(rest.is_empty());
+    /// ```
+    pub fn read_wire_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), WireFormatError> {
+        let (r#type, path, rest) = wire::decode(bytes)?;
+        let path = match r#type {
+            PathType::Unix => Self::from_unix(path),
+            PathType::Windows => Self::from_windows(path),
+        };
+
+        Ok((path, rest))
+    }
+
+    /// Encodes the path into a compact, versioned binary format that preserves both the encoding
+    /// of the path and its exact bytes, which can be decoded using [`from_wire_bytes`].
+    ///
+    /// The format consists of a version byte, a byte identifying the encoding, the length of
+    /// the path as an unsigned LEB128 integer, and finally the bytes of the path.
+    ///
+    /// [`from_wire_bytes`]: TypedPathBuf::from_wire_bytes
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::TypedPathBuf;
+    ///
+    /// let path = TypedPathBuf::from_unix("/tmp");
+    /// assert_eq!(path.to_wire_bytes(), b"\x01\x00\x04/tmp");
+    /// ```
+    pub fn to_wire_bytes(&self) -> Vec<u8> {
+        self.to_path().to_wire_bytes()
+    }
+
     /// Converts into a [`TypedPath`].
     pub fn to_path(&self) -> TypedPath {
         match self {
@@ -240,6 +402,162 @@ impl TypedPathBuf {
         }
     }
 
+    /// Extends `self` with `path`, first converting `path` to the encoding of `self` using
+    /// `policy` if the two differ.
+    ///
+    /// Unlike [`TypedPathBuf::push`], which treats its argument as bytes in the encoding of `self`,
+    /// this honors the encoding of `path`, translating separators, roots, `.`, and `..` into
+    /// the encoding of `self`. The converted path is then pushed as with [`TypedPathBuf::push`].
+    ///
+    /// # Errors
+    ///
+    /// With [`ConversionPolicy::Checked`], an error is returned if the conversion of `path`
+    /// fails, including when a Windows path with a prefix is converted to a Unix path. In that
+    /// case, `self` is left unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{CheckedPathError, ConversionPolicy, TypedPath, TypedPathBuf};
+    ///
+    /// let mut path = TypedPathBuf::from_unix("/srv");
+    /// let other = TypedPath::windows(r"www\..\index.html");
+    /// path.push_typed(other, ConversionPolicy::Lossy).unwrap();
+    /// assert_eq!(path.as_bytes(), b"/srv/www/../index.html");
+    ///
+    /// // Lossy conversion discards the prefix of an absolute Windows path
+    /// let mut path = TypedPathBuf::from_unix("/srv");
+    /// path.push_typed(TypedPath::derive(r"C:\assets"), ConversionPolicy::Lossy).unwrap();
+    /// assert_eq!(path.as_bytes(), b"/assets");
+    ///
+    /// // ...but rejected when converting with checks
+    /// let mut path = TypedPathBuf::from_unix("/srv");
+    /// assert_eq!(
+    ///     path.push_typed(TypedPath::derive(r"C:\assets"), ConversionPolicy::Checked),
+    ///     Err(CheckedPathError::UnexpectedPrefix),
+    /// );
+    /// ```
+    pub fn push_typed(
+        &mut self,
+        path: TypedPath<'_>,
+        policy: ConversionPolicy,
+    ) -> Result<(), CheckedPathError> {
+        if self.is_unix() == path.is_unix() {
+            self.push(path);
+            return Ok(());
+        }
+
+        let path = match (self.is_unix(), policy) {
+            (true, ConversionPolicy::Lossy) => path.with_unix_encoding(),
+            (true, ConversionPolicy::Checked) => match path {
+                TypedPath::Windows(p) if p.components().has_prefix() => {
+                    return Err(CheckedPathError::UnexpectedPrefix);
+                }
+                _ => path.with_unix_encoding_checked()?,
+            },
+            (false, ConversionPolicy::Lossy) => path.with_windows_encoding(),
+            (false, ConversionPolicy::Checked) => path.with_windows_encoding_checked()?,
+        };
+
+        self.push(path);
+        Ok(())
+    }
+
+    /// Extends `self` with `path`, failing if `path` uses a different encoding and cannot be
+    /// converted to the encoding of `self` without loss.
+    ///
+    /// This is the same as [`TypedPathBuf::push_typed`] using [`ConversionPolicy::Checked`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{TypedPath, TypedPathBuf};
+    ///
+    /// let mut path = TypedPathBuf::from_windows(r"C:\srv");
+    /// path.try_push(TypedPath::unix("www/index.html")).unwrap();
+    /// assert_eq!(path.as_bytes(), br"C:\srv\www\index.html");
+    ///
+    /// // `:` is not valid within a Windows filename
+    /// assert!(path.try_push(TypedPath::unix("a:b")).is_err());
+    /// ```
+    pub fn try_push(&mut self, path: TypedPath<'_>) -> Result<(), CheckedPathError> {
+        self.push_typed(path, ConversionPolicy::Checked)
+    }
+
+    /// Like [`TypedPathBuf::push`], extends `self` with `path`, but uses the given [`PushMode`] to
+    /// decide what happens when `path` has its own root or prefix.
+    ///
+    /// See [`PathBuf::push_with_mode`] for more details.
+    ///
+    /// [`PathBuf::push_with_mode`]: crate::PathBuf::push_with_mode
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{CheckedPathError, PushMode, TypedPathBuf};
+    ///
+    /// let mut path = TypedPathBuf::from("/srv/www");
+    /// path.push_with_mode("/etc", PushMode::Append).unwrap();
+    /// assert_eq!(path, TypedPathBuf::from("/srv/www/etc"));
+    ///
+    /// assert_eq!(
+    ///     path.push_with_mode("/etc", PushMode::Reject),
+    ///     Err(CheckedPathError::UnexpectedRoot),
+    /// );
+    /// ```
+    pub fn push_with_mode(
+        &mut self,
+        path: impl AsRef<[u8]>,
+        mode: PushMode,
+    ) -> Result<(), CheckedPathError> {
+        match self {
+            Self::Unix(a) => a.push_with_mode(UnixPath::new(&path), mode),
+            Self::Windows(a) => a.push_with_mode(WindowsPath::new(&path), mode),
+        }
+    }
+
+    /// Extends `self` with `path`, never replacing `self` even if `path` is absolute.
+    ///
+    /// See [`PathBuf::push_keeping_base`] for more details.
+    ///
+    /// [`PathBuf::push_keeping_base`]: crate::PathBuf::push_keeping_base
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::TypedPathBuf;
+    ///
+    /// let mut path = TypedPathBuf::from("/srv/www");
+    /// path.push_keeping_base("/index.html");
+    /// assert_eq!(path, TypedPathBuf::from("/srv/www/index.html"));
+    /// ```
+    pub fn push_keeping_base(&mut self, path: impl AsRef<[u8]>) {
+        match self {
+            Self::Unix(a) => a.push_keeping_base(UnixPath::new(&path)),
+            Self::Windows(a) => a.push_keeping_base(WindowsPath::new(&path)),
+        }
+    }
+
+    /// Extends `self` with `path`, failing if `path` has a root or prefix.
+    ///
+    /// See [`PathBuf::push_relative_only`] for more details.
+    ///
+    /// [`PathBuf::push_relative_only`]: crate::PathBuf::push_relative_only
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{CheckedPathError, TypedPathBuf};
+    ///
+    /// let mut path = TypedPathBuf::from("/srv");
+    /// assert_eq!(path.push_relative_only("/etc"), Err(CheckedPathError::UnexpectedRoot));
+    /// assert_eq!(path.push_relative_only("www"), Ok(()));
+    /// assert_eq!(path, TypedPathBuf::from("/srv/www"));
+    /// ```
+    pub fn push_relative_only(&mut self, path: impl AsRef<[u8]>) -> Result<(), CheckedPathError> {
+        self.push_with_mode(path, PushMode::Reject)
+    }
+
     /// Truncates `self` to [`self.parent`].
     ///
     /// Returns `false` and does nothing if [`self.parent`] is [`None`].
@@ -263,6 +581,94 @@ impl TypedPathBuf {
         impl_typed_fn!(self, pop)
     }
 
+    /// Inserts `component` into `self` so that it becomes the component at position `index`,
+    /// shifting all components after it to the right.
+    ///
+    /// See [`PathBuf::insert_component`] for more details.
+    ///
+    /// # Difference from PathBuf
+    ///
+    /// Unlike [`PathBuf::insert_component`], this implementation only supports types that
+    /// implement `AsRef<[u8]>` instead of `AsRef<Path>`.
+    ///
+    /// [`PathBuf::insert_component`]: crate::PathBuf::insert_component
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is greater than the number of components in the path, or if `index`
+    /// falls on the prefix or root of the path.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::TypedPathBuf;
+    ///
+    /// let mut p = TypedPathBuf::from_unix("/usr/lib");
+    /// p.insert_component(1, "opt/local");
+    /// assert_eq!(p.as_bytes(), b"/opt/local/usr/lib");
+    /// ```
+    pub fn insert_component(&mut self, index: usize, component: impl AsRef<[u8]>) {
+        match self {
+            Self::Unix(p) => p.insert_component(index, UnixPath::new(&component)),
+            Self::Windows(p) => p.insert_component(index, WindowsPath::new(&component)),
+        }
+    }
+
+    /// Removes the component at position `index` within `self`, shifting all components after it
+    /// to the left.
+    ///
+    /// Returns `false` and does nothing if `index` is out of range. Otherwise, returns `true`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::TypedPathBuf;
+    ///
+    /// let mut p = TypedPathBuf::from_unix("/usr/local/lib");
+    /// assert!(p.remove_component(2));
+    /// assert_eq!(p.as_bytes(), b"/usr/lib");
+    /// ```
+    pub fn remove_component(&mut self, index: usize) -> bool {
+        impl_typed_fn!(self, remove_component, index)
+    }
+
+    /// Retains only the components of `self` for which `f` returns `true`, passing each
+    /// component of the path in order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::TypedPathBuf;
+    ///
+    /// let mut p = TypedPathBuf::from_unix("/usr/local/lib");
+    /// p.retain_components(|c| c.as_bytes() != b"local");
+    /// assert_eq!(p.as_bytes(), b"/usr/lib");
+    /// ```
+    pub fn retain_components<F>(&mut self, mut f: F)
+    where
+        F: for<'a> FnMut(TypedComponent<'a>) -> bool,
+    {
+        match self {
+            Self::Unix(p) => p.retain_components(|c| f(TypedComponent::Unix(c))),
+            Self::Windows(p) => p.retain_components(|c| f(TypedComponent::Windows(c))),
+        }
+    }
+
+    /// Shortens `self` to its first `len` components, removing any separators that follow them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::TypedPathBuf;
+    ///
+    /// let mut p = TypedPathBuf::from_unix("/usr/local/lib");
+    /// p.truncate_components(2);
+    /// assert_eq!(p.as_bytes(), b"/usr");
+    /// ```
+    pub fn truncate_components(&mut self, len: usize) {
+        impl_typed_fn!(self, truncate_components, len)
+    }
+
     /// Updates [`self.file_name`] to `file_name`.
     ///
     /// If [`self.file_name`] was [`None`], this is equivalent to pushing
@@ -336,6 +742,23 @@ impl TypedPathBuf {
         impl_typed_fn!(self, into_vec)
     }
 
+    /// Consumes the [`TypedPathBuf`], yielding its internal [`Vec<u8>`] storage.
+    ///
+    /// This is the same as [`TypedPathBuf::into_vec`], named after [`String::into_bytes`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::TypedPathBuf;
+    ///
+    /// let p = TypedPathBuf::from_windows(r"C:\the\head");
+    /// assert_eq!(p.into_bytes(), br"C:\the\head");
+    /// ```
+    #[inline]
+    pub fn into_bytes(self) -> Vec<u8> {
+        impl_typed_fn!(self, into_bytes)
+    }
+
     /// Invokes [`capacity`] on the underlying instance of [`Vec`].
     ///
     /// [`capacity`]: Vec::capacity
@@ -463,6 +886,27 @@ impl TypedPathBuf {
         impl_typed_fn!(self, to_string_lossy)
     }
 
+    /// Returns an object that implements [`Display`] for printing the path with any non-Unicode
+    /// data escaped as `\xNN` rather than lossily replaced, optionally surrounded by quotes.
+    ///
+    /// See [`Path::escaped_display`] for more details.
+    ///
+    /// [`Display`]: core::fmt::Display
+    /// [`Path::escaped_display`]: crate::Path::escaped_display
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::TypedPathBuf;
+    ///
+    /// let path = TypedPathBuf::from(&b"/tmp/fo\xf0\x90\x80.txt"[..]);
+    /// assert_eq!(path.escaped_display().to_string(), r"/tmp/fo\xF0\x90\x80.txt");
+    /// ```
+    #[inline]
+    pub fn escaped_display(&self) -> EscapedDisplay<'_> {
+        EscapedDisplay::new(self.as_bytes())
+    }
+
     /// Returns `true` if the [`TypedPathBuf`] is absolute, i.e., if it is independent of
     /// the current directory.
     ///
@@ -530,6 +974,73 @@ impl TypedPathBuf {
         impl_typed_fn!(self, has_root)
     }
 
+    /// Returns the prefix and root at the start of the [`TypedPathBuf`], if it has either, such as `/`,
+    /// `C:\`, or `\\server\share\`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{TypedPath, TypedPathBuf};
+    ///
+    /// let path = TypedPathBuf::from(r"C:\Windows");
+    /// assert_eq!(path.root(), Some(TypedPath::derive(r"C:\")));
+    /// ```
+    pub fn root(&self) -> Option<TypedPath<'_>> {
+        self.to_path().root()
+    }
+
+    /// Splits the [`TypedPathBuf`] into its prefix and root, as returned by [`TypedPathBuf::root`], and the
+    /// relative remainder of the path.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{TypedPath, TypedPathBuf};
+    ///
+    /// let path = TypedPathBuf::from("/usr/lib");
+    /// let (root, rest) = path.split_root();
+    /// assert_eq!(root, Some(TypedPath::derive("/")));
+    /// assert_eq!(rest, TypedPath::derive("usr/lib"));
+    /// ```
+    pub fn split_root(&self) -> (Option<TypedPath<'_>>, TypedPath<'_>) {
+        self.to_path().split_root()
+    }
+
+    /// Returns `true` if the [`TypedPathBuf`] is made up of nothing more than a root, such as `/`, `C:\`,
+    /// or `\\server\share`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::TypedPathBuf;
+    ///
+    /// assert!(TypedPathBuf::from("/").is_root());
+    /// assert!(TypedPathBuf::from(r"C:\").is_root());
+    /// assert!(!TypedPathBuf::from("C:").is_root());
+    /// assert!(!TypedPathBuf::from("/etc").is_root());
+    /// ```
+    #[inline]
+    pub fn is_root(&self) -> bool {
+        impl_typed_fn!(self, is_root)
+    }
+
+    /// Returns `true` if the [`TypedPathBuf`] is made up of nothing more than a prefix without a root,
+    /// such as `C:`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::TypedPathBuf;
+    ///
+    /// assert!(TypedPathBuf::from("C:").is_prefix_only());
+    /// assert!(!TypedPathBuf::from(r"C:\").is_prefix_only());
+    /// assert!(!TypedPathBuf::from("/").is_prefix_only());
+    /// ```
+    #[inline]
+    pub fn is_prefix_only(&self) -> bool {
+        impl_typed_fn!(self, is_prefix_only)
+    }
+
     /// Returns the [`TypedPathBuf`] without its final component, if there is one.
     ///
     /// Returns [`None`] if the path terminates in a root or prefix.
@@ -586,14 +1097,61 @@ impl TypedPathBuf {
         self.to_path().ancestors()
     }
 
-    /// Returns the final component of the [`TypedPathBuf`], if there is one.
+    /// Produces an iterator over [`TypedPathBuf`] and its ancestors that stops after yielding
+    /// `base`.
     ///
-    /// If the path is a normal file, this is the file name. If it's the path of a directory, this
-    /// is the directory name.
+    /// See [`Path::ancestors_until`] for more details.
     ///
-    /// Returns [`None`] if the path terminates in `..`.
+    /// # Difference from Path
     ///
-    /// # Examples
+    /// Unlike [`Path::ancestors_until`], this implementation only supports types that implement
+    /// `AsRef<[u8]>` instead of `AsRef<Path>`.
+    ///
+    /// [`Path::ancestors_until`]: crate::Path::ancestors_until
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{TypedPath, TypedPathBuf};
+    ///
+    /// let path = TypedPathBuf::from("/home/user/project/src");
+    /// let mut ancestors = path.ancestors_until("/home/user/project");
+    /// assert_eq!(ancestors.next(), Some(TypedPath::derive("/home/user/project/src")));
+    /// assert_eq!(ancestors.next(), Some(TypedPath::derive("/home/user/project")));
+    /// assert_eq!(ancestors.next(), None);
+    /// ```
+    pub fn ancestors_until(&self, base: impl AsRef<[u8]>) -> TypedAncestors<'_> {
+        self.to_path().ancestors_until(base)
+    }
+
+    /// Returns the `n`th ancestor of the path, or [`None`] if the path has fewer than `n`
+    /// parents.
+    ///
+    /// See [`Path::nth_parent`] for more details.
+    ///
+    /// [`Path::nth_parent`]: crate::Path::nth_parent
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{TypedPath, TypedPathBuf};
+    ///
+    /// let path = TypedPathBuf::from("/foo/bar/baz");
+    /// assert_eq!(path.nth_parent(2), Some(TypedPath::derive("/foo")));
+    /// assert_eq!(path.nth_parent(4), None);
+    /// ```
+    pub fn nth_parent(&self, n: usize) -> Option<TypedPath<'_>> {
+        self.to_path().nth_parent(n)
+    }
+
+    /// Returns the final component of the [`TypedPathBuf`], if there is one.
+    ///
+    /// If the path is a normal file, this is the file name. If it's the path of a directory, this
+    /// is the directory name.
+    ///
+    /// Returns [`None`] if the path terminates in `..`.
+    ///
+    /// # Examples
     ///
     /// ```
     /// use typed_path::TypedPathBuf;
@@ -646,6 +1204,33 @@ impl TypedPathBuf {
         }
     }
 
+    /// Returns a relative path that, when joined onto `base`, refers to the same location as
+    /// `self`.
+    ///
+    /// See [`Path::relative_to`] for more details.
+    ///
+    /// # Difference from Path
+    ///
+    /// Unlike [`Path::relative_to`], this implementation only supports types that implement
+    /// `AsRef<[u8]>` instead of `AsRef<Path>`.
+    ///
+    /// [`Path::relative_to`]: crate::Path::relative_to
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::TypedPathBuf;
+    ///
+    /// let path = TypedPathBuf::from("/usr/share/doc");
+    /// assert_eq!(path.relative_to("/usr/lib").unwrap().as_bytes(), b"../share/doc");
+    /// ```
+    pub fn relative_to(
+        &self,
+        base: impl AsRef<[u8]>,
+    ) -> Result<TypedPathBuf, DifferentPrefixError> {
+        self.to_path().relative_to(base)
+    }
+
     /// Determines whether `base` is a prefix of `self`.
     ///
     /// Only considers whole path components to match.
@@ -708,6 +1293,243 @@ impl TypedPathBuf {
         self.to_path().ends_with(child)
     }
 
+    /// Determines whether `self` is a strict ancestor of `other`.
+    ///
+    /// See [`Path::is_ancestor_of`] for more details.
+    ///
+    /// # Difference from Path
+    ///
+    /// Unlike [`Path::is_ancestor_of`], this implementation only supports types that implement
+    /// `AsRef<[u8]>` instead of `AsRef<Path>`.
+    ///
+    /// [`Path::is_ancestor_of`]: crate::Path::is_ancestor_of
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::TypedPathBuf;
+    ///
+    /// let path = TypedPathBuf::from("/etc");
+    /// assert!(path.is_ancestor_of("/etc/passwd"));
+    /// assert!(!path.is_ancestor_of("/etcetera"));
+    /// ```
+    pub fn is_ancestor_of(&self, other: impl AsRef<[u8]>) -> bool {
+        self.to_path().is_ancestor_of(other)
+    }
+
+    /// Determines whether `self` is either the same path as `other` or one of its ancestors.
+    ///
+    /// See [`Path::is_inclusive_ancestor_of`] for more details.
+    ///
+    /// # Difference from Path
+    ///
+    /// Unlike [`Path::is_inclusive_ancestor_of`], this implementation only supports types that
+    /// implement `AsRef<[u8]>` instead of `AsRef<Path>`.
+    ///
+    /// [`Path::is_inclusive_ancestor_of`]: crate::Path::is_inclusive_ancestor_of
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::TypedPathBuf;
+    ///
+    /// let path = TypedPathBuf::from("/etc");
+    /// assert!(path.is_inclusive_ancestor_of("/etc"));
+    /// ```
+    pub fn is_inclusive_ancestor_of(&self, other: impl AsRef<[u8]>) -> bool {
+        self.to_path().is_inclusive_ancestor_of(other)
+    }
+
+    /// Determines whether `self` is a strict descendant of `other`.
+    ///
+    /// See [`Path::is_descendant_of`] for more details.
+    ///
+    /// # Difference from Path
+    ///
+    /// Unlike [`Path::is_descendant_of`], this implementation only supports types that implement
+    /// `AsRef<[u8]>` instead of `AsRef<Path>`.
+    ///
+    /// [`Path::is_descendant_of`]: crate::Path::is_descendant_of
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::TypedPathBuf;
+    ///
+    /// let path = TypedPathBuf::from("/etc/passwd");
+    /// assert!(path.is_descendant_of("/etc"));
+    /// assert!(!path.is_descendant_of("/et"));
+    /// ```
+    pub fn is_descendant_of(&self, other: impl AsRef<[u8]>) -> bool {
+        self.to_path().is_descendant_of(other)
+    }
+
+    /// Determines whether `self` is either the same path as `other` or one of its descendants.
+    ///
+    /// See [`Path::is_inclusive_descendant_of`] for more details.
+    ///
+    /// # Difference from Path
+    ///
+    /// Unlike [`Path::is_inclusive_descendant_of`], this implementation only supports types that
+    /// implement `AsRef<[u8]>` instead of `AsRef<Path>`.
+    ///
+    /// [`Path::is_inclusive_descendant_of`]: crate::Path::is_inclusive_descendant_of
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::TypedPathBuf;
+    ///
+    /// let path = TypedPathBuf::from("/etc/passwd");
+    /// assert!(path.is_inclusive_descendant_of("/etc/passwd"));
+    /// ```
+    pub fn is_inclusive_descendant_of(&self, other: impl AsRef<[u8]>) -> bool {
+        self.to_path().is_inclusive_descendant_of(other)
+    }
+
+    /// Determines whether `needle` appears anywhere within `self` as a sequence of whole
+    /// components.
+    ///
+    /// # Difference from Path
+    ///
+    /// Unlike [`Path::contains_path`], this implementation only supports types that implement
+    /// `AsRef<[u8]>` instead of `AsRef<Path>`.
+    ///
+    /// [`Path::contains_path`]: crate::Path::contains_path
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::TypedPathBuf;
+    ///
+    /// let path = TypedPathBuf::from("/repo/node_modules/.bin/tsc");
+    /// assert!(path.contains_path("node_modules/.bin"));
+    /// ```
+    pub fn contains_path(&self, needle: impl AsRef<[u8]>) -> bool {
+        self.to_path().contains_path(needle)
+    }
+
+    /// Locates the first occurrence of `needle` within `self` as a sequence of whole components,
+    /// returning the index of the first matching component alongside the span of bytes within
+    /// `self` covered by the matching components.
+    ///
+    /// # Difference from Path
+    ///
+    /// Unlike [`Path::find_subpath`], this implementation only supports types that implement
+    /// `AsRef<[u8]>` instead of `AsRef<Path>`.
+    ///
+    /// [`Path::find_subpath`]: crate::Path::find_subpath
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::TypedPathBuf;
+    ///
+    /// let path = TypedPathBuf::from("/repo/node_modules/.bin/tsc");
+    /// assert_eq!(path.find_subpath("node_modules/.bin"), Some((2, 6..23)));
+    /// ```
+    pub fn find_subpath(&self, needle: impl AsRef<[u8]>) -> Option<(usize, Range<usize>)> {
+        self.to_path().find_subpath(needle)
+    }
+
+    /// Creates an owned [`TypedPathBuf`] where every non-overlapping occurrence of the components
+    /// of `from` within `self` is replaced with `to`.
+    ///
+    /// See [`Path::replace_components`] for more details.
+    ///
+    /// # Difference from Path
+    ///
+    /// Unlike [`Path::replace_components`], this implementation only supports types that
+    /// implement `AsRef<[u8]>` instead of `AsRef<Path>`.
+    ///
+    /// [`Path::replace_components`]: crate::Path::replace_components
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::TypedPathBuf;
+    ///
+    /// let path = TypedPathBuf::from("/repo/src/main.rs");
+    /// assert_eq!(
+    ///     path.replace_components("src", "generated").as_bytes(),
+    ///     b"/repo/generated/main.rs",
+    /// );
+    /// ```
+    pub fn replace_components(&self, from: impl AsRef<[u8]>, to: impl AsRef<[u8]>) -> TypedPathBuf {
+        self.to_path().replace_components(from, to)
+    }
+
+    /// Creates an owned [`TypedPathBuf`] where each component of `self` is replaced with the
+    /// bytes returned by `f`.
+    ///
+    /// See [`Path::map_components`] for more details.
+    ///
+    /// [`Path::map_components`]: crate::Path::map_components
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::TypedPathBuf;
+    ///
+    /// let path = TypedPathBuf::from("/repo/src/main.rs");
+    /// let upper = path.map_components(|c| c.as_bytes().to_ascii_uppercase());
+    /// assert_eq!(upper.as_bytes(), b"/REPO/SRC/MAIN.RS");
+    /// ```
+    pub fn map_components<'a, F, C>(&'a self, f: F) -> TypedPathBuf
+    where
+        F: FnMut(TypedComponent<'a>) -> C,
+        C: AsRef<[u8]>,
+    {
+        self.to_path().map_components(f)
+    }
+
+    /// Determines whether `other` refers to the same place as `self` after lexical
+    /// normalization, using the case rules of the encoding.
+    ///
+    /// See [`Path::normalized_eq`] for more details.
+    ///
+    /// # Difference from Path
+    ///
+    /// Unlike [`Path::normalized_eq`], this implementation only supports types that implement
+    /// `AsRef<[u8]>` instead of `AsRef<Path>`.
+    ///
+    /// [`Path::normalized_eq`]: crate::Path::normalized_eq
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::TypedPathBuf;
+    ///
+    /// assert!(TypedPathBuf::from("/etc/./ssh//config").normalized_eq("/etc/ssl/../ssh/config"));
+    /// ```
+    pub fn normalized_eq(&self, other: impl AsRef<[u8]>) -> bool {
+        self.to_path().normalized_eq(other)
+    }
+
+    /// Determines whether `other` refers to the same place as `self` using the given
+    /// [`PathEquivalence`] rules.
+    ///
+    /// See [`Path::equivalent_with`] for more details.
+    ///
+    /// # Difference from Path
+    ///
+    /// Unlike [`Path::equivalent_with`], this implementation only supports types that implement
+    /// `AsRef<[u8]>` instead of `AsRef<Path>`.
+    ///
+    /// [`Path::equivalent_with`]: crate::Path::equivalent_with
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{PathEquivalence, TypedPathBuf};
+    ///
+    /// let rules = PathEquivalence::new().case_sensitive(false);
+    /// assert!(TypedPathBuf::from("/Users/Me").equivalent_with("/users/me", &rules));
+    /// ```
+    pub fn equivalent_with(&self, other: impl AsRef<[u8]>, rules: &PathEquivalence) -> bool {
+        self.to_path().equivalent_with(other, rules)
+    }
+
     /// Extracts the stem (non-extension) portion of [`self.file_name`].
     ///
     /// [`self.file_name`]: TypedPathBuf::file_name
@@ -756,6 +1578,45 @@ impl TypedPathBuf {
         impl_typed_fn!(self, extension)
     }
 
+    /// Returns true if the file name of the path marks it as hidden by convention alone, without
+    /// consulting any filesystem attributes.
+    ///
+    /// See [`Path::is_hidden_by_name`] for more details.
+    ///
+    /// [`Path::is_hidden_by_name`]: crate::Path::is_hidden_by_name
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::TypedPathBuf;
+    ///
+    /// assert!(TypedPathBuf::from("/home/user/.profile").is_hidden_by_name());
+    /// assert!(TypedPathBuf::from(r"C:\Docs\~$notes.docx").is_hidden_by_name());
+    /// assert!(!TypedPathBuf::from(r"C:\Docs\notes.docx").is_hidden_by_name());
+    /// ```
+    pub fn is_hidden_by_name(&self) -> bool {
+        impl_typed_fn!(self, is_hidden_by_name)
+    }
+
+    /// Guesses the media type of the path's content from its extension, ignoring ascii case.
+    ///
+    /// See [`Path::mime_type`] for more details.
+    ///
+    /// [`Path::mime_type`]: crate::Path::mime_type
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::TypedPathBuf;
+    ///
+    /// assert_eq!(TypedPathBuf::from(r"C:\www\photo.jpeg").mime_type(), Some("image/jpeg"));
+    /// assert_eq!(TypedPathBuf::from("/www/data.tar").mime_type(), Some("application/x-tar"));
+    /// assert_eq!(TypedPathBuf::from("/www/data.unknown").mime_type(), None);
+    /// ```
+    pub fn mime_type(&self) -> Option<&'static str> {
+        impl_typed_fn!(self, mime_type)
+    }
+
     /// Returns an owned [`TypedPathBuf`] by resolving `..` and `.` segments.
     ///
     /// When multiple, sequential path segment separation characters are found (e.g. `/` for Unix
@@ -806,6 +1667,46 @@ impl TypedPathBuf {
         self.to_path().normalize()
     }
 
+    /// Returns an owned [`TypedPathBuf`] by lexically cleaning the path in the same way as Go's
+    /// `path/filepath.Clean`.
+    ///
+    /// See [`Path::clean`] for more details.
+    ///
+    /// [`Path::clean`]: crate::Path::clean
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::TypedPathBuf;
+    ///
+    /// assert_eq!(TypedPathBuf::from("a/../../b/./c/").clean(), TypedPathBuf::from("../b/c"));
+    /// ```
+    pub fn clean(&self) -> TypedPathBuf {
+        self.to_path().clean()
+    }
+
+    /// Returns an owned [`TypedPathBuf`] by normalizing the path using the given
+    /// [`NormalizationOptions`].
+    ///
+    /// See [`Path::normalize_with`] for more details.
+    ///
+    /// [`Path::normalize_with`]: crate::Path::normalize_with
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{NormalizationOptions, TypedPathBuf};
+    ///
+    /// let options = NormalizationOptions::new().preserve_leading_parent(true);
+    /// assert_eq!(
+    ///     TypedPathBuf::from("../foo//./bar/..").normalize_with(&options),
+    ///     TypedPathBuf::from("../foo"),
+    /// );
+    /// ```
+    pub fn normalize_with(&self, options: &NormalizationOptions) -> TypedPathBuf {
+        self.to_path().normalize_with(options)
+    }
+
     /// Converts a path to an absolute form by [`normalizing`] the path, returning a
     /// [`TypedPathBuf`].
     ///
@@ -894,6 +1795,119 @@ impl TypedPathBuf {
         self.to_path().join_checked(path)
     }
 
+    /// Creates an owned [`TypedPathBuf`] with `path` adjoined to `self`, first converting `path` to
+    /// the encoding of `self` using `policy` if the two differ.
+    ///
+    /// See [`TypedPathBuf::push_typed`] for more details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{ConversionPolicy, TypedPath, TypedPathBuf};
+    ///
+    /// let base = TypedPathBuf::from(r"C:\srv");
+    /// let other = TypedPath::unix("www/index.html");
+    /// let path = base.join_typed(other, ConversionPolicy::Lossy);
+    /// assert_eq!(path.unwrap().as_bytes(), br"C:\srv\www\index.html");
+    /// ```
+    pub fn join_typed(
+        &self,
+        path: TypedPath<'_>,
+        policy: ConversionPolicy,
+    ) -> Result<TypedPathBuf, CheckedPathError> {
+        let mut buf = self.clone();
+        buf.push_typed(path, policy)?;
+        Ok(buf)
+    }
+
+    /// Creates an owned [`TypedPathBuf`] with `path` adjoined to `self`, failing if `path` uses a
+    /// different encoding and cannot be converted to the encoding of `self` without loss.
+    ///
+    /// This is the same as [`TypedPathBuf::join_typed`] using [`ConversionPolicy::Checked`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{CheckedPathError, TypedPath, TypedPathBuf};
+    ///
+    /// let base = TypedPathBuf::from("/srv");
+    /// assert_eq!(
+    ///     base.try_join(TypedPath::windows(r"www\index.html")).unwrap().as_bytes(),
+    ///     b"/srv/www/index.html",
+    /// );
+    /// assert_eq!(
+    ///     base.try_join(TypedPath::derive(r"D:\www")),
+    ///     Err(CheckedPathError::UnexpectedPrefix),
+    /// );
+    /// ```
+    pub fn try_join(&self, path: TypedPath<'_>) -> Result<TypedPathBuf, CheckedPathError> {
+        self.join_typed(path, ConversionPolicy::Checked)
+    }
+
+    /// Creates an owned [`TypedPathBuf`] with `path` adjoined to `self` using the given
+    /// [`PushMode`] to decide what happens when `path` has its own root or prefix.
+    ///
+    /// See [`TypedPathBuf::push_with_mode`] for more details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{PushMode, TypedPathBuf};
+    ///
+    /// assert_eq!(
+    ///     TypedPathBuf::from("/srv").join_with_mode("/etc", PushMode::Append),
+    ///     Ok(TypedPathBuf::from("/srv/etc")),
+    /// );
+    /// ```
+    pub fn join_with_mode(
+        &self,
+        path: impl AsRef<[u8]>,
+        mode: PushMode,
+    ) -> Result<TypedPathBuf, CheckedPathError> {
+        self.to_path().join_with_mode(path, mode)
+    }
+
+    /// Creates an owned [`TypedPathBuf`] with `path` adjoined to `self`, never replacing `self`
+    /// even if `path` is absolute.
+    ///
+    /// See [`TypedPathBuf::push_keeping_base`] for more details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::TypedPathBuf;
+    ///
+    /// assert_eq!(
+    ///     TypedPathBuf::from("/srv").join_keeping_base("/etc"),
+    ///     TypedPathBuf::from("/srv/etc"),
+    /// );
+    /// ```
+    pub fn join_keeping_base(&self, path: impl AsRef<[u8]>) -> TypedPathBuf {
+        self.to_path().join_keeping_base(path)
+    }
+
+    /// Creates an owned [`TypedPathBuf`] with `path` adjoined to `self`, failing if `path` has a
+    /// root or prefix.
+    ///
+    /// See [`TypedPathBuf::push_relative_only`] for more details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{CheckedPathError, TypedPathBuf};
+    ///
+    /// assert_eq!(
+    ///     TypedPathBuf::from("/srv").join_relative_only("/etc"),
+    ///     Err(CheckedPathError::UnexpectedRoot),
+    /// );
+    /// ```
+    pub fn join_relative_only(
+        &self,
+        path: impl AsRef<[u8]>,
+    ) -> Result<TypedPathBuf, CheckedPathError> {
+        self.to_path().join_relative_only(path)
+    }
+
     /// Creates an owned [`TypedPathBuf`] like `self` but with the given file name.
     ///
     /// See [`TypedPathBuf::set_file_name`] for more details.
@@ -934,6 +1948,105 @@ impl TypedPathBuf {
         self.to_path().with_extension(extension)
     }
 
+    /// Creates an owned [`TypedPathBuf`] like `self` but with every normal component shortened
+    /// to at most `n_bytes` bytes.
+    ///
+    /// See [`Path::truncate_component_to`] for more details.
+    ///
+    /// [`Path::truncate_component_to`]: crate::Path::truncate_component_to
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::TypedPathBuf;
+    ///
+    /// let path = TypedPathBuf::from("/photos/holiday_in_spain.jpeg");
+    /// assert_eq!(path.truncate_component_to(8).as_bytes(), b"/photos/hol.jpeg");
+    /// ```
+    pub fn truncate_component_to(&self, n_bytes: usize) -> TypedPathBuf {
+        self.to_path().truncate_component_to(n_bytes)
+    }
+
+    /// Renders `self` for display within `max_width` columns by replacing leading directories with
+    /// `…`, always keeping the prefix, root, and file name.
+    ///
+    /// See [`Path::abbreviate`] for more details.
+    ///
+    /// [`Path::abbreviate`]: crate::Path::abbreviate
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::TypedPathBuf;
+    ///
+    /// let path = TypedPathBuf::from(r"C:\Users\user\Documents\file.rs");
+    /// assert_eq!(path.abbreviate(22), r"C:\…\Documents\file.rs");
+    /// ```
+    pub fn abbreviate(&self, max_width: usize) -> String {
+        impl_typed_fn!(self, abbreviate, max_width)
+    }
+
+    /// Produces an owned key whose plain byte order matches the order of paths as trees, so that
+    /// paths can be stored in key-value stores without a custom comparator.
+    ///
+    /// See [`Path::sort_key`] for more details.
+    ///
+    /// [`Path::sort_key`]: crate::Path::sort_key
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::TypedPathBuf;
+    ///
+    /// let key = |s| TypedPathBuf::from(s).sort_key();
+    /// assert!(key("/foo") < key("/foo/bar"));
+    /// assert!(key("/foo/bar") < key("/foo-bar"));
+    /// ```
+    pub fn sort_key(&self) -> Vec<u8> {
+        impl_typed_fn!(self, sort_key)
+    }
+
+    /// Returns the bounds of the sort keys covering exactly `self` and its descendants, for prefix
+    /// scans over a key-value store ordered by sort key.
+    ///
+    /// See [`Path::range_bounds_for_subtree`] for more details.
+    ///
+    /// [`Path::range_bounds_for_subtree`]: crate::Path::range_bounds_for_subtree
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::ops::RangeBounds;
+    /// use typed_path::TypedPathBuf;
+    ///
+    /// let bounds = TypedPathBuf::from("/foo").range_bounds_for_subtree();
+    /// assert!(bounds.contains(&TypedPathBuf::from("/foo/bar").sort_key()));
+    /// assert!(!bounds.contains(&TypedPathBuf::from("/foo-bar").sort_key()));
+    /// ```
+    pub fn range_bounds_for_subtree(&self) -> (Bound<Vec<u8>>, Bound<Vec<u8>>) {
+        impl_typed_fn!(self, range_bounds_for_subtree)
+    }
+
+    /// Creates an owned [`TypedPathBuf`] like `self` but with its file name shortened such that
+    /// the entire path is at most `total_bytes` bytes.
+    ///
+    /// See [`Path::fit_within`] for more details.
+    ///
+    /// [`Path::fit_within`]: crate::Path::fit_within
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::TypedPathBuf;
+    ///
+    /// let path = TypedPathBuf::from(r"C:\notes\meeting.md");
+    /// assert_eq!(path.fit_within(15).unwrap().as_bytes(), br"C:\notes\mee.md");
+    /// assert_eq!(path.fit_within(9), None);
+    /// ```
+    pub fn fit_within(&self, total_bytes: usize) -> Option<TypedPathBuf> {
+        self.to_path().fit_within(total_bytes)
+    }
+
     /// Produces an iterator over the [`TypedComponent`]s of the path.
     ///
     /// When parsing the path, there is a small amount of normalization:
@@ -997,6 +2110,20 @@ impl TypedPathBuf {
     }
 }
 
+impl fmt::Debug for TypedPathBuf {
+    /// Writes the encoding of the path along with its bytes, the same as [`TypedPath`] does.
+    ///
+    /// ```
+    /// use typed_path::TypedPathBuf;
+    ///
+    /// let path = TypedPathBuf::from_unix(b"/tmp/caf\xe9");
+    /// assert_eq!(format!("{path:?}"), r#"Unix("/tmp/caf\xE9")"#);
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.to_path(), f)
+    }
+}
+
 impl AsRef<[u8]> for TypedPathBuf {
     #[inline]
     fn as_ref(&self) -> &[u8] {
@@ -1069,6 +2196,16 @@ impl From<String> for TypedPathBuf {
     }
 }
 
+impl From<TypedPathBuf> for Vec<u8> {
+    /// Converts a [`TypedPathBuf`] into a [`Vec<u8>`]
+    ///
+    /// This conversion does not allocate or copy memory.
+    #[inline]
+    fn from(path_buf: TypedPathBuf) -> Self {
+        path_buf.into_vec()
+    }
+}
+
 impl TryFrom<TypedPathBuf> for UnixPathBuf {
     type Error = TypedPathBuf;
 
@@ -1111,3 +2248,12 @@ impl PartialEq<TypedPath<'_>> for TypedPathBuf {
         path.eq(&self.to_path())
     }
 }
+
+impl_typed_cmp!(; TypedPathBuf, [u8], [u8], as_bytes);
+impl_typed_cmp!('a; TypedPathBuf, &'a [u8], [u8], as_bytes);
+impl_typed_cmp!(; TypedPathBuf, Vec<u8>, [u8], as_bytes);
+impl_typed_cmp!(; TypedPathBuf, str, [u8], as_bytes);
+impl_typed_cmp!('a; TypedPathBuf, &'a str, [u8], as_bytes);
+impl_typed_cmp!(; TypedPathBuf, String, [u8], as_bytes);
+impl_typed_cmp_utf8!('a; TypedPathBuf, Utf8TypedPath<'a>);
+impl_typed_cmp_utf8!(; TypedPathBuf, Utf8TypedPathBuf);
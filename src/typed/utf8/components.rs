@@ -17,24 +17,43 @@ pub enum Utf8TypedComponents<'a> {
 impl<'a> Utf8TypedComponents<'a> {
     /// Extracts a slice corresponding to the portion of the path remaining for iteration.
     ///
+    /// As with `std::path::Components::as_path`, trailing separators and current directory
+    /// components that will not be yielded are left out.
+    ///
     /// # Examples
     ///
     /// ```
     /// use typed_path::Utf8TypedPath;
     ///
-    /// let mut components = Utf8TypedPath::derive("/tmp/foo/bar.txt").components();
-    /// components.next();
+    /// let mut components = Utf8TypedPath::derive("/tmp/foo/./").components();
     /// components.next();
     ///
-    /// assert_eq!(Utf8TypedPath::derive("foo/bar.txt"), components.to_path());
+    /// assert_eq!(Utf8TypedPath::derive("tmp/foo"), components.as_path());
     /// ```
-    pub fn to_path(&self) -> Utf8TypedPath<'a> {
+    pub fn as_path(&self) -> Utf8TypedPath<'a> {
         match self {
             Self::Unix(components) => Utf8TypedPath::Unix(components.as_path()),
             Self::Windows(components) => Utf8TypedPath::Windows(components.as_path()),
         }
     }
 
+    /// Extracts a slice corresponding to the portion of the path remaining for iteration.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::Utf8TypedPath;
+    ///
+    /// let mut components = Utf8TypedPath::derive("/tmp/foo/bar.txt").components();
+    /// components.next();
+    /// components.next();
+    ///
+    /// assert_eq!(Utf8TypedPath::derive("foo/bar.txt"), components.to_path());
+    /// ```
+    pub fn to_path(&self) -> Utf8TypedPath<'a> {
+        self.as_path()
+    }
+
     /// Extracts a slice corresponding to the portion of the path remaining for iteration.
     pub fn as_str(&self) -> &'a str {
         impl_typed_fn!(self, as_str)
@@ -3,7 +3,7 @@ use core::fmt;
 use crate::typed::Utf8TypedPath;
 use crate::unix::Utf8UnixComponent;
 use crate::windows::Utf8WindowsComponent;
-use crate::{private, Utf8Component};
+use crate::{private, ComponentKind, Utf8Component};
 
 /// Str slice version of [`std::path::Component`] that represents either a Unix or Windows path
 /// component.
@@ -136,6 +136,29 @@ impl<'a> Utf8TypedComponent<'a> {
         impl_typed_fn!(self, is_current)
     }
 
+    /// Returns the [`ComponentKind`] of this component.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{ComponentKind, Utf8TypedPath};
+    ///
+    /// let path = Utf8TypedPath::derive(r"C:\..\file.txt");
+    /// let kinds: Vec<_> = path.components().map(|c| c.kind()).collect();
+    /// assert_eq!(
+    ///     kinds,
+    ///     [
+    ///         ComponentKind::Prefix,
+    ///         ComponentKind::RootDir,
+    ///         ComponentKind::ParentDir,
+    ///         ComponentKind::Normal,
+    ///     ],
+    /// );
+    /// ```
+    pub fn kind(&self) -> ComponentKind {
+        impl_typed_fn!(self, kind)
+    }
+
     /// Returns str length of component.
     pub fn len(&self) -> usize {
         impl_typed_fn!(self, len)
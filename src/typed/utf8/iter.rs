@@ -22,24 +22,43 @@ pub enum Utf8TypedIter<'a> {
 impl<'a> Utf8TypedIter<'a> {
     /// Extracts a slice corresponding to the portion of the path remaining for iteration.
     ///
+    /// As with `std::path::Components::as_path`, trailing separators and current directory
+    /// components that will not be yielded are left out.
+    ///
     /// # Examples
     ///
     /// ```
     /// use typed_path::Utf8TypedPath;
     ///
-    /// let mut iter = Utf8TypedPath::derive("/tmp/foo/bar.txt").iter();
-    /// iter.next();
+    /// let mut iter = Utf8TypedPath::derive("/tmp/foo/./").iter();
     /// iter.next();
     ///
-    /// assert_eq!(Utf8TypedPath::derive("foo/bar.txt"), iter.to_path());
+    /// assert_eq!(Utf8TypedPath::derive("tmp/foo"), iter.as_path());
     /// ```
-    pub fn to_path(&self) -> Utf8TypedPath {
+    pub fn as_path(&self) -> Utf8TypedPath<'a> {
         match self {
             Self::Unix(it) => Utf8TypedPath::Unix(it.as_path()),
             Self::Windows(it) => Utf8TypedPath::Windows(it.as_path()),
         }
     }
 
+    /// Extracts a slice corresponding to the portion of the path remaining for iteration.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::Utf8TypedPath;
+    ///
+    /// let mut iter = Utf8TypedPath::derive("/tmp/foo/bar.txt").iter();
+    /// iter.next();
+    /// iter.next();
+    ///
+    /// assert_eq!(Utf8TypedPath::derive("foo/bar.txt"), iter.to_path());
+    /// ```
+    pub fn to_path(&self) -> Utf8TypedPath<'a> {
+        self.as_path()
+    }
+
     /// Returns reference to the underlying str slice represented by this iterator.
     pub fn as_str(&self) -> &str {
         impl_typed_fn!(self, as_ref)
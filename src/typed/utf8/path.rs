@@ -1,21 +1,28 @@
+use core::convert::TryFrom;
 use core::fmt;
+use core::ops::{Bound, Range};
 
 #[cfg(feature = "std")]
 use std::path::Path;
 
-use crate::common::{CheckedPathError, StripPrefixError};
+use crate::common::{
+    CheckedPathError, ConversionPolicy, DifferentPrefixError, EncodingInfo, NormalizationOptions,
+    PathEquivalence, PushMode, StripPrefixError, Utf8Encoding, Utf8PathError,
+};
 use crate::convert::TryAsRef;
+use crate::no_std_compat::*;
 use crate::typed::{
-    PathType, Utf8TypedAncestors, Utf8TypedComponents, Utf8TypedIter, Utf8TypedPathBuf,
+    PathType, TypedPath, TypedPathBuf, Utf8TypedAncestors, Utf8TypedComponent, Utf8TypedComponents,
+    Utf8TypedIter, Utf8TypedPathBuf,
 };
-use crate::unix::Utf8UnixPath;
-use crate::windows::Utf8WindowsPath;
+use crate::unix::{Utf8UnixEncoding, Utf8UnixPath};
+use crate::windows::{Utf8WindowsEncoding, Utf8WindowsPath};
 
 /// Represents a path with a known type that can be one of:
 ///
 /// * [`Utf8UnixPath`]
 /// * [`Utf8WindowsPath`]
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, PartialEq, Eq)]
 pub enum Utf8TypedPath<'a> {
     Unix(&'a Utf8UnixPath),
     Windows(&'a Utf8WindowsPath),
@@ -169,6 +176,82 @@ impl<'a> Utf8TypedPath<'a> {
         impl_typed_fn!(self, has_root)
     }
 
+    /// Returns the prefix and root at the start of the [`Utf8TypedPath`], if it has either, such as `/`,
+    /// `C:\`, or `\\server\share\`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::Utf8TypedPath;
+    ///
+    /// assert_eq!(Utf8TypedPath::derive("/etc/passwd").root(), Some(Utf8TypedPath::derive("/")));
+    /// assert_eq!(Utf8TypedPath::derive(r"C:\Windows").root(), Some(Utf8TypedPath::derive(r"C:\")));
+    /// assert_eq!(Utf8TypedPath::derive("etc/passwd").root(), None);
+    /// ```
+    pub fn root(&self) -> Option<Self> {
+        self.split_root().0
+    }
+
+    /// Splits the [`Utf8TypedPath`] into its prefix and root, as returned by [`Utf8TypedPath::root`], and the
+    /// relative remainder of the path.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::Utf8TypedPath;
+    ///
+    /// let (root, rest) = Utf8TypedPath::derive(r"\\server\share\dir").split_root();
+    /// assert_eq!(root, Some(Utf8TypedPath::derive(r"\\server\share\")));
+    /// assert_eq!(rest, Utf8TypedPath::windows("dir"));
+    /// ```
+    pub fn split_root(&self) -> (Option<Self>, Self) {
+        match self {
+            Self::Unix(path) => {
+                let (root, rest) = path.split_root();
+                (root.map(Self::Unix), Self::Unix(rest))
+            }
+            Self::Windows(path) => {
+                let (root, rest) = path.split_root();
+                (root.map(Self::Windows), Self::Windows(rest))
+            }
+        }
+    }
+
+    /// Returns `true` if the [`Utf8TypedPath`] is made up of nothing more than a root, such as `/`, `C:\`,
+    /// or `\\server\share`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::Utf8TypedPath;
+    ///
+    /// assert!(Utf8TypedPath::derive("/").is_root());
+    /// assert!(Utf8TypedPath::derive(r"C:\").is_root());
+    /// assert!(!Utf8TypedPath::derive("C:").is_root());
+    /// assert!(!Utf8TypedPath::derive("/etc").is_root());
+    /// ```
+    #[inline]
+    pub fn is_root(&self) -> bool {
+        impl_typed_fn!(self, is_root)
+    }
+
+    /// Returns `true` if the [`Utf8TypedPath`] is made up of nothing more than a prefix without a root,
+    /// such as `C:`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::Utf8TypedPath;
+    ///
+    /// assert!(Utf8TypedPath::derive("C:").is_prefix_only());
+    /// assert!(!Utf8TypedPath::derive(r"C:\").is_prefix_only());
+    /// assert!(!Utf8TypedPath::derive("/").is_prefix_only());
+    /// ```
+    #[inline]
+    pub fn is_prefix_only(&self) -> bool {
+        impl_typed_fn!(self, is_prefix_only)
+    }
+
     /// Returns the [`Utf8TypedPath`] without its final component, if there is one.
     ///
     /// Returns [`None`] if the path terminates in a root or prefix.
@@ -229,6 +312,61 @@ impl<'a> Utf8TypedPath<'a> {
         }
     }
 
+    /// Produces an iterator over [`Utf8TypedPath`] and its ancestors that stops after yielding
+    /// `base`.
+    ///
+    /// See [`Utf8Path::ancestors_until`] for more details.
+    ///
+    /// # Difference from Path
+    ///
+    /// Unlike [`Utf8Path::ancestors_until`], this implementation only supports types that implement
+    /// `AsRef<str>` instead of `AsRef<Utf8Path>`.
+    ///
+    /// [`Utf8Path::ancestors_until`]: crate::Utf8Path::ancestors_until
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::Utf8TypedPath;
+    ///
+    /// let path = Utf8TypedPath::derive("/home/user/project/src");
+    /// let mut ancestors = path.ancestors_until("/home/user/project");
+    /// assert_eq!(ancestors.next(), Some(Utf8TypedPath::derive("/home/user/project/src")));
+    /// assert_eq!(ancestors.next(), Some(Utf8TypedPath::derive("/home/user/project")));
+    /// assert_eq!(ancestors.next(), None);
+    /// ```
+    pub fn ancestors_until(&self, base: impl AsRef<str>) -> Utf8TypedAncestors<'a> {
+        match self {
+            Self::Unix(p) => Utf8TypedAncestors::Unix(p.ancestors_until(Utf8UnixPath::new(&base))),
+            Self::Windows(p) => {
+                Utf8TypedAncestors::Windows(p.ancestors_until(Utf8WindowsPath::new(&base)))
+            }
+        }
+    }
+
+    /// Returns the `n`th ancestor of the path, or [`None`] if the path has fewer than `n`
+    /// parents.
+    ///
+    /// See [`Utf8Path::nth_parent`] for more details.
+    ///
+    /// [`Utf8Path::nth_parent`]: crate::Utf8Path::nth_parent
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::Utf8TypedPath;
+    ///
+    /// let path = Utf8TypedPath::derive("/foo/bar/baz");
+    /// assert_eq!(path.nth_parent(2), Some(Utf8TypedPath::derive("/foo")));
+    /// assert_eq!(path.nth_parent(4), None);
+    /// ```
+    pub fn nth_parent(&self, n: usize) -> Option<Self> {
+        match self {
+            Self::Unix(p) => p.nth_parent(n).map(Self::Unix),
+            Self::Windows(p) => p.nth_parent(n).map(Self::Windows),
+        }
+    }
+
     /// Returns the final component of the [`Utf8TypedPath`], if there is one.
     ///
     /// If the path is a normal file, this is the file name. If it's the path of a directory, this
@@ -287,7 +425,10 @@ impl<'a> Utf8TypedPath<'a> {
     /// let prefix = Utf8TypedPathBuf::from("/test/");
     /// assert_eq!(path.strip_prefix(prefix), Ok(Utf8TypedPath::derive("haha/foo.txt")));
     /// ```
-    pub fn strip_prefix(&self, base: impl AsRef<str>) -> Result<Utf8TypedPath, StripPrefixError> {
+    pub fn strip_prefix(
+        &self,
+        base: impl AsRef<str>,
+    ) -> Result<Utf8TypedPath<'_>, StripPrefixError> {
         match self {
             Self::Unix(p) => p
                 .strip_prefix(Utf8UnixPath::new(&base))
@@ -298,6 +439,41 @@ impl<'a> Utf8TypedPath<'a> {
         }
     }
 
+    /// Returns a relative path that, when joined onto `base`, refers to the same location as
+    /// `self`.
+    ///
+    /// See [`Utf8Path::relative_to`] for more details.
+    ///
+    /// # Difference from Path
+    ///
+    /// Unlike [`Utf8Path::relative_to`], this implementation only supports types that implement
+    /// `AsRef<str>` instead of `AsRef<Utf8Path>`.
+    ///
+    /// [`Utf8Path::relative_to`]: crate::Utf8Path::relative_to
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::Utf8TypedPath;
+    ///
+    /// let path = Utf8TypedPath::derive("/usr/share/doc");
+    /// assert_eq!(path.relative_to("/usr/lib").unwrap().as_str(), "../share/doc");
+    ///
+    /// let path = Utf8TypedPath::derive(r"C:\Users\Public");
+    /// assert!(path.relative_to(r"D:\Users").is_err());
+    /// ```
+    pub fn relative_to(
+        &self,
+        base: impl AsRef<str>,
+    ) -> Result<Utf8TypedPathBuf, DifferentPrefixError> {
+        Ok(match self {
+            Self::Unix(p) => Utf8TypedPathBuf::Unix(p.relative_to(Utf8UnixPath::new(&base))?),
+            Self::Windows(p) => {
+                Utf8TypedPathBuf::Windows(p.relative_to(Utf8WindowsPath::new(&base))?)
+            }
+        })
+    }
+
     /// Determines whether `base` is a prefix of `self`.
     ///
     /// Only considers whole path components to match.
@@ -366,218 +542,721 @@ impl<'a> Utf8TypedPath<'a> {
         }
     }
 
-    /// Extracts the stem (non-extension) portion of [`self.file_name`].
+    /// Determines whether `self` is a strict ancestor of `other`.
     ///
-    /// [`self.file_name`]: Utf8TypedPath::file_name
+    /// See [`Utf8Path::is_ancestor_of`] for more details.
     ///
-    /// The stem is:
+    /// # Difference from Path
     ///
-    /// * [`None`], if there is no file name;
-    /// * The entire file name if there is no embedded `.`;
-    /// * The entire file name if the file name begins with `.` and has no other `.`s within;
-    /// * Otherwise, the portion of the file name before the final `.`
+    /// Unlike [`Utf8Path::is_ancestor_of`], this implementation only supports types that implement
+    /// `AsRef<str>` instead of `AsRef<Utf8Path>`.
+    ///
+    /// [`Utf8Path::is_ancestor_of`]: crate::Utf8Path::is_ancestor_of
     ///
     /// # Examples
     ///
     /// ```
     /// use typed_path::Utf8TypedPath;
     ///
-    /// assert_eq!("foo", Utf8TypedPath::derive("foo.rs").file_stem().unwrap());
-    /// assert_eq!("foo.tar", Utf8TypedPath::derive("foo.tar.gz").file_stem().unwrap());
+    /// let path = Utf8TypedPath::derive("/etc");
+    /// assert!(path.is_ancestor_of("/etc/passwd"));
+    /// assert!(!path.is_ancestor_of("/etcetera"));
     /// ```
-    ///
-    pub fn file_stem(&self) -> Option<&str> {
-        impl_typed_fn!(self, file_stem)
+    pub fn is_ancestor_of(&self, other: impl AsRef<str>) -> bool {
+        match self {
+            Self::Unix(p) => p.is_ancestor_of(Utf8UnixPath::new(&other)),
+            Self::Windows(p) => p.is_ancestor_of(Utf8WindowsPath::new(&other)),
+        }
     }
 
-    /// Extracts the extension of [`self.file_name`], if possible.
+    /// Determines whether `self` is either the same path as `other` or one of its ancestors.
     ///
-    /// The extension is:
+    /// See [`Utf8Path::is_inclusive_ancestor_of`] for more details.
     ///
-    /// * [`None`], if there is no file name;
-    /// * [`None`], if there is no embedded `.`;
-    /// * [`None`], if the file name begins with `.` and has no other `.`s within;
-    /// * Otherwise, the portion of the file name after the final `.`
+    /// # Difference from Path
     ///
-    /// [`self.file_name`]: Utf8TypedPath::file_name
+    /// Unlike [`Utf8Path::is_inclusive_ancestor_of`], this implementation only supports types that
+    /// implement `AsRef<str>` instead of `AsRef<Utf8Path>`.
+    ///
+    /// [`Utf8Path::is_inclusive_ancestor_of`]: crate::Utf8Path::is_inclusive_ancestor_of
     ///
     /// # Examples
     ///
     /// ```
     /// use typed_path::Utf8TypedPath;
     ///
-    /// assert_eq!("rs", Utf8TypedPath::derive("foo.rs").extension().unwrap());
-    /// assert_eq!("gz", Utf8TypedPath::derive("foo.tar.gz").extension().unwrap());
+    /// let path = Utf8TypedPath::derive("/etc");
+    /// assert!(path.is_inclusive_ancestor_of("/etc"));
     /// ```
-    pub fn extension(&self) -> Option<&str> {
-        impl_typed_fn!(self, extension)
+    pub fn is_inclusive_ancestor_of(&self, other: impl AsRef<str>) -> bool {
+        match self {
+            Self::Unix(p) => p.is_inclusive_ancestor_of(Utf8UnixPath::new(&other)),
+            Self::Windows(p) => p.is_inclusive_ancestor_of(Utf8WindowsPath::new(&other)),
+        }
     }
 
-    /// Returns an owned [`Utf8TypedPathBuf`] by resolving `..` and `.` segments.
+    /// Determines whether `self` is a strict descendant of `other`.
     ///
-    /// When multiple, sequential path segment separation characters are found (e.g. `/` for Unix
-    /// and either `\` or `/` on Windows), they are replaced by a single instance of the
-    /// platform-specific path segment separator (`/` on Unix and `\` on Windows).
+    /// See [`Utf8Path::is_descendant_of`] for more details.
+    ///
+    /// # Difference from Path
+    ///
+    /// Unlike [`Utf8Path::is_descendant_of`], this implementation only supports types that
+    /// implement `AsRef<str>` instead of `AsRef<Utf8Path>`.
+    ///
+    /// [`Utf8Path::is_descendant_of`]: crate::Utf8Path::is_descendant_of
     ///
     /// # Examples
     ///
     /// ```
-    /// use typed_path::{Utf8TypedPath, Utf8TypedPathBuf};
+    /// use typed_path::Utf8TypedPath;
     ///
-    /// assert_eq!(
-    ///     Utf8TypedPath::derive("foo/bar//baz/./asdf/quux/..").normalize(),
-    ///     Utf8TypedPathBuf::from("foo/bar/baz/asdf"),
-    /// );
+    /// let path = Utf8TypedPath::derive("/etc/passwd");
+    /// assert!(path.is_descendant_of("/etc"));
+    /// assert!(!path.is_descendant_of("/et"));
     /// ```
+    pub fn is_descendant_of(&self, other: impl AsRef<str>) -> bool {
+        match self {
+            Self::Unix(p) => p.is_descendant_of(Utf8UnixPath::new(&other)),
+            Self::Windows(p) => p.is_descendant_of(Utf8WindowsPath::new(&other)),
+        }
+    }
+
+    /// Determines whether `self` is either the same path as `other` or one of its descendants.
     ///
-    /// When starting with a root directory, any `..` segment whose parent is the root directory
-    /// will be filtered out:
+    /// See [`Utf8Path::is_inclusive_descendant_of`] for more details.
     ///
-    /// ```
-    /// use typed_path::{Utf8TypedPath, Utf8TypedPathBuf};
+    /// # Difference from Path
     ///
-    /// // NOTE: A path cannot be created on its own without a defined encoding
-    /// assert_eq!(
-    ///     Utf8TypedPath::derive("/../foo").normalize(),
-    ///     Utf8TypedPathBuf::from("/foo"),
-    /// );
-    /// ```
+    /// Unlike [`Utf8Path::is_inclusive_descendant_of`], this implementation only supports types
+    /// that implement `AsRef<str>` instead of `AsRef<Utf8Path>`.
     ///
-    /// If any `..` is left unresolved as the path is relative and no parent is found, it is
-    /// discarded:
+    /// [`Utf8Path::is_inclusive_descendant_of`]: crate::Utf8Path::is_inclusive_descendant_of
     ///
-    /// ```
-    /// use typed_path::{Utf8TypedPath, Utf8TypedPathBuf};
+    /// # Examples
     ///
-    /// assert_eq!(
-    ///     Utf8TypedPath::derive("../foo/..").normalize(),
-    ///     Utf8TypedPathBuf::from(""),
-    /// );
+    /// ```
+    /// use typed_path::Utf8TypedPath;
     ///
-    /// // Windows prefixes also count this way, but the prefix remains
-    /// assert_eq!(
-    ///     Utf8TypedPath::derive(r"C:..\foo\..").normalize(),
-    ///     Utf8TypedPathBuf::from(r"C:"),
-    /// );
+    /// let path = Utf8TypedPath::derive("/etc/passwd");
+    /// assert!(path.is_inclusive_descendant_of("/etc/passwd"));
     /// ```
-    pub fn normalize(&self) -> Utf8TypedPathBuf {
+    pub fn is_inclusive_descendant_of(&self, other: impl AsRef<str>) -> bool {
         match self {
-            Self::Unix(path) => Utf8TypedPathBuf::Unix(path.normalize()),
-            Self::Windows(path) => Utf8TypedPathBuf::Windows(path.normalize()),
+            Self::Unix(p) => p.is_inclusive_descendant_of(Utf8UnixPath::new(&other)),
+            Self::Windows(p) => p.is_inclusive_descendant_of(Utf8WindowsPath::new(&other)),
         }
     }
 
-    /// Converts a path to an absolute form by [`normalizing`] the path, returning a
-    /// [`Utf8TypedPathBuf`].
+    /// Determines whether `needle` appears anywhere within `self` as a sequence of whole
+    /// components.
     ///
-    /// In the case that the path is relative, the current working directory is prepended prior to
-    /// normalizing.
+    /// # Difference from Path
     ///
-    /// [`normalizing`]: Utf8TypedPath::normalize
+    /// Unlike [`Utf8Path::contains_path`], this implementation only supports types that implement
+    /// `AsRef<str>` instead of `AsRef<Utf8Path>`.
+    ///
+    /// [`Utf8Path::contains_path`]: crate::Utf8Path::contains_path
     ///
     /// # Examples
     ///
     /// ```
-    /// use typed_path::{utils, Utf8TypedPath};
-    ///
-    /// // With an absolute path, it is just normalized
-    /// let path = Utf8TypedPath::derive("/a/b/../c/./d");
-    /// assert_eq!(path.absolutize().unwrap(), Utf8TypedPath::derive("/a/c/d"));
+    /// use typed_path::Utf8TypedPath;
     ///
-    /// // With a relative path, it is first joined with the current working directory
-    /// // and then normalized
-    /// let cwd = utils::current_dir().unwrap().with_unix_encoding().to_typed_path_buf();
-    /// let path = cwd.join("a/b/../c/./d");
-    /// assert_eq!(path.absolutize().unwrap(), cwd.join("a/c/d"));
+    /// let path = Utf8TypedPath::derive("/repo/node_modules/.bin/tsc");
+    /// assert!(path.contains_path("node_modules/.bin"));
+    /// assert!(!path.contains_path("node_modules/tsc"));
     /// ```
-    #[cfg(all(feature = "std", not(target_family = "wasm")))]
-    pub fn absolutize(&self) -> std::io::Result<Utf8TypedPathBuf> {
-        Ok(match self {
-            Self::Unix(path) => Utf8TypedPathBuf::Unix(path.absolutize()?),
-            Self::Windows(path) => Utf8TypedPathBuf::Windows(path.absolutize()?),
-        })
+    pub fn contains_path(&self, needle: impl AsRef<str>) -> bool {
+        match self {
+            Self::Unix(p) => p.contains_path(Utf8UnixPath::new(&needle)),
+            Self::Windows(p) => p.contains_path(Utf8WindowsPath::new(&needle)),
+        }
     }
 
-    /// Creates an owned [`Utf8TypedPathBuf`] with `path` adjoined to `self`.
-    ///
-    /// See [`Utf8TypedPathBuf::push`] for more details on what it means to adjoin a path.
+    /// Locates the first occurrence of `needle` within `self` as a sequence of whole components,
+    /// returning the index of the first matching component alongside the span of bytes within
+    /// `self` covered by the matching components.
     ///
     /// # Difference from Path
     ///
-    /// Unlike [`Utf8Path::join`], this implementation only supports types that implement
-    /// `AsRef<str>` instead of `AsRef<Path>`.
+    /// Unlike [`Utf8Path::find_subpath`], this implementation only supports types that implement
+    /// `AsRef<str>` instead of `AsRef<Utf8Path>`.
     ///
-    /// [`Utf8Path::join`]: crate::Utf8Path::join
+    /// [`Utf8Path::find_subpath`]: crate::Utf8Path::find_subpath
     ///
     /// # Examples
     ///
     /// ```
-    /// use typed_path::{Utf8TypedPath, Utf8TypedPathBuf};
+    /// use typed_path::Utf8TypedPath;
     ///
-    /// assert_eq!(
-    ///     Utf8TypedPath::derive("/etc").join("passwd"),
-    ///     Utf8TypedPathBuf::from("/etc/passwd"),
-    /// );
+    /// let path = Utf8TypedPath::derive("/repo/node_modules/.bin/tsc");
+    /// assert_eq!(path.find_subpath("node_modules/.bin"), Some((2, 6..23)));
     /// ```
-    pub fn join(&self, path: impl AsRef<str>) -> Utf8TypedPathBuf {
+    pub fn find_subpath(&self, needle: impl AsRef<str>) -> Option<(usize, Range<usize>)> {
         match self {
-            Self::Unix(p) => Utf8TypedPathBuf::Unix(p.join(Utf8UnixPath::new(&path))),
-            Self::Windows(p) => Utf8TypedPathBuf::Windows(p.join(Utf8WindowsPath::new(&path))),
+            Self::Unix(p) => p.find_subpath(Utf8UnixPath::new(&needle)),
+            Self::Windows(p) => p.find_subpath(Utf8WindowsPath::new(&needle)),
         }
     }
 
-    /// Creates an owned [`Utf8TypedPathBuf`] with `path` adjoined to `self`, checking the `path` to
-    /// ensure it is safe to join. _When dealing with user-provided paths, this is the preferred
-    /// method._
+    /// Creates an owned [`Utf8TypedPathBuf`] where every non-overlapping occurrence of the
+    /// components of `from` within `self` is replaced with `to`.
     ///
-    /// See [`Utf8TypedPathBuf::push_checked`] for more details on what it means to adjoin a path
-    /// safely.
+    /// See [`Utf8Path::replace_components`] for more details.
     ///
     /// # Difference from Path
     ///
-    /// Unlike [`Utf8Path::join_checked`], this implementation only supports types that implement
-    /// `AsRef<str>` instead of `AsRef<Path>`.
+    /// Unlike [`Utf8Path::replace_components`], this implementation only supports types that
+    /// implement `AsRef<str>` instead of `AsRef<Utf8Path>`.
     ///
-    /// [`Utf8Path::join_checked`]: crate::Utf8Path::join_checked
+    /// [`Utf8Path::replace_components`]: crate::Utf8Path::replace_components
     ///
     /// # Examples
     ///
     /// ```
-    /// use typed_path::{CheckedPathError, Utf8TypedPath, Utf8TypedPathBuf};
-    ///
-    /// assert_eq!(
-    ///     Utf8TypedPath::derive("/etc").join_checked("passwd"),
-    ///     Ok(Utf8TypedPathBuf::from("/etc/passwd")),
-    /// );
+    /// use typed_path::Utf8TypedPath;
     ///
-    /// assert_eq!(
-    ///     Utf8TypedPath::derive("/etc").join_checked("/sneaky/path"),
-    ///     Err(CheckedPathError::UnexpectedRoot),
-    /// );
+    /// let path = Utf8TypedPath::derive("/repo/src/main.rs");
+    /// assert_eq!(path.replace_components("src", "generated").as_str(), "/repo/generated/main.rs");
     /// ```
-    pub fn join_checked(
+    pub fn replace_components(
         &self,
-        path: impl AsRef<str>,
-    ) -> Result<Utf8TypedPathBuf, CheckedPathError> {
-        Ok(match self {
-            Self::Unix(p) => Utf8TypedPathBuf::Unix(p.join_checked(Utf8UnixPath::new(&path))?),
-            Self::Windows(p) => {
-                Utf8TypedPathBuf::Windows(p.join_checked(Utf8WindowsPath::new(&path))?)
-            }
-        })
+        from: impl AsRef<str>,
+        to: impl AsRef<str>,
+    ) -> Utf8TypedPathBuf {
+        match self {
+            Self::Unix(p) => Utf8TypedPathBuf::Unix(
+                p.replace_components(Utf8UnixPath::new(&from), Utf8UnixPath::new(&to)),
+            ),
+            Self::Windows(p) => Utf8TypedPathBuf::Windows(
+                p.replace_components(Utf8WindowsPath::new(&from), Utf8WindowsPath::new(&to)),
+            ),
+        }
     }
 
-    /// Creates an owned [`Utf8TypedPathBuf`] like `self` but with the given file name.
+    /// Creates an owned [`Utf8TypedPathBuf`] where each component of `self` is replaced with the
+    /// string returned by `f`.
     ///
-    /// See [`Utf8TypedPathBuf::set_file_name`] for more details.
+    /// See [`Utf8Path::map_components`] for more details.
+    ///
+    /// [`Utf8Path::map_components`]: crate::Utf8Path::map_components
     ///
     /// # Examples
     ///
     /// ```
-    /// use typed_path::{Utf8TypedPath, Utf8TypedPathBuf};
-    ///
-    /// let path = Utf8TypedPath::derive("/tmp/foo.txt");
-    /// assert_eq!(path.with_file_name("bar.txt"), Utf8TypedPathBuf::from("/tmp/bar.txt"));
+    /// use typed_path::Utf8TypedPath;
+    ///
+    /// let path = Utf8TypedPath::derive("/repo/src/main.rs");
+    /// let upper = path.map_components(|c| c.as_str().to_ascii_uppercase());
+    /// assert_eq!(upper.as_str(), "/REPO/SRC/MAIN.RS");
+    /// ```
+    pub fn map_components<F, C>(&self, mut f: F) -> Utf8TypedPathBuf
+    where
+        F: FnMut(Utf8TypedComponent<'a>) -> C,
+        C: AsRef<str>,
+    {
+        match self {
+            Self::Unix(p) => {
+                Utf8TypedPathBuf::Unix(p.map_components(|c| f(Utf8TypedComponent::Unix(c))))
+            }
+            Self::Windows(p) => {
+                Utf8TypedPathBuf::Windows(p.map_components(|c| f(Utf8TypedComponent::Windows(c))))
+            }
+        }
+    }
+
+    /// Determines whether `other` refers to the same place as `self` after lexical
+    /// normalization, using the case rules of the encoding.
+    ///
+    /// See [`Utf8Path::normalized_eq`] for more details.
+    ///
+    /// # Difference from Path
+    ///
+    /// Unlike [`Utf8Path::normalized_eq`], this implementation only supports types that implement
+    /// `AsRef<str>` instead of `AsRef<Utf8Path>`.
+    ///
+    /// [`Utf8Path::normalized_eq`]: crate::Utf8Path::normalized_eq
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::Utf8TypedPath;
+    ///
+    /// let path = Utf8TypedPath::derive("/etc/./ssh//config");
+    /// assert!(path.normalized_eq("/etc/ssl/../ssh/config"));
+    /// assert!(Utf8TypedPath::derive(r"C:\Windows").normalized_eq(r"c:\windows"));
+    /// ```
+    pub fn normalized_eq(&self, other: impl AsRef<str>) -> bool {
+        match self {
+            Self::Unix(p) => p.normalized_eq(Utf8UnixPath::new(&other)),
+            Self::Windows(p) => p.normalized_eq(Utf8WindowsPath::new(&other)),
+        }
+    }
+
+    /// Determines whether `other` refers to the same place as `self` using the given
+    /// [`PathEquivalence`] rules.
+    ///
+    /// See [`Utf8Path::equivalent_with`] for more details.
+    ///
+    /// # Difference from Path
+    ///
+    /// Unlike [`Utf8Path::equivalent_with`], this implementation only supports types that implement
+    /// `AsRef<str>` instead of `AsRef<Utf8Path>`.
+    ///
+    /// [`Utf8Path::equivalent_with`]: crate::Utf8Path::equivalent_with
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{PathEquivalence, Utf8TypedPath};
+    ///
+    /// let rules = PathEquivalence::new().case_sensitive(false);
+    /// assert!(Utf8TypedPath::derive("/Users/Me").equivalent_with("/users/me", &rules));
+    /// ```
+    pub fn equivalent_with(&self, other: impl AsRef<str>, rules: &PathEquivalence) -> bool {
+        match self {
+            Self::Unix(p) => p.equivalent_with(Utf8UnixPath::new(&other), rules),
+            Self::Windows(p) => p.equivalent_with(Utf8WindowsPath::new(&other), rules),
+        }
+    }
+
+    /// Extracts the stem (non-extension) portion of [`self.file_name`].
+    ///
+    /// [`self.file_name`]: Utf8TypedPath::file_name
+    ///
+    /// The stem is:
+    ///
+    /// * [`None`], if there is no file name;
+    /// * The entire file name if there is no embedded `.`;
+    /// * The entire file name if the file name begins with `.` and has no other `.`s within;
+    /// * Otherwise, the portion of the file name before the final `.`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::Utf8TypedPath;
+    ///
+    /// assert_eq!("foo", Utf8TypedPath::derive("foo.rs").file_stem().unwrap());
+    /// assert_eq!("foo.tar", Utf8TypedPath::derive("foo.tar.gz").file_stem().unwrap());
+    /// ```
+    ///
+    pub fn file_stem(&self) -> Option<&str> {
+        impl_typed_fn!(self, file_stem)
+    }
+
+    /// Extracts the extension of [`self.file_name`], if possible.
+    ///
+    /// The extension is:
+    ///
+    /// * [`None`], if there is no file name;
+    /// * [`None`], if there is no embedded `.`;
+    /// * [`None`], if the file name begins with `.` and has no other `.`s within;
+    /// * Otherwise, the portion of the file name after the final `.`
+    ///
+    /// [`self.file_name`]: Utf8TypedPath::file_name
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::Utf8TypedPath;
+    ///
+    /// assert_eq!("rs", Utf8TypedPath::derive("foo.rs").extension().unwrap());
+    /// assert_eq!("gz", Utf8TypedPath::derive("foo.tar.gz").extension().unwrap());
+    /// ```
+    pub fn extension(&self) -> Option<&str> {
+        impl_typed_fn!(self, extension)
+    }
+
+    /// Returns true if the file name of the path marks it as hidden by convention alone, without
+    /// consulting any filesystem attributes.
+    ///
+    /// See [`Utf8Path::is_hidden_by_name`] for more details.
+    ///
+    /// [`Utf8Path::is_hidden_by_name`]: crate::Utf8Path::is_hidden_by_name
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::Utf8TypedPath;
+    ///
+    /// assert!(Utf8TypedPath::derive("/home/user/.profile").is_hidden_by_name());
+    /// assert!(Utf8TypedPath::derive(r"C:\Docs\~$notes.docx").is_hidden_by_name());
+    /// assert!(!Utf8TypedPath::derive(r"C:\Docs\notes.docx").is_hidden_by_name());
+    /// ```
+    pub fn is_hidden_by_name(&self) -> bool {
+        impl_typed_fn!(self, is_hidden_by_name)
+    }
+
+    /// Guesses the media type of the path's content from its extension, ignoring ascii case.
+    ///
+    /// See [`Utf8Path::mime_type`] for more details.
+    ///
+    /// [`Utf8Path::mime_type`]: crate::Utf8Path::mime_type
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::Utf8TypedPath;
+    ///
+    /// assert_eq!(Utf8TypedPath::derive(r"C:\www\photo.jpeg").mime_type(), Some("image/jpeg"));
+    /// assert_eq!(Utf8TypedPath::derive("/www/data.tar").mime_type(), Some("application/x-tar"));
+    /// assert_eq!(Utf8TypedPath::derive("/www/data.unknown").mime_type(), None);
+    /// ```
+    pub fn mime_type(&self) -> Option<&'static str> {
+        impl_typed_fn!(self, mime_type)
+    }
+
+    /// Returns an owned [`Utf8TypedPathBuf`] by resolving `..` and `.` segments.
+    ///
+    /// When multiple, sequential path segment separation characters are found (e.g. `/` for Unix
+    /// and either `\` or `/` on Windows), they are replaced by a single instance of the
+    /// platform-specific path segment separator (`/` on Unix and `\` on Windows).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Utf8TypedPath, Utf8TypedPathBuf};
+    ///
+    /// assert_eq!(
+    ///     Utf8TypedPath::derive("foo/bar//baz/./asdf/quux/..").normalize(),
+    ///     Utf8TypedPathBuf::from("foo/bar/baz/asdf"),
+    /// );
+    /// ```
+    ///
+    /// When starting with a root directory, any `..` segment whose parent is the root directory
+    /// will be filtered out:
+    ///
+    /// ```
+    /// use typed_path::{Utf8TypedPath, Utf8TypedPathBuf};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// assert_eq!(
+    ///     Utf8TypedPath::derive("/../foo").normalize(),
+    ///     Utf8TypedPathBuf::from("/foo"),
+    /// );
+    /// ```
+    ///
+    /// If any `..` is left unresolved as the path is relative and no parent is found, it is
+    /// discarded:
+    ///
+    /// ```
+    /// use typed_path::{Utf8TypedPath, Utf8TypedPathBuf};
+    ///
+    /// assert_eq!(
+    ///     Utf8TypedPath::derive("../foo/..").normalize(),
+    ///     Utf8TypedPathBuf::from(""),
+    /// );
+    ///
+    /// // Windows prefixes also count this way, but the prefix remains
+    /// assert_eq!(
+    ///     Utf8TypedPath::derive(r"C:..\foo\..").normalize(),
+    ///     Utf8TypedPathBuf::from(r"C:"),
+    /// );
+    /// ```
+    pub fn normalize(&self) -> Utf8TypedPathBuf {
+        match self {
+            Self::Unix(path) => Utf8TypedPathBuf::Unix(path.normalize()),
+            Self::Windows(path) => Utf8TypedPathBuf::Windows(path.normalize()),
+        }
+    }
+
+    /// Returns an owned [`Utf8TypedPathBuf`] by lexically cleaning the path in the same way as Go's
+    /// `path/filepath.Clean`.
+    ///
+    /// See [`Utf8Path::clean`] for more details.
+    ///
+    /// [`Utf8Path::clean`]: crate::Utf8Path::clean
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Utf8TypedPath, Utf8TypedPathBuf};
+    ///
+    /// assert_eq!(
+    ///     Utf8TypedPath::derive("a/../../b/./c/").clean(),
+    ///     Utf8TypedPathBuf::from("../b/c"),
+    /// );
+    /// assert_eq!(Utf8TypedPath::derive("a/..").clean(), Utf8TypedPathBuf::from("."));
+    /// ```
+    pub fn clean(&self) -> Utf8TypedPathBuf {
+        match self {
+            Self::Unix(path) => Utf8TypedPathBuf::Unix(path.clean()),
+            Self::Windows(path) => Utf8TypedPathBuf::Windows(path.clean()),
+        }
+    }
+
+    /// Returns an owned [`Utf8TypedPathBuf`] by normalizing the path using the given
+    /// [`NormalizationOptions`].
+    ///
+    /// See [`Utf8Path::normalize_with`] for more details.
+    ///
+    /// [`Utf8Path::normalize_with`]: crate::Utf8Path::normalize_with
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{NormalizationOptions, Utf8TypedPath, Utf8TypedPathBuf};
+    ///
+    /// let options = NormalizationOptions::new().preserve_leading_parent(true);
+    /// assert_eq!(
+    ///     Utf8TypedPath::derive("../foo//./bar/..").normalize_with(&options),
+    ///     Utf8TypedPathBuf::from("../foo"),
+    /// );
+    /// ```
+    pub fn normalize_with(&self, options: &NormalizationOptions) -> Utf8TypedPathBuf {
+        match self {
+            Self::Unix(path) => Utf8TypedPathBuf::Unix(path.normalize_with(options)),
+            Self::Windows(path) => Utf8TypedPathBuf::Windows(path.normalize_with(options)),
+        }
+    }
+
+    /// Converts a path to an absolute form by [`normalizing`] the path, returning a
+    /// [`Utf8TypedPathBuf`].
+    ///
+    /// In the case that the path is relative, the current working directory is prepended prior to
+    /// normalizing.
+    ///
+    /// [`normalizing`]: Utf8TypedPath::normalize
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{utils, Utf8TypedPath};
+    ///
+    /// // With an absolute path, it is just normalized
+    /// let path = Utf8TypedPath::derive("/a/b/../c/./d");
+    /// assert_eq!(path.absolutize().unwrap(), Utf8TypedPath::derive("/a/c/d"));
+    ///
+    /// // With a relative path, it is first joined with the current working directory
+    /// // and then normalized
+    /// let cwd = utils::current_dir().unwrap().with_unix_encoding().to_typed_path_buf();
+    /// let path = cwd.join("a/b/../c/./d");
+    /// assert_eq!(path.absolutize().unwrap(), cwd.join("a/c/d"));
+    /// ```
+    #[cfg(all(feature = "std", not(target_family = "wasm")))]
+    pub fn absolutize(&self) -> std::io::Result<Utf8TypedPathBuf> {
+        Ok(match self {
+            Self::Unix(path) => Utf8TypedPathBuf::Unix(path.absolutize()?),
+            Self::Windows(path) => Utf8TypedPathBuf::Windows(path.absolutize()?),
+        })
+    }
+
+    /// Creates an owned [`Utf8TypedPathBuf`] with `path` adjoined to `self`.
+    ///
+    /// See [`Utf8TypedPathBuf::push`] for more details on what it means to adjoin a path.
+    ///
+    /// # Difference from Path
+    ///
+    /// Unlike [`Utf8Path::join`], this implementation only supports types that implement
+    /// `AsRef<str>` instead of `AsRef<Path>`.
+    ///
+    /// [`Utf8Path::join`]: crate::Utf8Path::join
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Utf8TypedPath, Utf8TypedPathBuf};
+    ///
+    /// assert_eq!(
+    ///     Utf8TypedPath::derive("/etc").join("passwd"),
+    ///     Utf8TypedPathBuf::from("/etc/passwd"),
+    /// );
+    /// ```
+    pub fn join(&self, path: impl AsRef<str>) -> Utf8TypedPathBuf {
+        match self {
+            Self::Unix(p) => Utf8TypedPathBuf::Unix(p.join(Utf8UnixPath::new(&path))),
+            Self::Windows(p) => Utf8TypedPathBuf::Windows(p.join(Utf8WindowsPath::new(&path))),
+        }
+    }
+
+    /// Creates an owned [`Utf8TypedPathBuf`] with `path` adjoined to `self`, checking the `path` to
+    /// ensure it is safe to join. _When dealing with user-provided paths, this is the preferred
+    /// method._
+    ///
+    /// See [`Utf8TypedPathBuf::push_checked`] for more details on what it means to adjoin a path
+    /// safely.
+    ///
+    /// # Difference from Path
+    ///
+    /// Unlike [`Utf8Path::join_checked`], this implementation only supports types that implement
+    /// `AsRef<str>` instead of `AsRef<Path>`.
+    ///
+    /// [`Utf8Path::join_checked`]: crate::Utf8Path::join_checked
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{CheckedPathError, Utf8TypedPath, Utf8TypedPathBuf};
+    ///
+    /// assert_eq!(
+    ///     Utf8TypedPath::derive("/etc").join_checked("passwd"),
+    ///     Ok(Utf8TypedPathBuf::from("/etc/passwd")),
+    /// );
+    ///
+    /// assert_eq!(
+    ///     Utf8TypedPath::derive("/etc").join_checked("/sneaky/path"),
+    ///     Err(CheckedPathError::UnexpectedRoot),
+    /// );
+    /// ```
+    pub fn join_checked(
+        &self,
+        path: impl AsRef<str>,
+    ) -> Result<Utf8TypedPathBuf, CheckedPathError> {
+        Ok(match self {
+            Self::Unix(p) => Utf8TypedPathBuf::Unix(p.join_checked(Utf8UnixPath::new(&path))?),
+            Self::Windows(p) => {
+                Utf8TypedPathBuf::Windows(p.join_checked(Utf8WindowsPath::new(&path))?)
+            }
+        })
+    }
+
+    /// Creates an owned [`Utf8TypedPathBuf`] with `path` adjoined to `self`, first converting
+    /// `path` to the encoding of `self` using `policy` if the two differ.
+    ///
+    /// See [`Utf8TypedPathBuf::push_typed`] for more details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{ConversionPolicy, Utf8TypedPath};
+    ///
+    /// let base = Utf8TypedPath::derive(r"C:\srv");
+    /// let path = base.join_typed(Utf8TypedPath::unix("www/index.html"), ConversionPolicy::Lossy);
+    /// assert_eq!(path.unwrap().as_str(), r"C:\srv\www\index.html");
+    /// ```
+    pub fn join_typed(
+        &self,
+        path: Utf8TypedPath<'_>,
+        policy: ConversionPolicy,
+    ) -> Result<Utf8TypedPathBuf, CheckedPathError> {
+        let mut buf = self.to_path_buf();
+        buf.push_typed(path, policy)?;
+        Ok(buf)
+    }
+
+    /// Creates an owned [`Utf8TypedPathBuf`] with `path` adjoined to `self`, failing if `path` uses
+    /// a different encoding and cannot be converted to the encoding of `self` without loss.
+    ///
+    /// This is the same as [`Utf8TypedPath::join_typed`] using [`ConversionPolicy::Checked`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{CheckedPathError, Utf8TypedPath};
+    ///
+    /// let base = Utf8TypedPath::derive("/srv");
+    /// assert_eq!(
+    ///     base.try_join(Utf8TypedPath::windows(r"www\index.html")).unwrap().as_str(),
+    ///     "/srv/www/index.html",
+    /// );
+    /// assert_eq!(
+    ///     base.try_join(Utf8TypedPath::derive(r"D:\www")),
+    ///     Err(CheckedPathError::UnexpectedPrefix),
+    /// );
+    /// ```
+    pub fn try_join(&self, path: Utf8TypedPath<'_>) -> Result<Utf8TypedPathBuf, CheckedPathError> {
+        self.join_typed(path, ConversionPolicy::Checked)
+    }
+
+    /// Creates an owned [`Utf8TypedPathBuf`] with `path` adjoined to `self` using the given
+    /// [`PushMode`] to decide what happens when `path` has its own root or prefix.
+    ///
+    /// See [`Utf8PathBuf::push_with_mode`] for more details.
+    ///
+    /// [`Utf8PathBuf::push_with_mode`]: crate::Utf8PathBuf::push_with_mode
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{CheckedPathError, PushMode, Utf8TypedPath, Utf8TypedPathBuf};
+    ///
+    /// let path = Utf8TypedPath::derive("/srv");
+    /// assert_eq!(
+    ///     path.join_with_mode("/etc", PushMode::Append),
+    ///     Ok(Utf8TypedPathBuf::from("/srv/etc")),
+    /// );
+    /// assert_eq!(
+    ///     path.join_with_mode("/etc", PushMode::Reject),
+    ///     Err(CheckedPathError::UnexpectedRoot),
+    /// );
+    /// ```
+    pub fn join_with_mode(
+        &self,
+        path: impl AsRef<str>,
+        mode: PushMode,
+    ) -> Result<Utf8TypedPathBuf, CheckedPathError> {
+        Ok(match self {
+            Self::Unix(p) => {
+                Utf8TypedPathBuf::Unix(p.join_with_mode(Utf8UnixPath::new(&path), mode)?)
+            }
+            Self::Windows(p) => {
+                Utf8TypedPathBuf::Windows(p.join_with_mode(Utf8WindowsPath::new(&path), mode)?)
+            }
+        })
+    }
+
+    /// Creates an owned [`Utf8TypedPathBuf`] with `path` adjoined to `self`, never replacing `self`
+    /// even if `path` is absolute.
+    ///
+    /// See [`Utf8Path::join_keeping_base`] for more details.
+    ///
+    /// [`Utf8Path::join_keeping_base`]: crate::Utf8Path::join_keeping_base
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Utf8TypedPath, Utf8TypedPathBuf};
+    ///
+    /// let path = Utf8TypedPath::derive("/srv/www");
+    /// assert_eq!(
+    ///     path.join_keeping_base("/index.html"),
+    ///     Utf8TypedPathBuf::from("/srv/www/index.html"),
+    /// );
+    /// ```
+    pub fn join_keeping_base(&self, path: impl AsRef<str>) -> Utf8TypedPathBuf {
+        match self {
+            Self::Unix(p) => Utf8TypedPathBuf::Unix(p.join_keeping_base(Utf8UnixPath::new(&path))),
+            Self::Windows(p) => {
+                Utf8TypedPathBuf::Windows(p.join_keeping_base(Utf8WindowsPath::new(&path)))
+            }
+        }
+    }
+
+    /// Creates an owned [`Utf8TypedPathBuf`] with `path` adjoined to `self`, failing if `path` has
+    /// a root or prefix.
+    ///
+    /// See [`Utf8Path::join_relative_only`] for more details.
+    ///
+    /// [`Utf8Path::join_relative_only`]: crate::Utf8Path::join_relative_only
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{CheckedPathError, Utf8TypedPath, Utf8TypedPathBuf};
+    ///
+    /// let path = Utf8TypedPath::derive("/srv");
+    /// assert_eq!(path.join_relative_only("www"), Ok(Utf8TypedPathBuf::from("/srv/www")));
+    /// assert_eq!(path.join_relative_only("/etc"), Err(CheckedPathError::UnexpectedRoot));
+    /// ```
+    pub fn join_relative_only(
+        &self,
+        path: impl AsRef<str>,
+    ) -> Result<Utf8TypedPathBuf, CheckedPathError> {
+        self.join_with_mode(path, PushMode::Reject)
+    }
+
+    /// Creates an owned [`Utf8TypedPathBuf`] like `self` but with the given file name.
+    ///
+    /// See [`Utf8TypedPathBuf::set_file_name`] for more details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Utf8TypedPath, Utf8TypedPathBuf};
+    ///
+    /// let path = Utf8TypedPath::derive("/tmp/foo.txt");
+    /// assert_eq!(path.with_file_name("bar.txt"), Utf8TypedPathBuf::from("/tmp/bar.txt"));
     ///
     /// let path = Utf8TypedPath::derive("/tmp");
     /// assert_eq!(path.with_file_name("var"), Utf8TypedPathBuf::from("/var"));
@@ -613,6 +1292,111 @@ impl<'a> Utf8TypedPath<'a> {
         }
     }
 
+    /// Creates an owned [`Utf8TypedPathBuf`] like `self` but with every normal component
+    /// shortened to at most `n_bytes` bytes.
+    ///
+    /// See [`Utf8Path::truncate_component_to`] for more details.
+    ///
+    /// [`Utf8Path::truncate_component_to`]: crate::Utf8Path::truncate_component_to
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::Utf8TypedPath;
+    ///
+    /// let path = Utf8TypedPath::derive("/photos/holiday_in_spain.jpeg");
+    /// assert_eq!(path.truncate_component_to(8).as_str(), "/photos/hol.jpeg");
+    /// ```
+    pub fn truncate_component_to(&self, n_bytes: usize) -> Utf8TypedPathBuf {
+        match self {
+            Self::Unix(path) => Utf8TypedPathBuf::Unix(path.truncate_component_to(n_bytes)),
+            Self::Windows(path) => Utf8TypedPathBuf::Windows(path.truncate_component_to(n_bytes)),
+        }
+    }
+
+    /// Renders `self` for display within `max_width` columns by replacing leading directories with
+    /// `…`, always keeping the prefix, root, and file name.
+    ///
+    /// See [`Utf8Path::abbreviate`] for more details.
+    ///
+    /// [`Utf8Path::abbreviate`]: crate::Utf8Path::abbreviate
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::Utf8TypedPath;
+    ///
+    /// let path = Utf8TypedPath::derive(r"C:\Users\user\Documents\file.rs");
+    /// assert_eq!(path.abbreviate(22), r"C:\…\Documents\file.rs");
+    /// ```
+    pub fn abbreviate(&self, max_width: usize) -> String {
+        impl_typed_fn!(self, abbreviate, max_width)
+    }
+
+    /// Produces an owned key whose plain byte order matches the order of paths as trees, so that
+    /// paths can be stored in key-value stores without a custom comparator.
+    ///
+    /// See [`Utf8Path::sort_key`] for more details.
+    ///
+    /// [`Utf8Path::sort_key`]: crate::Utf8Path::sort_key
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::Utf8TypedPath;
+    ///
+    /// let key = |s| Utf8TypedPath::derive(s).sort_key();
+    /// assert!(key("/foo") < key("/foo/bar"));
+    /// assert!(key("/foo/bar") < key("/foo-bar"));
+    /// ```
+    pub fn sort_key(&self) -> Vec<u8> {
+        impl_typed_fn!(self, sort_key)
+    }
+
+    /// Returns the bounds of the sort keys covering exactly `self` and its descendants, for prefix
+    /// scans over a key-value store ordered by sort key.
+    ///
+    /// See [`Utf8Path::range_bounds_for_subtree`] for more details.
+    ///
+    /// [`Utf8Path::range_bounds_for_subtree`]: crate::Utf8Path::range_bounds_for_subtree
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::ops::RangeBounds;
+    /// use typed_path::Utf8TypedPath;
+    ///
+    /// let bounds = Utf8TypedPath::derive("/foo").range_bounds_for_subtree();
+    /// assert!(bounds.contains(&Utf8TypedPath::derive("/foo/bar").sort_key()));
+    /// assert!(!bounds.contains(&Utf8TypedPath::derive("/foo-bar").sort_key()));
+    /// ```
+    pub fn range_bounds_for_subtree(&self) -> (Bound<Vec<u8>>, Bound<Vec<u8>>) {
+        impl_typed_fn!(self, range_bounds_for_subtree)
+    }
+
+    /// Creates an owned [`Utf8TypedPathBuf`] like `self` but with its file name shortened such
+    /// that the entire path is at most `total_bytes` bytes.
+    ///
+    /// See [`Utf8Path::fit_within`] for more details.
+    ///
+    /// [`Utf8Path::fit_within`]: crate::Utf8Path::fit_within
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::Utf8TypedPath;
+    ///
+    /// let path = Utf8TypedPath::derive(r"C:\notes\meeting.md");
+    /// assert_eq!(path.fit_within(15).unwrap().as_str(), r"C:\notes\mee.md");
+    /// assert_eq!(path.fit_within(9), None);
+    /// ```
+    pub fn fit_within(&self, total_bytes: usize) -> Option<Utf8TypedPathBuf> {
+        match self {
+            Self::Unix(path) => path.fit_within(total_bytes).map(Utf8TypedPathBuf::Unix),
+            Self::Windows(path) => path.fit_within(total_bytes).map(Utf8TypedPathBuf::Windows),
+        }
+    }
+
     /// Produces an iterator over the [`Utf8TypedComponent`]s of the path.
     ///
     /// When parsing the path, there is a small amount of normalization:
@@ -679,6 +1463,56 @@ impl<'a> Utf8TypedPath<'a> {
         }
     }
 
+    /// Returns an object that implements [`Display`] for printing `self` relative to the `home`
+    /// directory, such as `~/project/x` or `%USERPROFILE%\Desktop`. Paths outside of `home`,
+    /// including those of a different encoding, are printed in full.
+    ///
+    /// See [`Utf8Path::display_relative_to_home`] for more details.
+    ///
+    /// [`Display`]: fmt::Display
+    /// [`Utf8Path::display_relative_to_home`]: crate::Utf8Path::display_relative_to_home
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::Utf8TypedPath;
+    ///
+    /// let home = Utf8TypedPath::derive(r"C:\Users\me");
+    /// let path = Utf8TypedPath::derive(r"C:\Users\me\Desktop");
+    /// assert_eq!(
+    ///     path.display_relative_to_home(&home).to_string(),
+    ///     r"%USERPROFILE%\Desktop",
+    /// );
+    ///
+    /// let path = Utf8TypedPath::derive("/home/me/Desktop");
+    /// assert_eq!(path.display_relative_to_home(&home).to_string(), "/home/me/Desktop");
+    /// ```
+    pub fn display_relative_to_home<'b>(
+        &'b self,
+        home: &'b Utf8TypedPath<'b>,
+    ) -> impl fmt::Display + 'b {
+        struct HomeDisplay<'a> {
+            path: &'a Utf8TypedPath<'a>,
+            home: &'a Utf8TypedPath<'a>,
+        }
+
+        impl fmt::Display for HomeDisplay<'_> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                match (self.path, self.home) {
+                    (Utf8TypedPath::Unix(path), Utf8TypedPath::Unix(home)) => {
+                        fmt::Display::fmt(&path.display_relative_to_home(home), f)
+                    }
+                    (Utf8TypedPath::Windows(path), Utf8TypedPath::Windows(home)) => {
+                        fmt::Display::fmt(&path.display_relative_to_home(home), f)
+                    }
+                    (path, _) => f.write_str(path.as_str()),
+                }
+            }
+        }
+
+        HomeDisplay { path: self, home }
+    }
+
     /// Returns true if this path represents a Unix path.
     #[inline]
     pub fn is_unix(&self) -> bool {
@@ -691,6 +1525,26 @@ impl<'a> Utf8TypedPath<'a> {
         matches!(self, Self::Windows(_))
     }
 
+    /// Returns the [`EncodingInfo`] describing the separators and case sensitivity of the
+    /// encoding used by this path.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::Utf8TypedPath;
+    ///
+    /// let path = Utf8TypedPath::windows(r"C:\temp");
+    /// let info = path.encoding_info();
+    /// assert_eq!(info.name, "windows");
+    /// assert!(path.as_str().chars().any(|c| info.is_separator_char(c)));
+    /// ```
+    pub fn encoding_info(&self) -> EncodingInfo {
+        match self {
+            Self::Unix(_) => <Utf8UnixEncoding as Utf8Encoding>::info(),
+            Self::Windows(_) => <Utf8WindowsEncoding as Utf8Encoding>::info(),
+        }
+    }
+
     /// Converts this [`Utf8TypedPath`] into the Unix variant of [`Utf8TypedPathBuf`].
     pub fn with_unix_encoding(&self) -> Utf8TypedPathBuf {
         match self {
@@ -724,6 +1578,74 @@ impl<'a> Utf8TypedPath<'a> {
             Self::Windows(p) => Utf8TypedPathBuf::Windows(p.with_windows_encoding_checked()?),
         })
     }
+
+    /// Encodes the path as the path of a URI, joining its components with `/` and
+    /// percent-encoding every character of a component that is not allowed within a path segment.
+    ///
+    /// See [`Utf8Path::encode_percent`] for more details.
+    ///
+    /// [`Utf8Path::encode_percent`]: crate::Utf8Path::encode_percent
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::Utf8TypedPath;
+    ///
+    /// let path = Utf8TypedPath::derive(r"C:\My Files\a#b.txt");
+    /// assert_eq!(path.encode_percent(), "C:/My%20Files/a%23b.txt");
+    /// ```
+    pub fn encode_percent(&self) -> String {
+        match self {
+            Self::Unix(p) => p.encode_percent(),
+            Self::Windows(p) => p.encode_percent(),
+        }
+    }
+
+    /// Decodes the path as the path of a URI, percent-decoding each of its components into a
+    /// [`TypedPathBuf`] of the same encoding.
+    ///
+    /// See [`Utf8Path::decode_percent`] for more details.
+    ///
+    /// [`Utf8Path::decode_percent`]: crate::Utf8Path::decode_percent
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CheckedPathError`] if a decoded component is not a valid filename of the
+    /// encoding.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::Utf8TypedPath;
+    ///
+    /// let path = Utf8TypedPath::derive("/srv/caf%C3%A9%20menu").decode_percent().unwrap();
+    /// assert!(path.is_unix());
+    /// assert_eq!(path.as_bytes(), "/srv/café menu".as_bytes());
+    /// ```
+    pub fn decode_percent(&self) -> Result<TypedPathBuf, CheckedPathError> {
+        Ok(match self {
+            Self::Unix(p) => TypedPathBuf::Unix(p.decode_percent()?),
+            Self::Windows(p) => TypedPathBuf::Windows(p.decode_percent()?),
+        })
+    }
+}
+
+impl fmt::Debug for Utf8TypedPath<'_> {
+    /// Writes the encoding of the path along with its contents, escaped like a [`str`].
+    ///
+    /// ```
+    /// use typed_path::Utf8TypedPath;
+    ///
+    /// let path = Utf8TypedPath::derive(r"C:\x");
+    /// assert_eq!(format!("{path:?}"), r#"Windows("C:\\x")"#);
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (name, path) = match self {
+            Self::Unix(path) => ("Unix", path.as_str()),
+            Self::Windows(path) => ("Windows", path.as_str()),
+        };
+        f.debug_tuple(name).field(&path).finish()
+    }
 }
 
 impl fmt::Display for Utf8TypedPath<'_> {
@@ -742,6 +1664,19 @@ impl<'a> From<&'a str> for Utf8TypedPath<'a> {
     }
 }
 
+impl<'a> TryFrom<TypedPath<'a>> for Utf8TypedPath<'a> {
+    type Error = Utf8PathError;
+
+    fn try_from(path: TypedPath<'a>) -> Result<Self, Self::Error> {
+        match path {
+            TypedPath::Unix(path) => Utf8UnixPath::from_bytes_path(path).map(Utf8TypedPath::Unix),
+            TypedPath::Windows(path) => {
+                Utf8WindowsPath::from_bytes_path(path).map(Utf8TypedPath::Windows)
+            }
+        }
+    }
+}
+
 impl AsRef<str> for Utf8TypedPath<'_> {
     #[inline]
     fn as_ref(&self) -> &str {
@@ -804,8 +1739,10 @@ impl<'a> PartialEq<&'a str> for Utf8TypedPath<'_> {
     }
 }
 
-impl<'a> PartialEq<Utf8TypedPath<'_>> for &'a str {
+impl PartialEq<Utf8TypedPath<'_>> for &str {
     fn eq(&self, path: &Utf8TypedPath<'_>) -> bool {
         *self == path.as_str()
     }
 }
+
+impl_typed_cmp!('a; Utf8TypedPath<'a>, String, str, as_str);
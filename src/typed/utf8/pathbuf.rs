@@ -1,23 +1,28 @@
 use alloc::collections::TryReserveError;
 use core::convert::TryFrom;
 use core::fmt;
+use core::ops::{Bound, Range};
 
 #[cfg(feature = "std")]
 use std::path::PathBuf;
 
-use crate::common::{CheckedPathError, StripPrefixError};
+use crate::common::{
+    CheckedPathError, ConversionPolicy, DifferentPrefixError, EncodingInfo, NormalizationOptions,
+    PathEquivalence, PushMode, StripPrefixError, Utf8Encoding, Utf8PathError,
+};
 use crate::no_std_compat::*;
 use crate::typed::{
-    PathType, Utf8TypedAncestors, Utf8TypedComponents, Utf8TypedIter, Utf8TypedPath,
+    PathType, TypedPathBuf, Utf8TypedAncestors, Utf8TypedComponent, Utf8TypedComponents,
+    Utf8TypedIter, Utf8TypedPath,
 };
-use crate::unix::{Utf8UnixPath, Utf8UnixPathBuf};
-use crate::windows::{Utf8WindowsPath, Utf8WindowsPathBuf};
+use crate::unix::{Utf8UnixEncoding, Utf8UnixPath, Utf8UnixPathBuf};
+use crate::windows::{Utf8WindowsEncoding, Utf8WindowsPath, Utf8WindowsPathBuf};
 
 /// Represents a pathbuf with a known type that can be one of:
 ///
 /// * [`Utf8UnixPathBuf`]
 /// * [`Utf8WindowsPathBuf`]
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, PartialEq, Eq)]
 pub enum Utf8TypedPathBuf {
     Unix(Utf8UnixPathBuf),
     Windows(Utf8WindowsPathBuf),
@@ -36,6 +41,26 @@ impl Utf8TypedPathBuf {
         matches!(self, Self::Windows(_))
     }
 
+    /// Returns the [`EncodingInfo`] describing the separators and case sensitivity of the
+    /// encoding used by this path.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::Utf8TypedPathBuf;
+    ///
+    /// let path = Utf8TypedPathBuf::from_windows(r"C:\temp");
+    /// let info = path.encoding_info();
+    /// assert_eq!(info.name, "windows");
+    /// assert!(path.as_str().chars().any(|c| info.is_separator_char(c)));
+    /// ```
+    pub fn encoding_info(&self) -> EncodingInfo {
+        match self {
+            Self::Unix(_) => <Utf8UnixEncoding as Utf8Encoding>::info(),
+            Self::Windows(_) => <Utf8WindowsEncoding as Utf8Encoding>::info(),
+        }
+    }
+
     /// Converts this [`Utf8TypedPathBuf`] into the Unix variant.
     pub fn with_unix_encoding(&self) -> Utf8TypedPathBuf {
         match self {
@@ -70,6 +95,50 @@ impl Utf8TypedPathBuf {
         })
     }
 
+    /// Encodes the path as the path of a URI, joining its components with `/` and
+    /// percent-encoding every character of a component that is not allowed within a path segment.
+    ///
+    /// See [`Utf8Path::encode_percent`] for more details.
+    ///
+    /// [`Utf8Path::encode_percent`]: crate::Utf8Path::encode_percent
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::Utf8TypedPathBuf;
+    ///
+    /// let path = Utf8TypedPathBuf::from(r"C:\My Files\a#b.txt");
+    /// assert_eq!(path.encode_percent(), "C:/My%20Files/a%23b.txt");
+    /// ```
+    pub fn encode_percent(&self) -> String {
+        self.to_path().encode_percent()
+    }
+
+    /// Decodes the path as the path of a URI, percent-decoding each of its components into a
+    /// [`TypedPathBuf`] of the same encoding.
+    ///
+    /// See [`Utf8Path::decode_percent`] for more details.
+    ///
+    /// [`Utf8Path::decode_percent`]: crate::Utf8Path::decode_percent
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CheckedPathError`] if a decoded component is not a valid filename of the
+    /// encoding.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::Utf8TypedPathBuf;
+    ///
+    /// let path = Utf8TypedPathBuf::from("/srv/caf%C3%A9%20menu").decode_percent().unwrap();
+    /// assert!(path.is_unix());
+    /// assert_eq!(path.as_bytes(), "/srv/café menu".as_bytes());
+    /// ```
+    pub fn decode_percent(&self) -> Result<TypedPathBuf, CheckedPathError> {
+        self.to_path().decode_percent()
+    }
+
     /// Allocates an empty [`Utf8TypedPathBuf`] for the specified path type.
     ///
     /// # Examples
@@ -243,6 +312,163 @@ impl Utf8TypedPathBuf {
         }
     }
 
+    /// Extends `self` with `path`, first converting `path` to the encoding of `self` using
+    /// `policy` if the two differ.
+    ///
+    /// Unlike [`Utf8TypedPathBuf::push`], which treats its argument as bytes in the encoding of
+    /// `self`, this honors the encoding of `path`, translating separators, roots, `.`, and `..`
+    /// into the encoding of `self`. The converted path is then pushed as with
+    /// [`Utf8TypedPathBuf::push`].
+    ///
+    /// # Errors
+    ///
+    /// With [`ConversionPolicy::Checked`], an error is returned if the conversion of `path`
+    /// fails, including when a Windows path with a prefix is converted to a Unix path. In that
+    /// case, `self` is left unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{CheckedPathError, ConversionPolicy, Utf8TypedPath, Utf8TypedPathBuf};
+    ///
+    /// let mut path = Utf8TypedPathBuf::from_unix("/srv");
+    /// let other = Utf8TypedPath::windows(r"www\..\index.html");
+    /// path.push_typed(other, ConversionPolicy::Lossy).unwrap();
+    /// assert_eq!(path.as_str(), "/srv/www/../index.html");
+    ///
+    /// // Lossy conversion discards the prefix of an absolute Windows path
+    /// let mut path = Utf8TypedPathBuf::from_unix("/srv");
+    /// path.push_typed(Utf8TypedPath::derive(r"C:\assets"), ConversionPolicy::Lossy).unwrap();
+    /// assert_eq!(path.as_str(), "/assets");
+    ///
+    /// // ...but rejected when converting with checks
+    /// let mut path = Utf8TypedPathBuf::from_unix("/srv");
+    /// assert_eq!(
+    ///     path.push_typed(Utf8TypedPath::derive(r"C:\assets"), ConversionPolicy::Checked),
+    ///     Err(CheckedPathError::UnexpectedPrefix),
+    /// );
+    /// ```
+    pub fn push_typed(
+        &mut self,
+        path: Utf8TypedPath<'_>,
+        policy: ConversionPolicy,
+    ) -> Result<(), CheckedPathError> {
+        if self.is_unix() == path.is_unix() {
+            self.push(path);
+            return Ok(());
+        }
+
+        let path = match (self.is_unix(), policy) {
+            (true, ConversionPolicy::Lossy) => path.with_unix_encoding(),
+            (true, ConversionPolicy::Checked) => match path {
+                Utf8TypedPath::Windows(p) if p.components().has_prefix() => {
+                    return Err(CheckedPathError::UnexpectedPrefix);
+                }
+                _ => path.with_unix_encoding_checked()?,
+            },
+            (false, ConversionPolicy::Lossy) => path.with_windows_encoding(),
+            (false, ConversionPolicy::Checked) => path.with_windows_encoding_checked()?,
+        };
+
+        self.push(path);
+        Ok(())
+    }
+
+    /// Extends `self` with `path`, failing if `path` uses a different encoding and cannot be
+    /// converted to the encoding of `self` without loss.
+    ///
+    /// This is the same as [`Utf8TypedPathBuf::push_typed`] using [`ConversionPolicy::Checked`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Utf8TypedPath, Utf8TypedPathBuf};
+    ///
+    /// let mut path = Utf8TypedPathBuf::from_windows(r"C:\srv");
+    /// path.try_push(Utf8TypedPath::unix("www/index.html")).unwrap();
+    /// assert_eq!(path.as_str(), r"C:\srv\www\index.html");
+    ///
+    /// // `:` is not valid within a Windows filename
+    /// assert!(path.try_push(Utf8TypedPath::unix("a:b")).is_err());
+    /// ```
+    pub fn try_push(&mut self, path: Utf8TypedPath<'_>) -> Result<(), CheckedPathError> {
+        self.push_typed(path, ConversionPolicy::Checked)
+    }
+
+    /// Like [`Utf8TypedPathBuf::push`], extends `self` with `path`, but uses the given [`PushMode`]
+    /// to decide what happens when `path` has its own root or prefix.
+    ///
+    /// See [`Utf8PathBuf::push_with_mode`] for more details.
+    ///
+    /// [`Utf8PathBuf::push_with_mode`]: crate::Utf8PathBuf::push_with_mode
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{CheckedPathError, PushMode, Utf8TypedPathBuf};
+    ///
+    /// let mut path = Utf8TypedPathBuf::from("/srv/www");
+    /// path.push_with_mode("/etc", PushMode::Append).unwrap();
+    /// assert_eq!(path, Utf8TypedPathBuf::from("/srv/www/etc"));
+    ///
+    /// assert_eq!(
+    ///     path.push_with_mode("/etc", PushMode::Reject),
+    ///     Err(CheckedPathError::UnexpectedRoot),
+    /// );
+    /// ```
+    pub fn push_with_mode(
+        &mut self,
+        path: impl AsRef<str>,
+        mode: PushMode,
+    ) -> Result<(), CheckedPathError> {
+        match self {
+            Self::Unix(a) => a.push_with_mode(Utf8UnixPath::new(&path), mode),
+            Self::Windows(a) => a.push_with_mode(Utf8WindowsPath::new(&path), mode),
+        }
+    }
+
+    /// Extends `self` with `path`, never replacing `self` even if `path` is absolute.
+    ///
+    /// See [`Utf8PathBuf::push_keeping_base`] for more details.
+    ///
+    /// [`Utf8PathBuf::push_keeping_base`]: crate::Utf8PathBuf::push_keeping_base
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::Utf8TypedPathBuf;
+    ///
+    /// let mut path = Utf8TypedPathBuf::from("/srv/www");
+    /// path.push_keeping_base("/index.html");
+    /// assert_eq!(path, Utf8TypedPathBuf::from("/srv/www/index.html"));
+    /// ```
+    pub fn push_keeping_base(&mut self, path: impl AsRef<str>) {
+        match self {
+            Self::Unix(a) => a.push_keeping_base(Utf8UnixPath::new(&path)),
+            Self::Windows(a) => a.push_keeping_base(Utf8WindowsPath::new(&path)),
+        }
+    }
+
+    /// Extends `self` with `path`, failing if `path` has a root or prefix.
+    ///
+    /// See [`Utf8PathBuf::push_relative_only`] for more details.
+    ///
+    /// [`Utf8PathBuf::push_relative_only`]: crate::Utf8PathBuf::push_relative_only
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{CheckedPathError, Utf8TypedPathBuf};
+    ///
+    /// let mut path = Utf8TypedPathBuf::from("/srv");
+    /// assert_eq!(path.push_relative_only("/etc"), Err(CheckedPathError::UnexpectedRoot));
+    /// assert_eq!(path.push_relative_only("www"), Ok(()));
+    /// assert_eq!(path, Utf8TypedPathBuf::from("/srv/www"));
+    /// ```
+    pub fn push_relative_only(&mut self, path: impl AsRef<str>) -> Result<(), CheckedPathError> {
+        self.push_with_mode(path, PushMode::Reject)
+    }
+
     /// Truncates `self` to [`self.parent`].
     ///
     /// Returns `false` and does nothing if [`self.parent`] is [`None`].
@@ -266,6 +492,94 @@ impl Utf8TypedPathBuf {
         impl_typed_fn!(self, pop)
     }
 
+    /// Inserts `component` into `self` so that it becomes the component at position `index`,
+    /// shifting all components after it to the right.
+    ///
+    /// See [`Utf8PathBuf::insert_component`] for more details.
+    ///
+    /// # Difference from PathBuf
+    ///
+    /// Unlike [`Utf8PathBuf::insert_component`], this implementation only supports types that
+    /// implement `AsRef<str>` instead of `AsRef<Utf8Path>`.
+    ///
+    /// [`Utf8PathBuf::insert_component`]: crate::Utf8PathBuf::insert_component
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is greater than the number of components in the path, or if `index`
+    /// falls on the prefix or root of the path.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::Utf8TypedPathBuf;
+    ///
+    /// let mut p = Utf8TypedPathBuf::from_unix("/usr/lib");
+    /// p.insert_component(1, "opt/local");
+    /// assert_eq!(p.as_str(), "/opt/local/usr/lib");
+    /// ```
+    pub fn insert_component(&mut self, index: usize, component: impl AsRef<str>) {
+        match self {
+            Self::Unix(p) => p.insert_component(index, Utf8UnixPath::new(&component)),
+            Self::Windows(p) => p.insert_component(index, Utf8WindowsPath::new(&component)),
+        }
+    }
+
+    /// Removes the component at position `index` within `self`, shifting all components after it
+    /// to the left.
+    ///
+    /// Returns `false` and does nothing if `index` is out of range. Otherwise, returns `true`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::Utf8TypedPathBuf;
+    ///
+    /// let mut p = Utf8TypedPathBuf::from_unix("/usr/local/lib");
+    /// assert!(p.remove_component(2));
+    /// assert_eq!(p.as_str(), "/usr/lib");
+    /// ```
+    pub fn remove_component(&mut self, index: usize) -> bool {
+        impl_typed_fn!(self, remove_component, index)
+    }
+
+    /// Retains only the components of `self` for which `f` returns `true`, passing each
+    /// component of the path in order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::Utf8TypedPathBuf;
+    ///
+    /// let mut p = Utf8TypedPathBuf::from_unix("/usr/local/lib");
+    /// p.retain_components(|c| c.as_str() != "local");
+    /// assert_eq!(p.as_str(), "/usr/lib");
+    /// ```
+    pub fn retain_components<F>(&mut self, mut f: F)
+    where
+        F: for<'a> FnMut(Utf8TypedComponent<'a>) -> bool,
+    {
+        match self {
+            Self::Unix(p) => p.retain_components(|c| f(Utf8TypedComponent::Unix(c))),
+            Self::Windows(p) => p.retain_components(|c| f(Utf8TypedComponent::Windows(c))),
+        }
+    }
+
+    /// Shortens `self` to its first `len` components, removing any separators that follow them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::Utf8TypedPathBuf;
+    ///
+    /// let mut p = Utf8TypedPathBuf::from_unix("/usr/local/lib");
+    /// p.truncate_components(2);
+    /// assert_eq!(p.as_str(), "/usr");
+    /// ```
+    pub fn truncate_components(&mut self, len: usize) {
+        impl_typed_fn!(self, truncate_components, len)
+    }
+
     /// Updates [`self.file_name`] to `file_name`.
     ///
     /// If [`self.file_name`] was [`None`], this is equivalent to pushing
@@ -339,6 +653,22 @@ impl Utf8TypedPathBuf {
         impl_typed_fn!(self, into_string)
     }
 
+    /// Consumes the [`Utf8TypedPathBuf`], yielding the [`Vec<u8>`] storage of its internal
+    /// [`String`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::Utf8TypedPathBuf;
+    ///
+    /// let p = Utf8TypedPathBuf::from_windows(r"C:\the\head");
+    /// assert_eq!(p.into_bytes(), br"C:\the\head");
+    /// ```
+    #[inline]
+    pub fn into_bytes(self) -> Vec<u8> {
+        impl_typed_fn!(self, into_bytes)
+    }
+
     /// Invokes [`capacity`] on the underlying instance of [`Vec`].
     ///
     /// [`capacity`]: Vec::capacity
@@ -488,6 +818,73 @@ impl Utf8TypedPathBuf {
         impl_typed_fn!(self, has_root)
     }
 
+    /// Returns the prefix and root at the start of the [`Utf8TypedPathBuf`], if it has either, such as `/`,
+    /// `C:\`, or `\\server\share\`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Utf8TypedPath, Utf8TypedPathBuf};
+    ///
+    /// let path = Utf8TypedPathBuf::from(r"C:\Windows");
+    /// assert_eq!(path.root(), Some(Utf8TypedPath::derive(r"C:\")));
+    /// ```
+    pub fn root(&self) -> Option<Utf8TypedPath<'_>> {
+        self.to_path().root()
+    }
+
+    /// Splits the [`Utf8TypedPathBuf`] into its prefix and root, as returned by [`Utf8TypedPathBuf::root`], and the
+    /// relative remainder of the path.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Utf8TypedPath, Utf8TypedPathBuf};
+    ///
+    /// let path = Utf8TypedPathBuf::from("/usr/lib");
+    /// let (root, rest) = path.split_root();
+    /// assert_eq!(root, Some(Utf8TypedPath::derive("/")));
+    /// assert_eq!(rest, Utf8TypedPath::derive("usr/lib"));
+    /// ```
+    pub fn split_root(&self) -> (Option<Utf8TypedPath<'_>>, Utf8TypedPath<'_>) {
+        self.to_path().split_root()
+    }
+
+    /// Returns `true` if the [`Utf8TypedPathBuf`] is made up of nothing more than a root, such as `/`, `C:\`,
+    /// or `\\server\share`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::Utf8TypedPathBuf;
+    ///
+    /// assert!(Utf8TypedPathBuf::from("/").is_root());
+    /// assert!(Utf8TypedPathBuf::from(r"C:\").is_root());
+    /// assert!(!Utf8TypedPathBuf::from("C:").is_root());
+    /// assert!(!Utf8TypedPathBuf::from("/etc").is_root());
+    /// ```
+    #[inline]
+    pub fn is_root(&self) -> bool {
+        impl_typed_fn!(self, is_root)
+    }
+
+    /// Returns `true` if the [`Utf8TypedPathBuf`] is made up of nothing more than a prefix without a root,
+    /// such as `C:`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::Utf8TypedPathBuf;
+    ///
+    /// assert!(Utf8TypedPathBuf::from("C:").is_prefix_only());
+    /// assert!(!Utf8TypedPathBuf::from(r"C:\").is_prefix_only());
+    /// assert!(!Utf8TypedPathBuf::from("/").is_prefix_only());
+    /// ```
+    #[inline]
+    pub fn is_prefix_only(&self) -> bool {
+        impl_typed_fn!(self, is_prefix_only)
+    }
+
     /// Returns the [`Utf8TypedPathBuf`] without its final component, if there is one.
     ///
     /// Returns [`None`] if the path terminates in a root or prefix.
@@ -544,6 +941,53 @@ impl Utf8TypedPathBuf {
         self.to_path().ancestors()
     }
 
+    /// Produces an iterator over [`Utf8TypedPathBuf`] and its ancestors that stops after yielding
+    /// `base`.
+    ///
+    /// See [`Utf8Path::ancestors_until`] for more details.
+    ///
+    /// # Difference from Path
+    ///
+    /// Unlike [`Utf8Path::ancestors_until`], this implementation only supports types that implement
+    /// `AsRef<str>` instead of `AsRef<Utf8Path>`.
+    ///
+    /// [`Utf8Path::ancestors_until`]: crate::Utf8Path::ancestors_until
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Utf8TypedPath, Utf8TypedPathBuf};
+    ///
+    /// let path = Utf8TypedPathBuf::from("/home/user/project/src");
+    /// let mut ancestors = path.ancestors_until("/home/user/project");
+    /// assert_eq!(ancestors.next(), Some(Utf8TypedPath::derive("/home/user/project/src")));
+    /// assert_eq!(ancestors.next(), Some(Utf8TypedPath::derive("/home/user/project")));
+    /// assert_eq!(ancestors.next(), None);
+    /// ```
+    pub fn ancestors_until(&self, base: impl AsRef<str>) -> Utf8TypedAncestors<'_> {
+        self.to_path().ancestors_until(base)
+    }
+
+    /// Returns the `n`th ancestor of the path, or [`None`] if the path has fewer than `n`
+    /// parents.
+    ///
+    /// See [`Utf8Path::nth_parent`] for more details.
+    ///
+    /// [`Utf8Path::nth_parent`]: crate::Utf8Path::nth_parent
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Utf8TypedPath, Utf8TypedPathBuf};
+    ///
+    /// let path = Utf8TypedPathBuf::from("/foo/bar/baz");
+    /// assert_eq!(path.nth_parent(2), Some(Utf8TypedPath::derive("/foo")));
+    /// assert_eq!(path.nth_parent(4), None);
+    /// ```
+    pub fn nth_parent(&self, n: usize) -> Option<Utf8TypedPath<'_>> {
+        self.to_path().nth_parent(n)
+    }
+
     /// Returns the final component of the [`Utf8TypedPathBuf`], if there is one.
     ///
     /// If the path is a normal file, this is the file name. If it's the path of a directory, this
@@ -595,7 +1039,10 @@ impl Utf8TypedPathBuf {
     /// let prefix = Utf8TypedPathBuf::from("/test/");
     /// assert_eq!(path.strip_prefix(prefix), Ok(Utf8TypedPath::derive("haha/foo.txt")));
     /// ```
-    pub fn strip_prefix(&self, base: impl AsRef<str>) -> Result<Utf8TypedPath, StripPrefixError> {
+    pub fn strip_prefix(
+        &self,
+        base: impl AsRef<str>,
+    ) -> Result<Utf8TypedPath<'_>, StripPrefixError> {
         match self {
             Self::Unix(p) => p
                 .strip_prefix(Utf8UnixPath::new(&base))
@@ -606,6 +1053,33 @@ impl Utf8TypedPathBuf {
         }
     }
 
+    /// Returns a relative path that, when joined onto `base`, refers to the same location as
+    /// `self`.
+    ///
+    /// See [`Utf8Path::relative_to`] for more details.
+    ///
+    /// # Difference from Path
+    ///
+    /// Unlike [`Utf8Path::relative_to`], this implementation only supports types that implement
+    /// `AsRef<str>` instead of `AsRef<Utf8Path>`.
+    ///
+    /// [`Utf8Path::relative_to`]: crate::Utf8Path::relative_to
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::Utf8TypedPathBuf;
+    ///
+    /// let path = Utf8TypedPathBuf::from("/usr/share/doc");
+    /// assert_eq!(path.relative_to("/usr/lib").unwrap().as_str(), "../share/doc");
+    /// ```
+    pub fn relative_to(
+        &self,
+        base: impl AsRef<str>,
+    ) -> Result<Utf8TypedPathBuf, DifferentPrefixError> {
+        self.to_path().relative_to(base)
+    }
+
     /// Determines whether `base` is a prefix of `self`.
     ///
     /// Only considers whole path components to match.
@@ -668,24 +1142,263 @@ impl Utf8TypedPathBuf {
         self.to_path().ends_with(child)
     }
 
-    /// Extracts the stem (non-extension) portion of [`self.file_name`].
+    /// Determines whether `self` is a strict ancestor of `other`.
     ///
-    /// [`self.file_name`]: Utf8TypedPathBuf::file_name
+    /// See [`Utf8Path::is_ancestor_of`] for more details.
     ///
-    /// The stem is:
+    /// # Difference from Path
     ///
-    /// * [`None`], if there is no file name;
-    /// * The entire file name if there is no embedded `.`;
-    /// * The entire file name if the file name begins with `.` and has no other `.`s within;
-    /// * Otherwise, the portion of the file name before the final `.`
+    /// Unlike [`Utf8Path::is_ancestor_of`], this implementation only supports types that implement
+    /// `AsRef<str>` instead of `AsRef<Utf8Path>`.
+    ///
+    /// [`Utf8Path::is_ancestor_of`]: crate::Utf8Path::is_ancestor_of
     ///
     /// # Examples
     ///
     /// ```
     /// use typed_path::Utf8TypedPathBuf;
     ///
-    /// assert_eq!("foo", Utf8TypedPathBuf::from("foo.rs").file_stem().unwrap());
-    /// assert_eq!("foo.tar", Utf8TypedPathBuf::from("foo.tar.gz").file_stem().unwrap());
+    /// let path = Utf8TypedPathBuf::from("/etc");
+    /// assert!(path.is_ancestor_of("/etc/passwd"));
+    /// assert!(!path.is_ancestor_of("/etcetera"));
+    /// ```
+    pub fn is_ancestor_of(&self, other: impl AsRef<str>) -> bool {
+        self.to_path().is_ancestor_of(other)
+    }
+
+    /// Determines whether `self` is either the same path as `other` or one of its ancestors.
+    ///
+    /// See [`Utf8Path::is_inclusive_ancestor_of`] for more details.
+    ///
+    /// # Difference from Path
+    ///
+    /// Unlike [`Utf8Path::is_inclusive_ancestor_of`], this implementation only supports types that
+    /// implement `AsRef<str>` instead of `AsRef<Utf8Path>`.
+    ///
+    /// [`Utf8Path::is_inclusive_ancestor_of`]: crate::Utf8Path::is_inclusive_ancestor_of
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::Utf8TypedPathBuf;
+    ///
+    /// let path = Utf8TypedPathBuf::from("/etc");
+    /// assert!(path.is_inclusive_ancestor_of("/etc"));
+    /// ```
+    pub fn is_inclusive_ancestor_of(&self, other: impl AsRef<str>) -> bool {
+        self.to_path().is_inclusive_ancestor_of(other)
+    }
+
+    /// Determines whether `self` is a strict descendant of `other`.
+    ///
+    /// See [`Utf8Path::is_descendant_of`] for more details.
+    ///
+    /// # Difference from Path
+    ///
+    /// Unlike [`Utf8Path::is_descendant_of`], this implementation only supports types that
+    /// implement `AsRef<str>` instead of `AsRef<Utf8Path>`.
+    ///
+    /// [`Utf8Path::is_descendant_of`]: crate::Utf8Path::is_descendant_of
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::Utf8TypedPathBuf;
+    ///
+    /// let path = Utf8TypedPathBuf::from("/etc/passwd");
+    /// assert!(path.is_descendant_of("/etc"));
+    /// assert!(!path.is_descendant_of("/et"));
+    /// ```
+    pub fn is_descendant_of(&self, other: impl AsRef<str>) -> bool {
+        self.to_path().is_descendant_of(other)
+    }
+
+    /// Determines whether `self` is either the same path as `other` or one of its descendants.
+    ///
+    /// See [`Utf8Path::is_inclusive_descendant_of`] for more details.
+    ///
+    /// # Difference from Path
+    ///
+    /// Unlike [`Utf8Path::is_inclusive_descendant_of`], this implementation only supports types
+    /// that implement `AsRef<str>` instead of `AsRef<Utf8Path>`.
+    ///
+    /// [`Utf8Path::is_inclusive_descendant_of`]: crate::Utf8Path::is_inclusive_descendant_of
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::Utf8TypedPathBuf;
+    ///
+    /// let path = Utf8TypedPathBuf::from("/etc/passwd");
+    /// assert!(path.is_inclusive_descendant_of("/etc/passwd"));
+    /// ```
+    pub fn is_inclusive_descendant_of(&self, other: impl AsRef<str>) -> bool {
+        self.to_path().is_inclusive_descendant_of(other)
+    }
+
+    /// Determines whether `needle` appears anywhere within `self` as a sequence of whole
+    /// components.
+    ///
+    /// # Difference from Path
+    ///
+    /// Unlike [`Utf8Path::contains_path`], this implementation only supports types that implement
+    /// `AsRef<str>` instead of `AsRef<Utf8Path>`.
+    ///
+    /// [`Utf8Path::contains_path`]: crate::Utf8Path::contains_path
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::Utf8TypedPathBuf;
+    ///
+    /// let path = Utf8TypedPathBuf::from("/repo/node_modules/.bin/tsc");
+    /// assert!(path.contains_path("node_modules/.bin"));
+    /// ```
+    pub fn contains_path(&self, needle: impl AsRef<str>) -> bool {
+        self.to_path().contains_path(needle)
+    }
+
+    /// Locates the first occurrence of `needle` within `self` as a sequence of whole components,
+    /// returning the index of the first matching component alongside the span of bytes within
+    /// `self` covered by the matching components.
+    ///
+    /// # Difference from Path
+    ///
+    /// Unlike [`Utf8Path::find_subpath`], this implementation only supports types that implement
+    /// `AsRef<str>` instead of `AsRef<Utf8Path>`.
+    ///
+    /// [`Utf8Path::find_subpath`]: crate::Utf8Path::find_subpath
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::Utf8TypedPathBuf;
+    ///
+    /// let path = Utf8TypedPathBuf::from("/repo/node_modules/.bin/tsc");
+    /// assert_eq!(path.find_subpath("node_modules/.bin"), Some((2, 6..23)));
+    /// ```
+    pub fn find_subpath(&self, needle: impl AsRef<str>) -> Option<(usize, Range<usize>)> {
+        self.to_path().find_subpath(needle)
+    }
+
+    /// Creates an owned [`Utf8TypedPathBuf`] where every non-overlapping occurrence of the
+    /// components of `from` within `self` is replaced with `to`.
+    ///
+    /// See [`Utf8Path::replace_components`] for more details.
+    ///
+    /// # Difference from Path
+    ///
+    /// Unlike [`Utf8Path::replace_components`], this implementation only supports types that
+    /// implement `AsRef<str>` instead of `AsRef<Utf8Path>`.
+    ///
+    /// [`Utf8Path::replace_components`]: crate::Utf8Path::replace_components
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::Utf8TypedPathBuf;
+    ///
+    /// let path = Utf8TypedPathBuf::from("/repo/src/main.rs");
+    /// assert_eq!(path.replace_components("src", "generated").as_str(), "/repo/generated/main.rs");
+    /// ```
+    pub fn replace_components(
+        &self,
+        from: impl AsRef<str>,
+        to: impl AsRef<str>,
+    ) -> Utf8TypedPathBuf {
+        self.to_path().replace_components(from, to)
+    }
+
+    /// Creates an owned [`Utf8TypedPathBuf`] where each component of `self` is replaced with the
+    /// string returned by `f`.
+    ///
+    /// See [`Utf8Path::map_components`] for more details.
+    ///
+    /// [`Utf8Path::map_components`]: crate::Utf8Path::map_components
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::Utf8TypedPathBuf;
+    ///
+    /// let path = Utf8TypedPathBuf::from("/repo/src/main.rs");
+    /// let upper = path.map_components(|c| c.as_str().to_ascii_uppercase());
+    /// assert_eq!(upper.as_str(), "/REPO/SRC/MAIN.RS");
+    /// ```
+    pub fn map_components<'a, F, C>(&'a self, f: F) -> Utf8TypedPathBuf
+    where
+        F: FnMut(Utf8TypedComponent<'a>) -> C,
+        C: AsRef<str>,
+    {
+        self.to_path().map_components(f)
+    }
+
+    /// Determines whether `other` refers to the same place as `self` after lexical
+    /// normalization, using the case rules of the encoding.
+    ///
+    /// See [`Utf8Path::normalized_eq`] for more details.
+    ///
+    /// # Difference from Path
+    ///
+    /// Unlike [`Utf8Path::normalized_eq`], this implementation only supports types that implement
+    /// `AsRef<str>` instead of `AsRef<Utf8Path>`.
+    ///
+    /// [`Utf8Path::normalized_eq`]: crate::Utf8Path::normalized_eq
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::Utf8TypedPathBuf;
+    ///
+    /// let path = Utf8TypedPathBuf::from("/etc/./ssh//config");
+    /// assert!(path.normalized_eq("/etc/ssl/../ssh/config"));
+    /// ```
+    pub fn normalized_eq(&self, other: impl AsRef<str>) -> bool {
+        self.to_path().normalized_eq(other)
+    }
+
+    /// Determines whether `other` refers to the same place as `self` using the given
+    /// [`PathEquivalence`] rules.
+    ///
+    /// See [`Utf8Path::equivalent_with`] for more details.
+    ///
+    /// # Difference from Path
+    ///
+    /// Unlike [`Utf8Path::equivalent_with`], this implementation only supports types that implement
+    /// `AsRef<str>` instead of `AsRef<Utf8Path>`.
+    ///
+    /// [`Utf8Path::equivalent_with`]: crate::Utf8Path::equivalent_with
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{PathEquivalence, Utf8TypedPathBuf};
+    ///
+    /// let rules = PathEquivalence::new().case_sensitive(false);
+    /// assert!(Utf8TypedPathBuf::from("/Users/Me").equivalent_with("/users/me", &rules));
+    /// ```
+    pub fn equivalent_with(&self, other: impl AsRef<str>, rules: &PathEquivalence) -> bool {
+        self.to_path().equivalent_with(other, rules)
+    }
+
+    /// Extracts the stem (non-extension) portion of [`self.file_name`].
+    ///
+    /// [`self.file_name`]: Utf8TypedPathBuf::file_name
+    ///
+    /// The stem is:
+    ///
+    /// * [`None`], if there is no file name;
+    /// * The entire file name if there is no embedded `.`;
+    /// * The entire file name if the file name begins with `.` and has no other `.`s within;
+    /// * Otherwise, the portion of the file name before the final `.`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::Utf8TypedPathBuf;
+    ///
+    /// assert_eq!("foo", Utf8TypedPathBuf::from("foo.rs").file_stem().unwrap());
+    /// assert_eq!("foo.tar", Utf8TypedPathBuf::from("foo.tar.gz").file_stem().unwrap());
     /// ```
     ///
     pub fn file_stem(&self) -> Option<&str> {
@@ -715,6 +1428,45 @@ impl Utf8TypedPathBuf {
         impl_typed_fn!(self, extension)
     }
 
+    /// Returns true if the file name of the path marks it as hidden by convention alone, without
+    /// consulting any filesystem attributes.
+    ///
+    /// See [`Utf8Path::is_hidden_by_name`] for more details.
+    ///
+    /// [`Utf8Path::is_hidden_by_name`]: crate::Utf8Path::is_hidden_by_name
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::Utf8TypedPathBuf;
+    ///
+    /// assert!(Utf8TypedPathBuf::from("/home/user/.profile").is_hidden_by_name());
+    /// assert!(Utf8TypedPathBuf::from(r"C:\Docs\~$notes.docx").is_hidden_by_name());
+    /// assert!(!Utf8TypedPathBuf::from(r"C:\Docs\notes.docx").is_hidden_by_name());
+    /// ```
+    pub fn is_hidden_by_name(&self) -> bool {
+        impl_typed_fn!(self, is_hidden_by_name)
+    }
+
+    /// Guesses the media type of the path's content from its extension, ignoring ascii case.
+    ///
+    /// See [`Utf8Path::mime_type`] for more details.
+    ///
+    /// [`Utf8Path::mime_type`]: crate::Utf8Path::mime_type
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::Utf8TypedPathBuf;
+    ///
+    /// assert_eq!(Utf8TypedPathBuf::from(r"C:\www\photo.jpeg").mime_type(), Some("image/jpeg"));
+    /// assert_eq!(Utf8TypedPathBuf::from("/www/data.tar").mime_type(), Some("application/x-tar"));
+    /// assert_eq!(Utf8TypedPathBuf::from("/www/data.unknown").mime_type(), None);
+    /// ```
+    pub fn mime_type(&self) -> Option<&'static str> {
+        impl_typed_fn!(self, mime_type)
+    }
+
     /// Returns an owned [`Utf8TypedPathBuf`] by resolving `..` and `.` segments.
     ///
     /// When multiple, sequential path segment separation characters are found (e.g. `/` for Unix
@@ -765,6 +1517,49 @@ impl Utf8TypedPathBuf {
         self.to_path().normalize()
     }
 
+    /// Returns an owned [`Utf8TypedPathBuf`] by lexically cleaning the path in the same way as Go's
+    /// `path/filepath.Clean`.
+    ///
+    /// See [`Utf8Path::clean`] for more details.
+    ///
+    /// [`Utf8Path::clean`]: crate::Utf8Path::clean
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::Utf8TypedPathBuf;
+    ///
+    /// assert_eq!(
+    ///     Utf8TypedPathBuf::from("a/../../b/./c/").clean(),
+    ///     Utf8TypedPathBuf::from("../b/c"),
+    /// );
+    /// ```
+    pub fn clean(&self) -> Utf8TypedPathBuf {
+        self.to_path().clean()
+    }
+
+    /// Returns an owned [`Utf8TypedPathBuf`] by normalizing the path using the given
+    /// [`NormalizationOptions`].
+    ///
+    /// See [`Utf8Path::normalize_with`] for more details.
+    ///
+    /// [`Utf8Path::normalize_with`]: crate::Utf8Path::normalize_with
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{NormalizationOptions, Utf8TypedPathBuf};
+    ///
+    /// let options = NormalizationOptions::new().preserve_leading_parent(true);
+    /// assert_eq!(
+    ///     Utf8TypedPathBuf::from("../foo//./bar/..").normalize_with(&options),
+    ///     Utf8TypedPathBuf::from("../foo"),
+    /// );
+    /// ```
+    pub fn normalize_with(&self, options: &NormalizationOptions) -> Utf8TypedPathBuf {
+        self.to_path().normalize_with(options)
+    }
+
     /// Converts a path to an absolute form by [`normalizing`] the path, returning a
     /// [`Utf8TypedPathBuf`].
     ///
@@ -858,6 +1653,119 @@ impl Utf8TypedPathBuf {
         self.to_path().join_checked(path)
     }
 
+    /// Creates an owned [`Utf8TypedPathBuf`] with `path` adjoined to `self`, first converting
+    /// `path` to the encoding of `self` using `policy` if the two differ.
+    ///
+    /// See [`Utf8TypedPathBuf::push_typed`] for more details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{ConversionPolicy, Utf8TypedPath, Utf8TypedPathBuf};
+    ///
+    /// let base = Utf8TypedPathBuf::from(r"C:\srv");
+    /// let other = Utf8TypedPath::unix("www/index.html");
+    /// let path = base.join_typed(other, ConversionPolicy::Lossy);
+    /// assert_eq!(path.unwrap().as_str(), r"C:\srv\www\index.html");
+    /// ```
+    pub fn join_typed(
+        &self,
+        path: Utf8TypedPath<'_>,
+        policy: ConversionPolicy,
+    ) -> Result<Utf8TypedPathBuf, CheckedPathError> {
+        let mut buf = self.clone();
+        buf.push_typed(path, policy)?;
+        Ok(buf)
+    }
+
+    /// Creates an owned [`Utf8TypedPathBuf`] with `path` adjoined to `self`, failing if `path` uses
+    /// a different encoding and cannot be converted to the encoding of `self` without loss.
+    ///
+    /// This is the same as [`Utf8TypedPathBuf::join_typed`] using [`ConversionPolicy::Checked`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{CheckedPathError, Utf8TypedPath, Utf8TypedPathBuf};
+    ///
+    /// let base = Utf8TypedPathBuf::from("/srv");
+    /// assert_eq!(
+    ///     base.try_join(Utf8TypedPath::windows(r"www\index.html")).unwrap().as_str(),
+    ///     "/srv/www/index.html",
+    /// );
+    /// assert_eq!(
+    ///     base.try_join(Utf8TypedPath::derive(r"D:\www")),
+    ///     Err(CheckedPathError::UnexpectedPrefix),
+    /// );
+    /// ```
+    pub fn try_join(&self, path: Utf8TypedPath<'_>) -> Result<Utf8TypedPathBuf, CheckedPathError> {
+        self.join_typed(path, ConversionPolicy::Checked)
+    }
+
+    /// Creates an owned [`Utf8TypedPathBuf`] with `path` adjoined to `self` using the given
+    /// [`PushMode`] to decide what happens when `path` has its own root or prefix.
+    ///
+    /// See [`Utf8TypedPathBuf::push_with_mode`] for more details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{PushMode, Utf8TypedPathBuf};
+    ///
+    /// assert_eq!(
+    ///     Utf8TypedPathBuf::from("/srv").join_with_mode("/etc", PushMode::Append),
+    ///     Ok(Utf8TypedPathBuf::from("/srv/etc")),
+    /// );
+    /// ```
+    pub fn join_with_mode(
+        &self,
+        path: impl AsRef<str>,
+        mode: PushMode,
+    ) -> Result<Utf8TypedPathBuf, CheckedPathError> {
+        self.to_path().join_with_mode(path, mode)
+    }
+
+    /// Creates an owned [`Utf8TypedPathBuf`] with `path` adjoined to `self`, never replacing `self`
+    /// even if `path` is absolute.
+    ///
+    /// See [`Utf8TypedPathBuf::push_keeping_base`] for more details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::Utf8TypedPathBuf;
+    ///
+    /// assert_eq!(
+    ///     Utf8TypedPathBuf::from("/srv").join_keeping_base("/etc"),
+    ///     Utf8TypedPathBuf::from("/srv/etc"),
+    /// );
+    /// ```
+    pub fn join_keeping_base(&self, path: impl AsRef<str>) -> Utf8TypedPathBuf {
+        self.to_path().join_keeping_base(path)
+    }
+
+    /// Creates an owned [`Utf8TypedPathBuf`] with `path` adjoined to `self`, failing if `path` has
+    /// a root or prefix.
+    ///
+    /// See [`Utf8TypedPathBuf::push_relative_only`] for more details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{CheckedPathError, Utf8TypedPathBuf};
+    ///
+    /// assert_eq!(
+    ///     Utf8TypedPathBuf::from("/srv").join_relative_only("/etc"),
+    ///     Err(CheckedPathError::UnexpectedRoot),
+    /// );
+    /// ```
+    pub fn join_relative_only(
+        &self,
+        path: impl AsRef<str>,
+    ) -> Result<Utf8TypedPathBuf, CheckedPathError> {
+        self.to_path().join_relative_only(path)
+    }
+
     /// Creates an owned [`Utf8TypedPathBuf`] like `self` but with the given file name.
     ///
     /// See [`Utf8TypedPathBuf::set_file_name`] for more details.
@@ -898,6 +1806,105 @@ impl Utf8TypedPathBuf {
         self.to_path().with_extension(extension)
     }
 
+    /// Creates an owned [`Utf8TypedPathBuf`] like `self` but with every normal component
+    /// shortened to at most `n_bytes` bytes.
+    ///
+    /// See [`Utf8Path::truncate_component_to`] for more details.
+    ///
+    /// [`Utf8Path::truncate_component_to`]: crate::Utf8Path::truncate_component_to
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::Utf8TypedPathBuf;
+    ///
+    /// let path = Utf8TypedPathBuf::from("/photos/holiday_in_spain.jpeg");
+    /// assert_eq!(path.truncate_component_to(8).as_str(), "/photos/hol.jpeg");
+    /// ```
+    pub fn truncate_component_to(&self, n_bytes: usize) -> Utf8TypedPathBuf {
+        self.to_path().truncate_component_to(n_bytes)
+    }
+
+    /// Renders `self` for display within `max_width` columns by replacing leading directories with
+    /// `…`, always keeping the prefix, root, and file name.
+    ///
+    /// See [`Utf8Path::abbreviate`] for more details.
+    ///
+    /// [`Utf8Path::abbreviate`]: crate::Utf8Path::abbreviate
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::Utf8TypedPathBuf;
+    ///
+    /// let path = Utf8TypedPathBuf::from(r"C:\Users\user\Documents\file.rs");
+    /// assert_eq!(path.abbreviate(22), r"C:\…\Documents\file.rs");
+    /// ```
+    pub fn abbreviate(&self, max_width: usize) -> String {
+        impl_typed_fn!(self, abbreviate, max_width)
+    }
+
+    /// Produces an owned key whose plain byte order matches the order of paths as trees, so that
+    /// paths can be stored in key-value stores without a custom comparator.
+    ///
+    /// See [`Utf8Path::sort_key`] for more details.
+    ///
+    /// [`Utf8Path::sort_key`]: crate::Utf8Path::sort_key
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::Utf8TypedPathBuf;
+    ///
+    /// let key = |s| Utf8TypedPathBuf::from(s).sort_key();
+    /// assert!(key("/foo") < key("/foo/bar"));
+    /// assert!(key("/foo/bar") < key("/foo-bar"));
+    /// ```
+    pub fn sort_key(&self) -> Vec<u8> {
+        impl_typed_fn!(self, sort_key)
+    }
+
+    /// Returns the bounds of the sort keys covering exactly `self` and its descendants, for prefix
+    /// scans over a key-value store ordered by sort key.
+    ///
+    /// See [`Utf8Path::range_bounds_for_subtree`] for more details.
+    ///
+    /// [`Utf8Path::range_bounds_for_subtree`]: crate::Utf8Path::range_bounds_for_subtree
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::ops::RangeBounds;
+    /// use typed_path::Utf8TypedPathBuf;
+    ///
+    /// let bounds = Utf8TypedPathBuf::from("/foo").range_bounds_for_subtree();
+    /// assert!(bounds.contains(&Utf8TypedPathBuf::from("/foo/bar").sort_key()));
+    /// assert!(!bounds.contains(&Utf8TypedPathBuf::from("/foo-bar").sort_key()));
+    /// ```
+    pub fn range_bounds_for_subtree(&self) -> (Bound<Vec<u8>>, Bound<Vec<u8>>) {
+        impl_typed_fn!(self, range_bounds_for_subtree)
+    }
+
+    /// Creates an owned [`Utf8TypedPathBuf`] like `self` but with its file name shortened such
+    /// that the entire path is at most `total_bytes` bytes.
+    ///
+    /// See [`Utf8Path::fit_within`] for more details.
+    ///
+    /// [`Utf8Path::fit_within`]: crate::Utf8Path::fit_within
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::Utf8TypedPathBuf;
+    ///
+    /// let path = Utf8TypedPathBuf::from(r"C:\notes\meeting.md");
+    /// assert_eq!(path.fit_within(15).unwrap().as_str(), r"C:\notes\mee.md");
+    /// assert_eq!(path.fit_within(9), None);
+    /// ```
+    pub fn fit_within(&self, total_bytes: usize) -> Option<Utf8TypedPathBuf> {
+        self.to_path().fit_within(total_bytes)
+    }
+
     /// Produces an iterator over the [`Utf8TypedComponent`]s of the path.
     ///
     /// When parsing the path, there is a small amount of normalization:
@@ -961,6 +1968,14 @@ impl Utf8TypedPathBuf {
     }
 }
 
+impl fmt::Debug for Utf8TypedPathBuf {
+    /// Writes the encoding of the path along with its contents, the same as [`Utf8TypedPath`]
+    /// does.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.to_path(), f)
+    }
+}
+
 impl fmt::Display for Utf8TypedPathBuf {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
@@ -1023,6 +2038,41 @@ impl From<String> for Utf8TypedPathBuf {
     }
 }
 
+impl From<Utf8TypedPathBuf> for String {
+    /// Converts a [`Utf8TypedPathBuf`] into a [`String`]
+    ///
+    /// This conversion does not allocate or copy memory.
+    #[inline]
+    fn from(path_buf: Utf8TypedPathBuf) -> Self {
+        path_buf.into_string()
+    }
+}
+
+impl From<Utf8TypedPathBuf> for Vec<u8> {
+    /// Converts a [`Utf8TypedPathBuf`] into a [`Vec<u8>`]
+    ///
+    /// This conversion does not allocate or copy memory.
+    #[inline]
+    fn from(path_buf: Utf8TypedPathBuf) -> Self {
+        path_buf.into_bytes()
+    }
+}
+
+impl TryFrom<TypedPathBuf> for Utf8TypedPathBuf {
+    type Error = Utf8PathError;
+
+    fn try_from(path: TypedPathBuf) -> Result<Self, Self::Error> {
+        match path {
+            TypedPathBuf::Unix(path) => {
+                Utf8UnixPathBuf::from_bytes_path_buf(path).map(Utf8TypedPathBuf::Unix)
+            }
+            TypedPathBuf::Windows(path) => {
+                Utf8WindowsPathBuf::from_bytes_path_buf(path).map(Utf8TypedPathBuf::Windows)
+            }
+        }
+    }
+}
+
 impl TryFrom<Utf8TypedPathBuf> for Utf8UnixPathBuf {
     type Error = Utf8TypedPathBuf;
 
@@ -1084,8 +2134,10 @@ impl<'a> PartialEq<&'a str> for Utf8TypedPathBuf {
     }
 }
 
-impl<'a> PartialEq<Utf8TypedPathBuf> for &'a str {
+impl PartialEq<Utf8TypedPathBuf> for &str {
     fn eq(&self, path: &Utf8TypedPathBuf) -> bool {
         *self == path.as_str()
     }
 }
+
+impl_typed_cmp!(; Utf8TypedPathBuf, String, str, as_str);
@@ -0,0 +1,46 @@
+use crate::common::{varint, WireFormatError};
+use crate::no_std_compat::*;
+use crate::typed::PathType;
+
+/// Current version of the wire format, written as the first byte
+pub(crate) const VERSION: u8 = 1;
+
+const UNIX_TAG: u8 = 0;
+const WINDOWS_TAG: u8 = 1;
+
+/// Encodes `bytes` of a path of the given type as
+///
+/// ```text
+/// [version: u8] [encoding: u8] [length: LEB128] [bytes: length]
+/// ```
+pub(crate) fn encode(r#type: PathType, bytes: &[u8]) -> Vec<u8> {
+    let mut out = Vec::with_capacity(bytes.len() + 4);
+    out.push(VERSION);
+    out.push(match r#type {
+        PathType::Unix => UNIX_TAG,
+        PathType::Windows => WINDOWS_TAG,
+    });
+    varint::write(bytes.len(), &mut out);
+    out.extend_from_slice(bytes);
+    out
+}
+
+/// Decodes a single path from the front of `input`, returning its type, its bytes, and the
+/// remaining input
+pub(crate) fn decode(input: &[u8]) -> Result<(PathType, &[u8], &[u8]), WireFormatError> {
+    let (&version, input) = input.split_first().ok_or(WireFormatError::UnexpectedEnd)?;
+    if version != VERSION {
+        return Err(WireFormatError::UnsupportedVersion(version));
+    }
+
+    let (&tag, input) = input.split_first().ok_or(WireFormatError::UnexpectedEnd)?;
+    let r#type = match tag {
+        UNIX_TAG => PathType::Unix,
+        WINDOWS_TAG => PathType::Windows,
+        x => return Err(WireFormatError::UnknownEncoding(x)),
+    };
+
+    let (len, input) = varint::read(input)?;
+    let (bytes, rest) = varint::read_bytes(input, len)?;
+    Ok((r#type, bytes, rest))
+}
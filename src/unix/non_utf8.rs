@@ -18,6 +18,31 @@ pub type UnixPath = Path<UnixEncoding>;
 pub type UnixPathBuf = PathBuf<UnixEncoding>;
 
 /// Represents a Unix-specific [`Encoding`]
+///
+/// Paths are always separated by `/` no matter the platform running the code, which makes this
+/// encoding suitable for remote POSIX paths such as those used by SFTP and FTP. An absolute path
+/// starts at the root of the remote host, while a relative path is resolved by the server against
+/// the login directory. [`FilesystemProfile::remote_posix`] checks that names only use printable
+/// ascii characters for protocols that require it.
+///
+/// A `\` is part of a name rather than a separator, so paths built on Windows should be converted
+/// with [`Path::with_unix_encoding`] instead of being joined as-is.
+///
+/// [`FilesystemProfile::remote_posix`]: crate::FilesystemProfile::remote_posix
+///
+/// # Examples
+///
+/// ```
+/// use typed_path::{FilesystemProfile, UnixPath, WindowsPath};
+///
+/// let login_dir = UnixPath::new("/home/me");
+/// let local = WindowsPath::new(r"docs\report.txt");
+///
+/// let remote = local.with_unix_encoding();
+/// assert!(remote.is_relative());
+/// assert_eq!(login_dir.join(&remote), UnixPath::new("/home/me/docs/report.txt"));
+/// assert!(remote.is_valid_for(&FilesystemProfile::remote_posix()));
+/// ```
 #[derive(Copy, Clone)]
 pub struct UnixEncoding;
 
@@ -26,6 +51,12 @@ impl private::Sealed for UnixEncoding {}
 impl<'a> Encoding<'a> for UnixEncoding {
     type Components = UnixComponents<'a>;
 
+    const NAME: &'static str = "unix";
+    const SEPARATOR: char = SEPARATOR;
+    const ALT_SEPARATORS: &'static [char] = &[];
+    const CASE_SENSITIVE: bool = true;
+    const DISALLOWED_FILENAME_BYTES: &'static [u8] = &DISALLOWED_FILENAME_BYTES;
+
     fn label() -> &'static str {
         "unix"
     }
@@ -166,6 +197,18 @@ where
 }
 
 impl UnixPath {
+    /// The root directory `/`, which every absolute path starts with.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::UnixPath;
+    ///
+    /// assert_eq!(UnixPath::ROOT, UnixPath::new("/"));
+    /// assert_eq!(UnixPath::ROOT.join("etc"), UnixPath::new("/etc"));
+    /// ```
+    pub const ROOT: &'static UnixPath = Path::from_bytes_const(SEPARATOR_STR.as_bytes());
+
     pub fn to_typed_path(&self) -> TypedPath {
         TypedPath::unix(self)
     }
@@ -173,6 +216,29 @@ impl UnixPath {
     pub fn to_typed_path_buf(&self) -> TypedPathBuf {
         TypedPathBuf::from_unix(self)
     }
+
+    /// Returns true if the [`file_name`] of the path starts with `.`, which hides it from
+    /// directory listings by convention.
+    ///
+    /// See [`Path::is_hidden_by_name`] for a check that also understands Windows conventions.
+    ///
+    /// [`file_name`]: Path::file_name
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::UnixPath;
+    ///
+    /// assert!(UnixPath::new("/home/user/.bashrc").is_dotfile());
+    /// assert!(UnixPath::new("/home/user/.config/").is_dotfile());
+    /// assert!(!UnixPath::new("/home/.user/notes.txt").is_dotfile());
+    /// assert!(!UnixPath::new("/home/user/..").is_dotfile());
+    /// ```
+    pub fn is_dotfile(&self) -> bool {
+        self.file_name()
+            .map(|name| name.starts_with(b"."))
+            .unwrap_or(false)
+    }
 }
 
 #[cfg(test)]
@@ -324,4 +390,66 @@ mod tests {
         );
         assert_eq!(current_path, b"/some/path/abc/../def/.");
     }
+
+    #[test]
+    fn suggest_nearest_should_prefer_the_first_of_equally_close_candidates() {
+        let path = UnixPath::new(b"/src/ma\xffn.rs");
+        let candidates = [
+            UnixPath::new(b"/src/mazn.rs"),
+            UnixPath::new(b"/src/main.rs"),
+            UnixPath::new(b"/src/ma\xffn.rs/x"),
+        ];
+        assert_eq!(path.suggest_nearest(candidates), Some(candidates[0]));
+        assert_eq!(
+            path.edit_distance(candidates[2], &crate::SuggestOptions::new()),
+            3
+        );
+
+        let options = crate::SuggestOptions::new().component(0).substitute(5);
+        assert_eq!(
+            path.suggest_nearest_with(candidates, &options),
+            Some(candidates[2])
+        );
+    }
+
+    #[test]
+    fn insert_component_should_only_insert_the_relative_part_of_a_path() {
+        let mut path = UnixPathBuf::from("a");
+        path.insert_component(0, "/x");
+        assert_eq!(path.as_bytes(), b"x/a");
+        assert!(path.is_relative());
+
+        let mut path = UnixPathBuf::from("/a");
+        path.insert_component(1, "//x/y");
+        assert_eq!(path.as_bytes(), b"/x/y/a");
+
+        path.insert_component(4, "/");
+        assert_eq!(path.as_bytes(), b"/x/y/a");
+    }
+
+    #[test]
+    #[should_panic(expected = "falls on the prefix or root")]
+    fn insert_component_should_panic_if_index_falls_on_the_root() {
+        UnixPathBuf::from("/a").insert_component(0, "x");
+    }
+
+    #[test]
+    fn normalize_with_should_keep_current_dirs_before_popped_components() {
+        let options = crate::NormalizationOptions::new().remove_current(false);
+        let normalize = |path: &str| UnixPath::new(path).normalize_with(&options);
+
+        assert_eq!(normalize("./a/..").as_bytes(), b".");
+        assert_eq!(normalize("a/./b/..").as_bytes(), b"a/.");
+        assert_eq!(normalize("a/./b/../").as_bytes(), b"a/.");
+        assert_eq!(normalize("a/b/./..").as_bytes(), b"a/.");
+        assert_eq!(normalize("a/./b/../c").as_bytes(), b"a/./c");
+        assert_eq!(normalize("a//b/..").as_bytes(), b"a");
+
+        let options = options.collapse_separators(false);
+        let normalize = |path: &str| UnixPath::new(path).normalize_with(&options);
+
+        assert_eq!(normalize("./a/..").as_bytes(), b".");
+        assert_eq!(normalize("a/./b/..").as_bytes(), b"a/.");
+        assert_eq!(normalize("a/b/./..").as_bytes(), b"a/.");
+    }
 }
@@ -6,6 +6,7 @@ use core::{cmp, fmt, iter};
 pub use component::*;
 use parser::Parser;
 
+use crate::common::remaining_path;
 use crate::{private, Components, Encoding, Path};
 
 #[derive(Clone)]
@@ -22,6 +23,9 @@ impl<'a> UnixComponents<'a> {
 
     /// Extracts a slice corresponding to the portion of the path remaining for iteration.
     ///
+    /// As with `std::path::Components::as_path`, trailing separators and current directory
+    /// components that will not be yielded are left out.
+    ///
     /// # Examples
     ///
     /// ```
@@ -38,7 +42,7 @@ impl<'a> UnixComponents<'a> {
     where
         T: for<'enc> Encoding<'enc>,
     {
-        Path::new(self.parser.remaining())
+        Path::new(remaining_path(self))
     }
 }
 
@@ -1,6 +1,7 @@
 use crate::common::parser::*;
 use crate::unix::constants::{CURRENT_DIR, PARENT_DIR, SEPARATOR};
 use crate::unix::UnixComponent;
+use crate::Component;
 
 /// Parser to get [`UnixComponent`]s
 ///
@@ -57,6 +58,7 @@ impl<'a> Parser<'a> {
     /// Parses next component, advancing an internal input pointer past the component
     pub fn next_front(&mut self) -> Result<UnixComponent<'a>, ParseError> {
         let (input, component) = parse_front(self.state)(self.input)?;
+        debug_assert_consumed(self.input, input, component.len(), true);
         self.input = input;
         self.state = State::NotAtBeginning;
         Ok(component)
@@ -66,6 +68,7 @@ impl<'a> Parser<'a> {
     /// back of the input instead of the front
     pub fn next_back(&mut self) -> Result<UnixComponent<'a>, ParseError> {
         let (input, component) = parse_back(self.state)(self.input)?;
+        debug_assert_consumed(self.input, input, component.len(), false);
         self.input = input;
         Ok(component)
     }
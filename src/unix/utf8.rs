@@ -25,6 +25,13 @@ impl private::Sealed for Utf8UnixEncoding {}
 impl<'a> Utf8Encoding<'a> for Utf8UnixEncoding {
     type Components = Utf8UnixComponents<'a>;
 
+    const NAME: &'static str = <UnixEncoding as Encoding<'a>>::NAME;
+    const SEPARATOR: char = <UnixEncoding as Encoding<'a>>::SEPARATOR;
+    const ALT_SEPARATORS: &'static [char] = <UnixEncoding as Encoding<'a>>::ALT_SEPARATORS;
+    const CASE_SENSITIVE: bool = <UnixEncoding as Encoding<'a>>::CASE_SENSITIVE;
+    const DISALLOWED_FILENAME_BYTES: &'static [u8] =
+        <UnixEncoding as Encoding<'a>>::DISALLOWED_FILENAME_BYTES;
+
     fn label() -> &'static str {
         "unix"
     }
@@ -97,6 +104,19 @@ where
 }
 
 impl Utf8UnixPath {
+    /// The root directory `/`, which every absolute path starts with.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::Utf8UnixPath;
+    ///
+    /// assert_eq!(Utf8UnixPath::ROOT, Utf8UnixPath::new("/"));
+    /// assert_eq!(Utf8UnixPath::ROOT.join("etc"), Utf8UnixPath::new("/etc"));
+    /// ```
+    pub const ROOT: &'static Utf8UnixPath =
+        Utf8Path::from_str_const(super::constants::SEPARATOR_STR);
+
     pub fn to_typed_path(&self) -> Utf8TypedPath {
         Utf8TypedPath::unix(self)
     }
@@ -104,12 +124,61 @@ impl Utf8UnixPath {
     pub fn to_typed_path_buf(&self) -> Utf8TypedPathBuf {
         Utf8TypedPathBuf::from_unix(self)
     }
+
+    /// Returns true if the [`file_name`] of the path starts with `.`, which hides it from
+    /// directory listings by convention.
+    ///
+    /// See [`UnixPath::is_dotfile`] for more details.
+    ///
+    /// [`file_name`]: Utf8Path::file_name
+    /// [`UnixPath::is_dotfile`]: crate::UnixPath::is_dotfile
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::Utf8UnixPath;
+    ///
+    /// assert!(Utf8UnixPath::new("/srv/app/.env").is_dotfile());
+    /// assert!(!Utf8UnixPath::new("/srv/.app/main.rs").is_dotfile());
+    /// ```
+    pub fn is_dotfile(&self) -> bool {
+        self.file_name()
+            .map(|name| name.starts_with('.'))
+            .unwrap_or(false)
+    }
 }
 
+impl_cmp_utf8_bytes!(Utf8UnixEncoding, UnixEncoding);
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn utf8_paths_should_equal_byte_paths_with_the_same_bytes() {
+        use crate::UnixPath;
+
+        let path = Utf8UnixPath::new("/some/path");
+        let buf = path.to_path_buf();
+        let bytes = UnixPath::new("/some/path");
+        let bytes_buf = bytes.to_path_buf();
+
+        assert_eq!(*path, *bytes);
+        assert_eq!(*bytes, *path);
+        assert_eq!(path, bytes_buf);
+        assert_eq!(bytes_buf, path);
+        assert_eq!(buf, *bytes);
+        assert_eq!(*bytes, buf);
+        assert_eq!(buf, bytes);
+        assert_eq!(bytes, buf);
+        assert_eq!(buf, bytes_buf);
+        assert_eq!(bytes_buf, buf);
+
+        // Comparison is by bytes, so equivalent paths with different bytes are not equal
+        assert_ne!(*path, *UnixPath::new("/some//path"));
+        assert_ne!(buf, UnixPath::new("/some/path/"));
+    }
+
     #[test]
     fn push_should_replace_current_path_with_provided_path_if_provided_path_is_absolute() {
         // Empty current path will just become the provided path
@@ -255,4 +324,18 @@ mod tests {
         );
         assert_eq!(current_path, "/some/path/abc/../def/.");
     }
+
+    #[test]
+    fn insert_component_should_only_insert_the_relative_part_of_a_path() {
+        let mut path = Utf8UnixPathBuf::from("a");
+        path.insert_component(1, "/x");
+        assert_eq!(path.as_str(), "a/x");
+        assert!(path.is_relative());
+    }
+
+    #[test]
+    #[should_panic(expected = "falls on the prefix or root")]
+    fn insert_component_should_panic_if_index_falls_on_the_root() {
+        Utf8UnixPathBuf::from("/a").insert_component(0, "x");
+    }
 }
@@ -4,6 +4,7 @@ use core::{cmp, fmt, iter};
 
 pub use component::*;
 
+use crate::common::remaining_str;
 use crate::unix::UnixComponents;
 use crate::{private, Components, Utf8Components, Utf8Encoding, Utf8Path};
 
@@ -21,6 +22,9 @@ impl<'a> Utf8UnixComponents<'a> {
 
     /// Extracts a slice corresponding to the portion of the path remaining for iteration.
     ///
+    /// As with `std::path::Components::as_path`, trailing separators and current directory
+    /// components that will not be yielded are left out.
+    ///
     /// # Examples
     ///
     /// ```
@@ -37,7 +41,7 @@ impl<'a> Utf8UnixComponents<'a> {
     where
         T: for<'enc> Utf8Encoding<'enc>,
     {
-        Utf8Path::new(self.as_str())
+        Utf8Path::new(remaining_str(self))
     }
 }
 
@@ -81,7 +85,7 @@ where
 {
     #[inline]
     fn as_ref(&self) -> &Utf8Path<T> {
-        Utf8Path::new(self.as_str())
+        Utf8Path::new(remaining_str(self))
     }
 }
 
@@ -2,6 +2,8 @@ use std::convert::TryFrom;
 use std::{env, io};
 
 use crate::{NativePathBuf, Utf8NativePathBuf};
+#[cfg(any(unix, windows))]
+use crate::{TypedPath, TypedPathBuf};
 
 /// Returns the current working directory as [`NativePathBuf`].
 ///
@@ -63,6 +65,77 @@ pub fn utf8_current_dir() -> io::Result<Utf8NativePathBuf> {
     }
 }
 
+/// Returns the current working directory as [`TypedPathBuf`], tagged with the native encoding.
+///
+/// Unlike [`current_dir`], this never fails because of the encoding of the directory, as the path
+/// is converted with [`TypedPathBuf::from_native`].
+///
+/// # Errors
+///
+/// Returns an [`Err`] if the current working directory value is invalid.
+///
+/// Possible cases:
+///
+/// * Current directory does not exist.
+/// * There are insufficient permissions to access the current directory.
+///
+/// # Examples
+///
+/// ```
+/// fn main() -> std::io::Result<()> {
+///     let path = typed_path::utils::typed_current_dir()?;
+///     assert!(path.is_absolute());
+///     Ok(())
+/// }
+/// ```
+#[cfg(any(unix, windows))]
+pub fn typed_current_dir() -> io::Result<TypedPathBuf> {
+    Ok(TypedPathBuf::from_native(env::current_dir()?))
+}
+
+/// Returns `path` as an absolute, normalized path by joining it onto the current working
+/// directory when it is relative, as given by [`typed_current_dir`].
+///
+/// The current working directory is converted to the encoding of `path`, so a relative Unix path
+/// is resolved against a Unix form of the directory even on Windows. Nothing besides the current
+/// working directory is read from the environment, so symlinks are not resolved.
+///
+/// # Errors
+///
+/// Returns an [`Err`] if `path` is relative and the current working directory value is invalid.
+///
+/// # Examples
+///
+/// ```
+/// use typed_path::{utils, TypedPath};
+///
+/// fn main() -> std::io::Result<()> {
+///     // With an absolute path, it is just normalized
+///     let path = utils::absolutize_from_cwd(&TypedPath::derive("/a/b/../c"))?;
+///     assert_eq!(path, TypedPath::derive("/a/c"));
+///
+///     // With a relative path, it is first joined with the current working directory
+///     let cwd = utils::typed_current_dir()?.with_unix_encoding();
+///     let path = utils::absolutize_from_cwd(&TypedPath::unix("a/./b"))?;
+///     assert_eq!(path, cwd.join("a/b"));
+///     Ok(())
+/// }
+/// ```
+#[cfg(any(unix, windows))]
+pub fn absolutize_from_cwd(path: &TypedPath<'_>) -> io::Result<TypedPathBuf> {
+    if path.is_absolute() {
+        return Ok(path.normalize());
+    }
+
+    let cwd = typed_current_dir()?;
+    let cwd = match path {
+        TypedPath::Unix(_) => cwd.with_unix_encoding(),
+        TypedPath::Windows(_) => cwd.with_windows_encoding(),
+    };
+
+    Ok(cwd.join(path.as_bytes()).normalize())
+}
+
 /// Returns the full filesystem path of the current running executable as [`NativePathBuf`].
 ///
 /// # Errors
@@ -22,6 +22,14 @@ pub const CURRENT_DIR: &[u8] = b".";
 /// Path component value that represents the current directory
 pub const CURRENT_DIR_STR: &str = ".";
 
+/// Length in UTF-16 code units, including the terminating NUL, of the longest path that Win32
+/// functions accept without a verbatim (`\\?\`) prefix
+pub const MAX_PATH: usize = 260;
+
+/// Length in UTF-16 code units, including the terminating NUL, of the longest path that Win32
+/// functions accept with a verbatim (`\\?\`) prefix
+pub const MAX_VERBATIM_PATH: usize = 32767;
+
 /// Reserved names (case insensitive) that cannot be used with files or directories
 /// for personal use (system only)
 pub const RESERVED_DEVICE_NAMES: &[&[u8]] = &[
@@ -6,7 +6,8 @@ use core::{cmp, fmt, iter};
 pub use component::*;
 use parser::Parser;
 
-use crate::{private, Components, Encoding, Path};
+use crate::common::remaining_path;
+use crate::{private, Components, Encoding, Path, VerbatimMode};
 
 /// Represents a Windows-specific [`Components`]
 #[derive(Clone)]
@@ -21,8 +22,17 @@ impl<'a> WindowsComponents<'a> {
         }
     }
 
+    pub(crate) fn with_verbatim_mode(path: &'a [u8], mode: VerbatimMode) -> Self {
+        Self {
+            parser: Parser::with_verbatim_mode(path, mode),
+        }
+    }
+
     /// Extracts a slice corresponding to the portion of the path remaining for iteration.
     ///
+    /// As with `std::path::Components::as_path`, trailing separators and current directory
+    /// components that will not be yielded are left out.
+    ///
     /// # Examples
     ///
     /// ```
@@ -39,7 +49,7 @@ impl<'a> WindowsComponents<'a> {
     where
         T: for<'enc> Encoding<'enc>,
     {
-        Path::new(self.parser.remaining())
+        Path::new(remaining_path(self))
     }
 }
 
@@ -127,7 +137,11 @@ impl<'a> WindowsComponents<'a> {
     pub fn has_any_verbatim_prefix(&self) -> bool {
         matches!(
             self.prefix_kind(),
-            Some(WindowsPrefix::Verbatim(_) | WindowsPrefix::UNC(..) | WindowsPrefix::Disk(_))
+            Some(
+                WindowsPrefix::Verbatim(_)
+                    | WindowsPrefix::VerbatimUNC(..)
+                    | WindowsPrefix::VerbatimDisk(_)
+            )
         )
     }
 
@@ -2,7 +2,7 @@ use core::cmp;
 use core::convert::TryFrom;
 use core::hash::{Hash, Hasher};
 
-use crate::windows::WindowsComponents;
+use crate::windows::{UncHost, WindowsComponents};
 use crate::ParseError;
 
 /// A structure wrapping a Windows path prefix as well as its unparsed string
@@ -347,4 +347,32 @@ impl<'a> WindowsPrefix<'a> {
         use self::WindowsPrefix::*;
         matches!(*self, Verbatim(_) | VerbatimDisk(_) | VerbatimUNC(..))
     }
+
+    /// Returns the server of a UNC or verbatim UNC prefix as a [`UncHost`], decoding IPv4
+    /// addresses and IPv6 addresses written as `ipv6-literal.net` names, or [`None`] for any
+    /// other prefix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{UncHost, WindowsPath};
+    ///
+    /// let path = WindowsPath::new(r"\\2001-db8--1.ipv6-literal.net\logs\app.log");
+    /// assert_eq!(
+    ///     path.components().prefix_kind().and_then(|prefix| prefix.host()),
+    ///     Some(UncHost::Ipv6 {
+    ///         segments: [0x2001, 0xdb8, 0, 0, 0, 0, 0, 1],
+    ///         scope_id: None,
+    ///     }),
+    /// );
+    ///
+    /// let path = WindowsPath::new(r"C:\logs\app.log");
+    /// assert_eq!(path.components().prefix_kind().and_then(|prefix| prefix.host()), None);
+    /// ```
+    pub fn host(&self) -> Option<UncHost<'a>> {
+        match *self {
+            Self::UNC(server, _) | Self::VerbatimUNC(server, _) => Some(UncHost::parse(server)),
+            _ => None,
+        }
+    }
 }
@@ -1,6 +1,7 @@
 use crate::common::parser::*;
 use crate::windows::constants::{ALT_SEPARATOR, CURRENT_DIR, PARENT_DIR, SEPARATOR};
 use crate::windows::{WindowsComponent, WindowsPrefix, WindowsPrefixComponent};
+use crate::{Component, VerbatimMode};
 
 /// Parse input to get [`WindowsComponents`]
 ///
@@ -60,9 +61,15 @@ impl State {
 impl<'a> Parser<'a> {
     /// Create a new parser for the given `input`
     pub fn new(input: &'a [u8]) -> Self {
+        Self::with_verbatim_mode(input, VerbatimMode::Faithful)
+    }
+
+    /// Create a new parser for the given `input`, reading any verbatim path according to `mode`
+    pub fn with_verbatim_mode(input: &'a [u8], mode: VerbatimMode) -> Self {
         // Before the parser can operate, it needs to know if it should normalize the path. This
-        // happens in all cases EXCEPT when the path starts with exactly \\?\
-        let normalize = !input.starts_with(br"\\?\");
+        // happens in all cases EXCEPT when the path starts with exactly \\?\ and is read
+        // faithfully
+        let normalize = mode == VerbatimMode::Normal || !input.starts_with(br"\\?\");
 
         // NOTE: Usage of `maybe` guarantees that we will never have an error
         let (_, prefix) = maybe(prefix_component)(input).unwrap();
@@ -92,6 +99,7 @@ impl<'a> Parser<'a> {
 
         // Otherwise, parse our input like usual
         let (input, component) = parse_front(self.state, self.normalize)(self.input)?;
+        debug_assert_consumed(self.input, input, component.len(), true);
         self.input = input;
         self.state = State::NotAtBeginning;
         Ok(component)
@@ -106,7 +114,9 @@ impl<'a> Parser<'a> {
         let prefix_len = self.prefix_len();
 
         if !input.is_empty() {
-            let (input, component) = parse_back(self.state, self.normalize)(input)?;
+            let (rest, component) = parse_back(self.state, self.normalize)(input)?;
+            debug_assert_consumed(input, rest, component.len(), false);
+            let input = rest;
 
             // NOTE: The updated input does not include the prefix, so we need to adjust our update
             //       to factor in the prefix len
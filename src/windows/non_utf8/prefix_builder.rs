@@ -0,0 +1,301 @@
+use crate::common::WindowsPrefixError;
+use crate::no_std_compat::*;
+use crate::windows::constants::*;
+use crate::{WindowsComponents, WindowsPathBuf, WindowsPrefix};
+
+/// Builds a [`WindowsPathBuf`] holding only a prefix, and by default a root directory, from its
+/// parts, validating each part so that the result parses back into the requested prefix.
+///
+/// # Examples
+///
+/// ```
+/// use typed_path::{WindowsPathBuf, WindowsPrefixBuilder, WindowsPrefixError};
+///
+/// assert_eq!(
+///     WindowsPrefixBuilder::disk('c').build(),
+///     Ok(WindowsPathBuf::from(r"C:\")),
+/// );
+///
+/// assert_eq!(
+///     WindowsPrefixBuilder::unc("server", "share").verbatim(true).build(),
+///     Ok(WindowsPathBuf::from(r"\\?\UNC\server\share\")),
+/// );
+///
+/// assert_eq!(
+///     WindowsPrefixBuilder::device_ns("COM1").root(false).build(),
+///     Ok(WindowsPathBuf::from(r"\\.\COM1")),
+/// );
+///
+/// assert_eq!(
+///     WindowsPrefixBuilder::unc("server", "").build(),
+///     Err(WindowsPrefixError::EmptyShare),
+/// );
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct WindowsPrefixBuilder<'a> {
+    parts: PrefixParts<'a>,
+    verbatim: bool,
+    root: bool,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum PrefixParts<'a> {
+    Disk(char),
+    Unc(&'a [u8], &'a [u8]),
+    DeviceNS(&'a [u8]),
+    Verbatim(&'a [u8]),
+}
+
+impl<'a> WindowsPrefixBuilder<'a> {
+    const fn new(parts: PrefixParts<'a>) -> Self {
+        Self {
+            parts,
+            verbatim: false,
+            root: true,
+        }
+    }
+
+    /// Starts a disk prefix like `C:`, where `drive` must be an ascii letter and is uppercased.
+    pub const fn disk(drive: char) -> Self {
+        Self::new(PrefixParts::Disk(drive))
+    }
+
+    /// Starts a UNC prefix like `\\server\share`, where neither part may be empty.
+    pub fn unc<S: AsRef<[u8]> + ?Sized, H: AsRef<[u8]> + ?Sized>(
+        server: &'a S,
+        share: &'a H,
+    ) -> Self {
+        Self::new(PrefixParts::Unc(server.as_ref(), share.as_ref()))
+    }
+
+    /// Starts a device namespace prefix like `\\.\COM1`.
+    pub fn device_ns<S: AsRef<[u8]> + ?Sized>(name: &'a S) -> Self {
+        Self::new(PrefixParts::DeviceNS(name.as_ref()))
+    }
+
+    /// Starts a verbatim prefix like `\\?\Volume{...}`, which is always verbatim.
+    pub fn verbatim_name<S: AsRef<[u8]> + ?Sized>(name: &'a S) -> Self {
+        Self::new(PrefixParts::Verbatim(name.as_ref()))
+    }
+
+    /// Turns a disk prefix into a verbatim disk prefix (`\\?\C:`) and a UNC prefix into a
+    /// verbatim UNC prefix (`\\?\UNC\server\share`). Has no effect on other prefixes.
+    pub fn verbatim(mut self, yes: bool) -> Self {
+        self.verbatim = yes;
+        self
+    }
+
+    /// Whether to follow the prefix with a root directory, which is the default.
+    pub fn root(mut self, yes: bool) -> Self {
+        self.root = yes;
+        self
+    }
+
+    /// Returns the bytes of the prefix, followed by a separator if a root was requested.
+    pub(crate) fn build_bytes(&self) -> Result<Vec<u8>, WindowsPrefixError> {
+        let mut bytes = Vec::new();
+        let expected = match self.parts {
+            PrefixParts::Disk(drive) => {
+                if !drive.is_ascii_alphabetic() {
+                    return Err(WindowsPrefixError::InvalidDrive(drive));
+                }
+
+                let letter = drive.to_ascii_uppercase() as u8;
+                if self.verbatim {
+                    bytes.extend_from_slice(br"\\?\");
+                }
+                bytes.extend_from_slice(&[letter, b':']);
+
+                if self.verbatim {
+                    WindowsPrefix::VerbatimDisk(letter)
+                } else {
+                    WindowsPrefix::Disk(letter)
+                }
+            }
+            PrefixParts::Unc(server, share) => {
+                if server.is_empty() {
+                    return Err(WindowsPrefixError::EmptyServer);
+                } else if share.is_empty() {
+                    return Err(WindowsPrefixError::EmptyShare);
+                }
+                check_bytes(server, DISALLOWED_FILENAME_BYTES)?;
+                check_bytes(share, DISALLOWED_FILENAME_BYTES)?;
+
+                bytes.extend_from_slice(if self.verbatim { br"\\?\UNC\" } else { br"\\" });
+                bytes.extend_from_slice(server);
+                bytes.push(SEPARATOR as u8);
+                bytes.extend_from_slice(share);
+
+                if self.verbatim {
+                    WindowsPrefix::VerbatimUNC(server, share)
+                } else {
+                    WindowsPrefix::UNC(server, share)
+                }
+            }
+            PrefixParts::DeviceNS(name) | PrefixParts::Verbatim(name) => {
+                if name.is_empty() {
+                    return Err(WindowsPrefixError::EmptyName);
+                }
+
+                // Names like the volume `\\.\C:` are fine, so only separators and wildcards
+                // are rejected here
+                check_bytes(name, DISALLOWED_NAME_BYTES)?;
+
+                if let PrefixParts::DeviceNS(_) = self.parts {
+                    bytes.extend_from_slice(br"\\.\");
+                    bytes.extend_from_slice(name);
+                    WindowsPrefix::DeviceNS(name)
+                } else {
+                    bytes.extend_from_slice(br"\\?\");
+                    bytes.extend_from_slice(name);
+                    WindowsPrefix::Verbatim(name)
+                }
+            }
+        };
+
+        // Names like `C:` after `\\?\` would be read back as a different prefix, as would `UNC`
+        // once anything is pushed after it
+        if WindowsComponents::new(&bytes).prefix_kind() != Some(expected)
+            || self.parts == PrefixParts::Verbatim(b"UNC")
+        {
+            return Err(WindowsPrefixError::Ambiguous);
+        }
+
+        if self.root {
+            bytes.push(SEPARATOR as u8);
+        }
+
+        Ok(bytes)
+    }
+
+    /// Validates the parts and builds the path.
+    ///
+    /// # Errors
+    ///
+    /// * [`WindowsPrefixError::InvalidDrive`] if the drive is not an ascii letter
+    /// * [`WindowsPrefixError::EmptyServer`], [`WindowsPrefixError::EmptyShare`], or
+    ///   [`WindowsPrefixError::EmptyName`] if a required part is empty
+    /// * [`WindowsPrefixError::InvalidByte`] if a part contains a separator or another byte
+    ///   that cannot appear in it
+    /// * [`WindowsPrefixError::Ambiguous`] if the result would parse as a different prefix,
+    ///   such as the verbatim name `UNC`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{WindowsPathBuf, WindowsPrefixBuilder, WindowsPrefixError};
+    ///
+    /// assert_eq!(
+    ///     WindowsPrefixBuilder::disk('d').verbatim(true).root(false).build(),
+    ///     Ok(WindowsPathBuf::from(r"\\?\D:")),
+    /// );
+    ///
+    /// assert_eq!(
+    ///     WindowsPrefixBuilder::disk('1').build(),
+    ///     Err(WindowsPrefixError::InvalidDrive('1')),
+    /// );
+    /// assert_eq!(
+    ///     WindowsPrefixBuilder::unc(r"a\b", "share").build(),
+    ///     Err(WindowsPrefixError::InvalidByte(b'\\')),
+    /// );
+    /// assert_eq!(
+    ///     WindowsPrefixBuilder::verbatim_name("C:").build(),
+    ///     Err(WindowsPrefixError::Ambiguous),
+    /// );
+    /// ```
+    ///
+    /// [`WindowsPrefixError::InvalidDrive`]: crate::WindowsPrefixError::InvalidDrive
+    /// [`WindowsPrefixError::EmptyServer`]: crate::WindowsPrefixError::EmptyServer
+    /// [`WindowsPrefixError::EmptyShare`]: crate::WindowsPrefixError::EmptyShare
+    /// [`WindowsPrefixError::EmptyName`]: crate::WindowsPrefixError::EmptyName
+    /// [`WindowsPrefixError::InvalidByte`]: crate::WindowsPrefixError::InvalidByte
+    /// [`WindowsPrefixError::Ambiguous`]: crate::WindowsPrefixError::Ambiguous
+    pub fn build(&self) -> Result<WindowsPathBuf, WindowsPrefixError> {
+        self.build_bytes().map(WindowsPathBuf::from)
+    }
+}
+
+/// Bytes that are not allowed in a device or verbatim name, which unlike filenames may hold `:`
+const DISALLOWED_NAME_BYTES: &[u8] = b"\\/?*\"><|\0";
+
+fn check_bytes(part: &[u8], disallowed: &[u8]) -> Result<(), WindowsPrefixError> {
+    match part.iter().find(|b| disallowed.contains(b)) {
+        Some(b) => Err(WindowsPrefixError::InvalidByte(*b)),
+        None => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{WindowsComponent, WindowsPath};
+
+    #[test]
+    fn build_should_parse_back_into_the_requested_prefix() {
+        let cases = [
+            (WindowsPrefixBuilder::disk('z'), WindowsPrefix::Disk(b'Z')),
+            (
+                WindowsPrefixBuilder::disk('z').verbatim(true),
+                WindowsPrefix::VerbatimDisk(b'Z'),
+            ),
+            (
+                WindowsPrefixBuilder::unc("srv", "pub"),
+                WindowsPrefix::UNC(b"srv", b"pub"),
+            ),
+            (
+                WindowsPrefixBuilder::unc("srv", "pub").verbatim(true),
+                WindowsPrefix::VerbatimUNC(b"srv", b"pub"),
+            ),
+            (
+                WindowsPrefixBuilder::device_ns("C:"),
+                WindowsPrefix::DeviceNS(b"C:"),
+            ),
+            (
+                WindowsPrefixBuilder::verbatim_name("Volume{1234}"),
+                WindowsPrefix::Verbatim(b"Volume{1234}"),
+            ),
+        ];
+
+        for (builder, prefix) in cases {
+            for root in [true, false] {
+                let path = builder.root(root).build().unwrap();
+                let path: &WindowsPath = path.as_path();
+                assert_eq!(path.components().prefix_kind(), Some(prefix), "{path:?}");
+                assert_eq!(
+                    path.components().any(|c| c == WindowsComponent::RootDir),
+                    root,
+                    "{path:?}"
+                );
+                assert_eq!(path.components().count(), 1 + root as usize, "{path:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn build_should_reject_invalid_parts() {
+        assert_eq!(
+            WindowsPrefixBuilder::disk('é').build(),
+            Err(WindowsPrefixError::InvalidDrive('é'))
+        );
+        assert_eq!(
+            WindowsPrefixBuilder::unc("", "share").build(),
+            Err(WindowsPrefixError::EmptyServer)
+        );
+        assert_eq!(
+            WindowsPrefixBuilder::unc("srv", "a:b").build(),
+            Err(WindowsPrefixError::InvalidByte(b':'))
+        );
+        assert_eq!(
+            WindowsPrefixBuilder::device_ns("").build(),
+            Err(WindowsPrefixError::EmptyName)
+        );
+        assert_eq!(
+            WindowsPrefixBuilder::device_ns("pipe/x").build(),
+            Err(WindowsPrefixError::InvalidByte(b'/'))
+        );
+        assert_eq!(
+            WindowsPrefixBuilder::verbatim_name("UNC").build(),
+            Err(WindowsPrefixError::Ambiguous)
+        );
+    }
+}
@@ -0,0 +1,256 @@
+/// Suffix of the names Windows decodes back into IPv6 addresses within a UNC path
+const IPV6_LITERAL_SUFFIX: &[u8] = b".ipv6-literal.net";
+
+/// The server of a UNC (`\\server\share`) or verbatim UNC (`\\?\UNC\server\share`) prefix,
+/// returned by [`WindowsPrefix::host`].
+///
+/// As `:` cannot appear within a path, Windows names an IPv6 server using the
+/// `ipv6-literal.net` convention, writing `fe80::1%4` as `fe80--1s4.ipv6-literal.net`. Such
+/// names are decoded back into the address they stand for, as are dotted IPv4 addresses.
+///
+/// [`WindowsPrefix::host`]: crate::WindowsPrefix::host
+///
+/// # Examples
+///
+/// ```
+/// use typed_path::{UncHost, WindowsPrefix};
+///
+/// let prefix = WindowsPrefix::UNC(b"fe80--1s4.ipv6-literal.net", b"share");
+/// assert_eq!(
+///     prefix.host(),
+///     Some(UncHost::Ipv6 {
+///         segments: [0xfe80, 0, 0, 0, 0, 0, 0, 1],
+///         scope_id: Some(4),
+///     }),
+/// );
+///
+/// let prefix = WindowsPrefix::UNC(b"192.168.1.20", b"share");
+/// assert_eq!(prefix.host(), Some(UncHost::Ipv4([192, 168, 1, 20])));
+///
+/// let prefix = WindowsPrefix::VerbatimUNC(b"files.example.com", b"share");
+/// assert_eq!(prefix.host(), Some(UncHost::Name(b"files.example.com")));
+/// ```
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+pub enum UncHost<'a> {
+    /// Host name or fully qualified domain name, e.g., `fileserver` or `files.example.com`.
+    Name(&'a [u8]),
+
+    /// IPv4 address written in dotted decimal, e.g., `192.168.1.20`.
+    Ipv4([u8; 4]),
+
+    /// IPv6 address written using the `ipv6-literal.net` convention, e.g.,
+    /// `fe80--1s4.ipv6-literal.net`.
+    Ipv6 {
+        /// The eight 16-bit segments of the address.
+        segments: [u16; 8],
+
+        /// The zone index following `s`, e.g., `4` in `fe80--1s4.ipv6-literal.net`.
+        scope_id: Option<u32>,
+    },
+}
+
+impl<'a> UncHost<'a> {
+    /// Reads the UNC server `server` as a host.
+    pub fn parse(server: &'a [u8]) -> Self {
+        if let Some(octets) = parse_ipv4(server) {
+            return Self::Ipv4(octets);
+        }
+
+        let literal = match server.len().checked_sub(IPV6_LITERAL_SUFFIX.len()) {
+            Some(end) if server[end..].eq_ignore_ascii_case(IPV6_LITERAL_SUFFIX) => &server[..end],
+            _ => return Self::Name(server),
+        };
+
+        // Hex digits never include `s`, so the zone index begins at the first one
+        let (address, scope_id) = match literal.iter().position(|b| matches!(b, b's' | b'S')) {
+            Some(i) => (&literal[..i], parse_decimal(&literal[i + 1..]).map(Some)),
+            None => (literal, Some(None)),
+        };
+
+        match (parse_ipv6(address), scope_id) {
+            (Some(segments), Some(scope_id)) => Self::Ipv6 { segments, scope_id },
+            _ => Self::Name(server),
+        }
+    }
+
+    /// Converts the host into an [`IpAddr`](std::net::IpAddr), or returns [`None`] if it is a
+    /// host name. The zone index of an IPv6 address is dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::net::{IpAddr, Ipv6Addr};
+    /// use typed_path::UncHost;
+    ///
+    /// let host = UncHost::parse(b"2001-db8--8a2e-370-7334.ipv6-literal.net");
+    /// assert_eq!(
+    ///     host.ip_addr(),
+    ///     Some(IpAddr::V6(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0x8a2e, 0x370, 0x7334))),
+    /// );
+    /// assert_eq!(UncHost::parse(b"fileserver").ip_addr(), None);
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn ip_addr(&self) -> Option<std::net::IpAddr> {
+        match *self {
+            Self::Name(_) => None,
+            Self::Ipv4(octets) => Some(std::net::IpAddr::from(octets)),
+            Self::Ipv6 { segments, .. } => Some(std::net::IpAddr::from(segments)),
+        }
+    }
+}
+
+/// Parses a decimal number without a sign or leading zeros
+fn parse_decimal(digits: &[u8]) -> Option<u32> {
+    if digits.is_empty() || (digits.len() > 1 && digits[0] == b'0') {
+        return None;
+    }
+
+    digits.iter().try_fold(0u32, |n, b| match b {
+        b'0'..=b'9' => n.checked_mul(10)?.checked_add(u32::from(b - b'0')),
+        _ => None,
+    })
+}
+
+/// Parses four dot-separated decimal octets
+fn parse_ipv4(address: &[u8]) -> Option<[u8; 4]> {
+    let mut parts = address.split(|b| *b == b'.');
+    let mut octets = [0; 4];
+    for octet in octets.iter_mut() {
+        *octet = u8::try_from(parse_decimal(parts.next()?)?).ok()?;
+    }
+
+    match parts.next() {
+        Some(_) => None,
+        None => Some(octets),
+    }
+}
+
+/// Parses an IPv6 address whose `:` are written as `-`, where `--` stands in for `::`
+fn parse_ipv6(address: &[u8]) -> Option<[u16; 8]> {
+    let mut segments = [0; 8];
+    match address.windows(2).position(|pair| pair == b"--") {
+        Some(i) => {
+            let mut tail = [0; 8];
+            let head_len = parse_segments(&address[..i], &mut segments, false)?;
+            let tail_len = parse_segments(&address[i + 2..], &mut tail, true)?;
+
+            // `--` must stand in for at least one segment of zeros
+            if head_len + tail_len > 7 {
+                return None;
+            }
+            segments[8 - tail_len..].copy_from_slice(&tail[..tail_len]);
+        }
+        None => {
+            if parse_segments(address, &mut segments, true)? != 8 {
+                return None;
+            }
+        }
+    }
+
+    Some(segments)
+}
+
+/// Parses `-` separated hex segments into the front of `segments`, returning how many were
+/// written. If `allow_ipv4` is true, the last segment may instead be an IPv4 address filling
+/// the final two segments.
+fn parse_segments(groups: &[u8], segments: &mut [u16; 8], allow_ipv4: bool) -> Option<usize> {
+    if groups.is_empty() {
+        return Some(0);
+    }
+
+    let mut len = 0;
+    let mut groups = groups.split(|b| *b == b'-').peekable();
+    while let Some(group) = groups.next() {
+        if allow_ipv4 && groups.peek().is_none() && group.contains(&b'.') {
+            let [a, b, c, d] = parse_ipv4(group)?;
+            segments
+                .get_mut(len..len + 2)?
+                .copy_from_slice(&[u16::from_be_bytes([a, b]), u16::from_be_bytes([c, d])]);
+            return Some(len + 2);
+        }
+
+        if group.is_empty() || group.len() > 4 {
+            return None;
+        }
+
+        let segment = group.iter().try_fold(0u16, |n, b| {
+            let digit = (*b as char).to_digit(16)?;
+            Some((n << 4) | digit as u16)
+        })?;
+        *segments.get_mut(len)? = segment;
+        len += 1;
+    }
+
+    Some(len)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_should_decode_ipv6_literals() {
+        let ipv6 = |segments, scope_id| UncHost::Ipv6 { segments, scope_id };
+        let cases: &[(&[u8], UncHost<'_>)] = &[
+            (b"--.ipv6-literal.net", ipv6([0; 8], None)),
+            (
+                b"--1.ipv6-literal.net",
+                ipv6([0, 0, 0, 0, 0, 0, 0, 1], None),
+            ),
+            (
+                b"1--.IPV6-LITERAL.NET",
+                ipv6([1, 0, 0, 0, 0, 0, 0, 0], None),
+            ),
+            (
+                b"FE80--1S12.ipv6-literal.net",
+                ipv6([0xfe80, 0, 0, 0, 0, 0, 0, 1], Some(12)),
+            ),
+            (
+                b"1-2-3-4-5-6-7-8.ipv6-literal.net",
+                ipv6([1, 2, 3, 4, 5, 6, 7, 8], None),
+            ),
+            (
+                b"1-2-3--6-7-8.ipv6-literal.net",
+                ipv6([1, 2, 3, 0, 0, 6, 7, 8], None),
+            ),
+            (
+                b"--ffff-192.0.2.1.ipv6-literal.net",
+                ipv6([0, 0, 0, 0, 0, 0xffff, 0xc000, 0x0201], None),
+            ),
+            (b"10.0.0.255", UncHost::Ipv4([10, 0, 0, 255])),
+        ];
+        for (server, host) in cases {
+            assert_eq!(UncHost::parse(server), *host, "{:?}", server);
+        }
+    }
+
+    #[test]
+    fn parse_should_keep_malformed_addresses_as_names() {
+        let cases: &[&[u8]] = &[
+            b"server",
+            b"files.example.com",
+            b"ipv6-literal.net",
+            b"1-2-3-4-5-6-7.ipv6-literal.net",
+            b"1-2-3-4-5-6-7-8-9.ipv6-literal.net",
+            b"1-2-3-4--5-6-7-8.ipv6-literal.net",
+            b"1--2--3.ipv6-literal.net",
+            b"12345--.ipv6-literal.net",
+            b"g--.ipv6-literal.net",
+            b"--1s.ipv6-literal.net",
+            b"--1s01.ipv6-literal.net",
+            b"192.0.2.1--.ipv6-literal.net",
+            b"256.0.0.1",
+            b"1.2.3",
+            b"1.2.3.4.5",
+            b"01.2.3.4",
+        ];
+        for server in cases {
+            assert_eq!(
+                UncHost::parse(server),
+                UncHost::Name(server),
+                "{:?}",
+                server
+            );
+        }
+    }
+}
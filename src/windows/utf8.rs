@@ -1,14 +1,22 @@
 mod components;
+mod prefix_builder;
+mod unc_host;
 
 use core::fmt;
 use core::hash::Hasher;
 
 pub use components::*;
+pub use prefix_builder::*;
+pub use unc_host::*;
 
-use crate::common::CheckedPathError;
+use super::constants::MAX_VERBATIM_PATH;
+use crate::common::{CheckedPathError, DriveError, PathTooLongError, SmbUrlError, UncError};
 use crate::no_std_compat::*;
 use crate::typed::{Utf8TypedPath, Utf8TypedPathBuf};
-use crate::{private, Encoding, Utf8Encoding, Utf8Path, Utf8PathBuf, WindowsEncoding};
+use crate::{
+    private, Encoding, Utf8Encoding, Utf8Path, Utf8PathBuf, VerbatimMode, WindowsEncoding,
+    WindowsPath,
+};
 
 /// Represents a Windows-specific [`Utf8Path`]
 pub type Utf8WindowsPath = Utf8Path<Utf8WindowsEncoding>;
@@ -25,6 +33,13 @@ impl private::Sealed for Utf8WindowsEncoding {}
 impl<'a> Utf8Encoding<'a> for Utf8WindowsEncoding {
     type Components = Utf8WindowsComponents<'a>;
 
+    const NAME: &'static str = <WindowsEncoding as Encoding<'a>>::NAME;
+    const SEPARATOR: char = <WindowsEncoding as Encoding<'a>>::SEPARATOR;
+    const ALT_SEPARATORS: &'static [char] = <WindowsEncoding as Encoding<'a>>::ALT_SEPARATORS;
+    const CASE_SENSITIVE: bool = <WindowsEncoding as Encoding<'a>>::CASE_SENSITIVE;
+    const DISALLOWED_FILENAME_BYTES: &'static [u8] =
+        <WindowsEncoding as Encoding<'a>>::DISALLOWED_FILENAME_BYTES;
+
     fn label() -> &'static str {
         "windows"
     }
@@ -46,6 +61,10 @@ impl<'a> Utf8Encoding<'a> for Utf8WindowsEncoding {
     fn push_checked(current_path: &mut String, path: &str) -> Result<(), CheckedPathError> {
         unsafe { WindowsEncoding::push_checked(current_path.as_mut_vec(), path.as_bytes()) }
     }
+
+    fn is_valid_prefix(prefix: &str) -> bool {
+        <WindowsEncoding as Encoding<'a>>::is_valid_prefix(prefix.as_bytes())
+    }
 }
 
 impl fmt::Debug for Utf8WindowsEncoding {
@@ -97,6 +116,30 @@ where
 }
 
 impl Utf8WindowsPath {
+    /// Returns the root of `drive`, such as `C:\`, without parsing a string at runtime.
+    ///
+    /// See [`WindowsPath::root_of_drive`] for more details.
+    ///
+    /// [`WindowsPath::root_of_drive`]: crate::WindowsPath::root_of_drive
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::Utf8WindowsPath;
+    ///
+    /// assert_eq!(
+    ///     Utf8WindowsPath::root_of_drive('d'),
+    ///     Some(Utf8WindowsPath::new(r"D:\")),
+    /// );
+    /// assert_eq!(Utf8WindowsPath::root_of_drive('?'), None);
+    /// ```
+    pub const fn root_of_drive(drive: char) -> Option<&'static Utf8WindowsPath> {
+        match super::non_utf8::drive_root(drive) {
+            Some(root) => Some(Utf8Path::from_str_const(root)),
+            None => None,
+        }
+    }
+
     pub fn to_typed_path(&self) -> Utf8TypedPath {
         Utf8TypedPath::windows(self)
     }
@@ -104,12 +147,533 @@ impl Utf8WindowsPath {
     pub fn to_typed_path_buf(&self) -> Utf8TypedPathBuf {
         Utf8TypedPathBuf::from_windows(self)
     }
+
+    /// Creates an owned [`Utf8WindowsPathBuf`] with `path` adjoined to `self` using Win32
+    /// resolution rules.
+    ///
+    /// See [`Utf8WindowsPathBuf::push_win32`] for more details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Utf8WindowsPath, Utf8WindowsPathBuf};
+    ///
+    /// let path = Utf8WindowsPath::new(r"C:\bar");
+    /// assert_eq!(path.join_win32(r"C:baz"), Utf8WindowsPathBuf::from(r"C:\bar\baz"));
+    /// ```
+    pub fn join_win32<P: AsRef<Utf8WindowsPath>>(&self, path: P) -> Utf8WindowsPathBuf {
+        let mut buf = self.to_path_buf();
+        buf.push_win32(path);
+        buf
+    }
+
+    /// Produces an iterator over the components of the path like [`Utf8Path::components`],
+    /// reading a verbatim (`\\?\`) path according to `mode`.
+    ///
+    /// See [`WindowsPath::components_with_verbatim_mode`] for more details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Utf8Component, Utf8WindowsPath, VerbatimMode};
+    ///
+    /// let path = Utf8WindowsPath::new(r"\\?\C:\a\.\b/c");
+    /// let components: Vec<_> = path
+    ///     .components_with_verbatim_mode(VerbatimMode::Normal)
+    ///     .map(|c| c.as_str())
+    ///     .collect();
+    /// assert_eq!(components, [r"\\?\C:", r"\", "a", "b", "c"]);
+    /// ```
+    pub fn components_with_verbatim_mode(&self, mode: VerbatimMode) -> Utf8WindowsComponents<'_> {
+        Utf8WindowsComponents::with_verbatim_mode(self.as_str(), mode)
+    }
+
+    /// Creates an owned [`Utf8WindowsPathBuf`] with `path` adjoined to `self`, reading a
+    /// verbatim `self` according to `mode`.
+    ///
+    /// See [`WindowsPathBuf::push_with_verbatim_mode`] for more details.
+    ///
+    /// [`WindowsPathBuf::push_with_verbatim_mode`]: crate::WindowsPathBuf::push_with_verbatim_mode
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Utf8WindowsPath, Utf8WindowsPathBuf, VerbatimMode};
+    ///
+    /// let path = Utf8WindowsPath::new(r"\\?\C:\a/b");
+    /// assert_eq!(
+    ///     path.join_with_verbatim_mode(r"..\c", VerbatimMode::Faithful),
+    ///     Utf8WindowsPathBuf::from(r"\\?\C:\a/b\..\c"),
+    /// );
+    /// assert_eq!(
+    ///     path.join_with_verbatim_mode(r"..\c", VerbatimMode::Normal),
+    ///     Utf8WindowsPathBuf::from(r"\\?\C:\a\c"),
+    /// );
+    /// ```
+    pub fn join_with_verbatim_mode<P: AsRef<Utf8WindowsPath>>(
+        &self,
+        path: P,
+        mode: VerbatimMode,
+    ) -> Utf8WindowsPathBuf {
+        let mut buf = self.to_path_buf();
+        buf.push_with_verbatim_mode(path, mode);
+        buf
+    }
+
+    /// Normalizes the path like [`Utf8Path::normalize`], reading a verbatim (`\\?\`) path
+    /// according to `mode`.
+    ///
+    /// See [`WindowsPath::normalize_with_verbatim_mode`] for more details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Utf8WindowsPath, Utf8WindowsPathBuf, VerbatimMode};
+    ///
+    /// let path = Utf8WindowsPath::new(r"\\?\C:\a\\..\b/.\");
+    /// assert_eq!(
+    ///     path.normalize_with_verbatim_mode(VerbatimMode::Faithful),
+    ///     Utf8WindowsPathBuf::from(r"\\?\C:\a\..\b/."),
+    /// );
+    /// assert_eq!(
+    ///     path.normalize_with_verbatim_mode(VerbatimMode::Normal),
+    ///     Utf8WindowsPathBuf::from(r"\\?\C:\b"),
+    /// );
+    /// ```
+    pub fn normalize_with_verbatim_mode(&self, mode: VerbatimMode) -> Utf8WindowsPathBuf {
+        let bytes = WindowsEncoding::normalize_with_verbatim_mode(self.as_str().as_bytes(), mode);
+
+        // NOTE: Only whole components and `\` are copied, so the bytes remain valid UTF-8
+        Utf8WindowsPathBuf::from(unsafe { String::from_utf8_unchecked(bytes) })
+    }
+
+    /// Returns the drive letter of the path's disk (`C:`) or verbatim disk (`\\?\C:`) prefix,
+    /// always in uppercase.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::Utf8WindowsPath;
+    ///
+    /// assert_eq!(Utf8WindowsPath::new(r"C:\Windows").drive_letter(), Some('C'));
+    /// assert_eq!(Utf8WindowsPath::new(r"\\server\share").drive_letter(), None);
+    /// ```
+    pub fn drive_letter(&self) -> Option<char> {
+        WindowsPath::new(self.as_str()).drive_letter()
+    }
+
+    /// Creates an owned [`Utf8WindowsPathBuf`] like `self` but on `drive`.
+    ///
+    /// See [`Utf8WindowsPathBuf::set_drive`] for more details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Utf8WindowsPath, Utf8WindowsPathBuf};
+    ///
+    /// let path = Utf8WindowsPath::new(r"C:\srv\app");
+    /// assert_eq!(path.with_drive('E'), Ok(Utf8WindowsPathBuf::from(r"E:\srv\app")));
+    /// ```
+    pub fn with_drive(&self, drive: char) -> Result<Utf8WindowsPathBuf, DriveError> {
+        let mut buf = self.to_path_buf();
+        buf.set_drive(drive)?;
+        Ok(buf)
+    }
+
+    /// Creates an owned [`Utf8WindowsPathBuf`] like `self` but with the drive letter of its disk
+    /// (`c:`) or verbatim disk (`\\?\c:`) prefix made uppercase.
+    ///
+    /// See [`WindowsPath::normalize_drive_letter_case`] for more details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Utf8WindowsPath, Utf8WindowsPathBuf};
+    ///
+    /// let path = Utf8WindowsPath::new(r"d:\données");
+    /// assert_eq!(path.normalize_drive_letter_case(), Utf8WindowsPathBuf::from(r"D:\données"));
+    /// ```
+    pub fn normalize_drive_letter_case(&self) -> Utf8WindowsPathBuf {
+        let buf = WindowsPath::new(self.as_str()).normalize_drive_letter_case();
+
+        // NOTE: Only a single ascii byte is uppercased, so the bytes remain valid UTF-8
+        Utf8WindowsPathBuf::from(unsafe { String::from_utf8_unchecked(buf.inner) })
+    }
+
+    /// Determines whether `self` and `other` would open the same object if passed to Win32, going
+    /// only by how they are written.
+    ///
+    /// See [`WindowsPath::win32_equivalent`] for more details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::Utf8WindowsPath;
+    ///
+    /// let path = Utf8WindowsPath::new(r"C:\Users\Public\Documents");
+    /// assert!(path.win32_equivalent(r"\\?\c:\users\public\DOCUMENTS"));
+    /// assert!(path.win32_equivalent(r"C:/Users/Public/Documents./"));
+    /// assert!(!path.win32_equivalent(r"\\?\C:\Users\Public\Documents."));
+    /// ```
+    pub fn win32_equivalent<P: AsRef<Utf8WindowsPath>>(&self, other: P) -> bool {
+        let name = WindowsEncoding::win32_object_name(self.as_str().as_bytes());
+        name.eq_ignore_ascii_case(&WindowsEncoding::win32_object_name(
+            other.as_ref().as_str().as_bytes(),
+        ))
+    }
+
+    /// Creates an owned [`Utf8WindowsPathBuf`] like `self` but pointing at the server `host`.
+    ///
+    /// See [`WindowsPath::with_unc_host`] for more details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Utf8WindowsPath, Utf8WindowsPathBuf};
+    ///
+    /// let path = Utf8WindowsPath::new(r"\\primary\data\reports");
+    /// assert_eq!(
+    ///     path.with_unc_host("replica"),
+    ///     Ok(Utf8WindowsPathBuf::from(r"\\replica\data\reports")),
+    /// );
+    /// ```
+    pub fn with_unc_host<S: AsRef<str>>(&self, host: S) -> Result<Utf8WindowsPathBuf, UncError> {
+        let mut buf = self.to_path_buf();
+
+        // Only ever splices in a complete UTF-8 string at the boundaries of other UTF-8 strings
+        unsafe {
+            WindowsEncoding::set_unc(buf.inner.as_mut_vec(), host.as_ref().as_bytes(), true)?;
+        }
+
+        Ok(buf)
+    }
+
+    /// Creates an owned [`Utf8WindowsPathBuf`] like `self` but pointing at the share `share`.
+    ///
+    /// See [`WindowsPath::with_unc_share`] for more details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Utf8WindowsPath, Utf8WindowsPathBuf};
+    ///
+    /// let path = Utf8WindowsPath::new(r"\\?\UNC\server\data\reports");
+    /// assert_eq!(
+    ///     path.with_unc_share("backup"),
+    ///     Ok(Utf8WindowsPathBuf::from(r"\\?\UNC\server\backup\reports")),
+    /// );
+    /// ```
+    pub fn with_unc_share<S: AsRef<str>>(&self, share: S) -> Result<Utf8WindowsPathBuf, UncError> {
+        let mut buf = self.to_path_buf();
+
+        // Only ever splices in a complete UTF-8 string at the boundaries of other UTF-8 strings
+        unsafe {
+            WindowsEncoding::set_unc(buf.inner.as_mut_vec(), share.as_ref().as_bytes(), false)?;
+        }
+
+        Ok(buf)
+    }
+
+    /// Creates an owned [`Utf8WindowsPathBuf`] of the UNC (`\\server\share`) or verbatim UNC
+    /// (`\\?\UNC\server\share`) path `self` in a canonical form.
+    ///
+    /// See [`WindowsPath::canonicalize_unc`] for more details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Utf8WindowsPath, Utf8WindowsPathBuf};
+    ///
+    /// let path = Utf8WindowsPath::new(r"\\?\UNC\FileServer\Public\Reports\");
+    /// assert_eq!(
+    ///     path.canonicalize_unc(),
+    ///     Ok(Utf8WindowsPathBuf::from(r"\\fileserver\Public\Reports")),
+    /// );
+    /// ```
+    pub fn canonicalize_unc(&self) -> Result<Utf8WindowsPathBuf, UncError> {
+        let bytes = WindowsEncoding::canonicalize_unc(self.as_str().as_bytes())?;
+
+        // NOTE: Only whole components, ascii prefixes and separators, and ascii lowercasing are
+        //       applied, so the bytes remain valid UTF-8
+        Ok(Utf8WindowsPathBuf::from(unsafe {
+            String::from_utf8_unchecked(bytes)
+        }))
+    }
+
+    /// Converts a UNC (`\\server\share`) or verbatim UNC (`\\?\UNC\server\share`) path into the
+    /// equivalent `smb://server/share` URL.
+    ///
+    /// See [`WindowsPath::to_smb_url`] for more details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::Utf8WindowsPath;
+    ///
+    /// let path = Utf8WindowsPath::new(r"\\fileserver\public\résumé.pdf");
+    /// assert_eq!(
+    ///     path.to_smb_url().as_deref(),
+    ///     Some("smb://fileserver/public/r%C3%A9sum%C3%A9.pdf"),
+    /// );
+    /// ```
+    pub fn to_smb_url(&self) -> Option<String> {
+        WindowsEncoding::to_smb_url(self.as_str().as_bytes())
+    }
+
+    /// Creates an owned [`Utf8WindowsPathBuf`] with `path` adjoined to `self`, failing if the
+    /// result is longer than `max_utf16_units` once encoded as UTF-16.
+    ///
+    /// See [`WindowsPath::join_within_limit`] for more details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::constants::windows::MAX_PATH;
+    /// use typed_path::{Utf8WindowsPath, Utf8WindowsPathBuf};
+    ///
+    /// let path = Utf8WindowsPath::new(r"C:\Program Files");
+    /// assert_eq!(
+    ///     path.join_within_limit("Café", MAX_PATH - 1),
+    ///     Ok(Utf8WindowsPathBuf::from(r"C:\Program Files\Café")),
+    /// );
+    ///
+    /// // Each character counts once, however many bytes it takes up
+    /// let err = path.join_within_limit("é".repeat(300), MAX_PATH - 1).unwrap_err();
+    /// assert_eq!(err.utf16_len(), 317);
+    /// ```
+    pub fn join_within_limit<P: AsRef<Utf8WindowsPath>>(
+        &self,
+        path: P,
+        max_utf16_units: usize,
+    ) -> Result<Utf8WindowsPathBuf, PathTooLongError> {
+        let buf = self.join(path);
+        let len = buf.as_str().encode_utf16().count();
+        if len > max_utf16_units {
+            return Err(PathTooLongError {
+                len,
+                max: max_utf16_units,
+            });
+        }
+
+        Ok(buf)
+    }
+
+    /// Like [`Utf8WindowsPath::join_within_limit`], but when the result is too long and is an
+    /// absolute disk or UNC path, converts it into the equivalent verbatim path instead of
+    /// failing.
+    ///
+    /// See [`WindowsPath::join_within_limit_or_verbatim`] for more details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::constants::windows::MAX_PATH;
+    /// use typed_path::{Utf8WindowsPath, Utf8WindowsPathBuf};
+    ///
+    /// let name = "é".repeat(300);
+    /// let path = Utf8WindowsPath::new(r"C:\data\..\logs");
+    /// assert_eq!(
+    ///     path.join_within_limit_or_verbatim(&name, MAX_PATH - 1),
+    ///     Ok(Utf8WindowsPathBuf::from(format!(r"\\?\C:\logs\{name}"))),
+    /// );
+    /// ```
+    pub fn join_within_limit_or_verbatim<P: AsRef<Utf8WindowsPath>>(
+        &self,
+        path: P,
+        max_utf16_units: usize,
+    ) -> Result<Utf8WindowsPathBuf, PathTooLongError> {
+        let buf = self.join(path);
+        let len = buf.as_str().encode_utf16().count();
+        if len <= max_utf16_units {
+            return Ok(buf);
+        }
+
+        // Verbatim paths are assembled from whole components of the original, so stay UTF-8
+        let max = MAX_VERBATIM_PATH - 1;
+        match WindowsEncoding::to_verbatim(buf.as_str().as_bytes())
+            .and_then(|verbatim| String::from_utf8(verbatim).ok())
+        {
+            Some(verbatim) => match verbatim.encode_utf16().count() {
+                len if len <= max => Ok(Utf8WindowsPathBuf::from(verbatim)),
+                len => Err(PathTooLongError { len, max }),
+            },
+            None => Err(PathTooLongError {
+                len,
+                max: max_utf16_units,
+            }),
+        }
+    }
 }
 
+impl Utf8WindowsPathBuf {
+    /// Extends `self` with `path` the way Win32 (e.g. `GetFullPathNameW` and `CreateFileW`) would
+    /// resolve `path` if `self` were the current directory.
+    ///
+    /// See [`WindowsPathBuf::push_win32`] for more details.
+    ///
+    /// [`WindowsPathBuf::push_win32`]: crate::WindowsPathBuf::push_win32
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::Utf8WindowsPathBuf;
+    ///
+    /// // Root-relative paths keep the drive
+    /// let mut path = Utf8WindowsPathBuf::from(r"C:\bar");
+    /// path.push_win32(r"\foo");
+    /// assert_eq!(path, Utf8WindowsPathBuf::from(r"C:\foo"));
+    ///
+    /// // Drive-relative paths on the same drive resolve against the current path
+    /// let mut path = Utf8WindowsPathBuf::from(r"C:\bar");
+    /// path.push_win32(r"c:foo");
+    /// assert_eq!(path, Utf8WindowsPathBuf::from(r"C:\bar\foo"));
+    ///
+    /// // Drive-relative paths on another drive remain drive-relative
+    /// let mut path = Utf8WindowsPathBuf::from(r"C:\bar");
+    /// path.push_win32(r"D:file");
+    /// assert_eq!(path, Utf8WindowsPathBuf::from(r"D:file"));
+    /// ```
+    pub fn push_win32<P: AsRef<Utf8WindowsPath>>(&mut self, path: P) {
+        unsafe {
+            WindowsEncoding::push_win32(self.inner.as_mut_vec(), path.as_ref().as_str().as_bytes());
+        }
+    }
+
+    /// Extends `self` with `path` like [`Utf8PathBuf::push`], reading a verbatim (`\\?\`) `self`
+    /// according to `mode`.
+    ///
+    /// See [`WindowsPathBuf::push_with_verbatim_mode`] for more details.
+    ///
+    /// [`WindowsPathBuf::push_with_verbatim_mode`]: crate::WindowsPathBuf::push_with_verbatim_mode
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Utf8WindowsPathBuf, VerbatimMode};
+    ///
+    /// let mut path = Utf8WindowsPathBuf::from(r"\\?\C:\a");
+    /// path.push_with_verbatim_mode(r"..\b", VerbatimMode::Faithful);
+    /// assert_eq!(path, Utf8WindowsPathBuf::from(r"\\?\C:\a\..\b"));
+    /// ```
+    pub fn push_with_verbatim_mode<P: AsRef<Utf8WindowsPath>>(
+        &mut self,
+        path: P,
+        mode: VerbatimMode,
+    ) {
+        unsafe {
+            WindowsEncoding::push_with_verbatim_mode(
+                self.inner.as_mut_vec(),
+                path.as_ref().as_str().as_bytes(),
+                mode,
+            );
+        }
+    }
+
+    /// Moves the path onto `drive`, which must be an ascii letter.
+    ///
+    /// See [`WindowsPathBuf::set_drive`] for more details.
+    ///
+    /// [`WindowsPathBuf::set_drive`]: crate::WindowsPathBuf::set_drive
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{DriveError, Utf8WindowsPathBuf};
+    ///
+    /// let mut path = Utf8WindowsPathBuf::from(r"c:data");
+    /// path.set_drive('d').unwrap();
+    /// assert_eq!(path, Utf8WindowsPathBuf::from(r"d:data"));
+    ///
+    /// let mut path = Utf8WindowsPathBuf::from(r"\\?\UNC\server\share");
+    /// assert_eq!(path.set_drive('D'), Err(DriveError::UnsupportedPrefix));
+    /// ```
+    pub fn set_drive(&mut self, drive: char) -> Result<(), DriveError> {
+        // Only ever writes an ascii letter and colon, so the path remains valid UTF-8
+        unsafe { WindowsEncoding::set_drive(self.inner.as_mut_vec(), drive) }
+    }
+
+    /// Converts an `smb://server/share` URL into the equivalent UNC path (`\\server\share`).
+    ///
+    /// See [`WindowsPathBuf::from_smb_url`] for more details.
+    ///
+    /// [`WindowsPathBuf::from_smb_url`]: crate::WindowsPathBuf::from_smb_url
+    ///
+    /// # Errors
+    ///
+    /// In addition to the errors of [`WindowsPathBuf::from_smb_url`], returns
+    /// [`SmbUrlError::InvalidUtf8`] if the decoded path is not valid UTF-8.
+    ///
+    /// [`SmbUrlError::InvalidUtf8`]: crate::SmbUrlError::InvalidUtf8
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{SmbUrlError, Utf8WindowsPathBuf};
+    ///
+    /// assert_eq!(
+    ///     Utf8WindowsPathBuf::from_smb_url("smb://fileserver/public/r%C3%A9sum%C3%A9.pdf"),
+    ///     Ok(Utf8WindowsPathBuf::from(r"\\fileserver\public\résumé.pdf")),
+    /// );
+    ///
+    /// assert_eq!(
+    ///     Utf8WindowsPathBuf::from_smb_url("smb://fileserver/public/%FF"),
+    ///     Err(SmbUrlError::InvalidUtf8),
+    /// );
+    /// ```
+    pub fn from_smb_url(url: &str) -> Result<Self, SmbUrlError> {
+        let bytes = WindowsEncoding::from_smb_url(url)?;
+        String::from_utf8(bytes)
+            .map(Self::from)
+            .map_err(|_| SmbUrlError::InvalidUtf8)
+    }
+}
+
+impl_cmp_utf8_bytes!(Utf8WindowsEncoding, WindowsEncoding);
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn push_should_only_resolve_dots_for_verbatim_paths() {
+        let mut path = Utf8WindowsPathBuf::from(r"C:\a");
+        path.push(r"..\.\b");
+        assert_eq!(path, r"C:\a\..\.\b");
+
+        let mut path = Utf8WindowsPathBuf::from(r"\\server\share");
+        path.push(r"..\a");
+        assert_eq!(path, r"\\server\share\..\a");
+
+        let mut path = Utf8WindowsPathBuf::from(r"\\?\C:\a");
+        path.push(r"..\.\b");
+        assert_eq!(path, r"\\?\C:\b");
+        assert!(path.components().has_any_verbatim_prefix());
+        assert!(!Utf8WindowsPath::new(r"C:\b")
+            .components()
+            .has_any_verbatim_prefix());
+    }
+
+    #[test]
+    fn utf8_paths_should_equal_byte_paths_with_the_same_bytes() {
+        use crate::WindowsPath;
+
+        let path = Utf8WindowsPath::new(r"C:\some\path");
+        let buf = path.to_path_buf();
+        let bytes = WindowsPath::new(r"C:\some\path");
+        let bytes_buf = bytes.to_path_buf();
+
+        assert_eq!(*path, *bytes);
+        assert_eq!(*bytes, *path);
+        assert_eq!(path, bytes_buf);
+        assert_eq!(bytes_buf, path);
+        assert_eq!(buf, bytes);
+        assert_eq!(buf, bytes_buf);
+
+        // Comparison is by bytes, so equivalent paths with different bytes are not equal
+        assert_ne!(*path, *WindowsPath::new("C:/some/path"));
+        assert_ne!(buf, WindowsPath::new(r"c:\some\path"));
+    }
+
     #[test]
     fn push_checked_should_fail_if_providing_an_absolute_path() {
         // Empty current path will fail when pushing an absolute path
@@ -4,8 +4,9 @@ use core::{cmp, fmt, iter};
 
 pub use component::*;
 
+use crate::common::remaining_str;
 use crate::windows::WindowsComponents;
-use crate::{private, Components, Utf8Components, Utf8Encoding, Utf8Path};
+use crate::{private, Components, Utf8Components, Utf8Encoding, Utf8Path, VerbatimMode};
 
 /// Represents a Windows-specific [`Components`]
 #[derive(Clone)]
@@ -20,8 +21,17 @@ impl<'a> Utf8WindowsComponents<'a> {
         }
     }
 
+    pub(crate) fn with_verbatim_mode(path: &'a str, mode: VerbatimMode) -> Self {
+        Self {
+            inner: WindowsComponents::with_verbatim_mode(path.as_bytes(), mode),
+        }
+    }
+
     /// Extracts a slice corresponding to the portion of the path remaining for iteration.
     ///
+    /// As with `std::path::Components::as_path`, trailing separators and current directory
+    /// components that will not be yielded are left out.
+    ///
     /// # Examples
     ///
     /// ```
@@ -38,7 +48,7 @@ impl<'a> Utf8WindowsComponents<'a> {
     where
         T: for<'enc> Utf8Encoding<'enc>,
     {
-        Utf8Path::new(self.as_str())
+        Utf8Path::new(remaining_str(self))
     }
 }
 
@@ -101,8 +111,8 @@ impl<'a> Utf8WindowsComponents<'a> {
             self.prefix_kind(),
             Some(
                 Utf8WindowsPrefix::Verbatim(_)
-                    | Utf8WindowsPrefix::UNC(..)
-                    | Utf8WindowsPrefix::Disk(_)
+                    | Utf8WindowsPrefix::VerbatimUNC(..)
+                    | Utf8WindowsPrefix::VerbatimDisk(_)
             )
         )
     }
@@ -177,7 +187,7 @@ where
 {
     #[inline]
     fn as_ref(&self) -> &Utf8Path<T> {
-        Utf8Path::new(self.as_str())
+        Utf8Path::new(remaining_str(self))
     }
 }
 
@@ -3,7 +3,7 @@ use core::convert::TryFrom;
 use core::hash::{Hash, Hasher};
 use core::str::Utf8Error;
 
-use crate::windows::{Utf8WindowsComponents, WindowsPrefix, WindowsPrefixComponent};
+use crate::windows::{Utf8UncHost, Utf8WindowsComponents, WindowsPrefix, WindowsPrefixComponent};
 use crate::ParseError;
 
 /// A structure wrapping a Windows path prefix as well as its unparsed string
@@ -351,6 +351,29 @@ impl<'a> Utf8WindowsPrefix<'a> {
         matches!(*self, Verbatim(_) | VerbatimDisk(_) | VerbatimUNC(..))
     }
 
+    /// Returns the server of a UNC or verbatim UNC prefix as a [`Utf8UncHost`], or [`None`] for
+    /// any other prefix.
+    ///
+    /// See [`WindowsPrefix::host`] for more details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Utf8UncHost, Utf8WindowsPath};
+    ///
+    /// let path = Utf8WindowsPath::new(r"\\?\UNC\10.0.0.5\logs\app.log");
+    /// assert_eq!(
+    ///     path.components().prefix_kind().and_then(|prefix| prefix.host()),
+    ///     Some(Utf8UncHost::Ipv4([10, 0, 0, 5])),
+    /// );
+    /// ```
+    pub fn host(&self) -> Option<Utf8UncHost<'a>> {
+        match *self {
+            Self::UNC(server, _) | Self::VerbatimUNC(server, _) => Some(Utf8UncHost::parse(server)),
+            _ => None,
+        }
+    }
+
     /// Converts a non-UTF-8 [`WindowsPrefix`] to a UTF-8 [`Utf8WindowsPrefix`]
     /// by checking that the prefix contains valid UTF-8.
     ///
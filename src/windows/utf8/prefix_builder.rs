@@ -0,0 +1,90 @@
+use crate::common::WindowsPrefixError;
+use crate::no_std_compat::*;
+use crate::{Utf8WindowsPathBuf, WindowsPrefixBuilder};
+
+/// Builds a [`Utf8WindowsPathBuf`] holding only a prefix, and by default a root directory, from
+/// its parts.
+///
+/// See [`WindowsPrefixBuilder`] for more details.
+///
+/// # Examples
+///
+/// ```
+/// use typed_path::{Utf8WindowsPathBuf, Utf8WindowsPrefixBuilder, WindowsPrefixError};
+///
+/// assert_eq!(
+///     Utf8WindowsPrefixBuilder::unc("server", "share").build(),
+///     Ok(Utf8WindowsPathBuf::from(r"\\server\share\")),
+/// );
+///
+/// assert_eq!(
+///     Utf8WindowsPrefixBuilder::disk('c').verbatim(true).build(),
+///     Ok(Utf8WindowsPathBuf::from(r"\\?\C:\")),
+/// );
+///
+/// assert_eq!(
+///     Utf8WindowsPrefixBuilder::verbatim_name("").build(),
+///     Err(WindowsPrefixError::EmptyName),
+/// );
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Utf8WindowsPrefixBuilder<'a> {
+    inner: WindowsPrefixBuilder<'a>,
+}
+
+impl<'a> Utf8WindowsPrefixBuilder<'a> {
+    /// Starts a disk prefix like `C:`, where `drive` must be an ascii letter and is uppercased.
+    pub const fn disk(drive: char) -> Self {
+        Self {
+            inner: WindowsPrefixBuilder::disk(drive),
+        }
+    }
+
+    /// Starts a UNC prefix like `\\server\share`, where neither part may be empty.
+    pub fn unc(server: &'a str, share: &'a str) -> Self {
+        Self {
+            inner: WindowsPrefixBuilder::unc(server, share),
+        }
+    }
+
+    /// Starts a device namespace prefix like `\\.\COM1`.
+    pub fn device_ns(name: &'a str) -> Self {
+        Self {
+            inner: WindowsPrefixBuilder::device_ns(name),
+        }
+    }
+
+    /// Starts a verbatim prefix like `\\?\Volume{...}`, which is always verbatim.
+    pub fn verbatim_name(name: &'a str) -> Self {
+        Self {
+            inner: WindowsPrefixBuilder::verbatim_name(name),
+        }
+    }
+
+    /// Turns a disk prefix into a verbatim disk prefix (`\\?\C:`) and a UNC prefix into a
+    /// verbatim UNC prefix (`\\?\UNC\server\share`). Has no effect on other prefixes.
+    pub fn verbatim(self, yes: bool) -> Self {
+        Self {
+            inner: self.inner.verbatim(yes),
+        }
+    }
+
+    /// Whether to follow the prefix with a root directory, which is the default.
+    pub fn root(self, yes: bool) -> Self {
+        Self {
+            inner: self.inner.root(yes),
+        }
+    }
+
+    /// Validates the parts and builds the path.
+    ///
+    /// See [`WindowsPrefixBuilder::build`] for the possible errors.
+    pub fn build(&self) -> Result<Utf8WindowsPathBuf, WindowsPrefixError> {
+        let bytes = self.inner.build_bytes()?;
+
+        // Every part came from a `str` and is only joined with ascii bytes
+        Ok(Utf8WindowsPathBuf::from(unsafe {
+            String::from_utf8_unchecked(bytes)
+        }))
+    }
+}
@@ -0,0 +1,68 @@
+use crate::UncHost;
+
+/// The server of a UNC (`\\server\share`) or verbatim UNC (`\\?\UNC\server\share`) prefix,
+/// returned by [`Utf8WindowsPrefix::host`].
+///
+/// See [`UncHost`] for more details.
+///
+/// [`Utf8WindowsPrefix::host`]: crate::Utf8WindowsPrefix::host
+///
+/// # Examples
+///
+/// ```
+/// use typed_path::{Utf8UncHost, Utf8WindowsPrefix};
+///
+/// let prefix = Utf8WindowsPrefix::UNC("fe80--1.ipv6-literal.net", "share");
+/// assert_eq!(
+///     prefix.host(),
+///     Some(Utf8UncHost::Ipv6 {
+///         segments: [0xfe80, 0, 0, 0, 0, 0, 0, 1],
+///         scope_id: None,
+///     }),
+/// );
+///
+/// let prefix = Utf8WindowsPrefix::UNC("files.example.com", "share");
+/// assert_eq!(prefix.host(), Some(Utf8UncHost::Name("files.example.com")));
+/// ```
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+pub enum Utf8UncHost<'a> {
+    /// Host name or fully qualified domain name, e.g., `fileserver` or `files.example.com`.
+    Name(&'a str),
+
+    /// IPv4 address written in dotted decimal, e.g., `192.168.1.20`.
+    Ipv4([u8; 4]),
+
+    /// IPv6 address written using the `ipv6-literal.net` convention, e.g.,
+    /// `fe80--1s4.ipv6-literal.net`.
+    Ipv6 {
+        /// The eight 16-bit segments of the address.
+        segments: [u16; 8],
+
+        /// The zone index following `s`, e.g., `4` in `fe80--1s4.ipv6-literal.net`.
+        scope_id: Option<u32>,
+    },
+}
+
+impl<'a> Utf8UncHost<'a> {
+    /// Reads the UNC server `server` as a host.
+    pub fn parse(server: &'a str) -> Self {
+        match UncHost::parse(server.as_bytes()) {
+            UncHost::Name(_) => Self::Name(server),
+            UncHost::Ipv4(octets) => Self::Ipv4(octets),
+            UncHost::Ipv6 { segments, scope_id } => Self::Ipv6 { segments, scope_id },
+        }
+    }
+
+    /// Converts the host into an [`IpAddr`](std::net::IpAddr), or returns [`None`] if it is a
+    /// host name.
+    ///
+    /// See [`UncHost::ip_addr`] for more details.
+    #[cfg(feature = "std")]
+    pub fn ip_addr(&self) -> Option<std::net::IpAddr> {
+        match *self {
+            Self::Name(_) => None,
+            Self::Ipv4(octets) => Some(std::net::IpAddr::from(octets)),
+            Self::Ipv6 { segments, .. } => Some(std::net::IpAddr::from(segments)),
+        }
+    }
+}
@@ -0,0 +1,377 @@
+use core::fmt;
+use core::str::FromStr;
+
+use crate::common::DatasetNameError;
+use crate::no_std_compat::*;
+use crate::{Utf8UnixPath, Utf8UnixPathBuf};
+
+/// A z/OS MVS dataset name, such as `SYS1.PROCLIB` or `HLQ.SOURCE.COBOL(PAYROLL)`, made up of
+/// qualifiers separated by `.` and an optional partitioned dataset member in parentheses.
+///
+/// Dataset names have no notion of a root, current, or parent directory, so rather than being
+/// an [`Encoding`](crate::Encoding), this is a validated name that can be mapped to and from the
+/// `//'DATASET.NAME(MEMBER)'` form that z/OS UNIX System Services (USS) uses to refer to
+/// datasets from within its Unix filesystem.
+///
+/// A name is valid when:
+///
+/// * it is at most [`MAX_LEN`](Self::MAX_LEN) characters long, not counting the member
+/// * every qualifier is between 1 and [`MAX_QUALIFIER_LEN`](Self::MAX_QUALIFIER_LEN) characters
+///   long, starts with a letter or one of `@`, `#`, or `$`, and otherwise only contains letters,
+///   digits, `@`, `#`, `$`, or `-`
+/// * the member, if any, follows the same rules as a qualifier except that `-` is not allowed
+///
+/// Names are case-insensitive and always stored in uppercase.
+///
+/// # Examples
+///
+/// ```
+/// use typed_path::{DatasetName, Utf8UnixPath};
+///
+/// let name = DatasetName::new("hlq.source.cobol(payroll)").unwrap();
+/// assert_eq!(name.as_str(), "HLQ.SOURCE.COBOL(PAYROLL)");
+/// assert_eq!(name.qualifiers().collect::<Vec<_>>(), ["HLQ", "SOURCE", "COBOL"]);
+/// assert_eq!(name.high_level_qualifier(), "HLQ");
+/// assert_eq!(name.dataset(), "HLQ.SOURCE.COBOL");
+/// assert_eq!(name.member(), Some("PAYROLL"));
+///
+/// let uss = name.to_uss_path();
+/// assert_eq!(uss, Utf8UnixPath::new("//'HLQ.SOURCE.COBOL(PAYROLL)'"));
+/// assert_eq!(DatasetName::from_uss_path(&uss), Ok(name));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct DatasetName {
+    /// Full name including any member, in uppercase
+    inner: String,
+
+    /// Length of the name without the member
+    dataset_len: usize,
+}
+
+impl DatasetName {
+    /// Maximum length of a dataset name, not counting the member
+    pub const MAX_LEN: usize = 44;
+
+    /// Maximum length of a single qualifier or member
+    pub const MAX_QUALIFIER_LEN: usize = 8;
+
+    /// Parses and validates `name` into a new [`DatasetName`], converting it to uppercase.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DatasetNameError`] describing the first rule that `name` breaks.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{DatasetName, DatasetNameError};
+    ///
+    /// assert!(DatasetName::new("SYS1.PROCLIB").is_ok());
+    /// assert!(DatasetName::new("#USER.$DATA.@X-1").is_ok());
+    ///
+    /// assert_eq!(DatasetName::new("SYS1..PROCLIB"), Err(DatasetNameError::InvalidQualifier(1)));
+    /// assert_eq!(DatasetName::new("TOOLONGHLQ.X"), Err(DatasetNameError::InvalidQualifier(0)));
+    /// assert_eq!(DatasetName::new("1ST.NAME"), Err(DatasetNameError::InvalidQualifier(0)));
+    /// assert_eq!(DatasetName::new("SYS1.PROCLIB(IEF-PROC)"), Err(DatasetNameError::InvalidMember));
+    /// assert_eq!(
+    ///     DatasetName::new("AAAAAAAA.BBBBBBBB.CCCCCCCC.DDDDDDDD.EEEEEEEE.F"),
+    ///     Err(DatasetNameError::TooLong(46)),
+    /// );
+    /// ```
+    pub fn new(name: &str) -> Result<Self, DatasetNameError> {
+        let (dataset, member) = match name.find('(') {
+            Some(i) => {
+                let member = name[i + 1..]
+                    .strip_suffix(')')
+                    .ok_or(DatasetNameError::InvalidMember)?;
+                (&name[..i], Some(member))
+            }
+            None if name.contains(')') => return Err(DatasetNameError::InvalidMember),
+            None => (name, None),
+        };
+
+        if dataset.len() > Self::MAX_LEN {
+            return Err(DatasetNameError::TooLong(dataset.len()));
+        }
+
+        for (i, qualifier) in dataset.split('.').enumerate() {
+            if !is_valid_name(qualifier, true) {
+                return Err(DatasetNameError::InvalidQualifier(i));
+            }
+        }
+
+        if let Some(member) = member {
+            if !is_valid_name(member, false) {
+                return Err(DatasetNameError::InvalidMember);
+            }
+        }
+
+        Ok(Self {
+            inner: name.to_ascii_uppercase(),
+            dataset_len: dataset.len(),
+        })
+    }
+
+    /// Returns the full name, including the member in parentheses if there is one.
+    pub fn as_str(&self) -> &str {
+        &self.inner
+    }
+
+    /// Returns the name of the dataset without any member.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::DatasetName;
+    ///
+    /// let name = DatasetName::new("SYS1.PROCLIB(IEFPROC)").unwrap();
+    /// assert_eq!(name.dataset(), "SYS1.PROCLIB");
+    /// ```
+    pub fn dataset(&self) -> &str {
+        &self.inner[..self.dataset_len]
+    }
+
+    /// Returns an iterator over the qualifiers of the dataset, from the high-level qualifier to
+    /// the last qualifier.
+    pub fn qualifiers(&self) -> impl DoubleEndedIterator<Item = &str> {
+        self.dataset().split('.')
+    }
+
+    /// Returns the first qualifier, which is typically the owning user or system.
+    pub fn high_level_qualifier(&self) -> &str {
+        self.qualifiers().next().unwrap_or_default()
+    }
+
+    /// Returns the member of the partitioned dataset, if there is one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::DatasetName;
+    ///
+    /// assert_eq!(DatasetName::new("SYS1.PROCLIB(IEFPROC)").unwrap().member(), Some("IEFPROC"));
+    /// assert_eq!(DatasetName::new("SYS1.PROCLIB").unwrap().member(), None);
+    /// ```
+    pub fn member(&self) -> Option<&str> {
+        let rest = &self.inner[self.dataset_len..];
+        rest.strip_prefix('(')?.strip_suffix(')')
+    }
+
+    /// Creates a new [`DatasetName`] for `member` within the same dataset, replacing any existing
+    /// member.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DatasetNameError::InvalidMember`] if `member` is not a valid member name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{DatasetName, DatasetNameError};
+    ///
+    /// let name = DatasetName::new("HLQ.JCL(OLD)").unwrap();
+    /// assert_eq!(name.with_member("new").unwrap().as_str(), "HLQ.JCL(NEW)");
+    /// assert_eq!(name.with_member("TOOLONGNAME"), Err(DatasetNameError::InvalidMember));
+    /// ```
+    pub fn with_member(&self, member: &str) -> Result<Self, DatasetNameError> {
+        if !is_valid_name(member, false) {
+            return Err(DatasetNameError::InvalidMember);
+        }
+
+        let mut inner = String::with_capacity(self.dataset_len + member.len() + 2);
+        inner.push_str(self.dataset());
+        inner.push('(');
+        inner.push_str(&member.to_ascii_uppercase());
+        inner.push(')');
+
+        Ok(Self {
+            inner,
+            dataset_len: self.dataset_len,
+        })
+    }
+
+    /// Creates a new [`DatasetName`] for the dataset as a whole, without any member.
+    pub fn without_member(&self) -> Self {
+        Self {
+            inner: self.dataset().to_string(),
+            dataset_len: self.dataset_len,
+        }
+    }
+
+    /// Maps the dataset to the `//'DATASET.NAME(MEMBER)'` path that z/OS UNIX System Services
+    /// accepts wherever a file path is expected, such as with `cp` or `fopen`.
+    pub fn to_uss_path(&self) -> Utf8UnixPathBuf {
+        let mut path = String::with_capacity(self.inner.len() + 4);
+        path.push_str("//'");
+        path.push_str(&self.inner);
+        path.push('\'');
+        Utf8UnixPathBuf::from(path)
+    }
+
+    /// Parses a `//'DATASET.NAME(MEMBER)'` path used by z/OS UNIX System Services to refer to a
+    /// dataset.
+    ///
+    /// Only fully qualified names in quotes are supported, as an unquoted `//NAME` is relative to
+    /// the TSO prefix of the user running the program.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DatasetNameError::NotDatasetPath`] if `path` does not use this form, or any
+    /// other [`DatasetNameError`] if the name within is invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{DatasetName, DatasetNameError, Utf8UnixPath};
+    ///
+    /// let name = DatasetName::from_uss_path(Utf8UnixPath::new("//'SYS1.PROCLIB'")).unwrap();
+    /// assert_eq!(name.as_str(), "SYS1.PROCLIB");
+    ///
+    /// assert_eq!(
+    ///     DatasetName::from_uss_path(Utf8UnixPath::new("/u/user/file")),
+    ///     Err(DatasetNameError::NotDatasetPath),
+    /// );
+    /// ```
+    pub fn from_uss_path<P: AsRef<Utf8UnixPath>>(path: P) -> Result<Self, DatasetNameError> {
+        let name = path
+            .as_ref()
+            .as_str()
+            .strip_prefix("//'")
+            .and_then(|s| s.strip_suffix('\''))
+            .ok_or(DatasetNameError::NotDatasetPath)?;
+
+        Self::new(name)
+    }
+}
+
+/// Returns true if `name` is a valid qualifier, or a valid member when `is_qualifier` is false
+fn is_valid_name(name: &str, is_qualifier: bool) -> bool {
+    let is_national = |b: u8| matches!(b, b'@' | b'#' | b'$');
+    let bytes = name.as_bytes();
+
+    match bytes.split_first() {
+        Some((first, rest)) if bytes.len() <= DatasetName::MAX_QUALIFIER_LEN => {
+            (first.is_ascii_alphabetic() || is_national(*first))
+                && rest.iter().all(|b| {
+                    b.is_ascii_alphanumeric() || is_national(*b) || (is_qualifier && *b == b'-')
+                })
+        }
+        _ => false,
+    }
+}
+
+impl fmt::Display for DatasetName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.inner)
+    }
+}
+
+impl FromStr for DatasetName {
+    type Err = DatasetNameError;
+
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::new(s)
+    }
+}
+
+impl AsRef<str> for DatasetName {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        &self.inner
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new_should_enforce_qualifier_and_length_limits() {
+        assert!(DatasetName::new("A").is_ok());
+        assert!(DatasetName::new("ABCDEFGH.ABCDEFGH").is_ok());
+        assert!(DatasetName::new("AAAAAAAA.BBBBBBBB.CCCCCCCC.DDDDDDDD.EEEEEEEE").is_ok());
+        assert!(DatasetName::new("AAAAAAAA.BBBBBBBB.CCCCCCCC.DDDDDDDD.EEEEEEEE(MEMBER)").is_ok());
+
+        assert_eq!(
+            DatasetName::new("AAAAAAAA.BBBBBBBB.CCCCCCCC.DDDDDDDD.EEEEE.FFF"),
+            Err(DatasetNameError::TooLong(45))
+        );
+        assert_eq!(
+            DatasetName::new(""),
+            Err(DatasetNameError::InvalidQualifier(0))
+        );
+        assert_eq!(
+            DatasetName::new("A."),
+            Err(DatasetNameError::InvalidQualifier(1))
+        );
+        assert_eq!(
+            DatasetName::new(".A"),
+            Err(DatasetNameError::InvalidQualifier(0))
+        );
+        assert_eq!(
+            DatasetName::new("A.-B"),
+            Err(DatasetNameError::InvalidQualifier(1))
+        );
+        assert_eq!(
+            DatasetName::new("A.B_C"),
+            Err(DatasetNameError::InvalidQualifier(1))
+        );
+        assert_eq!(
+            DatasetName::new("A.ABCDEFGHI"),
+            Err(DatasetNameError::InvalidQualifier(1))
+        );
+        assert_eq!(
+            DatasetName::new("A.\u{e9}"),
+            Err(DatasetNameError::InvalidQualifier(1))
+        );
+    }
+
+    #[test]
+    fn new_should_validate_member() {
+        assert_eq!(
+            DatasetName::new("A(M").unwrap_err(),
+            DatasetNameError::InvalidMember
+        );
+        assert_eq!(
+            DatasetName::new("A(M))").unwrap_err(),
+            DatasetNameError::InvalidMember
+        );
+        assert_eq!(
+            DatasetName::new("A()").unwrap_err(),
+            DatasetNameError::InvalidMember
+        );
+        assert_eq!(
+            DatasetName::new("A)M").unwrap_err(),
+            DatasetNameError::InvalidMember
+        );
+        assert_eq!(
+            DatasetName::new("A(1M)").unwrap_err(),
+            DatasetNameError::InvalidMember
+        );
+        assert_eq!(
+            DatasetName::new("A(M)B").unwrap_err(),
+            DatasetNameError::InvalidMember
+        );
+
+        let name = DatasetName::new("a.b($m#1)").unwrap();
+        assert_eq!(name.member(), Some("$M#1"));
+        assert_eq!(name.without_member().as_str(), "A.B");
+        assert_eq!(name.without_member().member(), None);
+    }
+
+    #[test]
+    fn uss_path_should_round_trip() {
+        for name in ["SYS1.PROCLIB", "HLQ.JCL(JOB1)", "A"] {
+            let name = DatasetName::new(name).unwrap();
+            assert_eq!(DatasetName::from_uss_path(name.to_uss_path()), Ok(name));
+        }
+
+        for path in ["//SYS1.PROCLIB", "//'SYS1.PROCLIB", "/'SYS1.PROCLIB'"] {
+            assert_eq!(
+                DatasetName::from_uss_path(Utf8UnixPath::new(path)),
+                Err(DatasetNameError::NotDatasetPath)
+            );
+        }
+    }
+}